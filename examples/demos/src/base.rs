@@ -2,6 +2,7 @@
 // https://github.com/SaschaWillems/Vulkan/blob/master/base/vulkanexamplebase.cpp
 
 use std::mem;
+use std::slice;
 use std::os::raw::c_void;
 use std::ptr::{null, null_mut};
 use std::ffi::CString;
@@ -80,43 +81,31 @@ impl ExampleBase {
         this
     }
 
-    pub fn create_instance(&mut self, enable_validation: bool) -> Result<(), vk::Result> {
+    pub fn create_instance(&mut self, enable_validation: bool) -> Result<(), vk::Error> {
         self.enable_validation = enable_validation;
         self.title = Some(String::from("Vulkan Example"));
         self.name = Some(String::from("vulkanExample"));
-        let name_cstr = cstr(self.name.as_ref().unwrap());
-
-        let app_info = vk::ApplicationInfo {
-            sType: vk::StructureType::APPLICATION_INFO,
-            pApplicationName: name_cstr,
-            pEngineName: name_cstr,
-            apiVersion: vk::make_version(1, 0, 2),
-            applicationVersion: 0,
-            engineVersion: 0,
-            pNext: null(),
-        };
+        let name = cstr(self.name.as_ref().unwrap());
+        let app_info = vk::ApplicationInfo::builder()
+            .application_name(&name)
+            .engine_name(&name)
+            .api_version(vk::Version::new(1, 0, 2).into());
 
-        let mut enabled_extensions = vec![
+        let extension_names = [
             cstr(vk::khr::surface::EXTENSION_NAME),
             cstr(vk::khr::xcb_surface::EXTENSION_NAME),
         ];
+        let enabled_extensions: Vec<_> = extension_names.iter().map(|n| n.as_ptr()).collect();
 
         // TODO: validation
 
-        let instance_create_info = vk::InstanceCreateInfo {
-            sType: vk::StructureType::INSTANCE_CREATE_INFO,
-            pNext: null(),
-            pApplicationInfo: &app_info,
-            enabledExtensionCount: enabled_extensions.len() as u32,
-            ppEnabledExtensionNames: enabled_extensions.as_ptr(),
-            enabledLayerCount: 0, // TODO: validation
-            ppEnabledLayerNames: null(), // TODO: validation
-            flags: Default::default(),
-        };
+        let instance_create_info = vk::InstanceCreateInfo::builder()
+            .application_info(&app_info)
+            .enabled_extension_names(&enabled_extensions);
 
         let instance = unsafe {
             let mut instance = mem::uninitialized();
-            vktry!(vk::vkCreateInstance(&instance_create_info, null(), &mut instance));
+            vk::vkCreateInstance(&*instance_create_info, null(), &mut instance).result()?;
             instance
         };
 
@@ -125,29 +114,18 @@ impl ExampleBase {
     }
 
     pub fn create_device(&mut self, requested_queues: vk::DeviceQueueCreateInfo,
-                         enable_validation: bool) -> Result<(), vk::Result> {
-        let enabled_extensions = vec![
-            cstr(vk::khr::swapchain::EXTENSION_NAME),
-        ];
+                         enable_validation: bool) -> Result<(), vk::Error> {
+        let extension_names = [cstr(vk::khr::swapchain::EXTENSION_NAME)];
+        let enabled_extensions: Vec<_> = extension_names.iter().map(|n| n.as_ptr()).collect();
 
-        let device_create_info = vk::DeviceCreateInfo {
-            sType: vk::StructureType::DEVICE_CREATE_INFO,
-            pNext: null(),
-            queueCreateInfoCount: 1,
-            pQueueCreateInfos: &requested_queues,
-            pEnabledFeatures: null(),
-            enabledExtensionCount: enabled_extensions.len() as u32,
-            ppEnabledExtensionNames:
-                if enabled_extensions.len() == 0 { null() } else { enabled_extensions.as_ptr() },
-            enabledLayerCount: 0,
-            ppEnabledLayerNames: null(),
-            flags: Default::default(),
-        };
+        let device_create_info = vk::DeviceCreateInfo::builder()
+            .queue_create_infos(slice::from_ref(&requested_queues))
+            .enabled_extension_names(&enabled_extensions);
 
         let device = unsafe {
             let mut device = mem::uninitialized();
-            vktry!(vk::vkCreateDevice(self.physical_device.unwrap(),
-                                         &device_create_info, null(), &mut device));
+            vk::vkCreateDevice(self.physical_device.unwrap(),
+                               &*device_create_info, null(), &mut device).result()?;
             device
         };
         // TODO: validation
@@ -159,50 +137,27 @@ impl ExampleBase {
         let res = self.create_instance(enable_validation);
         vkrqr(res, "could not create instance");
 
-        let mut gpu_count = 0u32;
-        let res = unsafe {
-            vk::vkEnumeratePhysicalDevices(self.instance.unwrap(), &mut gpu_count, null_mut())
-        };
-        vkrq(res, "could not enumerate physical devices");
-        assert!(gpu_count > 0, "no physical devices found");
-
-        let mut physical_devices = Vec::with_capacity(gpu_count as usize);
-        unsafe {
-            physical_devices.set_len(gpu_count as usize);
-            let res = vk::vkEnumeratePhysicalDevices(self.instance.unwrap(), &mut gpu_count,
-                                                     physical_devices.as_mut_ptr());
-            vkrq(res, "could not enumerate physical devices");
-        }
+        let physical_devices = unsafe { vk::enumerate_physical_devices(self.instance.unwrap()) };
+        let physical_devices = vkrqr(physical_devices, "could not enumerate physical devices");
+        assert!(!physical_devices.is_empty(), "no physical devices found");
 
         let physical_device = physical_devices[0];
 
-        let mut queue_count = 0u32;
-        unsafe {
-            vk::vkGetPhysicalDeviceQueueFamilyProperties(physical_device, &mut queue_count,
-                                                         null_mut());
-        }
-        assert!(queue_count >= 1, "could not query physical device queue family");
-
-        let mut queue_props = Vec::with_capacity(queue_count as usize);
-        unsafe {
-            queue_props.set_len(queue_count as usize);
-            vk::vkGetPhysicalDeviceQueueFamilyProperties(physical_device, &mut queue_count,
-                                                         queue_props.as_mut_ptr());
-        }
+        let queue_props = unsafe { vk::get_physical_device_queue_family_properties(physical_device) };
+        assert!(!queue_props.is_empty(), "could not query physical device queue family");
 
         let graphics_queue_index = queue_props.iter()
-            .position(|p| (p.queueFlags & vk::QueueFlag::GRAPHICS).0 != 0);
+            .position(|p| p.queueFlags.contains(vk::QueueFlag::GRAPHICS));
         assert!(graphics_queue_index.is_some(), "could not find graphics queue");
         let graphics_queue_index = graphics_queue_index.unwrap();
 
         let queue_priorities = [0f32];
         let queue_create_info = vk::DeviceQueueCreateInfo {
-            sType: vk::StructureType::DEVICE_QUEUE_CREATE_INFO,
             queueFamilyIndex: graphics_queue_index as u32,
             queueCount: 1,
             pQueuePriorities: &queue_priorities[0],
             flags: Default::default(),
-            pNext: null(),
+            ..Default::default()
         };
 
         vkrqr(self.create_device(queue_create_info, enable_validation),
@@ -255,9 +210,9 @@ impl ExampleBase {
                               value_mask, value_list.as_ptr());
         }
 
-        let cookie = unsafe { xcb_intern_atom(connection, 1, 12, cstr("WM_PROTOCOLS")) };
+        let cookie = unsafe { xcb_intern_atom(connection, 1, 12, cstr("WM_PROTOCOLS").as_ptr()) };
         let reply = unsafe { xcb_intern_atom_reply(connection, cookie, null_mut()) };
-        let cookie2 = unsafe { xcb_intern_atom(connection, 0, 16, cstr("WM_DELETE_WINDOW")) };
+        let cookie2 = unsafe { xcb_intern_atom(connection, 0, 16, cstr("WM_DELETE_WINDOW").as_ptr()) };
 
         let awdw = unsafe { *xcb_intern_atom_reply(connection, cookie2, null_mut()) };
         self.atom_wm_delete_window = Some(awdw);
@@ -272,7 +227,7 @@ impl ExampleBase {
 
             xcb_change_property(connection, XCB_PROP_MODE_REPLACE as u8,
                                 window, XCB_ATOM_WM_NAME, XCB_ATOM_STRING, 8,
-                                title.len() as u32, cstr(title) as *const _);
+                                title.len() as u32, cstr(title).as_ptr() as *const _);
         }
 
         unsafe {
@@ -312,10 +267,9 @@ impl ExampleBase {
 
     fn create_command_pool(&mut self) {
         let cmd_pool_info = vk::CommandPoolCreateInfo {
-            sType: vk::StructureType::COMMAND_POOL_CREATE_INFO,
             queueFamilyIndex: self.swapchain.as_ref().unwrap().queue_node_index,
             flags: vk::CommandPoolCreateFlag::RESET_COMMAND_BUFFER.into(),
-            pNext: null(),
+            ..Default::default()
         };
 
         let cmd_pool = unsafe {
@@ -3,7 +3,6 @@ extern crate xcb;
 extern crate cgmath;
 extern crate libc;
 
-#[macro_use]
 mod util;
 mod swapchain;
 mod textureloader;
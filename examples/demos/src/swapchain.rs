@@ -16,8 +16,8 @@ pub struct SwapchainBuffer {
 impl Default for SwapchainBuffer {
     fn default() -> Self {
         SwapchainBuffer {
-            image: null_mut(),
-            view: null_mut(),
+            image: vk::Image::null(),
+            view: vk::ImageView::null(),
         }
     }
 }
@@ -30,15 +30,15 @@ pub struct Swapchain {
     physical_device: vk::PhysicalDevice,
     surface: vk::khr::Surface,
     // Function pointers
-    fpGetPhysicalDeviceSurfaceSupport:      vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceSupport,
-    fpGetPhysicalDeviceSurfaceCapabilities: vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceCapabilities,
-    fpGetPhysicalDeviceSurfaceFormats:      vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceFormats,
-    fpGetPhysicalDeviceSurfacePresentModes: vk::khr::surface::PFN_vkGetPhysicalDeviceSurfacePresentModes,
-    fpCreateSwapchain:                      vk::khr::swapchain::PFN_vkCreateSwapchain,
-    fpDestroySwapchain:                     vk::khr::swapchain::PFN_vkDestroySwapchain,
-    fpGetSwapchainImages:                   vk::khr::swapchain::PFN_vkGetSwapchainImages,
-    fpAcquireNextImage:                     vk::khr::swapchain::PFN_vkAcquireNextImage,
-    fpQueuePresent:                         vk::khr::swapchain::PFN_vkQueuePresent,
+    fpGetPhysicalDeviceSurfaceSupport:      vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceSupportKHR,
+    fpGetPhysicalDeviceSurfaceCapabilities: vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR,
+    fpGetPhysicalDeviceSurfaceFormats:      vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR,
+    fpGetPhysicalDeviceSurfacePresentModes: vk::khr::surface::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR,
+    fpCreateSwapchain:                      vk::khr::swapchain::PFN_vkCreateSwapchainKHR,
+    fpDestroySwapchain:                     vk::khr::swapchain::PFN_vkDestroySwapchainKHR,
+    fpGetSwapchainImages:                   vk::khr::swapchain::PFN_vkGetSwapchainImagesKHR,
+    fpAcquireNextImage:                     vk::khr::swapchain::PFN_vkAcquireNextImageKHR,
+    fpQueuePresent:                         vk::khr::swapchain::PFN_vkQueuePresentKHR,
     pub color_format: vk::Format,
     pub color_space: vk::khr::surface::ColorSpace,
     pub swapchain: Option<vk::khr::Swapchain>,
@@ -51,14 +51,14 @@ pub struct Swapchain {
 
 macro_rules! get_instance_proc_addr {
     ($inst:expr, $entrypoint:expr) => {{
-        let f = vk::vkGetInstanceProcAddr($inst, $entrypoint.as_ptr() as *const i8);
+        let f = vk::vkGetInstanceProcAddr($inst, concat!($entrypoint, "\0").as_ptr() as *const i8);
         mem::transmute(f)
     }}
 }
 
 macro_rules! get_device_proc_addr {
     ($dev:expr, $entrypoint:expr) => {{
-        let f = vk::vkGetDeviceProcAddr($dev, $entrypoint.as_ptr() as *const i8);
+        let f = vk::vkGetDeviceProcAddr($dev, concat!($entrypoint, "\0").as_ptr() as *const i8);
         mem::transmute(f)
     }}
 }
@@ -72,41 +72,40 @@ impl Swapchain {
                window: xcb_window_t) -> Self {
         // ## connect
         let                                            fpGetPhysicalDeviceSurfaceSupport:
-                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceSupport =
-            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfaceSupport") };
+                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceSupportKHR =
+            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfaceSupportKHR") };
         let                                            fpGetPhysicalDeviceSurfaceCapabilities:
-                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceCapabilities =
-            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfaceCapabilities") };
+                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR =
+            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfaceCapabilitiesKHR") };
         let                                            fpGetPhysicalDeviceSurfaceFormats:
-                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceFormats =
-            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfaceFormats") };
+                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR =
+            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfaceFormatsKHR") };
         let                                            fpGetPhysicalDeviceSurfacePresentModes:
-                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfacePresentModes =
-            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfacePresentModes") };
+                                 vk::khr::surface::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR =
+            unsafe { get_instance_proc_addr!(instance, "vkGetPhysicalDeviceSurfacePresentModesKHR") };
         let                                            fpCreateSwapchain:
-                               vk::khr::swapchain::PFN_vkCreateSwapchain =
-            unsafe { get_device_proc_addr!(device,     "vkCreateSwapchain") };
+                               vk::khr::swapchain::PFN_vkCreateSwapchainKHR =
+            unsafe { get_device_proc_addr!(device,     "vkCreateSwapchainKHR") };
         let                                            fpDestroySwapchain:
-                               vk::khr::swapchain::PFN_vkDestroySwapchain =
-            unsafe { get_device_proc_addr!(device,     "vkDestroySwapchain") };
+                               vk::khr::swapchain::PFN_vkDestroySwapchainKHR =
+            unsafe { get_device_proc_addr!(device,     "vkDestroySwapchainKHR") };
         let                                            fpGetSwapchainImages:
-                               vk::khr::swapchain::PFN_vkGetSwapchainImages =
-            unsafe { get_device_proc_addr!(device,     "vkGetSwapchainImages") };
+                               vk::khr::swapchain::PFN_vkGetSwapchainImagesKHR =
+            unsafe { get_device_proc_addr!(device,     "vkGetSwapchainImagesKHR") };
         let                                            fpAcquireNextImage:
-                               vk::khr::swapchain::PFN_vkAcquireNextImage =
-            unsafe { get_device_proc_addr!(device,     "vkAcquireNextImage") };
+                               vk::khr::swapchain::PFN_vkAcquireNextImageKHR =
+            unsafe { get_device_proc_addr!(device,     "vkAcquireNextImageKHR") };
         let                                            fpQueuePresent:
-                               vk::khr::swapchain::PFN_vkQueuePresent =
-            unsafe { get_device_proc_addr!(device,     "vkQueuePresent") };
+                               vk::khr::swapchain::PFN_vkQueuePresentKHR =
+            unsafe { get_device_proc_addr!(device,     "vkQueuePresentKHR") };
 
         // ## initSurface
 
         let surface_create_info = vk::khr::xcb_surface::CreateInfo {
-            sType: vk::StructureType::XCB_SURFACE_CREATE_INFO,
             connection: connection,
             window: window,
-            pNext: null(),
             flags: Default::default(),
+            ..Default::default()
         };
         let surface = unsafe {
             let mut surface = mem::uninitialized();
@@ -114,19 +113,9 @@ impl Swapchain {
             surface
         };
 
-        let mut queue_count = 0u32;
-        unsafe {
-            vk::vkGetPhysicalDeviceQueueFamilyProperties(physical_device, &mut queue_count,
-                                                         null_mut());
-        }
-        assert!(queue_count >= 1);
-
-        let mut queue_props: Vec<vk::QueueFamilyProperties> = Vec::with_capacity(queue_count as usize);
-        unsafe {
-            vk::vkGetPhysicalDeviceQueueFamilyProperties(physical_device, &mut queue_count,
-                                                         queue_props.as_mut_ptr());
-            queue_props.set_len(queue_count as usize);
-        }
+        let queue_props = unsafe { vk::get_physical_device_queue_family_properties(physical_device) };
+        assert!(!queue_props.is_empty());
+        let queue_count = queue_props.len() as u32;
 
         let mut supports_present: Vec<vk::Bool32> = vec![vk::FALSE; queue_count as usize];
         for i in 0..queue_count {
@@ -139,7 +128,7 @@ impl Swapchain {
         let mut graphics_queue_node_index = std::u32::MAX;
         let mut present_queue_node_index = std::u32::MAX;
         for i in 0..queue_count {
-            if (queue_props[i as usize].queueFlags & vk::QueueFlag::GRAPHICS).0 > 0 {
+            if queue_props[i as usize].queueFlags.contains(vk::QueueFlag::GRAPHICS) {
                 if graphics_queue_node_index == std::u32::MAX {
                     graphics_queue_node_index = i;
                 }
@@ -163,22 +152,16 @@ impl Swapchain {
         assert!(present_queue_node_index != std::u32::MAX);
         assert!(graphics_queue_node_index == present_queue_node_index);
 
-        let mut format_count = 0u32;
-        unsafe {
-            vksuccess(fpGetPhysicalDeviceSurfaceFormats.unwrap()(
-                    physical_device, surface, &mut format_count, null_mut()));
-        }
-        assert!(format_count > 0);
-
-        let mut surface_formats: Vec<vk::khr::surface::Format> = Vec::with_capacity(format_count as usize);
-        unsafe {
-            vksuccess(fpGetPhysicalDeviceSurfaceFormats.unwrap()(
-                    physical_device, surface, &mut format_count, surface_formats.as_mut_ptr()));
-            surface_formats.set_len(format_count as usize);
-        }
+        let surface_formats = unsafe {
+            vk::enumerate(|count, data| {
+                fpGetPhysicalDeviceSurfaceFormats.unwrap()(physical_device, surface, count, data)
+            })
+        };
+        let surface_formats = vkrqr(surface_formats, "could not get surface formats");
+        assert!(!surface_formats.is_empty());
 
         let color_format =
-            if format_count == 1 && surface_formats[0].format == vk::Format::UNDEFINED {
+            if surface_formats.len() == 1 && surface_formats[0].format == vk::Format::UNDEFINED {
                 vk::Format::B8G8R8A8_UNORM
             } else {
                 surface_formats[0].format
@@ -210,28 +193,21 @@ impl Swapchain {
     }
 
     pub fn create(&mut self, cmd_buffer: vk::CommandBuffer, width: &mut u32, height: &mut u32) {
-        let old_swapchain = if let Some(s) = self.swapchain { s } else { null_mut() };
+        let old_swapchain = if let Some(s) = self.swapchain { s } else { vk::khr::Swapchain::null() };
         let mut surf_caps = vk::khr::surface::Capabilities::default();
         unsafe {
             vksuccess(self.fpGetPhysicalDeviceSurfaceCapabilities.unwrap()(
                     self.physical_device, self.surface, &mut surf_caps));
         }
 
-        let mut present_mode_count = 0u32;
-        unsafe {
-            vksuccess(self.fpGetPhysicalDeviceSurfacePresentModes.unwrap()(
-                    self.physical_device, self.surface,
-                    &mut present_mode_count, null_mut()));
-        }
-        assert!(present_mode_count > 0);
-
-        let mut present_modes = Vec::with_capacity(present_mode_count as usize);
-        unsafe {
-            vksuccess(self.fpGetPhysicalDeviceSurfacePresentModes.unwrap()(
-                    self.physical_device, self.surface,
-                    &mut present_mode_count, present_modes.as_mut_ptr()));
-            present_modes.set_len(present_mode_count as usize);
-        }
+        let present_modes = unsafe {
+            vk::enumerate(|count, data| {
+                self.fpGetPhysicalDeviceSurfacePresentModes.unwrap()(self.physical_device, self.surface,
+                                                                     count, data)
+            })
+        };
+        let present_modes = vkrqr(present_modes, "could not get present modes");
+        assert!(!present_modes.is_empty());
 
         let swapchain_extent = if surf_caps.currentExtent.width == std::u32::MAX {
             vk::Extent2D {
@@ -265,15 +241,13 @@ impl Swapchain {
         }
 
         let pre_transform =
-            if (surf_caps.supportedTransforms & vk::khr::surface::TransformFlag::IDENTITY).0 > 0 {
+            if surf_caps.supportedTransforms.contains(vk::khr::surface::TransformFlag::IDENTITY) {
                 vk::khr::surface::TransformFlag::IDENTITY
             } else {
                 surf_caps.currentTransform
             };
 
         let swapchain_ci = vk::khr::swapchain::CreateInfo {
-            sType: vk::StructureType::SWAPCHAIN_CREATE_INFO,
-            pNext: null(),
             surface: self.surface,
             minImageCount: desired_number_of_swapchain_images,
             imageFormat: self.color_format,
@@ -290,6 +264,7 @@ impl Swapchain {
             clipped: vk::TRUE,
             compositeAlpha: vk::khr::surface::CompositeAlphaFlag::OPAQUE,
             flags: Default::default(),
+            ..Default::default()
         };
 
         // If an existing swapchain is re-created, destroy the old swapchain
@@ -301,35 +276,25 @@ impl Swapchain {
             self.swapchain = Some(swapchain);
         }
 
-        if old_swapchain != null_mut() {
+        if !old_swapchain.is_null() {
             unsafe {
                 self.fpDestroySwapchain.unwrap()(self.device, old_swapchain, null());
             }
         }
 
         // Get the swapchain images
-        let mut image_count = 0u32;
-        unsafe {
-            vksuccess(self.fpGetSwapchainImages.unwrap()(
-                    self.device, self.swapchain.unwrap(),
-                    &mut image_count, null_mut()));
-        }
-
-        self.images.reserve_exact(image_count as usize);
-        unsafe {
-            vksuccess(self.fpGetSwapchainImages.unwrap()(
-                    self.device, self.swapchain.unwrap(),
-                    &mut image_count, self.images.as_mut_ptr()));
-            self.images.set_len(image_count as usize);
-        }
-
+        let images = unsafe {
+            vk::enumerate(|count, data| {
+                self.fpGetSwapchainImages.unwrap()(self.device, self.swapchain.unwrap(), count, data)
+            })
+        };
+        self.images = vkrqr(images, "could not get swapchain images");
+        let image_count = self.images.len() as u32;
 
         // Get the swap chain buffers containing the image and imageview
         self.buffers.reserve_exact(image_count as usize);
         for i in 0..image_count as usize {
             let color_attachment_view = vk::ImageViewCreateInfo {
-                sType: vk::StructureType::IMAGE_VIEW_CREATE_INFO,
-                pNext: null(),
                 format: self.color_format,
                 components: vk::ComponentMapping::default(),
                 subresourceRange: vk::ImageSubresourceRange {
@@ -342,6 +307,7 @@ impl Swapchain {
                 viewType: vk::ImageViewType::E_2D,
                 flags: Default::default(),
                 image: self.images[i],
+                ..Default::default()
             };
 
             self.buffers[i].image = self.images[i];
@@ -360,32 +326,27 @@ impl Swapchain {
     }
 
     pub fn acquire_next_image(&self, present_complete_semaphore: vk::Semaphore)
-                              -> Result<u32, vk::Result> {
+                              -> Result<u32, vk::Error> {
         let mut current_buffer = 0u32;
         let res = unsafe {
             self.fpAcquireNextImage.unwrap()(self.device, self.swapchain.unwrap(),
                                              std::u64::MAX,
                                              present_complete_semaphore,
-                                             null_mut(), &mut current_buffer)
+                                             vk::Fence::null(), &mut current_buffer)
         };
-        if res == vk::Result::SUCCESS {
-            Ok(current_buffer)
-        } else {
-            Err(res)
-        }
+        res.result_with(current_buffer)
     }
 
     pub fn queue_present(&self, queue: vk::Queue, current_buffer: u32,
                          wait_semaphore: vk::Semaphore) -> vk::Result {
         let present_info = vk::khr::swapchain::PresentInfo {
-            sType: vk::StructureType::PRESENT_INFO,
-            pNext: null(),
             swapchainCount: 1,
             pSwapchains: &self.swapchain.unwrap(),
             pImageIndices: &current_buffer,
             pWaitSemaphores: &wait_semaphore,
-            waitSemaphoreCount: if wait_semaphore == null_mut() { 0 } else { 1 },
+            waitSemaphoreCount: if wait_semaphore.is_null() { 0 } else { 1 },
             pResults: null_mut(),
+            ..Default::default()
         };
         unsafe { self.fpQueuePresent.unwrap()(queue, &present_info) }
     }
@@ -400,7 +361,7 @@ impl Drop for Swapchain {
         }
         unsafe {
             self.fpDestroySwapchain.unwrap()(self.device, self.swapchain.unwrap(), null());
-            vk::khr::surface::vkDestroySurface(self.instance, self.surface, null());
+            vk::khr::surface::vkDestroySurfaceKHR(self.instance, self.surface, null());
         }
     }
 }
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use vulkan_bind::vk;
-use std::ptr::{null, null_mut};
+use std::ptr::null;
 
 fn df<T: Default>() -> T {
     Default::default()
@@ -9,49 +9,43 @@ fn df<T: Default>() -> T {
 
 pub fn memoryAllocateInfo() -> vk::MemoryAllocateInfo {
     vk::MemoryAllocateInfo {
-        sType: vk::StructureType::MEMORY_ALLOCATE_INFO,
-        pNext: null(),
         allocationSize: 0,
         memoryTypeIndex: 0,
+        ..df()
     }
 }
 
 pub fn commandBufferAllocateInfo(commandPool: vk::CommandPool, level: vk::CommandBufferLevel, bufferCount: u32) -> vk::CommandBufferAllocateInfo {
     vk::CommandBufferAllocateInfo {
-        sType: vk::StructureType::COMMAND_BUFFER_ALLOCATE_INFO,
         commandPool: commandPool,
         level: level,
         commandBufferCount: bufferCount,
-        pNext: null(),
+        ..df()
     }
 }
 
 pub fn commandBufferBeginInfo() -> vk::CommandBufferBeginInfo {
     vk::CommandBufferBeginInfo {
-        sType: vk::StructureType::COMMAND_BUFFER_BEGIN_INFO,
-        pNext: null(),
         flags: df(),
         pInheritanceInfo: null(),
+        ..df()
     }
 }
 
 pub fn renderPassBeginInfo() -> vk::RenderPassBeginInfo {
     vk::RenderPassBeginInfo {
-        sType: vk::StructureType::RENDER_PASS_BEGIN_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
-        renderPass: null_mut(),
-        framebuffer: null_mut(),
+        renderPass: vk::RenderPass::null(),
+        framebuffer: vk::Framebuffer::null(),
         renderArea: df(),
         clearValueCount: 0,
         pClearValues: null(),
+        ..df()
     }
 }
 
 pub fn imageMemoryBarrier() -> vk::ImageMemoryBarrier {
     vk::ImageMemoryBarrier {
-        sType: vk::StructureType::IMAGE_MEMORY_BARRIER,
-        pNext: null(),
         // Some default values
         srcQueueFamilyIndex: vk::QUEUE_FAMILY_IGNORED,
         dstQueueFamilyIndex: vk::QUEUE_FAMILY_IGNORED,
@@ -60,40 +54,37 @@ pub fn imageMemoryBarrier() -> vk::ImageMemoryBarrier {
         dstAccessMask: df(),
         oldLayout: vk::ImageLayout::UNDEFINED,
         newLayout: vk::ImageLayout::UNDEFINED,
-        image: null_mut(),
+        image: vk::Image::null(),
         subresourceRange: df(),
+        ..df()
     }
 }
 
 pub fn bufferMemoryBarrier() -> vk::BufferMemoryBarrier {
     vk::BufferMemoryBarrier {
-        sType: vk::StructureType::BUFFER_MEMORY_BARRIER,
-        pNext: null(),
         // Fields that were omitted in C++:
         srcAccessMask: df(),
         dstAccessMask: df(),
         srcQueueFamilyIndex: 0,
         dstQueueFamilyIndex: 0,
-        buffer: null_mut(),
+        buffer: vk::Buffer::null(),
         offset: df(),
         size: df(),
+        ..df()
     }
 }
 
 pub fn memoryBarrier() -> vk::MemoryBarrier {
     vk::MemoryBarrier {
-        sType: vk::StructureType::MEMORY_BARRIER,
-        pNext: null(),
         // Fields that were omitted in C++:
         srcAccessMask: df(),
         dstAccessMask: df(),
+        ..df()
     }
 }
 
 pub fn imageCreateInfo() -> vk::ImageCreateInfo {
     vk::ImageCreateInfo {
-        sType: vk::StructureType::IMAGE_CREATE_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
         flags: df(),
         imageType: vk::ImageType::E_1D,
@@ -108,13 +99,12 @@ pub fn imageCreateInfo() -> vk::ImageCreateInfo {
         queueFamilyIndexCount: 0,
         pQueueFamilyIndices: null(),
         initialLayout: vk::ImageLayout::UNDEFINED,
+        ..df()
     }
 }
 
 pub fn samplerCreateInfo() -> vk::SamplerCreateInfo {
     vk::SamplerCreateInfo {
-        sType: vk::StructureType::SAMPLER_CREATE_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
         flags: df(),
         magFilter: vk::Filter::NEAREST,
@@ -124,58 +114,54 @@ pub fn samplerCreateInfo() -> vk::SamplerCreateInfo {
         addressModeV: vk::SamplerAddressMode::REPEAT,
         addressModeW: vk::SamplerAddressMode::REPEAT,
         mipLodBias: 0.0,
-        anisotropyEnable: vk::Bool32::False,
+        anisotropyEnable: vk::FALSE,
         maxAnisotropy: 0.0,
-        compareEnable: vk::Bool32::False,
+        compareEnable: vk::FALSE,
         compareOp: vk::CompareOp::NEVER,
         minLod: 0.0,
         maxLod: 0.0,
         borderColor: vk::BorderColor::FLOAT_TRANSPARENT_BLACK,
-        unnormalizedCoordinates: vk::Bool32::False,
+        unnormalizedCoordinates: vk::FALSE,
+        ..df()
     }
 }
 
 pub fn imageViewCreateInfo() -> vk::ImageViewCreateInfo {
     vk::ImageViewCreateInfo {
-        sType: vk::StructureType::IMAGE_VIEW_CREATE_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
         flags: df(),
-        image: null_mut(),
+        image: vk::Image::null(),
         viewType: vk::ImageViewType::E_1D,
         format: vk::Format::UNDEFINED,
         components: df(),
         subresourceRange: df(),
+        ..df()
     }
 }
 
 pub fn framebufferCreateInfo() -> vk::FramebufferCreateInfo {
     vk::FramebufferCreateInfo {
-        sType: vk::StructureType::FRAMEBUFFER_CREATE_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
         flags: df(),
-        renderPass: null_mut(),
+        renderPass: vk::RenderPass::null(),
         attachmentCount: 0,
         pAttachments: null(),
         width: 0,
         height: 0,
         layers: 0,
+        ..df()
     }
 }
 
 pub fn semaphoreCreateInfo(flags: vk::SemaphoreCreateFlags) -> vk::SemaphoreCreateInfo {
     vk::SemaphoreCreateInfo {
-        sType: vk::StructureType::SEMAPHORE_CREATE_INFO,
-        pNext: null(),
         flags: flags,
+        ..df()
     }
 }
 
 pub fn submitInfo() -> vk::SubmitInfo {
     vk::SubmitInfo {
-        sType: vk::StructureType::SUBMIT_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
         waitSemaphoreCount: 0,
         pWaitSemaphores: null(),
@@ -184,6 +170,7 @@ pub fn submitInfo() -> vk::SubmitInfo {
         pCommandBuffers: null(),
         signalSemaphoreCount: 0,
         pSignalSemaphores: null(),
+        ..df()
     }
 }
 
@@ -213,8 +200,6 @@ pub fn rect2D(width: u32,
 
 pub fn bufferCreateInfo(usage: vk::BufferUsageFlags, size: vk::DeviceSize) -> vk::BufferCreateInfo {
     vk::BufferCreateInfo {
-        sType: vk::StructureType::BUFFER_CREATE_INFO,
-        pNext: null(),
         usage: usage,
         size: size,
         flags: df(),
@@ -222,19 +207,19 @@ pub fn bufferCreateInfo(usage: vk::BufferUsageFlags, size: vk::DeviceSize) -> vk
         sharingMode: vk::SharingMode::EXCLUSIVE,
         queueFamilyIndexCount: 0,
         pQueueFamilyIndices: null(),
+        ..df()
     }
 }
 
 pub fn descriptorPoolCreateInfo(poolSizes: &[vk::DescriptorPoolSize],
                             maxSets: u32) -> vk::DescriptorPoolCreateInfo {
     vk::DescriptorPoolCreateInfo {
-        sType: vk::StructureType::DESCRIPTOR_POOL_CREATE_INFO,
-        pNext: null(),
         poolSizeCount: poolSizes.len() as u32,
         pPoolSizes: poolSizes.as_ptr(),
         maxSets: maxSets,
         // Fields that were omitted in C++:
         flags: df(),
+        ..df()
     }
 }
 
@@ -262,25 +247,23 @@ pub fn descriptorSetLayoutBinding(typ: vk::DescriptorType,
 pub fn descriptorSetLayoutCreateInfo(bindings: &[vk::DescriptorSetLayoutBinding]
                                 ) -> vk::DescriptorSetLayoutCreateInfo {
     vk::DescriptorSetLayoutCreateInfo {
-        sType: vk::StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
-        pNext: null(),
         pBindings: bindings.as_ptr(),
         bindingCount: bindings.len() as u32,
         // Fields that were omitted in C++:
         flags: df(),
+        ..df()
     }
 }
 
 pub fn pipelineLayoutCreateInfo(setLayouts: &[vk::DescriptorSetLayout]) -> vk::PipelineLayoutCreateInfo {
     vk::PipelineLayoutCreateInfo {
-        sType: vk::StructureType::PIPELINE_LAYOUT_CREATE_INFO,
-        pNext: null(),
         setLayoutCount: setLayouts.len() as u32,
         pSetLayouts: setLayouts.as_ptr(),
         // Fields that were omitted in C++:
         flags: df(),
         pushConstantRangeCount: 0,
         pPushConstantRanges: null(),
+        ..df()
     }
 }
 
@@ -288,11 +271,10 @@ pub fn descriptorSetAllocateInfo(descriptorPool: vk::DescriptorPool,
                              setLayouts: &[vk::DescriptorSetLayout],
                              descriptorSetCount: u32) -> vk::DescriptorSetAllocateInfo {
     vk::DescriptorSetAllocateInfo {
-        sType: vk::StructureType::DESCRIPTOR_SET_ALLOCATE_INFO,
-        pNext: null(),
         descriptorPool: descriptorPool,
         pSetLayouts: setLayouts.as_ptr(),
         descriptorSetCount: descriptorSetCount,
+        ..df()
     }
 }
 
@@ -309,8 +291,6 @@ pub fn writeDescriptorSetBuffer(dstSet: vk::DescriptorSet,
                             binding: u32,
                             bufferInfo: &vk::DescriptorBufferInfo) -> vk::WriteDescriptorSet {
     vk::WriteDescriptorSet {
-        sType: vk::StructureType::WRITE_DESCRIPTOR_SET,
-        pNext: null(),
         dstSet: dstSet,
         descriptorType: typ,
         dstBinding: binding,
@@ -321,6 +301,7 @@ pub fn writeDescriptorSetBuffer(dstSet: vk::DescriptorSet,
         dstArrayElement: 0,
         pImageInfo: null(),
         pTexelBufferView: null(),
+        ..df()
     }
 }
 
@@ -329,8 +310,6 @@ pub fn writeDescriptorSetImage(dstSet: vk::DescriptorSet,
                            binding: u32,
                            imageInfo: &vk::DescriptorImageInfo) -> vk::WriteDescriptorSet {
     vk::WriteDescriptorSet {
-        sType: vk::StructureType::WRITE_DESCRIPTOR_SET,
-        pNext: null(),
         dstSet: dstSet,
         descriptorType: typ,
         dstBinding: binding,
@@ -341,6 +320,7 @@ pub fn writeDescriptorSetImage(dstSet: vk::DescriptorSet,
         dstArrayElement: 0,
         pBufferInfo: null(),
         pTexelBufferView: null(),
+        ..df()
     }
 }
 
@@ -367,14 +347,13 @@ pub fn vertexInputAttributeDescription(binding: u32,
 
 pub fn pipelineVertexInputStateCreateInfo() -> vk::PipelineVertexInputStateCreateInfo {
     vk::PipelineVertexInputStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
-        pNext: null(),
         // Fields that were omitted in C++:
         flags: df(),
         vertexBindingDescriptionCount: 0,
         pVertexBindingDescriptions: null(),
         vertexAttributeDescriptionCount: 0,
         pVertexAttributeDescriptions: null(),
+        ..df()
     }
 }
 
@@ -383,11 +362,10 @@ pub fn pipelineInputAssemblyStateCreateInfo(topology: vk::PrimitiveTopology,
                                         primitiveRestartEnable: vk::Bool32,
                                         ) -> vk::PipelineInputAssemblyStateCreateInfo {
     vk::PipelineInputAssemblyStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
         topology: topology,
         flags: flags,
         primitiveRestartEnable: primitiveRestartEnable,
-        pNext: null(),
+        ..df()
     }
 }
 
@@ -397,20 +375,19 @@ pub fn pipelineRasterizationStateCreateInfo(polygonMode: vk::PolygonMode,
                                         flags: vk::PipelineRasterizationStateCreateFlags,
                                         ) -> vk::PipelineRasterizationStateCreateInfo {
     vk::PipelineRasterizationStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
         polygonMode: polygonMode,
         cullMode: cullMode,
         frontFace: frontFace,
         flags: flags,
-        depthClampEnable: vk::Bool32::True,
+        depthClampEnable: vk::TRUE,
         // Fields that were omitted in C++:
-        pNext: null(),
-        rasterizerDiscardEnable: vk::Bool32::False,
-        depthBiasEnable: vk::Bool32::False,
+        rasterizerDiscardEnable: vk::FALSE,
+        depthBiasEnable: vk::FALSE,
         depthBiasConstantFactor: 0.0,
         depthBiasClamp: 0.0,
         depthBiasSlopeFactor: 0.0,
         lineWidth: 0.0,
+        ..df()
     }
 }
 
@@ -432,15 +409,14 @@ pub fn pipelineColorBlendAttachmentState(colorWriteMask: vk::ColorComponentFlags
 
 pub fn pipelineColorBlendStateCreateInfo(attachments: &[vk::PipelineColorBlendAttachmentState]) -> vk::PipelineColorBlendStateCreateInfo {
     vk::PipelineColorBlendStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
-        pNext: null(),
         attachmentCount: attachments.len() as u32,
         pAttachments: attachments.as_ptr(),
         // Fields that were omitted in C++:
         flags: df(),
-        logicOpEnable: vk::Bool32::False,
+        logicOpEnable: vk::FALSE,
         logicOp: vk::LogicOp::CLEAR,
         blendConstants: [0.0; 4],
+        ..df()
     }
 }
 
@@ -448,19 +424,18 @@ pub fn pipelineDepthStencilStateCreateInfo(depthTestEnable: vk::Bool32, depthWri
                                        depthCompareOp: vk::CompareOp,
                                        ) -> vk::PipelineDepthStencilStateCreateInfo {
     vk::PipelineDepthStencilStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
         depthTestEnable: depthTestEnable,
         depthWriteEnable: depthWriteEnable,
         depthCompareOp: depthCompareOp,
         front: df(),
         back: vk::StencilOpState { compareOp: vk::CompareOp::ALWAYS, ..df() },
         // Fields that were omitted in C++:
-        pNext: null(),
         flags: df(),
-        depthBoundsTestEnable: vk::Bool32::False,
-        stencilTestEnable: vk::Bool32::False,
+        depthBoundsTestEnable: vk::FALSE,
+        stencilTestEnable: vk::FALSE,
         minDepthBounds: 0.0,
         maxDepthBounds: 0.0,
+        ..df()
     }
 }
 
@@ -468,14 +443,13 @@ pub fn pipelineViewportStateCreateInfo(viewportCount: u32, scissorCount: u32,
                                    flags: vk::PipelineViewportStateCreateFlags,
                                    ) -> vk::PipelineViewportStateCreateInfo {
     vk::PipelineViewportStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_VIEWPORT_STATE_CREATE_INFO,
         viewportCount: viewportCount,
         scissorCount: scissorCount,
         flags: flags,
         // Fields that were omitted in C++:
-        pNext: null(),
         pViewports: null(),
         pScissors: null(),
+        ..df()
     }
 }
 
@@ -483,16 +457,15 @@ pub fn pipelineMultisampleStateCreateInfo(rasterizationSamples: vk::SampleCountF
                                       flags: vk::PipelineMultisampleStateCreateFlags,
                                       ) -> vk::PipelineMultisampleStateCreateInfo {
     vk::PipelineMultisampleStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
         rasterizationSamples: rasterizationSamples.into(),
         // Fields that were omitted in C++:
-        pNext: null(),
         flags: flags,
-        sampleShadingEnable: vk::Bool32::False,
+        sampleShadingEnable: vk::FALSE,
         minSampleShading: 0.0,
         pSampleMask: null(),
-        alphaToCoverageEnable: vk::Bool32::False,
-        alphaToOneEnable: vk::Bool32::False,
+        alphaToCoverageEnable: vk::FALSE,
+        alphaToOneEnable: vk::FALSE,
+        ..df()
     }
 }
 
@@ -500,22 +473,20 @@ pub fn pipelineDynamicStateCreateInfo(dynamicStates: &[vk::DynamicState],
                                   flags: vk::PipelineDynamicStateCreateFlags,
                                   ) -> vk::PipelineDynamicStateCreateInfo {
     vk::PipelineDynamicStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_DYNAMIC_STATE_CREATE_INFO,
         pDynamicStates: dynamicStates.as_ptr(),
         dynamicStateCount: dynamicStates.len() as u32,
         // Fields that were omitted in C++:
-        pNext: null(),
         flags: flags,
+        ..df()
     }
 }
 
 pub fn pipelineTessellationStateCreateInfo(patchControlPoints: u32) -> vk::PipelineTessellationStateCreateInfo {
     vk::PipelineTessellationStateCreateInfo {
-        sType: vk::StructureType::PIPELINE_TESSELLATION_STATE_CREATE_INFO,
         patchControlPoints: patchControlPoints,
         // Fields that were omitted in C++:
-        pNext: null(),
         flags: df(),
+        ..df()
     }
 }
 
@@ -523,8 +494,6 @@ pub fn pipelineCreateInfo(layout: vk::PipelineLayout,
                       renderPass: vk::RenderPass,
                       flags: vk::PipelineCreateFlags) -> vk::GraphicsPipelineCreateInfo {
     vk::GraphicsPipelineCreateInfo {
-        sType: vk::StructureType::GRAPHICS_PIPELINE_CREATE_INFO,
-        pNext: null(),
         layout: layout,
         renderPass: renderPass,
         flags: flags,
@@ -541,29 +510,28 @@ pub fn pipelineCreateInfo(layout: vk::PipelineLayout,
         pColorBlendState: null(),
         pDynamicState: null(),
         subpass: 0,
-        basePipelineHandle: null_mut(),
+        basePipelineHandle: vk::Pipeline::null(),
         basePipelineIndex: 0,
+        ..df()
     }
 }
 
 pub fn computePipelineCreateInfo(layout: vk::PipelineLayout, flags: vk::PipelineCreateFlags) -> vk::ComputePipelineCreateInfo {
     vk::ComputePipelineCreateInfo {
-        sType: vk::StructureType::COMPUTE_PIPELINE_CREATE_INFO,
         layout: layout,
         flags: flags,
         // Fields that were omitted in C++:
-        pNext: null(),
         stage: vk::PipelineShaderStageCreateInfo {
-            sType: vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
-            pNext: null(),
             flags: df(),
             stage: df(),
-            module: null_mut(),
+            module: vk::ShaderModule::null(),
             pName: null(),
             pSpecializationInfo: null(),
+            ..df()
         },
-        basePipelineHandle: null_mut(),
+        basePipelineHandle: vk::Pipeline::null(),
         basePipelineIndex: 0,
+        ..df()
     }
 }
 
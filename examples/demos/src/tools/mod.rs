@@ -6,14 +6,10 @@ pub mod initializers;
 
 use std;
 use std::io::{self, Read};
-use std::ptr::{null, null_mut};
+use std::ptr::null;
 
 use vulkan_bind::vk;
 
-fn cstr(s: &[i8; 256]) -> &str {
-    unsafe { std::str::from_utf8_unchecked(std::mem::transmute::<_, &[u8; 256]>(s)) }
-}
-
 fn u32_bytes(v: u32) -> [u8; 4] {
     unsafe { std::mem::transmute(v) }
 }
@@ -23,36 +19,14 @@ pub fn vkassert(result: vk::Result) {
 }
 
 pub fn check_global_extension_present(extension_name: &str) -> bool {
-    let mut extension_count = 0u32;
-    unsafe {
-        vk::vkEnumerateInstanceExtensionProperties(null(), &mut extension_count, null_mut());
-    }
-
-    let mut extensions: Vec<vk::ExtensionProperties> = Vec::with_capacity(extension_count as usize);
-    unsafe {
-        vk::vkEnumerateInstanceExtensionProperties(null(), &mut extension_count, extensions.as_mut_ptr());
-        extensions.set_len(extension_count as usize);
-    }
-
-    extensions.into_iter().any(|ext| extension_name == cstr(&ext.extensionName))
+    let extensions = unsafe { vk::enumerate_instance_extension_properties(None) };
+    extensions.map_or(false, |e| e.iter().any(|ext| ext.is(extension_name)))
 }
 
 pub fn check_device_extension_present(physical_device: vk::PhysicalDevice,
                                       extension_name: &str) -> bool {
-    let mut extension_count = 0u32;
-    unsafe {
-        vk::vkEnumerateDeviceExtensionProperties(physical_device, null(),
-                                                 &mut extension_count, null_mut());
-    }
-
-    let mut extensions = Vec::with_capacity(extension_count as usize);
-    unsafe {
-        vk::vkEnumerateDeviceExtensionProperties(physical_device, null(),
-                                                 &mut extension_count, extensions.as_mut_ptr());
-        extensions.set_len(extension_count as usize);
-    }
-
-    extensions.into_iter().any(|ext| extension_name == cstr(&ext.extensionName))
+    let extensions = unsafe { vk::enumerate_device_extension_properties(physical_device, None) };
+    extensions.map_or(false, |e| e.iter().any(|ext| ext.is(extension_name)))
 }
 
 pub fn get_supported_depth_format(physical_device: vk::PhysicalDevice) -> Option<vk::Format> {
@@ -192,11 +166,10 @@ pub fn load_shader(filename: &str, device: vk::Device,
     let shader_code = try!(read_binary_file(filename));
 
     let module_create_info = vk::ShaderModuleCreateInfo {
-        sType: vk::StructureType::SHADER_MODULE_CREATE_INFO,
-        pNext: null(),
         codeSize: shader_code.len(),
         pCode: unsafe { std::mem::transmute(shader_code.as_ptr()) },
         flags: Default::default(),
+        ..Default::default()
     };
 
     unsafe {
@@ -219,11 +192,10 @@ pub fn load_shader_glsl(filename: &str, device: vk::Device,
     assert_eq!(shader_data.len(), 12 + shader_code.len() + 1);
 
     let module_create_info = vk::ShaderModuleCreateInfo {
-        sType: vk::StructureType::SHADER_MODULE_CREATE_INFO,
-        pNext: null(),
         codeSize: 3 * std::mem::size_of::<u32>() + shader_code.len() + 1,
         pCode: unsafe { std::mem::transmute(shader_code.as_ptr()) },
         flags: Default::default(),
+        ..Default::default()
     };
 
     unsafe {
@@ -1,31 +1,25 @@
 use vulkan_bind::vk;
 
 pub fn vkrq(res: vk::Result, msg: &str) {
-    if res != vk::Result::SUCCESS {
-        println!("fatal: {}: {:?}", msg, res);
-        ::std::process::exit(1);
-    }
+    vkrqr(res.result(), msg);
 }
 
-pub fn vkrqr<T>(res: Result<T, vk::Result>, msg: &str) {
-    if let Err(e) = res {
-        vkrq(e, msg);
+pub fn vkrqr<T>(res: Result<T, vk::Error>, msg: &str) -> T {
+    match res {
+        Ok(value) => value,
+        Err(e) => {
+            println!("fatal: {}: {}", msg, e);
+            ::std::process::exit(1);
+        }
     }
 }
 
-pub fn vkwrap(res: vk::Result) -> Result<(), vk::Result> {
-    if res == vk::Result::SUCCESS { Ok(()) } else { Err(res) }
-}
-
 pub fn vksuccess(res: vk::Result) {
-    assert_eq!(vk::Result::SUCCESS, res);
-}
-
-#[macro_export]
-macro_rules! vktry {
-    ( $res:expr ) => { try!(::util::vkwrap($res)) }
+    if let Err(e) = res.result() {
+        panic!("{}", e);
+    }
 }
 
-pub fn cstr(s: &str) -> *const i8 {
-    ::std::ffi::CString::new(s).unwrap().as_ptr()
+pub fn cstr(s: &str) -> ::std::ffi::CString {
+    ::std::ffi::CString::new(s).unwrap()
 }
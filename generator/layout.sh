@@ -0,0 +1,26 @@
+#!/bin/sh
+# Measures every struct in tests/layout.c with the C compiler and writes the
+# expectations in tests/layout.rs. Needs git and a gcc which can compile for
+# i386 (-m32); only assembly is produced, and -ffreestanding takes stdint.h
+# from the compiler, so no 32-bit libc is needed.
+#
+# 32-bit targets other than x86 outside Windows align 64-bit fields to 8
+# bytes, which -malign-double gives i386 as well.
+set -e
+
+tag=v1.3.204
+dumps=target/layout
+headers=$dumps/Vulkan-Headers-$tag
+
+mkdir -p $dumps
+if [ ! -d $headers ]; then
+    git clone --quiet --depth 1 --branch $tag \
+        https://github.com/KhronosGroup/Vulkan-Headers $headers
+fi
+
+cc="${CC:-gcc} -S -std=c11 -ffreestanding -I $headers/include"
+$cc -m64 tests/layout.c -o $dumps/64.s
+$cc -m32 tests/layout.c -o $dumps/x86.s
+$cc -m32 -malign-double tests/layout.c -o $dumps/32.s
+
+cargo run -p vulkan-bind-generator -- --layout $dumps tests
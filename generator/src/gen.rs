@@ -0,0 +1,2027 @@
+// Assignment of registry items to modules, and emission of the Rust sources
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use registry::*;
+
+/// Vendors whose extensions get a module under `vk::`.
+const VENDORS: &[&str] = &["KHR", "EXT"];
+
+/// The extensions which get a module. Any others in the registry are left
+/// out, so that regenerating from a newer vk.xml doesn't add modules.
+const EXTENSIONS: &[&str] = &[
+    "VK_KHR_surface",
+    "VK_KHR_swapchain",
+    "VK_KHR_display",
+    "VK_KHR_display_swapchain",
+    "VK_EXT_debug_report",
+];
+
+/// Names chosen by hand where the mechanical ones read badly.
+const TYPE_NAMES: &[(&str, &str)] = &[
+    ("VkDebugReportCallbackCreateInfoEXT", "CreateInfo"),
+];
+const VALUE_NAMES: &[(&str, &str)] = &[
+    ("VK_PIPELINE_CACHE_HEADER_VERSION_ONE", "PIPELINE_CACHE_HEADER_VERSION_ONE"),
+];
+
+/// Structs which also derive `Debug`, as the hand-written bindings did.
+const DEBUG_STRUCTS: &[&str] = &["VkExtent2D", "VkSurfaceCapabilitiesKHR"];
+
+/// Enums given a `Default` through `enum_defl!`.
+const ENUM_DEFAULTS: &[(&str, &str)] = &[
+    ("VkComponentSwizzle", "IDENTITY"),
+    ("VkCompareOp", "NEVER"),
+    ("VkStencilOp", "KEEP"),
+];
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "box", "break", "const", "continue", "crate", "do", "else", "enum",
+    "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self",
+    "static", "struct", "super", "trait", "true", "type", "typeof", "unsafe", "unsized",
+    "use", "virtual", "where", "while", "yield",
+];
+
+fn primitive(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "void" => "::std::os::raw::c_void",
+        "char" => "::std::os::raw::c_char",
+        "int" => "::std::os::raw::c_int",
+        "float" => "f32",
+        "double" => "f64",
+        "int8_t" => "i8",
+        "uint8_t" => "u8",
+        "int16_t" => "i16",
+        "uint16_t" => "u16",
+        "int32_t" => "i32",
+        "uint32_t" => "u32",
+        "int64_t" => "i64",
+        "uint64_t" => "u64",
+        "size_t" => "usize",
+        "VkFlags" => "u32",
+        "VkFlags64" => "u64",
+        _ => return None,
+    })
+}
+
+/// The C ABIs `tests/layout.rs` has expectations for, by the `cfg` which
+/// selects each, in the order `generator/layout.sh` measures them.
+struct Abi {
+    cfg: &'static str,
+    pointer: u64,
+    /// Alignment of 64-bit integers and doubles inside structs.
+    align64: u64,
+}
+
+const ABIS: &[Abi] = &[
+    Abi { cfg: "target_pointer_width = \"64\"", pointer: 8, align64: 8 },
+    Abi { cfg: "all(target_arch = \"x86\", not(windows))", pointer: 4, align64: 4 },
+    Abi {
+        cfg: "all(target_pointer_width = \"32\", any(not(target_arch = \"x86\"), windows))",
+        pointer: 4,
+        align64: 8,
+    },
+];
+
+/// Size and alignment of a primitive.
+fn primitive_layout(name: &str, abi: &Abi) -> (u64, u64) {
+    match name {
+        "int8_t" | "uint8_t" | "char" => (1, 1),
+        "int16_t" | "uint16_t" => (2, 2),
+        "int64_t" | "uint64_t" | "double" | "VkFlags64" => (8, abi.align64),
+        "size_t" => (abi.pointer, abi.pointer),
+        _ => (4, 4),
+    }
+}
+
+pub fn escape(name: &str) -> String {
+    if KEYWORDS.contains(&name) { format!("_{}", name) } else { name.to_owned() }
+}
+
+pub fn camel_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for i in 0..chars.len() {
+        let c = chars[i];
+        let boundary = i > 0 && c.is_uppercase() &&
+            (chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit() ||
+             (chars[i - 1].is_uppercase() && i + 1 < chars.len() && chars[i + 1].is_lowercase()));
+        if boundary && !word.is_empty() {
+            words.push(word.to_uppercase());
+            word.clear();
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word.to_uppercase());
+    }
+    words
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModuleId {
+    Core,
+    Ext(usize),
+}
+
+pub struct ExtModule<'a> {
+    pub ext: &'a Extension,
+    /// `khr`, `ext`.
+    pub vendor: String,
+    /// File and module name, `surface`.
+    pub name: String,
+    /// Prefix stripped from type names, `Surface`.
+    prefix: String,
+    types: Vec<String>,
+    commands: Vec<String>,
+    enum_values: Vec<(String, EnumValue)>,
+    constants: Vec<(String, String)>,
+    locals: HashSet<String>,
+    /// Whether `module_rs` emitted `ext_enums!`, and so `ENUM_NAMES`.
+    named: bool,
+}
+
+pub struct Generator<'a> {
+    reg: &'a Registry,
+    tags: Vec<String>,
+    owner: HashMap<String, ModuleId>,
+    core_types: Vec<String>,
+    core_commands: Vec<String>,
+    /// Commands added by each core version, in the order of `reg.features`.
+    feature_commands: Vec<Vec<String>>,
+    core_values: Vec<(String, EnumValue)>,
+    core_locals: HashSet<String>,
+    pub modules: Vec<ExtModule<'a>>,
+    /// Extensions left out, with the reason.
+    pub skipped: Vec<(String, String)>,
+    /// Names of the variants of each enum emitted so far, with their values.
+    variants: HashMap<String, HashMap<String, i64>>,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(reg: &'a Registry) -> Result<Generator<'a>, String> {
+        let mut gen = Generator {
+            reg,
+            tags: reg.tags.clone(),
+            owner: HashMap::new(),
+            core_types: Vec::new(),
+            core_commands: Vec::new(),
+            feature_commands: Vec::new(),
+            core_values: Vec::new(),
+            core_locals: HashSet::new(),
+            modules: Vec::new(),
+            skipped: Vec::new(),
+            variants: HashMap::new(),
+        };
+
+        // Everything required by a core version belongs to the core modules.
+        for feature in &reg.features {
+            let mut commands = Vec::new();
+            for req in &feature.requires {
+                let mut roots = req.types.clone();
+                for cmd in &req.commands {
+                    if !gen.core_commands.contains(cmd) {
+                        gen.core_commands.push(cmd.clone());
+                        commands.push(cmd.clone());
+                    }
+                    roots.extend(gen.command_deps(cmd)?);
+                }
+                let new = gen.closure(&roots).map_err(|e| format!("{}: {}", feature.name, e))?;
+                for ty in new {
+                    gen.owner.insert(ty.clone(), ModuleId::Core);
+                    gen.core_types.push(ty);
+                }
+                gen.core_values.extend(req.enum_values.iter().cloned());
+            }
+            gen.feature_commands.push(commands);
+        }
+        gen.sort_types(None);
+        for ty in &gen.core_types {
+            gen.core_locals.insert(gen.rust_name(ty));
+        }
+
+        for ext in reg.extensions.iter().filter(|e| EXTENSIONS.contains(&&e.name[..])) {
+            let (vendor, name) = match module_name(&ext.name) {
+                Some(names) => names,
+                None => continue,
+            };
+            let rest = ext.name.splitn(3, '_').nth(2).unwrap_or("");
+            if let Some(ref platform) = ext.platform {
+                gen.skipped.push((ext.name.clone(), format!("needs the `{}` platform headers", platform)));
+                continue;
+            }
+            let mut roots = Vec::new();
+            let mut commands = Vec::new();
+            let mut enum_values = Vec::new();
+            let mut constants = Vec::new();
+            let mut ok = Ok(());
+            for req in &ext.requires {
+                enum_values.extend(req.enum_values.iter().cloned());
+                constants.extend(req.constants.iter().cloned());
+                if req.conditional {
+                    continue;
+                }
+                roots.extend(req.types.iter().cloned());
+                for cmd in &req.commands {
+                    commands.push(cmd.clone());
+                    match gen.command_deps(cmd) {
+                        Ok(deps) => roots.extend(deps),
+                        Err(e) => ok = Err(e),
+                    }
+                }
+            }
+            let types = match ok.and_then(|_| gen.closure(&roots)) {
+                Ok(types) => types,
+                Err(e) => {
+                    gen.skipped.push((ext.name.clone(), e));
+                    continue;
+                }
+            };
+            let id = ModuleId::Ext(gen.modules.len());
+            for ty in &types {
+                gen.owner.insert(ty.clone(), id);
+            }
+            let prefix = rest.split('_').map(|w| {
+                let mut cs = w.chars();
+                match cs.next() {
+                    Some(c) => c.to_uppercase().chain(cs).collect::<String>(),
+                    None => String::new(),
+                }
+            }).collect();
+            gen.modules.push(ExtModule {
+                ext,
+                vendor,
+                name,
+                prefix,
+                types,
+                commands,
+                enum_values,
+                constants,
+                locals: HashSet::new(),
+                named: false,
+            });
+            let index = gen.modules.len() - 1;
+            gen.sort_types(Some(index));
+            let locals = gen.modules[index].types.iter().map(|t| gen.rust_name(t)).collect();
+            gen.modules[index].locals = locals;
+        }
+        Ok(gen)
+    }
+
+    fn sort_types(&mut self, module: Option<usize>) {
+        let reg = self.reg;
+        let types = match module {
+            None => &mut self.core_types,
+            Some(i) => &mut self.modules[i].types,
+        };
+        types.sort_by_key(|t| reg.type_index[t]);
+    }
+
+    fn command_deps(&self, name: &str) -> Result<Vec<String>, String> {
+        let cmd = self.reg.signature(name).ok_or_else(|| format!("unknown command {}", name))?;
+        let mut deps = vec![cmd.ret.name.clone()];
+        deps.extend(cmd.params.iter().map(|p| p.ty.name.clone()));
+        Ok(deps)
+    }
+
+    fn type_deps(&self, ty: &Type) -> Vec<String> {
+        match ty.kind {
+            TypeKind::Struct { ref members, .. } => members.iter().map(|m| m.ty.name.clone()).collect(),
+            TypeKind::FuncPointer { ref ret, ref params } => {
+                let mut deps = vec![ret.name.clone()];
+                deps.extend(params.iter().map(|p| p.ty.name.clone()));
+                deps
+            }
+            TypeKind::Bitmask { bits: Some(ref bits), .. } => vec![bits.clone()],
+            TypeKind::Alias(ref target) => vec![target.clone()],
+            TypeKind::BaseType(Some(ref target)) => vec![target.name.clone()],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Types reachable from `roots` which no module owns yet.
+    fn closure(&self, roots: &[String]) -> Result<Vec<String>, String> {
+        let mut new = Vec::new();
+        let mut seen = HashSet::new();
+        let mut stack: Vec<String> = roots.iter().rev().cloned().collect();
+        while let Some(name) = stack.pop() {
+            if primitive(&name).is_some() || self.owner.contains_key(&name) || !seen.insert(name.clone()) {
+                continue;
+            }
+            let ty = match self.reg.ty(&name) {
+                Some(ty) => ty,
+                None => return Err(format!("unknown type {}", name)),
+            };
+            match ty.kind {
+                TypeKind::External => return Err(format!("{} comes from an external header", name)),
+                TypeKind::Other => continue,
+                _ => { }
+            }
+            new.push(name.clone());
+            stack.extend(self.type_deps(ty));
+        }
+        Ok(new)
+    }
+
+    // Naming
+
+    fn tag_of<'b>(&self, name: &'b str) -> Option<&'b str> {
+        self.tags.iter().find(|t| name.ends_with(&t[..])).map(|t| &name[name.len() - t.len()..])
+    }
+
+    /// Unqualified Rust name of the registry type `c_name`.
+    pub fn rust_name(&self, c_name: &str) -> String {
+        if let Some(&(_, name)) = TYPE_NAMES.iter().find(|&&(c, _)| c == c_name) {
+            return name.to_owned();
+        }
+        if c_name.starts_with("PFN_") {
+            return c_name.to_owned();
+        }
+        let mut name = c_name.trim_start_matches("Vk").to_owned();
+        if let Some(ModuleId::Ext(i)) = self.owner.get(c_name).cloned() {
+            if let Some(tag) = self.tag_of(&name).map(|t| t.to_owned()) {
+                let len = name.len() - tag.len();
+                name.truncate(len);
+            }
+            let prefix = &self.modules[i].prefix;
+            if name.starts_with(&prefix[..]) &&
+               name[prefix.len()..].starts_with(|c: char| c.is_uppercase()) {
+                name = name[prefix.len()..].to_owned();
+            }
+        }
+        name.replace("FlagBits", "Flag")
+    }
+
+    /// Rust name of the flag (single bit) type paired with the bitmask `c_name`.
+    fn flag_name(&self, c_name: &str) -> String {
+        match self.reg.ty(c_name).map(|t| &t.kind) {
+            Some(&TypeKind::Bitmask { bits: Some(ref bits), .. }) => self.rust_name(bits),
+            _ => {
+                let flags = self.rust_name(c_name);
+                match flags.rfind("Flags") {
+                    Some(i) => format!("{}Flag{}", &flags[..i], &flags[i + 5..]),
+                    None => format!("{}Flag", flags),
+                }
+            }
+        }
+    }
+
+    fn locals(&self, module: ModuleId) -> &HashSet<String> {
+        match module {
+            ModuleId::Core => &self.core_locals,
+            ModuleId::Ext(i) => &self.modules[i].locals,
+        }
+    }
+
+    fn module_path(&self, module: ModuleId) -> String {
+        match module {
+            ModuleId::Core => "::vk".to_owned(),
+            ModuleId::Ext(i) => format!("{}::{}", self.modules[i].vendor, self.modules[i].name),
+        }
+    }
+
+    /// How code in module `from` refers to the registry type `c_name`.
+    fn type_ref(&self, c_name: &str, from: ModuleId) -> String {
+        if let Some(p) = primitive(c_name) {
+            return p.to_owned();
+        }
+        let owner = self.owner.get(c_name).cloned().unwrap_or(ModuleId::Core);
+        let name = self.rust_name(c_name);
+        if owner == from {
+            name
+        } else if owner == ModuleId::Core {
+            if self.locals(from).contains(&name) { format!("::vk::{}", name) } else { name }
+        } else {
+            format!("{}::{}", self.module_path(owner), name)
+        }
+    }
+
+    fn array_len(&self, dim: &str) -> String {
+        let value = self.reg.constants.iter()
+            .find(|c| c.0 == dim)
+            .map(|c| c.2.clone())
+            .unwrap_or_else(|| dim.to_owned());
+        format!("{}usize", value)
+    }
+
+    /// Rust spelling of a struct member's type.
+    fn member_type(&self, ty: &CType, from: ModuleId) -> String {
+        let mut out = self.type_ref(&ty.name, from);
+        for &pointee_const in &ty.pointers {
+            out = format!("*{} {}", if pointee_const { "const" } else { "mut" }, out);
+        }
+        for dim in ty.array.iter().rev() {
+            out = format!("[{}; {}]", out, self.array_len(dim));
+        }
+        out
+    }
+
+    /// Rust spelling of a parameter's type; arrays decay to pointers.
+    fn param_type(&self, ty: &CType, from: ModuleId) -> String {
+        let mut out = self.type_ref(&ty.name, from);
+        for &pointee_const in &ty.pointers {
+            out = format!("*{} {}", if pointee_const { "const" } else { "mut" }, out);
+        }
+        if !ty.array.is_empty() {
+            out = format!("*{} {}", if ty.is_const { "const" } else { "mut" }, out);
+        }
+        out
+    }
+
+    fn return_type(&self, ty: &CType, from: ModuleId) -> Option<String> {
+        if ty.name == "void" && ty.pointers.is_empty() {
+            None
+        } else {
+            Some(self.param_type(ty, from))
+        }
+    }
+
+    // Enum values
+
+    fn enum_prefix(&self, c_enum: &str) -> Vec<String> {
+        let mut name = c_enum.trim_start_matches("Vk");
+        if let Some(tag) = self.tag_of(name) {
+            name = &name[..name.len() - tag.len()];
+        }
+        let (base, suffix) = match name.find("FlagBits") {
+            Some(i) => (&name[..i], &name[i + 8..]),
+            None => (name, ""),
+        };
+        let mut words = vec!["VK".to_owned()];
+        words.extend(camel_words(base));
+        if !suffix.is_empty() {
+            words.push(suffix.to_uppercase());
+        }
+        words
+    }
+
+    /// Number of leading words stripped from the value names of `c_enum`.
+    fn enum_strip(&self, c_enum: &str) -> usize {
+        let prefix = self.enum_prefix(c_enum);
+        let mut strip = prefix.len();
+        if let Some(enums) = self.reg.enums(c_enum) {
+            for v in enums.values.iter().filter(|v| !is_alias(v)) {
+                strip = ::std::cmp::min(strip, shared_words(&prefix, &v.name));
+            }
+        }
+        strip
+    }
+
+    fn value_name(&self, c_enum: &str, bitmask: bool, c_value: &str) -> String {
+        if let Some(&(_, name)) = VALUE_NAMES.iter().find(|&&(c, _)| c == c_value) {
+            return name.to_owned();
+        }
+        let prefix = self.enum_prefix(c_enum);
+        let strip = ::std::cmp::min(self.enum_strip(c_enum), shared_words(&prefix, c_value));
+        let words: Vec<&str> = c_value.split('_').collect();
+        let mut rest = &words[::std::cmp::min(strip, words.len() - 1)..];
+        if rest.len() > 1 && self.tags.iter().any(|t| t == rest[rest.len() - 1]) {
+            rest = &rest[..rest.len() - 1];
+        }
+        if bitmask && rest.len() > 1 && rest[rest.len() - 1] == "BIT" {
+            rest = &rest[..rest.len() - 1];
+        }
+        let name = rest.join("_");
+        if name.starts_with(|c: char| c.is_ascii_digit()) { format!("E_{}", name) } else { name }
+    }
+
+    fn find_value(&self, c_enum: &str, c_value: &str) -> Option<Value> {
+        let block = self.reg.enums(c_enum).into_iter().flat_map(|e| e.values.iter());
+        let core = self.core_values.iter().filter(|v| v.0 == c_enum).map(|v| &v.1);
+        let ext = self.modules.iter().flat_map(|m| m.enum_values.iter())
+                      .filter(|v| v.0 == c_enum).map(|v| &v.1);
+        let found = block.chain(core).chain(ext).find(|v| v.name == c_value).map(|v| v.value.clone());
+        match found {
+            Some(Value::Alias(ref target)) => self.find_value(c_enum, target),
+            other => other,
+        }
+    }
+
+    /// Renders the values of `c_enum` which are new, recording their names.
+    fn render_values(&mut self, c_enum: &str, values: &[&EnumValue]) -> String {
+        let bitmask = self.reg.enums(c_enum).is_some_and(|e| e.bitmask);
+        let mut out = String::new();
+        for v in values {
+            let value = match v.value {
+                Value::Alias(ref target) => match self.find_value(c_enum, target) {
+                    Some(value) => value,
+                    None => continue,
+                },
+                ref value => value.clone(),
+            };
+            let (number, text) = match value {
+                Value::Int(n) if bitmask => (n, format!("0x{:08x}", n)),
+                Value::Int(n) | Value::Literal(n, _) if !bitmask => (n, n.to_string()),
+                Value::Bit(b) => (1i64 << b, format!("0x{:08x}", 1u64 << b)),
+                Value::Literal(n, ref s) => (n, s.clone()),
+                _ => continue,
+            };
+            let mut name = self.value_name(c_enum, bitmask, &v.name);
+            let used = self.variants.entry(c_enum.to_owned()).or_default();
+            match used.get(&name).cloned() {
+                Some(n) if n == number => continue,
+                Some(_) => {
+                    // Same name from two vendors; keep the vendor tag to tell them apart.
+                    match self.tags.iter().find(|t| v.name.ends_with(&t[..])) {
+                        Some(tag) => name = format!("{}_{}", name, tag),
+                        None => continue,
+                    }
+                    if used.contains_key(&name) {
+                        continue;
+                    }
+                }
+                None => { }
+            }
+            used.insert(name.clone(), number);
+            let _ = writeln!(out, "    {} = {},", name, text);
+        }
+        out
+    }
+
+    /// Values for `c_enum` declared in its own block and by the core versions.
+    fn own_values(&self, c_enum: &str) -> Vec<EnumValue> {
+        let mut values: Vec<EnumValue> = self.reg.enums(c_enum)
+            .map(|e| e.values.clone())
+            .unwrap_or_default();
+        values.extend(self.core_values.iter().filter(|v| v.0 == c_enum).map(|v| v.1.clone()));
+        values
+    }
+
+    // Layout
+
+    fn layout(&self, ty: &CType, abi: &Abi) -> (u64, u64) {
+        let (mut size, align) = if !ty.pointers.is_empty() {
+            (abi.pointer, abi.pointer)
+        } else {
+            self.base_layout(&ty.name, abi)
+        };
+        for dim in &ty.array {
+            size *= self.array_len(dim).trim_end_matches("usize").parse::<u64>().unwrap_or(1);
+        }
+        (size, align)
+    }
+
+    fn base_layout(&self, name: &str, abi: &Abi) -> (u64, u64) {
+        match self.reg.ty(name).map(|t| &t.kind) {
+            // Non-dispatchable handles are `uint64_t` everywhere.
+            Some(&TypeKind::Handle { dispatchable: false, .. }) => (8, abi.align64),
+            Some(&TypeKind::Handle { .. }) | Some(&TypeKind::FuncPointer { .. }) =>
+                (abi.pointer, abi.pointer),
+            Some(&TypeKind::Bitmask { wide, .. }) => if wide { (8, abi.align64) } else { (4, 4) },
+            Some(TypeKind::Alias(target)) => self.base_layout(target, abi),
+            Some(&TypeKind::BaseType(Some(ref target))) => self.layout(target, abi),
+            Some(&TypeKind::Struct { ref members, union, .. }) => {
+                let (_, size, align) = self.member_offsets(members, union, abi);
+                (size, align)
+            }
+            _ => primitive_layout(name, abi),
+        }
+    }
+
+    /// Offsets of the fields `emit_structs` writes for `members`, with the
+    /// size and alignment of the whole struct.
+    fn member_offsets(&self, members: &[Member], union: bool, abi: &Abi)
+        -> (Vec<(String, u64)>, u64, u64)
+    {
+        let mut fields = Vec::new();
+        let (mut size, mut align) = (0, 1);
+        let mut bitfield: Option<(Vec<String>, u32)> = None;
+        let mut place = |name: String, (s, a): (u64, u64), fields: &mut Vec<(String, u64)>| {
+            align = ::std::cmp::max(align, a);
+            if union {
+                fields.push((name, 0));
+                size = ::std::cmp::max(size, s);
+            } else {
+                let offset = size.div_ceil(a) * a;
+                fields.push((name, offset));
+                size = offset + s;
+            }
+        };
+        for m in members {
+            if let Some(bits) = m.ty.bits {
+                let (mut names, mut used) = bitfield.take().unwrap_or((Vec::new(), 0));
+                if used + bits > 32 {
+                    place(names.join("_and_"), (4, 4), &mut fields);
+                    names.clear();
+                    used = 0;
+                }
+                names.push(m.name.clone());
+                bitfield = Some((names, used + bits));
+                continue;
+            }
+            if let Some((names, _)) = bitfield.take() {
+                place(names.join("_and_"), (4, 4), &mut fields);
+            }
+            place(escape(&m.name), self.layout(&m.ty, abi), &mut fields);
+        }
+        if let Some((names, _)) = bitfield.take() {
+            place(names.join("_and_"), (4, 4), &mut fields);
+        }
+        (fields, size.div_ceil(align) * align, align)
+    }
+
+    fn derives_default(&self, ty: &CType) -> bool {
+        if !ty.pointers.is_empty() {
+            return false;
+        }
+        let arrays_ok = ty.array.iter().all(|d| {
+            self.array_len(d).trim_end_matches("usize").parse::<u64>().map(|n| n <= 32).unwrap_or(false)
+        });
+        arrays_ok && self.base_default(&ty.name)
+    }
+
+    fn base_default(&self, name: &str) -> bool {
+        if name == "void" || name == "char" {
+            return false;
+        }
+        match self.reg.ty(name).map(|t| &t.kind) {
+            None => primitive(name).is_some(),
+            Some(&TypeKind::Bitmask { .. }) => true,
+            Some(&TypeKind::Enum) => {
+                ENUM_DEFAULTS.iter().any(|&(e, _)| e == name) ||
+                self.reg.enums(name).is_some_and(|e| e.bitmask)
+            }
+            Some(TypeKind::Alias(target)) => self.base_default(target),
+            Some(&TypeKind::BaseType(Some(ref target))) => self.derives_default(target),
+            Some(&TypeKind::Struct { ref members, union: false, .. }) => {
+                members.iter().all(|m| m.ty.bits.is_none() && self.derives_default(&m.ty))
+            }
+            _ => primitive(name).is_some(),
+        }
+    }
+
+    // Emission
+
+    fn emit_enums(&mut self, types: &[String], module: ModuleId, out: &mut String) {
+        for c_name in types {
+            let is_enum = match self.reg.ty(c_name).map(|t| &t.kind) {
+                Some(&TypeKind::Enum) => !self.reg.enums(c_name).is_some_and(|e| e.bitmask),
+                _ => false,
+            };
+            if !is_enum {
+                continue;
+            }
+            let values = self.own_values(c_name);
+            let values = if module == ModuleId::Core {
+                values
+            } else {
+                let mut values = values;
+                let m = match module { ModuleId::Ext(i) => i, ModuleId::Core => unreachable!() };
+                values.extend(self.modules[m].enum_values.iter()
+                                  .filter(|v| &v.0 == c_name).map(|v| v.1.clone()));
+                values
+            };
+            let refs: Vec<&EnumValue> = values.iter().collect();
+            let body = self.render_values(c_name, &refs);
+            let _ = writeln!(out, "make_enum!{{{};", self.rust_name(c_name));
+            out.push_str(&body);
+            out.push_str("}\n");
+            if let Some(&(_, variant)) = ENUM_DEFAULTS.iter().find(|&&(e, _)| e == c_name) {
+                let _ = writeln!(out, "enum_defl!{{{}::{}}}", self.rust_name(c_name), variant);
+            }
+        }
+    }
+
+    fn emit_flags(&mut self, types: &[String], module: ModuleId, out: &mut String) {
+        let mut empty = String::new();
+        for c_name in types {
+            let (bits, wide) = match self.reg.ty(c_name).map(|t| &t.kind) {
+                Some(TypeKind::Bitmask { bits, wide }) => (bits.clone(), *wide),
+                _ => continue,
+            };
+            let flag = self.flag_name(c_name);
+            let flags = if wide {
+                format!("{}: u64", self.rust_name(c_name))
+            } else {
+                self.rust_name(c_name)
+            };
+            let bits = bits.map(|b| self.reg.resolve(&b).to_owned());
+            match bits {
+                Some(ref bits) if self.reg.enums(bits).is_some_and(|e| !e.values.is_empty()) ||
+                                  self.core_values.iter().any(|v| &v.0 == bits) => {
+                    let mut values = self.own_values(bits);
+                    if let ModuleId::Ext(m) = module {
+                        values.extend(self.modules[m].enum_values.iter()
+                                          .filter(|v| &v.0 == bits).map(|v| v.1.clone()));
+                    }
+                    let refs: Vec<&EnumValue> = values.iter().collect();
+                    let body = self.render_values(bits, &refs);
+                    let _ = writeln!(out, "make_flag!{{{}; {};", flag, flags);
+                    out.push_str(&body);
+                    out.push_str("}\n");
+                }
+                _ => {
+                    let _ = writeln!(empty, "make_flag!{{{}; {}; }}", flag, flags);
+                }
+            }
+        }
+        out.push_str(&empty);
+    }
+
+    fn emit_handles(&self, types: &[String], out: &mut String) {
+        for c_name in types {
+            if let Some(&TypeKind::Handle { dispatchable, ref object_type }) = self.reg.ty(c_name).map(|t| &t.kind) {
+                let name = self.rust_name(c_name);
+                let object_type = match *object_type {
+                    Some(ref value) => self.value_name("VkObjectType", false, value),
+                    None => "UNKNOWN".to_owned(),
+                };
+                if dispatchable {
+                    let _ = writeln!(out, "opaque!{{_{}, {}, {}}}", name, name, object_type);
+                } else {
+                    let _ = writeln!(out, "handle!{{{}, {}}}", name, object_type);
+                }
+            }
+        }
+    }
+
+    fn emit_aliases(&self, types: &[String], module: ModuleId, out: &mut String) {
+        for c_name in types {
+            if let Some(TypeKind::Alias(target)) = self.reg.ty(c_name).map(|t| &t.kind) {
+                let name = self.rust_name(c_name);
+                let target_ref = self.type_ref(target, module);
+                if name != target_ref && !c_name.starts_with("VkFlags") {
+                    let _ = writeln!(out, "pub type {} = {};", name, target_ref);
+                }
+            }
+        }
+    }
+
+    fn pfn(&self, name: &str, params: &[Member], ret: &CType, module: ModuleId) -> String {
+        if name == "PFN_vkVoidFunction" {
+            return "pub type PFN_vkVoidFunction = ::std::option::Option<extern \"C\" fn()>;\n".to_owned();
+        }
+        let mut out = format!("pub type {} =\n    ::std::option::Option<unsafe extern \"C\" fn(", name);
+        let indent = " ".repeat(47);
+        for (i, p) in params.iter().enumerate() {
+            if i > 0 {
+                out.push_str(",\n");
+                out.push_str(&indent);
+            }
+            let _ = write!(out, "{}: {}", escape(&p.name), self.param_type(&p.ty, module));
+        }
+        out.push(')');
+        if let Some(ret) = self.return_type(ret, module) {
+            let _ = write!(out, "\n{}-> {}", " ".repeat(30), ret);
+        }
+        out.push_str(">;\n");
+        out
+    }
+
+    fn emit_funcpointers(&self, types: &[String], module: ModuleId, out: &mut String) {
+        for c_name in types {
+            if let Some(TypeKind::FuncPointer { ret, params }) = self.reg.ty(c_name).map(|t| &t.kind) {
+                out.push_str(&self.pfn(c_name, params, ret, module));
+            }
+        }
+    }
+
+    fn emit_structs(&self, types: &[String], module: ModuleId, unions: bool, out: &mut String) {
+        for c_name in types {
+            let members = match self.reg.ty(c_name).map(|t| &t.kind) {
+                Some(&TypeKind::Struct { ref members, union, .. }) if union == unions => members,
+                _ => continue,
+            };
+            let name = self.rust_name(c_name);
+            if unions {
+                self.emit_union(&name, c_name, members, module, out);
+                continue;
+            }
+            let default = members.iter().all(|m| m.ty.bits.is_none() && self.derives_default(&m.ty));
+            out.push_str("#[repr(C)]\n");
+            let debug = if DEBUG_STRUCTS.contains(&&c_name[..]) { "Debug, " } else { "" };
+            let _ = writeln!(out, "#[derive({}Copy, Clone{})]", debug, if default { ", Default" } else { "" });
+            let _ = writeln!(out, "pub struct {} {{", name);
+            let mut bitfield: Option<(Vec<String>, u32)> = None;
+            for m in members {
+                if let Some(bits) = m.ty.bits {
+                    // Pack consecutive bitfields into the 32-bit words C would use.
+                    let (mut names, mut used) = bitfield.take().unwrap_or((Vec::new(), 0));
+                    if used + bits > 32 {
+                        let _ = writeln!(out, "    pub {}: u32,", names.join("_and_"));
+                        names.clear();
+                        used = 0;
+                    }
+                    names.push(m.name.clone());
+                    bitfield = Some((names, used + bits));
+                    continue;
+                }
+                if let Some((names, _)) = bitfield.take() {
+                    let _ = writeln!(out, "    pub {}: u32,", names.join("_and_"));
+                }
+                let _ = writeln!(out, "    pub {}: {},", escape(&m.name), self.member_type(&m.ty, module));
+            }
+            if let Some((names, _)) = bitfield.take() {
+                let _ = writeln!(out, "    pub {}: u32,", names.join("_and_"));
+            }
+            out.push_str("}\n");
+            let tagged = members.iter().any(|m| m.name == "sType" && m.values.is_some());
+            if !default && !tagged {
+                let _ = writeln!(out, "zero_defl!{{{}}}", name);
+            }
+            let strings: Vec<String> = members.iter()
+                .filter(|m| m.ty.name == "char" && m.ty.pointers.is_empty() && !m.ty.array.is_empty())
+                .map(|m| {
+                    let method = camel_words(&m.name).join("_").to_lowercase();
+                    format!("{} => ({}, {}_lossy)", m.name, escape(&method), method)
+                })
+                .collect();
+            if !strings.is_empty() {
+                let line = format!("c_strings!{{{} {{ {} }}}}", name, strings.join(", "));
+                if line.len() <= 100 {
+                    let _ = writeln!(out, "{}", line);
+                } else {
+                    let _ = writeln!(out, "c_strings!{{{} {{\n    {}\n}}}}", name, strings.join(",\n    "));
+                }
+            }
+            self.emit_chain(c_name, &name, members, module, out);
+        }
+    }
+
+    /// Builders for the `*Info` structs among `types`, which borrow the
+    /// arrays and strings they point to and fill in their counts.
+    fn emit_builders(&self, types: &[String], module: ModuleId, out: &mut String) {
+        for c_name in types {
+            let members = match self.reg.ty(c_name).map(|t| &t.kind) {
+                Some(TypeKind::Struct { members, union: false, .. }) => members,
+                _ => continue,
+            };
+            let name = self.rust_name(c_name);
+            if !name.trim_end_matches(char::is_numeric).ends_with("Info") || members.iter().any(|m| m.ty.bits.is_some()) {
+                continue;
+            }
+            let _ = writeln!(out, "builder!{{{}, {}Builder}}", name, name);
+            let _ = writeln!(out, "impl<'a> {}Builder<'a> {{", name);
+
+            let count_of = |m: &Member| -> Option<String> {
+                let len = m.len.as_ref()?;
+                let first = len.split(',').next().unwrap_or("");
+                if members.iter().any(|c| c.name == first) {
+                    return Some(first.to_owned());
+                }
+                // `codeSize / 4`: the count is in bytes.
+                let alt = m.altlen.as_ref()?;
+                let count = alt.split(" / ").next().unwrap_or("");
+                if members.iter().any(|c| c.name == count) { Some(count.to_owned()) } else { None }
+            };
+            for m in members {
+                if m.name == "sType" || m.name == "pNext" {
+                    continue;
+                }
+                let arrays: Vec<&Member> = members.iter()
+                    .filter(|a| count_of(a).as_ref() == Some(&m.name))
+                    .collect();
+                if !arrays.is_empty() && !arrays.iter().any(|a| a.noautovalidity) {
+                    continue;
+                }
+                let is_ptr = !m.ty.pointers.is_empty();
+                let words = camel_words(&m.name);
+                let skip = if is_ptr && words.len() > 1 && words[0].chars().all(|c| c == 'P') { 1 } else { 0 };
+                let method = escape(&words[skip..].join("_").to_lowercase());
+                let field = escape(&m.name);
+                let null_terminated = m.len.as_ref().map(|l| l.split(',').next() == Some("null-terminated"));
+                let (param, body) = if null_terminated == Some(true) && m.ty.name == "char" && m.ty.pointers.len() == 1 {
+                    ("&'a ::std::ffi::CStr".to_owned(), format!("self.inner.{} = {}.as_ptr();", field, method))
+                } else if m.len.is_some() && m.ty.array.is_empty() && is_ptr {
+                    let mut elem = m.ty.clone();
+                    let pointee_const = elem.pointers.pop().unwrap_or(true);
+                    let void = elem.name == "void" && elem.pointers.is_empty();
+                    let elem = if void { "u8".to_owned() } else { self.member_type(&elem, module) };
+                    let (slice, as_ptr) = if pointee_const {
+                        (format!("&'a [{}]", elem), "as_ptr()")
+                    } else {
+                        (format!("&'a mut [{}]", elem), "as_mut_ptr()")
+                    };
+                    let ptr = if void {
+                        format!("{}.{} as {}", method, as_ptr, self.member_type(&m.ty, module))
+                    } else {
+                        format!("{}.{}", method, as_ptr)
+                    };
+                    let body = match count_of(m) {
+                        Some(count) => {
+                            let count_ty = members.iter().find(|c| c.name == count)
+                                .map(|c| self.member_type(&c.ty, module))
+                                .unwrap_or_default();
+                            let scale = m.altlen.as_ref()
+                                .and_then(|a| a.split(" / ").nth(1))
+                                .map(|n| format!(" * {}", n))
+                                .unwrap_or_default();
+                            let len = match (&count_ty[..], scale.is_empty()) {
+                                ("usize", _) => format!("{}.len(){}", method, scale),
+                                (_, true) => format!("{}.len() as {}", method, count_ty),
+                                (_, false) => format!("({}.len(){}) as {}", method, scale, count_ty),
+                            };
+                            format!("self.inner.{} = {};\n        self.inner.{} = {};",
+                                    escape(&count), len, field, ptr)
+                        }
+                        // Without a count, an empty array is passed as null.
+                        None => format!("self.inner.{} = if {}.is_empty() {{ ::std::ptr::null{}() }} else {{ {} }};",
+                                        field, method, if pointee_const { "" } else { "_mut" }, ptr),
+                    };
+                    (slice, body)
+                } else if m.ty.pointers.len() == 1 && m.ty.name != "void" && m.ty.name != "char" &&
+                          m.ty.array.is_empty() && self.reg.ty(&m.ty.name).is_some() {
+                    let mut pointee = m.ty.clone();
+                    let pointee_const = pointee.pointers.pop().unwrap_or(true);
+                    let pointee = self.member_type(&pointee, module);
+                    let param = if pointee_const { format!("&'a {}", pointee) } else { format!("&'a mut {}", pointee) };
+                    (param, format!("self.inner.{} = {};", field, method))
+                } else {
+                    (self.member_type(&m.ty, module), format!("self.inner.{} = {};", field, method))
+                };
+                let _ = write!(out, "    #[inline]\n    pub fn {}(mut self, {}: {}) -> Self {{\n        {}\n        self\n    }}\n",
+                               method, method, param, body);
+            }
+            if self.reg.types.iter().any(|t| match t.kind {
+                TypeKind::Struct { ref extends, .. } => {
+                    self.owner.contains_key(&t.name) && extends.iter().any(|e| self.reg.resolve(e) == &c_name[..])
+                }
+                _ => false,
+            }) {
+                let _ = write!(out, "    #[inline]\n    pub fn push_next<T: Extends{}>(mut self, next: &'a mut T) -> Self {{\n        \
+                                     unsafe {{ self.inner.push_next(next); }}\n        self\n    }}\n", name);
+            }
+            out.push_str("}\n");
+        }
+    }
+
+    /// `pNext` chain support for the struct `c_name`: `extensible!` if other
+    /// structs may be chained onto it, and `tagged!` if it has an `sType`.
+    fn emit_chain(&self, c_name: &str, name: &str, members: &[Member], module: ModuleId, out: &mut String) {
+        let emitted = |c: &String| self.owner.contains_key(self.reg.resolve(c));
+        let extenders: Vec<&String> = self.reg.types.iter()
+            .filter(|t| match t.kind {
+                TypeKind::Struct { ref extends, .. } => {
+                    extends.iter().any(|e| self.reg.resolve(e) == c_name)
+                }
+                _ => false,
+            })
+            .map(|t| &t.name)
+            .filter(|t| emitted(t))
+            .collect();
+        if !extenders.is_empty() {
+            let _ = writeln!(out, "extensible!{{{}, Extends{}}}", name, name);
+        }
+
+        let stype = members.iter()
+            .find(|m| m.name == "sType")
+            .and_then(|m| m.values.as_ref())
+            .map(|v| self.value_name("VkStructureType", false, v));
+        if let Some(stype) = stype {
+            let bases: Vec<String> = match self.reg.ty(c_name).map(|t| &t.kind) {
+                Some(TypeKind::Struct { extends, .. }) => extends.iter()
+                    .filter(|e| emitted(e))
+                    .map(|e| self.reg.resolve(e))
+                    .filter(|e| !(module == ModuleId::Core && self.owner[*e] != ModuleId::Core))
+                    .map(|e| {
+                        let owner = self.owner[e];
+                        let trait_name = format!("Extends{}", self.rust_name(e));
+                        if owner == module || owner == ModuleId::Core {
+                            trait_name
+                        } else {
+                            format!("{}::{}", self.module_path(owner), trait_name)
+                        }
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let mut line = format!("tagged!{{{}, {}", name, stype);
+            let mut sep = "; ";
+            for base in &bases {
+                if line.len() + sep.len() + base.len() + 1 > 100 {
+                    let _ = writeln!(out, "{}{}", line, sep.trim_end());
+                    line = format!("    {}", base);
+                } else {
+                    let _ = write!(line, "{}{}", sep, base);
+                }
+                sep = ", ";
+            }
+            let _ = writeln!(out, "{}}}", line);
+        }
+
+        // Core structs can't name traits of extension modules, so their
+        // impls go next to the struct they extend.
+        if module != ModuleId::Core {
+            for extender in extenders {
+                if self.owner[self.reg.resolve(extender)] == ModuleId::Core {
+                    let _ = writeln!(out, "unsafe impl Extends{} for {} {{}}", name,
+                                     self.type_ref(self.reg.resolve(extender), module));
+                }
+            }
+        }
+    }
+
+    /// A native union, with a constructor per member. Struct members are
+    /// built from their fields, and union members also get a constructor per
+    /// member of theirs, named after its element type: `color_f32`.
+    fn emit_union(&self, name: &str, c_name: &str, members: &[Member], module: ModuleId, out: &mut String) {
+        // The constructors zero the whole union, so every byte is defined.
+        let (size, align) = self.base_layout(c_name, &ABIS[0]);
+        let bits = if align > 4 {
+            format!("[u64; {}usize]", size / 8)
+        } else {
+            format!("[u32; {}usize]", size / 4)
+        };
+        out.push_str("#[repr(C)]\n#[derive(Copy, Clone)]\n");
+        let _ = writeln!(out, "pub union {} {{", name);
+        for m in members {
+            let _ = writeln!(out, "    pub {}: {},", escape(&m.name), self.member_type(&m.ty, module));
+        }
+        out.push_str("}\n");
+        let _ = writeln!(out, "union_bits!{{{}, {}}}", name, bits);
+        let _ = writeln!(out, "impl {} {{", name);
+        let snake = |n: &str| escape(&camel_words(n).join("_").to_lowercase());
+        // Starts from zeroes so that bytes past the member written are defined.
+        let constructor = |out: &mut String, method: &str, params: &[String], field: &str, value: &str| {
+            let _ = writeln!(out, "    #[inline]\n    pub fn {}({}) -> Self {{", method, params.join(", "));
+            let _ = writeln!(out, "        let mut value = Self::default();");
+            let _ = writeln!(out, "        value.{} = {};\n        value\n    }}", field, value);
+        };
+        for m in members {
+            let field = escape(&m.name);
+            let ty = self.member_type(&m.ty, module);
+            let inner = match self.reg.ty(&m.ty.name).map(|t| &t.kind) {
+                Some(TypeKind::Struct { members, union, .. })
+                        if m.ty.pointers.is_empty() && m.ty.array.is_empty() => Some((members, *union)),
+                _ => None,
+            };
+            match inner {
+                Some((inner, false)) => {
+                    let params: Vec<String> = inner.iter()
+                        .map(|i| format!("{}: {}", escape(&i.name), self.member_type(&i.ty, module)))
+                        .collect();
+                    let fields: Vec<String> = inner.iter()
+                        .map(|i| escape(&i.name))
+                        .collect();
+                    constructor(out, &snake(&m.name), &params, &field,
+                                &format!("{} {{ {} }}", ty, fields.join(", ")));
+                }
+                Some((inner, true)) => {
+                    constructor(out, &snake(&m.name), &[format!("{}: {}", field, ty)], &field, &field);
+                    for i in inner {
+                        let inner_field = escape(&i.name);
+                        constructor(out, &format!("{}_{}", snake(&m.name), self.type_ref(&i.ty.name, module)),
+                                    &[format!("{}: {}", inner_field, self.member_type(&i.ty, module))],
+                                    &field, &format!("{}::{}({})", ty, snake(&i.name), inner_field));
+                    }
+                }
+                None => {
+                    constructor(out, &snake(&m.name), &[format!("{}: {}", field, ty)], &field, &field);
+                }
+            }
+        }
+        out.push_str("}\n");
+    }
+
+    fn signature(&self, name: &str) -> &Command {
+        self.reg.signature(name).expect("command checked when assigning modules")
+    }
+
+    fn emit_command_pfns(&self, commands: &[String], module: ModuleId, out: &mut String) {
+        for name in commands {
+            let cmd = self.signature(name);
+            out.push_str(&self.pfn(&format!("PFN_{}", name), &cmd.params, &cmd.ret, module));
+        }
+    }
+
+    fn emit_fn_table(&self, name: &str, doc: Option<&str>, commands: &[&String], module: ModuleId,
+                     out: &mut String) {
+        out.push_str("fn_table!{\n");
+        if let Some(doc) = doc {
+            for line in doc.lines() {
+                let _ = writeln!(out, "    /// {}", line);
+            }
+        }
+        let _ = writeln!(out, "    pub struct {} {{", name);
+        for cmd_name in commands {
+            let cmd = self.signature(cmd_name);
+            let _ = write!(out, "        fn {}: PFN_{}(", cmd_name, cmd_name);
+            for (i, p) in cmd.params.iter().enumerate() {
+                out.push_str(if i == 0 { "\n" } else { ",\n" });
+                let _ = write!(out, "            {}: {}", escape(&p.name), self.param_type(&p.ty, module));
+            }
+            out.push(')');
+            if let Some(ret) = self.return_type(&cmd.ret, module) {
+                let _ = write!(out, " -> {}", ret);
+            }
+            out.push_str(";\n");
+        }
+        out.push_str("    }\n}\n");
+    }
+
+    fn emit_externs(&self, commands: &[String], module: ModuleId, out: &mut String) {
+        out.push_str("#[link(name = \"vulkan\")]\nextern \"C\" {\n");
+        for name in commands {
+            let cmd = self.signature(name);
+            let head = format!("    pub fn {}(", name);
+            out.push_str(&head);
+            let indent = " ".repeat(head.len());
+            for (i, p) in cmd.params.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(",\n");
+                    out.push_str(&indent);
+                }
+                let _ = write!(out, "{}: {}", escape(&p.name), self.param_type(&p.ty, module));
+            }
+            out.push(')');
+            if let Some(ret) = self.return_type(&cmd.ret, module) {
+                let _ = write!(out, " -> {}", ret);
+            }
+            out.push_str(";\n");
+        }
+        out.push_str("}\n");
+    }
+
+    /// Wrappers collecting the count/pointer queries among `commands` into
+    /// `Vec`s: methods on the table `table`, or free functions calling the
+    /// linked commands, behind `cfg` if given.
+    fn emit_enumerators(&self, table: Option<&str>, commands: &[&String], module: ModuleId,
+                        cfg: Option<&str>, out: &mut String) {
+        let mut body = String::new();
+        for cmd_name in commands {
+            let cmd = self.signature(cmd_name);
+            let n = cmd.params.len();
+            if n < 2 {
+                continue;
+            }
+            let (count, array) = (&cmd.params[n - 2], &cmd.params[n - 1]);
+            let counted = array.len.as_ref().map(|l| l.split(',').next() == Some(&count.name[..]));
+            if counted != Some(true) || count.ty.pointers != [false] || array.ty.pointers != [false] {
+                continue;
+            }
+            let indent = if table.is_some() { "    " } else { "" };
+            let (mut params, mut lets, mut args) = (Vec::new(), Vec::new(), Vec::new());
+            if table.is_some() {
+                params.push("&self".to_owned());
+            }
+            for p in &cmd.params[..n - 2] {
+                let words = camel_words(&p.name);
+                let skip = if !p.ty.pointers.is_empty() && words.len() > 1 && words[0].chars().all(|c| c == 'P') {
+                    1
+                } else {
+                    0
+                };
+                let name = escape(&words[skip..].join("_").to_lowercase());
+                if p.ty.name == "char" && p.ty.pointers == [true] {
+                    params.push(format!("{}: Option<&::std::ffi::CStr>", name));
+                    lets.push(format!("let {} = {}.map_or(::std::ptr::null(), |s| s.as_ptr());", name, name));
+                } else if p.ty.pointers == [true] {
+                    let mut pointee = p.ty.clone();
+                    pointee.pointers.clear();
+                    params.push(format!("{}: &{}", name, self.member_type(&pointee, module)));
+                } else {
+                    params.push(format!("{}: {}", name, self.param_type(&p.ty, module)));
+                }
+                args.push(name);
+            }
+            args.push("count".to_owned());
+            let void = array.ty.name == "void";
+            let elem = if void { "u8".to_owned() } else { self.type_ref(&array.ty.name, module) };
+            args.push(if void { "data as *mut ::std::os::raw::c_void".to_owned() } else { "data".to_owned() });
+            let tagged = match self.reg.ty(&array.ty.name).map(|t| &t.kind) {
+                Some(TypeKind::Struct { members, .. }) => {
+                    members.iter().any(|m| m.name == "sType" && m.values.is_some())
+                }
+                _ => false,
+            };
+
+            let mut method = cmd_name.trim_start_matches("vk");
+            if let Some(tag) = self.tag_of(method) {
+                method = &method[..method.len() - tag.len()];
+            }
+            let method = camel_words(method).join("_").to_lowercase();
+            let returns = self.return_type(&cmd.ret, module).is_some();
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            let _ = writeln!(body, "{}/// `{}`, collected into a `Vec`.", indent, cmd_name);
+            let _ = writeln!(body, "{0}///\n{0}/// # Safety\n{0}///", indent);
+            let _ = writeln!(body, "{}/// The arguments must be valid for `{}`.", indent, cmd_name);
+            if let (None, Some(cfg)) = (table, cfg) {
+                let _ = writeln!(body, "{}", cfg);
+            }
+            let _ = writeln!(body, "{}", wrap_args(&format!("{}pub unsafe fn {}(", indent, method), &params));
+            if returns {
+                let _ = writeln!(body, "{}        -> ::std::result::Result<Vec<{}>, Error> {{", indent, elem);
+            } else {
+                let _ = writeln!(body, "{}        -> Vec<{}> {{", indent, elem);
+            }
+            for l in &lets {
+                let _ = writeln!(body, "{}    {}", indent, l);
+            }
+            let _ = writeln!(body, "{}    {}(|count, data{}| {{", indent,
+                             if tagged { "enumerate_tagged" } else { "enumerate" },
+                             if void { ": *mut u8" } else { "" });
+            let call = wrap_args(&format!("{}        {}{}(", indent, if table.is_some() { "self." } else { "" },
+                                          cmd_name), &args);
+            if returns {
+                let _ = writeln!(body, "{}\n{}    }})", call, indent);
+            } else {
+                let _ = writeln!(body, "{};\n{}        Result::SUCCESS\n{}    }}).unwrap_or_default()",
+                                 call, indent, indent);
+            }
+            let _ = writeln!(body, "{}}}", indent);
+        }
+        if body.is_empty() {
+            return;
+        }
+        out.push('\n');
+        match table {
+            Some(table) => { let _ = write!(out, "impl {} {{\n{}}}\n", table, body); }
+            None => out.push_str(&body),
+        }
+    }
+
+    /// Whether the command is dispatched through an instance or physical device.
+    fn instance_level(&self, name: &str) -> Option<bool> {
+        if name == "vkGetDeviceProcAddr" {
+            return Some(true);
+        }
+        let first = self.signature(name).params.first().map(|p| &p.ty.name[..]);
+        match first {
+            Some("VkInstance") | Some("VkPhysicalDevice") => Some(true),
+            Some("VkDevice") | Some("VkQueue") | Some("VkCommandBuffer") => Some(false),
+            _ => None,
+        }
+    }
+
+    // Files
+
+    pub fn consts_rs(&self) -> String {
+        let version = self.reg.features.last().map(|f| f.number.clone()).unwrap_or_default();
+        let mut parts = version.split('.');
+        let major = parts.next().unwrap_or("1");
+        let minor = parts.next().unwrap_or("0");
+        let mut out = String::from("use std;\n\n");
+        let mut consts = vec![
+            ("API_VERSION".to_owned(), "u32".to_owned(),
+             format!("(1 << 22) | ({} << 12) | ({})", minor, self.reg.header_version.unwrap_or(0))),
+        ];
+        if major != "1" {
+            consts[0].2 = format!("({} << 22) | ({} << 12) | ({})", major, minor,
+                                  self.reg.header_version.unwrap_or(0));
+        }
+        for feature in &self.reg.features {
+            let mut parts = feature.number.split('.');
+            let (major, minor) = (parts.next().unwrap_or("1"), parts.next().unwrap_or("0"));
+            consts.push((format!("API_VERSION_{}_{}", major, minor), "u32".to_owned(),
+                         format!("({} << 22) | ({} << 12) | (0)", major, minor)));
+        }
+        let versions = consts.len();
+        for (name, ty, value) in &self.reg.constants {
+            if name == "VK_TRUE" || name == "VK_FALSE" {
+                continue;
+            }
+            let (ty, value) = constant(ty.as_ref().map(|s| &s[..]), value);
+            consts.push((name.trim_start_matches("VK_").to_owned(), ty, value));
+        }
+        let width = consts.iter().map(|c| c.0.len()).max().unwrap_or(0) + 1;
+        for (i, (name, ty, value)) in consts.iter().enumerate() {
+            let _ = writeln!(out, "pub const {:w$} {} = {};", format!("{}:", name), ty, value, w = width);
+            if i + 1 == versions {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    pub fn types_rs(&self) -> String {
+        let mut out = String::new();
+        for c_name in &self.core_types {
+            if let Some(&TypeKind::BaseType(Some(ref target))) = self.reg.ty(c_name).map(|t| &t.kind) {
+                if c_name != "VkBool32" && primitive(c_name).is_none() {
+                    let _ = writeln!(out, "pub type {} = {};", self.rust_name(c_name),
+                                     self.member_type(target, ModuleId::Core));
+                }
+            }
+        }
+        out.push_str(BOOL32);
+        out.push_str("\n// Handles\n");
+        out.push_str(HANDLE_TRAIT);
+        self.emit_handles(&self.core_types, &mut out);
+        let mut aliases = String::new();
+        self.emit_aliases(&self.core_types, ModuleId::Core, &mut aliases);
+        if !aliases.is_empty() {
+            out.push_str("\n// Aliases\n\n");
+            out.push_str(&aliases);
+        }
+        out
+    }
+
+    pub fn enums_rs(&mut self) -> String {
+        let mut out = String::new();
+        let types = self.core_types.clone();
+        self.emit_enums(&types, ModuleId::Core, &mut out);
+        out
+    }
+
+    pub fn flags_rs(&mut self) -> String {
+        let mut out = String::new();
+        let types = self.core_types.clone();
+        self.emit_flags(&types, ModuleId::Core, &mut out);
+        out
+    }
+
+    pub fn structs_rs(&self) -> String {
+        let mut out = String::from("use vk::*;\n\n");
+        self.emit_structs(&self.core_types, ModuleId::Core, false, &mut out);
+        out
+    }
+
+    pub fn builders_rs(&self) -> String {
+        let mut out = String::from("use vk::*;\n\n");
+        self.emit_builders(&self.core_types, ModuleId::Core, &mut out);
+        out
+    }
+
+    pub fn unions_rs(&self) -> String {
+        let mut out = String::from("use vk::*;\n\n");
+        self.emit_structs(&self.core_types, ModuleId::Core, true, &mut out);
+        out
+    }
+
+    pub fn pfns_rs(&self) -> String {
+        let mut out = String::from("use vk::*;\n\n");
+        self.emit_funcpointers(&self.core_types, ModuleId::Core, &mut out);
+        self.emit_command_pfns(&self.core_commands, ModuleId::Core, &mut out);
+        out
+    }
+
+    pub fn fns_rs(&self) -> String {
+        let mut out = String::from("use vk::*;\n\n");
+        self.emit_externs(&self.core_commands, ModuleId::Core, &mut out);
+        let commands: Vec<&String> = self.core_commands.iter().collect();
+        self.emit_enumerators(None, &commands, ModuleId::Core, None, &mut out);
+        out
+    }
+
+    pub fn dispatch_rs(&self) -> String {
+        let mut out = String::from("// Dispatch tables for core commands\n\nuse vk::*;\n\n");
+        for (i, commands) in self.feature_commands.iter().enumerate() {
+            let instance: Vec<&String> = commands.iter()
+                .filter(|c| self.instance_level(c) == Some(true)).collect();
+            let device: Vec<&String> = commands.iter()
+                .filter(|c| self.instance_level(c) == Some(false)).collect();
+            if i == 0 {
+                self.emit_fn_table("InstanceFns",
+                                   Some("Instance-level core commands, resolved with `vkGetInstanceProcAddr`."),
+                                   &instance, ModuleId::Core, &mut out);
+                out.push_str(INSTANCE_FNS_LOAD);
+                self.emit_enumerators(Some("InstanceFns"), &instance, ModuleId::Core, None, &mut out);
+                out.push('\n');
+                self.emit_fn_table("DeviceFns",
+                                   Some("Device-level core commands, resolved with `vkGetDeviceProcAddr` so that\n\
+                                         calls go straight to the driver of the device they were loaded for."),
+                                   &device, ModuleId::Core, &mut out);
+                out.push_str(DEVICE_FNS_LOAD);
+                self.emit_enumerators(Some("DeviceFns"), &device, ModuleId::Core, None, &mut out);
+                continue;
+            }
+            let version = &self.reg.features[i].number;
+            let suffix = version.replace('.', "_");
+            let fill = |template: &str| template.replace("{version}", version).replace("{suffix}", &suffix);
+            if !instance.is_empty() {
+                out.push('\n');
+                self.emit_fn_table(&format!("InstanceFnsV{}", suffix),
+                                   Some(&fill(VERSION_INSTANCE_FNS_DOC)),
+                                   &instance, ModuleId::Core, &mut out);
+                out.push_str(&fill(VERSION_INSTANCE_FNS_LOAD));
+                self.emit_enumerators(Some(&format!("InstanceFnsV{}", suffix)), &instance, ModuleId::Core,
+                                      None, &mut out);
+            }
+            if !device.is_empty() {
+                out.push('\n');
+                self.emit_fn_table(&format!("DeviceFnsV{}", suffix),
+                                   Some(&fill(VERSION_DEVICE_FNS_DOC)),
+                                   &device, ModuleId::Core, &mut out);
+                out.push_str(&fill(VERSION_DEVICE_FNS_LOAD));
+                self.emit_enumerators(Some(&format!("DeviceFnsV{}", suffix)), &device, ModuleId::Core,
+                                      None, &mut out);
+            }
+        }
+        out
+    }
+
+    /// `tests/layout.c`, which has the C compiler measure every struct the
+    /// layout tests cover, one test per source file. `layout_rs` turns what
+    /// it measures into `tests/layout.rs`.
+    pub fn layout_c(&self) -> String {
+        let mut out = String::from(LAYOUT_C_HEAD);
+        self.emit_layout_c("structs", "vk", &self.core_types, false, &mut out);
+        self.emit_layout_c("unions", "vk", &self.core_types, true, &mut out);
+        for m in &self.modules {
+            let name = format!("{}_{}", m.vendor, m.name);
+            let path = format!("vk::{}::{}", m.vendor, m.name);
+            self.emit_layout_c(&name, &path, &m.types, false, &mut out);
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// A `STRUCT` and its `FIELD`s for every struct among `types`. Bitfields
+    /// have no offset in C, so only the size covers them.
+    fn emit_layout_c(&self, test: &str, path: &str, types: &[String], unions: bool, out: &mut String) {
+        for c_name in types {
+            let members = match self.reg.ty(c_name).map(|t| &t.kind) {
+                Some(&TypeKind::Struct { ref members, union, .. }) if union == unions => members,
+                _ => continue,
+            };
+            let _ = writeln!(out, "    STRUCT({}, {}::{}, {});", test, path, self.rust_name(c_name), c_name);
+            for m in members.iter().filter(|m| m.ty.bits.is_none()) {
+                let _ = writeln!(out, "    FIELD({}, {}, {});", c_name, m.name, escape(&m.name));
+            }
+        }
+    }
+
+    pub fn module_rs(&mut self, index: usize) -> String {
+        let module = ModuleId::Ext(index);
+        let mut out = String::from("use vk::*;\n\n");
+
+        let (spec, ext_name) = {
+            let m = &self.modules[index];
+            let find = |suffix: &str| m.constants.iter().find(|c| c.0.ends_with(suffix)).map(|c| c.1.clone());
+            (find("_SPEC_VERSION").unwrap_or_else(|| "1".to_owned()),
+             find("_EXTENSION_NAME").unwrap_or_else(|| format!("\"{}\"", m.ext.name)))
+        };
+        let _ = writeln!(out, "pub const SPEC_VERSION: u32 = {};", spec);
+        let _ = writeln!(out, "pub const EXTENSION_NAME: &'static str = {};", ext_name);
+        let others: Vec<(String, String)> = self.modules[index].constants.iter()
+            .filter(|c| !c.0.ends_with("_SPEC_VERSION") && !c.0.ends_with("_EXTENSION_NAME"))
+            .cloned().collect();
+        let mut seen = HashSet::new();
+        for (name, value) in others {
+            let mut name = name.trim_start_matches("VK_").to_owned();
+            if let Some(tag) = self.tag_of(&name).map(|t| t.len()) {
+                let len = name.len() - tag - 1;
+                name.truncate(len);
+            }
+            if value.starts_with("VK_") || !seen.insert(name.clone()) {
+                continue;
+            }
+            let (ty, value) = constant(None, &value);
+            let _ = writeln!(out, "pub const {}: {} = {};", name, ty, value);
+        }
+        out.push('\n');
+
+        // Values this extension adds to enums defined elsewhere. Bits go
+        // through `impl_enum!`; enum values through `ext_enums!`, which also
+        // records their names.
+        let mut named = String::new();
+        for target in self.extended(index) {
+            let values: Vec<EnumValue> = self.modules[index].enum_values.iter()
+                .filter(|v| self.reg.resolve(&v.0) == &target[..])
+                .map(|v| v.1.clone()).collect();
+            let refs: Vec<&EnumValue> = values.iter().collect();
+            let body = self.render_values(&target, &refs);
+            if body.is_empty() {
+                continue;
+            }
+            let path = self.type_ref(&target, module);
+            if self.is_bits(&target) {
+                let _ = write!(out, "impl_enum!{{{};\n{}}}\n", path, body);
+            } else {
+                let _ = writeln!(named, "    {} {{", path);
+                for line in body.lines() {
+                    let _ = writeln!(named, "    {}", line);
+                }
+                named.push_str("    }\n");
+            }
+        }
+        if !named.is_empty() {
+            let _ = write!(out, "ext_enums!{{\n{}}}\n", named);
+            self.modules[index].named = true;
+        }
+
+        let types = self.modules[index].types.clone();
+        self.emit_flags(&types, module, &mut out);
+        self.emit_enums(&types, module, &mut out);
+        self.emit_aliases(&types, module, &mut out);
+
+        let mut handles = String::new();
+        self.emit_handles(&types, &mut handles);
+        if !handles.is_empty() {
+            out.push('\n');
+            out.push_str(&handles);
+        }
+        let mut pfns = String::new();
+        self.emit_funcpointers(&types, module, &mut pfns);
+        if !pfns.is_empty() {
+            out.push('\n');
+            out.push_str(&pfns);
+        }
+        let mut structs = String::new();
+        self.emit_structs(&types, module, false, &mut structs);
+        self.emit_structs(&types, module, true, &mut structs);
+        if !structs.is_empty() {
+            out.push('\n');
+            out.push_str(&structs);
+        }
+        let mut builders = String::new();
+        self.emit_builders(&types, module, &mut builders);
+        if !builders.is_empty() {
+            out.push('\n');
+            out.push_str(&builders);
+        }
+
+        let commands = self.modules[index].commands.clone();
+        if commands.is_empty() {
+            return out;
+        }
+        out.push('\n');
+        self.emit_command_pfns(&commands, module, &mut out);
+
+        let is_device = self.modules[index].ext.kind == "device";
+        let (own, physical): (Vec<&String>, Vec<&String>) = commands.iter()
+            .partition(|c| !is_device || self.instance_level(c) != Some(true));
+        out.push('\n');
+        self.emit_fn_table("Fns", None, &own, module, &mut out);
+        out.push('\n');
+        out.push_str(if is_device { DEVICE_EXT_LOAD } else { INSTANCE_EXT_LOAD });
+        self.emit_enumerators(Some("Fns"), &own, module, None, &mut out);
+        if !physical.is_empty() {
+            out.push('\n');
+            self.emit_fn_table("PhysicalDeviceFns",
+                               Some("Commands of this extension which are dispatched through a\n\
+                                     physical device, and so resolved through the instance."),
+                               &physical, module, &mut out);
+            out.push('\n');
+            out.push_str(PHYSICAL_DEVICE_EXT_LOAD);
+            self.emit_enumerators(Some("PhysicalDeviceFns"), &physical, module, None, &mut out);
+        }
+        out.push_str("\n#[cfg(feature = \"static_link\")]\n");
+        self.emit_externs(&commands, module, &mut out);
+        let commands: Vec<&String> = commands.iter().collect();
+        self.emit_enumerators(None, &commands, module, Some("#[cfg(feature = \"static_link\")]"), &mut out);
+        out
+    }
+
+    /// Enums defined elsewhere which the extension module `index` adds values to.
+    fn extended(&self, index: usize) -> Vec<String> {
+        let module = ModuleId::Ext(index);
+        let mut extended: Vec<String> = Vec::new();
+        for (target, _) in &self.modules[index].enum_values {
+            let target = self.reg.resolve(target).to_owned();
+            let owner = self.owner.get(&target).cloned();
+            if owner.is_some() && owner != Some(module) && !extended.contains(&target) {
+                extended.push(target);
+            }
+        }
+        extended
+    }
+
+    fn is_bits(&self, c_name: &str) -> bool {
+        self.reg.enums(c_name).is_some_and(|e| e.bitmask)
+    }
+
+    /// `mod.rs` for `vendor`, listing generated modules and any of `existing`
+    /// (hand-written platform modules, with their source) for that vendor.
+    /// Call after `module_rs`.
+    pub fn vendor_mod_rs(&self, vendor: &str, existing: &[(String, String)]) -> String {
+        // Module name, generated module index, and the `cfg`s to collect its
+        // `ENUM_NAMES` under, if it has any.
+        let mut entries: Vec<(String, Option<usize>, Option<Vec<String>>)> = self.modules.iter()
+            .enumerate()
+            .filter(|&(_, m)| m.vendor == vendor)
+            .map(|(i, m)| (m.name.clone(), Some(i), if m.named { Some(Vec::new()) } else { None }))
+            .collect();
+        for (name, src) in existing {
+            if !entries.iter().any(|e| &e.0 == name) {
+                let cfgs = src.lines()
+                    .filter(|l| l.starts_with("#![cfg("))
+                    .map(|l| l.replacen("#!", "#", 1))
+                    .collect();
+                let named = src.contains("ext_enums!");
+                entries.push((name.clone(), None, if named { Some(cfgs) } else { None }));
+            }
+        }
+        entries.sort();
+        let mut out = String::new();
+        for &(ref name, index, _) in &entries {
+            let _ = writeln!(out, "pub mod {};", name);
+            if let Some(i) = index {
+                // Handles named after their extension are re-exported, as in `khr::Surface`.
+                for c_name in &self.modules[i].types {
+                    if let Some(&TypeKind::Handle { .. }) = self.reg.ty(c_name).map(|t| &t.kind) {
+                        let rust = self.rust_name(c_name);
+                        if rust == self.modules[i].prefix {
+                            let _ = writeln!(out, "pub use self::{}::{};", name, rust);
+                        }
+                    }
+                }
+            }
+        }
+
+        out.push_str("\n/// Collects the `ENUM_NAMES` of the modules above.\n\
+                      #[doc(hidden)]\n\
+                      pub fn enum_names(tables: &mut Vec<&'static [::vk::ExtEnumNames]>) {\n");
+        for (name, _, cfgs) in entries {
+            if let Some(cfgs) = cfgs {
+                for cfg in cfgs {
+                    let _ = writeln!(out, "    {}", cfg);
+                }
+                let _ = writeln!(out, "    tables.push({}::ENUM_NAMES);", name);
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Vendor module and module name for the extension `ext_name`, if it is one
+/// the generator handles: `VK_KHR_surface` is `khr::surface`.
+pub fn module_name(ext_name: &str) -> Option<(String, String)> {
+    let mut parts = ext_name.splitn(3, '_').skip(1);
+    let vendor = parts.next().unwrap_or("");
+    let rest = parts.next().unwrap_or("").to_lowercase();
+    if !VENDORS.contains(&vendor) || rest.is_empty() {
+        return None;
+    }
+    let name = if rest.starts_with(|c: char| c.is_ascii_digit()) { format!("_{}", rest) } else { rest };
+    Some((vendor.to_lowercase(), name))
+}
+
+/// Size, alignment and field offsets of one struct on one ABI.
+#[derive(PartialEq)]
+struct Layout {
+    size: u64,
+    align: u64,
+    fields: Vec<(String, u64)>,
+}
+
+/// The `(test, Rust name, layout)` of every struct measured in `dump`, the
+/// assembly of `tests/layout.c`.
+fn parse_layout_dump(dump: &str) -> Result<Vec<(String, String, Layout)>, String> {
+    let mut structs: Vec<(String, String, Layout)> = Vec::new();
+    for line in dump.lines() {
+        let line = line.trim();
+        if !line.starts_with(".ascii \"@") {
+            continue;
+        }
+        let words: Vec<&str> = line.trim_start_matches(".ascii \"@").trim_end_matches('"')
+            .split(' ')
+            .collect();
+        let number = |w: &str| w.parse::<u64>().map_err(|_| format!("bad number in `{}`", line));
+        match (words[0], words.len()) {
+            ("struct", 5) => structs.push((words[1].to_owned(), words[2].to_owned(), Layout {
+                size: number(words[3])?,
+                align: number(words[4])?,
+                fields: Vec::new(),
+            })),
+            ("field", 3) => match structs.last_mut() {
+                Some(s) => s.2.fields.push((words[1].to_owned(), number(words[2])?)),
+                None => return Err(format!("field before any struct: `{}`", line)),
+            },
+            _ => return Err(format!("unexpected `{}`", line)),
+        }
+    }
+    Ok(structs)
+}
+
+/// `tests/layout.rs` from `dumps`, the assembly of `tests/layout.c` compiled
+/// for each of `ABIS` in turn.
+pub fn layout_rs(dumps: &[String]) -> Result<String, String> {
+    if dumps.len() != ABIS.len() {
+        return Err(format!("expected {} layout dumps, got {}", ABIS.len(), dumps.len()));
+    }
+    let dumps = dumps.iter().map(|d| parse_layout_dump(d)).collect::<Result<Vec<_>, _>>()?;
+    if dumps[0].is_empty() || dumps.iter().any(|d| {
+        d.len() != dumps[0].len() || d.iter().zip(&dumps[0]).any(|(a, b)| a.1 != b.1)
+    }) {
+        return Err("the layout dumps don't measure the same structs".to_owned());
+    }
+    let mut out = String::from(LAYOUT_HEAD);
+    let mut body = String::new();
+    for (i, (test, name, _)) in dumps[0].iter().enumerate() {
+        // One `check_layout!` where every ABI agrees, and otherwise one per
+        // distinct layout under the `cfg` of the ABIs which share it.
+        let mut layouts: Vec<(Vec<&str>, &Layout)> = Vec::new();
+        for (abi, dump) in ABIS.iter().zip(&dumps) {
+            let layout = &dump[i].2;
+            match layouts.iter_mut().find(|l| l.1 == layout) {
+                Some(l) => l.0.push(abi.cfg),
+                None => layouts.push((vec![abi.cfg], layout)),
+            }
+        }
+        for (cfgs, layout) in &layouts {
+            if layouts.len() > 1 {
+                // With three ABIs, a layout shared by two is the one the
+                // third doesn't have.
+                let _ = match cfgs.len() {
+                    1 => writeln!(body, "    #[cfg({})]", cfgs[0]),
+                    _ => {
+                        let other = ABIS.iter().find(|abi| !cfgs.contains(&abi.cfg)).unwrap();
+                        if other.pointer == 8 {
+                            writeln!(body, "    #[cfg(target_pointer_width = \"32\")]")
+                        } else {
+                            writeln!(body, "    #[cfg(not({}))]", other.cfg)
+                        }
+                    }
+                };
+            }
+            let mut line = format!("    check_layout!({}, {}, {}", name, layout.size, layout.align);
+            for (field, offset) in &layout.fields {
+                let piece = format!("{}: {}", field, offset);
+                if line.len() + piece.len() + 4 > 100 {
+                    let _ = writeln!(body, "{},", line);
+                    line = format!("        {}", piece);
+                } else {
+                    let _ = write!(line, ", {}", piece);
+                }
+            }
+            let _ = writeln!(body, "{});", line);
+        }
+        if dumps[0].get(i + 1).is_none_or(|next| next.0 != *test) {
+            let _ = write!(out, "\n#[test]\nfn {}() {{\n{}}}\n", test, body);
+            body.clear();
+        }
+    }
+    Ok(out)
+}
+
+/// `head` followed by `args` and a closing parenthesis, wrapping at 100
+/// columns with continuation lines aligned after `head`.
+fn wrap_args(head: &str, args: &[String]) -> String {
+    let mut out = String::new();
+    let mut line = head.to_owned();
+    for (i, arg) in args.iter().enumerate() {
+        let piece = format!("{}{}", arg, if i + 1 < args.len() { "," } else { ")" });
+        if i == 0 {
+            line.push_str(&piece);
+        } else if line.len() + 1 + piece.len() > 100 {
+            let _ = writeln!(out, "{}", line);
+            line = format!("{}{}", " ".repeat(head.len()), piece);
+        } else {
+            line.push(' ');
+            line.push_str(&piece);
+        }
+    }
+    if args.is_empty() {
+        line.push(')');
+    }
+    out.push_str(&line);
+    out
+}
+
+fn is_alias(v: &EnumValue) -> bool {
+    matches!(v.value, Value::Alias(_))
+}
+
+fn shared_words(prefix: &[String], value: &str) -> usize {
+    prefix.iter().zip(value.split('_')).take_while(|&(p, v)| p == v).count()
+}
+
+/// Rust type and value of a C constant.
+fn constant(ty: Option<&str>, value: &str) -> (String, String) {
+    let ty = match ty {
+        Some("uint32_t") => "u32",
+        Some("uint64_t") => "u64",
+        Some("float") => "f32",
+        Some("int32_t") => "i32",
+        _ if value.contains("ULL") => "u64",
+        _ if value.contains('.') => "f32",
+        _ if value.starts_with('"') => "&'static str",
+        _ => "u32",
+    };
+    let value = match value {
+        "(~0U)" => "std::u32::MAX".to_owned(),
+        "(~0ULL)" => "std::u64::MAX".to_owned(),
+        v if v.starts_with("(~") => format!("!{}", v.trim_matches(|c| c == '(' || c == ')' || c == '~' ||
+                                                                c == 'U' || c == 'L')),
+        v if ty == "f32" => v.trim_end_matches(['F', 'f']).to_owned(),
+        v => v.trim_end_matches(['U', 'L']).to_owned(),
+    };
+    (ty.to_owned(), value)
+}
+
+const LAYOUT_HEAD: &str = "\
+//! Size, alignment and field offsets of every `#[repr(C)]` struct, checked
+//! against what the C compiler gives the declarations in `vulkan_core.h` on
+//! 64-bit targets, on 32-bit x86 outside Windows (where 64-bit fields are
+//! 4-byte aligned) and on other 32-bit targets.
+//!
+//! Generated by `generator/layout.sh`, which compiles `tests/layout.c`
+//! against the Vulkan-Headers release the bindings are generated from.
+
+extern crate vulkan_bind;
+
+use std::mem::{align_of, offset_of, size_of};
+
+use vulkan_bind::vk;
+
+macro_rules! check_layout {
+    ($ty:ty, $size:expr, $align:expr $(, $field:ident: $offset:expr)*) => {
+        assert_eq!(size_of::<$ty>(), $size, concat!(\"size of \", stringify!($ty)));
+        assert_eq!(align_of::<$ty>(), $align, concat!(\"alignment of \", stringify!($ty)));
+        $(assert_eq!(offset_of!($ty, $field), $offset,
+                     concat!(\"offset of \", stringify!($ty), \"::\", stringify!($field)));)*
+    }
+}
+";
+
+const LAYOUT_C_HEAD: &str = "\
+/* The layout of every struct in the bindings, as the C compiler sees it.
+ *
+ * Generated by vulkan-bind-generator from vk.xml. Compiled with -S, each
+ * STRUCT and FIELD leaves an `@struct <test> <Rust name> <size> <align>` or
+ * `@field <Rust name> <offset>` line in the assembly, which
+ * `vulkan-bind-generator --layout` turns into tests/layout.rs. See
+ * generator/layout.sh.
+ */
+
+#include <stddef.h>
+#include <vulkan/vulkan_core.h>
+
+#define STRUCT(test, rust, c) \\
+    __asm__ volatile (\"\\n.ascii \\\"@struct \" #test \" \" #rust \" %c0 %c1\\\"\" \\
+                      : : \"i\" (sizeof(c)), \"i\" (_Alignof(c)))
+#define FIELD(c, field, rust) \\
+    __asm__ volatile (\"\\n.ascii \\\"@field \" #rust \" %c0\\\"\" : : \"i\" (offsetof(c, field)))
+
+void layout(void)
+{
+";
+
+const BOOL32: &str = "
+/// A Vulkan boolean. Implementations may write any non-zero value for true,
+/// so it is a `u32` rather than an enum; compare with `==` or convert to
+/// `bool` rather than matching on the raw value.
+#[repr(transparent)]
+#[derive(Copy, Clone, Default)]
+pub struct Bool32(pub u32);
+
+pub const FALSE: Bool32 = Bool32(0);
+pub const TRUE: Bool32 = Bool32(1);
+
+impl From<bool> for Bool32 {
+    #[inline]
+    fn from(value: bool) -> Bool32 { if value { TRUE } else { FALSE } }
+}
+
+impl From<Bool32> for bool {
+    #[inline]
+    fn from(value: Bool32) -> bool { value.0 != 0 }
+}
+
+impl PartialEq for Bool32 {
+    #[inline]
+    fn eq(&self, other: &Bool32) -> bool { bool::from(*self) == bool::from(*other) }
+}
+
+impl Eq for Bool32 { }
+
+impl ::std::hash::Hash for Bool32 {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) { bool::from(*self).hash(state) }
+}
+
+impl ::std::fmt::Debug for Bool32 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Debug::fmt(&bool::from(*self), f)
+    }
+}
+";
+
+const HANDLE_TRAIT: &str = "
+/// Operations shared by every handle type.
+pub trait Handle: Copy {
+    /// The object type of this handle, as used to name or report objects.
+    const OBJECT_TYPE: ::vk::ObjectType;
+
+    /// The handle as the `uint64_t` used by object names and debug reports.
+    fn as_raw(self) -> u64;
+
+    /// The inverse of `as_raw`.
+    fn from_raw(raw: u64) -> Self;
+}
+
+";
+
+const INSTANCE_FNS_LOAD: &str = "
+impl InstanceFns {
+    /// Resolves every instance-level command for `instance`, failing with the
+    /// names of those the implementation does not provide.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry`.
+    pub unsafe fn load(entry: &Entry, instance: Instance)
+            -> ::std::result::Result<InstanceFns, LoadingError> {
+        let fns = InstanceFns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
+
+const DEVICE_FNS_LOAD: &str = "
+impl DeviceFns {
+    /// Resolves every device-level command for `device`, failing with the
+    /// names of those the implementation does not provide.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device of the instance `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device)
+            -> ::std::result::Result<DeviceFns, LoadingError> {
+        let fns = DeviceFns::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
+
+const VERSION_INSTANCE_FNS_DOC: &str =
+    "Instance-level commands added in Vulkan {version}, kept apart from\n\
+     `InstanceFns` since older implementations do not provide them.";
+
+const VERSION_INSTANCE_FNS_LOAD: &str = "
+impl InstanceFnsV{suffix} {
+    /// Resolves the Vulkan {version} instance-level commands for `instance`,
+    /// which must have been created with an `apiVersion` of at least {version}.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry`.
+    pub unsafe fn load(entry: &Entry, instance: Instance)
+            -> ::std::result::Result<InstanceFnsV{suffix}, LoadingError> {
+        let fns = InstanceFnsV{suffix}::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
+
+const VERSION_DEVICE_FNS_DOC: &str =
+    "Device-level commands added in Vulkan {version}, kept apart from `DeviceFns`\n\
+     since older implementations do not provide them.";
+
+const VERSION_DEVICE_FNS_LOAD: &str = "
+impl DeviceFnsV{suffix} {
+    /// Resolves the Vulkan {version} device-level commands for `device`, whose
+    /// physical device must report an `apiVersion` of at least {version}.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device of the instance `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device)
+            -> ::std::result::Result<DeviceFnsV{suffix}, LoadingError> {
+        let fns = DeviceFnsV{suffix}::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
+
+const INSTANCE_EXT_LOAD: &str = "impl Fns {
+    /// Resolves the commands of this extension for `instance`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry` with `create_info`.
+    pub unsafe fn load(entry: &Entry, instance: Instance, create_info: &InstanceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
+
+const DEVICE_EXT_LOAD: &str = "impl Fns {
+    /// Resolves the commands of this extension for `device`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device created with `create_info`, on the instance
+    /// `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device, create_info: &DeviceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
+
+const PHYSICAL_DEVICE_EXT_LOAD: &str = "impl PhysicalDeviceFns {
+    /// Resolves the physical-device commands of this extension for `instance`.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry`.
+    pub unsafe fn load(entry: &Entry, instance: Instance)
+            -> ::std::result::Result<PhysicalDeviceFns, LoadingError> {
+        let fns = PhysicalDeviceFns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+";
@@ -0,0 +1,164 @@
+//! Generates the raw bindings in `src/vk` from the Khronos API registry.
+//!
+//! ```text
+//! cargo run -p vulkan-bind-generator -- [--check] [path/to/vk.xml] [output dir] [tests dir]
+//! cargo run -p vulkan-bind-generator -- --layout <dump dir> [tests dir]
+//! ```
+//!
+//! The registry defaults to `generator/vk.xml`, the output to `src/vk` and
+//! the tests directory, where `layout.c` goes, to `tests`. The checked-in
+//! output is generated from the vk.xml of Vulkan-Docs tag `v1.3.204`
+//! (`REGISTRY_TAG`), and other versions are refused. With `--check` nothing
+//! is written; the generator fails if any file would change.
+//!
+//! Every core version in the registry goes into `consts.rs`, `types.rs`,
+//! `enums.rs`, `flags.rs`, `structs.rs`, `builders.rs`, `unions.rs`,
+//! `pfns.rs`, `fns.rs` and `dispatch.rs`; the extensions listed in
+//! `gen::EXTENSIONS` get a module in `khr/` or `ext/`. Extensions which need
+//! platform or video headers are left to the hand-written modules next to
+//! the generated ones.
+//!
+//! `tests/layout.rs` comes from the C compiler rather than from the
+//! generator's reading of vk.xml: `generator/layout.sh` compiles
+//! `tests/layout.c` against the Vulkan headers for each ABI into
+//! `<dump dir>/{64,x86,32}.s`, and `--layout` turns those into the tests.
+//!
+//! The unit tests run the generator over `tests/registry.xml`, a few types
+//! and commands copied out of vk.xml.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod gen;
+mod registry;
+mod xml;
+#[cfg(test)]
+mod tests;
+
+use gen::Generator;
+use registry::Registry;
+
+/// The Vulkan-Docs tag whose `xml/vk.xml` the checked-in bindings come from.
+const REGISTRY_TAG: &str = "v1.3.204";
+/// `VK_HEADER_VERSION` in that vk.xml.
+const HEADER_VERSION: u32 = 204;
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    File::create(path)
+        .and_then(|mut f| f.write_all(contents.as_bytes()))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// The files in `files` whose contents on disk differ, or which are missing.
+fn stale(files: &[(PathBuf, String)]) -> Vec<&Path> {
+    files.iter()
+        .filter(|f| fs::read_to_string(&f.0).ok().as_ref() != Some(&f.1))
+        .map(|f| f.0.as_path())
+        .collect()
+}
+
+/// `tests/layout.rs` from the layout dumps in `dump_dir`.
+fn layout(dump_dir: &Path, tests_dir: &Path) -> Result<(), String> {
+    let mut dumps = Vec::new();
+    for abi in &["64", "x86", "32"] {
+        let path = dump_dir.join(format!("{}.s", abi));
+        let dump = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        dumps.push(dump);
+    }
+    write(&tests_dir.join("layout.rs"), &gen::layout_rs(&dumps)?)
+}
+
+fn run() -> Result<(), String> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(|a| &a[..]) == Some("--layout") {
+        let dump_dir = args.get(1).ok_or("--layout needs the directory of the dumps")?;
+        let tests_dir = args.get(2).map_or("tests", |d| &d[..]);
+        return layout(Path::new(dump_dir), Path::new(tests_dir));
+    }
+    let check = args.iter().any(|a| a == "--check");
+    args.retain(|a| a != "--check");
+    let mut args = args.into_iter();
+    let xml_path = args.next().unwrap_or_else(|| "generator/vk.xml".to_owned());
+    let out_dir = args.next().unwrap_or_else(|| "src/vk".to_owned());
+    let out_dir = Path::new(&out_dir);
+    let tests_dir = args.next().unwrap_or_else(|| "tests".to_owned());
+    let tests_dir = Path::new(&tests_dir);
+
+    let mut src = String::new();
+    File::open(&xml_path)
+        .and_then(|mut f| f.read_to_string(&mut src))
+        .map_err(|e| format!("{}: {} (download xml/vk.xml from tag {} of \
+                              https://github.com/KhronosGroup/Vulkan-Docs)",
+                             xml_path, e, REGISTRY_TAG))?;
+    let root = xml::parse(&src).map_err(|e| format!("{}: {}", xml_path, e))?;
+    let reg = Registry::from_xml(&root)?;
+    if reg.header_version != Some(HEADER_VERSION) {
+        let found = reg.header_version.map_or("missing".to_owned(), |v| v.to_string());
+        return Err(format!("{}: VK_HEADER_VERSION is {}, the bindings are generated from \
+                            the vk.xml of Vulkan-Docs tag {}",
+                           xml_path, found, REGISTRY_TAG));
+    }
+    let mut gen = Generator::new(&reg)?;
+
+    let mut files = vec![
+        (out_dir.join("consts.rs"), gen.consts_rs()),
+        (out_dir.join("types.rs"), gen.types_rs()),
+        (out_dir.join("enums.rs"), gen.enums_rs()),
+        (out_dir.join("flags.rs"), gen.flags_rs()),
+        (out_dir.join("structs.rs"), gen.structs_rs()),
+        (out_dir.join("builders.rs"), gen.builders_rs()),
+        (out_dir.join("unions.rs"), gen.unions_rs()),
+        (out_dir.join("pfns.rs"), gen.pfns_rs()),
+        (out_dir.join("fns.rs"), gen.fns_rs()),
+        (out_dir.join("dispatch.rs"), gen.dispatch_rs()),
+        (tests_dir.join("layout.c"), gen.layout_c()),
+    ];
+
+    for i in 0..gen.modules.len() {
+        let path = out_dir.join(&gen.modules[i].vendor).join(format!("{}.rs", gen.modules[i].name));
+        files.push((path, gen.module_rs(i)));
+    }
+
+    // Hand-written modules for skipped extensions stay listed in `mod.rs`.
+    for vendor in &["khr", "ext"] {
+        let existing: Vec<(String, String)> = reg.extensions.iter()
+            .filter_map(|e| gen::module_name(&e.name))
+            .filter(|(v, _)| v == vendor)
+            .filter_map(|(v, name)| {
+                let path = out_dir.join(v).join(format!("{}.rs", name));
+                fs::read_to_string(&path).ok().map(|src| (name, src))
+            })
+            .collect();
+        files.push((out_dir.join(vendor).join("mod.rs"), gen.vendor_mod_rs(vendor, &existing)));
+    }
+
+    for (name, reason) in &gen.skipped {
+        eprintln!("skipped {}: {}", name, reason);
+    }
+
+    if check {
+        let stale = stale(&files);
+        if !stale.is_empty() {
+            let names: Vec<_> = stale.iter().map(|p| p.display().to_string()).collect();
+            return Err(format!("out of date: {}", names.join(", ")));
+        }
+        return Ok(());
+    }
+    for (path, contents) in &files {
+        write(path, contents)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,552 @@
+// Typed view of the parts of `vk.xml` the generator uses
+
+use std::collections::HashMap;
+
+use xml::{Element, Node};
+
+/// A C declaration: `const char* const* ppEnabledLayerNames`.
+#[derive(Clone, Debug)]
+pub struct CType {
+    /// Base type, as spelled in C (`char`, `VkInstance`, ...).
+    pub name: String,
+    /// One entry per `*`, innermost first; `true` if the pointee is const.
+    pub pointers: Vec<bool>,
+    /// Array dimensions, either literals or API constant names.
+    pub array: Vec<String>,
+    /// Bitfield width, for the packed members of a few ray tracing structs.
+    pub bits: Option<u32>,
+    /// Whether the base type itself is const (only matters for arrays).
+    pub is_const: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub ty: CType,
+    /// The only legal value, as for `sType`.
+    pub values: Option<String>,
+    /// Length of the array this points to: another member, `null-terminated`
+    /// or a formula, with `altlen` spelling the formula in C.
+    pub len: Option<String>,
+    pub altlen: Option<String>,
+    pub noautovalidity: bool,
+}
+
+#[derive(Clone, Debug)]
+pub enum TypeKind {
+    /// `VK_DEFINE_HANDLE` handles are dispatchable; the others are 64-bit.
+    Handle { dispatchable: bool, object_type: Option<String> },
+    Enum,
+    Bitmask { bits: Option<String>, wide: bool },
+    /// `extends` lists the structs this one may be chained onto.
+    Struct { members: Vec<Member>, union: bool, extends: Vec<String> },
+    FuncPointer { ret: CType, params: Vec<Member> },
+    BaseType(Option<CType>),
+    /// Anything provided by a platform or video header instead of the registry.
+    External,
+    Alias(String),
+    Other,
+}
+
+#[derive(Clone, Debug)]
+pub struct Type {
+    pub name: String,
+    pub kind: TypeKind,
+}
+
+#[derive(Clone, Debug)]
+pub struct EnumValue {
+    pub name: String,
+    pub value: Value,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    /// A bit position, for flags.
+    Bit(u32),
+    /// An explicit value, kept as spelled in the registry (`0x3`).
+    Literal(i64, String),
+    Alias(String),
+}
+
+#[derive(Debug)]
+pub struct Enums {
+    pub bitmask: bool,
+    pub values: Vec<EnumValue>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Command {
+    pub ret: CType,
+    pub params: Vec<Member>,
+    pub alias: Option<String>,
+}
+
+#[derive(Default, Debug)]
+pub struct Require {
+    pub types: Vec<String>,
+    pub commands: Vec<String>,
+    /// Values added to existing enums, as `(extended enum, value)`.
+    pub enum_values: Vec<(String, EnumValue)>,
+    /// Values which stand on their own, like `VK_KHR_SURFACE_SPEC_VERSION`.
+    pub constants: Vec<(String, String)>,
+    /// Only applies when another version or extension is also present.
+    pub conditional: bool,
+}
+
+#[derive(Debug)]
+pub struct Feature {
+    pub name: String,
+    pub number: String,
+    pub requires: Vec<Require>,
+}
+
+#[derive(Debug)]
+pub struct Extension {
+    pub name: String,
+    /// `instance` or `device`.
+    pub kind: String,
+    pub platform: Option<String>,
+    pub requires: Vec<Require>,
+}
+
+#[derive(Debug)]
+pub struct Registry {
+    pub tags: Vec<String>,
+    pub types: Vec<Type>,
+    pub type_index: HashMap<String, usize>,
+    pub enums: Vec<Enums>,
+    pub enum_index: HashMap<String, usize>,
+    /// API constants, as `(name, C type, C value)`.
+    pub constants: Vec<(String, Option<String>, String)>,
+    pub commands: Vec<Command>,
+    pub command_index: HashMap<String, usize>,
+    pub features: Vec<Feature>,
+    pub extensions: Vec<Extension>,
+    pub header_version: Option<u32>,
+}
+
+/// Whether an element applies to the regular Vulkan API, as opposed to
+/// Vulkan SC only.
+fn for_vulkan(e: &Element) -> bool {
+    match e.attr("api") {
+        Some(api) => api.split(',').any(|a| a == "vulkan"),
+        None => true,
+    }
+}
+
+/// Whether an element is an old spelling kept only for source compatibility,
+/// like `VK_COLORSPACE_SRGB_NONLINEAR_KHR`.
+fn deprecated(e: &Element) -> bool {
+    e.attr("deprecated").is_some()
+}
+
+impl Registry {
+    pub fn from_xml(root: &Element) -> Result<Registry, String> {
+        let mut reg = Registry {
+            tags: Vec::new(),
+            types: Vec::new(),
+            type_index: HashMap::new(),
+            enums: Vec::new(),
+            enum_index: HashMap::new(),
+            constants: Vec::new(),
+            commands: Vec::new(),
+            command_index: HashMap::new(),
+            features: Vec::new(),
+            extensions: Vec::new(),
+            header_version: None,
+        };
+
+        for section in root.elements() {
+            match &section.name[..] {
+                "tags" => {
+                    for tag in section.named("tag") {
+                        if let Some(name) = tag.attr("name") {
+                            reg.tags.push(name.to_owned());
+                        }
+                    }
+                }
+                "types" => {
+                    for ty in section.named("type").filter(|t| for_vulkan(t)) {
+                        reg.parse_type(ty)?;
+                    }
+                }
+                "enums" => reg.parse_enums(section)?,
+                "commands" => {
+                    for cmd in section.named("command").filter(|c| for_vulkan(c)) {
+                        reg.parse_command(cmd)?;
+                    }
+                }
+                "feature" if for_vulkan(section) => {
+                    let name = section.attr("name").unwrap_or("").to_owned();
+                    let number = section.attr("number").unwrap_or("").to_owned();
+                    let requires = parse_requires(section, None)?;
+                    reg.features.push(Feature { name, number, requires });
+                }
+                "extensions" => {
+                    for ext in section.named("extension") {
+                        if let Some(ext) = parse_extension(ext)? {
+                            reg.extensions.push(ext);
+                        }
+                    }
+                }
+                _ => { }
+            }
+        }
+        Ok(reg)
+    }
+
+    pub fn ty(&self, name: &str) -> Option<&Type> {
+        self.type_index.get(name).map(|&i| &self.types[i])
+    }
+
+    pub fn command(&self, name: &str) -> Option<&Command> {
+        self.command_index.get(name).map(|&i| &self.commands[i])
+    }
+
+    pub fn enums(&self, name: &str) -> Option<&Enums> {
+        self.enum_index.get(name).map(|&i| &self.enums[i])
+    }
+
+    /// Follows type aliases to the type which is actually defined.
+    pub fn resolve<'a>(&'a self, mut name: &'a str) -> &'a str {
+        while let Some(&Type { kind: TypeKind::Alias(ref target), .. }) = self.ty(name) {
+            name = target;
+        }
+        name
+    }
+
+    /// Signature of `name`, following command aliases.
+    pub fn signature(&self, name: &str) -> Option<&Command> {
+        let mut cmd = self.command(name)?;
+        while let Some(ref alias) = cmd.alias {
+            cmd = self.command(alias)?;
+        }
+        Some(cmd)
+    }
+
+    fn add_type(&mut self, ty: Type) {
+        // Later definitions of the same name are for other APIs; keep the first.
+        if !self.type_index.contains_key(&ty.name) {
+            self.type_index.insert(ty.name.clone(), self.types.len());
+            self.types.push(ty);
+        }
+    }
+
+    fn parse_type(&mut self, e: &Element) -> Result<(), String> {
+        let name = match e.attr("name") {
+            Some(name) => name.to_owned(),
+            None => e.child("name").map(|n| n.text()).unwrap_or_default(),
+        };
+        if let Some(alias) = e.attr("alias") {
+            self.add_type(Type { name, kind: TypeKind::Alias(alias.to_owned()) });
+            return Ok(());
+        }
+        let kind = match e.attr("category") {
+            Some("handle") => {
+                let dispatchable = e.child("type").map(|t| t.text()) == Some("VK_DEFINE_HANDLE".to_owned());
+                let object_type = e.attr("objtypeenum").map(|s| s.to_owned());
+                TypeKind::Handle { dispatchable, object_type }
+            }
+            Some("enum") => TypeKind::Enum,
+            Some("bitmask") => {
+                let bits = e.attr("requires").or(e.attr("bitvalues")).map(|s| s.to_owned());
+                let wide = e.child("type").map(|t| t.text()) == Some("VkFlags64".to_owned());
+                TypeKind::Bitmask { bits, wide }
+            }
+            Some(category @ "struct") | Some(category @ "union") => {
+                let mut members = Vec::new();
+                for m in e.named("member").filter(|m| for_vulkan(m)) {
+                    members.push(parse_member(m)?);
+                }
+                let extends = e.attr("structextends").map_or(Vec::new(), |s| {
+                    s.split(',').map(|s| s.to_owned()).collect()
+                });
+                TypeKind::Struct { members, union: category == "union", extends }
+            }
+            Some("funcpointer") => {
+                let (ret, params) = parse_funcpointer(e)?;
+                TypeKind::FuncPointer { ret, params }
+            }
+            Some("basetype") => {
+                let tokens = tokenize(e);
+                if tokens.first().map(|t| &t[..]) == Some("typedef") {
+                    let (ty, _) = parse_decl(&tokens[1..])?;
+                    TypeKind::BaseType(Some(ty))
+                } else {
+                    TypeKind::BaseType(None)
+                }
+            }
+            Some("define") => {
+                if name == "VK_HEADER_VERSION" {
+                    let text = e.text();
+                    let version = text.split_whitespace().filter_map(|w| w.parse().ok()).next_back();
+                    self.header_version = version;
+                }
+                TypeKind::Other
+            }
+            Some("include") => TypeKind::Other,
+            None if e.attr("requires").is_some() => TypeKind::External,
+            _ => TypeKind::Other,
+        };
+        self.add_type(Type { name, kind });
+        Ok(())
+    }
+
+    fn parse_enums(&mut self, e: &Element) -> Result<(), String> {
+        let name = e.attr("name").unwrap_or("").to_owned();
+        if name == "API Constants" {
+            for c in e.named("enum") {
+                let cname = c.attr("name").unwrap_or("").to_owned();
+                if let Some(value) = c.attr("value") {
+                    self.constants.push((cname, c.attr("type").map(|s| s.to_owned()),
+                                         value.to_owned()));
+                } else if let Some(alias) = c.attr("alias") {
+                    if let Some((_, ty, value)) = self.constants.iter().find(|c| c.0 == alias) {
+                        let (ty, value) = (ty.clone(), value.clone());
+                        self.constants.push((cname, ty, value));
+                    }
+                }
+            }
+            return Ok(());
+        }
+        let bitmask = e.attr("type") == Some("bitmask");
+        let mut values = Vec::new();
+        for v in e.named("enum").filter(|v| for_vulkan(v) && !deprecated(v)) {
+            values.push(parse_value(v, None)?);
+        }
+        self.enum_index.insert(name, self.enums.len());
+        self.enums.push(Enums { bitmask, values });
+        Ok(())
+    }
+
+    fn parse_command(&mut self, e: &Element) -> Result<(), String> {
+        if let Some(alias) = e.attr("alias") {
+            let name = e.attr("name").unwrap_or("").to_owned();
+            let cmd = Command {
+                ret: void(),
+                params: Vec::new(),
+                alias: Some(alias.to_owned()),
+            };
+            self.command_index.insert(name, self.commands.len());
+            self.commands.push(cmd);
+            return Ok(());
+        }
+        let proto = e.child("proto").ok_or("command without <proto>")?;
+        let (ret, name) = parse_decl(&tokenize(proto))?;
+        if self.command_index.contains_key(&name) {
+            return Ok(());
+        }
+        let mut params = Vec::new();
+        for p in e.named("param").filter(|p| for_vulkan(p)) {
+            params.push(parse_member(p)?);
+        }
+        self.command_index.insert(name, self.commands.len());
+        self.commands.push(Command { ret, params, alias: None });
+        Ok(())
+    }
+}
+
+fn void() -> CType {
+    CType { name: "void".to_owned(), pointers: Vec::new(), array: Vec::new(), bits: None, is_const: false }
+}
+
+fn parse_extension(e: &Element) -> Result<Option<Extension>, String> {
+    let supported = e.attr("supported").unwrap_or("");
+    if !supported.split(',').any(|s| s == "vulkan") {
+        return Ok(None);
+    }
+    let number = e.attr("number").and_then(|n| n.parse().ok()).ok_or("extension without number")?;
+    let requires = parse_requires(e, Some(number))?;
+    Ok(Some(Extension {
+        name: e.attr("name").unwrap_or("").to_owned(),
+        kind: e.attr("type").unwrap_or("device").to_owned(),
+        platform: e.attr("platform").map(|s| s.to_owned()),
+        requires,
+    }))
+}
+
+fn parse_requires(e: &Element, extnumber: Option<i64>) -> Result<Vec<Require>, String> {
+    let mut requires = Vec::new();
+    for r in e.named("require").filter(|r| for_vulkan(r)) {
+        let mut req = Require {
+            conditional: r.attr("feature").is_some() || r.attr("extension").is_some() ||
+                         r.attr("depends").is_some(),
+            ..Require::default()
+        };
+        for item in r.elements().filter(|i| for_vulkan(i) && !deprecated(i)) {
+            let name = item.attr("name").unwrap_or("").to_owned();
+            match &item.name[..] {
+                "type" => req.types.push(name),
+                "command" => req.commands.push(name),
+                "enum" => {
+                    if let Some(extends) = item.attr("extends") {
+                        req.enum_values.push((extends.to_owned(), parse_value(item, extnumber)?));
+                    } else if let Some(value) = item.attr("value") {
+                        req.constants.push((name, value.to_owned()));
+                    }
+                }
+                _ => { }
+            }
+        }
+        requires.push(req);
+    }
+    Ok(requires)
+}
+
+fn parse_int(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (neg, s) = match s.strip_prefix('-') { Some(s) => (true, s), None => (false, s) };
+    let s = s.trim_end_matches(['U', 'L']);
+    let v = if s.starts_with("0x") || s.starts_with("0X") {
+        i64::from_str_radix(&s[2..], 16).ok()?
+    } else {
+        s.parse().ok()?
+    };
+    Some(if neg { -v } else { v })
+}
+
+fn parse_value(e: &Element, extnumber: Option<i64>) -> Result<EnumValue, String> {
+    let name = e.attr("name").unwrap_or("").to_owned();
+    let value = if let Some(alias) = e.attr("alias") {
+        Value::Alias(alias.to_owned())
+    } else if let Some(bit) = e.attr("bitpos") {
+        Value::Bit(bit.parse().map_err(|_| format!("bad bitpos for {}", name))?)
+    } else if let Some(offset) = e.attr("offset") {
+        let offset = parse_int(offset).ok_or_else(|| format!("bad offset for {}", name))?;
+        let ext = e.attr("extnumber").and_then(parse_int).or(extnumber)
+                   .ok_or_else(|| format!("no extension number for {}", name))?;
+        let v = 1000000000 + (ext - 1) * 1000 + offset;
+        Value::Int(if e.attr("dir") == Some("-") { -v } else { v })
+    } else if let Some(value) = e.attr("value") {
+        let v = parse_int(value).ok_or_else(|| format!("bad value for {}", name))?;
+        if value.starts_with("0x") { Value::Literal(v, value.to_owned()) } else { Value::Int(v) }
+    } else {
+        return Err(format!("enum {} has no value", name));
+    };
+    Ok(EnumValue { name, value })
+}
+
+/// Splits the mixed content of a declaration into C tokens.
+fn tokenize(e: &Element) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for node in &e.children {
+        match *node {
+            Node::Element(ref child) => {
+                if child.name != "comment" {
+                    tokens.push(child.text().trim().to_owned());
+                }
+            }
+            Node::Text(ref text) => {
+                let mut word = String::new();
+                for c in text.chars() {
+                    if c.is_alphanumeric() || c == '_' {
+                        word.push(c);
+                        continue;
+                    }
+                    if !word.is_empty() {
+                        tokens.push(word.clone());
+                        word.clear();
+                    }
+                    if !c.is_whitespace() {
+                        tokens.push(c.to_string());
+                    }
+                }
+                if !word.is_empty() {
+                    tokens.push(word);
+                }
+            }
+        }
+    }
+    tokens
+}
+
+/// Parses `[const] [struct] type [*[const]]* name [\[dim\]]* [: bits]`.
+fn parse_decl(tokens: &[String]) -> Result<(CType, String), String> {
+    let mut it = tokens.iter().map(|t| &t[..]).peekable();
+    let mut is_const = false;
+    let mut name = None;
+    while let Some(&t) = it.peek() {
+        match t {
+            "const" => is_const = true,
+            "struct" | "typedef" => { }
+            _ => {
+                name = Some(t.to_owned());
+                it.next();
+                break;
+            }
+        }
+        it.next();
+    }
+    let mut ty = CType {
+        name: name.ok_or_else(|| format!("no type in `{}`", tokens.join(" ")))?,
+        pointers: Vec::new(),
+        array: Vec::new(),
+        bits: None,
+        is_const,
+    };
+    let mut pointee_const = is_const;
+    let mut decl_name = String::new();
+    while let Some(t) = it.next() {
+        match t {
+            "*" => {
+                ty.pointers.push(pointee_const);
+                pointee_const = false;
+            }
+            "const" => pointee_const = true,
+            "[" => {
+                let dim = it.next().ok_or("unterminated array")?;
+                ty.array.push(dim.to_owned());
+                it.next();
+            }
+            ":" => {
+                let bits = it.next().ok_or("missing bitfield width")?;
+                ty.bits = Some(bits.parse().map_err(|_| "bad bitfield width")?);
+            }
+            ";" => { }
+            _ => decl_name = t.to_owned(),
+        }
+    }
+    Ok((ty, decl_name))
+}
+
+fn parse_member(e: &Element) -> Result<Member, String> {
+    let (ty, name) = parse_decl(&tokenize(e))?;
+    let attr = |name| e.attr(name).map(|s| s.to_owned());
+    Ok(Member {
+        name,
+        ty,
+        values: attr("values"),
+        len: attr("len"),
+        altlen: attr("altlen"),
+        noautovalidity: e.attr("noautovalidity") == Some("true"),
+    })
+}
+
+fn parse_funcpointer(e: &Element) -> Result<(CType, Vec<Member>), String> {
+    // Newer registries describe function pointers like commands.
+    if let Some(proto) = e.child("proto") {
+        let (ret, _) = parse_decl(&tokenize(proto))?;
+        let mut params = Vec::new();
+        for p in e.named("param") {
+            params.push(parse_member(p)?);
+        }
+        return Ok((ret, params));
+    }
+
+    // typedef RET (VKAPI_PTR *NAME)(PARAMS);
+    let tokens = tokenize(e);
+    let open = tokens.iter().position(|t| t == "(").ok_or("malformed funcpointer")?;
+    let (ret, _) = parse_decl(&tokens[1..open])?;
+    let close = open + tokens[open..].iter().position(|t| t == ")").ok_or("malformed funcpointer")?;
+    let args = &tokens[close + 2..tokens.len() - 2];
+    let mut params = Vec::new();
+    if !(args.len() == 1 && args[0] == "void") {
+        for arg in args.split(|t| t == ",") {
+            let (ty, name) = parse_decl(arg)?;
+            params.push(Member { name, ty, values: None, len: None, altlen: None, noautovalidity: false });
+        }
+    }
+    Ok((ret, params))
+}
@@ -0,0 +1,192 @@
+// Runs the generator over `tests/registry.xml`, a small subset of vk.xml
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use gen::{self, Generator};
+use registry::{Registry, TypeKind, Value};
+use stale;
+use xml;
+
+const REGISTRY: &str = include_str!("../tests/registry.xml");
+
+fn registry() -> Registry {
+    let root = xml::parse(REGISTRY).unwrap();
+    Registry::from_xml(&root).unwrap()
+}
+
+#[test]
+fn xml_entities_and_text() {
+    let root = xml::parse("<?xml version=\"1.0\"?><!-- c --><a x=\"&quot;1&quot;\">\
+                           b<c>&lt;d&gt;</c></a>").unwrap();
+    assert_eq!(root.name, "a");
+    assert_eq!(root.attr("x"), Some("\"1\""));
+    assert_eq!(root.text(), "b<d>");
+    assert_eq!(root.child("c").map(|c| c.text()), Some("<d>".to_owned()));
+}
+
+#[test]
+fn xml_error_line() {
+    let err = xml::parse("<a>\n<b>\n</a>").err().unwrap();
+    assert_eq!(err.line, 3);
+}
+
+#[test]
+fn struct_members() {
+    let reg = registry();
+    let members = match reg.ty("VkInstanceCreateInfo").unwrap().kind {
+        TypeKind::Struct { ref members, union: false, .. } => members,
+        ref kind => panic!("{:?}", kind),
+    };
+    assert_eq!(members[0].values.as_ref().unwrap(), "VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO");
+    let names = &members[5];
+    assert_eq!(names.name, "ppEnabledLayerNames");
+    assert_eq!(names.ty.name, "char");
+    assert_eq!(names.ty.pointers, [true, true]);
+    assert_eq!(names.len.as_ref().unwrap(), "enabledLayerCount,null-terminated");
+
+    let props = match reg.ty("VkPhysicalDeviceProperties").unwrap().kind {
+        TypeKind::Struct { ref members, .. } => members,
+        ref kind => panic!("{:?}", kind),
+    };
+    assert_eq!(props[1].ty.array, ["VK_MAX_PHYSICAL_DEVICE_NAME_SIZE"]);
+    assert_eq!(props[2].ty.array, ["4"]);
+}
+
+#[test]
+fn extension_enum_offsets() {
+    let reg = registry();
+    let surface = reg.extensions.iter().find(|e| e.name == "VK_KHR_surface").unwrap();
+    assert_eq!(surface.kind, "instance");
+    let lost = surface.requires.iter()
+        .flat_map(|r| &r.enum_values)
+        .find(|&(_, v)| v.name == "VK_ERROR_SURFACE_LOST_KHR")
+        .unwrap();
+    assert_eq!(lost.0, "VkResult");
+    assert_eq!(lost.1.value, Value::Int(-1000000000));
+}
+
+#[test]
+fn layout_c() {
+    let reg = registry();
+    let gen = Generator::new(&reg).unwrap();
+    let layout = gen.layout_c();
+    assert!(layout.contains("#include <vulkan/vulkan_core.h>"));
+    assert!(layout.contains("    STRUCT(structs, vk::Extent2D, VkExtent2D);\n    \
+                             FIELD(VkExtent2D, width, width);\n    \
+                             FIELD(VkExtent2D, height, height);\n"));
+    assert!(layout.contains("    STRUCT(unions, vk::ClearValue, VkClearValue);\n"));
+    assert!(layout.contains("    STRUCT(khr_swapchain, vk::khr::swapchain::CreateInfo, \
+                             VkSwapchainCreateInfoKHR);\n"));
+    assert!(layout.ends_with("}\n"));
+}
+
+/// What gcc -S makes of `STRUCT` and `FIELD` in `tests/layout.c`.
+fn layout_dump(application_info: (u64, u64, u64)) -> String {
+    let (size, align, pointer) = application_info;
+    format!("layout:\n\
+             #APP\n\
+             # 5 \"tests/layout.c\" 1\n\
+             \t\n.ascii \"@struct structs vk::Extent2D 8 4\"\n\
+             \t\n.ascii \"@field width 0\"\n\
+             \t\n.ascii \"@field height 4\"\n\
+             \t\n.ascii \"@struct structs vk::ApplicationInfo {} {}\"\n\
+             \t\n.ascii \"@field sType 0\"\n\
+             \t\n.ascii \"@field pNext {}\"\n\
+             \t\n.ascii \"@struct unions vk::ClearValue 16 4\"\n\
+             \t\n.ascii \"@field color 0\"\n\
+             #NO_APP\n",
+            size, align, pointer)
+}
+
+#[test]
+fn layout_rs_from_dumps() {
+    let dumps = [layout_dump((16, 8, 8)), layout_dump((8, 4, 4)), layout_dump((8, 4, 4))];
+    let layout = gen::layout_rs(&dumps).unwrap();
+    assert!(layout.contains("\n#[test]\nfn structs() {\n    \
+                             check_layout!(vk::Extent2D, 8, 4, width: 0, height: 4);\n"));
+    assert!(layout.contains("    #[cfg(target_pointer_width = \"64\")]\n    \
+                             check_layout!(vk::ApplicationInfo, 16, 8, sType: 0, pNext: 8);\n    \
+                             #[cfg(target_pointer_width = \"32\")]\n    \
+                             check_layout!(vk::ApplicationInfo, 8, 4, sType: 0, pNext: 4);\n}\n"));
+    assert!(layout.contains("\n#[test]\nfn unions() {\n    \
+                             check_layout!(vk::ClearValue, 16, 4, color: 0);\n}\n"));
+
+    let short = "\t\n.ascii \"@struct structs vk::Extent2D 8 4\"\n".to_owned();
+    assert!(gen::layout_rs(&[dumps[0].clone(), dumps[1].clone(), short]).is_err());
+    assert!(gen::layout_rs(&dumps[..2]).is_err());
+}
+
+#[test]
+fn struct_derives() {
+    let reg = registry();
+    let gen = Generator::new(&reg).unwrap();
+    let structs = gen.structs_rs();
+    assert!(structs.contains("#[derive(Debug, Copy, Clone, Default)]\npub struct Extent2D {"));
+    assert!(structs.contains("#[derive(Copy, Clone)]\npub struct ApplicationInfo {"));
+}
+
+#[test]
+fn info_builders() {
+    let reg = registry();
+    let gen = Generator::new(&reg).unwrap();
+    let builders = gen.builders_rs();
+    assert!(builders.contains("builder!{InstanceCreateInfo, InstanceCreateInfoBuilder}"));
+    assert!(builders.contains("builder!{DeviceQueueInfo2, DeviceQueueInfo2Builder}"));
+    assert!(!builders.contains("builder!{Extent2D"));
+}
+
+#[test]
+fn union_constructors() {
+    let reg = registry();
+    let gen = Generator::new(&reg).unwrap();
+    let unions = gen.unions_rs();
+    assert!(unions.contains("union_bits!{ClearColorValue, [u32; 4usize]}"));
+    assert!(unions.contains("union_bits!{ClearValue, [u32; 4usize]}"));
+    assert!(unions.contains("    pub fn depth_stencil(depth: f32, stencil: u32) -> Self {\n        \
+                             let mut value = Self::default();\n        \
+                             value.depthStencil = ClearDepthStencilValue { depth, \
+                             stencil };\n        value\n    }"));
+    assert!(unions.contains("value.color = ClearColorValue::uint32(uint32);"));
+}
+
+#[test]
+fn extension_modules() {
+    let reg = registry();
+    let mut gen = Generator::new(&reg).unwrap();
+    let names: Vec<_> = gen.modules.iter().map(|m| format!("{}::{}", m.vendor, m.name)).collect();
+    // VK_KHR_shader_draw_parameters isn't in `gen::EXTENSIONS`.
+    assert_eq!(names, ["khr::surface", "khr::swapchain"]);
+    let surface = gen.module_rs(0);
+    assert!(surface.contains("pub struct Capabilities {"));
+    assert!(surface.contains("ERROR_SURFACE_LOST"));
+}
+
+#[test]
+fn names() {
+    assert_eq!(gen::module_name("VK_KHR_surface"),
+               Some(("khr".to_owned(), "surface".to_owned())));
+    assert_eq!(gen::module_name("VK_EXT_4444_formats"),
+               Some(("ext".to_owned(), "_4444_formats".to_owned())));
+    assert_eq!(gen::module_name("VK_NV_ray_tracing"), None);
+    assert_eq!(gen::camel_words("PhysicalDevice8BitStorageFeatures"),
+               ["PHYSICAL", "DEVICE8", "BIT", "STORAGE", "FEATURES"]);
+    assert_eq!(gen::camel_words("RGBAFormat"), ["RGBA", "FORMAT"]);
+    assert_eq!(gen::escape("type"), "_type");
+    assert_eq!(gen::escape("width"), "width");
+}
+
+#[test]
+fn check_finds_stale_files() {
+    let dir = env::temp_dir().join(format!("vulkan-bind-generator-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let files: Vec<(PathBuf, String)> = ["same.rs", "changed.rs", "missing.rs"].iter()
+        .map(|name| (dir.join(name), format!("// {}\n", name)))
+        .collect();
+    fs::write(&files[0].0, &files[0].1).unwrap();
+    fs::write(&files[1].0, "// old\n").unwrap();
+    let _ = fs::remove_file(&files[2].0);
+    assert_eq!(stale(&files), [files[1].0.as_path(), files[2].0.as_path()]);
+    fs::remove_dir_all(&dir).unwrap();
+}
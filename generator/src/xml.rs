@@ -0,0 +1,244 @@
+// Minimal XML reader, covering the subset used by the Khronos registry
+
+use std::fmt;
+
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+pub struct Element {
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs.iter().find(|&(n, _)| n == name).map(|(_, v)| &v[..])
+    }
+
+    /// Child elements, in document order.
+    pub fn elements<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Element> + 'a> {
+        Box::new(self.children.iter().filter_map(|node| match *node {
+            Node::Element(ref e) => Some(e),
+            Node::Text(_) => None,
+        }))
+    }
+
+    /// Child elements called `name`.
+    pub fn named<'a>(&'a self, name: &'a str) -> Box<dyn Iterator<Item = &'a Element> + 'a> {
+        Box::new(self.elements().filter(move |e| e.name == name))
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// Concatenated text of this element and all of its descendants.
+    pub fn text(&self) -> String {
+        let mut out = String::new();
+        self.collect_text(&mut out);
+        out
+    }
+
+    fn collect_text(&self, out: &mut String) {
+        for node in &self.children {
+            match *node {
+                Node::Element(ref e) => e.collect_text(out),
+                Node::Text(ref t) => out.push_str(t),
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Error {
+    pub line: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.msg)
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+/// Parses `src` and returns its root element.
+pub fn parse(src: &str) -> Result<Element, Error> {
+    let mut p = Parser { src, pos: 0 };
+    loop {
+        p.skip_whitespace();
+        if p.eat("<?") {
+            p.skip_past("?>")?;
+        } else if p.eat("<!--") {
+            p.skip_past("-->")?;
+        } else if p.eat("<!") {
+            p.skip_past(">")?;
+        } else if p.rest().starts_with('<') {
+            return p.element();
+        } else {
+            return Err(p.error("expected root element"));
+        }
+    }
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn error(&self, msg: &str) -> Error {
+        Error {
+            line: self.src[..self.pos].matches('\n').count() + 1,
+            msg: msg.to_owned(),
+        }
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), Error> {
+        if self.eat(s) { Ok(()) } else { Err(self.error(&format!("expected `{}`", s))) }
+    }
+
+    fn skip_past(&mut self, s: &str) -> Result<(), Error> {
+        match self.rest().find(s) {
+            Some(i) => {
+                self.pos += i + s.len();
+                Ok(())
+            }
+            None => Err(self.error(&format!("unterminated, expected `{}`", s))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let trimmed = self.rest().trim_start();
+        self.pos = self.src.len() - trimmed.len();
+    }
+
+    fn name(&mut self) -> Result<String, Error> {
+        let len = self.rest()
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-' || c == ':' || c == '.'))
+            .unwrap_or(self.rest().len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        let name = self.rest()[..len].to_owned();
+        self.pos += len;
+        Ok(name)
+    }
+
+    fn element(&mut self) -> Result<Element, Error> {
+        self.expect("<")?;
+        let name = self.name()?;
+        let mut attrs = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.eat("/>") {
+                return Ok(Element { name, attrs, children: Vec::new() });
+            }
+            if self.eat(">") {
+                break;
+            }
+            let attr = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = if self.eat("\"") {
+                "\""
+            } else {
+                self.expect("'")?;
+                "'"
+            };
+            let end = match self.rest().find(quote) {
+                Some(end) => end,
+                None => return Err(self.error("unterminated attribute value")),
+            };
+            let value = unescape(&self.rest()[..end]);
+            self.pos += end + 1;
+            attrs.push((attr, value));
+        }
+
+        let mut children = Vec::new();
+        loop {
+            if self.eat("</") {
+                let close = self.name()?;
+                if close != name {
+                    return Err(self.error(&format!("`</{}>` does not close `<{}>`", close, name)));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(Element { name, attrs, children });
+            } else if self.eat("<!--") {
+                self.skip_past("-->")?;
+            } else if self.eat("<![CDATA[") {
+                let end = match self.rest().find("]]>") {
+                    Some(end) => end,
+                    None => return Err(self.error("unterminated CDATA section")),
+                };
+                children.push(Node::Text(self.rest()[..end].to_owned()));
+                self.pos += end + 3;
+            } else if self.rest().starts_with('<') {
+                children.push(Node::Element(self.element()?));
+            } else if self.rest().is_empty() {
+                return Err(self.error(&format!("unclosed `<{}>`", name)));
+            } else {
+                let end = self.rest().find('<').unwrap_or(self.rest().len());
+                children.push(Node::Text(unescape(&self.rest()[..end])));
+                self.pos += end;
+            }
+        }
+    }
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let semi = match rest.find(';') {
+            Some(semi) => semi,
+            None => break,
+        };
+        let entity = &rest[1..semi];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ if entity.starts_with("#x") => {
+                u32::from_str_radix(&entity[2..], 16).ok().and_then(::std::char::from_u32)
+            }
+            _ if entity.starts_with('#') => {
+                entity[1..].parse().ok().and_then(::std::char::from_u32)
+            }
+            _ => None,
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
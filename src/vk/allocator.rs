@@ -0,0 +1,458 @@
+// Sub-allocation of device memory out of large blocks
+
+use std::cmp;
+use std::convert::TryFrom;
+use std::os::raw::c_void;
+use std::ptr::{null, null_mut};
+
+use vk::*;
+
+/// Size of the blocks an `Allocator` carves up, unless the heap is small.
+pub const DEFAULT_BLOCK_SIZE: DeviceSize = 256 << 20;
+
+/// Smallest block size `Allocator::with_block_size` accepts.
+const MIN_BLOCK_SIZE: DeviceSize = 64 << 10;
+
+/// What an `Allocator` should allocate memory for.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct AllocationRequest {
+    /// Properties the memory type must have, such as `HOST_VISIBLE`.
+    pub required: MemoryPropertyFlags,
+    /// Properties to prefer among the types with the required ones.
+    pub preferred: MemoryPropertyFlags,
+    /// Whether the memory is for a buffer or a linearly tiled image, rather
+    /// than an optimally tiled image. The two kinds are kept
+    /// `bufferImageGranularity` apart within a block.
+    pub linear: bool,
+    /// Gives the resource a `DeviceMemory` of its own. Resources larger than
+    /// half a block get one anyway, as do those which ask for one through
+    /// `MemoryDedicatedRequirements`; see
+    /// `Allocator::with_dedicated_allocation`.
+    pub dedicated: bool,
+}
+
+/// A range of device memory from `Allocator::allocate`, which must be given
+/// back to the same allocator with `Allocator::free`.
+#[derive(Debug)]
+pub struct Allocation {
+    memory: DeviceMemory,
+    offset: DeviceSize,
+    size: DeviceSize,
+    memory_type: u32,
+}
+
+impl Allocation {
+    /// The memory to bind, at `offset()`.
+    #[inline]
+    pub fn memory(&self) -> DeviceMemory { self.memory }
+
+    #[inline]
+    pub fn offset(&self) -> DeviceSize { self.offset }
+
+    #[inline]
+    pub fn size(&self) -> DeviceSize { self.size }
+
+    #[inline]
+    pub fn memory_type(&self) -> u32 { self.memory_type }
+}
+
+/// Memory use of one heap, from `Allocator::heap_stats`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// `DeviceMemory` objects, including dedicated ones.
+    pub block_count: u32,
+    pub dedicated_count: u32,
+    pub allocation_count: u32,
+    /// Bytes in `DeviceMemory` objects.
+    pub reserved: DeviceSize,
+    /// Bytes handed out, not counting alignment padding.
+    pub used: DeviceSize,
+    /// `MemoryHeap::size`.
+    pub size: DeviceSize,
+}
+
+#[derive(Debug)]
+struct Range {
+    offset: DeviceSize,
+    size: DeviceSize,
+    linear: bool,
+}
+
+#[derive(Debug)]
+struct Block {
+    memory: DeviceMemory,
+    memory_type: u32,
+    size: DeviceSize,
+    dedicated: bool,
+    /// Sorted by offset.
+    ranges: Vec<Range>,
+    mapping: *mut u8,
+}
+
+/// Hands out ranges of a few large `DeviceMemory` blocks, so that many
+/// resources share one allocation, and picks memory types by property.
+pub struct Allocator {
+    fns: DeviceFns,
+    /// For `vkGet*MemoryRequirements2`, if dedicated allocations are named.
+    fns_v1_1: Option<DeviceFnsV1_1>,
+    device: Device,
+    memory_properties: PhysicalDeviceMemoryProperties,
+    granularity: DeviceSize,
+    max_allocations: u32,
+    block_size: DeviceSize,
+    blocks: Vec<Block>,
+}
+
+// The mapping pointers are only used through `&mut self`.
+unsafe impl Send for Allocator {}
+
+impl Allocator {
+    /// An allocator for `device`, which was made from the physical device
+    /// with `properties` and `memory_properties`.
+    pub fn new(fns: &DeviceFns, device: Device, properties: &PhysicalDeviceProperties,
+               memory_properties: &PhysicalDeviceMemoryProperties) -> Allocator {
+        Allocator {
+            fns: *fns,
+            fns_v1_1: None,
+            device,
+            memory_properties: *memory_properties,
+            granularity: cmp::max(properties.limits.bufferImageGranularity, 1),
+            max_allocations: properties.limits.maxMemoryAllocationCount,
+            block_size: DEFAULT_BLOCK_SIZE,
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Uses blocks of `size` bytes instead of `DEFAULT_BLOCK_SIZE`. Sizes
+    /// below 64 KiB are raised to that.
+    pub fn with_block_size(mut self, size: DeviceSize) -> Allocator {
+        self.block_size = cmp::max(size, MIN_BLOCK_SIZE);
+        self
+    }
+
+    /// Queries buffers and images with `vkGet*MemoryRequirements2`, giving
+    /// those which prefer or require it a `DeviceMemory` of their own, and
+    /// names the resource in `MemoryDedicatedAllocateInfo` whenever one
+    /// gets its own memory. `fns` must be loaded for the allocator's
+    /// device, which needs Vulkan 1.1.
+    pub fn with_dedicated_allocation(mut self, fns: &DeviceFnsV1_1) -> Allocator {
+        self.fns_v1_1 = Some(*fns);
+        self
+    }
+
+    /// The index of the memory type in `type_bits`, such as
+    /// `MemoryRequirements::memoryTypeBits`, which has all of `required` and
+    /// the most of `preferred`. Ties go to the lowest index, which the spec
+    /// orders from fastest to slowest.
+    pub fn find_memory_type(&self, type_bits: u32, required: MemoryPropertyFlags,
+                            preferred: MemoryPropertyFlags) -> Option<u32> {
+        let count = self.memory_properties.memoryTypeCount as usize;
+        let mut best: Option<(u32, u32)> = None;
+        for (i, ty) in self.memory_properties.memoryTypes[..count].iter().enumerate() {
+            if type_bits & (1 << i) == 0 || !ty.propertyFlags.contains(required) {
+                continue;
+            }
+            let score = (ty.propertyFlags & preferred).0.count_ones();
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((i as u32, score));
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+
+    /// Memory meeting `requirements`, in a memory type chosen by `request`.
+    /// Fails with `ERROR_FEATURE_NOT_PRESENT` if no memory type fits, and
+    /// with `ERROR_TOO_MANY_OBJECTS` if a new `DeviceMemory` would take the
+    /// allocator past `maxMemoryAllocationCount`.
+    ///
+    /// # Safety
+    ///
+    /// The device this allocator was made for must still be alive.
+    pub unsafe fn allocate(&mut self, requirements: &MemoryRequirements,
+                           request: &AllocationRequest)
+        -> ::std::result::Result<Allocation, Error>
+    {
+        self.allocate_for(requirements, request, None)
+    }
+
+    /// `allocate`, with the resource to name if the memory is dedicated.
+    unsafe fn allocate_for(&mut self, requirements: &MemoryRequirements,
+                           request: &AllocationRequest,
+                           resource: Option<&MemoryDedicatedAllocateInfo>)
+        -> ::std::result::Result<Allocation, Error>
+    {
+        let memory_type = self.find_memory_type(requirements.memoryTypeBits, request.required,
+                                                request.preferred)
+            .ok_or(Error(Result::ERROR_FEATURE_NOT_PRESENT))?;
+        let heap = self.memory_properties.memoryTypes[memory_type as usize].heapIndex;
+        let block_size = cmp::min(self.block_size,
+                                  self.memory_properties.memoryHeaps[heap as usize].size / 8);
+        if request.dedicated || requirements.size > block_size / 2 {
+            let memory = self.allocate_memory(memory_type, requirements.size, resource)?;
+            self.blocks.push(Block {
+                memory,
+                memory_type,
+                size: requirements.size,
+                dedicated: true,
+                ranges: vec![Range { offset: 0, size: requirements.size, linear: request.linear }],
+                mapping: null_mut(),
+            });
+            return Ok(Allocation { memory, offset: 0, size: requirements.size, memory_type });
+        }
+
+        let granularity = self.granularity;
+        for block in &mut self.blocks {
+            if block.dedicated || block.memory_type != memory_type {
+                continue;
+            }
+            let allocation = block.sub_allocate(requirements, request.linear, granularity);
+            if let Some(allocation) = allocation {
+                return Ok(allocation);
+            }
+        }
+        let memory = self.allocate_memory(memory_type, block_size, None)?;
+        self.blocks.push(Block {
+            memory,
+            memory_type,
+            size: block_size,
+            dedicated: false,
+            ranges: Vec::new(),
+            mapping: null_mut(),
+        });
+        let block = self.blocks.last_mut().unwrap();
+        block.sub_allocate(requirements, request.linear, granularity)
+            .ok_or(Error(Result::ERROR_OUT_OF_DEVICE_MEMORY))
+    }
+
+    /// Allocates memory for `buffer` and binds it.
+    ///
+    /// # Safety
+    ///
+    /// `buffer` must be a buffer of this allocator's device with no memory
+    /// bound to it.
+    pub unsafe fn allocate_buffer(&mut self, buffer: Buffer, request: &AllocationRequest)
+        -> ::std::result::Result<Allocation, Error>
+    {
+        let mut requirements = MemoryRequirements::default();
+        let mut dedicated = MemoryDedicatedRequirements::default();
+        match self.fns_v1_1 {
+            Some(ref fns) => {
+                let info = BufferMemoryRequirementsInfo2 { buffer, ..Default::default() };
+                let mut requirements2 = MemoryRequirements2 {
+                    pNext: &mut dedicated as *mut MemoryDedicatedRequirements as *mut c_void,
+                    ..Default::default()
+                };
+                fns.vkGetBufferMemoryRequirements2(self.device, &info, &mut requirements2);
+                requirements = requirements2.memoryRequirements;
+            }
+            None => self.fns.vkGetBufferMemoryRequirements(self.device, buffer, &mut requirements),
+        }
+        let request = AllocationRequest {
+            linear: true,
+            dedicated: request.dedicated || wants_dedicated(&dedicated),
+            ..*request
+        };
+        let resource = MemoryDedicatedAllocateInfo { buffer, ..Default::default() };
+        let allocation = self.allocate_for(&requirements, &request, Some(&resource))?;
+        let res = self.fns.vkBindBufferMemory(self.device, buffer, allocation.memory,
+                                              allocation.offset);
+        self.bind_result(res, allocation)
+    }
+
+    /// Allocates memory for `image` and binds it. `request.linear` should
+    /// say whether `image` has linear tiling.
+    ///
+    /// # Safety
+    ///
+    /// `image` must be an image of this allocator's device with no memory
+    /// bound to it.
+    pub unsafe fn allocate_image(&mut self, image: Image, request: &AllocationRequest)
+        -> ::std::result::Result<Allocation, Error>
+    {
+        let mut requirements = MemoryRequirements::default();
+        let mut dedicated = MemoryDedicatedRequirements::default();
+        match self.fns_v1_1 {
+            Some(ref fns) => {
+                let info = ImageMemoryRequirementsInfo2 { image, ..Default::default() };
+                let mut requirements2 = MemoryRequirements2 {
+                    pNext: &mut dedicated as *mut MemoryDedicatedRequirements as *mut c_void,
+                    ..Default::default()
+                };
+                fns.vkGetImageMemoryRequirements2(self.device, &info, &mut requirements2);
+                requirements = requirements2.memoryRequirements;
+            }
+            None => self.fns.vkGetImageMemoryRequirements(self.device, image, &mut requirements),
+        }
+        let request = AllocationRequest {
+            dedicated: request.dedicated || wants_dedicated(&dedicated),
+            ..*request
+        };
+        let resource = MemoryDedicatedAllocateInfo { image, ..Default::default() };
+        let allocation = self.allocate_for(&requirements, &request, Some(&resource))?;
+        let res = self.fns.vkBindImageMemory(self.device, image, allocation.memory,
+                                             allocation.offset);
+        self.bind_result(res, allocation)
+    }
+
+    /// Gives `allocation` back. A block which no longer holds anything is
+    /// freed.
+    ///
+    /// # Safety
+    ///
+    /// Nothing the device still uses may be bound to `allocation`.
+    ///
+    /// # Panics
+    ///
+    /// If `allocation` is not from this allocator.
+    pub unsafe fn free(&mut self, allocation: Allocation) {
+        let i = self.blocks.iter().position(|block| block.memory == allocation.memory)
+            .expect("allocation is not from this allocator");
+        let empty = {
+            let block = &mut self.blocks[i];
+            block.ranges.retain(|range| range.offset != allocation.offset);
+            block.ranges.is_empty()
+        };
+        if empty {
+            let block = self.blocks.swap_remove(i);
+            self.fns.vkFreeMemory(self.device, block.memory, null());
+        }
+    }
+
+    /// A pointer to the start of `allocation`, which must be in a
+    /// `HOST_VISIBLE` memory type. Its block stays mapped until it is freed.
+    ///
+    /// # Safety
+    ///
+    /// The memory must not be mapped through `vkMapMemory` directly.
+    ///
+    /// # Panics
+    ///
+    /// If `allocation` is not from this allocator.
+    pub unsafe fn map(&mut self, allocation: &Allocation)
+        -> ::std::result::Result<*mut c_void, Error>
+    {
+        let block = self.blocks.iter_mut().find(|block| block.memory == allocation.memory)
+            .expect("allocation is not from this allocator");
+        if block.mapping.is_null() {
+            let mut data = null_mut();
+            self.fns.vkMapMemory(self.device, block.memory, 0, WHOLE_SIZE, MemoryMapFlags::empty(),
+                                 &mut data).result()?;
+            block.mapping = data as *mut u8;
+        }
+        // The whole block is mapped, so its offsets fit the address space.
+        let offset = usize::try_from(allocation.offset)
+            .map_err(|_| Error(Result::ERROR_MEMORY_MAP_FAILED))?;
+        Ok(block.mapping.add(offset) as *mut c_void)
+    }
+
+    /// Usage of each of the device's memory heaps, by heap index.
+    pub fn heap_stats(&self) -> Vec<HeapStats> {
+        let count = self.memory_properties.memoryHeapCount as usize;
+        let mut stats: Vec<_> = self.memory_properties.memoryHeaps[..count].iter()
+            .map(|heap| HeapStats { size: heap.size, ..HeapStats::default() })
+            .collect();
+        for block in &self.blocks {
+            let heap = self.memory_properties.memoryTypes[block.memory_type as usize].heapIndex;
+            let stats = &mut stats[heap as usize];
+            stats.block_count += 1;
+            stats.dedicated_count += block.dedicated as u32;
+            stats.allocation_count += block.ranges.len() as u32;
+            stats.reserved += block.size;
+            stats.used += block.ranges.iter().map(|range| range.size).sum::<DeviceSize>();
+        }
+        stats
+    }
+
+    /// A new `DeviceMemory`, naming `resource` in its `pNext` chain if
+    /// dedicated allocations are enabled.
+    unsafe fn allocate_memory(&self, memory_type: u32, size: DeviceSize,
+                              resource: Option<&MemoryDedicatedAllocateInfo>)
+        -> ::std::result::Result<DeviceMemory, Error>
+    {
+        if self.blocks.len() >= self.max_allocations as usize {
+            return Err(Error(Result::ERROR_TOO_MANY_OBJECTS));
+        }
+        let p_next = match resource {
+            Some(resource) if self.fns_v1_1.is_some() =>
+                resource as *const MemoryDedicatedAllocateInfo as *const c_void,
+            _ => null(),
+        };
+        let info = MemoryAllocateInfo {
+            pNext: p_next,
+            allocationSize: size,
+            memoryTypeIndex: memory_type,
+            ..Default::default()
+        };
+        let mut memory = DeviceMemory::null();
+        self.fns.vkAllocateMemory(self.device, &info, null(), &mut memory).result()?;
+        Ok(memory)
+    }
+
+    unsafe fn bind_result(&mut self, res: Result, allocation: Allocation)
+        -> ::std::result::Result<Allocation, Error>
+    {
+        if let Err(err) = res.result() {
+            self.free(allocation);
+            return Err(err);
+        }
+        Ok(allocation)
+    }
+}
+
+impl Drop for Allocator {
+    /// Frees every block, whether or not its allocations were given back.
+    fn drop(&mut self) {
+        for block in &self.blocks {
+            unsafe { self.fns.vkFreeMemory(self.device, block.memory, null()) }
+        }
+    }
+}
+
+impl Block {
+    /// First fit. Resources of the other kind from a neighbouring range
+    /// must not share a `granularity`-sized page with the new one.
+    fn sub_allocate(&mut self, requirements: &MemoryRequirements, linear: bool,
+                    granularity: DeviceSize) -> Option<Allocation> {
+        let page = |offset: DeviceSize| offset / granularity;
+        let alignment = cmp::max(requirements.alignment, 1);
+        for i in 0..self.ranges.len() + 1 {
+            let prev = if i == 0 { None } else { Some(&self.ranges[i - 1]) };
+            let next = self.ranges.get(i);
+            let start = prev.map_or(0, |prev| prev.offset + prev.size);
+            let end = next.map_or(self.size, |next| next.offset);
+
+            let mut offset = align(start, alignment);
+            if let Some(prev) = prev {
+                if prev.linear != linear && page(prev.offset + prev.size - 1) == page(offset) {
+                    offset = align(offset, granularity);
+                }
+            }
+            let range_end = offset + requirements.size;
+            if range_end > end {
+                continue;
+            }
+            if let Some(next) = next {
+                if next.linear != linear && page(range_end - 1) == page(next.offset) {
+                    continue;
+                }
+            }
+            self.ranges.insert(i, Range { offset, size: requirements.size, linear });
+            return Some(Allocation {
+                memory: self.memory,
+                offset,
+                size: requirements.size,
+                memory_type: self.memory_type,
+            });
+        }
+        None
+    }
+}
+
+fn wants_dedicated(requirements: &MemoryDedicatedRequirements) -> bool {
+    bool::from(requirements.prefersDedicatedAllocation)
+        || bool::from(requirements.requiresDedicatedAllocation)
+}
+
+fn align(offset: DeviceSize, alignment: DeviceSize) -> DeviceSize {
+    offset.div_ceil(alignment) * alignment
+}
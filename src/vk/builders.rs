@@ -0,0 +1,2514 @@
+use vk::*;
+
+builder!{ApplicationInfo, ApplicationInfoBuilder}
+impl<'a> ApplicationInfoBuilder<'a> {
+    #[inline]
+    pub fn application_name(mut self, application_name: &'a ::std::ffi::CStr) -> Self {
+        self.inner.pApplicationName = application_name.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn application_version(mut self, application_version: u32) -> Self {
+        self.inner.applicationVersion = application_version;
+        self
+    }
+    #[inline]
+    pub fn engine_name(mut self, engine_name: &'a ::std::ffi::CStr) -> Self {
+        self.inner.pEngineName = engine_name.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn engine_version(mut self, engine_version: u32) -> Self {
+        self.inner.engineVersion = engine_version;
+        self
+    }
+    #[inline]
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.inner.apiVersion = api_version;
+        self
+    }
+}
+builder!{InstanceCreateInfo, InstanceCreateInfoBuilder}
+impl<'a> InstanceCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: InstanceCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn application_info(mut self, application_info: &'a ApplicationInfo) -> Self {
+        self.inner.pApplicationInfo = application_info;
+        self
+    }
+    #[inline]
+    pub fn enabled_layer_names(mut self, enabled_layer_names: &'a [*const ::std::os::raw::c_char]) -> Self {
+        self.inner.enabledLayerCount = enabled_layer_names.len() as u32;
+        self.inner.ppEnabledLayerNames = enabled_layer_names.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn enabled_extension_names(mut self, enabled_extension_names: &'a [*const ::std::os::raw::c_char]) -> Self {
+        self.inner.enabledExtensionCount = enabled_extension_names.len() as u32;
+        self.inner.ppEnabledExtensionNames = enabled_extension_names.as_ptr();
+        self
+    }
+}
+builder!{DeviceQueueCreateInfo, DeviceQueueCreateInfoBuilder}
+impl<'a> DeviceQueueCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: DeviceQueueCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn queue_family_index(mut self, queue_family_index: u32) -> Self {
+        self.inner.queueFamilyIndex = queue_family_index;
+        self
+    }
+    #[inline]
+    pub fn queue_priorities(mut self, queue_priorities: &'a [::std::os::raw::c_float]) -> Self {
+        self.inner.queueCount = queue_priorities.len() as u32;
+        self.inner.pQueuePriorities = queue_priorities.as_ptr();
+        self
+    }
+}
+builder!{DeviceCreateInfo, DeviceCreateInfoBuilder}
+impl<'a> DeviceCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: DeviceCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn queue_create_infos(mut self, queue_create_infos: &'a [DeviceQueueCreateInfo]) -> Self {
+        self.inner.queueCreateInfoCount = queue_create_infos.len() as u32;
+        self.inner.pQueueCreateInfos = queue_create_infos.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn enabled_layer_names(mut self, enabled_layer_names: &'a [*const ::std::os::raw::c_char]) -> Self {
+        self.inner.enabledLayerCount = enabled_layer_names.len() as u32;
+        self.inner.ppEnabledLayerNames = enabled_layer_names.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn enabled_extension_names(mut self, enabled_extension_names: &'a [*const ::std::os::raw::c_char]) -> Self {
+        self.inner.enabledExtensionCount = enabled_extension_names.len() as u32;
+        self.inner.ppEnabledExtensionNames = enabled_extension_names.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn enabled_features(mut self, enabled_features: &'a PhysicalDeviceFeatures) -> Self {
+        self.inner.pEnabledFeatures = enabled_features;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsDeviceCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{SubmitInfo, SubmitInfoBuilder}
+impl<'a> SubmitInfoBuilder<'a> {
+    #[inline]
+    pub fn wait_semaphores(mut self, wait_semaphores: &'a [Semaphore]) -> Self {
+        self.inner.waitSemaphoreCount = wait_semaphores.len() as u32;
+        self.inner.pWaitSemaphores = wait_semaphores.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn wait_dst_stage_mask(mut self, wait_dst_stage_mask: &'a [PipelineStageFlags]) -> Self {
+        self.inner.waitSemaphoreCount = wait_dst_stage_mask.len() as u32;
+        self.inner.pWaitDstStageMask = wait_dst_stage_mask.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn command_buffers(mut self, command_buffers: &'a [CommandBuffer]) -> Self {
+        self.inner.commandBufferCount = command_buffers.len() as u32;
+        self.inner.pCommandBuffers = command_buffers.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn signal_semaphores(mut self, signal_semaphores: &'a [Semaphore]) -> Self {
+        self.inner.signalSemaphoreCount = signal_semaphores.len() as u32;
+        self.inner.pSignalSemaphores = signal_semaphores.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsSubmitInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{MemoryAllocateInfo, MemoryAllocateInfoBuilder}
+impl<'a> MemoryAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn allocation_size(mut self, allocation_size: DeviceSize) -> Self {
+        self.inner.allocationSize = allocation_size;
+        self
+    }
+    #[inline]
+    pub fn memory_type_index(mut self, memory_type_index: u32) -> Self {
+        self.inner.memoryTypeIndex = memory_type_index;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsMemoryAllocateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{SparseBufferMemoryBindInfo, SparseBufferMemoryBindInfoBuilder}
+impl<'a> SparseBufferMemoryBindInfoBuilder<'a> {
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+    #[inline]
+    pub fn binds(mut self, binds: &'a [SparseMemoryBind]) -> Self {
+        self.inner.bindCount = binds.len() as u32;
+        self.inner.pBinds = binds.as_ptr();
+        self
+    }
+}
+builder!{SparseImageOpaqueMemoryBindInfo, SparseImageOpaqueMemoryBindInfoBuilder}
+impl<'a> SparseImageOpaqueMemoryBindInfoBuilder<'a> {
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+    #[inline]
+    pub fn binds(mut self, binds: &'a [SparseMemoryBind]) -> Self {
+        self.inner.bindCount = binds.len() as u32;
+        self.inner.pBinds = binds.as_ptr();
+        self
+    }
+}
+builder!{SparseImageMemoryBindInfo, SparseImageMemoryBindInfoBuilder}
+impl<'a> SparseImageMemoryBindInfoBuilder<'a> {
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+    #[inline]
+    pub fn binds(mut self, binds: &'a [SparseImageMemoryBind]) -> Self {
+        self.inner.bindCount = binds.len() as u32;
+        self.inner.pBinds = binds.as_ptr();
+        self
+    }
+}
+builder!{BindSparseInfo, BindSparseInfoBuilder}
+impl<'a> BindSparseInfoBuilder<'a> {
+    #[inline]
+    pub fn wait_semaphores(mut self, wait_semaphores: &'a [Semaphore]) -> Self {
+        self.inner.waitSemaphoreCount = wait_semaphores.len() as u32;
+        self.inner.pWaitSemaphores = wait_semaphores.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn buffer_binds(mut self, buffer_binds: &'a [SparseBufferMemoryBindInfo]) -> Self {
+        self.inner.bufferBindCount = buffer_binds.len() as u32;
+        self.inner.pBufferBinds = buffer_binds.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn image_opaque_binds(mut self, image_opaque_binds: &'a [SparseImageOpaqueMemoryBindInfo]) -> Self {
+        self.inner.imageOpaqueBindCount = image_opaque_binds.len() as u32;
+        self.inner.pImageOpaqueBinds = image_opaque_binds.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn image_binds(mut self, image_binds: &'a [SparseImageMemoryBindInfo]) -> Self {
+        self.inner.imageBindCount = image_binds.len() as u32;
+        self.inner.pImageBinds = image_binds.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn signal_semaphores(mut self, signal_semaphores: &'a [Semaphore]) -> Self {
+        self.inner.signalSemaphoreCount = signal_semaphores.len() as u32;
+        self.inner.pSignalSemaphores = signal_semaphores.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsBindSparseInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{FenceCreateInfo, FenceCreateInfoBuilder}
+impl<'a> FenceCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: FenceCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsFenceCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{SemaphoreCreateInfo, SemaphoreCreateInfoBuilder}
+impl<'a> SemaphoreCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: SemaphoreCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsSemaphoreCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{EventCreateInfo, EventCreateInfoBuilder}
+impl<'a> EventCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: EventCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+}
+builder!{QueryPoolCreateInfo, QueryPoolCreateInfoBuilder}
+impl<'a> QueryPoolCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: QueryPoolCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn query_type(mut self, query_type: QueryType) -> Self {
+        self.inner.queryType = query_type;
+        self
+    }
+    #[inline]
+    pub fn query_count(mut self, query_count: u32) -> Self {
+        self.inner.queryCount = query_count;
+        self
+    }
+    #[inline]
+    pub fn pipeline_statistics(mut self, pipeline_statistics: QueryPipelineStatisticFlags) -> Self {
+        self.inner.pipelineStatistics = pipeline_statistics;
+        self
+    }
+}
+builder!{BufferCreateInfo, BufferCreateInfoBuilder}
+impl<'a> BufferCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: BufferCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn size(mut self, size: DeviceSize) -> Self {
+        self.inner.size = size;
+        self
+    }
+    #[inline]
+    pub fn usage(mut self, usage: BufferUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+    #[inline]
+    pub fn sharing_mode(mut self, sharing_mode: SharingMode) -> Self {
+        self.inner.sharingMode = sharing_mode;
+        self
+    }
+    #[inline]
+    pub fn queue_family_indices(mut self, queue_family_indices: &'a [u32]) -> Self {
+        self.inner.queueFamilyIndexCount = queue_family_indices.len() as u32;
+        self.inner.pQueueFamilyIndices = queue_family_indices.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsBufferCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{BufferViewCreateInfo, BufferViewCreateInfoBuilder}
+impl<'a> BufferViewCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: BufferViewCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Format) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn offset(mut self, offset: DeviceSize) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+    #[inline]
+    pub fn range(mut self, range: DeviceSize) -> Self {
+        self.inner.range = range;
+        self
+    }
+}
+builder!{ImageCreateInfo, ImageCreateInfoBuilder}
+impl<'a> ImageCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: ImageCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn image_type(mut self, image_type: ImageType) -> Self {
+        self.inner.imageType = image_type;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Format) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn extent(mut self, extent: Extent3D) -> Self {
+        self.inner.extent = extent;
+        self
+    }
+    #[inline]
+    pub fn mip_levels(mut self, mip_levels: u32) -> Self {
+        self.inner.mipLevels = mip_levels;
+        self
+    }
+    #[inline]
+    pub fn array_layers(mut self, array_layers: u32) -> Self {
+        self.inner.arrayLayers = array_layers;
+        self
+    }
+    #[inline]
+    pub fn samples(mut self, samples: SampleCountFlags) -> Self {
+        self.inner.samples = samples;
+        self
+    }
+    #[inline]
+    pub fn tiling(mut self, tiling: ImageTiling) -> Self {
+        self.inner.tiling = tiling;
+        self
+    }
+    #[inline]
+    pub fn usage(mut self, usage: ImageUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+    #[inline]
+    pub fn sharing_mode(mut self, sharing_mode: SharingMode) -> Self {
+        self.inner.sharingMode = sharing_mode;
+        self
+    }
+    #[inline]
+    pub fn queue_family_indices(mut self, queue_family_indices: &'a [u32]) -> Self {
+        self.inner.queueFamilyIndexCount = queue_family_indices.len() as u32;
+        self.inner.pQueueFamilyIndices = queue_family_indices.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn initial_layout(mut self, initial_layout: ImageLayout) -> Self {
+        self.inner.initialLayout = initial_layout;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsImageCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{ImageViewCreateInfo, ImageViewCreateInfoBuilder}
+impl<'a> ImageViewCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: ImageViewCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+    #[inline]
+    pub fn view_type(mut self, view_type: ImageViewType) -> Self {
+        self.inner.viewType = view_type;
+        self
+    }
+    #[inline]
+    pub fn format(mut self, format: Format) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn components(mut self, components: ComponentMapping) -> Self {
+        self.inner.components = components;
+        self
+    }
+    #[inline]
+    pub fn subresource_range(mut self, subresource_range: ImageSubresourceRange) -> Self {
+        self.inner.subresourceRange = subresource_range;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsImageViewCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{ShaderModuleCreateInfo, ShaderModuleCreateInfoBuilder}
+impl<'a> ShaderModuleCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: ShaderModuleCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn code(mut self, code: &'a [u32]) -> Self {
+        self.inner.codeSize = code.len() * 4;
+        self.inner.pCode = code.as_ptr();
+        self
+    }
+}
+builder!{PipelineCacheCreateInfo, PipelineCacheCreateInfoBuilder}
+impl<'a> PipelineCacheCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineCacheCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn initial_data(mut self, initial_data: &'a [u8]) -> Self {
+        self.inner.initialDataSize = initial_data.len();
+        self.inner.pInitialData = initial_data.as_ptr() as *const ::std::os::raw::c_void;
+        self
+    }
+}
+builder!{SpecializationInfo, SpecializationInfoBuilder}
+impl<'a> SpecializationInfoBuilder<'a> {
+    #[inline]
+    pub fn map_entries(mut self, map_entries: &'a [SpecializationMapEntry]) -> Self {
+        self.inner.mapEntryCount = map_entries.len() as u32;
+        self.inner.pMapEntries = map_entries.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn data(mut self, data: &'a [u8]) -> Self {
+        self.inner.dataSize = data.len();
+        self.inner.pData = data.as_ptr() as *const ::std::os::raw::c_void;
+        self
+    }
+}
+builder!{PipelineShaderStageCreateInfo, PipelineShaderStageCreateInfoBuilder}
+impl<'a> PipelineShaderStageCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineShaderStageCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn stage(mut self, stage: ShaderStageFlags) -> Self {
+        self.inner.stage = stage;
+        self
+    }
+    #[inline]
+    pub fn module(mut self, module: ShaderModule) -> Self {
+        self.inner.module = module;
+        self
+    }
+    #[inline]
+    pub fn name(mut self, name: &'a ::std::ffi::CStr) -> Self {
+        self.inner.pName = name.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn specialization_info(mut self, specialization_info: &'a SpecializationInfo) -> Self {
+        self.inner.pSpecializationInfo = specialization_info;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsPipelineShaderStageCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{PipelineVertexInputStateCreateInfo, PipelineVertexInputStateCreateInfoBuilder}
+impl<'a> PipelineVertexInputStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineVertexInputStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn vertex_binding_descriptions(mut self, vertex_binding_descriptions: &'a [VertexInputBindingDescription]) -> Self {
+        self.inner.vertexBindingDescriptionCount = vertex_binding_descriptions.len() as u32;
+        self.inner.pVertexBindingDescriptions = vertex_binding_descriptions.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn vertex_attribute_descriptions(mut self, vertex_attribute_descriptions: &'a [VertexInputAttributeDescription]) -> Self {
+        self.inner.vertexAttributeDescriptionCount = vertex_attribute_descriptions.len() as u32;
+        self.inner.pVertexAttributeDescriptions = vertex_attribute_descriptions.as_ptr();
+        self
+    }
+}
+builder!{PipelineInputAssemblyStateCreateInfo, PipelineInputAssemblyStateCreateInfoBuilder}
+impl<'a> PipelineInputAssemblyStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineInputAssemblyStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn topology(mut self, topology: PrimitiveTopology) -> Self {
+        self.inner.topology = topology;
+        self
+    }
+    #[inline]
+    pub fn primitive_restart_enable(mut self, primitive_restart_enable: Bool32) -> Self {
+        self.inner.primitiveRestartEnable = primitive_restart_enable;
+        self
+    }
+}
+builder!{PipelineTessellationStateCreateInfo, PipelineTessellationStateCreateInfoBuilder}
+impl<'a> PipelineTessellationStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineTessellationStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn patch_control_points(mut self, patch_control_points: u32) -> Self {
+        self.inner.patchControlPoints = patch_control_points;
+        self
+    }
+}
+builder!{PipelineViewportStateCreateInfo, PipelineViewportStateCreateInfoBuilder}
+impl<'a> PipelineViewportStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineViewportStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn viewport_count(mut self, viewport_count: u32) -> Self {
+        self.inner.viewportCount = viewport_count;
+        self
+    }
+    #[inline]
+    pub fn viewports(mut self, viewports: &'a [Viewport]) -> Self {
+        self.inner.viewportCount = viewports.len() as u32;
+        self.inner.pViewports = viewports.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn scissor_count(mut self, scissor_count: u32) -> Self {
+        self.inner.scissorCount = scissor_count;
+        self
+    }
+    #[inline]
+    pub fn scissors(mut self, scissors: &'a [Rect2D]) -> Self {
+        self.inner.scissorCount = scissors.len() as u32;
+        self.inner.pScissors = scissors.as_ptr();
+        self
+    }
+}
+builder!{PipelineRasterizationStateCreateInfo, PipelineRasterizationStateCreateInfoBuilder}
+impl<'a> PipelineRasterizationStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineRasterizationStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn depth_clamp_enable(mut self, depth_clamp_enable: Bool32) -> Self {
+        self.inner.depthClampEnable = depth_clamp_enable;
+        self
+    }
+    #[inline]
+    pub fn rasterizer_discard_enable(mut self, rasterizer_discard_enable: Bool32) -> Self {
+        self.inner.rasterizerDiscardEnable = rasterizer_discard_enable;
+        self
+    }
+    #[inline]
+    pub fn polygon_mode(mut self, polygon_mode: PolygonMode) -> Self {
+        self.inner.polygonMode = polygon_mode;
+        self
+    }
+    #[inline]
+    pub fn cull_mode(mut self, cull_mode: CullModeFlags) -> Self {
+        self.inner.cullMode = cull_mode;
+        self
+    }
+    #[inline]
+    pub fn front_face(mut self, front_face: FrontFace) -> Self {
+        self.inner.frontFace = front_face;
+        self
+    }
+    #[inline]
+    pub fn depth_bias_enable(mut self, depth_bias_enable: Bool32) -> Self {
+        self.inner.depthBiasEnable = depth_bias_enable;
+        self
+    }
+    #[inline]
+    pub fn depth_bias_constant_factor(mut self, depth_bias_constant_factor: ::std::os::raw::c_float) -> Self {
+        self.inner.depthBiasConstantFactor = depth_bias_constant_factor;
+        self
+    }
+    #[inline]
+    pub fn depth_bias_clamp(mut self, depth_bias_clamp: ::std::os::raw::c_float) -> Self {
+        self.inner.depthBiasClamp = depth_bias_clamp;
+        self
+    }
+    #[inline]
+    pub fn depth_bias_slope_factor(mut self, depth_bias_slope_factor: ::std::os::raw::c_float) -> Self {
+        self.inner.depthBiasSlopeFactor = depth_bias_slope_factor;
+        self
+    }
+    #[inline]
+    pub fn line_width(mut self, line_width: ::std::os::raw::c_float) -> Self {
+        self.inner.lineWidth = line_width;
+        self
+    }
+}
+builder!{PipelineMultisampleStateCreateInfo, PipelineMultisampleStateCreateInfoBuilder}
+impl<'a> PipelineMultisampleStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineMultisampleStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn rasterization_samples(mut self, rasterization_samples: SampleCountFlags) -> Self {
+        self.inner.rasterizationSamples = rasterization_samples;
+        self
+    }
+    #[inline]
+    pub fn sample_shading_enable(mut self, sample_shading_enable: Bool32) -> Self {
+        self.inner.sampleShadingEnable = sample_shading_enable;
+        self
+    }
+    #[inline]
+    pub fn min_sample_shading(mut self, min_sample_shading: ::std::os::raw::c_float) -> Self {
+        self.inner.minSampleShading = min_sample_shading;
+        self
+    }
+    #[inline]
+    pub fn sample_mask(mut self, sample_mask: &'a [SampleMask]) -> Self {
+        self.inner.pSampleMask = if sample_mask.is_empty() { ::std::ptr::null() } else { sample_mask.as_ptr() };
+        self
+    }
+    #[inline]
+    pub fn alpha_to_coverage_enable(mut self, alpha_to_coverage_enable: Bool32) -> Self {
+        self.inner.alphaToCoverageEnable = alpha_to_coverage_enable;
+        self
+    }
+    #[inline]
+    pub fn alpha_to_one_enable(mut self, alpha_to_one_enable: Bool32) -> Self {
+        self.inner.alphaToOneEnable = alpha_to_one_enable;
+        self
+    }
+}
+builder!{PipelineDepthStencilStateCreateInfo, PipelineDepthStencilStateCreateInfoBuilder}
+impl<'a> PipelineDepthStencilStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineDepthStencilStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn depth_test_enable(mut self, depth_test_enable: Bool32) -> Self {
+        self.inner.depthTestEnable = depth_test_enable;
+        self
+    }
+    #[inline]
+    pub fn depth_write_enable(mut self, depth_write_enable: Bool32) -> Self {
+        self.inner.depthWriteEnable = depth_write_enable;
+        self
+    }
+    #[inline]
+    pub fn depth_compare_op(mut self, depth_compare_op: CompareOp) -> Self {
+        self.inner.depthCompareOp = depth_compare_op;
+        self
+    }
+    #[inline]
+    pub fn depth_bounds_test_enable(mut self, depth_bounds_test_enable: Bool32) -> Self {
+        self.inner.depthBoundsTestEnable = depth_bounds_test_enable;
+        self
+    }
+    #[inline]
+    pub fn stencil_test_enable(mut self, stencil_test_enable: Bool32) -> Self {
+        self.inner.stencilTestEnable = stencil_test_enable;
+        self
+    }
+    #[inline]
+    pub fn front(mut self, front: StencilOpState) -> Self {
+        self.inner.front = front;
+        self
+    }
+    #[inline]
+    pub fn back(mut self, back: StencilOpState) -> Self {
+        self.inner.back = back;
+        self
+    }
+    #[inline]
+    pub fn min_depth_bounds(mut self, min_depth_bounds: ::std::os::raw::c_float) -> Self {
+        self.inner.minDepthBounds = min_depth_bounds;
+        self
+    }
+    #[inline]
+    pub fn max_depth_bounds(mut self, max_depth_bounds: ::std::os::raw::c_float) -> Self {
+        self.inner.maxDepthBounds = max_depth_bounds;
+        self
+    }
+}
+builder!{PipelineColorBlendStateCreateInfo, PipelineColorBlendStateCreateInfoBuilder}
+impl<'a> PipelineColorBlendStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineColorBlendStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn logic_op_enable(mut self, logic_op_enable: Bool32) -> Self {
+        self.inner.logicOpEnable = logic_op_enable;
+        self
+    }
+    #[inline]
+    pub fn logic_op(mut self, logic_op: LogicOp) -> Self {
+        self.inner.logicOp = logic_op;
+        self
+    }
+    #[inline]
+    pub fn attachments(mut self, attachments: &'a [PipelineColorBlendAttachmentState]) -> Self {
+        self.inner.attachmentCount = attachments.len() as u32;
+        self.inner.pAttachments = attachments.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn blend_constants(mut self, blend_constants: [::std::os::raw::c_float; 4usize]) -> Self {
+        self.inner.blendConstants = blend_constants;
+        self
+    }
+}
+builder!{PipelineDynamicStateCreateInfo, PipelineDynamicStateCreateInfoBuilder}
+impl<'a> PipelineDynamicStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineDynamicStateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn dynamic_states(mut self, dynamic_states: &'a [DynamicState]) -> Self {
+        self.inner.dynamicStateCount = dynamic_states.len() as u32;
+        self.inner.pDynamicStates = dynamic_states.as_ptr();
+        self
+    }
+}
+builder!{GraphicsPipelineCreateInfo, GraphicsPipelineCreateInfoBuilder}
+impl<'a> GraphicsPipelineCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn stages(mut self, stages: &'a [PipelineShaderStageCreateInfo]) -> Self {
+        self.inner.stageCount = stages.len() as u32;
+        self.inner.pStages = stages.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn vertex_input_state(mut self, vertex_input_state: &'a PipelineVertexInputStateCreateInfo) -> Self {
+        self.inner.pVertexInputState = vertex_input_state;
+        self
+    }
+    #[inline]
+    pub fn input_assembly_state(mut self, input_assembly_state: &'a PipelineInputAssemblyStateCreateInfo) -> Self {
+        self.inner.pInputAssemblyState = input_assembly_state;
+        self
+    }
+    #[inline]
+    pub fn tessellation_state(mut self, tessellation_state: &'a PipelineTessellationStateCreateInfo) -> Self {
+        self.inner.pTessellationState = tessellation_state;
+        self
+    }
+    #[inline]
+    pub fn viewport_state(mut self, viewport_state: &'a PipelineViewportStateCreateInfo) -> Self {
+        self.inner.pViewportState = viewport_state;
+        self
+    }
+    #[inline]
+    pub fn rasterization_state(mut self, rasterization_state: &'a PipelineRasterizationStateCreateInfo) -> Self {
+        self.inner.pRasterizationState = rasterization_state;
+        self
+    }
+    #[inline]
+    pub fn multisample_state(mut self, multisample_state: &'a PipelineMultisampleStateCreateInfo) -> Self {
+        self.inner.pMultisampleState = multisample_state;
+        self
+    }
+    #[inline]
+    pub fn depth_stencil_state(mut self, depth_stencil_state: &'a PipelineDepthStencilStateCreateInfo) -> Self {
+        self.inner.pDepthStencilState = depth_stencil_state;
+        self
+    }
+    #[inline]
+    pub fn color_blend_state(mut self, color_blend_state: &'a PipelineColorBlendStateCreateInfo) -> Self {
+        self.inner.pColorBlendState = color_blend_state;
+        self
+    }
+    #[inline]
+    pub fn dynamic_state(mut self, dynamic_state: &'a PipelineDynamicStateCreateInfo) -> Self {
+        self.inner.pDynamicState = dynamic_state;
+        self
+    }
+    #[inline]
+    pub fn layout(mut self, layout: PipelineLayout) -> Self {
+        self.inner.layout = layout;
+        self
+    }
+    #[inline]
+    pub fn render_pass(mut self, render_pass: RenderPass) -> Self {
+        self.inner.renderPass = render_pass;
+        self
+    }
+    #[inline]
+    pub fn subpass(mut self, subpass: u32) -> Self {
+        self.inner.subpass = subpass;
+        self
+    }
+    #[inline]
+    pub fn base_pipeline_handle(mut self, base_pipeline_handle: Pipeline) -> Self {
+        self.inner.basePipelineHandle = base_pipeline_handle;
+        self
+    }
+    #[inline]
+    pub fn base_pipeline_index(mut self, base_pipeline_index: i32) -> Self {
+        self.inner.basePipelineIndex = base_pipeline_index;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsGraphicsPipelineCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{ComputePipelineCreateInfo, ComputePipelineCreateInfoBuilder}
+impl<'a> ComputePipelineCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn stage(mut self, stage: PipelineShaderStageCreateInfo) -> Self {
+        self.inner.stage = stage;
+        self
+    }
+    #[inline]
+    pub fn layout(mut self, layout: PipelineLayout) -> Self {
+        self.inner.layout = layout;
+        self
+    }
+    #[inline]
+    pub fn base_pipeline_handle(mut self, base_pipeline_handle: Pipeline) -> Self {
+        self.inner.basePipelineHandle = base_pipeline_handle;
+        self
+    }
+    #[inline]
+    pub fn base_pipeline_index(mut self, base_pipeline_index: i32) -> Self {
+        self.inner.basePipelineIndex = base_pipeline_index;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsComputePipelineCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{PipelineLayoutCreateInfo, PipelineLayoutCreateInfoBuilder}
+impl<'a> PipelineLayoutCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PipelineLayoutCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn set_layouts(mut self, set_layouts: &'a [DescriptorSetLayout]) -> Self {
+        self.inner.setLayoutCount = set_layouts.len() as u32;
+        self.inner.pSetLayouts = set_layouts.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_constant_ranges(mut self, push_constant_ranges: &'a [PushConstantRange]) -> Self {
+        self.inner.pushConstantRangeCount = push_constant_ranges.len() as u32;
+        self.inner.pPushConstantRanges = push_constant_ranges.as_ptr();
+        self
+    }
+}
+builder!{SamplerCreateInfo, SamplerCreateInfoBuilder}
+impl<'a> SamplerCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: SamplerCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn mag_filter(mut self, mag_filter: Filter) -> Self {
+        self.inner.magFilter = mag_filter;
+        self
+    }
+    #[inline]
+    pub fn min_filter(mut self, min_filter: Filter) -> Self {
+        self.inner.minFilter = min_filter;
+        self
+    }
+    #[inline]
+    pub fn mipmap_mode(mut self, mipmap_mode: SamplerMipmapMode) -> Self {
+        self.inner.mipmapMode = mipmap_mode;
+        self
+    }
+    #[inline]
+    pub fn address_mode_u(mut self, address_mode_u: SamplerAddressMode) -> Self {
+        self.inner.addressModeU = address_mode_u;
+        self
+    }
+    #[inline]
+    pub fn address_mode_v(mut self, address_mode_v: SamplerAddressMode) -> Self {
+        self.inner.addressModeV = address_mode_v;
+        self
+    }
+    #[inline]
+    pub fn address_mode_w(mut self, address_mode_w: SamplerAddressMode) -> Self {
+        self.inner.addressModeW = address_mode_w;
+        self
+    }
+    #[inline]
+    pub fn mip_lod_bias(mut self, mip_lod_bias: ::std::os::raw::c_float) -> Self {
+        self.inner.mipLodBias = mip_lod_bias;
+        self
+    }
+    #[inline]
+    pub fn anisotropy_enable(mut self, anisotropy_enable: Bool32) -> Self {
+        self.inner.anisotropyEnable = anisotropy_enable;
+        self
+    }
+    #[inline]
+    pub fn max_anisotropy(mut self, max_anisotropy: ::std::os::raw::c_float) -> Self {
+        self.inner.maxAnisotropy = max_anisotropy;
+        self
+    }
+    #[inline]
+    pub fn compare_enable(mut self, compare_enable: Bool32) -> Self {
+        self.inner.compareEnable = compare_enable;
+        self
+    }
+    #[inline]
+    pub fn compare_op(mut self, compare_op: CompareOp) -> Self {
+        self.inner.compareOp = compare_op;
+        self
+    }
+    #[inline]
+    pub fn min_lod(mut self, min_lod: ::std::os::raw::c_float) -> Self {
+        self.inner.minLod = min_lod;
+        self
+    }
+    #[inline]
+    pub fn max_lod(mut self, max_lod: ::std::os::raw::c_float) -> Self {
+        self.inner.maxLod = max_lod;
+        self
+    }
+    #[inline]
+    pub fn border_color(mut self, border_color: BorderColor) -> Self {
+        self.inner.borderColor = border_color;
+        self
+    }
+    #[inline]
+    pub fn unnormalized_coordinates(mut self, unnormalized_coordinates: Bool32) -> Self {
+        self.inner.unnormalizedCoordinates = unnormalized_coordinates;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsSamplerCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{DescriptorSetLayoutCreateInfo, DescriptorSetLayoutCreateInfoBuilder}
+impl<'a> DescriptorSetLayoutCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: DescriptorSetLayoutCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn bindings(mut self, bindings: &'a [DescriptorSetLayoutBinding]) -> Self {
+        self.inner.bindingCount = bindings.len() as u32;
+        self.inner.pBindings = bindings.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsDescriptorSetLayoutCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{DescriptorPoolCreateInfo, DescriptorPoolCreateInfoBuilder}
+impl<'a> DescriptorPoolCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: DescriptorPoolCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn max_sets(mut self, max_sets: u32) -> Self {
+        self.inner.maxSets = max_sets;
+        self
+    }
+    #[inline]
+    pub fn pool_sizes(mut self, pool_sizes: &'a [DescriptorPoolSize]) -> Self {
+        self.inner.poolSizeCount = pool_sizes.len() as u32;
+        self.inner.pPoolSizes = pool_sizes.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsDescriptorPoolCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{DescriptorSetAllocateInfo, DescriptorSetAllocateInfoBuilder}
+impl<'a> DescriptorSetAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn descriptor_pool(mut self, descriptor_pool: DescriptorPool) -> Self {
+        self.inner.descriptorPool = descriptor_pool;
+        self
+    }
+    #[inline]
+    pub fn set_layouts(mut self, set_layouts: &'a [DescriptorSetLayout]) -> Self {
+        self.inner.descriptorSetCount = set_layouts.len() as u32;
+        self.inner.pSetLayouts = set_layouts.as_ptr();
+        self
+    }
+}
+builder!{DescriptorImageInfo, DescriptorImageInfoBuilder}
+impl<'a> DescriptorImageInfoBuilder<'a> {
+    #[inline]
+    pub fn sampler(mut self, sampler: Sampler) -> Self {
+        self.inner.sampler = sampler;
+        self
+    }
+    #[inline]
+    pub fn image_view(mut self, image_view: ImageView) -> Self {
+        self.inner.imageView = image_view;
+        self
+    }
+    #[inline]
+    pub fn image_layout(mut self, image_layout: ImageLayout) -> Self {
+        self.inner.imageLayout = image_layout;
+        self
+    }
+}
+builder!{DescriptorBufferInfo, DescriptorBufferInfoBuilder}
+impl<'a> DescriptorBufferInfoBuilder<'a> {
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+    #[inline]
+    pub fn offset(mut self, offset: DeviceSize) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+    #[inline]
+    pub fn range(mut self, range: DeviceSize) -> Self {
+        self.inner.range = range;
+        self
+    }
+}
+builder!{FramebufferCreateInfo, FramebufferCreateInfoBuilder}
+impl<'a> FramebufferCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: FramebufferCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn render_pass(mut self, render_pass: RenderPass) -> Self {
+        self.inner.renderPass = render_pass;
+        self
+    }
+    #[inline]
+    pub fn attachment_count(mut self, attachment_count: u32) -> Self {
+        self.inner.attachmentCount = attachment_count;
+        self
+    }
+    #[inline]
+    pub fn attachments(mut self, attachments: &'a [ImageView]) -> Self {
+        self.inner.attachmentCount = attachments.len() as u32;
+        self.inner.pAttachments = attachments.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: u32) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: u32) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn layers(mut self, layers: u32) -> Self {
+        self.inner.layers = layers;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsFramebufferCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{RenderPassCreateInfo, RenderPassCreateInfoBuilder}
+impl<'a> RenderPassCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: RenderPassCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn attachments(mut self, attachments: &'a [AttachmentDescription]) -> Self {
+        self.inner.attachmentCount = attachments.len() as u32;
+        self.inner.pAttachments = attachments.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn subpasses(mut self, subpasses: &'a [SubpassDescription]) -> Self {
+        self.inner.subpassCount = subpasses.len() as u32;
+        self.inner.pSubpasses = subpasses.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn dependencies(mut self, dependencies: &'a [SubpassDependency]) -> Self {
+        self.inner.dependencyCount = dependencies.len() as u32;
+        self.inner.pDependencies = dependencies.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsRenderPassCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{CommandPoolCreateInfo, CommandPoolCreateInfoBuilder}
+impl<'a> CommandPoolCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: CommandPoolCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn queue_family_index(mut self, queue_family_index: u32) -> Self {
+        self.inner.queueFamilyIndex = queue_family_index;
+        self
+    }
+}
+builder!{CommandBufferAllocateInfo, CommandBufferAllocateInfoBuilder}
+impl<'a> CommandBufferAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn command_pool(mut self, command_pool: CommandPool) -> Self {
+        self.inner.commandPool = command_pool;
+        self
+    }
+    #[inline]
+    pub fn level(mut self, level: CommandBufferLevel) -> Self {
+        self.inner.level = level;
+        self
+    }
+    #[inline]
+    pub fn command_buffer_count(mut self, command_buffer_count: u32) -> Self {
+        self.inner.commandBufferCount = command_buffer_count;
+        self
+    }
+}
+builder!{CommandBufferInheritanceInfo, CommandBufferInheritanceInfoBuilder}
+impl<'a> CommandBufferInheritanceInfoBuilder<'a> {
+    #[inline]
+    pub fn render_pass(mut self, render_pass: RenderPass) -> Self {
+        self.inner.renderPass = render_pass;
+        self
+    }
+    #[inline]
+    pub fn subpass(mut self, subpass: u32) -> Self {
+        self.inner.subpass = subpass;
+        self
+    }
+    #[inline]
+    pub fn framebuffer(mut self, framebuffer: Framebuffer) -> Self {
+        self.inner.framebuffer = framebuffer;
+        self
+    }
+    #[inline]
+    pub fn occlusion_query_enable(mut self, occlusion_query_enable: Bool32) -> Self {
+        self.inner.occlusionQueryEnable = occlusion_query_enable;
+        self
+    }
+    #[inline]
+    pub fn query_flags(mut self, query_flags: QueryControlFlags) -> Self {
+        self.inner.queryFlags = query_flags;
+        self
+    }
+    #[inline]
+    pub fn pipeline_statistics(mut self, pipeline_statistics: QueryPipelineStatisticFlags) -> Self {
+        self.inner.pipelineStatistics = pipeline_statistics;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsCommandBufferInheritanceInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{CommandBufferBeginInfo, CommandBufferBeginInfoBuilder}
+impl<'a> CommandBufferBeginInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: CommandBufferUsageFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn inheritance_info(mut self, inheritance_info: &'a CommandBufferInheritanceInfo) -> Self {
+        self.inner.pInheritanceInfo = inheritance_info;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsCommandBufferBeginInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{RenderPassBeginInfo, RenderPassBeginInfoBuilder}
+impl<'a> RenderPassBeginInfoBuilder<'a> {
+    #[inline]
+    pub fn render_pass(mut self, render_pass: RenderPass) -> Self {
+        self.inner.renderPass = render_pass;
+        self
+    }
+    #[inline]
+    pub fn framebuffer(mut self, framebuffer: Framebuffer) -> Self {
+        self.inner.framebuffer = framebuffer;
+        self
+    }
+    #[inline]
+    pub fn render_area(mut self, render_area: Rect2D) -> Self {
+        self.inner.renderArea = render_area;
+        self
+    }
+    #[inline]
+    pub fn clear_values(mut self, clear_values: &'a [ClearValue]) -> Self {
+        self.inner.clearValueCount = clear_values.len() as u32;
+        self.inner.pClearValues = clear_values.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsRenderPassBeginInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{BindBufferMemoryInfo, BindBufferMemoryInfoBuilder}
+impl<'a> BindBufferMemoryInfoBuilder<'a> {
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+    #[inline]
+    pub fn memory(mut self, memory: DeviceMemory) -> Self {
+        self.inner.memory = memory;
+        self
+    }
+    #[inline]
+    pub fn memory_offset(mut self, memory_offset: DeviceSize) -> Self {
+        self.inner.memoryOffset = memory_offset;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsBindBufferMemoryInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{BindImageMemoryInfo, BindImageMemoryInfoBuilder}
+impl<'a> BindImageMemoryInfoBuilder<'a> {
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+    #[inline]
+    pub fn memory(mut self, memory: DeviceMemory) -> Self {
+        self.inner.memory = memory;
+        self
+    }
+    #[inline]
+    pub fn memory_offset(mut self, memory_offset: DeviceSize) -> Self {
+        self.inner.memoryOffset = memory_offset;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsBindImageMemoryInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{MemoryDedicatedAllocateInfo, MemoryDedicatedAllocateInfoBuilder}
+impl<'a> MemoryDedicatedAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+}
+builder!{MemoryAllocateFlagsInfo, MemoryAllocateFlagsInfoBuilder}
+impl<'a> MemoryAllocateFlagsInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: MemoryAllocateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn device_mask(mut self, device_mask: u32) -> Self {
+        self.inner.deviceMask = device_mask;
+        self
+    }
+}
+builder!{DeviceGroupRenderPassBeginInfo, DeviceGroupRenderPassBeginInfoBuilder}
+impl<'a> DeviceGroupRenderPassBeginInfoBuilder<'a> {
+    #[inline]
+    pub fn device_mask(mut self, device_mask: u32) -> Self {
+        self.inner.deviceMask = device_mask;
+        self
+    }
+    #[inline]
+    pub fn device_render_areas(mut self, device_render_areas: &'a [Rect2D]) -> Self {
+        self.inner.deviceRenderAreaCount = device_render_areas.len() as u32;
+        self.inner.pDeviceRenderAreas = device_render_areas.as_ptr();
+        self
+    }
+}
+builder!{DeviceGroupCommandBufferBeginInfo, DeviceGroupCommandBufferBeginInfoBuilder}
+impl<'a> DeviceGroupCommandBufferBeginInfoBuilder<'a> {
+    #[inline]
+    pub fn device_mask(mut self, device_mask: u32) -> Self {
+        self.inner.deviceMask = device_mask;
+        self
+    }
+}
+builder!{DeviceGroupSubmitInfo, DeviceGroupSubmitInfoBuilder}
+impl<'a> DeviceGroupSubmitInfoBuilder<'a> {
+    #[inline]
+    pub fn wait_semaphore_device_indices(mut self, wait_semaphore_device_indices: &'a [u32]) -> Self {
+        self.inner.waitSemaphoreCount = wait_semaphore_device_indices.len() as u32;
+        self.inner.pWaitSemaphoreDeviceIndices = wait_semaphore_device_indices.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn command_buffer_device_masks(mut self, command_buffer_device_masks: &'a [u32]) -> Self {
+        self.inner.commandBufferCount = command_buffer_device_masks.len() as u32;
+        self.inner.pCommandBufferDeviceMasks = command_buffer_device_masks.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn signal_semaphore_device_indices(mut self, signal_semaphore_device_indices: &'a [u32]) -> Self {
+        self.inner.signalSemaphoreCount = signal_semaphore_device_indices.len() as u32;
+        self.inner.pSignalSemaphoreDeviceIndices = signal_semaphore_device_indices.as_ptr();
+        self
+    }
+}
+builder!{DeviceGroupBindSparseInfo, DeviceGroupBindSparseInfoBuilder}
+impl<'a> DeviceGroupBindSparseInfoBuilder<'a> {
+    #[inline]
+    pub fn resource_device_index(mut self, resource_device_index: u32) -> Self {
+        self.inner.resourceDeviceIndex = resource_device_index;
+        self
+    }
+    #[inline]
+    pub fn memory_device_index(mut self, memory_device_index: u32) -> Self {
+        self.inner.memoryDeviceIndex = memory_device_index;
+        self
+    }
+}
+builder!{BindBufferMemoryDeviceGroupInfo, BindBufferMemoryDeviceGroupInfoBuilder}
+impl<'a> BindBufferMemoryDeviceGroupInfoBuilder<'a> {
+    #[inline]
+    pub fn device_indices(mut self, device_indices: &'a [u32]) -> Self {
+        self.inner.deviceIndexCount = device_indices.len() as u32;
+        self.inner.pDeviceIndices = device_indices.as_ptr();
+        self
+    }
+}
+builder!{BindImageMemoryDeviceGroupInfo, BindImageMemoryDeviceGroupInfoBuilder}
+impl<'a> BindImageMemoryDeviceGroupInfoBuilder<'a> {
+    #[inline]
+    pub fn device_indices(mut self, device_indices: &'a [u32]) -> Self {
+        self.inner.deviceIndexCount = device_indices.len() as u32;
+        self.inner.pDeviceIndices = device_indices.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn split_instance_bind_regions(mut self, split_instance_bind_regions: &'a [Rect2D]) -> Self {
+        self.inner.splitInstanceBindRegionCount = split_instance_bind_regions.len() as u32;
+        self.inner.pSplitInstanceBindRegions = split_instance_bind_regions.as_ptr();
+        self
+    }
+}
+builder!{DeviceGroupDeviceCreateInfo, DeviceGroupDeviceCreateInfoBuilder}
+impl<'a> DeviceGroupDeviceCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn physical_devices(mut self, physical_devices: &'a [PhysicalDevice]) -> Self {
+        self.inner.physicalDeviceCount = physical_devices.len() as u32;
+        self.inner.pPhysicalDevices = physical_devices.as_ptr();
+        self
+    }
+}
+builder!{BufferMemoryRequirementsInfo2, BufferMemoryRequirementsInfo2Builder}
+impl<'a> BufferMemoryRequirementsInfo2Builder<'a> {
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+}
+builder!{ImageMemoryRequirementsInfo2, ImageMemoryRequirementsInfo2Builder}
+impl<'a> ImageMemoryRequirementsInfo2Builder<'a> {
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsImageMemoryRequirementsInfo2>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{ImageSparseMemoryRequirementsInfo2, ImageSparseMemoryRequirementsInfo2Builder}
+impl<'a> ImageSparseMemoryRequirementsInfo2Builder<'a> {
+    #[inline]
+    pub fn image(mut self, image: Image) -> Self {
+        self.inner.image = image;
+        self
+    }
+}
+builder!{PhysicalDeviceImageFormatInfo2, PhysicalDeviceImageFormatInfo2Builder}
+impl<'a> PhysicalDeviceImageFormatInfo2Builder<'a> {
+    #[inline]
+    pub fn format(mut self, format: Format) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn _type(mut self, _type: ImageType) -> Self {
+        self.inner._type = _type;
+        self
+    }
+    #[inline]
+    pub fn tiling(mut self, tiling: ImageTiling) -> Self {
+        self.inner.tiling = tiling;
+        self
+    }
+    #[inline]
+    pub fn usage(mut self, usage: ImageUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+    #[inline]
+    pub fn flags(mut self, flags: ImageCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsPhysicalDeviceImageFormatInfo2>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{PhysicalDeviceSparseImageFormatInfo2, PhysicalDeviceSparseImageFormatInfo2Builder}
+impl<'a> PhysicalDeviceSparseImageFormatInfo2Builder<'a> {
+    #[inline]
+    pub fn format(mut self, format: Format) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn _type(mut self, _type: ImageType) -> Self {
+        self.inner._type = _type;
+        self
+    }
+    #[inline]
+    pub fn samples(mut self, samples: SampleCountFlag) -> Self {
+        self.inner.samples = samples;
+        self
+    }
+    #[inline]
+    pub fn usage(mut self, usage: ImageUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+    #[inline]
+    pub fn tiling(mut self, tiling: ImageTiling) -> Self {
+        self.inner.tiling = tiling;
+        self
+    }
+}
+builder!{RenderPassInputAttachmentAspectCreateInfo, RenderPassInputAttachmentAspectCreateInfoBuilder}
+impl<'a> RenderPassInputAttachmentAspectCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn aspect_references(mut self, aspect_references: &'a [InputAttachmentAspectReference]) -> Self {
+        self.inner.aspectReferenceCount = aspect_references.len() as u32;
+        self.inner.pAspectReferences = aspect_references.as_ptr();
+        self
+    }
+}
+builder!{ImageViewUsageCreateInfo, ImageViewUsageCreateInfoBuilder}
+impl<'a> ImageViewUsageCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn usage(mut self, usage: ImageUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+}
+builder!{PipelineTessellationDomainOriginStateCreateInfo, PipelineTessellationDomainOriginStateCreateInfoBuilder}
+impl<'a> PipelineTessellationDomainOriginStateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn domain_origin(mut self, domain_origin: TessellationDomainOrigin) -> Self {
+        self.inner.domainOrigin = domain_origin;
+        self
+    }
+}
+builder!{RenderPassMultiviewCreateInfo, RenderPassMultiviewCreateInfoBuilder}
+impl<'a> RenderPassMultiviewCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn view_masks(mut self, view_masks: &'a [u32]) -> Self {
+        self.inner.subpassCount = view_masks.len() as u32;
+        self.inner.pViewMasks = view_masks.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn view_offsets(mut self, view_offsets: &'a [i32]) -> Self {
+        self.inner.dependencyCount = view_offsets.len() as u32;
+        self.inner.pViewOffsets = view_offsets.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn correlation_masks(mut self, correlation_masks: &'a [u32]) -> Self {
+        self.inner.correlationMaskCount = correlation_masks.len() as u32;
+        self.inner.pCorrelationMasks = correlation_masks.as_ptr();
+        self
+    }
+}
+builder!{DeviceQueueInfo2, DeviceQueueInfo2Builder}
+impl<'a> DeviceQueueInfo2Builder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: DeviceQueueCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn queue_family_index(mut self, queue_family_index: u32) -> Self {
+        self.inner.queueFamilyIndex = queue_family_index;
+        self
+    }
+    #[inline]
+    pub fn queue_index(mut self, queue_index: u32) -> Self {
+        self.inner.queueIndex = queue_index;
+        self
+    }
+}
+builder!{ProtectedSubmitInfo, ProtectedSubmitInfoBuilder}
+impl<'a> ProtectedSubmitInfoBuilder<'a> {
+    #[inline]
+    pub fn protected_submit(mut self, protected_submit: Bool32) -> Self {
+        self.inner.protectedSubmit = protected_submit;
+        self
+    }
+}
+builder!{SamplerYcbcrConversionCreateInfo, SamplerYcbcrConversionCreateInfoBuilder}
+impl<'a> SamplerYcbcrConversionCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn format(mut self, format: Format) -> Self {
+        self.inner.format = format;
+        self
+    }
+    #[inline]
+    pub fn ycbcr_model(mut self, ycbcr_model: SamplerYcbcrModelConversion) -> Self {
+        self.inner.ycbcrModel = ycbcr_model;
+        self
+    }
+    #[inline]
+    pub fn ycbcr_range(mut self, ycbcr_range: SamplerYcbcrRange) -> Self {
+        self.inner.ycbcrRange = ycbcr_range;
+        self
+    }
+    #[inline]
+    pub fn components(mut self, components: ComponentMapping) -> Self {
+        self.inner.components = components;
+        self
+    }
+    #[inline]
+    pub fn x_chroma_offset(mut self, x_chroma_offset: ChromaLocation) -> Self {
+        self.inner.xChromaOffset = x_chroma_offset;
+        self
+    }
+    #[inline]
+    pub fn y_chroma_offset(mut self, y_chroma_offset: ChromaLocation) -> Self {
+        self.inner.yChromaOffset = y_chroma_offset;
+        self
+    }
+    #[inline]
+    pub fn chroma_filter(mut self, chroma_filter: Filter) -> Self {
+        self.inner.chromaFilter = chroma_filter;
+        self
+    }
+    #[inline]
+    pub fn force_explicit_reconstruction(mut self, force_explicit_reconstruction: Bool32) -> Self {
+        self.inner.forceExplicitReconstruction = force_explicit_reconstruction;
+        self
+    }
+}
+builder!{SamplerYcbcrConversionInfo, SamplerYcbcrConversionInfoBuilder}
+impl<'a> SamplerYcbcrConversionInfoBuilder<'a> {
+    #[inline]
+    pub fn conversion(mut self, conversion: SamplerYcbcrConversion) -> Self {
+        self.inner.conversion = conversion;
+        self
+    }
+}
+builder!{BindImagePlaneMemoryInfo, BindImagePlaneMemoryInfoBuilder}
+impl<'a> BindImagePlaneMemoryInfoBuilder<'a> {
+    #[inline]
+    pub fn plane_aspect(mut self, plane_aspect: ImageAspectFlag) -> Self {
+        self.inner.planeAspect = plane_aspect;
+        self
+    }
+}
+builder!{ImagePlaneMemoryRequirementsInfo, ImagePlaneMemoryRequirementsInfoBuilder}
+impl<'a> ImagePlaneMemoryRequirementsInfoBuilder<'a> {
+    #[inline]
+    pub fn plane_aspect(mut self, plane_aspect: ImageAspectFlag) -> Self {
+        self.inner.planeAspect = plane_aspect;
+        self
+    }
+}
+builder!{DescriptorUpdateTemplateCreateInfo, DescriptorUpdateTemplateCreateInfoBuilder}
+impl<'a> DescriptorUpdateTemplateCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: DescriptorUpdateTemplateCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn descriptor_update_entries(mut self, descriptor_update_entries: &'a [DescriptorUpdateTemplateEntry]) -> Self {
+        self.inner.descriptorUpdateEntryCount = descriptor_update_entries.len() as u32;
+        self.inner.pDescriptorUpdateEntries = descriptor_update_entries.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn template_type(mut self, template_type: DescriptorUpdateTemplateType) -> Self {
+        self.inner.templateType = template_type;
+        self
+    }
+    #[inline]
+    pub fn descriptor_set_layout(mut self, descriptor_set_layout: DescriptorSetLayout) -> Self {
+        self.inner.descriptorSetLayout = descriptor_set_layout;
+        self
+    }
+    #[inline]
+    pub fn pipeline_bind_point(mut self, pipeline_bind_point: PipelineBindPoint) -> Self {
+        self.inner.pipelineBindPoint = pipeline_bind_point;
+        self
+    }
+    #[inline]
+    pub fn pipeline_layout(mut self, pipeline_layout: PipelineLayout) -> Self {
+        self.inner.pipelineLayout = pipeline_layout;
+        self
+    }
+    #[inline]
+    pub fn set(mut self, set: u32) -> Self {
+        self.inner.set = set;
+        self
+    }
+}
+builder!{PhysicalDeviceExternalImageFormatInfo, PhysicalDeviceExternalImageFormatInfoBuilder}
+impl<'a> PhysicalDeviceExternalImageFormatInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_type(mut self, handle_type: ExternalMemoryHandleTypeFlag) -> Self {
+        self.inner.handleType = handle_type;
+        self
+    }
+}
+builder!{PhysicalDeviceExternalBufferInfo, PhysicalDeviceExternalBufferInfoBuilder}
+impl<'a> PhysicalDeviceExternalBufferInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: BufferCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn usage(mut self, usage: BufferUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+    #[inline]
+    pub fn handle_type(mut self, handle_type: ExternalMemoryHandleTypeFlag) -> Self {
+        self.inner.handleType = handle_type;
+        self
+    }
+}
+builder!{ExternalMemoryImageCreateInfo, ExternalMemoryImageCreateInfoBuilder}
+impl<'a> ExternalMemoryImageCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_types(mut self, handle_types: ExternalMemoryHandleTypeFlags) -> Self {
+        self.inner.handleTypes = handle_types;
+        self
+    }
+}
+builder!{ExternalMemoryBufferCreateInfo, ExternalMemoryBufferCreateInfoBuilder}
+impl<'a> ExternalMemoryBufferCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_types(mut self, handle_types: ExternalMemoryHandleTypeFlags) -> Self {
+        self.inner.handleTypes = handle_types;
+        self
+    }
+}
+builder!{ExportMemoryAllocateInfo, ExportMemoryAllocateInfoBuilder}
+impl<'a> ExportMemoryAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_types(mut self, handle_types: ExternalMemoryHandleTypeFlags) -> Self {
+        self.inner.handleTypes = handle_types;
+        self
+    }
+}
+builder!{PhysicalDeviceExternalFenceInfo, PhysicalDeviceExternalFenceInfoBuilder}
+impl<'a> PhysicalDeviceExternalFenceInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_type(mut self, handle_type: ExternalFenceHandleTypeFlag) -> Self {
+        self.inner.handleType = handle_type;
+        self
+    }
+}
+builder!{ExportFenceCreateInfo, ExportFenceCreateInfoBuilder}
+impl<'a> ExportFenceCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_types(mut self, handle_types: ExternalFenceHandleTypeFlags) -> Self {
+        self.inner.handleTypes = handle_types;
+        self
+    }
+}
+builder!{ExportSemaphoreCreateInfo, ExportSemaphoreCreateInfoBuilder}
+impl<'a> ExportSemaphoreCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_types(mut self, handle_types: ExternalSemaphoreHandleTypeFlags) -> Self {
+        self.inner.handleTypes = handle_types;
+        self
+    }
+}
+builder!{PhysicalDeviceExternalSemaphoreInfo, PhysicalDeviceExternalSemaphoreInfoBuilder}
+impl<'a> PhysicalDeviceExternalSemaphoreInfoBuilder<'a> {
+    #[inline]
+    pub fn handle_type(mut self, handle_type: ExternalSemaphoreHandleTypeFlag) -> Self {
+        self.inner.handleType = handle_type;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsPhysicalDeviceExternalSemaphoreInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{ImageFormatListCreateInfo, ImageFormatListCreateInfoBuilder}
+impl<'a> ImageFormatListCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn view_formats(mut self, view_formats: &'a [Format]) -> Self {
+        self.inner.viewFormatCount = view_formats.len() as u32;
+        self.inner.pViewFormats = view_formats.as_ptr();
+        self
+    }
+}
+builder!{RenderPassCreateInfo2, RenderPassCreateInfo2Builder}
+impl<'a> RenderPassCreateInfo2Builder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: RenderPassCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn attachments(mut self, attachments: &'a [AttachmentDescription2]) -> Self {
+        self.inner.attachmentCount = attachments.len() as u32;
+        self.inner.pAttachments = attachments.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn subpasses(mut self, subpasses: &'a [SubpassDescription2]) -> Self {
+        self.inner.subpassCount = subpasses.len() as u32;
+        self.inner.pSubpasses = subpasses.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn dependencies(mut self, dependencies: &'a [SubpassDependency2]) -> Self {
+        self.inner.dependencyCount = dependencies.len() as u32;
+        self.inner.pDependencies = dependencies.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn correlated_view_masks(mut self, correlated_view_masks: &'a [u32]) -> Self {
+        self.inner.correlatedViewMaskCount = correlated_view_masks.len() as u32;
+        self.inner.pCorrelatedViewMasks = correlated_view_masks.as_ptr();
+        self
+    }
+}
+builder!{SubpassBeginInfo, SubpassBeginInfoBuilder}
+impl<'a> SubpassBeginInfoBuilder<'a> {
+    #[inline]
+    pub fn contents(mut self, contents: SubpassContents) -> Self {
+        self.inner.contents = contents;
+        self
+    }
+}
+builder!{SubpassEndInfo, SubpassEndInfoBuilder}
+impl<'a> SubpassEndInfoBuilder<'a> {
+}
+builder!{DescriptorSetLayoutBindingFlagsCreateInfo, DescriptorSetLayoutBindingFlagsCreateInfoBuilder}
+impl<'a> DescriptorSetLayoutBindingFlagsCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn binding_flags(mut self, binding_flags: &'a [DescriptorBindingFlags]) -> Self {
+        self.inner.bindingCount = binding_flags.len() as u32;
+        self.inner.pBindingFlags = binding_flags.as_ptr();
+        self
+    }
+}
+builder!{DescriptorSetVariableDescriptorCountAllocateInfo, DescriptorSetVariableDescriptorCountAllocateInfoBuilder}
+impl<'a> DescriptorSetVariableDescriptorCountAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn descriptor_counts(mut self, descriptor_counts: &'a [u32]) -> Self {
+        self.inner.descriptorSetCount = descriptor_counts.len() as u32;
+        self.inner.pDescriptorCounts = descriptor_counts.as_ptr();
+        self
+    }
+}
+builder!{ImageStencilUsageCreateInfo, ImageStencilUsageCreateInfoBuilder}
+impl<'a> ImageStencilUsageCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn stencil_usage(mut self, stencil_usage: ImageUsageFlags) -> Self {
+        self.inner.stencilUsage = stencil_usage;
+        self
+    }
+}
+builder!{SamplerReductionModeCreateInfo, SamplerReductionModeCreateInfoBuilder}
+impl<'a> SamplerReductionModeCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn reduction_mode(mut self, reduction_mode: SamplerReductionMode) -> Self {
+        self.inner.reductionMode = reduction_mode;
+        self
+    }
+}
+builder!{FramebufferAttachmentImageInfo, FramebufferAttachmentImageInfoBuilder}
+impl<'a> FramebufferAttachmentImageInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: ImageCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn usage(mut self, usage: ImageUsageFlags) -> Self {
+        self.inner.usage = usage;
+        self
+    }
+    #[inline]
+    pub fn width(mut self, width: u32) -> Self {
+        self.inner.width = width;
+        self
+    }
+    #[inline]
+    pub fn height(mut self, height: u32) -> Self {
+        self.inner.height = height;
+        self
+    }
+    #[inline]
+    pub fn layer_count(mut self, layer_count: u32) -> Self {
+        self.inner.layerCount = layer_count;
+        self
+    }
+    #[inline]
+    pub fn view_formats(mut self, view_formats: &'a [Format]) -> Self {
+        self.inner.viewFormatCount = view_formats.len() as u32;
+        self.inner.pViewFormats = view_formats.as_ptr();
+        self
+    }
+}
+builder!{FramebufferAttachmentsCreateInfo, FramebufferAttachmentsCreateInfoBuilder}
+impl<'a> FramebufferAttachmentsCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn attachment_image_infos(mut self, attachment_image_infos: &'a [FramebufferAttachmentImageInfo]) -> Self {
+        self.inner.attachmentImageInfoCount = attachment_image_infos.len() as u32;
+        self.inner.pAttachmentImageInfos = attachment_image_infos.as_ptr();
+        self
+    }
+}
+builder!{RenderPassAttachmentBeginInfo, RenderPassAttachmentBeginInfoBuilder}
+impl<'a> RenderPassAttachmentBeginInfoBuilder<'a> {
+    #[inline]
+    pub fn attachments(mut self, attachments: &'a [ImageView]) -> Self {
+        self.inner.attachmentCount = attachments.len() as u32;
+        self.inner.pAttachments = attachments.as_ptr();
+        self
+    }
+}
+builder!{SemaphoreTypeCreateInfo, SemaphoreTypeCreateInfoBuilder}
+impl<'a> SemaphoreTypeCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn semaphore_type(mut self, semaphore_type: SemaphoreType) -> Self {
+        self.inner.semaphoreType = semaphore_type;
+        self
+    }
+    #[inline]
+    pub fn initial_value(mut self, initial_value: u64) -> Self {
+        self.inner.initialValue = initial_value;
+        self
+    }
+}
+builder!{TimelineSemaphoreSubmitInfo, TimelineSemaphoreSubmitInfoBuilder}
+impl<'a> TimelineSemaphoreSubmitInfoBuilder<'a> {
+    #[inline]
+    pub fn wait_semaphore_values(mut self, wait_semaphore_values: &'a [u64]) -> Self {
+        self.inner.waitSemaphoreValueCount = wait_semaphore_values.len() as u32;
+        self.inner.pWaitSemaphoreValues = wait_semaphore_values.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn signal_semaphore_values(mut self, signal_semaphore_values: &'a [u64]) -> Self {
+        self.inner.signalSemaphoreValueCount = signal_semaphore_values.len() as u32;
+        self.inner.pSignalSemaphoreValues = signal_semaphore_values.as_ptr();
+        self
+    }
+}
+builder!{SemaphoreWaitInfo, SemaphoreWaitInfoBuilder}
+impl<'a> SemaphoreWaitInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: SemaphoreWaitFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn semaphores(mut self, semaphores: &'a [Semaphore]) -> Self {
+        self.inner.semaphoreCount = semaphores.len() as u32;
+        self.inner.pSemaphores = semaphores.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn values(mut self, values: &'a [u64]) -> Self {
+        self.inner.semaphoreCount = values.len() as u32;
+        self.inner.pValues = values.as_ptr();
+        self
+    }
+}
+builder!{SemaphoreSignalInfo, SemaphoreSignalInfoBuilder}
+impl<'a> SemaphoreSignalInfoBuilder<'a> {
+    #[inline]
+    pub fn semaphore(mut self, semaphore: Semaphore) -> Self {
+        self.inner.semaphore = semaphore;
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: u64) -> Self {
+        self.inner.value = value;
+        self
+    }
+}
+builder!{BufferDeviceAddressInfo, BufferDeviceAddressInfoBuilder}
+impl<'a> BufferDeviceAddressInfoBuilder<'a> {
+    #[inline]
+    pub fn buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer;
+        self
+    }
+}
+builder!{BufferOpaqueCaptureAddressCreateInfo, BufferOpaqueCaptureAddressCreateInfoBuilder}
+impl<'a> BufferOpaqueCaptureAddressCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn opaque_capture_address(mut self, opaque_capture_address: u64) -> Self {
+        self.inner.opaqueCaptureAddress = opaque_capture_address;
+        self
+    }
+}
+builder!{MemoryOpaqueCaptureAddressAllocateInfo, MemoryOpaqueCaptureAddressAllocateInfoBuilder}
+impl<'a> MemoryOpaqueCaptureAddressAllocateInfoBuilder<'a> {
+    #[inline]
+    pub fn opaque_capture_address(mut self, opaque_capture_address: u64) -> Self {
+        self.inner.opaqueCaptureAddress = opaque_capture_address;
+        self
+    }
+}
+builder!{DeviceMemoryOpaqueCaptureAddressInfo, DeviceMemoryOpaqueCaptureAddressInfoBuilder}
+impl<'a> DeviceMemoryOpaqueCaptureAddressInfoBuilder<'a> {
+    #[inline]
+    pub fn memory(mut self, memory: DeviceMemory) -> Self {
+        self.inner.memory = memory;
+        self
+    }
+}
+builder!{PipelineCreationFeedbackCreateInfo, PipelineCreationFeedbackCreateInfoBuilder}
+impl<'a> PipelineCreationFeedbackCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn pipeline_creation_feedback(mut self, pipeline_creation_feedback: &'a mut PipelineCreationFeedback) -> Self {
+        self.inner.pPipelineCreationFeedback = pipeline_creation_feedback;
+        self
+    }
+    #[inline]
+    pub fn pipeline_stage_creation_feedbacks(mut self, pipeline_stage_creation_feedbacks: &'a mut [PipelineCreationFeedback]) -> Self {
+        self.inner.pipelineStageCreationFeedbackCount = pipeline_stage_creation_feedbacks.len() as u32;
+        self.inner.pPipelineStageCreationFeedbacks = pipeline_stage_creation_feedbacks.as_mut_ptr();
+        self
+    }
+}
+builder!{DevicePrivateDataCreateInfo, DevicePrivateDataCreateInfoBuilder}
+impl<'a> DevicePrivateDataCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn private_data_slot_request_count(mut self, private_data_slot_request_count: u32) -> Self {
+        self.inner.privateDataSlotRequestCount = private_data_slot_request_count;
+        self
+    }
+}
+builder!{PrivateDataSlotCreateInfo, PrivateDataSlotCreateInfoBuilder}
+impl<'a> PrivateDataSlotCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: PrivateDataSlotCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+}
+builder!{DependencyInfo, DependencyInfoBuilder}
+impl<'a> DependencyInfoBuilder<'a> {
+    #[inline]
+    pub fn dependency_flags(mut self, dependency_flags: DependencyFlags) -> Self {
+        self.inner.dependencyFlags = dependency_flags;
+        self
+    }
+    #[inline]
+    pub fn memory_barriers(mut self, memory_barriers: &'a [MemoryBarrier2]) -> Self {
+        self.inner.memoryBarrierCount = memory_barriers.len() as u32;
+        self.inner.pMemoryBarriers = memory_barriers.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn buffer_memory_barriers(mut self, buffer_memory_barriers: &'a [BufferMemoryBarrier2]) -> Self {
+        self.inner.bufferMemoryBarrierCount = buffer_memory_barriers.len() as u32;
+        self.inner.pBufferMemoryBarriers = buffer_memory_barriers.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn image_memory_barriers(mut self, image_memory_barriers: &'a [ImageMemoryBarrier2]) -> Self {
+        self.inner.imageMemoryBarrierCount = image_memory_barriers.len() as u32;
+        self.inner.pImageMemoryBarriers = image_memory_barriers.as_ptr();
+        self
+    }
+}
+builder!{SemaphoreSubmitInfo, SemaphoreSubmitInfoBuilder}
+impl<'a> SemaphoreSubmitInfoBuilder<'a> {
+    #[inline]
+    pub fn semaphore(mut self, semaphore: Semaphore) -> Self {
+        self.inner.semaphore = semaphore;
+        self
+    }
+    #[inline]
+    pub fn value(mut self, value: u64) -> Self {
+        self.inner.value = value;
+        self
+    }
+    #[inline]
+    pub fn stage_mask(mut self, stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.stageMask = stage_mask;
+        self
+    }
+    #[inline]
+    pub fn device_index(mut self, device_index: u32) -> Self {
+        self.inner.deviceIndex = device_index;
+        self
+    }
+}
+builder!{CommandBufferSubmitInfo, CommandBufferSubmitInfoBuilder}
+impl<'a> CommandBufferSubmitInfoBuilder<'a> {
+    #[inline]
+    pub fn command_buffer(mut self, command_buffer: CommandBuffer) -> Self {
+        self.inner.commandBuffer = command_buffer;
+        self
+    }
+    #[inline]
+    pub fn device_mask(mut self, device_mask: u32) -> Self {
+        self.inner.deviceMask = device_mask;
+        self
+    }
+}
+builder!{SubmitInfo2, SubmitInfo2Builder}
+impl<'a> SubmitInfo2Builder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: SubmitFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn wait_semaphore_infos(mut self, wait_semaphore_infos: &'a [SemaphoreSubmitInfo]) -> Self {
+        self.inner.waitSemaphoreInfoCount = wait_semaphore_infos.len() as u32;
+        self.inner.pWaitSemaphoreInfos = wait_semaphore_infos.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn command_buffer_infos(mut self, command_buffer_infos: &'a [CommandBufferSubmitInfo]) -> Self {
+        self.inner.commandBufferInfoCount = command_buffer_infos.len() as u32;
+        self.inner.pCommandBufferInfos = command_buffer_infos.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn signal_semaphore_infos(mut self, signal_semaphore_infos: &'a [SemaphoreSubmitInfo]) -> Self {
+        self.inner.signalSemaphoreInfoCount = signal_semaphore_infos.len() as u32;
+        self.inner.pSignalSemaphoreInfos = signal_semaphore_infos.as_ptr();
+        self
+    }
+}
+builder!{CopyBufferInfo2, CopyBufferInfo2Builder}
+impl<'a> CopyBufferInfo2Builder<'a> {
+    #[inline]
+    pub fn src_buffer(mut self, src_buffer: Buffer) -> Self {
+        self.inner.srcBuffer = src_buffer;
+        self
+    }
+    #[inline]
+    pub fn dst_buffer(mut self, dst_buffer: Buffer) -> Self {
+        self.inner.dstBuffer = dst_buffer;
+        self
+    }
+    #[inline]
+    pub fn regions(mut self, regions: &'a [BufferCopy2]) -> Self {
+        self.inner.regionCount = regions.len() as u32;
+        self.inner.pRegions = regions.as_ptr();
+        self
+    }
+}
+builder!{CopyImageInfo2, CopyImageInfo2Builder}
+impl<'a> CopyImageInfo2Builder<'a> {
+    #[inline]
+    pub fn src_image(mut self, src_image: Image) -> Self {
+        self.inner.srcImage = src_image;
+        self
+    }
+    #[inline]
+    pub fn src_image_layout(mut self, src_image_layout: ImageLayout) -> Self {
+        self.inner.srcImageLayout = src_image_layout;
+        self
+    }
+    #[inline]
+    pub fn dst_image(mut self, dst_image: Image) -> Self {
+        self.inner.dstImage = dst_image;
+        self
+    }
+    #[inline]
+    pub fn dst_image_layout(mut self, dst_image_layout: ImageLayout) -> Self {
+        self.inner.dstImageLayout = dst_image_layout;
+        self
+    }
+    #[inline]
+    pub fn regions(mut self, regions: &'a [ImageCopy2]) -> Self {
+        self.inner.regionCount = regions.len() as u32;
+        self.inner.pRegions = regions.as_ptr();
+        self
+    }
+}
+builder!{CopyBufferToImageInfo2, CopyBufferToImageInfo2Builder}
+impl<'a> CopyBufferToImageInfo2Builder<'a> {
+    #[inline]
+    pub fn src_buffer(mut self, src_buffer: Buffer) -> Self {
+        self.inner.srcBuffer = src_buffer;
+        self
+    }
+    #[inline]
+    pub fn dst_image(mut self, dst_image: Image) -> Self {
+        self.inner.dstImage = dst_image;
+        self
+    }
+    #[inline]
+    pub fn dst_image_layout(mut self, dst_image_layout: ImageLayout) -> Self {
+        self.inner.dstImageLayout = dst_image_layout;
+        self
+    }
+    #[inline]
+    pub fn regions(mut self, regions: &'a [BufferImageCopy2]) -> Self {
+        self.inner.regionCount = regions.len() as u32;
+        self.inner.pRegions = regions.as_ptr();
+        self
+    }
+}
+builder!{CopyImageToBufferInfo2, CopyImageToBufferInfo2Builder}
+impl<'a> CopyImageToBufferInfo2Builder<'a> {
+    #[inline]
+    pub fn src_image(mut self, src_image: Image) -> Self {
+        self.inner.srcImage = src_image;
+        self
+    }
+    #[inline]
+    pub fn src_image_layout(mut self, src_image_layout: ImageLayout) -> Self {
+        self.inner.srcImageLayout = src_image_layout;
+        self
+    }
+    #[inline]
+    pub fn dst_buffer(mut self, dst_buffer: Buffer) -> Self {
+        self.inner.dstBuffer = dst_buffer;
+        self
+    }
+    #[inline]
+    pub fn regions(mut self, regions: &'a [BufferImageCopy2]) -> Self {
+        self.inner.regionCount = regions.len() as u32;
+        self.inner.pRegions = regions.as_ptr();
+        self
+    }
+}
+builder!{BlitImageInfo2, BlitImageInfo2Builder}
+impl<'a> BlitImageInfo2Builder<'a> {
+    #[inline]
+    pub fn src_image(mut self, src_image: Image) -> Self {
+        self.inner.srcImage = src_image;
+        self
+    }
+    #[inline]
+    pub fn src_image_layout(mut self, src_image_layout: ImageLayout) -> Self {
+        self.inner.srcImageLayout = src_image_layout;
+        self
+    }
+    #[inline]
+    pub fn dst_image(mut self, dst_image: Image) -> Self {
+        self.inner.dstImage = dst_image;
+        self
+    }
+    #[inline]
+    pub fn dst_image_layout(mut self, dst_image_layout: ImageLayout) -> Self {
+        self.inner.dstImageLayout = dst_image_layout;
+        self
+    }
+    #[inline]
+    pub fn regions(mut self, regions: &'a [ImageBlit2]) -> Self {
+        self.inner.regionCount = regions.len() as u32;
+        self.inner.pRegions = regions.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.inner.filter = filter;
+        self
+    }
+}
+builder!{ResolveImageInfo2, ResolveImageInfo2Builder}
+impl<'a> ResolveImageInfo2Builder<'a> {
+    #[inline]
+    pub fn src_image(mut self, src_image: Image) -> Self {
+        self.inner.srcImage = src_image;
+        self
+    }
+    #[inline]
+    pub fn src_image_layout(mut self, src_image_layout: ImageLayout) -> Self {
+        self.inner.srcImageLayout = src_image_layout;
+        self
+    }
+    #[inline]
+    pub fn dst_image(mut self, dst_image: Image) -> Self {
+        self.inner.dstImage = dst_image;
+        self
+    }
+    #[inline]
+    pub fn dst_image_layout(mut self, dst_image_layout: ImageLayout) -> Self {
+        self.inner.dstImageLayout = dst_image_layout;
+        self
+    }
+    #[inline]
+    pub fn regions(mut self, regions: &'a [ImageResolve2]) -> Self {
+        self.inner.regionCount = regions.len() as u32;
+        self.inner.pRegions = regions.as_ptr();
+        self
+    }
+}
+builder!{PipelineShaderStageRequiredSubgroupSizeCreateInfo, PipelineShaderStageRequiredSubgroupSizeCreateInfoBuilder}
+impl<'a> PipelineShaderStageRequiredSubgroupSizeCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn required_subgroup_size(mut self, required_subgroup_size: u32) -> Self {
+        self.inner.requiredSubgroupSize = required_subgroup_size;
+        self
+    }
+}
+builder!{DescriptorPoolInlineUniformBlockCreateInfo, DescriptorPoolInlineUniformBlockCreateInfoBuilder}
+impl<'a> DescriptorPoolInlineUniformBlockCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn max_inline_uniform_block_bindings(mut self, max_inline_uniform_block_bindings: u32) -> Self {
+        self.inner.maxInlineUniformBlockBindings = max_inline_uniform_block_bindings;
+        self
+    }
+}
+builder!{RenderingAttachmentInfo, RenderingAttachmentInfoBuilder}
+impl<'a> RenderingAttachmentInfoBuilder<'a> {
+    #[inline]
+    pub fn image_view(mut self, image_view: ImageView) -> Self {
+        self.inner.imageView = image_view;
+        self
+    }
+    #[inline]
+    pub fn image_layout(mut self, image_layout: ImageLayout) -> Self {
+        self.inner.imageLayout = image_layout;
+        self
+    }
+    #[inline]
+    pub fn resolve_mode(mut self, resolve_mode: ResolveModeFlag) -> Self {
+        self.inner.resolveMode = resolve_mode;
+        self
+    }
+    #[inline]
+    pub fn resolve_image_view(mut self, resolve_image_view: ImageView) -> Self {
+        self.inner.resolveImageView = resolve_image_view;
+        self
+    }
+    #[inline]
+    pub fn resolve_image_layout(mut self, resolve_image_layout: ImageLayout) -> Self {
+        self.inner.resolveImageLayout = resolve_image_layout;
+        self
+    }
+    #[inline]
+    pub fn load_op(mut self, load_op: AttachmentLoadOp) -> Self {
+        self.inner.loadOp = load_op;
+        self
+    }
+    #[inline]
+    pub fn store_op(mut self, store_op: AttachmentStoreOp) -> Self {
+        self.inner.storeOp = store_op;
+        self
+    }
+    #[inline]
+    pub fn clear_value(mut self, clear_value: ClearValue) -> Self {
+        self.inner.clearValue = clear_value;
+        self
+    }
+}
+builder!{RenderingInfo, RenderingInfoBuilder}
+impl<'a> RenderingInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: RenderingFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn render_area(mut self, render_area: Rect2D) -> Self {
+        self.inner.renderArea = render_area;
+        self
+    }
+    #[inline]
+    pub fn layer_count(mut self, layer_count: u32) -> Self {
+        self.inner.layerCount = layer_count;
+        self
+    }
+    #[inline]
+    pub fn view_mask(mut self, view_mask: u32) -> Self {
+        self.inner.viewMask = view_mask;
+        self
+    }
+    #[inline]
+    pub fn color_attachments(mut self, color_attachments: &'a [RenderingAttachmentInfo]) -> Self {
+        self.inner.colorAttachmentCount = color_attachments.len() as u32;
+        self.inner.pColorAttachments = color_attachments.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn depth_attachment(mut self, depth_attachment: &'a RenderingAttachmentInfo) -> Self {
+        self.inner.pDepthAttachment = depth_attachment;
+        self
+    }
+    #[inline]
+    pub fn stencil_attachment(mut self, stencil_attachment: &'a RenderingAttachmentInfo) -> Self {
+        self.inner.pStencilAttachment = stencil_attachment;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsRenderingInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{PipelineRenderingCreateInfo, PipelineRenderingCreateInfoBuilder}
+impl<'a> PipelineRenderingCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn view_mask(mut self, view_mask: u32) -> Self {
+        self.inner.viewMask = view_mask;
+        self
+    }
+    #[inline]
+    pub fn color_attachment_formats(mut self, color_attachment_formats: &'a [Format]) -> Self {
+        self.inner.colorAttachmentCount = color_attachment_formats.len() as u32;
+        self.inner.pColorAttachmentFormats = color_attachment_formats.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn depth_attachment_format(mut self, depth_attachment_format: Format) -> Self {
+        self.inner.depthAttachmentFormat = depth_attachment_format;
+        self
+    }
+    #[inline]
+    pub fn stencil_attachment_format(mut self, stencil_attachment_format: Format) -> Self {
+        self.inner.stencilAttachmentFormat = stencil_attachment_format;
+        self
+    }
+}
+builder!{CommandBufferInheritanceRenderingInfo, CommandBufferInheritanceRenderingInfoBuilder}
+impl<'a> CommandBufferInheritanceRenderingInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: RenderingFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn view_mask(mut self, view_mask: u32) -> Self {
+        self.inner.viewMask = view_mask;
+        self
+    }
+    #[inline]
+    pub fn color_attachment_formats(mut self, color_attachment_formats: &'a [Format]) -> Self {
+        self.inner.colorAttachmentCount = color_attachment_formats.len() as u32;
+        self.inner.pColorAttachmentFormats = color_attachment_formats.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn depth_attachment_format(mut self, depth_attachment_format: Format) -> Self {
+        self.inner.depthAttachmentFormat = depth_attachment_format;
+        self
+    }
+    #[inline]
+    pub fn stencil_attachment_format(mut self, stencil_attachment_format: Format) -> Self {
+        self.inner.stencilAttachmentFormat = stencil_attachment_format;
+        self
+    }
+    #[inline]
+    pub fn rasterization_samples(mut self, rasterization_samples: SampleCountFlag) -> Self {
+        self.inner.rasterizationSamples = rasterization_samples;
+        self
+    }
+}
@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+use std::os::raw::c_void;
+
+use vk::{BaseOutStructure, StructureType};
+
+/// Structs which start with `sType` and `pNext`, and so can be part of a
+/// `pNext` chain.
+///
+/// # Safety
+///
+/// The struct must be `repr(C)`, start with `sType` and `pNext`, and be the
+/// one `STRUCTURE_TYPE` names.
+pub unsafe trait TaggedStructure: Sized {
+    /// The `sType` of this struct.
+    const STRUCTURE_TYPE: StructureType;
+
+    /// The structs chained onto this one.
+    ///
+    /// # Safety
+    ///
+    /// Every `pNext` in the chain must be null or point to a live struct
+    /// starting with `sType` and `pNext`.
+    unsafe fn iter_next<'a>(&'a self) -> ChainIter<'a> {
+        ChainIter::new((*(self as *const Self as *const BaseOutStructure)).pNext as *const c_void)
+    }
+
+    /// The first struct of type `T` chained onto this one, as filled in by
+    /// queries such as `vkGetPhysicalDeviceFeatures2`.
+    ///
+    /// # Safety
+    ///
+    /// As for `iter_next`.
+    unsafe fn find_next<T: TaggedStructure>(&self) -> Option<&T> {
+        self.iter_next().filter_map(|s| s.downcast()).next()
+    }
+}
+
+#[doc(hidden)]
+pub unsafe fn push_next<B: TaggedStructure, T: TaggedStructure>(base: &mut B, next: &mut T) {
+    let base = base as *mut B as *mut BaseOutStructure;
+    let next = next as *mut T as *mut BaseOutStructure;
+    let mut last = next;
+    loop {
+        let linked = ChainIter::new((*base).pNext as *const c_void).any(|s| s.ptr == last);
+        assert!(last != base && !linked, "struct is already in the pNext chain");
+        if (*last).pNext.is_null() {
+            break;
+        }
+        last = (*last).pNext;
+    }
+    (*last).pNext = (*base).pNext;
+    (*base).pNext = next;
+}
+
+/// Iterator over a `pNext` chain.
+pub struct ChainIter<'a> {
+    next: *const BaseOutStructure,
+    marker: PhantomData<&'a BaseOutStructure>,
+}
+
+impl<'a> ChainIter<'a> {
+    /// Walks the chain starting at `p_next`.
+    ///
+    /// # Safety
+    ///
+    /// `p_next` must be null or point to a chain that lives for `'a`, as
+    /// for `TaggedStructure::iter_next`.
+    pub unsafe fn new(p_next: *const c_void) -> ChainIter<'a> {
+        ChainIter { next: p_next as *const BaseOutStructure, marker: PhantomData }
+    }
+}
+
+impl<'a> Iterator for ChainIter<'a> {
+    type Item = ChainEntry<'a>;
+
+    fn next(&mut self) -> Option<ChainEntry<'a>> {
+        if self.next.is_null() {
+            return None;
+        }
+        let entry = ChainEntry { ptr: self.next, marker: PhantomData };
+        self.next = unsafe { (*self.next).pNext };
+        Some(entry)
+    }
+}
+
+/// One struct of a `pNext` chain, known only by its `sType`.
+#[derive(Copy, Clone)]
+pub struct ChainEntry<'a> {
+    ptr: *const BaseOutStructure,
+    marker: PhantomData<&'a BaseOutStructure>,
+}
+
+impl<'a> ChainEntry<'a> {
+    pub fn structure_type(self) -> StructureType {
+        unsafe { (*self.ptr).sType }
+    }
+
+    /// This struct as a `T`, if its `sType` is `T`'s.
+    pub fn downcast<T: TaggedStructure>(self) -> Option<&'a T> {
+        if self.structure_type() == T::STRUCTURE_TYPE {
+            Some(unsafe { &*(self.ptr as *const T) })
+        } else {
+            None
+        }
+    }
+}
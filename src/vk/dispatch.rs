@@ -0,0 +1,1311 @@
+// Dispatch tables for core commands
+
+use std::os::raw::{c_char, c_void};
+
+use vk::*;
+
+fn_table!{
+    /// Instance-level core commands, resolved with `vkGetInstanceProcAddr`.
+    pub struct InstanceFns {
+        fn vkDestroyInstance: PFN_vkDestroyInstance(
+            instance: Instance,
+            pAllocator: *const AllocationCallbacks);
+        fn vkEnumeratePhysicalDevices: PFN_vkEnumeratePhysicalDevices(
+            instance: Instance,
+            pPhysicalDeviceCount: *mut u32,
+            pPhysicalDevices: *mut PhysicalDevice) -> Result;
+        fn vkGetPhysicalDeviceFeatures: PFN_vkGetPhysicalDeviceFeatures(
+            physicalDevice: PhysicalDevice,
+            pFeatures: *mut PhysicalDeviceFeatures);
+        fn vkGetPhysicalDeviceFormatProperties: PFN_vkGetPhysicalDeviceFormatProperties(
+            physicalDevice: PhysicalDevice,
+            format: Format,
+            pFormatProperties: *mut FormatProperties);
+        fn vkGetPhysicalDeviceImageFormatProperties: PFN_vkGetPhysicalDeviceImageFormatProperties(
+            physicalDevice: PhysicalDevice,
+            format: Format,
+            _type: ImageType,
+            tiling: ImageTiling,
+            usage: ImageUsageFlags,
+            flags: ImageCreateFlags,
+            pImageFormatProperties: *mut ImageFormatProperties) -> Result;
+        fn vkGetPhysicalDeviceProperties: PFN_vkGetPhysicalDeviceProperties(
+            physicalDevice: PhysicalDevice,
+            pProperties: *mut PhysicalDeviceProperties);
+        fn vkGetPhysicalDeviceQueueFamilyProperties: PFN_vkGetPhysicalDeviceQueueFamilyProperties(
+            physicalDevice: PhysicalDevice,
+            pQueueFamilyPropertyCount: *mut u32,
+            pQueueFamilyProperties: *mut QueueFamilyProperties);
+        fn vkGetPhysicalDeviceMemoryProperties: PFN_vkGetPhysicalDeviceMemoryProperties(
+            physicalDevice: PhysicalDevice,
+            pMemoryProperties: *mut PhysicalDeviceMemoryProperties);
+        fn vkGetDeviceProcAddr: PFN_vkGetDeviceProcAddr(
+            device: Device,
+            pName: *const c_char) -> PFN_vkVoidFunction;
+        fn vkCreateDevice: PFN_vkCreateDevice(
+            physicalDevice: PhysicalDevice,
+            pCreateInfo: *const DeviceCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pDevice: *mut Device) -> Result;
+        fn vkEnumerateDeviceExtensionProperties: PFN_vkEnumerateDeviceExtensionProperties(
+            physicalDevice: PhysicalDevice,
+            pLayerName: *const c_char,
+            pPropertyCount: *mut u32,
+            pProperties: *mut ExtensionProperties) -> Result;
+        fn vkEnumerateDeviceLayerProperties: PFN_vkEnumerateDeviceLayerProperties(
+            physicalDevice: PhysicalDevice,
+            pPropertyCount: *mut u32,
+            pProperties: *mut LayerProperties) -> Result;
+        fn vkGetPhysicalDeviceSparseImageFormatProperties: PFN_vkGetPhysicalDeviceSparseImageFormatProperties(
+            physicalDevice: PhysicalDevice,
+            format: Format,
+            _type: ImageType,
+            samples: SampleCountFlag,
+            usage: ImageUsageFlags,
+            tiling: ImageTiling,
+            pPropertyCount: *mut u32,
+            pProperties: *mut SparseImageFormatProperties);
+    }
+}
+
+impl InstanceFns {
+    /// Resolves every instance-level command for `instance`, failing with the
+    /// names of those the implementation does not provide.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry`.
+    pub unsafe fn load(entry: &Entry, instance: Instance)
+            -> ::std::result::Result<InstanceFns, LoadingError> {
+        let fns = InstanceFns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl InstanceFns {
+    /// `vkEnumeratePhysicalDevices`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumeratePhysicalDevices`.
+    pub unsafe fn enumerate_physical_devices(&self, instance: Instance)
+            -> ::std::result::Result<Vec<PhysicalDevice>, Error> {
+        enumerate(|count, data| {
+            self.vkEnumeratePhysicalDevices(instance, count, data)
+        })
+    }
+
+    /// `vkGetPhysicalDeviceQueueFamilyProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceQueueFamilyProperties`.
+    pub unsafe fn get_physical_device_queue_family_properties(&self,
+                                                              physical_device: PhysicalDevice)
+            -> Vec<QueueFamilyProperties> {
+        enumerate(|count, data| {
+            self.vkGetPhysicalDeviceQueueFamilyProperties(physical_device, count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+
+    /// `vkEnumerateDeviceExtensionProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumerateDeviceExtensionProperties`.
+    pub unsafe fn enumerate_device_extension_properties(&self, physical_device: PhysicalDevice,
+                                                        layer_name: Option<&::std::ffi::CStr>)
+            -> ::std::result::Result<Vec<ExtensionProperties>, Error> {
+        let layer_name = layer_name.map_or(::std::ptr::null(), |s| s.as_ptr());
+        enumerate(|count, data| {
+            self.vkEnumerateDeviceExtensionProperties(physical_device, layer_name, count, data)
+        })
+    }
+
+    /// `vkEnumerateDeviceLayerProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumerateDeviceLayerProperties`.
+    pub unsafe fn enumerate_device_layer_properties(&self, physical_device: PhysicalDevice)
+            -> ::std::result::Result<Vec<LayerProperties>, Error> {
+        enumerate(|count, data| {
+            self.vkEnumerateDeviceLayerProperties(physical_device, count, data)
+        })
+    }
+
+    /// `vkGetPhysicalDeviceSparseImageFormatProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceSparseImageFormatProperties`.
+    pub unsafe fn get_physical_device_sparse_image_format_properties(&self,
+                                                                     physical_device: PhysicalDevice,
+                                                                     format: Format,
+                                                                     _type: ImageType,
+                                                                     samples: SampleCountFlag,
+                                                                     usage: ImageUsageFlags,
+                                                                     tiling: ImageTiling)
+            -> Vec<SparseImageFormatProperties> {
+        enumerate(|count, data| {
+            self.vkGetPhysicalDeviceSparseImageFormatProperties(physical_device, format, _type,
+                                                                samples, usage, tiling, count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+}
+
+fn_table!{
+    /// Device-level core commands, resolved with `vkGetDeviceProcAddr` so that
+    /// calls go straight to the driver of the device they were loaded for.
+    pub struct DeviceFns {
+        fn vkDestroyDevice: PFN_vkDestroyDevice(
+            device: Device,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetDeviceQueue: PFN_vkGetDeviceQueue(
+            device: Device,
+            queueFamilyIndex: u32,
+            queueIndex: u32,
+            pQueue: *mut Queue);
+        fn vkQueueSubmit: PFN_vkQueueSubmit(
+            queue: Queue,
+            submitCount: u32,
+            pSubmits: *const SubmitInfo,
+            fence: Fence) -> Result;
+        fn vkQueueWaitIdle: PFN_vkQueueWaitIdle(
+            queue: Queue) -> Result;
+        fn vkDeviceWaitIdle: PFN_vkDeviceWaitIdle(
+            device: Device) -> Result;
+        fn vkAllocateMemory: PFN_vkAllocateMemory(
+            device: Device,
+            pAllocateInfo: *const MemoryAllocateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pMemory: *mut DeviceMemory) -> Result;
+        fn vkFreeMemory: PFN_vkFreeMemory(
+            device: Device,
+            memory: DeviceMemory,
+            pAllocator: *const AllocationCallbacks);
+        fn vkMapMemory: PFN_vkMapMemory(
+            device: Device,
+            memory: DeviceMemory,
+            offset: DeviceSize,
+            size: DeviceSize,
+            flags: MemoryMapFlags,
+            ppData: *mut *mut c_void) -> Result;
+        fn vkUnmapMemory: PFN_vkUnmapMemory(
+            device: Device,
+            memory: DeviceMemory);
+        fn vkFlushMappedMemoryRanges: PFN_vkFlushMappedMemoryRanges(
+            device: Device,
+            memoryRangeCount: u32,
+            pMemoryRanges: *const MappedMemoryRange) -> Result;
+        fn vkInvalidateMappedMemoryRanges: PFN_vkInvalidateMappedMemoryRanges(
+            device: Device,
+            memoryRangeCount: u32,
+            pMemoryRanges: *const MappedMemoryRange) -> Result;
+        fn vkGetDeviceMemoryCommitment: PFN_vkGetDeviceMemoryCommitment(
+            device: Device,
+            memory: DeviceMemory,
+            pCommittedMemoryInBytes: *mut DeviceSize);
+        fn vkBindBufferMemory: PFN_vkBindBufferMemory(
+            device: Device,
+            buffer: Buffer,
+            memory: DeviceMemory,
+            memoryOffset: DeviceSize) -> Result;
+        fn vkBindImageMemory: PFN_vkBindImageMemory(
+            device: Device,
+            image: Image,
+            memory: DeviceMemory,
+            memoryOffset: DeviceSize) -> Result;
+        fn vkGetBufferMemoryRequirements: PFN_vkGetBufferMemoryRequirements(
+            device: Device,
+            buffer: Buffer,
+            pMemoryRequirements: *mut MemoryRequirements);
+        fn vkGetImageMemoryRequirements: PFN_vkGetImageMemoryRequirements(
+            device: Device,
+            image: Image,
+            pMemoryRequirements: *mut MemoryRequirements);
+        fn vkGetImageSparseMemoryRequirements: PFN_vkGetImageSparseMemoryRequirements(
+            device: Device,
+            image: Image,
+            pSparseMemoryRequirementCount: *mut u32,
+            pSparseMemoryRequirements: *mut SparseImageMemoryRequirements);
+        fn vkQueueBindSparse: PFN_vkQueueBindSparse(
+            queue: Queue,
+            bindInfoCount: u32,
+            pBindInfo: *const BindSparseInfo,
+            fence: Fence) -> Result;
+        fn vkCreateFence: PFN_vkCreateFence(
+            device: Device,
+            pCreateInfo: *const FenceCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pFence: *mut Fence) -> Result;
+        fn vkDestroyFence: PFN_vkDestroyFence(
+            device: Device,
+            fence: Fence,
+            pAllocator: *const AllocationCallbacks);
+        fn vkResetFences: PFN_vkResetFences(
+            device: Device,
+            fenceCount: u32,
+            pFences: *const Fence) -> Result;
+        fn vkGetFenceStatus: PFN_vkGetFenceStatus(
+            device: Device,
+            fence: Fence) -> Result;
+        fn vkWaitForFences: PFN_vkWaitForFences(
+            device: Device,
+            fenceCount: u32,
+            pFences: *const Fence,
+            waitAll: Bool32,
+            timeout: u64) -> Result;
+        fn vkCreateSemaphore: PFN_vkCreateSemaphore(
+            device: Device,
+            pCreateInfo: *const SemaphoreCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSemaphore: *mut Semaphore) -> Result;
+        fn vkDestroySemaphore: PFN_vkDestroySemaphore(
+            device: Device,
+            semaphore: Semaphore,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateEvent: PFN_vkCreateEvent(
+            device: Device,
+            pCreateInfo: *const EventCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pEvent: *mut Event) -> Result;
+        fn vkDestroyEvent: PFN_vkDestroyEvent(
+            device: Device,
+            event: Event,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetEventStatus: PFN_vkGetEventStatus(
+            device: Device,
+            event: Event) -> Result;
+        fn vkSetEvent: PFN_vkSetEvent(
+            device: Device,
+            event: Event) -> Result;
+        fn vkResetEvent: PFN_vkResetEvent(
+            device: Device,
+            event: Event) -> Result;
+        fn vkCreateQueryPool: PFN_vkCreateQueryPool(
+            device: Device,
+            pCreateInfo: *const QueryPoolCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pQueryPool: *mut QueryPool) -> Result;
+        fn vkDestroyQueryPool: PFN_vkDestroyQueryPool(
+            device: Device,
+            queryPool: QueryPool,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetQueryPoolResults: PFN_vkGetQueryPoolResults(
+            device: Device,
+            queryPool: QueryPool,
+            firstQuery: u32,
+            queryCount: u32,
+            dataSize: usize,
+            pData: *mut c_void,
+            stride: DeviceSize,
+            flags: QueryResultFlags) -> Result;
+        fn vkCreateBuffer: PFN_vkCreateBuffer(
+            device: Device,
+            pCreateInfo: *const BufferCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pBuffer: *mut Buffer) -> Result;
+        fn vkDestroyBuffer: PFN_vkDestroyBuffer(
+            device: Device,
+            buffer: Buffer,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateBufferView: PFN_vkCreateBufferView(
+            device: Device,
+            pCreateInfo: *const BufferViewCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pView: *mut BufferView) -> Result;
+        fn vkDestroyBufferView: PFN_vkDestroyBufferView(
+            device: Device,
+            bufferView: BufferView,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateImage: PFN_vkCreateImage(
+            device: Device,
+            pCreateInfo: *const ImageCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pImage: *mut Image) -> Result;
+        fn vkDestroyImage: PFN_vkDestroyImage(
+            device: Device,
+            image: Image,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetImageSubresourceLayout: PFN_vkGetImageSubresourceLayout(
+            device: Device,
+            image: Image,
+            pSubresource: *const ImageSubresource,
+            pLayout: *mut SubresourceLayout);
+        fn vkCreateImageView: PFN_vkCreateImageView(
+            device: Device,
+            pCreateInfo: *const ImageViewCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pView: *mut ImageView) -> Result;
+        fn vkDestroyImageView: PFN_vkDestroyImageView(
+            device: Device,
+            imageView: ImageView,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateShaderModule: PFN_vkCreateShaderModule(
+            device: Device,
+            pCreateInfo: *const ShaderModuleCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pShaderModule: *mut ShaderModule) -> Result;
+        fn vkDestroyShaderModule: PFN_vkDestroyShaderModule(
+            device: Device,
+            shaderModule: ShaderModule,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreatePipelineCache: PFN_vkCreatePipelineCache(
+            device: Device,
+            pCreateInfo: *const PipelineCacheCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pPipelineCache: *mut PipelineCache) -> Result;
+        fn vkDestroyPipelineCache: PFN_vkDestroyPipelineCache(
+            device: Device,
+            pipelineCache: PipelineCache,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetPipelineCacheData: PFN_vkGetPipelineCacheData(
+            device: Device,
+            pipelineCache: PipelineCache,
+            pDataSize: *mut usize,
+            pData: *mut c_void) -> Result;
+        fn vkMergePipelineCaches: PFN_vkMergePipelineCaches(
+            device: Device,
+            dstCache: PipelineCache,
+            srcCacheCount: u32,
+            pSrcCaches: *const PipelineCache) -> Result;
+        fn vkCreateGraphicsPipelines: PFN_vkCreateGraphicsPipelines(
+            device: Device,
+            pipelineCache: PipelineCache,
+            createInfoCount: u32,
+            pCreateInfos: *const GraphicsPipelineCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pPipelines: *mut Pipeline) -> Result;
+        fn vkCreateComputePipelines: PFN_vkCreateComputePipelines(
+            device: Device,
+            pipelineCache: PipelineCache,
+            createInfoCount: u32,
+            pCreateInfos: *const ComputePipelineCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pPipelines: *mut Pipeline) -> Result;
+        fn vkDestroyPipeline: PFN_vkDestroyPipeline(
+            device: Device,
+            pipeline: Pipeline,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreatePipelineLayout: PFN_vkCreatePipelineLayout(
+            device: Device,
+            pCreateInfo: *const PipelineLayoutCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pPipelineLayout: *mut PipelineLayout) -> Result;
+        fn vkDestroyPipelineLayout: PFN_vkDestroyPipelineLayout(
+            device: Device,
+            pipelineLayout: PipelineLayout,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateSampler: PFN_vkCreateSampler(
+            device: Device,
+            pCreateInfo: *const SamplerCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSampler: *mut Sampler) -> Result;
+        fn vkDestroySampler: PFN_vkDestroySampler(
+            device: Device,
+            sampler: Sampler,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateDescriptorSetLayout: PFN_vkCreateDescriptorSetLayout(
+            device: Device,
+            pCreateInfo: *const DescriptorSetLayoutCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSetLayout: *mut DescriptorSetLayout) -> Result;
+        fn vkDestroyDescriptorSetLayout: PFN_vkDestroyDescriptorSetLayout(
+            device: Device,
+            descriptorSetLayout: DescriptorSetLayout,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateDescriptorPool: PFN_vkCreateDescriptorPool(
+            device: Device,
+            pCreateInfo: *const DescriptorPoolCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pDescriptorPool: *mut DescriptorPool) -> Result;
+        fn vkDestroyDescriptorPool: PFN_vkDestroyDescriptorPool(
+            device: Device,
+            descriptorPool: DescriptorPool,
+            pAllocator: *const AllocationCallbacks);
+        fn vkResetDescriptorPool: PFN_vkResetDescriptorPool(
+            device: Device,
+            descriptorPool: DescriptorPool,
+            flags: DescriptorPoolResetFlags) -> Result;
+        fn vkAllocateDescriptorSets: PFN_vkAllocateDescriptorSets(
+            device: Device,
+            pAllocateInfo: *const DescriptorSetAllocateInfo,
+            pDescriptorSets: *mut DescriptorSet) -> Result;
+        fn vkFreeDescriptorSets: PFN_vkFreeDescriptorSets(
+            device: Device,
+            descriptorPool: DescriptorPool,
+            descriptorSetCount: u32,
+            pDescriptorSets: *const DescriptorSet) -> Result;
+        fn vkUpdateDescriptorSets: PFN_vkUpdateDescriptorSets(
+            device: Device,
+            descriptorWriteCount: u32,
+            pDescriptorWrites: *const WriteDescriptorSet,
+            descriptorCopyCount: u32,
+            pDescriptorCopies: *const CopyDescriptorSet);
+        fn vkCreateFramebuffer: PFN_vkCreateFramebuffer(
+            device: Device,
+            pCreateInfo: *const FramebufferCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pFramebuffer: *mut Framebuffer) -> Result;
+        fn vkDestroyFramebuffer: PFN_vkDestroyFramebuffer(
+            device: Device,
+            framebuffer: Framebuffer,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateRenderPass: PFN_vkCreateRenderPass(
+            device: Device,
+            pCreateInfo: *const RenderPassCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pRenderPass: *mut RenderPass) -> Result;
+        fn vkDestroyRenderPass: PFN_vkDestroyRenderPass(
+            device: Device,
+            renderPass: RenderPass,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetRenderAreaGranularity: PFN_vkGetRenderAreaGranularity(
+            device: Device,
+            renderPass: RenderPass,
+            pGranularity: *mut Extent2D);
+        fn vkCreateCommandPool: PFN_vkCreateCommandPool(
+            device: Device,
+            pCreateInfo: *const CommandPoolCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pCommandPool: *mut CommandPool) -> Result;
+        fn vkDestroyCommandPool: PFN_vkDestroyCommandPool(
+            device: Device,
+            commandPool: CommandPool,
+            pAllocator: *const AllocationCallbacks);
+        fn vkResetCommandPool: PFN_vkResetCommandPool(
+            device: Device,
+            commandPool: CommandPool,
+            flags: CommandPoolResetFlags) -> Result;
+        fn vkAllocateCommandBuffers: PFN_vkAllocateCommandBuffers(
+            device: Device,
+            pAllocateInfo: *const CommandBufferAllocateInfo,
+            pCommandBuffers: *mut CommandBuffer) -> Result;
+        fn vkFreeCommandBuffers: PFN_vkFreeCommandBuffers(
+            device: Device,
+            commandPool: CommandPool,
+            commandBufferCount: u32,
+            pCommandBuffers: *const CommandBuffer);
+        fn vkBeginCommandBuffer: PFN_vkBeginCommandBuffer(
+            commandBuffer: CommandBuffer,
+            pBeginInfo: *const CommandBufferBeginInfo) -> Result;
+        fn vkEndCommandBuffer: PFN_vkEndCommandBuffer(
+            commandBuffer: CommandBuffer) -> Result;
+        fn vkResetCommandBuffer: PFN_vkResetCommandBuffer(
+            commandBuffer: CommandBuffer,
+            flags: CommandBufferResetFlags) -> Result;
+        fn vkCmdBindPipeline: PFN_vkCmdBindPipeline(
+            commandBuffer: CommandBuffer,
+            pipelineBindPoint: PipelineBindPoint,
+            pipeline: Pipeline);
+        fn vkCmdSetViewport: PFN_vkCmdSetViewport(
+            commandBuffer: CommandBuffer,
+            firstViewport: u32,
+            viewportCount: u32,
+            pViewports: *const Viewport);
+        fn vkCmdSetScissor: PFN_vkCmdSetScissor(
+            commandBuffer: CommandBuffer,
+            firstScissor: u32,
+            scissorCount: u32,
+            pScissors: *const Rect2D);
+        fn vkCmdSetLineWidth: PFN_vkCmdSetLineWidth(
+            commandBuffer: CommandBuffer,
+            lineWidth: ::std::os::raw::c_float);
+        fn vkCmdSetDepthBias: PFN_vkCmdSetDepthBias(
+            commandBuffer: CommandBuffer,
+            depthBiasConstantFactor: ::std::os::raw::c_float,
+            depthBiasClamp: ::std::os::raw::c_float,
+            depthBiasSlopeFactor: ::std::os::raw::c_float);
+        fn vkCmdSetBlendConstants: PFN_vkCmdSetBlendConstants(
+            commandBuffer: CommandBuffer,
+            blendConstants: *mut ::std::os::raw::c_float);
+        fn vkCmdSetDepthBounds: PFN_vkCmdSetDepthBounds(
+            commandBuffer: CommandBuffer,
+            minDepthBounds: ::std::os::raw::c_float,
+            maxDepthBounds: ::std::os::raw::c_float);
+        fn vkCmdSetStencilCompareMask: PFN_vkCmdSetStencilCompareMask(
+            commandBuffer: CommandBuffer,
+            faceMask: StencilFaceFlags,
+            compareMask: u32);
+        fn vkCmdSetStencilWriteMask: PFN_vkCmdSetStencilWriteMask(
+            commandBuffer: CommandBuffer,
+            faceMask: StencilFaceFlags,
+            writeMask: u32);
+        fn vkCmdSetStencilReference: PFN_vkCmdSetStencilReference(
+            commandBuffer: CommandBuffer,
+            faceMask: StencilFaceFlags,
+            reference: u32);
+        fn vkCmdBindDescriptorSets: PFN_vkCmdBindDescriptorSets(
+            commandBuffer: CommandBuffer,
+            pipelineBindPoint: PipelineBindPoint,
+            layout: PipelineLayout,
+            firstSet: u32,
+            descriptorSetCount: u32,
+            pDescriptorSets: *const DescriptorSet,
+            dynamicOffsetCount: u32,
+            pDynamicOffsets: *const u32);
+        fn vkCmdBindIndexBuffer: PFN_vkCmdBindIndexBuffer(
+            commandBuffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            indexType: IndexType);
+        fn vkCmdBindVertexBuffers: PFN_vkCmdBindVertexBuffers(
+            commandBuffer: CommandBuffer,
+            firstBinding: u32,
+            bindingCount: u32,
+            pBuffers: *const Buffer,
+            pOffsets: *const DeviceSize);
+        fn vkCmdDraw: PFN_vkCmdDraw(
+            commandBuffer: CommandBuffer,
+            vertexCount: u32,
+            instanceCount: u32,
+            firstVertex: u32,
+            firstInstance: u32);
+        fn vkCmdDrawIndexed: PFN_vkCmdDrawIndexed(
+            commandBuffer: CommandBuffer,
+            indexCount: u32,
+            instanceCount: u32,
+            firstIndex: u32,
+            vertexOffset: i32,
+            firstInstance: u32);
+        fn vkCmdDrawIndirect: PFN_vkCmdDrawIndirect(
+            commandBuffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            drawCount: u32,
+            stride: u32);
+        fn vkCmdDrawIndexedIndirect: PFN_vkCmdDrawIndexedIndirect(
+            commandBuffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            drawCount: u32,
+            stride: u32);
+        fn vkCmdDispatch: PFN_vkCmdDispatch(
+            commandBuffer: CommandBuffer,
+            x: u32,
+            y: u32,
+            z: u32);
+        fn vkCmdDispatchIndirect: PFN_vkCmdDispatchIndirect(
+            commandBuffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize);
+        fn vkCmdCopyBuffer: PFN_vkCmdCopyBuffer(
+            commandBuffer: CommandBuffer,
+            srcBuffer: Buffer,
+            dstBuffer: Buffer,
+            regionCount: u32,
+            pRegions: *const BufferCopy);
+        fn vkCmdCopyImage: PFN_vkCmdCopyImage(
+            commandBuffer: CommandBuffer,
+            srcImage: Image,
+            srcImageLayout: ImageLayout,
+            dstImage: Image,
+            dstImageLayout: ImageLayout,
+            regionCount: u32,
+            pRegions: *const ImageCopy);
+        fn vkCmdBlitImage: PFN_vkCmdBlitImage(
+            commandBuffer: CommandBuffer,
+            srcImage: Image,
+            srcImageLayout: ImageLayout,
+            dstImage: Image,
+            dstImageLayout: ImageLayout,
+            regionCount: u32,
+            pRegions: *const ImageBlit,
+            filter: Filter);
+        fn vkCmdCopyBufferToImage: PFN_vkCmdCopyBufferToImage(
+            commandBuffer: CommandBuffer,
+            srcBuffer: Buffer,
+            dstImage: Image,
+            dstImageLayout: ImageLayout,
+            regionCount: u32,
+            pRegions: *const BufferImageCopy);
+        fn vkCmdCopyImageToBuffer: PFN_vkCmdCopyImageToBuffer(
+            commandBuffer: CommandBuffer,
+            srcImage: Image,
+            srcImageLayout: ImageLayout,
+            dstBuffer: Buffer,
+            regionCount: u32,
+            pRegions: *const BufferImageCopy);
+        fn vkCmdUpdateBuffer: PFN_vkCmdUpdateBuffer(
+            commandBuffer: CommandBuffer,
+            dstBuffer: Buffer,
+            dstOffset: DeviceSize,
+            dataSize: DeviceSize,
+            pData: *const u32);
+        fn vkCmdFillBuffer: PFN_vkCmdFillBuffer(
+            commandBuffer: CommandBuffer,
+            dstBuffer: Buffer,
+            dstOffset: DeviceSize,
+            size: DeviceSize,
+            data: u32);
+        fn vkCmdClearColorImage: PFN_vkCmdClearColorImage(
+            commandBuffer: CommandBuffer,
+            image: Image,
+            imageLayout: ImageLayout,
+            pColor: *const ClearColorValue,
+            rangeCount: u32,
+            pRanges: *const ImageSubresourceRange);
+        fn vkCmdClearDepthStencilImage: PFN_vkCmdClearDepthStencilImage(
+            commandBuffer: CommandBuffer,
+            image: Image,
+            imageLayout: ImageLayout,
+            pDepthStencil: *const ClearDepthStencilValue,
+            rangeCount: u32,
+            pRanges: *const ImageSubresourceRange);
+        fn vkCmdClearAttachments: PFN_vkCmdClearAttachments(
+            commandBuffer: CommandBuffer,
+            attachmentCount: u32,
+            pAttachments: *const ClearAttachment,
+            rectCount: u32,
+            pRects: *const ClearRect);
+        fn vkCmdResolveImage: PFN_vkCmdResolveImage(
+            commandBuffer: CommandBuffer,
+            srcImage: Image,
+            srcImageLayout: ImageLayout,
+            dstImage: Image,
+            dstImageLayout: ImageLayout,
+            regionCount: u32,
+            pRegions: *const ImageResolve);
+        fn vkCmdSetEvent: PFN_vkCmdSetEvent(
+            commandBuffer: CommandBuffer,
+            event: Event,
+            stageMask: PipelineStageFlags);
+        fn vkCmdResetEvent: PFN_vkCmdResetEvent(
+            commandBuffer: CommandBuffer,
+            event: Event,
+            stageMask: PipelineStageFlags);
+        fn vkCmdWaitEvents: PFN_vkCmdWaitEvents(
+            commandBuffer: CommandBuffer,
+            eventCount: u32,
+            pEvents: *const Event,
+            srcStageMask: PipelineStageFlags,
+            dstStageMask: PipelineStageFlags,
+            memoryBarrierCount: u32,
+            pMemoryBarriers: *const MemoryBarrier,
+            bufferMemoryBarrierCount: u32,
+            pBufferMemoryBarriers: *const BufferMemoryBarrier,
+            imageMemoryBarrierCount: u32,
+            pImageMemoryBarriers: *const ImageMemoryBarrier);
+        fn vkCmdPipelineBarrier: PFN_vkCmdPipelineBarrier(
+            commandBuffer: CommandBuffer,
+            srcStageMask: PipelineStageFlags,
+            dstStageMask: PipelineStageFlags,
+            dependencyFlags: DependencyFlags,
+            memoryBarrierCount: u32,
+            pMemoryBarriers: *const MemoryBarrier,
+            bufferMemoryBarrierCount: u32,
+            pBufferMemoryBarriers: *const BufferMemoryBarrier,
+            imageMemoryBarrierCount: u32,
+            pImageMemoryBarriers: *const ImageMemoryBarrier);
+        fn vkCmdBeginQuery: PFN_vkCmdBeginQuery(
+            commandBuffer: CommandBuffer,
+            queryPool: QueryPool,
+            query: u32,
+            flags: QueryControlFlags);
+        fn vkCmdEndQuery: PFN_vkCmdEndQuery(
+            commandBuffer: CommandBuffer,
+            queryPool: QueryPool,
+            query: u32);
+        fn vkCmdResetQueryPool: PFN_vkCmdResetQueryPool(
+            commandBuffer: CommandBuffer,
+            queryPool: QueryPool,
+            firstQuery: u32,
+            queryCount: u32);
+        fn vkCmdWriteTimestamp: PFN_vkCmdWriteTimestamp(
+            commandBuffer: CommandBuffer,
+            pipelineStage: PipelineStageFlag,
+            queryPool: QueryPool,
+            query: u32);
+        fn vkCmdCopyQueryPoolResults: PFN_vkCmdCopyQueryPoolResults(
+            commandBuffer: CommandBuffer,
+            queryPool: QueryPool,
+            firstQuery: u32,
+            queryCount: u32,
+            dstBuffer: Buffer,
+            dstOffset: DeviceSize,
+            stride: DeviceSize,
+            flags: QueryResultFlags);
+        fn vkCmdPushConstants: PFN_vkCmdPushConstants(
+            commandBuffer: CommandBuffer,
+            layout: PipelineLayout,
+            stageFlags: ShaderStageFlags,
+            offset: u32,
+            size: u32,
+            pValues: *const c_void);
+        fn vkCmdBeginRenderPass: PFN_vkCmdBeginRenderPass(
+            commandBuffer: CommandBuffer,
+            pRenderPassBegin: *const RenderPassBeginInfo,
+            contents: SubpassContents);
+        fn vkCmdNextSubpass: PFN_vkCmdNextSubpass(
+            commandBuffer: CommandBuffer,
+            contents: SubpassContents);
+        fn vkCmdEndRenderPass: PFN_vkCmdEndRenderPass(
+            commandBuffer: CommandBuffer);
+        fn vkCmdExecuteCommands: PFN_vkCmdExecuteCommands(
+            commandBuffer: CommandBuffer,
+            commandBufferCount: u32,
+            pCommandBuffers: *const CommandBuffer);
+    }
+}
+
+impl DeviceFns {
+    /// Resolves every device-level command for `device`, failing with the
+    /// names of those the implementation does not provide.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device of the instance `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device)
+            -> ::std::result::Result<DeviceFns, LoadingError> {
+        let fns = DeviceFns::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl DeviceFns {
+    /// `vkGetImageSparseMemoryRequirements`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetImageSparseMemoryRequirements`.
+    pub unsafe fn get_image_sparse_memory_requirements(&self, device: Device, image: Image)
+            -> Vec<SparseImageMemoryRequirements> {
+        enumerate(|count, data| {
+            self.vkGetImageSparseMemoryRequirements(device, image, count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+
+    /// `vkGetPipelineCacheData`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPipelineCacheData`.
+    pub unsafe fn get_pipeline_cache_data(&self, device: Device, pipeline_cache: PipelineCache)
+            -> ::std::result::Result<Vec<u8>, Error> {
+        enumerate(|count, data: *mut u8| {
+            self.vkGetPipelineCacheData(device, pipeline_cache, count,
+                                        data as *mut ::std::os::raw::c_void)
+        })
+    }
+}
+
+fn_table!{
+    /// Instance-level commands added in Vulkan 1.1, kept apart from
+    /// `InstanceFns` since older implementations do not provide them.
+    pub struct InstanceFnsV1_1 {
+        fn vkEnumeratePhysicalDeviceGroups: PFN_vkEnumeratePhysicalDeviceGroups(
+            instance: Instance,
+            pPhysicalDeviceGroupCount: *mut u32,
+            pPhysicalDeviceGroupProperties: *mut PhysicalDeviceGroupProperties) -> Result;
+        fn vkGetPhysicalDeviceFeatures2: PFN_vkGetPhysicalDeviceFeatures2(
+            physicalDevice: PhysicalDevice,
+            pFeatures: *mut PhysicalDeviceFeatures2);
+        fn vkGetPhysicalDeviceProperties2: PFN_vkGetPhysicalDeviceProperties2(
+            physicalDevice: PhysicalDevice,
+            pProperties: *mut PhysicalDeviceProperties2);
+        fn vkGetPhysicalDeviceFormatProperties2: PFN_vkGetPhysicalDeviceFormatProperties2(
+            physicalDevice: PhysicalDevice,
+            format: Format,
+            pFormatProperties: *mut FormatProperties2);
+        fn vkGetPhysicalDeviceImageFormatProperties2: PFN_vkGetPhysicalDeviceImageFormatProperties2(
+            physicalDevice: PhysicalDevice,
+            pImageFormatInfo: *const PhysicalDeviceImageFormatInfo2,
+            pImageFormatProperties: *mut ImageFormatProperties2) -> Result;
+        fn vkGetPhysicalDeviceQueueFamilyProperties2: PFN_vkGetPhysicalDeviceQueueFamilyProperties2(
+            physicalDevice: PhysicalDevice,
+            pQueueFamilyPropertyCount: *mut u32,
+            pQueueFamilyProperties: *mut QueueFamilyProperties2);
+        fn vkGetPhysicalDeviceMemoryProperties2: PFN_vkGetPhysicalDeviceMemoryProperties2(
+            physicalDevice: PhysicalDevice,
+            pMemoryProperties: *mut PhysicalDeviceMemoryProperties2);
+        fn vkGetPhysicalDeviceSparseImageFormatProperties2: PFN_vkGetPhysicalDeviceSparseImageFormatProperties2(
+            physicalDevice: PhysicalDevice,
+            pFormatInfo: *const PhysicalDeviceSparseImageFormatInfo2,
+            pPropertyCount: *mut u32,
+            pProperties: *mut SparseImageFormatProperties2);
+        fn vkGetPhysicalDeviceExternalBufferProperties: PFN_vkGetPhysicalDeviceExternalBufferProperties(
+            physicalDevice: PhysicalDevice,
+            pExternalBufferInfo: *const PhysicalDeviceExternalBufferInfo,
+            pExternalBufferProperties: *mut ExternalBufferProperties);
+        fn vkGetPhysicalDeviceExternalFenceProperties: PFN_vkGetPhysicalDeviceExternalFenceProperties(
+            physicalDevice: PhysicalDevice,
+            pExternalFenceInfo: *const PhysicalDeviceExternalFenceInfo,
+            pExternalFenceProperties: *mut ExternalFenceProperties);
+        fn vkGetPhysicalDeviceExternalSemaphoreProperties: PFN_vkGetPhysicalDeviceExternalSemaphoreProperties(
+            physicalDevice: PhysicalDevice,
+            pExternalSemaphoreInfo: *const PhysicalDeviceExternalSemaphoreInfo,
+            pExternalSemaphoreProperties: *mut ExternalSemaphoreProperties);
+    }
+}
+
+impl InstanceFnsV1_1 {
+    /// Resolves the Vulkan 1.1 instance-level commands for `instance`,
+    /// which must have been created with an `apiVersion` of at least 1.1.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry`.
+    pub unsafe fn load(entry: &Entry, instance: Instance)
+            -> ::std::result::Result<InstanceFnsV1_1, LoadingError> {
+        let fns = InstanceFnsV1_1::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl InstanceFnsV1_1 {
+    /// `vkEnumeratePhysicalDeviceGroups`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumeratePhysicalDeviceGroups`.
+    pub unsafe fn enumerate_physical_device_groups(&self, instance: Instance)
+            -> ::std::result::Result<Vec<PhysicalDeviceGroupProperties>, Error> {
+        enumerate_tagged(|count, data| {
+            self.vkEnumeratePhysicalDeviceGroups(instance, count, data)
+        })
+    }
+
+    /// `vkGetPhysicalDeviceQueueFamilyProperties2`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceQueueFamilyProperties2`.
+    pub unsafe fn get_physical_device_queue_family_properties2(&self,
+                                                               physical_device: PhysicalDevice)
+            -> Vec<QueueFamilyProperties2> {
+        enumerate_tagged(|count, data| {
+            self.vkGetPhysicalDeviceQueueFamilyProperties2(physical_device, count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+
+    /// `vkGetPhysicalDeviceSparseImageFormatProperties2`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceSparseImageFormatProperties2`.
+    pub unsafe fn get_physical_device_sparse_image_format_properties2(&self,
+                                                                      physical_device: PhysicalDevice,
+                                                                      format_info: &PhysicalDeviceSparseImageFormatInfo2)
+            -> Vec<SparseImageFormatProperties2> {
+        enumerate_tagged(|count, data| {
+            self.vkGetPhysicalDeviceSparseImageFormatProperties2(physical_device, format_info,
+                                                                 count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+}
+
+fn_table!{
+    /// Device-level commands added in Vulkan 1.1, kept apart from `DeviceFns`
+    /// since older implementations do not provide them.
+    pub struct DeviceFnsV1_1 {
+        fn vkBindBufferMemory2: PFN_vkBindBufferMemory2(
+            device: Device,
+            bindInfoCount: u32,
+            pBindInfos: *const BindBufferMemoryInfo) -> Result;
+        fn vkBindImageMemory2: PFN_vkBindImageMemory2(
+            device: Device,
+            bindInfoCount: u32,
+            pBindInfos: *const BindImageMemoryInfo) -> Result;
+        fn vkGetDeviceGroupPeerMemoryFeatures: PFN_vkGetDeviceGroupPeerMemoryFeatures(
+            device: Device,
+            heapIndex: u32,
+            localDeviceIndex: u32,
+            remoteDeviceIndex: u32,
+            pPeerMemoryFeatures: *mut PeerMemoryFeatureFlags);
+        fn vkCmdSetDeviceMask: PFN_vkCmdSetDeviceMask(
+            commandBuffer: CommandBuffer,
+            deviceMask: u32);
+        fn vkCmdDispatchBase: PFN_vkCmdDispatchBase(
+            commandBuffer: CommandBuffer,
+            baseGroupX: u32,
+            baseGroupY: u32,
+            baseGroupZ: u32,
+            groupCountX: u32,
+            groupCountY: u32,
+            groupCountZ: u32);
+        fn vkGetImageMemoryRequirements2: PFN_vkGetImageMemoryRequirements2(
+            device: Device,
+            pInfo: *const ImageMemoryRequirementsInfo2,
+            pMemoryRequirements: *mut MemoryRequirements2);
+        fn vkGetBufferMemoryRequirements2: PFN_vkGetBufferMemoryRequirements2(
+            device: Device,
+            pInfo: *const BufferMemoryRequirementsInfo2,
+            pMemoryRequirements: *mut MemoryRequirements2);
+        fn vkGetImageSparseMemoryRequirements2: PFN_vkGetImageSparseMemoryRequirements2(
+            device: Device,
+            pInfo: *const ImageSparseMemoryRequirementsInfo2,
+            pSparseMemoryRequirementCount: *mut u32,
+            pSparseMemoryRequirements: *mut SparseImageMemoryRequirements2);
+        fn vkTrimCommandPool: PFN_vkTrimCommandPool(
+            device: Device,
+            commandPool: CommandPool,
+            flags: CommandPoolTrimFlags);
+        fn vkGetDeviceQueue2: PFN_vkGetDeviceQueue2(
+            device: Device,
+            pQueueInfo: *const DeviceQueueInfo2,
+            pQueue: *mut Queue);
+        fn vkCreateSamplerYcbcrConversion: PFN_vkCreateSamplerYcbcrConversion(
+            device: Device,
+            pCreateInfo: *const SamplerYcbcrConversionCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pYcbcrConversion: *mut SamplerYcbcrConversion) -> Result;
+        fn vkDestroySamplerYcbcrConversion: PFN_vkDestroySamplerYcbcrConversion(
+            device: Device,
+            ycbcrConversion: SamplerYcbcrConversion,
+            pAllocator: *const AllocationCallbacks);
+        fn vkCreateDescriptorUpdateTemplate: PFN_vkCreateDescriptorUpdateTemplate(
+            device: Device,
+            pCreateInfo: *const DescriptorUpdateTemplateCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pDescriptorUpdateTemplate: *mut DescriptorUpdateTemplate) -> Result;
+        fn vkDestroyDescriptorUpdateTemplate: PFN_vkDestroyDescriptorUpdateTemplate(
+            device: Device,
+            descriptorUpdateTemplate: DescriptorUpdateTemplate,
+            pAllocator: *const AllocationCallbacks);
+        fn vkUpdateDescriptorSetWithTemplate: PFN_vkUpdateDescriptorSetWithTemplate(
+            device: Device,
+            descriptorSet: DescriptorSet,
+            descriptorUpdateTemplate: DescriptorUpdateTemplate,
+            pData: *const ::std::os::raw::c_void);
+        fn vkGetDescriptorSetLayoutSupport: PFN_vkGetDescriptorSetLayoutSupport(
+            device: Device,
+            pCreateInfo: *const DescriptorSetLayoutCreateInfo,
+            pSupport: *mut DescriptorSetLayoutSupport);
+    }
+}
+
+impl DeviceFnsV1_1 {
+    /// Resolves the Vulkan 1.1 device-level commands for `device`, whose
+    /// physical device must report an `apiVersion` of at least 1.1.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device of the instance `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device)
+            -> ::std::result::Result<DeviceFnsV1_1, LoadingError> {
+        let fns = DeviceFnsV1_1::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl DeviceFnsV1_1 {
+    /// `vkGetImageSparseMemoryRequirements2`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetImageSparseMemoryRequirements2`.
+    pub unsafe fn get_image_sparse_memory_requirements2(&self, device: Device,
+                                                        info: &ImageSparseMemoryRequirementsInfo2)
+            -> Vec<SparseImageMemoryRequirements2> {
+        enumerate_tagged(|count, data| {
+            self.vkGetImageSparseMemoryRequirements2(device, info, count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+}
+
+fn_table!{
+    /// Device-level commands added in Vulkan 1.2, kept apart from `DeviceFns`
+    /// since older implementations do not provide them.
+    pub struct DeviceFnsV1_2 {
+        fn vkCmdDrawIndirectCount: PFN_vkCmdDrawIndirectCount(
+            commandBuffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            countBuffer: Buffer,
+            countBufferOffset: DeviceSize,
+            maxDrawCount: u32,
+            stride: u32);
+        fn vkCmdDrawIndexedIndirectCount: PFN_vkCmdDrawIndexedIndirectCount(
+            commandBuffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            countBuffer: Buffer,
+            countBufferOffset: DeviceSize,
+            maxDrawCount: u32,
+            stride: u32);
+        fn vkCreateRenderPass2: PFN_vkCreateRenderPass2(
+            device: Device,
+            pCreateInfo: *const RenderPassCreateInfo2,
+            pAllocator: *const AllocationCallbacks,
+            pRenderPass: *mut RenderPass) -> Result;
+        fn vkCmdBeginRenderPass2: PFN_vkCmdBeginRenderPass2(
+            commandBuffer: CommandBuffer,
+            pRenderPassBegin: *const RenderPassBeginInfo,
+            pSubpassBeginInfo: *const SubpassBeginInfo);
+        fn vkCmdNextSubpass2: PFN_vkCmdNextSubpass2(
+            commandBuffer: CommandBuffer,
+            pSubpassBeginInfo: *const SubpassBeginInfo,
+            pSubpassEndInfo: *const SubpassEndInfo);
+        fn vkCmdEndRenderPass2: PFN_vkCmdEndRenderPass2(
+            commandBuffer: CommandBuffer,
+            pSubpassEndInfo: *const SubpassEndInfo);
+        fn vkResetQueryPool: PFN_vkResetQueryPool(
+            device: Device,
+            queryPool: QueryPool,
+            firstQuery: u32,
+            queryCount: u32);
+        fn vkGetSemaphoreCounterValue: PFN_vkGetSemaphoreCounterValue(
+            device: Device,
+            semaphore: Semaphore,
+            pValue: *mut u64) -> Result;
+        fn vkWaitSemaphores: PFN_vkWaitSemaphores(
+            device: Device,
+            pWaitInfo: *const SemaphoreWaitInfo,
+            timeout: u64) -> Result;
+        fn vkSignalSemaphore: PFN_vkSignalSemaphore(
+            device: Device,
+            pSignalInfo: *const SemaphoreSignalInfo) -> Result;
+        fn vkGetBufferDeviceAddress: PFN_vkGetBufferDeviceAddress(
+            device: Device,
+            pInfo: *const BufferDeviceAddressInfo) -> DeviceAddress;
+        fn vkGetBufferOpaqueCaptureAddress: PFN_vkGetBufferOpaqueCaptureAddress(
+            device: Device,
+            pInfo: *const BufferDeviceAddressInfo) -> u64;
+        fn vkGetDeviceMemoryOpaqueCaptureAddress: PFN_vkGetDeviceMemoryOpaqueCaptureAddress(
+            device: Device,
+            pInfo: *const DeviceMemoryOpaqueCaptureAddressInfo) -> u64;
+    }
+}
+
+impl DeviceFnsV1_2 {
+    /// Resolves the Vulkan 1.2 device-level commands for `device`, whose
+    /// physical device must report an `apiVersion` of at least 1.2.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device of the instance `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device)
+            -> ::std::result::Result<DeviceFnsV1_2, LoadingError> {
+        let fns = DeviceFnsV1_2::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+fn_table!{
+    /// Instance-level commands added in Vulkan 1.3, kept apart from
+    /// `InstanceFns` since older implementations do not provide them.
+    pub struct InstanceFnsV1_3 {
+        fn vkGetPhysicalDeviceToolProperties: PFN_vkGetPhysicalDeviceToolProperties(
+            physicalDevice: PhysicalDevice,
+            pToolCount: *mut u32,
+            pToolProperties: *mut PhysicalDeviceToolProperties) -> Result;
+    }
+}
+
+impl InstanceFnsV1_3 {
+    /// Resolves the Vulkan 1.3 instance-level commands for `instance`,
+    /// which must have been created with an `apiVersion` of at least 1.3.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry`.
+    pub unsafe fn load(entry: &Entry, instance: Instance)
+            -> ::std::result::Result<InstanceFnsV1_3, LoadingError> {
+        let fns = InstanceFnsV1_3::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl InstanceFnsV1_3 {
+    /// `vkGetPhysicalDeviceToolProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceToolProperties`.
+    pub unsafe fn get_physical_device_tool_properties(&self, physical_device: PhysicalDevice)
+            -> ::std::result::Result<Vec<PhysicalDeviceToolProperties>, Error> {
+        enumerate_tagged(|count, data| {
+            self.vkGetPhysicalDeviceToolProperties(physical_device, count, data)
+        })
+    }
+}
+
+fn_table!{
+    /// Device-level commands added in Vulkan 1.3, kept apart from `DeviceFns`
+    /// since older implementations do not provide them.
+    pub struct DeviceFnsV1_3 {
+        fn vkCreatePrivateDataSlot: PFN_vkCreatePrivateDataSlot(
+            device: Device,
+            pCreateInfo: *const PrivateDataSlotCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pPrivateDataSlot: *mut PrivateDataSlot) -> Result;
+        fn vkDestroyPrivateDataSlot: PFN_vkDestroyPrivateDataSlot(
+            device: Device,
+            privateDataSlot: PrivateDataSlot,
+            pAllocator: *const AllocationCallbacks);
+        fn vkSetPrivateData: PFN_vkSetPrivateData(
+            device: Device,
+            objectType: ObjectType,
+            objectHandle: u64,
+            privateDataSlot: PrivateDataSlot,
+            data: u64) -> Result;
+        fn vkGetPrivateData: PFN_vkGetPrivateData(
+            device: Device,
+            objectType: ObjectType,
+            objectHandle: u64,
+            privateDataSlot: PrivateDataSlot,
+            pData: *mut u64);
+        fn vkCmdSetEvent2: PFN_vkCmdSetEvent2(
+            commandBuffer: CommandBuffer,
+            event: Event,
+            pDependencyInfo: *const DependencyInfo);
+        fn vkCmdResetEvent2: PFN_vkCmdResetEvent2(
+            commandBuffer: CommandBuffer,
+            event: Event,
+            stageMask: PipelineStageFlags2);
+        fn vkCmdWaitEvents2: PFN_vkCmdWaitEvents2(
+            commandBuffer: CommandBuffer,
+            eventCount: u32,
+            pEvents: *const Event,
+            pDependencyInfos: *const DependencyInfo);
+        fn vkCmdPipelineBarrier2: PFN_vkCmdPipelineBarrier2(
+            commandBuffer: CommandBuffer,
+            pDependencyInfo: *const DependencyInfo);
+        fn vkCmdWriteTimestamp2: PFN_vkCmdWriteTimestamp2(
+            commandBuffer: CommandBuffer,
+            stage: PipelineStageFlags2,
+            queryPool: QueryPool,
+            query: u32);
+        fn vkQueueSubmit2: PFN_vkQueueSubmit2(
+            queue: Queue,
+            submitCount: u32,
+            pSubmits: *const SubmitInfo2,
+            fence: Fence) -> Result;
+        fn vkCmdCopyBuffer2: PFN_vkCmdCopyBuffer2(
+            commandBuffer: CommandBuffer,
+            pCopyBufferInfo: *const CopyBufferInfo2);
+        fn vkCmdCopyImage2: PFN_vkCmdCopyImage2(
+            commandBuffer: CommandBuffer,
+            pCopyImageInfo: *const CopyImageInfo2);
+        fn vkCmdCopyBufferToImage2: PFN_vkCmdCopyBufferToImage2(
+            commandBuffer: CommandBuffer,
+            pCopyBufferToImageInfo: *const CopyBufferToImageInfo2);
+        fn vkCmdCopyImageToBuffer2: PFN_vkCmdCopyImageToBuffer2(
+            commandBuffer: CommandBuffer,
+            pCopyImageToBufferInfo: *const CopyImageToBufferInfo2);
+        fn vkCmdBlitImage2: PFN_vkCmdBlitImage2(
+            commandBuffer: CommandBuffer,
+            pBlitImageInfo: *const BlitImageInfo2);
+        fn vkCmdResolveImage2: PFN_vkCmdResolveImage2(
+            commandBuffer: CommandBuffer,
+            pResolveImageInfo: *const ResolveImageInfo2);
+        fn vkCmdBeginRendering: PFN_vkCmdBeginRendering(
+            commandBuffer: CommandBuffer,
+            pRenderingInfo: *const RenderingInfo);
+        fn vkCmdEndRendering: PFN_vkCmdEndRendering(
+            commandBuffer: CommandBuffer);
+        fn vkCmdSetCullMode: PFN_vkCmdSetCullMode(
+            commandBuffer: CommandBuffer,
+            cullMode: CullModeFlags);
+        fn vkCmdSetFrontFace: PFN_vkCmdSetFrontFace(
+            commandBuffer: CommandBuffer,
+            frontFace: FrontFace);
+        fn vkCmdSetPrimitiveTopology: PFN_vkCmdSetPrimitiveTopology(
+            commandBuffer: CommandBuffer,
+            primitiveTopology: PrimitiveTopology);
+        fn vkCmdSetViewportWithCount: PFN_vkCmdSetViewportWithCount(
+            commandBuffer: CommandBuffer,
+            viewportCount: u32,
+            pViewports: *const Viewport);
+        fn vkCmdSetScissorWithCount: PFN_vkCmdSetScissorWithCount(
+            commandBuffer: CommandBuffer,
+            scissorCount: u32,
+            pScissors: *const Rect2D);
+        fn vkCmdBindVertexBuffers2: PFN_vkCmdBindVertexBuffers2(
+            commandBuffer: CommandBuffer,
+            firstBinding: u32,
+            bindingCount: u32,
+            pBuffers: *const Buffer,
+            pOffsets: *const DeviceSize,
+            pSizes: *const DeviceSize,
+            pStrides: *const DeviceSize);
+        fn vkCmdSetDepthTestEnable: PFN_vkCmdSetDepthTestEnable(
+            commandBuffer: CommandBuffer,
+            depthTestEnable: Bool32);
+        fn vkCmdSetDepthWriteEnable: PFN_vkCmdSetDepthWriteEnable(
+            commandBuffer: CommandBuffer,
+            depthWriteEnable: Bool32);
+        fn vkCmdSetDepthCompareOp: PFN_vkCmdSetDepthCompareOp(
+            commandBuffer: CommandBuffer,
+            depthCompareOp: CompareOp);
+        fn vkCmdSetDepthBoundsTestEnable: PFN_vkCmdSetDepthBoundsTestEnable(
+            commandBuffer: CommandBuffer,
+            depthBoundsTestEnable: Bool32);
+        fn vkCmdSetStencilTestEnable: PFN_vkCmdSetStencilTestEnable(
+            commandBuffer: CommandBuffer,
+            stencilTestEnable: Bool32);
+        fn vkCmdSetStencilOp: PFN_vkCmdSetStencilOp(
+            commandBuffer: CommandBuffer,
+            faceMask: StencilFaceFlags,
+            failOp: StencilOp,
+            passOp: StencilOp,
+            depthFailOp: StencilOp,
+            compareOp: CompareOp);
+        fn vkCmdSetRasterizerDiscardEnable: PFN_vkCmdSetRasterizerDiscardEnable(
+            commandBuffer: CommandBuffer,
+            rasterizerDiscardEnable: Bool32);
+        fn vkCmdSetDepthBiasEnable: PFN_vkCmdSetDepthBiasEnable(
+            commandBuffer: CommandBuffer,
+            depthBiasEnable: Bool32);
+        fn vkCmdSetPrimitiveRestartEnable: PFN_vkCmdSetPrimitiveRestartEnable(
+            commandBuffer: CommandBuffer,
+            primitiveRestartEnable: Bool32);
+        fn vkGetDeviceBufferMemoryRequirements: PFN_vkGetDeviceBufferMemoryRequirements(
+            device: Device,
+            pInfo: *const DeviceBufferMemoryRequirements,
+            pMemoryRequirements: *mut MemoryRequirements2);
+        fn vkGetDeviceImageMemoryRequirements: PFN_vkGetDeviceImageMemoryRequirements(
+            device: Device,
+            pInfo: *const DeviceImageMemoryRequirements,
+            pMemoryRequirements: *mut MemoryRequirements2);
+        fn vkGetDeviceImageSparseMemoryRequirements: PFN_vkGetDeviceImageSparseMemoryRequirements(
+            device: Device,
+            pInfo: *const DeviceImageMemoryRequirements,
+            pSparseMemoryRequirementCount: *mut u32,
+            pSparseMemoryRequirements: *mut SparseImageMemoryRequirements2);
+    }
+}
+
+impl DeviceFnsV1_3 {
+    /// Resolves the Vulkan 1.3 device-level commands for `device`, whose
+    /// physical device must report an `apiVersion` of at least 1.3.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device of the instance `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device)
+            -> ::std::result::Result<DeviceFnsV1_3, LoadingError> {
+        let fns = DeviceFnsV1_3::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl DeviceFnsV1_3 {
+    /// `vkGetDeviceImageSparseMemoryRequirements`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetDeviceImageSparseMemoryRequirements`.
+    pub unsafe fn get_device_image_sparse_memory_requirements(&self, device: Device,
+                                                              info: &DeviceImageMemoryRequirements)
+            -> Vec<SparseImageMemoryRequirements2> {
+        enumerate_tagged(|count, data| {
+            self.vkGetDeviceImageSparseMemoryRequirements(device, info, count, data);
+            Result::SUCCESS
+        }).unwrap_or_default()
+    }
+}
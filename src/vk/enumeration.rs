@@ -0,0 +1,58 @@
+// Two-call enumeration: ask for the count, then fill in that many elements
+
+use std::convert::TryInto;
+use std::ptr::null_mut;
+
+use vk::{Error, Result};
+
+/// Runs a count/pointer query such as `vkEnumeratePhysicalDevices` through
+/// `f`, which is called with the count and array pointers. Starts over if
+/// the second call returns `INCOMPLETE`, which happens when elements were
+/// added in between the two calls, and fails on error codes.
+///
+/// # Safety
+///
+/// `f` must write at most as many elements as the count it is given, and
+/// set the count to the number written.
+pub unsafe fn enumerate<N, T, F>(f: F) -> ::std::result::Result<Vec<T>, Error>
+    where N: Copy + Default + TryInto<usize>,
+          F: FnMut(*mut N, *mut T) -> Result
+{
+    read(f, |len| Vec::with_capacity(len), |data, len| data.set_len(len))
+}
+
+/// `enumerate` for queries whose elements must be initialized first, such as
+/// structs whose `sType` the implementation checks. Each element starts out
+/// as `T::default()`.
+///
+/// # Safety
+///
+/// As for `enumerate`.
+pub unsafe fn enumerate_tagged<N, T, F>(f: F) -> ::std::result::Result<Vec<T>, Error>
+    where N: Copy + Default + TryInto<usize>,
+          T: Default + Clone,
+          F: FnMut(*mut N, *mut T) -> Result
+{
+    read(f, |len| vec![T::default(); len], |data, len| data.truncate(len))
+}
+
+unsafe fn read<N, T, F, A, S>(mut f: F, alloc: A, set_len: S) -> ::std::result::Result<Vec<T>, Error>
+    where N: Copy + Default + TryInto<usize>,
+          F: FnMut(*mut N, *mut T) -> Result,
+          A: Fn(usize) -> Vec<T>,
+          S: Fn(&mut Vec<T>, usize)
+{
+    let len = |count: N| count.try_into().ok().expect("count does not fit in usize");
+    loop {
+        let mut count = N::default();
+        f(&mut count, null_mut()).result()?;
+        let mut data = alloc(len(count));
+        let res = f(&mut count, data.as_mut_ptr());
+        if res == Result::INCOMPLETE {
+            continue;
+        }
+        res.result()?;
+        set_len(&mut data, len(count));
+        return Ok(data);
+    }
+}
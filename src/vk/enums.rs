@@ -19,6 +19,13 @@ make_enum!{Result;
     ERROR_INCOMPATIBLE_DRIVER = -9,
     ERROR_TOO_MANY_OBJECTS = -10,
     ERROR_FORMAT_NOT_SUPPORTED = -11,
+    ERROR_FRAGMENTED_POOL = -12,
+    ERROR_UNKNOWN = -13,
+    ERROR_OUT_OF_POOL_MEMORY = -1000069000,
+    ERROR_INVALID_EXTERNAL_HANDLE = -1000072003,
+    ERROR_FRAGMENTATION = -1000161000,
+    ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS = -1000257000,
+    PIPELINE_COMPILE_REQUIRED = 1000297000,
 }
 make_enum!{StructureType;
     APPLICATION_INFO = 0,
@@ -70,6 +77,173 @@ make_enum!{StructureType;
     MEMORY_BARRIER = 46,
     LOADER_INSTANCE_CREATE_INFO = 47,
     LOADER_DEVICE_CREATE_INFO = 48,
+    PHYSICAL_DEVICE_SUBGROUP_PROPERTIES = 1000094000,
+    BIND_BUFFER_MEMORY_INFO = 1000157000,
+    BIND_IMAGE_MEMORY_INFO = 1000157001,
+    PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES = 1000083000,
+    MEMORY_DEDICATED_REQUIREMENTS = 1000127000,
+    MEMORY_DEDICATED_ALLOCATE_INFO = 1000127001,
+    MEMORY_ALLOCATE_FLAGS_INFO = 1000060000,
+    DEVICE_GROUP_RENDER_PASS_BEGIN_INFO = 1000060003,
+    DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO = 1000060004,
+    DEVICE_GROUP_SUBMIT_INFO = 1000060005,
+    DEVICE_GROUP_BIND_SPARSE_INFO = 1000060006,
+    BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO = 1000060013,
+    BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO = 1000060014,
+    PHYSICAL_DEVICE_GROUP_PROPERTIES = 1000070000,
+    DEVICE_GROUP_DEVICE_CREATE_INFO = 1000070001,
+    BUFFER_MEMORY_REQUIREMENTS_INFO_2 = 1000146000,
+    IMAGE_MEMORY_REQUIREMENTS_INFO_2 = 1000146001,
+    IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2 = 1000146002,
+    MEMORY_REQUIREMENTS_2 = 1000146003,
+    SPARSE_IMAGE_MEMORY_REQUIREMENTS_2 = 1000146004,
+    PHYSICAL_DEVICE_FEATURES_2 = 1000059000,
+    PHYSICAL_DEVICE_PROPERTIES_2 = 1000059001,
+    FORMAT_PROPERTIES_2 = 1000059002,
+    IMAGE_FORMAT_PROPERTIES_2 = 1000059003,
+    PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 = 1000059004,
+    QUEUE_FAMILY_PROPERTIES_2 = 1000059005,
+    PHYSICAL_DEVICE_MEMORY_PROPERTIES_2 = 1000059006,
+    SPARSE_IMAGE_FORMAT_PROPERTIES_2 = 1000059007,
+    PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2 = 1000059008,
+    PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES = 1000117000,
+    RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO = 1000117001,
+    IMAGE_VIEW_USAGE_CREATE_INFO = 1000117002,
+    PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO = 1000117003,
+    RENDER_PASS_MULTIVIEW_CREATE_INFO = 1000053000,
+    PHYSICAL_DEVICE_MULTIVIEW_FEATURES = 1000053001,
+    PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES = 1000053002,
+    PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES = 1000120000,
+    PROTECTED_SUBMIT_INFO = 1000145000,
+    PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES = 1000145001,
+    PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES = 1000145002,
+    DEVICE_QUEUE_INFO_2 = 1000145003,
+    SAMPLER_YCBCR_CONVERSION_CREATE_INFO = 1000156000,
+    SAMPLER_YCBCR_CONVERSION_INFO = 1000156001,
+    BIND_IMAGE_PLANE_MEMORY_INFO = 1000156002,
+    IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO = 1000156003,
+    PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES = 1000156004,
+    SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES = 1000156005,
+    DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO = 1000085000,
+    PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO = 1000071000,
+    EXTERNAL_IMAGE_FORMAT_PROPERTIES = 1000071001,
+    PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO = 1000071002,
+    EXTERNAL_BUFFER_PROPERTIES = 1000071003,
+    PHYSICAL_DEVICE_ID_PROPERTIES = 1000071004,
+    EXTERNAL_MEMORY_BUFFER_CREATE_INFO = 1000072000,
+    EXTERNAL_MEMORY_IMAGE_CREATE_INFO = 1000072001,
+    EXPORT_MEMORY_ALLOCATE_INFO = 1000072002,
+    PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO = 1000112000,
+    EXTERNAL_FENCE_PROPERTIES = 1000112001,
+    EXPORT_FENCE_CREATE_INFO = 1000113000,
+    EXPORT_SEMAPHORE_CREATE_INFO = 1000077000,
+    PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO = 1000076000,
+    EXTERNAL_SEMAPHORE_PROPERTIES = 1000076001,
+    PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES = 1000168000,
+    DESCRIPTOR_SET_LAYOUT_SUPPORT = 1000168001,
+    PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES = 1000063000,
+    PHYSICAL_DEVICE_VULKAN_1_1_FEATURES = 49,
+    PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES = 50,
+    PHYSICAL_DEVICE_VULKAN_1_2_FEATURES = 51,
+    PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES = 52,
+    IMAGE_FORMAT_LIST_CREATE_INFO = 1000147000,
+    ATTACHMENT_DESCRIPTION_2 = 1000109000,
+    ATTACHMENT_REFERENCE_2 = 1000109001,
+    SUBPASS_DESCRIPTION_2 = 1000109002,
+    SUBPASS_DEPENDENCY_2 = 1000109003,
+    RENDER_PASS_CREATE_INFO_2 = 1000109004,
+    SUBPASS_BEGIN_INFO = 1000109005,
+    SUBPASS_END_INFO = 1000109006,
+    PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES = 1000177000,
+    PHYSICAL_DEVICE_DRIVER_PROPERTIES = 1000196000,
+    PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES = 1000180000,
+    PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES = 1000082000,
+    PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES = 1000197000,
+    DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO = 1000161000,
+    PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES = 1000161001,
+    PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES = 1000161002,
+    DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO = 1000161003,
+    DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT = 1000161004,
+    PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES = 1000199000,
+    SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE = 1000199001,
+    PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES = 1000221000,
+    IMAGE_STENCIL_USAGE_CREATE_INFO = 1000246000,
+    PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES = 1000130000,
+    SAMPLER_REDUCTION_MODE_CREATE_INFO = 1000130001,
+    PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES = 1000211000,
+    PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES = 1000108000,
+    FRAMEBUFFER_ATTACHMENTS_CREATE_INFO = 1000108001,
+    FRAMEBUFFER_ATTACHMENT_IMAGE_INFO = 1000108002,
+    RENDER_PASS_ATTACHMENT_BEGIN_INFO = 1000108003,
+    PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES = 1000253000,
+    PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES = 1000175000,
+    PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES = 1000241000,
+    ATTACHMENT_REFERENCE_STENCIL_LAYOUT = 1000241001,
+    ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT = 1000241002,
+    PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES = 1000261000,
+    PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES = 1000207000,
+    PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES = 1000207001,
+    SEMAPHORE_TYPE_CREATE_INFO = 1000207002,
+    TIMELINE_SEMAPHORE_SUBMIT_INFO = 1000207003,
+    SEMAPHORE_WAIT_INFO = 1000207004,
+    SEMAPHORE_SIGNAL_INFO = 1000207005,
+    PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES = 1000257000,
+    BUFFER_DEVICE_ADDRESS_INFO = 1000244001,
+    BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO = 1000257002,
+    MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO = 1000257003,
+    DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO = 1000257004,
+    PHYSICAL_DEVICE_VULKAN_1_3_FEATURES = 53,
+    PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES = 54,
+    PIPELINE_CREATION_FEEDBACK_CREATE_INFO = 1000192000,
+    PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES = 1000215000,
+    PHYSICAL_DEVICE_TOOL_PROPERTIES = 1000245000,
+    PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES = 1000276000,
+    PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES = 1000295000,
+    DEVICE_PRIVATE_DATA_CREATE_INFO = 1000295001,
+    PRIVATE_DATA_SLOT_CREATE_INFO = 1000295002,
+    PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES = 1000297000,
+    MEMORY_BARRIER_2 = 1000314000,
+    BUFFER_MEMORY_BARRIER_2 = 1000314001,
+    IMAGE_MEMORY_BARRIER_2 = 1000314002,
+    DEPENDENCY_INFO = 1000314003,
+    SUBMIT_INFO_2 = 1000314004,
+    SEMAPHORE_SUBMIT_INFO = 1000314005,
+    COMMAND_BUFFER_SUBMIT_INFO = 1000314006,
+    PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES = 1000314007,
+    PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES = 1000325000,
+    PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES = 1000335000,
+    COPY_BUFFER_INFO_2 = 1000337000,
+    COPY_IMAGE_INFO_2 = 1000337001,
+    COPY_BUFFER_TO_IMAGE_INFO_2 = 1000337002,
+    COPY_IMAGE_TO_BUFFER_INFO_2 = 1000337003,
+    BLIT_IMAGE_INFO_2 = 1000337004,
+    RESOLVE_IMAGE_INFO_2 = 1000337005,
+    BUFFER_COPY_2 = 1000337006,
+    IMAGE_COPY_2 = 1000337007,
+    IMAGE_BLIT_2 = 1000337008,
+    BUFFER_IMAGE_COPY_2 = 1000337009,
+    IMAGE_RESOLVE_2 = 1000337010,
+    PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES = 1000225000,
+    PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO = 1000225001,
+    PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES = 1000225002,
+    PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES = 1000138000,
+    PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES = 1000138001,
+    WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK = 1000138002,
+    DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO = 1000138003,
+    PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES = 1000066000,
+    RENDERING_INFO = 1000044000,
+    RENDERING_ATTACHMENT_INFO = 1000044001,
+    PIPELINE_RENDERING_CREATE_INFO = 1000044002,
+    PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES = 1000044003,
+    COMMAND_BUFFER_INHERITANCE_RENDERING_INFO = 1000044004,
+    PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES = 1000280000,
+    PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES = 1000280001,
+    PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES = 1000281001,
+    FORMAT_PROPERTIES_3 = 1000360000,
+    PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES = 1000413000,
+    PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES = 1000413001,
+    DEVICE_BUFFER_MEMORY_REQUIREMENTS = 1000413002,
+    DEVICE_IMAGE_MEMORY_REQUIREMENTS = 1000413003,
 }
 make_enum!{SystemAllocationScope;
     COMMAND = 0,
@@ -267,6 +441,60 @@ make_enum!{Format;
     ASTC_12x10_SRGB_BLOCK = 182,
     ASTC_12x12_UNORM_BLOCK = 183,
     ASTC_12x12_SRGB_BLOCK = 184,
+    G8B8G8R8_422_UNORM = 1000156000,
+    B8G8R8G8_422_UNORM = 1000156001,
+    G8_B8_R8_3PLANE_420_UNORM = 1000156002,
+    G8_B8R8_2PLANE_420_UNORM = 1000156003,
+    G8_B8_R8_3PLANE_422_UNORM = 1000156004,
+    G8_B8R8_2PLANE_422_UNORM = 1000156005,
+    G8_B8_R8_3PLANE_444_UNORM = 1000156006,
+    R10X6_UNORM_PACK16 = 1000156007,
+    R10X6G10X6_UNORM_2PACK16 = 1000156008,
+    R10X6G10X6B10X6A10X6_UNORM_4PACK16 = 1000156009,
+    G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 = 1000156010,
+    B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 = 1000156011,
+    G10X6_B10X6_R10X6_3PLANE_420_UNORM_3PACK16 = 1000156012,
+    G10X6_B10X6R10X6_2PLANE_420_UNORM_3PACK16 = 1000156013,
+    G10X6_B10X6_R10X6_3PLANE_422_UNORM_3PACK16 = 1000156014,
+    G10X6_B10X6R10X6_2PLANE_422_UNORM_3PACK16 = 1000156015,
+    G10X6_B10X6_R10X6_3PLANE_444_UNORM_3PACK16 = 1000156016,
+    R12X4_UNORM_PACK16 = 1000156017,
+    R12X4G12X4_UNORM_2PACK16 = 1000156018,
+    R12X4G12X4B12X4A12X4_UNORM_4PACK16 = 1000156019,
+    G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 = 1000156020,
+    B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 = 1000156021,
+    G12X4_B12X4_R12X4_3PLANE_420_UNORM_3PACK16 = 1000156022,
+    G12X4_B12X4R12X4_2PLANE_420_UNORM_3PACK16 = 1000156023,
+    G12X4_B12X4_R12X4_3PLANE_422_UNORM_3PACK16 = 1000156024,
+    G12X4_B12X4R12X4_2PLANE_422_UNORM_3PACK16 = 1000156025,
+    G12X4_B12X4_R12X4_3PLANE_444_UNORM_3PACK16 = 1000156026,
+    G16B16G16R16_422_UNORM = 1000156027,
+    B16G16R16G16_422_UNORM = 1000156028,
+    G16_B16_R16_3PLANE_420_UNORM = 1000156029,
+    G16_B16R16_2PLANE_420_UNORM = 1000156030,
+    G16_B16_R16_3PLANE_422_UNORM = 1000156031,
+    G16_B16R16_2PLANE_422_UNORM = 1000156032,
+    G16_B16_R16_3PLANE_444_UNORM = 1000156033,
+    A4R4G4B4_UNORM_PACK16 = 1000340000,
+    A4B4G4R4_UNORM_PACK16 = 1000340001,
+    ASTC_4x4_SFLOAT_BLOCK = 1000066000,
+    ASTC_5x4_SFLOAT_BLOCK = 1000066001,
+    ASTC_5x5_SFLOAT_BLOCK = 1000066002,
+    ASTC_6x5_SFLOAT_BLOCK = 1000066003,
+    ASTC_6x6_SFLOAT_BLOCK = 1000066004,
+    ASTC_8x5_SFLOAT_BLOCK = 1000066005,
+    ASTC_8x6_SFLOAT_BLOCK = 1000066006,
+    ASTC_8x8_SFLOAT_BLOCK = 1000066007,
+    ASTC_10x5_SFLOAT_BLOCK = 1000066008,
+    ASTC_10x6_SFLOAT_BLOCK = 1000066009,
+    ASTC_10x8_SFLOAT_BLOCK = 1000066010,
+    ASTC_10x10_SFLOAT_BLOCK = 1000066011,
+    ASTC_12x10_SFLOAT_BLOCK = 1000066012,
+    ASTC_12x12_SFLOAT_BLOCK = 1000066013,
+    G8_B8R8_2PLANE_444_UNORM = 1000330000,
+    G10X6_B10X6R10X6_2PLANE_444_UNORM_3PACK16 = 1000330001,
+    G12X4_B12X4R12X4_2PLANE_444_UNORM_3PACK16 = 1000330002,
+    G16_B16R16_2PLANE_444_UNORM = 1000330003,
 }
 make_enum!{ImageType;
     E_1D = 0,
@@ -303,6 +531,14 @@ make_enum!{ImageLayout;
     TRANSFER_SRC_OPTIMAL = 6,
     TRANSFER_DST_OPTIMAL = 7,
     PREINITIALIZED = 8,
+    DEPTH_READ_ONLY_STENCIL_ATTACHMENT_OPTIMAL = 1000117000,
+    DEPTH_ATTACHMENT_STENCIL_READ_ONLY_OPTIMAL = 1000117001,
+    DEPTH_ATTACHMENT_OPTIMAL = 1000241000,
+    DEPTH_READ_ONLY_OPTIMAL = 1000241001,
+    STENCIL_ATTACHMENT_OPTIMAL = 1000241002,
+    STENCIL_READ_ONLY_OPTIMAL = 1000241003,
+    READ_ONLY_OPTIMAL = 1000314000,
+    ATTACHMENT_OPTIMAL = 1000314001,
 }
 make_enum!{ImageViewType;
     E_1D = 0,
@@ -427,6 +663,21 @@ make_enum!{DynamicState;
     STENCIL_COMPARE_MASK = 6,
     STENCIL_WRITE_MASK = 7,
     STENCIL_REFERENCE = 8,
+    CULL_MODE = 1000267000,
+    FRONT_FACE = 1000267001,
+    PRIMITIVE_TOPOLOGY = 1000267002,
+    VIEWPORT_WITH_COUNT = 1000267003,
+    SCISSOR_WITH_COUNT = 1000267004,
+    VERTEX_INPUT_BINDING_STRIDE = 1000267005,
+    DEPTH_TEST_ENABLE = 1000267006,
+    DEPTH_WRITE_ENABLE = 1000267007,
+    DEPTH_COMPARE_OP = 1000267008,
+    DEPTH_BOUNDS_TEST_ENABLE = 1000267009,
+    STENCIL_TEST_ENABLE = 1000267010,
+    STENCIL_OP = 1000267011,
+    RASTERIZER_DISCARD_ENABLE = 1000377001,
+    DEPTH_BIAS_ENABLE = 1000377002,
+    PRIMITIVE_RESTART_ENABLE = 1000377004,
 }
 make_enum!{Filter;
     NEAREST = 0,
@@ -463,6 +714,7 @@ make_enum!{DescriptorType;
     UNIFORM_BUFFER_DYNAMIC = 8,
     STORAGE_BUFFER_DYNAMIC = 9,
     INPUT_ATTACHMENT = 10,
+    INLINE_UNIFORM_BLOCK = 1000138000,
 }
 make_enum!{AttachmentLoadOp;
     LOAD = 0,
@@ -472,6 +724,7 @@ make_enum!{AttachmentLoadOp;
 make_enum!{AttachmentStoreOp;
     STORE = 0,
     DONT_CARE = 1,
+    NONE = 1000301000,
 }
 make_enum!{PipelineBindPoint;
     GRAPHICS = 0,
@@ -489,3 +742,100 @@ make_enum!{SubpassContents;
     INLINE = 0,
     SECONDARY_COMMAND_BUFFERS = 1,
 }
+make_enum!{ObjectType;
+    UNKNOWN = 0,
+    INSTANCE = 1,
+    PHYSICAL_DEVICE = 2,
+    DEVICE = 3,
+    QUEUE = 4,
+    SEMAPHORE = 5,
+    COMMAND_BUFFER = 6,
+    FENCE = 7,
+    DEVICE_MEMORY = 8,
+    BUFFER = 9,
+    IMAGE = 10,
+    EVENT = 11,
+    QUERY_POOL = 12,
+    BUFFER_VIEW = 13,
+    IMAGE_VIEW = 14,
+    SHADER_MODULE = 15,
+    PIPELINE_CACHE = 16,
+    PIPELINE_LAYOUT = 17,
+    RENDER_PASS = 18,
+    PIPELINE = 19,
+    DESCRIPTOR_SET_LAYOUT = 20,
+    SAMPLER = 21,
+    DESCRIPTOR_POOL = 22,
+    DESCRIPTOR_SET = 23,
+    FRAMEBUFFER = 24,
+    COMMAND_POOL = 25,
+    SAMPLER_YCBCR_CONVERSION = 1000156000,
+    DESCRIPTOR_UPDATE_TEMPLATE = 1000085000,
+    PRIVATE_DATA_SLOT = 1000295000,
+}
+make_enum!{PointClippingBehavior;
+    ALL_CLIP_PLANES = 0,
+    USER_CLIP_PLANES_ONLY = 1,
+}
+make_enum!{TessellationDomainOrigin;
+    UPPER_LEFT = 0,
+    LOWER_LEFT = 1,
+}
+make_enum!{SamplerYcbcrModelConversion;
+    RGB_IDENTITY = 0,
+    YCBCR_IDENTITY = 1,
+    YCBCR_709 = 2,
+    YCBCR_601 = 3,
+    YCBCR_2020 = 4,
+}
+make_enum!{SamplerYcbcrRange;
+    ITU_FULL = 0,
+    ITU_NARROW = 1,
+}
+make_enum!{ChromaLocation;
+    COSITED_EVEN = 0,
+    MIDPOINT = 1,
+}
+make_enum!{DescriptorUpdateTemplateType;
+    DESCRIPTOR_SET = 0,
+}
+make_enum!{DriverId;
+    AMD_PROPRIETARY = 1,
+    AMD_OPEN_SOURCE = 2,
+    MESA_RADV = 3,
+    NVIDIA_PROPRIETARY = 4,
+    INTEL_PROPRIETARY_WINDOWS = 5,
+    INTEL_OPEN_SOURCE_MESA = 6,
+    IMAGINATION_PROPRIETARY = 7,
+    QUALCOMM_PROPRIETARY = 8,
+    ARM_PROPRIETARY = 9,
+    GOOGLE_SWIFTSHADER = 10,
+    GGP_PROPRIETARY = 11,
+    BROADCOM_PROPRIETARY = 12,
+    MESA_LLVMPIPE = 13,
+    MOLTENVK = 14,
+    COREAVI_PROPRIETARY = 15,
+    JUGGLER_PROPRIETARY = 16,
+    MESA_TURNIP = 17,
+    MESA_V3DV = 18,
+    MESA_PANVK = 19,
+    SAMSUNG_PROPRIETARY = 20,
+    MESA_VENUS = 21,
+    MESA_DOZEN = 22,
+    MESA_NVK = 23,
+    IMAGINATION_OPEN_SOURCE_MESA = 24,
+}
+make_enum!{ShaderFloatControlsIndependence;
+    E_32_BIT_ONLY = 0,
+    ALL = 1,
+    NONE = 2,
+}
+make_enum!{SamplerReductionMode;
+    WEIGHTED_AVERAGE = 0,
+    MIN = 1,
+    MAX = 2,
+}
+make_enum!{SemaphoreType;
+    BINARY = 0,
+    TIMELINE = 1,
+}
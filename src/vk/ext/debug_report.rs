@@ -1,13 +1,18 @@
 use vk::*;
 
 pub const SPEC_VERSION: u32 = 2;
-pub const EXTENSION_NAME: &'static str = "VK_debug_report";
+pub const EXTENSION_NAME: &'static str = "VK_EXT_debug_report";
 
-impl_enum!{Result;
-    ERROR_VALIDATION_FAILED = -1000011001,
-}
-impl_enum!{StructureType;
-    DEBUG_REPORT_CREATE_INFO = 1000011000,
+ext_enums!{
+    Result {
+        ERROR_VALIDATION_FAILED = -1000011001,
+    }
+    StructureType {
+        DEBUG_REPORT_CREATE_INFO = 1000011000,
+    }
+    ::vk::ObjectType {
+        DEBUG_REPORT_CALLBACK = 1000011000,
+    }
 }
 make_enum!{ObjectType;
     UNKNOWN = 0,
@@ -51,3 +56,106 @@ make_flag!{Flag; Flags;
     ERROR = 0x00000008,
     DEBUG = 0x00000010,
 }
+
+handle!{Callback, DEBUG_REPORT_CALLBACK}
+
+pub type PFN_vkDebugReportCallbackEXT =
+    ::std::option::Option<unsafe extern "C" fn(flags: Flags,
+                                               objectType: ObjectType,
+                                               object: u64,
+                                               location: usize,
+                                               messageCode: i32,
+                                               pLayerPrefix: *const ::std::os::raw::c_char,
+                                               pMessage: *const ::std::os::raw::c_char,
+                                               pUserData: *mut ::std::os::raw::c_void)
+                              -> Bool32>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: Flags,
+    pub pfnCallback: PFN_vkDebugReportCallbackEXT,
+    pub pUserData: *mut ::std::os::raw::c_void,
+}
+tagged!{CreateInfo, DEBUG_REPORT_CREATE_INFO}
+
+pub type PFN_vkCreateDebugReportCallbackEXT =
+    ::std::option::Option<unsafe extern "C" fn(instance: Instance,
+                                               pCreateInfo: *const CreateInfo,
+                                               pAllocator: *const AllocationCallbacks,
+                                               pCallback: *mut Callback)
+                              -> Result>;
+pub type PFN_vkDestroyDebugReportCallbackEXT =
+    ::std::option::Option<unsafe extern "C" fn(instance: Instance,
+                                               callback: Callback,
+                                               pAllocator: *const AllocationCallbacks)>;
+pub type PFN_vkDebugReportMessageEXT =
+    ::std::option::Option<unsafe extern "C" fn(instance: Instance,
+                                               flags: Flags,
+                                               objectType: ObjectType,
+                                               object: u64,
+                                               location: usize,
+                                               messageCode: i32,
+                                               pLayerPrefix: *const ::std::os::raw::c_char,
+                                               pMessage: *const ::std::os::raw::c_char)>;
+
+fn_table!{
+    pub struct Fns {
+        fn vkCreateDebugReportCallbackEXT: PFN_vkCreateDebugReportCallbackEXT(
+            instance: Instance,
+            pCreateInfo: *const CreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pCallback: *mut Callback) -> Result;
+        fn vkDestroyDebugReportCallbackEXT: PFN_vkDestroyDebugReportCallbackEXT(
+            instance: Instance,
+            callback: Callback,
+            pAllocator: *const AllocationCallbacks);
+        fn vkDebugReportMessageEXT: PFN_vkDebugReportMessageEXT(
+            instance: Instance,
+            flags: Flags,
+            objectType: ObjectType,
+            object: u64,
+            location: usize,
+            messageCode: i32,
+            pLayerPrefix: *const ::std::os::raw::c_char,
+            pMessage: *const ::std::os::raw::c_char);
+    }
+}
+
+impl Fns {
+    /// Resolves the commands of this extension for `instance`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry` with `create_info`.
+    pub unsafe fn load(entry: &Entry, instance: Instance, create_info: &InstanceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+builder!{CreateInfo, CreateInfoBuilder}
+impl<'a> CreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: Flags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn pfn_callback(mut self, pfn_callback: PFN_vkDebugReportCallbackEXT) -> Self {
+        self.inner.pfnCallback = pfn_callback;
+        self
+    }
+    #[inline]
+    pub fn user_data(mut self, user_data: *mut ::std::os::raw::c_void) -> Self {
+        self.inner.pUserData = user_data;
+        self
+    }
+}
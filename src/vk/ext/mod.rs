@@ -1 +1,7 @@
 pub mod debug_report;
+
+/// Collects the `ENUM_NAMES` of the modules above.
+#[doc(hidden)]
+pub fn enum_names(tables: &mut Vec<&'static [::vk::ExtEnumNames]>) {
+    tables.push(debug_report::ENUM_NAMES);
+}
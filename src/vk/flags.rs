@@ -12,6 +12,16 @@ make_flag!{FormatFeatureFlag; FormatFeatureFlags;
     BLIT_SRC = 0x00000400,
     BLIT_DST = 0x00000800,
     SAMPLED_IMAGE_FILTER_LINEAR = 0x00001000,
+    TRANSFER_SRC = 0x00004000,
+    TRANSFER_DST = 0x00008000,
+    SAMPLED_IMAGE_FILTER_MINMAX = 0x00010000,
+    MIDPOINT_CHROMA_SAMPLES = 0x00020000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER = 0x00040000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER = 0x00080000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT = 0x00100000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE = 0x00200000,
+    DISJOINT = 0x00400000,
+    COSITED_CHROMA_SAMPLES = 0x00800000,
 }
 make_flag!{ImageUsageFlag; ImageUsageFlags;
     TRANSFER_SRC = 0x00000001,
@@ -29,6 +39,13 @@ make_flag!{ImageCreateFlag; ImageCreateFlags;
     SPARSE_ALIASED = 0x00000004,
     MUTABLE_FORMAT = 0x00000008,
     CUBE_COMPATIBLE = 0x00000010,
+    E_2D_ARRAY_COMPATIBLE = 0x00000020,
+    SPLIT_INSTANCE_BIND_REGIONS = 0x00000040,
+    BLOCK_TEXEL_VIEW_COMPATIBLE = 0x00000080,
+    EXTENDED_USAGE = 0x00000100,
+    DISJOINT = 0x00000200,
+    ALIAS = 0x00000400,
+    PROTECTED = 0x00000800,
 }
 make_flag!{SampleCountFlag; SampleCountFlags;
     E_1 = 0x00000001,
@@ -44,6 +61,7 @@ make_flag!{QueueFlag; QueueFlags;
     COMPUTE = 0x00000002,
     TRANSFER = 0x00000004,
     SPARSE_BINDING = 0x00000008,
+    PROTECTED = 0x00000010,
 }
 make_flag!{MemoryPropertyFlag; MemoryPropertyFlags;
     DEVICE_LOCAL = 0x00000001,
@@ -51,11 +69,14 @@ make_flag!{MemoryPropertyFlag; MemoryPropertyFlags;
     HOST_COHERENT = 0x00000004,
     HOST_CACHED = 0x00000008,
     LAZILY_ALLOCATED = 0x00000010,
+    PROTECTED = 0x00000020,
 }
 make_flag!{MemoryHeapFlag; MemoryHeapFlags;
     DEVICE_LOCAL = 0x00000001,
+    MULTI_INSTANCE = 0x00000002,
 }
 make_flag!{PipelineStageFlag; PipelineStageFlags;
+    NONE = 0,
     TOP_OF_PIPE = 0x00000001,
     DRAW_INDIRECT = 0x00000002,
     VERTEX_INPUT = 0x00000004,
@@ -79,6 +100,9 @@ make_flag!{ImageAspectFlag; ImageAspectFlags;
     DEPTH = 0x00000002,
     STENCIL = 0x00000004,
     METADATA = 0x00000008,
+    PLANE_0 = 0x00000010,
+    PLANE_1 = 0x00000020,
+    PLANE_2 = 0x00000040,
 }
 make_flag!{SparseImageFormatFlag; SparseImageFormatFlags;
     SINGLE_MIPTAIL = 0x00000001,
@@ -114,6 +138,8 @@ make_flag!{BufferCreateFlag; BufferCreateFlags;
     SPARSE_BINDING = 0x00000001,
     SPARSE_RESIDENCY = 0x00000002,
     SPARSE_ALIASED = 0x00000004,
+    PROTECTED = 0x00000008,
+    DEVICE_ADDRESS_CAPTURE_REPLAY = 0x00000010,
 }
 make_flag!{BufferUsageFlag; BufferUsageFlags;
     TRANSFER_SRC = 0x00000001,
@@ -125,11 +151,16 @@ make_flag!{BufferUsageFlag; BufferUsageFlags;
     INDEX_BUFFER = 0x00000040,
     VERTEX_BUFFER = 0x00000080,
     INDIRECT_BUFFER = 0x00000100,
+    SHADER_DEVICE_ADDRESS = 0x00020000,
 }
 make_flag!{PipelineCreateFlag; PipelineCreateFlags;
     DISABLE_OPTIMIZATION = 0x00000001,
     ALLOW_DERIVATIVES = 0x00000002,
     DERIVATIVE = 0x00000004,
+    VIEW_INDEX_FROM_DEVICE_INDEX = 0x00000008,
+    DISPATCH_BASE = 0x00000010,
+    FAIL_ON_PIPELINE_COMPILE_REQUIRED = 0x00000100,
+    EARLY_RETURN_ON_FAILURE = 0x00000200,
 }
 make_flag!{ShaderStageFlag; ShaderStageFlags;
     VERTEX = 0x00000001,
@@ -155,11 +186,13 @@ make_flag!{ColorComponentFlag; ColorComponentFlags;
 }
 make_flag!{DescriptorPoolCreateFlag; DescriptorPoolCreateFlags;
     FREE_DESCRIPTOR_SET = 0x00000001,
+    UPDATE_AFTER_BIND = 0x00000002,
 }
 make_flag!{AttachmentDescriptionFlag; AttachmentDescriptionFlags;
     MAY_ALIAS = 0x00000001,
 }
 make_flag!{AccessFlag; AccessFlags;
+    NONE = 0,
     INDIRECT_COMMAND_READ = 0x00000001,
     INDEX_READ = 0x00000002,
     VERTEX_ATTRIBUTE_READ = 0x00000004,
@@ -180,10 +213,13 @@ make_flag!{AccessFlag; AccessFlags;
 }
 make_flag!{DependencyFlag; DependencyFlags;
     BY_REGION = 0x00000001,
+    VIEW_LOCAL = 0x00000002,
+    DEVICE_GROUP = 0x00000004,
 }
 make_flag!{CommandPoolCreateFlag; CommandPoolCreateFlags;
     TRANSIENT = 0x00000001,
     RESET_COMMAND_BUFFER = 0x00000002,
+    PROTECTED = 0x00000004,
 }
 make_flag!{CommandPoolResetFlag; CommandPoolResetFlags;
     RELEASE_RESOURCES = 0x00000001,
@@ -204,18 +240,211 @@ make_flag!{StencilFaceFlag; StencilFaceFlags;
     FACE_BACK = 0x00000002,
     FRONT_AND_BACK = 0x3,
 }
+make_flag!{DeviceQueueCreateFlag; DeviceQueueCreateFlags;
+    PROTECTED = 0x00000001,
+}
+make_flag!{SubgroupFeatureFlag; SubgroupFeatureFlags;
+    BASIC = 0x00000001,
+    VOTE = 0x00000002,
+    ARITHMETIC = 0x00000004,
+    BALLOT = 0x00000008,
+    SHUFFLE = 0x00000010,
+    SHUFFLE_RELATIVE = 0x00000020,
+    CLUSTERED = 0x00000040,
+    QUAD = 0x00000080,
+}
+make_flag!{PeerMemoryFeatureFlag; PeerMemoryFeatureFlags;
+    COPY_SRC = 0x00000001,
+    COPY_DST = 0x00000002,
+    GENERIC_SRC = 0x00000004,
+    GENERIC_DST = 0x00000008,
+}
+make_flag!{MemoryAllocateFlag; MemoryAllocateFlags;
+    DEVICE_MASK = 0x00000001,
+    DEVICE_ADDRESS = 0x00000002,
+    DEVICE_ADDRESS_CAPTURE_REPLAY = 0x00000004,
+}
+make_flag!{ExternalMemoryHandleTypeFlag; ExternalMemoryHandleTypeFlags;
+    OPAQUE_FD = 0x00000001,
+    OPAQUE_WIN32 = 0x00000002,
+    OPAQUE_WIN32_KMT = 0x00000004,
+    D3D11_TEXTURE = 0x00000008,
+    D3D11_TEXTURE_KMT = 0x00000010,
+    D3D12_HEAP = 0x00000020,
+    D3D12_RESOURCE = 0x00000040,
+}
+make_flag!{ExternalMemoryFeatureFlag; ExternalMemoryFeatureFlags;
+    DEDICATED_ONLY = 0x00000001,
+    EXPORTABLE = 0x00000002,
+    IMPORTABLE = 0x00000004,
+}
+make_flag!{ExternalFenceHandleTypeFlag; ExternalFenceHandleTypeFlags;
+    OPAQUE_FD = 0x00000001,
+    OPAQUE_WIN32 = 0x00000002,
+    OPAQUE_WIN32_KMT = 0x00000004,
+    SYNC_FD = 0x00000008,
+}
+make_flag!{ExternalFenceFeatureFlag; ExternalFenceFeatureFlags;
+    EXPORTABLE = 0x00000001,
+    IMPORTABLE = 0x00000002,
+}
+make_flag!{FenceImportFlag; FenceImportFlags;
+    TEMPORARY = 0x00000001,
+}
+make_flag!{SemaphoreImportFlag; SemaphoreImportFlags;
+    TEMPORARY = 0x00000001,
+}
+make_flag!{ExternalSemaphoreHandleTypeFlag; ExternalSemaphoreHandleTypeFlags;
+    OPAQUE_FD = 0x00000001,
+    OPAQUE_WIN32 = 0x00000002,
+    OPAQUE_WIN32_KMT = 0x00000004,
+    D3D12_FENCE = 0x00000008,
+    SYNC_FD = 0x00000010,
+}
+make_flag!{ExternalSemaphoreFeatureFlag; ExternalSemaphoreFeatureFlags;
+    EXPORTABLE = 0x00000001,
+    IMPORTABLE = 0x00000002,
+}
+make_flag!{DescriptorSetLayoutCreateFlag; DescriptorSetLayoutCreateFlags;
+    UPDATE_AFTER_BIND_POOL = 0x00000002,
+}
+make_flag!{FramebufferCreateFlag; FramebufferCreateFlags;
+    IMAGELESS = 0x00000001,
+}
+make_flag!{ResolveModeFlag; ResolveModeFlags;
+    NONE = 0,
+    SAMPLE_ZERO = 0x00000001,
+    AVERAGE = 0x00000002,
+    MIN = 0x00000004,
+    MAX = 0x00000008,
+}
+make_flag!{DescriptorBindingFlag; DescriptorBindingFlags;
+    UPDATE_AFTER_BIND = 0x00000001,
+    UPDATE_UNUSED_WHILE_PENDING = 0x00000002,
+    PARTIALLY_BOUND = 0x00000004,
+    VARIABLE_DESCRIPTOR_COUNT = 0x00000008,
+}
+make_flag!{SemaphoreWaitFlag; SemaphoreWaitFlags;
+    ANY = 0x00000001,
+}
+make_flag!{EventCreateFlag; EventCreateFlags;
+    DEVICE_ONLY = 0x00000001,
+}
+make_flag!{PipelineCacheCreateFlag; PipelineCacheCreateFlags;
+    EXTERNALLY_SYNCHRONIZED = 0x00000001,
+}
+make_flag!{PipelineShaderStageCreateFlag; PipelineShaderStageCreateFlags;
+    ALLOW_VARYING_SUBGROUP_SIZE = 0x00000001,
+    REQUIRE_FULL_SUBGROUPS = 0x00000002,
+}
+make_flag!{PipelineCreationFeedbackFlag; PipelineCreationFeedbackFlags;
+    VALID = 0x00000001,
+    APPLICATION_PIPELINE_CACHE_HIT = 0x00000002,
+    BASE_PIPELINE_ACCELERATION = 0x00000004,
+}
+make_flag!{ToolPurposeFlag; ToolPurposeFlags;
+    VALIDATION = 0x00000001,
+    PROFILING = 0x00000002,
+    TRACING = 0x00000004,
+    ADDITIONAL_FEATURES = 0x00000008,
+    MODIFYING_FEATURES = 0x00000010,
+}
+make_flag!{PipelineStageFlag2; PipelineStageFlags2: u64;
+    NONE = 0,
+    TOP_OF_PIPE = 0x00000001,
+    DRAW_INDIRECT = 0x00000002,
+    VERTEX_INPUT = 0x00000004,
+    VERTEX_SHADER = 0x00000008,
+    TESSELLATION_CONTROL_SHADER = 0x00000010,
+    TESSELLATION_EVALUATION_SHADER = 0x00000020,
+    GEOMETRY_SHADER = 0x00000040,
+    FRAGMENT_SHADER = 0x00000080,
+    EARLY_FRAGMENT_TESTS = 0x00000100,
+    LATE_FRAGMENT_TESTS = 0x00000200,
+    COLOR_ATTACHMENT_OUTPUT = 0x00000400,
+    COMPUTE_SHADER = 0x00000800,
+    ALL_TRANSFER = 0x00001000,
+    TRANSFER = 0x00001000,
+    BOTTOM_OF_PIPE = 0x00002000,
+    HOST = 0x00004000,
+    ALL_GRAPHICS = 0x00008000,
+    ALL_COMMANDS = 0x00010000,
+    COPY = 0x100000000,
+    RESOLVE = 0x200000000,
+    BLIT = 0x400000000,
+    CLEAR = 0x800000000,
+    INDEX_INPUT = 0x1000000000,
+    VERTEX_ATTRIBUTE_INPUT = 0x2000000000,
+    PRE_RASTERIZATION_SHADERS = 0x4000000000,
+}
+make_flag!{AccessFlag2; AccessFlags2: u64;
+    NONE = 0,
+    INDIRECT_COMMAND_READ = 0x00000001,
+    INDEX_READ = 0x00000002,
+    VERTEX_ATTRIBUTE_READ = 0x00000004,
+    UNIFORM_READ = 0x00000008,
+    INPUT_ATTACHMENT_READ = 0x00000010,
+    SHADER_READ = 0x00000020,
+    SHADER_WRITE = 0x00000040,
+    COLOR_ATTACHMENT_READ = 0x00000080,
+    COLOR_ATTACHMENT_WRITE = 0x00000100,
+    DEPTH_STENCIL_ATTACHMENT_READ = 0x00000200,
+    DEPTH_STENCIL_ATTACHMENT_WRITE = 0x00000400,
+    TRANSFER_READ = 0x00000800,
+    TRANSFER_WRITE = 0x00001000,
+    HOST_READ = 0x00002000,
+    HOST_WRITE = 0x00004000,
+    MEMORY_READ = 0x00008000,
+    MEMORY_WRITE = 0x00010000,
+    SHADER_SAMPLED_READ = 0x100000000,
+    SHADER_STORAGE_READ = 0x200000000,
+    SHADER_STORAGE_WRITE = 0x400000000,
+}
+make_flag!{SubmitFlag; SubmitFlags;
+    PROTECTED = 0x00000001,
+}
+make_flag!{RenderingFlag; RenderingFlags;
+    CONTENTS_SECONDARY_COMMAND_BUFFERS = 0x00000001,
+    SUSPENDING = 0x00000002,
+    RESUMING = 0x00000004,
+}
+make_flag!{FormatFeatureFlag2; FormatFeatureFlags2: u64;
+    SAMPLED_IMAGE = 0x00000001,
+    STORAGE_IMAGE = 0x00000002,
+    STORAGE_IMAGE_ATOMIC = 0x00000004,
+    UNIFORM_TEXEL_BUFFER = 0x00000008,
+    STORAGE_TEXEL_BUFFER = 0x00000010,
+    STORAGE_TEXEL_BUFFER_ATOMIC = 0x00000020,
+    VERTEX_BUFFER = 0x00000040,
+    COLOR_ATTACHMENT = 0x00000080,
+    COLOR_ATTACHMENT_BLEND = 0x00000100,
+    DEPTH_STENCIL_ATTACHMENT = 0x00000200,
+    BLIT_SRC = 0x00000400,
+    BLIT_DST = 0x00000800,
+    SAMPLED_IMAGE_FILTER_LINEAR = 0x00001000,
+    SAMPLED_IMAGE_FILTER_CUBIC = 0x00002000,
+    TRANSFER_SRC = 0x00004000,
+    TRANSFER_DST = 0x00008000,
+    SAMPLED_IMAGE_FILTER_MINMAX = 0x00010000,
+    MIDPOINT_CHROMA_SAMPLES = 0x00020000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER = 0x00040000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER = 0x00080000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT = 0x00100000,
+    SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE = 0x00200000,
+    DISJOINT = 0x00400000,
+    COSITED_CHROMA_SAMPLES = 0x00800000,
+    STORAGE_READ_WITHOUT_FORMAT = 0x80000000,
+    STORAGE_WRITE_WITHOUT_FORMAT = 0x100000000,
+    SAMPLED_IMAGE_DEPTH_COMPARISON = 0x200000000,
+}
 make_flag!{InstanceCreateFlag; InstanceCreateFlags; }
 make_flag!{DeviceCreateFlag; DeviceCreateFlags; }
-make_flag!{DeviceQueueCreateFlag; DeviceQueueCreateFlags; }
 make_flag!{MemoryMapFlag; MemoryMapFlags; }
 make_flag!{SemaphoreCreateFlag; SemaphoreCreateFlags; }
-make_flag!{EventCreateFlag; EventCreateFlags; }
 make_flag!{QueryPoolCreateFlag; QueryPoolCreateFlags; }
 make_flag!{BufferViewCreateFlag; BufferViewCreateFlags; }
 make_flag!{ImageViewCreateFlag; ImageViewCreateFlags; }
 make_flag!{ShaderModuleCreateFlag; ShaderModuleCreateFlags; }
-make_flag!{PipelineCacheCreateFlag; PipelineCacheCreateFlags; }
-make_flag!{PipelineShaderStageCreateFlag; PipelineShaderStageCreateFlags; }
 make_flag!{PipelineVertexInputStateCreateFlag; PipelineVertexInputStateCreateFlags; }
 make_flag!{PipelineInputAssemblyStateCreateFlag; PipelineInputAssemblyStateCreateFlags; }
 make_flag!{PipelineTessellationStateCreateFlag; PipelineTessellationStateCreateFlags; }
@@ -227,8 +456,9 @@ make_flag!{PipelineColorBlendStateCreateFlag; PipelineColorBlendStateCreateFlags
 make_flag!{PipelineDynamicStateCreateFlag; PipelineDynamicStateCreateFlags; }
 make_flag!{PipelineLayoutCreateFlag; PipelineLayoutCreateFlags; }
 make_flag!{SamplerCreateFlag; SamplerCreateFlags; }
-make_flag!{DescriptorSetLayoutCreateFlag; DescriptorSetLayoutCreateFlags; }
 make_flag!{DescriptorPoolResetFlag; DescriptorPoolResetFlags; }
-make_flag!{FramebufferCreateFlag; FramebufferCreateFlags; }
 make_flag!{RenderPassCreateFlag; RenderPassCreateFlags; }
 make_flag!{SubpassDescriptionFlag; SubpassDescriptionFlags; }
+make_flag!{CommandPoolTrimFlag; CommandPoolTrimFlags; }
+make_flag!{DescriptorUpdateTemplateCreateFlag; DescriptorUpdateTemplateCreateFlags; }
+make_flag!{PrivateDataSlotCreateFlag; PrivateDataSlotCreateFlags; }
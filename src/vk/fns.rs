@@ -95,7 +95,7 @@ extern "C" {
     pub fn vkGetPhysicalDeviceSparseImageFormatProperties(physicalDevice: PhysicalDevice,
                                                           format: Format,
                                                           _type: ImageType,
-                                                          samples: SampleCountFlags,
+                                                          samples: SampleCountFlag,
                                                           usage: ImageUsageFlags,
                                                           tiling: ImageTiling,
                                                           pPropertyCount: *mut uint32_t,
@@ -426,7 +426,7 @@ extern "C" {
                                queryPool: QueryPool, firstQuery: uint32_t,
                                queryCount: uint32_t);
     pub fn vkCmdWriteTimestamp(commandBuffer: CommandBuffer,
-                               pipelineStage: PipelineStageFlags,
+                               pipelineStage: PipelineStageFlag,
                                queryPool: QueryPool, query: uint32_t);
     pub fn vkCmdCopyQueryPoolResults(commandBuffer: CommandBuffer,
                                      queryPool: QueryPool,
@@ -450,4 +450,441 @@ extern "C" {
     pub fn vkCmdExecuteCommands(commandBuffer: CommandBuffer,
                                 commandBufferCount: uint32_t,
                                 pCommandBuffers: *const CommandBuffer);
+    pub fn vkEnumerateInstanceVersion(pApiVersion: *mut u32) -> Result;
+    pub fn vkEnumeratePhysicalDeviceGroups(instance: Instance,
+                                           pPhysicalDeviceGroupCount: *mut u32,
+                                           pPhysicalDeviceGroupProperties: *mut PhysicalDeviceGroupProperties) -> Result;
+    pub fn vkGetPhysicalDeviceFeatures2(physicalDevice: PhysicalDevice,
+                                        pFeatures: *mut PhysicalDeviceFeatures2);
+    pub fn vkGetPhysicalDeviceProperties2(physicalDevice: PhysicalDevice,
+                                          pProperties: *mut PhysicalDeviceProperties2);
+    pub fn vkGetPhysicalDeviceFormatProperties2(physicalDevice: PhysicalDevice,
+                                                format: Format,
+                                                pFormatProperties: *mut FormatProperties2);
+    pub fn vkGetPhysicalDeviceImageFormatProperties2(physicalDevice: PhysicalDevice,
+                                                     pImageFormatInfo: *const PhysicalDeviceImageFormatInfo2,
+                                                     pImageFormatProperties: *mut ImageFormatProperties2) -> Result;
+    pub fn vkGetPhysicalDeviceQueueFamilyProperties2(physicalDevice: PhysicalDevice,
+                                                     pQueueFamilyPropertyCount: *mut u32,
+                                                     pQueueFamilyProperties: *mut QueueFamilyProperties2);
+    pub fn vkGetPhysicalDeviceMemoryProperties2(physicalDevice: PhysicalDevice,
+                                                pMemoryProperties: *mut PhysicalDeviceMemoryProperties2);
+    pub fn vkGetPhysicalDeviceSparseImageFormatProperties2(physicalDevice: PhysicalDevice,
+                                                           pFormatInfo: *const PhysicalDeviceSparseImageFormatInfo2,
+                                                           pPropertyCount: *mut u32,
+                                                           pProperties: *mut SparseImageFormatProperties2);
+    pub fn vkGetPhysicalDeviceExternalBufferProperties(physicalDevice: PhysicalDevice,
+                                                       pExternalBufferInfo: *const PhysicalDeviceExternalBufferInfo,
+                                                       pExternalBufferProperties: *mut ExternalBufferProperties);
+    pub fn vkGetPhysicalDeviceExternalFenceProperties(physicalDevice: PhysicalDevice,
+                                                      pExternalFenceInfo: *const PhysicalDeviceExternalFenceInfo,
+                                                      pExternalFenceProperties: *mut ExternalFenceProperties);
+    pub fn vkGetPhysicalDeviceExternalSemaphoreProperties(physicalDevice: PhysicalDevice,
+                                                          pExternalSemaphoreInfo: *const PhysicalDeviceExternalSemaphoreInfo,
+                                                          pExternalSemaphoreProperties: *mut ExternalSemaphoreProperties);
+    pub fn vkBindBufferMemory2(device: Device,
+                               bindInfoCount: u32,
+                               pBindInfos: *const BindBufferMemoryInfo) -> Result;
+    pub fn vkBindImageMemory2(device: Device,
+                              bindInfoCount: u32,
+                              pBindInfos: *const BindImageMemoryInfo) -> Result;
+    pub fn vkGetDeviceGroupPeerMemoryFeatures(device: Device,
+                                              heapIndex: u32,
+                                              localDeviceIndex: u32,
+                                              remoteDeviceIndex: u32,
+                                              pPeerMemoryFeatures: *mut PeerMemoryFeatureFlags);
+    pub fn vkCmdSetDeviceMask(commandBuffer: CommandBuffer,
+                              deviceMask: u32);
+    pub fn vkCmdDispatchBase(commandBuffer: CommandBuffer,
+                             baseGroupX: u32,
+                             baseGroupY: u32,
+                             baseGroupZ: u32,
+                             groupCountX: u32,
+                             groupCountY: u32,
+                             groupCountZ: u32);
+    pub fn vkGetImageMemoryRequirements2(device: Device,
+                                         pInfo: *const ImageMemoryRequirementsInfo2,
+                                         pMemoryRequirements: *mut MemoryRequirements2);
+    pub fn vkGetBufferMemoryRequirements2(device: Device,
+                                          pInfo: *const BufferMemoryRequirementsInfo2,
+                                          pMemoryRequirements: *mut MemoryRequirements2);
+    pub fn vkGetImageSparseMemoryRequirements2(device: Device,
+                                               pInfo: *const ImageSparseMemoryRequirementsInfo2,
+                                               pSparseMemoryRequirementCount: *mut u32,
+                                               pSparseMemoryRequirements: *mut SparseImageMemoryRequirements2);
+    pub fn vkTrimCommandPool(device: Device,
+                             commandPool: CommandPool,
+                             flags: CommandPoolTrimFlags);
+    pub fn vkGetDeviceQueue2(device: Device,
+                             pQueueInfo: *const DeviceQueueInfo2,
+                             pQueue: *mut Queue);
+    pub fn vkCreateSamplerYcbcrConversion(device: Device,
+                                          pCreateInfo: *const SamplerYcbcrConversionCreateInfo,
+                                          pAllocator: *const AllocationCallbacks,
+                                          pYcbcrConversion: *mut SamplerYcbcrConversion) -> Result;
+    pub fn vkDestroySamplerYcbcrConversion(device: Device,
+                                           ycbcrConversion: SamplerYcbcrConversion,
+                                           pAllocator: *const AllocationCallbacks);
+    pub fn vkCreateDescriptorUpdateTemplate(device: Device,
+                                            pCreateInfo: *const DescriptorUpdateTemplateCreateInfo,
+                                            pAllocator: *const AllocationCallbacks,
+                                            pDescriptorUpdateTemplate: *mut DescriptorUpdateTemplate) -> Result;
+    pub fn vkDestroyDescriptorUpdateTemplate(device: Device,
+                                             descriptorUpdateTemplate: DescriptorUpdateTemplate,
+                                             pAllocator: *const AllocationCallbacks);
+    pub fn vkUpdateDescriptorSetWithTemplate(device: Device,
+                                             descriptorSet: DescriptorSet,
+                                             descriptorUpdateTemplate: DescriptorUpdateTemplate,
+                                             pData: *const ::std::os::raw::c_void);
+    pub fn vkGetDescriptorSetLayoutSupport(device: Device,
+                                           pCreateInfo: *const DescriptorSetLayoutCreateInfo,
+                                           pSupport: *mut DescriptorSetLayoutSupport);
+    pub fn vkCmdDrawIndirectCount(commandBuffer: CommandBuffer,
+                                  buffer: Buffer,
+                                  offset: DeviceSize,
+                                  countBuffer: Buffer,
+                                  countBufferOffset: DeviceSize,
+                                  maxDrawCount: u32,
+                                  stride: u32);
+    pub fn vkCmdDrawIndexedIndirectCount(commandBuffer: CommandBuffer,
+                                         buffer: Buffer,
+                                         offset: DeviceSize,
+                                         countBuffer: Buffer,
+                                         countBufferOffset: DeviceSize,
+                                         maxDrawCount: u32,
+                                         stride: u32);
+    pub fn vkCreateRenderPass2(device: Device,
+                               pCreateInfo: *const RenderPassCreateInfo2,
+                               pAllocator: *const AllocationCallbacks,
+                               pRenderPass: *mut RenderPass) -> Result;
+    pub fn vkCmdBeginRenderPass2(commandBuffer: CommandBuffer,
+                                 pRenderPassBegin: *const RenderPassBeginInfo,
+                                 pSubpassBeginInfo: *const SubpassBeginInfo);
+    pub fn vkCmdNextSubpass2(commandBuffer: CommandBuffer,
+                             pSubpassBeginInfo: *const SubpassBeginInfo,
+                             pSubpassEndInfo: *const SubpassEndInfo);
+    pub fn vkCmdEndRenderPass2(commandBuffer: CommandBuffer,
+                               pSubpassEndInfo: *const SubpassEndInfo);
+    pub fn vkResetQueryPool(device: Device,
+                            queryPool: QueryPool,
+                            firstQuery: u32,
+                            queryCount: u32);
+    pub fn vkGetSemaphoreCounterValue(device: Device,
+                                      semaphore: Semaphore,
+                                      pValue: *mut u64) -> Result;
+    pub fn vkWaitSemaphores(device: Device,
+                            pWaitInfo: *const SemaphoreWaitInfo,
+                            timeout: u64) -> Result;
+    pub fn vkSignalSemaphore(device: Device,
+                             pSignalInfo: *const SemaphoreSignalInfo) -> Result;
+    pub fn vkGetBufferDeviceAddress(device: Device,
+                                    pInfo: *const BufferDeviceAddressInfo) -> DeviceAddress;
+    pub fn vkGetBufferOpaqueCaptureAddress(device: Device,
+                                           pInfo: *const BufferDeviceAddressInfo) -> u64;
+    pub fn vkGetDeviceMemoryOpaqueCaptureAddress(device: Device,
+                                                 pInfo: *const DeviceMemoryOpaqueCaptureAddressInfo) -> u64;
+    pub fn vkGetPhysicalDeviceToolProperties(physicalDevice: PhysicalDevice,
+                                             pToolCount: *mut u32,
+                                             pToolProperties: *mut PhysicalDeviceToolProperties) -> Result;
+    pub fn vkCreatePrivateDataSlot(device: Device,
+                                   pCreateInfo: *const PrivateDataSlotCreateInfo,
+                                   pAllocator: *const AllocationCallbacks,
+                                   pPrivateDataSlot: *mut PrivateDataSlot) -> Result;
+    pub fn vkDestroyPrivateDataSlot(device: Device,
+                                    privateDataSlot: PrivateDataSlot,
+                                    pAllocator: *const AllocationCallbacks);
+    pub fn vkSetPrivateData(device: Device,
+                            objectType: ObjectType,
+                            objectHandle: u64,
+                            privateDataSlot: PrivateDataSlot,
+                            data: u64) -> Result;
+    pub fn vkGetPrivateData(device: Device,
+                            objectType: ObjectType,
+                            objectHandle: u64,
+                            privateDataSlot: PrivateDataSlot,
+                            pData: *mut u64);
+    pub fn vkCmdSetEvent2(commandBuffer: CommandBuffer,
+                          event: Event,
+                          pDependencyInfo: *const DependencyInfo);
+    pub fn vkCmdResetEvent2(commandBuffer: CommandBuffer,
+                            event: Event,
+                            stageMask: PipelineStageFlags2);
+    pub fn vkCmdWaitEvents2(commandBuffer: CommandBuffer,
+                            eventCount: u32,
+                            pEvents: *const Event,
+                            pDependencyInfos: *const DependencyInfo);
+    pub fn vkCmdPipelineBarrier2(commandBuffer: CommandBuffer,
+                                 pDependencyInfo: *const DependencyInfo);
+    pub fn vkCmdWriteTimestamp2(commandBuffer: CommandBuffer,
+                                stage: PipelineStageFlags2,
+                                queryPool: QueryPool,
+                                query: u32);
+    pub fn vkQueueSubmit2(queue: Queue,
+                          submitCount: u32,
+                          pSubmits: *const SubmitInfo2,
+                          fence: Fence) -> Result;
+    pub fn vkCmdCopyBuffer2(commandBuffer: CommandBuffer,
+                            pCopyBufferInfo: *const CopyBufferInfo2);
+    pub fn vkCmdCopyImage2(commandBuffer: CommandBuffer,
+                           pCopyImageInfo: *const CopyImageInfo2);
+    pub fn vkCmdCopyBufferToImage2(commandBuffer: CommandBuffer,
+                                   pCopyBufferToImageInfo: *const CopyBufferToImageInfo2);
+    pub fn vkCmdCopyImageToBuffer2(commandBuffer: CommandBuffer,
+                                   pCopyImageToBufferInfo: *const CopyImageToBufferInfo2);
+    pub fn vkCmdBlitImage2(commandBuffer: CommandBuffer,
+                           pBlitImageInfo: *const BlitImageInfo2);
+    pub fn vkCmdResolveImage2(commandBuffer: CommandBuffer,
+                              pResolveImageInfo: *const ResolveImageInfo2);
+    pub fn vkCmdBeginRendering(commandBuffer: CommandBuffer,
+                               pRenderingInfo: *const RenderingInfo);
+    pub fn vkCmdEndRendering(commandBuffer: CommandBuffer);
+    pub fn vkCmdSetCullMode(commandBuffer: CommandBuffer,
+                            cullMode: CullModeFlags);
+    pub fn vkCmdSetFrontFace(commandBuffer: CommandBuffer,
+                             frontFace: FrontFace);
+    pub fn vkCmdSetPrimitiveTopology(commandBuffer: CommandBuffer,
+                                     primitiveTopology: PrimitiveTopology);
+    pub fn vkCmdSetViewportWithCount(commandBuffer: CommandBuffer,
+                                     viewportCount: u32,
+                                     pViewports: *const Viewport);
+    pub fn vkCmdSetScissorWithCount(commandBuffer: CommandBuffer,
+                                    scissorCount: u32,
+                                    pScissors: *const Rect2D);
+    pub fn vkCmdBindVertexBuffers2(commandBuffer: CommandBuffer,
+                                   firstBinding: u32,
+                                   bindingCount: u32,
+                                   pBuffers: *const Buffer,
+                                   pOffsets: *const DeviceSize,
+                                   pSizes: *const DeviceSize,
+                                   pStrides: *const DeviceSize);
+    pub fn vkCmdSetDepthTestEnable(commandBuffer: CommandBuffer,
+                                   depthTestEnable: Bool32);
+    pub fn vkCmdSetDepthWriteEnable(commandBuffer: CommandBuffer,
+                                    depthWriteEnable: Bool32);
+    pub fn vkCmdSetDepthCompareOp(commandBuffer: CommandBuffer,
+                                  depthCompareOp: CompareOp);
+    pub fn vkCmdSetDepthBoundsTestEnable(commandBuffer: CommandBuffer,
+                                         depthBoundsTestEnable: Bool32);
+    pub fn vkCmdSetStencilTestEnable(commandBuffer: CommandBuffer,
+                                     stencilTestEnable: Bool32);
+    pub fn vkCmdSetStencilOp(commandBuffer: CommandBuffer,
+                             faceMask: StencilFaceFlags,
+                             failOp: StencilOp,
+                             passOp: StencilOp,
+                             depthFailOp: StencilOp,
+                             compareOp: CompareOp);
+    pub fn vkCmdSetRasterizerDiscardEnable(commandBuffer: CommandBuffer,
+                                           rasterizerDiscardEnable: Bool32);
+    pub fn vkCmdSetDepthBiasEnable(commandBuffer: CommandBuffer,
+                                   depthBiasEnable: Bool32);
+    pub fn vkCmdSetPrimitiveRestartEnable(commandBuffer: CommandBuffer,
+                                          primitiveRestartEnable: Bool32);
+    pub fn vkGetDeviceBufferMemoryRequirements(device: Device,
+                                               pInfo: *const DeviceBufferMemoryRequirements,
+                                               pMemoryRequirements: *mut MemoryRequirements2);
+    pub fn vkGetDeviceImageMemoryRequirements(device: Device,
+                                              pInfo: *const DeviceImageMemoryRequirements,
+                                              pMemoryRequirements: *mut MemoryRequirements2);
+    pub fn vkGetDeviceImageSparseMemoryRequirements(device: Device,
+                                                    pInfo: *const DeviceImageMemoryRequirements,
+                                                    pSparseMemoryRequirementCount: *mut u32,
+                                                    pSparseMemoryRequirements: *mut SparseImageMemoryRequirements2);
+}
+
+/// `vkEnumeratePhysicalDevices`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkEnumeratePhysicalDevices`.
+pub unsafe fn enumerate_physical_devices(instance: Instance)
+        -> ::std::result::Result<Vec<PhysicalDevice>, Error> {
+    enumerate(|count, data| {
+        vkEnumeratePhysicalDevices(instance, count, data)
+    })
+}
+
+/// `vkGetPhysicalDeviceQueueFamilyProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceQueueFamilyProperties`.
+pub unsafe fn get_physical_device_queue_family_properties(physical_device: PhysicalDevice)
+        -> Vec<QueueFamilyProperties> {
+    enumerate(|count, data| {
+        vkGetPhysicalDeviceQueueFamilyProperties(physical_device, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
+}
+
+/// `vkEnumerateInstanceExtensionProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkEnumerateInstanceExtensionProperties`.
+pub unsafe fn enumerate_instance_extension_properties(layer_name: Option<&::std::ffi::CStr>)
+        -> ::std::result::Result<Vec<ExtensionProperties>, Error> {
+    let layer_name = layer_name.map_or(::std::ptr::null(), |s| s.as_ptr());
+    enumerate(|count, data| {
+        vkEnumerateInstanceExtensionProperties(layer_name, count, data)
+    })
+}
+
+/// `vkEnumerateDeviceExtensionProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkEnumerateDeviceExtensionProperties`.
+pub unsafe fn enumerate_device_extension_properties(physical_device: PhysicalDevice,
+                                                    layer_name: Option<&::std::ffi::CStr>)
+        -> ::std::result::Result<Vec<ExtensionProperties>, Error> {
+    let layer_name = layer_name.map_or(::std::ptr::null(), |s| s.as_ptr());
+    enumerate(|count, data| {
+        vkEnumerateDeviceExtensionProperties(physical_device, layer_name, count, data)
+    })
+}
+
+/// `vkEnumerateInstanceLayerProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkEnumerateInstanceLayerProperties`.
+pub unsafe fn enumerate_instance_layer_properties()
+        -> ::std::result::Result<Vec<LayerProperties>, Error> {
+    enumerate(|count, data| {
+        vkEnumerateInstanceLayerProperties(count, data)
+    })
+}
+
+/// `vkEnumerateDeviceLayerProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkEnumerateDeviceLayerProperties`.
+pub unsafe fn enumerate_device_layer_properties(physical_device: PhysicalDevice)
+        -> ::std::result::Result<Vec<LayerProperties>, Error> {
+    enumerate(|count, data| {
+        vkEnumerateDeviceLayerProperties(physical_device, count, data)
+    })
+}
+
+/// `vkGetImageSparseMemoryRequirements`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetImageSparseMemoryRequirements`.
+pub unsafe fn get_image_sparse_memory_requirements(device: Device, image: Image)
+        -> Vec<SparseImageMemoryRequirements> {
+    enumerate(|count, data| {
+        vkGetImageSparseMemoryRequirements(device, image, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
+}
+
+/// `vkGetPhysicalDeviceSparseImageFormatProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceSparseImageFormatProperties`.
+pub unsafe fn get_physical_device_sparse_image_format_properties(physical_device: PhysicalDevice,
+                                                                 format: Format, _type: ImageType,
+                                                                 samples: SampleCountFlag,
+                                                                 usage: ImageUsageFlags,
+                                                                 tiling: ImageTiling)
+        -> Vec<SparseImageFormatProperties> {
+    enumerate(|count, data| {
+        vkGetPhysicalDeviceSparseImageFormatProperties(physical_device, format, _type, samples,
+                                                       usage, tiling, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
+}
+
+/// `vkGetPipelineCacheData`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPipelineCacheData`.
+pub unsafe fn get_pipeline_cache_data(device: Device, pipeline_cache: PipelineCache)
+        -> ::std::result::Result<Vec<u8>, Error> {
+    enumerate(|count, data: *mut u8| {
+        vkGetPipelineCacheData(device, pipeline_cache, count, data as *mut ::std::os::raw::c_void)
+    })
+}
+
+/// `vkEnumeratePhysicalDeviceGroups`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkEnumeratePhysicalDeviceGroups`.
+pub unsafe fn enumerate_physical_device_groups(instance: Instance)
+        -> ::std::result::Result<Vec<PhysicalDeviceGroupProperties>, Error> {
+    enumerate_tagged(|count, data| {
+        vkEnumeratePhysicalDeviceGroups(instance, count, data)
+    })
+}
+
+/// `vkGetPhysicalDeviceQueueFamilyProperties2`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceQueueFamilyProperties2`.
+pub unsafe fn get_physical_device_queue_family_properties2(physical_device: PhysicalDevice)
+        -> Vec<QueueFamilyProperties2> {
+    enumerate_tagged(|count, data| {
+        vkGetPhysicalDeviceQueueFamilyProperties2(physical_device, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
+}
+
+/// `vkGetPhysicalDeviceSparseImageFormatProperties2`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceSparseImageFormatProperties2`.
+pub unsafe fn get_physical_device_sparse_image_format_properties2(physical_device: PhysicalDevice,
+                                                                  format_info: &PhysicalDeviceSparseImageFormatInfo2)
+        -> Vec<SparseImageFormatProperties2> {
+    enumerate_tagged(|count, data| {
+        vkGetPhysicalDeviceSparseImageFormatProperties2(physical_device, format_info, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
+}
+
+/// `vkGetImageSparseMemoryRequirements2`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetImageSparseMemoryRequirements2`.
+pub unsafe fn get_image_sparse_memory_requirements2(device: Device,
+                                                    info: &ImageSparseMemoryRequirementsInfo2)
+        -> Vec<SparseImageMemoryRequirements2> {
+    enumerate_tagged(|count, data| {
+        vkGetImageSparseMemoryRequirements2(device, info, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
+}
+
+/// `vkGetPhysicalDeviceToolProperties`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceToolProperties`.
+pub unsafe fn get_physical_device_tool_properties(physical_device: PhysicalDevice)
+        -> ::std::result::Result<Vec<PhysicalDeviceToolProperties>, Error> {
+    enumerate_tagged(|count, data| {
+        vkGetPhysicalDeviceToolProperties(physical_device, count, data)
+    })
+}
+
+/// `vkGetDeviceImageSparseMemoryRequirements`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetDeviceImageSparseMemoryRequirements`.
+pub unsafe fn get_device_image_sparse_memory_requirements(device: Device,
+                                                          info: &DeviceImageMemoryRequirements)
+        -> Vec<SparseImageMemoryRequirements2> {
+    enumerate_tagged(|count, data| {
+        vkGetDeviceImageSparseMemoryRequirements(device, info, count, data);
+        Result::SUCCESS
+    }).unwrap_or_default()
 }
@@ -3,14 +3,14 @@
 macro_rules! decl_enum {
     ($name:ident) => {
         #[repr(C)]
-        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+        #[derive(Copy, Clone, Eq, PartialEq)]
         pub struct $name(pub i32);
     }
 }
 
 #[macro_export]
 macro_rules! impl_enum {
-    ($name:ident; $($variant:ident = $value:expr,)*) => {
+    ($name:path; $($variant:ident = $value:expr,)*) => {
         #[allow(non_upper_case_globals)]
         impl $name {
             $(pub const $variant: $name = $name($value);)*
@@ -18,11 +18,72 @@ macro_rules! impl_enum {
     }
 }
 
+/// Values an extension adds to enums defined elsewhere. Besides the
+/// constants, this records their names in the module's `ENUM_NAMES`, which
+/// `khr::enum_names` and `ext::enum_names` collect for `make_enum!`.
+#[macro_export]
+macro_rules! ext_enums {
+    ($($name:path { $($variant:ident = $value:expr,)* })*) => {
+        $(impl_enum!{$name; $($variant = $value,)*})*
+
+        #[doc(hidden)]
+        pub const ENUM_NAMES: &'static [$crate::vk::ExtEnumNames] = &[
+            $($crate::vk::ExtEnumNames {
+                ty: ::std::any::TypeId::of::<$name>,
+                names: &[$((stringify!($variant), $value),)*],
+            },)*
+        ];
+    }
+}
+
 #[macro_export]
 macro_rules! make_enum {
     ($name:ident; $($variant:ident = $value:expr,)*) => {
         decl_enum!{$name}
         impl_enum!{$name; $($variant = $value,)*}
+
+        impl $name {
+            /// The name of this value, including values added by extensions.
+            pub fn name(self) -> Option<&'static str> {
+                let names: &[(&str, i32)] = &[$((stringify!($variant), $value),)*];
+                names.iter().find(|&&(_, value)| value == self.0).map(|&(name, _)| name)
+                    .or_else(|| $crate::vk::ext_enum_name(::std::any::TypeId::of::<$name>(), self.0))
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self.name() {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, concat!(stringify!($name), "({})"), self.0),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self.name() {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "{}", self.0),
+                }
+            }
+        }
+
+        /// Parses a name, as printed by `Display`, or a plain number, which
+        /// `Display` prints for values without a name. Any number parses,
+        /// named or not: `"-4"` is `Result::ERROR_DEVICE_LOST`.
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::vk::ParseEnumError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                let names: &[(&str, i32)] = &[$((stringify!($variant), $value),)*];
+                names.iter().find(|&&(name, _)| name == s).map(|&(_, value)| value)
+                    .or_else(|| $crate::vk::ext_enum_value(::std::any::TypeId::of::<$name>(), s))
+                    .or_else(|| s.parse().ok())
+                    .map($name)
+                    .ok_or_else(|| $crate::vk::ParseEnumError::new(stringify!($name), s))
+            }
+        }
     }
 }
 
@@ -35,13 +96,24 @@ macro_rules! enum_defl {
     }
 }
 
+/// `Default` for structs which can't derive it, such as those with pointers:
+/// all zeroes, so null pointers and `None` callbacks.
+#[macro_export]
+macro_rules! zero_defl {
+    ($name:ident) => {
+        impl ::std::default::Default for $name {
+            fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+        }
+    }
+}
+
 // C flags
 
 macro_rules! decl_flag {
-    ($name:ident) => {
+    ($name:ident, $repr:ty) => {
         #[repr(C)]
-        #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-        pub struct $name(pub u32);
+        #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+        pub struct $name(pub $repr);
 
         impl ::std::default::Default for $name {
             fn default() -> Self { $name(0) }
@@ -68,16 +140,142 @@ macro_rules! impl_bitwise {
                 $out(self.0 & other.0)
             }
         }
+
+        impl ::std::ops::BitXor<$rhs> for $lhs {
+            type Output = $out;
+
+            #[inline]
+            fn bitxor(self, other: $rhs) -> Self::Output {
+                $out(self.0 ^ other.0)
+            }
+        }
+
+        impl ::std::ops::Sub<$rhs> for $lhs {
+            type Output = $out;
+
+            #[inline]
+            fn sub(self, other: $rhs) -> Self::Output {
+                $out(self.0 & !other.0)
+            }
+        }
+    }
+}
+
+macro_rules! impl_bitwise_assign {
+    ($lhs:ident, $rhs:ident) => {
+        impl ::std::ops::BitOrAssign<$rhs> for $lhs {
+            #[inline]
+            fn bitor_assign(&mut self, other: $rhs) { self.0 |= other.0; }
+        }
+
+        impl ::std::ops::BitAndAssign<$rhs> for $lhs {
+            #[inline]
+            fn bitand_assign(&mut self, other: $rhs) { self.0 &= other.0; }
+        }
+
+        impl ::std::ops::BitXorAssign<$rhs> for $lhs {
+            #[inline]
+            fn bitxor_assign(&mut self, other: $rhs) { self.0 ^= other.0; }
+        }
+
+        impl ::std::ops::SubAssign<$rhs> for $lhs {
+            #[inline]
+            fn sub_assign(&mut self, other: $rhs) { self.0 &= !other.0; }
+        }
     }
 }
 
 #[macro_export]
 macro_rules! make_flag {
     ($flag:ident; $flags:ident; $($variant:ident = $value:expr,)*) => {
-        decl_flag!{$flag}
-        decl_flag!{$flags}
+        make_flag!{$flag; $flags: u32; $($variant = $value,)*}
+    };
+    ($flag:ident; $flags:ident: $repr:ty; $($variant:ident = $value:expr,)*) => {
+        decl_flag!{$flag, $repr}
+        decl_flag!{$flags, $repr}
         impl_enum!{$flag; $($variant = $value,)*}
 
+        impl $flags {
+            #[inline]
+            pub fn empty() -> $flags { $flags(0) }
+
+            /// Every bit with a name.
+            #[inline]
+            pub fn all() -> $flags { $flags(0 $(| $value)*) }
+
+            #[inline]
+            pub fn is_empty(self) -> bool { self.0 == 0 }
+
+            /// Whether every bit of `other` is set.
+            #[inline]
+            pub fn contains<T: Into<$flags>>(self, other: T) -> bool {
+                let other = other.into();
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether any bit of `other` is set.
+            #[inline]
+            pub fn intersects<T: Into<$flags>>(self, other: T) -> bool {
+                self.0 & other.into().0 != 0
+            }
+
+            #[inline]
+            pub fn insert<T: Into<$flags>>(&mut self, other: T) { self.0 |= other.into().0; }
+
+            #[inline]
+            pub fn remove<T: Into<$flags>>(&mut self, other: T) { self.0 &= !other.into().0; }
+
+            #[inline]
+            pub fn toggle<T: Into<$flags>>(&mut self, other: T) { self.0 ^= other.into().0; }
+
+            /// The named bits which are not set. `!` flips every bit,
+            /// unnamed ones included.
+            #[inline]
+            pub fn complement(self) -> $flags { $flags(!self.0 & $flags::all().0) }
+
+            /// The set bits, lowest first, one at a time.
+            pub fn iter(self) -> impl Iterator<Item = $flag> {
+                let bits = self.0;
+                (0..8 * ::std::mem::size_of::<$repr>() as u32)
+                    .map(|i| (1 as $repr) << i)
+                    .filter(move |bit| bits & bit != 0)
+                    .map($flag)
+            }
+        }
+
+        impl ::std::fmt::Debug for $flags {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let names: &[(&str, $repr)] = &[$((stringify!($variant), $value),)*];
+                if self.0 == 0 {
+                    let zero = names.iter().find(|&&(_, value)| value == 0);
+                    return f.write_str(zero.map_or("(empty)", |&(name, _)| name));
+                }
+                // Names are taken in declaration order, so aliases and
+                // combinations of bits already printed are skipped.
+                let mut rest = self.0;
+                let mut first = true;
+                for &(name, value) in names {
+                    if value != 0 && rest & value == value {
+                        if !first { f.write_str(" | ")?; }
+                        f.write_str(name)?;
+                        rest &= !value;
+                        first = false;
+                    }
+                }
+                if rest != 0 {
+                    if !first { f.write_str(" | ")?; }
+                    write!(f, "{:#x}", rest)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl ::std::fmt::Debug for $flag {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Debug::fmt(&$flags(self.0), f)
+            }
+        }
+
         impl ::std::convert::From<$flag> for $flags {
             fn from(other: $flag) -> Self {
                 $flags(other.0)
@@ -88,17 +286,429 @@ macro_rules! make_flag {
             fn into(self) -> bool { self.0 != 0 }
         }
 
+        impl ::std::ops::Not for $flag {
+            type Output = $flags;
+
+            #[inline]
+            fn not(self) -> $flags { !$flags(self.0) }
+        }
+
+        impl ::std::ops::Not for $flags {
+            type Output = $flags;
+
+            #[inline]
+            fn not(self) -> $flags { $flags(!self.0) }
+        }
+
         impl_bitwise!{$flag, $flag, $flags}
         impl_bitwise!{$flag, $flags, $flags}
         impl_bitwise!{$flags, $flag, $flags}
         impl_bitwise!{$flags, $flags, $flags}
+        impl_bitwise_assign!{$flags, $flag}
+        impl_bitwise_assign!{$flags, $flags}
     }
 }
 
 #[macro_export]
 macro_rules! opaque {
-    ($name_t: ident, $name:ident) => {
+    ($name_t: ident, $name:ident, $object_type:ident) => {
         pub enum $name_t { }
         pub type $name = *mut $name_t;
+
+        impl $crate::vk::Handle for $name {
+            const OBJECT_TYPE: $crate::vk::ObjectType = $crate::vk::ObjectType::$object_type;
+
+            #[inline]
+            fn as_raw(self) -> u64 { self as usize as u64 }
+
+            #[inline]
+            fn from_raw(raw: u64) -> Self { raw as usize as $name }
+        }
+    }
+}
+
+/// Non-dispatchable handles, which are 64 bits wide on every target.
+#[macro_export]
+macro_rules! handle {
+    ($name:ident, $object_type:ident) => {
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name(pub u64);
+
+        impl $name {
+            /// `VK_NULL_HANDLE`.
+            #[inline]
+            pub const fn null() -> $name { $name(0) }
+
+            #[inline]
+            pub fn is_null(self) -> bool { self.0 == 0 }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, concat!(stringify!($name), "({:#x})"), self.0)
+            }
+        }
+
+        impl $crate::vk::Handle for $name {
+            const OBJECT_TYPE: $crate::vk::ObjectType = $crate::vk::ObjectType::$object_type;
+
+            #[inline]
+            fn as_raw(self) -> u64 { self.0 }
+
+            #[inline]
+            fn from_raw(raw: u64) -> Self { $name(raw) }
+        }
+    }
+}
+
+// pNext chains
+
+/// Declares `$extends`, the trait of structs which may be chained onto
+/// `$name`, and `$name::push_next`.
+#[macro_export]
+macro_rules! extensible {
+    ($name:ident, $extends:ident) => {
+        /// Structs the spec allows in this struct's `pNext` chain.
+        ///
+        /// # Safety
+        ///
+        /// Only for structs listing this one in `structextends`.
+        pub unsafe trait $extends: $crate::vk::TaggedStructure { }
+
+        impl $name {
+            /// Links `next`, along with anything already chained onto it, in
+            /// right after this struct.
+            ///
+            /// # Safety
+            ///
+            /// Only a pointer to `next` is kept, so `next` and its chain must
+            /// outlive every use of this struct. The builder's `push_next`
+            /// checks this by borrowing `next` for the builder's lifetime.
+            ///
+            /// # Panics
+            ///
+            /// If `next`'s chain is already part of this struct's, which would
+            /// make it a cycle.
+            pub unsafe fn push_next<T: $extends>(&mut self, next: &mut T) -> &mut Self {
+                $crate::vk::push_next(self, next);
+                self
+            }
+        }
+    }
+}
+
+/// Implements `TaggedStructure` for `$name`, and the traits of the structs
+/// it may be chained onto. `Default` is zeroed like `zero_defl!`, but with
+/// `sType` filled in.
+#[macro_export]
+macro_rules! tagged {
+    ($name:ident, $stype:ident $(; $($extends:path),*)*) => {
+        impl $name {
+            pub const STRUCTURE_TYPE: $crate::vk::StructureType = $crate::vk::StructureType::$stype;
+        }
+
+        unsafe impl $crate::vk::TaggedStructure for $name {
+            const STRUCTURE_TYPE: $crate::vk::StructureType = $name::STRUCTURE_TYPE;
+        }
+
+        impl ::std::default::Default for $name {
+            fn default() -> Self {
+                let mut s: $name = unsafe { ::std::mem::zeroed() };
+                s.sType = $name::STRUCTURE_TYPE;
+                s
+            }
+        }
+
+        $($(unsafe impl $extends for $name { })*)*
+    }
+}
+
+// Strings
+
+/// Accessors for the strings in `$name`'s fixed-size `char` arrays: `$method`
+/// up to the NUL terminator, `None` if there is none, and `$lossy` as UTF-8,
+/// up to the NUL or the end of the array.
+#[macro_export]
+macro_rules! c_strings {
+    ($name:ident { $($field:ident => ($method:ident, $lossy:ident)),* }) => {
+        impl $name {
+            $(
+                pub fn $method(&self) -> ::std::option::Option<&::std::ffi::CStr> {
+                    $crate::vk::c_str(&self.$field)
+                }
+
+                pub fn $lossy<'a>(&'a self) -> ::std::borrow::Cow<'a, str> {
+                    $crate::vk::c_str_lossy(&self.$field)
+                }
+            )*
+        }
+    }
+}
+
+// Unions
+
+/// `Default` and `Debug` for a union. `Debug` prints `$bits`, the whole
+/// union, as raw hex words, since which member is live isn't recorded.
+/// `Default` and the generated constructors zero every byte first.
+#[macro_export]
+macro_rules! union_bits {
+    ($name:ident, $bits:ty) => {
+        zero_defl!{$name}
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let bits: $bits = unsafe {
+                    ::std::ptr::read(self as *const $name as *const $bits)
+                };
+                f.write_str(concat!(stringify!($name), " { bits: ["))?;
+                for (i, word) in bits.iter().enumerate() {
+                    write!(f, "{}{:#x}", if i == 0 { "" } else { ", " }, word)?;
+                }
+                f.write_str("] }")
+            }
+        }
+    }
+}
+
+// Builders
+
+/// Declares `$builder` and `$name::builder`. `$builder` fills in a `$name`
+/// from data borrowed for `'a`, and dereferences to it, so `&*builder` can
+/// be passed to commands while the borrows are still alive.
+#[macro_export]
+macro_rules! builder {
+    ($name:ident, $builder:ident) => {
+        pub struct $builder<'a> {
+            inner: $name,
+            marker: ::std::marker::PhantomData<&'a ()>,
+        }
+
+        impl $name {
+            pub fn builder<'a>() -> $builder<'a> {
+                $builder { inner: $name::default(), marker: ::std::marker::PhantomData }
+            }
+        }
+
+        impl<'a> $builder<'a> {
+            /// The raw struct, whose pointers are no longer tied to `'a`.
+            #[inline]
+            pub fn build(self) -> $name { self.inner }
+        }
+
+        impl<'a> ::std::ops::Deref for $builder<'a> {
+            type Target = $name;
+
+            #[inline]
+            fn deref(&self) -> &$name { &self.inner }
+        }
+    }
+}
+
+// Function pointer tables
+
+/// Declares `$name`, a table of optional entry points resolved by
+/// `load_with`, with a method of the same name calling each, which panics
+/// if that entry point was not loaded.
+#[macro_export]
+macro_rules! fn_table {
+    ($(#[$attr:meta])* pub struct $name:ident {
+        $(fn $cmd:ident: $pfn:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)*;)*
+    }) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone)]
+        pub struct $name {
+            $(pub $cmd: $pfn,)*
+        }
+
+        impl $name {
+            /// Resolves every entry point through `f`. Entry points that `f`
+            /// cannot resolve are left as `None`; see `missing()`.
+            ///
+            /// # Safety
+            ///
+            /// `f` must return null or the entry point it was asked for, as
+            /// `vkGetInstanceProcAddr` does.
+            pub unsafe fn load_with<F>(mut f: F) -> Self
+                where F: FnMut(&::std::ffi::CStr) -> $crate::vk::PFN_vkVoidFunction
+            {
+                $name {
+                    $($cmd: ::std::mem::transmute::<$crate::vk::PFN_vkVoidFunction, $pfn>(
+                        f(::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                            concat!(stringify!($cmd), "\0").as_bytes()))),)*
+                }
+            }
+
+            /// Names of the entry points which could not be resolved.
+            pub fn missing(&self) -> Vec<&'static str> {
+                #[allow(unused_mut)]
+                let mut missing = Vec::new();
+                $(if self.$cmd.is_none() { missing.push(stringify!($cmd)); })*
+                missing
+            }
+
+            $(
+                /// Calls the entry point of the same name.
+                ///
+                /// # Safety
+                ///
+                /// The arguments must meet the command's valid usage rules.
+                ///
+                /// # Panics
+                ///
+                /// If the entry point was not loaded; `missing()` lists those.
+                #[inline]
+                #[allow(clippy::too_many_arguments)]
+                pub unsafe fn $cmd(&self, $($arg: $ty),*) $(-> $ret)* {
+                    match self.$cmd {
+                        Some(f) => f($($arg),*),
+                        None => panic!(concat!(stringify!($cmd), " was not loaded")),
+                    }
+                }
+            )*
+        }
     }
 }
+
+// Owned handles
+
+/// Declares an owned `$name` in `vk::owned`, which holds on to its device
+/// and destroys the raw `vk::$name` with `$destroy` from the device's
+/// `DeviceFns` when dropped. With a `$ctor: $create($info)` clause,
+/// `$name::$ctor` makes one with `$create`.
+///
+/// Objects of later core versions name their table, as in
+/// `SamplerYcbcrConversion: DeviceFnsV1_1`, and keep an `Arc` of it too.
+#[cfg(feature = "owned")]
+macro_rules! owned {
+    ($name:ident, $ctor:ident: $create:ident($info:ident), $destroy:ident) => {
+        owned!{$name, $destroy}
+
+        impl $name {
+            /// Creates one with `$create`.
+            ///
+            /// # Safety
+            ///
+            /// `info` must be valid for `$create`.
+            pub unsafe fn $ctor(device: &::std::sync::Arc<Device>, info: &$crate::vk::$info)
+                -> ::std::result::Result<$name, $crate::vk::Error>
+            {
+                let mut handle = $crate::vk::$name::null();
+                device.fns().$create(device.handle(), info, ::std::ptr::null(), &mut handle)
+                    .result()?;
+                Ok($name::from_raw(device.clone(), handle))
+            }
+        }
+    };
+    ($name:ident, $destroy:ident) => {
+        #[derive(Debug)]
+        pub struct $name {
+            handle: $crate::vk::$name,
+            device: ::std::sync::Arc<Device>,
+        }
+
+        impl $name {
+            /// Takes ownership of `handle`.
+            ///
+            /// # Safety
+            ///
+            /// `handle` must have been made from `device` and not be
+            /// destroyed by anything else.
+            pub unsafe fn from_raw(device: ::std::sync::Arc<Device>, handle: $crate::vk::$name)
+                -> $name
+            {
+                $name { handle, device }
+            }
+
+            /// Gives up ownership of the handle without destroying it.
+            pub fn into_raw(self) -> $crate::vk::$name {
+                let this = ::std::mem::ManuallyDrop::new(self);
+                drop(unsafe { ::std::ptr::read(&this.device) });
+                this.handle
+            }
+
+            #[inline]
+            pub fn handle(&self) -> $crate::vk::$name { self.handle }
+
+            #[inline]
+            pub fn device(&self) -> &::std::sync::Arc<Device> { &self.device }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe {
+                    self.device.fns().$destroy(self.device.handle(), self.handle,
+                                               ::std::ptr::null())
+                }
+            }
+        }
+    };
+    ($name:ident: $fns:ident, $ctor:ident: $create:ident($info:ident), $destroy:ident) => {
+        pub struct $name {
+            handle: $crate::vk::$name,
+            device: ::std::sync::Arc<Device>,
+            fns: ::std::sync::Arc<$crate::vk::$fns>,
+        }
+
+        impl $name {
+            /// Creates one with `$create` from `fns`.
+            ///
+            /// # Safety
+            ///
+            /// `fns` must have been loaded for `device`, and `info` must be
+            /// valid for `$create`.
+            pub unsafe fn $ctor(device: &::std::sync::Arc<Device>,
+                                fns: &::std::sync::Arc<$crate::vk::$fns>,
+                                info: &$crate::vk::$info)
+                -> ::std::result::Result<$name, $crate::vk::Error>
+            {
+                let mut handle = $crate::vk::$name::null();
+                fns.$create(device.handle(), info, ::std::ptr::null(), &mut handle).result()?;
+                Ok($name::from_raw(device.clone(), fns.clone(), handle))
+            }
+
+            /// Takes ownership of `handle`.
+            ///
+            /// # Safety
+            ///
+            /// `handle` must have been made from `device` and not be
+            /// destroyed by anything else. `fns` must have been loaded for
+            /// `device`.
+            pub unsafe fn from_raw(device: ::std::sync::Arc<Device>,
+                                   fns: ::std::sync::Arc<$crate::vk::$fns>,
+                                   handle: $crate::vk::$name) -> $name
+            {
+                $name { handle, device, fns }
+            }
+
+            /// Gives up ownership of the handle without destroying it.
+            pub fn into_raw(self) -> $crate::vk::$name {
+                let this = ::std::mem::ManuallyDrop::new(self);
+                drop(unsafe { ::std::ptr::read(&this.device) });
+                drop(unsafe { ::std::ptr::read(&this.fns) });
+                this.handle
+            }
+
+            #[inline]
+            pub fn handle(&self) -> $crate::vk::$name { self.handle }
+
+            #[inline]
+            pub fn device(&self) -> &::std::sync::Arc<Device> { &self.device }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("handle", &self.handle)
+                    .field("device", &self.device)
+                    .finish()
+            }
+        }
+
+        impl Drop for $name {
+            fn drop(&mut self) {
+                unsafe { self.fns.$destroy(self.device.handle(), self.handle, ::std::ptr::null()) }
+            }
+        }
+    };
+}
@@ -3,9 +3,15 @@ use vk::*;
 pub const SPEC_VERSION: u32 = 21;
 pub const EXTENSION_NAME: &'static str = "VK_KHR_display";
 
-impl_enum!{StructureType;
-    DISPLAY_MODE_CREATE_INFO = 1000002000,
-    DISPLAY_SURFACE_CREATE_INFO = 1000002001,
+ext_enums!{
+    StructureType {
+        DISPLAY_MODE_CREATE_INFO = 1000002000,
+        DISPLAY_SURFACE_CREATE_INFO = 1000002001,
+    }
+    ObjectType {
+        DISPLAY = 1000002000,
+        DISPLAY_MODE = 1000002001,
+    }
 }
 make_flag!{PlaneAlphaFlag; PlaneAlphaFlags;
     OPAQUE = 0x00000001,
@@ -16,10 +22,11 @@ make_flag!{PlaneAlphaFlag; PlaneAlphaFlags;
 make_flag!{ModeCreateFlag; ModeCreateFlags; }
 make_flag!{CreateFlag; CreateFlags; }
 
-pub enum Display { }
-pub enum Mode { }
+handle!{Display, DISPLAY}
+handle!{Mode, DISPLAY_MODE}
 
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub struct Properties {
     pub display: Display,
     pub displayName: *const ::std::os::raw::c_char,
@@ -29,17 +36,21 @@ pub struct Properties {
     pub planeReorderPossible: Bool32,
     pub persistentContent: Bool32,
 }
+zero_defl!{Properties}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ModeParameters {
     pub visibleRegion: Extent2D,
     pub refreshRate: u32,
 }
+zero_defl!{ModeParameters}
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub struct ModeProperties {
     pub displayMode: Mode,
     pub parameters: ModeParameters,
 }
+zero_defl!{ModeProperties}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ModeCreateInfo {
@@ -48,6 +59,7 @@ pub struct ModeCreateInfo {
     pub flags: ModeCreateFlags,
     pub parameters: ModeParameters,
 }
+tagged!{ModeCreateInfo, DISPLAY_MODE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PlaneCapabilities {
@@ -61,12 +73,16 @@ pub struct PlaneCapabilities {
     pub minDstExtent: Extent2D,
     pub maxDstExtent: Extent2D,
 }
+zero_defl!{PlaneCapabilities}
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub struct PlaneProperties {
     pub currentDisplay: Display,
     pub currentStackIndex: u32,
 }
+zero_defl!{PlaneProperties}
 #[repr(C)]
+#[derive(Copy, Clone)]
 pub struct SurfaceCreateInfo {
     pub sType: StructureType,
     pub pNext: *const ::std::os::raw::c_void,
@@ -79,7 +95,9 @@ pub struct SurfaceCreateInfo {
     pub alphaMode: PlaneAlphaFlag,
     pub imageExtent: Extent2D,
 }
+tagged!{SurfaceCreateInfo, DISPLAY_SURFACE_CREATE_INFO}
 
+#[cfg(feature = "static_link")]
 #[link(name = "vulkan")]
 extern "C" {
     pub fn vkGetPhysicalDeviceDisplayPropertiesKHR(physicalDevice: PhysicalDevice,
@@ -111,6 +129,59 @@ extern "C" {
                                           pSurface: *mut khr::surface::Surface) -> Result;
 }
 
+/// `vkGetPhysicalDeviceDisplayPropertiesKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceDisplayPropertiesKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_physical_device_display_properties(physical_device: PhysicalDevice)
+        -> ::std::result::Result<Vec<Properties>, Error> {
+    enumerate(|count, data| {
+        vkGetPhysicalDeviceDisplayPropertiesKHR(physical_device, count, data)
+    })
+}
+
+/// `vkGetPhysicalDeviceDisplayPlanePropertiesKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceDisplayPlanePropertiesKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_physical_device_display_plane_properties(physical_device: PhysicalDevice)
+        -> ::std::result::Result<Vec<PlaneProperties>, Error> {
+    enumerate(|count, data| {
+        vkGetPhysicalDeviceDisplayPlanePropertiesKHR(physical_device, count, data)
+    })
+}
+
+/// `vkGetDisplayPlaneSupportedDisplaysKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetDisplayPlaneSupportedDisplaysKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_display_plane_supported_displays(physical_device: PhysicalDevice,
+                                                   plane_index: u32)
+        -> ::std::result::Result<Vec<Display>, Error> {
+    enumerate(|count, data| {
+        vkGetDisplayPlaneSupportedDisplaysKHR(physical_device, plane_index, count, data)
+    })
+}
+
+/// `vkGetDisplayModePropertiesKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetDisplayModePropertiesKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_display_mode_properties(physical_device: PhysicalDevice, display: Display)
+        -> ::std::result::Result<Vec<ModeProperties>, Error> {
+    enumerate(|count, data| {
+        vkGetDisplayModePropertiesKHR(physical_device, display, count, data)
+    })
+}
+
 pub type PFN_vkGetPhysicalDeviceDisplayPropertiesKHR =
     ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
                                                pPropertyCount: *mut u32,
@@ -152,3 +223,169 @@ pub type PFN_vkCreateDisplayPlaneSurfaceKHR =
                                                pAllocator: *const AllocationCallbacks,
                                                pSurface: *mut khr::surface::Surface)
                               -> Result>;
+
+fn_table!{
+    pub struct Fns {
+        fn vkGetPhysicalDeviceDisplayPropertiesKHR: PFN_vkGetPhysicalDeviceDisplayPropertiesKHR(
+            physicalDevice: PhysicalDevice,
+            pPropertyCount: *mut u32,
+            pProperties: *mut Properties) -> Result;
+        fn vkGetPhysicalDeviceDisplayPlanePropertiesKHR: PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR(
+            physicalDevice: PhysicalDevice,
+            pPropertyCount: *mut u32,
+            pProperties: *mut PlaneProperties) -> Result;
+        fn vkGetDisplayPlaneSupportedDisplaysKHR: PFN_vkGetDisplayPlaneSupportedDisplaysKHR(
+            physicalDevice: PhysicalDevice,
+            planeIndex: u32,
+            pDisplayCount: *mut u32,
+            pDisplays: *mut Display) -> Result;
+        fn vkGetDisplayModePropertiesKHR: PFN_vkGetDisplayModePropertiesKHR(
+            physicalDevice: PhysicalDevice,
+            display: Display,
+            pPropertyCount: *mut u32,
+            pProperties: *mut ModeProperties) -> Result;
+        fn vkCreateDisplayModeKHR: PFN_vkCreateDisplayModeKHR(
+            physicalDevice: PhysicalDevice,
+            display: Display,
+            pCreateInfo: *const ModeCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pMode: *mut Mode) -> Result;
+        fn vkGetDisplayPlaneCapabilitiesKHR: PFN_vkGetDisplayPlaneCapabilitiesKHR(
+            physicalDevice: PhysicalDevice,
+            mode: Mode,
+            planeIndex: u32,
+            pCapabilities: *mut PlaneCapabilities) -> Result;
+        fn vkCreateDisplayPlaneSurfaceKHR: PFN_vkCreateDisplayPlaneSurfaceKHR(
+            instance: Instance,
+            pCreateInfo: *const SurfaceCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSurface: *mut khr::surface::Surface) -> Result;
+    }
+}
+
+impl Fns {
+    /// Resolves the commands of this extension for `instance`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry` with `create_info`.
+    pub unsafe fn load(entry: &Entry, instance: Instance, create_info: &InstanceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl Fns {
+    /// `vkGetPhysicalDeviceDisplayPropertiesKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceDisplayPropertiesKHR`.
+    pub unsafe fn get_physical_device_display_properties(&self, physical_device: PhysicalDevice)
+            -> ::std::result::Result<Vec<Properties>, Error> {
+        enumerate(|count, data| {
+            self.vkGetPhysicalDeviceDisplayPropertiesKHR(physical_device, count, data)
+        })
+    }
+
+    /// `vkGetPhysicalDeviceDisplayPlanePropertiesKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceDisplayPlanePropertiesKHR`.
+    pub unsafe fn get_physical_device_display_plane_properties(&self,
+                                                               physical_device: PhysicalDevice)
+            -> ::std::result::Result<Vec<PlaneProperties>, Error> {
+        enumerate(|count, data| {
+            self.vkGetPhysicalDeviceDisplayPlanePropertiesKHR(physical_device, count, data)
+        })
+    }
+
+    /// `vkGetDisplayPlaneSupportedDisplaysKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetDisplayPlaneSupportedDisplaysKHR`.
+    pub unsafe fn get_display_plane_supported_displays(&self, physical_device: PhysicalDevice,
+                                                       plane_index: u32)
+            -> ::std::result::Result<Vec<Display>, Error> {
+        enumerate(|count, data| {
+            self.vkGetDisplayPlaneSupportedDisplaysKHR(physical_device, plane_index, count, data)
+        })
+    }
+
+    /// `vkGetDisplayModePropertiesKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetDisplayModePropertiesKHR`.
+    pub unsafe fn get_display_mode_properties(&self, physical_device: PhysicalDevice,
+                                              display: Display)
+            -> ::std::result::Result<Vec<ModeProperties>, Error> {
+        enumerate(|count, data| {
+            self.vkGetDisplayModePropertiesKHR(physical_device, display, count, data)
+        })
+    }
+}
+
+builder!{ModeCreateInfo, ModeCreateInfoBuilder}
+impl<'a> ModeCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: ModeCreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn parameters(mut self, parameters: ModeParameters) -> Self {
+        self.inner.parameters = parameters;
+        self
+    }
+}
+builder!{SurfaceCreateInfo, SurfaceCreateInfoBuilder}
+impl<'a> SurfaceCreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: CreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn display_mode(mut self, display_mode: Mode) -> Self {
+        self.inner.displayMode = display_mode;
+        self
+    }
+    #[inline]
+    pub fn plane_index(mut self, plane_index: u32) -> Self {
+        self.inner.planeIndex = plane_index;
+        self
+    }
+    #[inline]
+    pub fn plane_stack_index(mut self, plane_stack_index: u32) -> Self {
+        self.inner.planeStackIndex = plane_stack_index;
+        self
+    }
+    #[inline]
+    pub fn transform(mut self, transform: khr::surface::TransformFlag) -> Self {
+        self.inner.transform = transform;
+        self
+    }
+    #[inline]
+    pub fn global_alpha(mut self, global_alpha: ::std::os::raw::c_float) -> Self {
+        self.inner.globalAlpha = global_alpha;
+        self
+    }
+    #[inline]
+    pub fn alpha_mode(mut self, alpha_mode: PlaneAlphaFlag) -> Self {
+        self.inner.alphaMode = alpha_mode;
+        self
+    }
+    #[inline]
+    pub fn image_extent(mut self, image_extent: Extent2D) -> Self {
+        self.inner.imageExtent = image_extent;
+        self
+    }
+}
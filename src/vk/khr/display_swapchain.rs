@@ -1,15 +1,15 @@
 use vk::*;
-use libc::*;
 
 pub const SPEC_VERSION: u32 = 9;
 pub const EXTENSION_NAME: &'static str = "VK_KHR_display_swapchain";
 
-impl_enum!{Result;
-    ERROR_INCOMPATIBLE_DISPLAY = -1000003001,
-}
-
-impl_enum!{StructureType;
-    DISPLAY_PRESENT_INFO = 1000003000,
+ext_enums!{
+    Result {
+        ERROR_INCOMPATIBLE_DISPLAY = -1000003001,
+    }
+    StructureType {
+        DISPLAY_PRESENT_INFO = 1000003000,
+    }
 }
 
 #[repr(C)]
@@ -21,6 +21,7 @@ pub struct DisplayPresentInfo {
     pub dstRect: Rect2D,
     pub persistent: Bool32,
 }
+tagged!{DisplayPresentInfo, DISPLAY_PRESENT_INFO; khr::swapchain::ExtendsPresentInfo}
 
 pub type PFN_vkCreateSharedSwapchainsKHR =
     ::std::option::Option<unsafe extern "C" fn(device: Device,
@@ -30,11 +31,60 @@ pub type PFN_vkCreateSharedSwapchainsKHR =
                                                pSwapchains: *mut khr::swapchain::Swapchain)
                               -> Result>;
 
+fn_table!{
+    pub struct Fns {
+        fn vkCreateSharedSwapchainsKHR: PFN_vkCreateSharedSwapchainsKHR(
+            device: Device,
+            swapchainCount: u32,
+            pCreateInfos: *const khr::swapchain::CreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSwapchains: *mut khr::swapchain::Swapchain) -> Result;
+    }
+}
+
+impl Fns {
+    /// Resolves the commands of this extension for `device`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device created with `create_info`, on the instance
+    /// `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device, create_info: &DeviceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+#[cfg(feature = "static_link")]
 #[link(name = "vulkan")]
 extern "C" {
-    pub fn vkCreateSharedSwapchains(device: Device,
-                                    swapchainCount: uint32_t,
-                                    pCreateInfos: *const khr::swapchain::CreateInfo,
-                                    pAllocator: *const AllocationCallbacks,
-                                    pSwapchains: *mut khr::swapchain::Swapchain) -> Result;
+    pub fn vkCreateSharedSwapchainsKHR(device: Device,
+                                       swapchainCount: u32,
+                                       pCreateInfos: *const khr::swapchain::CreateInfo,
+                                       pAllocator: *const AllocationCallbacks,
+                                       pSwapchains: *mut khr::swapchain::Swapchain) -> Result;
+}
+
+builder!{DisplayPresentInfo, DisplayPresentInfoBuilder}
+impl<'a> DisplayPresentInfoBuilder<'a> {
+    #[inline]
+    pub fn src_rect(mut self, src_rect: Rect2D) -> Self {
+        self.inner.srcRect = src_rect;
+        self
+    }
+    #[inline]
+    pub fn dst_rect(mut self, dst_rect: Rect2D) -> Self {
+        self.inner.dstRect = dst_rect;
+        self
+    }
+    #[inline]
+    pub fn persistent(mut self, persistent: Bool32) -> Self {
+        self.inner.persistent = persistent;
+        self
+    }
 }
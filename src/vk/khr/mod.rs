@@ -10,3 +10,28 @@ pub mod wayland_surface;
 pub mod win32_surface;
 pub mod xcb_surface;
 pub mod xlib_surface;
+
+/// Collects the `ENUM_NAMES` of the modules above.
+#[doc(hidden)]
+pub fn enum_names(tables: &mut Vec<&'static [::vk::ExtEnumNames]>) {
+    #[cfg(target_os = "android")]
+    tables.push(android_surface::ENUM_NAMES);
+    tables.push(display::ENUM_NAMES);
+    tables.push(display_swapchain::ENUM_NAMES);
+    #[cfg(target_os = "linux")]
+    #[cfg(feature = "mir_surface")]
+    tables.push(mir_surface::ENUM_NAMES);
+    tables.push(surface::ENUM_NAMES);
+    tables.push(swapchain::ENUM_NAMES);
+    #[cfg(target_os = "linux")]
+    #[cfg(feature = "wayland_surface")]
+    tables.push(wayland_surface::ENUM_NAMES);
+    #[cfg(target_os = "windows")]
+    tables.push(win32_surface::ENUM_NAMES);
+    #[cfg(target_os = "linux")]
+    #[cfg(feature = "xcb_surface")]
+    tables.push(xcb_surface::ENUM_NAMES);
+    #[cfg(target_os = "linux")]
+    #[cfg(feature = "xlib_surface")]
+    tables.push(xlib_surface::ENUM_NAMES);
+}
@@ -1,12 +1,16 @@
 use vk::*;
-use libc::*;
 
 pub const SPEC_VERSION: u32 = 25;
 pub const EXTENSION_NAME: &'static str = "VK_KHR_surface";
 
-impl_enum!{Result;
-    ERROR_SURFACE_LOST = -1000000000,
-    ERROR_NATIVE_WINDOW_IN_USE = -1000000001,
+ext_enums!{
+    Result {
+        ERROR_SURFACE_LOST = -1000000000,
+        ERROR_NATIVE_WINDOW_IN_USE = -1000000001,
+    }
+    ObjectType {
+        SURFACE = 1000000000,
+    }
 }
 make_flag!{TransformFlag; TransformFlags;
     IDENTITY = 0x00000001,
@@ -35,7 +39,7 @@ make_enum!{PresentMode;
     FIFO_RELAXED = 3,
 }
 
-opaque!{_Surface, Surface}
+handle!{Surface, SURFACE}
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Default)]
@@ -57,52 +61,154 @@ pub struct Format {
     pub format: ::vk::Format,
     pub colorSpace: ColorSpace,
 }
+zero_defl!{Format}
 
-pub type PFN_vkDestroySurface =
+pub type PFN_vkDestroySurfaceKHR =
     ::std::option::Option<unsafe extern "C" fn(instance: Instance,
                                                surface: Surface,
                                                pAllocator: *const AllocationCallbacks)>;
-pub type PFN_vkGetPhysicalDeviceSurfaceSupport =
+pub type PFN_vkGetPhysicalDeviceSurfaceSupportKHR =
     ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
                                                queueFamilyIndex: u32,
                                                surface: Surface,
                                                pSupported: *mut Bool32)
                               -> Result>;
-pub type PFN_vkGetPhysicalDeviceSurfaceCapabilities =
+pub type PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR =
     ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
                                                surface: Surface,
                                                pSurfaceCapabilities: *mut khr::surface::Capabilities)
                               -> Result>;
-pub type PFN_vkGetPhysicalDeviceSurfaceFormats =
+pub type PFN_vkGetPhysicalDeviceSurfaceFormatsKHR =
     ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
                                                surface: Surface,
                                                pSurfaceFormatCount: *mut u32,
                                                pSurfaceFormats: *mut khr::surface::Format)
                               -> Result>;
-pub type PFN_vkGetPhysicalDeviceSurfacePresentModes =
+pub type PFN_vkGetPhysicalDeviceSurfacePresentModesKHR =
     ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
                                                surface: Surface,
                                                pPresentModeCount: *mut u32,
                                                pPresentModes: *mut khr::surface::PresentMode)
                               -> Result>;
 
+fn_table!{
+    pub struct Fns {
+        fn vkDestroySurfaceKHR: PFN_vkDestroySurfaceKHR(
+            instance: Instance,
+            surface: Surface,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetPhysicalDeviceSurfaceSupportKHR: PFN_vkGetPhysicalDeviceSurfaceSupportKHR(
+            physicalDevice: PhysicalDevice,
+            queueFamilyIndex: u32,
+            surface: Surface,
+            pSupported: *mut Bool32) -> Result;
+        fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR: PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR(
+            physicalDevice: PhysicalDevice,
+            surface: Surface,
+            pSurfaceCapabilities: *mut Capabilities) -> Result;
+        fn vkGetPhysicalDeviceSurfaceFormatsKHR: PFN_vkGetPhysicalDeviceSurfaceFormatsKHR(
+            physicalDevice: PhysicalDevice,
+            surface: Surface,
+            pSurfaceFormatCount: *mut u32,
+            pSurfaceFormats: *mut Format) -> Result;
+        fn vkGetPhysicalDeviceSurfacePresentModesKHR: PFN_vkGetPhysicalDeviceSurfacePresentModesKHR(
+            physicalDevice: PhysicalDevice,
+            surface: Surface,
+            pPresentModeCount: *mut u32,
+            pPresentModes: *mut PresentMode) -> Result;
+    }
+}
+
+impl Fns {
+    /// Resolves the commands of this extension for `instance`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry` with `create_info`.
+    pub unsafe fn load(entry: &Entry, instance: Instance, create_info: &InstanceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl Fns {
+    /// `vkGetPhysicalDeviceSurfaceFormatsKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceSurfaceFormatsKHR`.
+    pub unsafe fn get_physical_device_surface_formats(&self, physical_device: PhysicalDevice,
+                                                      surface: Surface)
+            -> ::std::result::Result<Vec<Format>, Error> {
+        enumerate(|count, data| {
+            self.vkGetPhysicalDeviceSurfaceFormatsKHR(physical_device, surface, count, data)
+        })
+    }
+
+    /// `vkGetPhysicalDeviceSurfacePresentModesKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetPhysicalDeviceSurfacePresentModesKHR`.
+    pub unsafe fn get_physical_device_surface_present_modes(&self, physical_device: PhysicalDevice,
+                                                            surface: Surface)
+            -> ::std::result::Result<Vec<PresentMode>, Error> {
+        enumerate(|count, data| {
+            self.vkGetPhysicalDeviceSurfacePresentModesKHR(physical_device, surface, count, data)
+        })
+    }
+}
+
+#[cfg(feature = "static_link")]
 #[link(name = "vulkan")]
 extern "C" {
-    pub fn vkDestroySurface(instance: Instance, surface: khr::Surface,
-                            pAllocator: *const AllocationCallbacks);
-    pub fn vkGetPhysicalDeviceSurfaceSupport(physicalDevice: PhysicalDevice,
-                                             queueFamilyIndex: uint32_t,
-                                             surface: khr::Surface,
-                                             pSupported: *mut Bool32) -> Result;
-    pub fn vkGetPhysicalDeviceSurfaceCapabilities(physicalDevice: PhysicalDevice,
-                                                  surface: khr::surface::Surface,
-                                                  pSurfaceCapabilities: *mut khr::surface::Capabilities) -> Result;
-    pub fn vkGetPhysicalDeviceSurfaceFormats(physicalDevice: PhysicalDevice,
-                                             surface: khr::surface::Surface,
-                                             pSurfaceFormatCount: *mut uint32_t,
-                                             pSurfaceFormats: *mut khr::surface::Format) -> Result;
-    pub fn vkGetPhysicalDeviceSurfacePresentModes(physicalDevice: PhysicalDevice,
-                                                  surface: khr::Surface,
-                                                  pPresentModeCount: *mut uint32_t,
-                                                  pPresentModes: *mut khr::surface::PresentMode) -> Result;
+    pub fn vkDestroySurfaceKHR(instance: Instance, surface: khr::Surface,
+                               pAllocator: *const AllocationCallbacks);
+    pub fn vkGetPhysicalDeviceSurfaceSupportKHR(physicalDevice: PhysicalDevice,
+                                                queueFamilyIndex: u32,
+                                                surface: khr::Surface,
+                                                pSupported: *mut Bool32) -> Result;
+    pub fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR(physicalDevice: PhysicalDevice,
+                                                     surface: khr::surface::Surface,
+                                                     pSurfaceCapabilities: *mut khr::surface::Capabilities) -> Result;
+    pub fn vkGetPhysicalDeviceSurfaceFormatsKHR(physicalDevice: PhysicalDevice,
+                                                surface: khr::surface::Surface,
+                                                pSurfaceFormatCount: *mut u32,
+                                                pSurfaceFormats: *mut khr::surface::Format) -> Result;
+    pub fn vkGetPhysicalDeviceSurfacePresentModesKHR(physicalDevice: PhysicalDevice,
+                                                     surface: khr::Surface,
+                                                     pPresentModeCount: *mut u32,
+                                                     pPresentModes: *mut khr::surface::PresentMode) -> Result;
+}
+
+/// `vkGetPhysicalDeviceSurfaceFormatsKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceSurfaceFormatsKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_physical_device_surface_formats(physical_device: PhysicalDevice, surface: Surface)
+        -> ::std::result::Result<Vec<Format>, Error> {
+    enumerate(|count, data| {
+        vkGetPhysicalDeviceSurfaceFormatsKHR(physical_device, surface, count, data)
+    })
+}
+
+/// `vkGetPhysicalDeviceSurfacePresentModesKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetPhysicalDeviceSurfacePresentModesKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_physical_device_surface_present_modes(physical_device: PhysicalDevice,
+                                                        surface: Surface)
+        -> ::std::result::Result<Vec<PresentMode>, Error> {
+    enumerate(|count, data| {
+        vkGetPhysicalDeviceSurfacePresentModesKHR(physical_device, surface, count, data)
+    })
 }
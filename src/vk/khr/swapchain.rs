@@ -1,23 +1,27 @@
 use vk::*;
-use libc::*;
 
 pub const SPEC_VERSION: u32 = 67;
 pub const EXTENSION_NAME: &'static str = "VK_KHR_swapchain";
 
-impl_enum!{Result;
-    SUBOPTIMAL = 1000001003,
-    ERROR_OUT_OF_DATE = -1000001004,
-}
-impl_enum!{StructureType;
-    SWAPCHAIN_CREATE_INFO = 1000001000,
-    PRESENT_INFO = 1000001001,
-}
-impl_enum!{ImageLayout;
-    PRESENT_SRC = 1000001002,
+ext_enums!{
+    Result {
+        SUBOPTIMAL = 1000001003,
+        ERROR_OUT_OF_DATE = -1000001004,
+    }
+    StructureType {
+        SWAPCHAIN_CREATE_INFO = 1000001000,
+        PRESENT_INFO = 1000001001,
+    }
+    ImageLayout {
+        PRESENT_SRC = 1000001002,
+    }
+    ObjectType {
+        SWAPCHAIN = 1000001000,
+    }
 }
 make_flag!{CreateFlag; CreateFlags; }
 
-opaque!{_Swapchain, Swapchain}
+handle!{Swapchain, SWAPCHAIN}
 
 #[repr(C)]
 pub struct CreateInfo {
@@ -40,6 +44,9 @@ pub struct CreateInfo {
     pub clipped: Bool32,
     pub oldSwapchain: khr::swapchain::Swapchain,
 }
+extensible!{CreateInfo, ExtendsCreateInfo}
+tagged!{CreateInfo, SWAPCHAIN_CREATE_INFO}
+unsafe impl ExtendsCreateInfo for ImageFormatListCreateInfo {}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PresentInfo {
@@ -52,24 +59,26 @@ pub struct PresentInfo {
     pub pImageIndices: *const u32,
     pub pResults: *mut Result,
 }
+extensible!{PresentInfo, ExtendsPresentInfo}
+tagged!{PresentInfo, PRESENT_INFO}
 
-pub type PFN_vkCreateSwapchain =
+pub type PFN_vkCreateSwapchainKHR =
     ::std::option::Option<unsafe extern "C" fn(device: Device,
                                                pCreateInfo: *const CreateInfo,
                                                pAllocator: *const AllocationCallbacks,
                                                pSwapchain: *mut Swapchain)
                               -> Result>;
-pub type PFN_vkDestroySwapchain =
+pub type PFN_vkDestroySwapchainKHR =
     ::std::option::Option<unsafe extern "C" fn(device: Device,
                                                swapchain: Swapchain,
                                                pAllocator: *const AllocationCallbacks)>;
-pub type PFN_vkGetSwapchainImages =
+pub type PFN_vkGetSwapchainImagesKHR =
     ::std::option::Option<unsafe extern "C" fn(device: Device,
                                                swapchain: Swapchain,
                                                pSwapchainImageCount: *mut u32,
                                                pSwapchainImages: *mut Image)
                               -> Result>;
-pub type PFN_vkAcquireNextImage =
+pub type PFN_vkAcquireNextImageKHR =
     ::std::option::Option<unsafe extern "C" fn(device: Device,
                                                swapchain: Swapchain,
                                                timeout: u64,
@@ -77,26 +86,218 @@ pub type PFN_vkAcquireNextImage =
                                                fence: Fence,
                                                pImageIndex: *mut u32)
                               -> Result>;
-pub type PFN_vkQueuePresent =
+pub type PFN_vkQueuePresentKHR =
     ::std::option::Option<unsafe extern "C" fn(queue: Queue,
                                                pPresentInfo: *const PresentInfo)
                               -> Result>;
 
+fn_table!{
+    pub struct Fns {
+        fn vkCreateSwapchainKHR: PFN_vkCreateSwapchainKHR(
+            device: Device,
+            pCreateInfo: *const CreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSwapchain: *mut Swapchain) -> Result;
+        fn vkDestroySwapchainKHR: PFN_vkDestroySwapchainKHR(
+            device: Device,
+            swapchain: Swapchain,
+            pAllocator: *const AllocationCallbacks);
+        fn vkGetSwapchainImagesKHR: PFN_vkGetSwapchainImagesKHR(
+            device: Device,
+            swapchain: Swapchain,
+            pSwapchainImageCount: *mut u32,
+            pSwapchainImages: *mut Image) -> Result;
+        fn vkAcquireNextImageKHR: PFN_vkAcquireNextImageKHR(
+            device: Device,
+            swapchain: Swapchain,
+            timeout: u64,
+            semaphore: Semaphore,
+            fence: Fence,
+            pImageIndex: *mut u32) -> Result;
+        fn vkQueuePresentKHR: PFN_vkQueuePresentKHR(
+            queue: Queue,
+            pPresentInfo: *const PresentInfo) -> Result;
+    }
+}
+
+impl Fns {
+    /// Resolves the commands of this extension for `device`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a live device created with `create_info`, on the instance
+    /// `instance_fns` was loaded for.
+    pub unsafe fn load(instance_fns: &InstanceFns, device: Device, create_info: &DeviceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+impl Fns {
+    /// `vkGetSwapchainImagesKHR`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkGetSwapchainImagesKHR`.
+    pub unsafe fn get_swapchain_images(&self, device: Device, swapchain: Swapchain)
+            -> ::std::result::Result<Vec<Image>, Error> {
+        enumerate(|count, data| {
+            self.vkGetSwapchainImagesKHR(device, swapchain, count, data)
+        })
+    }
+}
+
+#[cfg(feature = "static_link")]
 #[link(name = "vulkan")]
 extern "C" {
-    pub fn vkCreateSwapchain(device: Device,
-                             pCreateInfo: *const khr::swapchain::CreateInfo,
-                             pAllocator: *const AllocationCallbacks,
-                             pSwapchain: *mut khr::swapchain::Swapchain) -> Result;
-    pub fn vkDestroySwapchain(device: Device, swapchain: khr::swapchain::Swapchain,
-                              pAllocator: *const AllocationCallbacks);
-    pub fn vkGetSwapchainImages(device: Device,
-                                swapchain: khr::swapchain::Swapchain,
-                                pSwapchainImageCount: *mut uint32_t,
-                                pSwapchainImages: *mut Image) -> Result;
-    pub fn vkAcquireNextImage(device: Device, swapchain: khr::Swapchain,
-                              timeout: uint64_t, semaphore: Semaphore,
-                              fence: Fence, pImageIndex: *mut uint32_t) -> Result;
-    pub fn vkQueuePresent(queue: Queue,
-                          pPresentInfo: *const khr::swapchain::PresentInfo) -> Result;
+    pub fn vkCreateSwapchainKHR(device: Device,
+                                pCreateInfo: *const khr::swapchain::CreateInfo,
+                                pAllocator: *const AllocationCallbacks,
+                                pSwapchain: *mut khr::swapchain::Swapchain) -> Result;
+    pub fn vkDestroySwapchainKHR(device: Device, swapchain: khr::swapchain::Swapchain,
+                                 pAllocator: *const AllocationCallbacks);
+    pub fn vkGetSwapchainImagesKHR(device: Device,
+                                   swapchain: khr::swapchain::Swapchain,
+                                   pSwapchainImageCount: *mut u32,
+                                   pSwapchainImages: *mut Image) -> Result;
+    pub fn vkAcquireNextImageKHR(device: Device, swapchain: khr::Swapchain,
+                                 timeout: u64, semaphore: Semaphore,
+                                 fence: Fence, pImageIndex: *mut u32) -> Result;
+    pub fn vkQueuePresentKHR(queue: Queue,
+                             pPresentInfo: *const khr::swapchain::PresentInfo) -> Result;
+}
+
+/// `vkGetSwapchainImagesKHR`, collected into a `Vec`.
+///
+/// # Safety
+///
+/// The arguments must be valid for `vkGetSwapchainImagesKHR`.
+#[cfg(feature = "static_link")]
+pub unsafe fn get_swapchain_images(device: Device, swapchain: Swapchain)
+        -> ::std::result::Result<Vec<Image>, Error> {
+    enumerate(|count, data| {
+        vkGetSwapchainImagesKHR(device, swapchain, count, data)
+    })
+}
+
+builder!{CreateInfo, CreateInfoBuilder}
+impl<'a> CreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: CreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn surface(mut self, surface: khr::surface::Surface) -> Self {
+        self.inner.surface = surface;
+        self
+    }
+    #[inline]
+    pub fn min_image_count(mut self, min_image_count: u32) -> Self {
+        self.inner.minImageCount = min_image_count;
+        self
+    }
+    #[inline]
+    pub fn image_format(mut self, image_format: Format) -> Self {
+        self.inner.imageFormat = image_format;
+        self
+    }
+    #[inline]
+    pub fn image_color_space(mut self, image_color_space: khr::surface::ColorSpace) -> Self {
+        self.inner.imageColorSpace = image_color_space;
+        self
+    }
+    #[inline]
+    pub fn image_extent(mut self, image_extent: Extent2D) -> Self {
+        self.inner.imageExtent = image_extent;
+        self
+    }
+    #[inline]
+    pub fn image_array_layers(mut self, image_array_layers: u32) -> Self {
+        self.inner.imageArrayLayers = image_array_layers;
+        self
+    }
+    #[inline]
+    pub fn image_usage(mut self, image_usage: ImageUsageFlags) -> Self {
+        self.inner.imageUsage = image_usage;
+        self
+    }
+    #[inline]
+    pub fn image_sharing_mode(mut self, image_sharing_mode: SharingMode) -> Self {
+        self.inner.imageSharingMode = image_sharing_mode;
+        self
+    }
+    #[inline]
+    pub fn queue_family_indices(mut self, queue_family_indices: &'a [u32]) -> Self {
+        self.inner.queueFamilyIndexCount = queue_family_indices.len() as u32;
+        self.inner.pQueueFamilyIndices = queue_family_indices.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn pre_transform(mut self, pre_transform: khr::surface::TransformFlag) -> Self {
+        self.inner.preTransform = pre_transform;
+        self
+    }
+    #[inline]
+    pub fn composite_alpha(mut self, composite_alpha: khr::surface::CompositeAlphaFlag) -> Self {
+        self.inner.compositeAlpha = composite_alpha;
+        self
+    }
+    #[inline]
+    pub fn present_mode(mut self, present_mode: khr::surface::PresentMode) -> Self {
+        self.inner.presentMode = present_mode;
+        self
+    }
+    #[inline]
+    pub fn clipped(mut self, clipped: Bool32) -> Self {
+        self.inner.clipped = clipped;
+        self
+    }
+    #[inline]
+    pub fn old_swapchain(mut self, old_swapchain: khr::swapchain::Swapchain) -> Self {
+        self.inner.oldSwapchain = old_swapchain;
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsCreateInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
+}
+builder!{PresentInfo, PresentInfoBuilder}
+impl<'a> PresentInfoBuilder<'a> {
+    #[inline]
+    pub fn wait_semaphores(mut self, wait_semaphores: &'a [Semaphore]) -> Self {
+        self.inner.waitSemaphoreCount = wait_semaphores.len() as u32;
+        self.inner.pWaitSemaphores = wait_semaphores.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn swapchains(mut self, swapchains: &'a [Swapchain]) -> Self {
+        self.inner.swapchainCount = swapchains.len() as u32;
+        self.inner.pSwapchains = swapchains.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn image_indices(mut self, image_indices: &'a [u32]) -> Self {
+        self.inner.swapchainCount = image_indices.len() as u32;
+        self.inner.pImageIndices = image_indices.as_ptr();
+        self
+    }
+    #[inline]
+    pub fn results(mut self, results: &'a mut [Result]) -> Self {
+        self.inner.swapchainCount = results.len() as u32;
+        self.inner.pResults = results.as_mut_ptr();
+        self
+    }
+    #[inline]
+    pub fn push_next<T: ExtendsPresentInfo>(mut self, next: &'a mut T) -> Self {
+        unsafe { self.inner.push_next(next); }
+        self
+    }
 }
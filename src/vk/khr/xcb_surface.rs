@@ -9,8 +9,10 @@ use self::xcb::ffi::*;
 pub const SPEC_VERSION: u32 = 6;
 pub const EXTENSION_NAME: &'static str = "VK_KHR_xcb_surface";
 
-impl_enum!{StructureType;
-    XCB_SURFACE_CREATE_INFO = 1000005000,
+ext_enums!{
+    StructureType {
+        XCB_SURFACE_CREATE_INFO = 1000005000,
+    }
 }
 make_flag!{CreateFlag; CreateFlags; }
 
@@ -23,7 +25,9 @@ pub struct CreateInfo {
     pub connection: *mut xcb_connection_t,
     pub window: xcb_window_t,
 }
+tagged!{CreateInfo, XCB_SURFACE_CREATE_INFO}
 
+#[cfg(feature = "static_link")]
 #[link(name = "vulkan")]
 extern "C" {
     pub fn vkCreateXcbSurfaceKHR(instance: Instance,
@@ -48,3 +52,54 @@ pub type PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR =
                                                connection: *mut xcb_connection_t,
                                                visual_id: xcb_visualid_t)
                               -> Bool32>;
+
+fn_table!{
+    pub struct Fns {
+        fn vkCreateXcbSurfaceKHR: PFN_vkCreateXcbSurfaceKHR(
+            instance: Instance,
+            pCreateInfo: *const CreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pSurface: *mut khr::surface::Surface) -> Result;
+        fn vkGetPhysicalDeviceXcbPresentationSupportKHR: PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR(
+            physicalDevice: PhysicalDevice,
+            queueFamilyIndex: u32,
+            connection: *mut xcb_connection_t,
+            visual_id: xcb_visualid_t) -> Bool32;
+    }
+}
+
+impl Fns {
+    /// Resolves the commands of this extension for `instance`, which must have
+    /// been created with `create_info` and have the extension enabled.
+    ///
+    /// # Safety
+    ///
+    /// `instance` must be a live instance created through `entry` with `create_info`.
+    pub unsafe fn load(entry: &Entry, instance: Instance, create_info: &InstanceCreateInfo)
+            -> ::std::result::Result<Fns, LoadingError> {
+        check_enabled(EXTENSION_NAME, create_info.enabledExtensionCount,
+                      create_info.ppEnabledExtensionNames)?;
+        let fns = Fns::load_with(|name| entry.vkGetInstanceProcAddr(instance, name.as_ptr()));
+        let missing = fns.missing();
+        check_missing(fns, missing)
+    }
+}
+
+builder!{CreateInfo, CreateInfoBuilder}
+impl<'a> CreateInfoBuilder<'a> {
+    #[inline]
+    pub fn flags(mut self, flags: CreateFlags) -> Self {
+        self.inner.flags = flags;
+        self
+    }
+    #[inline]
+    pub fn connection(mut self, connection: *mut xcb_connection_t) -> Self {
+        self.inner.connection = connection;
+        self
+    }
+    #[inline]
+    pub fn window(mut self, window: xcb_window_t) -> Self {
+        self.inner.window = window;
+        self
+    }
+}
@@ -0,0 +1,347 @@
+// Runtime loading of the Vulkan loader library
+
+use std::error;
+use std::ffi::{CStr, OsStr};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::ptr::null_mut;
+use std::sync::Arc;
+
+use vk::*;
+
+/// Name of the Vulkan loader library opened by `Entry::load`.
+#[cfg(all(unix, not(any(target_os = "android", target_os = "macos"))))]
+pub const LIBRARY_NAME: &str = "libvulkan.so.1";
+#[cfg(target_os = "android")]
+pub const LIBRARY_NAME: &str = "libvulkan.so";
+#[cfg(target_os = "macos")]
+pub const LIBRARY_NAME: &str = "libvulkan.1.dylib";
+#[cfg(windows)]
+pub const LIBRARY_NAME: &str = "vulkan-1.dll";
+
+/// Libraries `Entry::load` tries when `LIBRARY_NAME` cannot be opened: on
+/// macOS, MoltenVK used directly without the loader.
+#[cfg(target_os = "macos")]
+const FALLBACK_LIBRARY_NAMES: &[&str] = &["libMoltenVK.dylib"];
+#[cfg(not(target_os = "macos"))]
+const FALLBACK_LIBRARY_NAMES: &[&str] = &[];
+
+#[derive(Clone, Debug)]
+pub enum LoadingError {
+    /// The loader library could not be opened.
+    Library(String),
+    /// Entry points which are required but could not be resolved.
+    MissingEntryPoints(Vec<&'static str>),
+    /// The extension whose commands were requested was not enabled.
+    ExtensionNotEnabled(&'static str),
+}
+
+impl fmt::Display for LoadingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LoadingError::Library(ref msg) =>
+                write!(f, "failed to open Vulkan loader: {}", msg),
+            LoadingError::MissingEntryPoints(ref names) =>
+                write!(f, "missing Vulkan entry points: {}", names.join(", ")),
+            LoadingError::ExtensionNotEnabled(name) =>
+                write!(f, "extension {} is not enabled", name),
+        }
+    }
+}
+
+impl error::Error for LoadingError { }
+
+/// Turns a table with unresolved entry points into a `MissingEntryPoints` error.
+pub(crate) fn check_missing<T>(table: T, missing: Vec<&'static str>)
+        -> ::std::result::Result<T, LoadingError> {
+    if missing.is_empty() {
+        Ok(table)
+    } else {
+        Err(LoadingError::MissingEntryPoints(missing))
+    }
+}
+
+/// Fails with `ExtensionNotEnabled` unless `name` is among the `count`
+/// extension names at `names`, as passed in `ppEnabledExtensionNames`.
+pub(crate) unsafe fn check_enabled(name: &'static str, count: u32, names: *const *const c_char)
+        -> ::std::result::Result<(), LoadingError> {
+    for i in 0..count as isize {
+        if CStr::from_ptr(*names.offset(i)).to_bytes() == name.as_bytes() {
+            return Ok(());
+        }
+    }
+    Err(LoadingError::ExtensionNotEnabled(name))
+}
+
+// Shared library handling
+
+#[cfg(unix)]
+mod dl {
+    use std::ffi::{CStr, CString, OsStr};
+    use std::os::raw::c_void;
+    use std::os::unix::ffi::OsStrExt;
+    use libc;
+
+    pub unsafe fn open(path: &OsStr) -> Result<*mut c_void, String> {
+        let path = CString::new(path.as_bytes()).map_err(|e| e.to_string())?;
+        let handle = libc::dlopen(path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+        if handle.is_null() {
+            Err(last_error())
+        } else {
+            Ok(handle)
+        }
+    }
+
+    pub unsafe fn sym(handle: *mut c_void, name: &CStr) -> *mut c_void {
+        libc::dlsym(handle, name.as_ptr())
+    }
+
+    pub unsafe fn close(handle: *mut c_void) {
+        libc::dlclose(handle);
+    }
+
+    unsafe fn last_error() -> String {
+        let err = libc::dlerror();
+        if err.is_null() {
+            "unknown error".to_owned()
+        } else {
+            CStr::from_ptr(err).to_string_lossy().into_owned()
+        }
+    }
+}
+
+#[cfg(windows)]
+mod dl {
+    use std::ffi::{CStr, OsStr};
+    use std::os::raw::{c_char, c_void};
+    use std::os::windows::ffi::OsStrExt;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn LoadLibraryW(lpLibFileName: *const u16) -> *mut c_void;
+        fn GetProcAddress(hModule: *mut c_void, lpProcName: *const c_char) -> *mut c_void;
+        fn FreeLibrary(hLibModule: *mut c_void) -> i32;
+        fn GetLastError() -> u32;
+    }
+
+    pub unsafe fn open(path: &OsStr) -> Result<*mut c_void, String> {
+        let path: Vec<u16> = path.encode_wide().chain(Some(0)).collect();
+        let handle = LoadLibraryW(path.as_ptr());
+        if handle.is_null() {
+            Err(format!("LoadLibraryW failed with error {}", GetLastError()))
+        } else {
+            Ok(handle)
+        }
+    }
+
+    pub unsafe fn sym(handle: *mut c_void, name: &CStr) -> *mut c_void {
+        GetProcAddress(handle, name.as_ptr())
+    }
+
+    pub unsafe fn close(handle: *mut c_void) {
+        FreeLibrary(handle);
+    }
+}
+
+struct Library(*mut c_void);
+
+unsafe impl Send for Library { }
+unsafe impl Sync for Library { }
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        unsafe { dl::close(self.0) }
+    }
+}
+
+// Entry
+
+fn_table!{
+    /// Global commands, which are resolved without an instance.
+    pub struct EntryFns {
+        fn vkCreateInstance: PFN_vkCreateInstance(
+            pCreateInfo: *const InstanceCreateInfo,
+            pAllocator: *const AllocationCallbacks,
+            pInstance: *mut Instance) -> Result;
+        fn vkEnumerateInstanceExtensionProperties: PFN_vkEnumerateInstanceExtensionProperties(
+            pLayerName: *const c_char,
+            pPropertyCount: *mut u32,
+            pProperties: *mut ExtensionProperties) -> Result;
+        fn vkEnumerateInstanceLayerProperties: PFN_vkEnumerateInstanceLayerProperties(
+            pPropertyCount: *mut u32,
+            pProperties: *mut LayerProperties) -> Result;
+    }
+}
+
+/// Entry point into a Vulkan implementation.
+///
+/// Holds `vkGetInstanceProcAddr` and the global commands. When created with
+/// `load` or `load_from`, the loader library stays open until the `Entry` and
+/// all its clones are dropped, so one of them must outlive every object
+/// created through it. Cloning is cheap; `owned::Instance` keeps a clone.
+#[derive(Clone)]
+pub struct Entry {
+    /// The loader library, or whatever else provides the commands.
+    _owner: Option<Arc<dyn Send + Sync>>,
+    get_instance_proc_addr: unsafe extern "C" fn(Instance, *const c_char) -> PFN_vkVoidFunction,
+    fns: EntryFns,
+    /// Only provided by Vulkan 1.1 loaders, so it is not part of `EntryFns`.
+    enumerate_instance_version: PFN_vkEnumerateInstanceVersion,
+}
+
+impl Entry {
+    /// Opens the system Vulkan loader (`LIBRARY_NAME`), or on macOS
+    /// MoltenVK if there is no loader. The error is the one for
+    /// `LIBRARY_NAME`.
+    pub fn load() -> ::std::result::Result<Entry, LoadingError> {
+        let err = match Entry::load_from(LIBRARY_NAME) {
+            Ok(entry) => return Ok(entry),
+            Err(err) => err,
+        };
+        FALLBACK_LIBRARY_NAMES.iter()
+            .filter_map(|name| Entry::load_from(name).ok())
+            .next()
+            .ok_or(err)
+    }
+
+    /// Opens the Vulkan loader at `path`.
+    pub fn load_from<P: AsRef<OsStr>>(path: P) -> ::std::result::Result<Entry, LoadingError> {
+        unsafe {
+            let lib = Library(dl::open(path.as_ref()).map_err(LoadingError::Library)?);
+            let name = CStr::from_bytes_with_nul_unchecked(b"vkGetInstanceProcAddr\0");
+            let gipa = match ::std::mem::transmute::<*mut c_void, PFN_vkGetInstanceProcAddr>(
+                dl::sym(lib.0, name)) {
+                Some(gipa) => gipa,
+                None => return Err(LoadingError::MissingEntryPoints(vec!["vkGetInstanceProcAddr"])),
+            };
+            Entry::from_proc_addr_owned(gipa, Arc::new(lib))
+        }
+    }
+
+    /// Uses the `vkGetInstanceProcAddr` linked into the binary.
+    #[cfg(feature = "static_link")]
+    pub fn linked() -> ::std::result::Result<Entry, LoadingError> {
+        unsafe { Entry::from_proc_addr(::vk::fns::vkGetInstanceProcAddr) }
+    }
+
+    /// Builds an `Entry` around an already resolved `vkGetInstanceProcAddr`.
+    ///
+    /// # Safety
+    ///
+    /// `get_instance_proc_addr` must behave as `vkGetInstanceProcAddr`, and
+    /// whatever provides it must outlive the `Entry`.
+    pub unsafe fn from_proc_addr(get_instance_proc_addr: unsafe extern "C" fn(Instance, *const c_char)
+                                                                              -> PFN_vkVoidFunction)
+            -> ::std::result::Result<Entry, LoadingError> {
+        let fns = EntryFns::load_with(|name| get_instance_proc_addr(null_mut(), name.as_ptr()));
+        let missing = fns.missing();
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkEnumerateInstanceVersion\0");
+        let enumerate_instance_version = ::std::mem::transmute::<PFN_vkVoidFunction,
+                                                                 PFN_vkEnumerateInstanceVersion>(
+            get_instance_proc_addr(null_mut(), name.as_ptr()));
+        check_missing(Entry {
+            _owner: None,
+            get_instance_proc_addr,
+            fns,
+            enumerate_instance_version,
+        }, missing)
+    }
+
+    /// Like `from_proc_addr`, and keeps `owner` alive until the `Entry` and
+    /// all its clones are dropped.
+    pub(crate) unsafe fn from_proc_addr_owned(get_instance_proc_addr: unsafe extern "C" fn(
+                                                  Instance, *const c_char) -> PFN_vkVoidFunction,
+                                              owner: Arc<dyn Send + Sync>)
+            -> ::std::result::Result<Entry, LoadingError> {
+        let mut entry = Entry::from_proc_addr(get_instance_proc_addr)?;
+        entry._owner = Some(owner);
+        Ok(entry)
+    }
+
+    pub fn fns(&self) -> &EntryFns {
+        &self.fns
+    }
+
+    /// Resolves the command named `pName` for `instance`, or a global
+    /// command if `instance` is null.
+    ///
+    /// # Safety
+    ///
+    /// `pName` must be a NUL-terminated string, and `instance` null or a
+    /// live instance created through this `Entry`.
+    pub unsafe fn vkGetInstanceProcAddr(&self, instance: Instance,
+                                        pName: *const c_char) -> PFN_vkVoidFunction {
+        (self.get_instance_proc_addr)(instance, pName)
+    }
+
+    /// Calls `vkCreateInstance`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkCreateInstance`.
+    pub unsafe fn vkCreateInstance(&self, pCreateInfo: *const InstanceCreateInfo,
+                                   pAllocator: *const AllocationCallbacks,
+                                   pInstance: *mut Instance) -> Result {
+        self.fns.vkCreateInstance(pCreateInfo, pAllocator, pInstance)
+    }
+
+    /// Calls `vkEnumerateInstanceExtensionProperties`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumerateInstanceExtensionProperties`.
+    pub unsafe fn vkEnumerateInstanceExtensionProperties(&self, pLayerName: *const c_char,
+                                                         pPropertyCount: *mut u32,
+                                                         pProperties: *mut ExtensionProperties)
+                                                         -> Result {
+        self.fns.vkEnumerateInstanceExtensionProperties(pLayerName, pPropertyCount, pProperties)
+    }
+
+    /// Calls `vkEnumerateInstanceLayerProperties`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumerateInstanceLayerProperties`.
+    pub unsafe fn vkEnumerateInstanceLayerProperties(&self, pPropertyCount: *mut u32,
+                                                     pProperties: *mut LayerProperties) -> Result {
+        self.fns.vkEnumerateInstanceLayerProperties(pPropertyCount, pProperties)
+    }
+
+    /// `vkEnumerateInstanceExtensionProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumerateInstanceExtensionProperties`.
+    pub unsafe fn enumerate_instance_extension_properties(&self, layer_name: Option<&CStr>)
+            -> ::std::result::Result<Vec<ExtensionProperties>, Error> {
+        let layer_name = layer_name.map_or(::std::ptr::null(), |s| s.as_ptr());
+        enumerate(|count, data| self.vkEnumerateInstanceExtensionProperties(layer_name, count, data))
+    }
+
+    /// `vkEnumerateInstanceLayerProperties`, collected into a `Vec`.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be valid for `vkEnumerateInstanceLayerProperties`.
+    pub unsafe fn enumerate_instance_layer_properties(&self)
+            -> ::std::result::Result<Vec<LayerProperties>, Error> {
+        enumerate(|count, data| self.vkEnumerateInstanceLayerProperties(count, data))
+    }
+
+    /// Writes the instance-level API version to `pApiVersion`. Vulkan 1.0
+    /// loaders do not have this command, in which case the version is
+    /// reported as `API_VERSION_1_0`.
+    ///
+    /// # Safety
+    ///
+    /// `pApiVersion` must be valid for writes.
+    pub unsafe fn vkEnumerateInstanceVersion(&self, pApiVersion: *mut u32) -> Result {
+        match self.enumerate_instance_version {
+            Some(f) => f(pApiVersion),
+            None => {
+                *pApiVersion = API_VERSION_1_0;
+                Result::SUCCESS
+            }
+        }
+    }
+}
@@ -0,0 +1,1776 @@
+// In-process fake implementation of the core commands, for testing without a GPU
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::ptr::null_mut;
+use std::slice;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use vk::*;
+
+/// Description of the physical devices reported by mock instances.
+#[derive(Clone)]
+pub struct Config {
+    pub physical_devices: Vec<PhysicalDeviceConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { physical_devices: vec![PhysicalDeviceConfig::default()] }
+    }
+}
+
+/// Everything a mock physical device reports about itself.
+#[derive(Clone)]
+pub struct PhysicalDeviceConfig {
+    pub properties: PhysicalDeviceProperties,
+    pub features: PhysicalDeviceFeatures,
+    pub memory_properties: PhysicalDeviceMemoryProperties,
+    pub queue_families: Vec<QueueFamilyProperties>,
+    /// Formats which are not listed support no features at all.
+    pub format_properties: Vec<(Format, FormatProperties)>,
+}
+
+impl PhysicalDeviceConfig {
+    /// Sets the name reported in `PhysicalDeviceProperties::deviceName`.
+    pub fn set_name(&mut self, name: &str) {
+        let len = ::std::cmp::min(name.len(), self.properties.deviceName.len() - 1);
+        for (dst, src) in self.properties.deviceName.iter_mut().zip(name.bytes().take(len)) {
+            *dst = src as c_char;
+        }
+        self.properties.deviceName[len] = 0;
+    }
+}
+
+impl Default for PhysicalDeviceConfig {
+    /// A Vulkan 1.0 device with one graphics/compute/transfer queue family, a separate
+    /// transfer family, a 256 MiB device-local heap and a 256 MiB host heap.
+    fn default() -> Self {
+        let mut properties: PhysicalDeviceProperties = unsafe { mem::zeroed() };
+        properties.apiVersion = API_VERSION_1_0;
+        properties.driverVersion = make_version(1, 0, 0);
+        properties.deviceType = PhysicalDeviceType::CPU;
+        {
+            let limits = &mut properties.limits;
+            limits.maxImageDimension1D = 16384;
+            limits.maxImageDimension2D = 16384;
+            limits.maxImageDimension3D = 2048;
+            limits.maxImageDimensionCube = 16384;
+            limits.maxImageArrayLayers = 2048;
+            limits.maxTexelBufferElements = 1 << 27;
+            limits.maxUniformBufferRange = 65536;
+            limits.maxStorageBufferRange = 1 << 30;
+            limits.maxPushConstantsSize = 128;
+            limits.maxMemoryAllocationCount = 4096;
+            limits.maxSamplerAllocationCount = 4000;
+            limits.bufferImageGranularity = 1024;
+            limits.maxBoundDescriptorSets = 8;
+            limits.maxPerStageDescriptorSamplers = 16;
+            limits.maxPerStageDescriptorUniformBuffers = 12;
+            limits.maxPerStageDescriptorStorageBuffers = 4;
+            limits.maxPerStageDescriptorSampledImages = 16;
+            limits.maxPerStageDescriptorStorageImages = 4;
+            limits.maxPerStageDescriptorInputAttachments = 4;
+            limits.maxPerStageResources = 128;
+            limits.maxDescriptorSetSamplers = 96;
+            limits.maxDescriptorSetUniformBuffers = 72;
+            limits.maxDescriptorSetUniformBuffersDynamic = 8;
+            limits.maxDescriptorSetStorageBuffers = 24;
+            limits.maxDescriptorSetStorageBuffersDynamic = 4;
+            limits.maxDescriptorSetSampledImages = 96;
+            limits.maxDescriptorSetStorageImages = 24;
+            limits.maxDescriptorSetInputAttachments = 4;
+            limits.maxVertexInputAttributes = 16;
+            limits.maxVertexInputBindings = 16;
+            limits.maxVertexInputAttributeOffset = 2047;
+            limits.maxVertexInputBindingStride = 2048;
+            limits.maxVertexOutputComponents = 64;
+            limits.maxFragmentInputComponents = 64;
+            limits.maxFragmentOutputAttachments = 4;
+            limits.maxFragmentCombinedOutputResources = 4;
+            limits.maxComputeSharedMemorySize = 16384;
+            limits.maxComputeWorkGroupCount = [65535; 3];
+            limits.maxComputeWorkGroupInvocations = 128;
+            limits.maxComputeWorkGroupSize = [128, 128, 64];
+            limits.subPixelPrecisionBits = 4;
+            limits.subTexelPrecisionBits = 4;
+            limits.mipmapPrecisionBits = 4;
+            limits.maxDrawIndexedIndexValue = u32::MAX;
+            limits.maxDrawIndirectCount = 1;
+            limits.maxSamplerLodBias = 2.0;
+            limits.maxSamplerAnisotropy = 1.0;
+            limits.maxViewports = 1;
+            limits.maxViewportDimensions = [4096, 4096];
+            limits.viewportBoundsRange = [-8192.0, 8191.0];
+            limits.minMemoryMapAlignment = 64;
+            limits.minTexelBufferOffsetAlignment = 256;
+            limits.minUniformBufferOffsetAlignment = 256;
+            limits.minStorageBufferOffsetAlignment = 256;
+            limits.maxFramebufferWidth = 4096;
+            limits.maxFramebufferHeight = 4096;
+            limits.maxFramebufferLayers = 256;
+            limits.framebufferColorSampleCounts = SampleCountFlag::E_1.into();
+            limits.framebufferDepthSampleCounts = SampleCountFlag::E_1.into();
+            limits.framebufferStencilSampleCounts = SampleCountFlag::E_1.into();
+            limits.framebufferNoAttachmentsSampleCounts = SampleCountFlag::E_1.into();
+            limits.maxColorAttachments = 4;
+            limits.sampledImageColorSampleCounts = SampleCountFlag::E_1.into();
+            limits.sampledImageIntegerSampleCounts = SampleCountFlag::E_1.into();
+            limits.sampledImageDepthSampleCounts = SampleCountFlag::E_1.into();
+            limits.sampledImageStencilSampleCounts = SampleCountFlag::E_1.into();
+            limits.storageImageSampleCounts = SampleCountFlag::E_1.into();
+            limits.maxSampleMaskWords = 1;
+            limits.timestampPeriod = 1.0;
+            limits.discreteQueuePriorities = 2;
+            limits.pointSizeRange = [1.0, 1.0];
+            limits.lineWidthRange = [1.0, 1.0];
+            limits.optimalBufferCopyOffsetAlignment = 1;
+            limits.optimalBufferCopyRowPitchAlignment = 1;
+            limits.nonCoherentAtomSize = 64;
+        }
+
+        let mut memory_properties: PhysicalDeviceMemoryProperties = unsafe { mem::zeroed() };
+        memory_properties.memoryHeapCount = 2;
+        memory_properties.memoryHeaps[0] = MemoryHeap {
+            size: 256 << 20,
+            flags: MemoryHeapFlag::DEVICE_LOCAL.into(),
+        };
+        memory_properties.memoryHeaps[1] = MemoryHeap {
+            size: 256 << 20,
+            flags: MemoryHeapFlags::default(),
+        };
+        memory_properties.memoryTypeCount = 3;
+        memory_properties.memoryTypes[0] = MemoryType {
+            propertyFlags: MemoryPropertyFlag::DEVICE_LOCAL.into(),
+            heapIndex: 0,
+        };
+        memory_properties.memoryTypes[1] = MemoryType {
+            propertyFlags: MemoryPropertyFlag::HOST_VISIBLE | MemoryPropertyFlag::HOST_COHERENT,
+            heapIndex: 1,
+        };
+        memory_properties.memoryTypes[2] = MemoryType {
+            propertyFlags: MemoryPropertyFlag::HOST_VISIBLE | MemoryPropertyFlag::HOST_COHERENT |
+                           MemoryPropertyFlag::HOST_CACHED,
+            heapIndex: 1,
+        };
+
+        let unit = Extent3D { width: 1, height: 1, depth: 1 };
+        let queue_families = vec![
+            QueueFamilyProperties {
+                queueFlags: QueueFlag::GRAPHICS | QueueFlag::COMPUTE | QueueFlag::TRANSFER,
+                queueCount: 4,
+                timestampValidBits: 64,
+                minImageTransferGranularity: unit,
+            },
+            QueueFlag::TRANSFER.into(),
+        ];
+
+        let color = FormatProperties {
+            linearTilingFeatures: FormatFeatureFlags(0x0000_1dff),
+            optimalTilingFeatures: FormatFeatureFlags(0x0000_1dff),
+            bufferFeatures: FormatFeatureFlag::VERTEX_BUFFER | FormatFeatureFlag::UNIFORM_TEXEL_BUFFER |
+                            FormatFeatureFlag::STORAGE_TEXEL_BUFFER,
+        };
+        let depth = FormatProperties {
+            linearTilingFeatures: FormatFeatureFlags::default(),
+            optimalTilingFeatures: FormatFeatureFlag::SAMPLED_IMAGE |
+                                   FormatFeatureFlag::DEPTH_STENCIL_ATTACHMENT |
+                                   FormatFeatureFlag::BLIT_SRC,
+            bufferFeatures: FormatFeatureFlags::default(),
+        };
+        let format_properties = vec![
+            (Format::R8_UNORM, color),
+            (Format::R8G8_UNORM, color),
+            (Format::R8G8B8A8_UNORM, color),
+            (Format::R8G8B8A8_SRGB, color),
+            (Format::B8G8R8A8_UNORM, color),
+            (Format::B8G8R8A8_SRGB, color),
+            (Format::R32_SFLOAT, color),
+            (Format::R32G32_SFLOAT, color),
+            (Format::R32G32B32_SFLOAT, color),
+            (Format::R32G32B32A32_SFLOAT, color),
+            (Format::D16_UNORM, depth),
+            (Format::D32_SFLOAT, depth),
+            (Format::D24_UNORM_S8_UINT, depth),
+            (Format::D32_SFLOAT_S8_UINT, depth),
+        ];
+
+        let mut config = PhysicalDeviceConfig {
+            properties,
+            features: unsafe { mem::zeroed() },
+            memory_properties,
+            queue_families,
+            format_properties,
+        };
+        config.set_name("Mock Vulkan Device");
+        config
+    }
+}
+
+impl From<QueueFlag> for QueueFamilyProperties {
+    fn from(flag: QueueFlag) -> Self {
+        QueueFamilyProperties {
+            queueFlags: flag.into(),
+            queueCount: 1,
+            timestampValidBits: 64,
+            minImageTransferGranularity: Extent3D { width: 1, height: 1, depth: 1 },
+        }
+    }
+}
+
+type GetInstanceProcAddr = unsafe extern "C" fn(Instance, *const c_char) -> PFN_vkVoidFunction;
+
+macro_rules! entry_points {
+    ($($slot:tt)*) => { [$(vkGetInstanceProcAddr::<$slot> as GetInstanceProcAddr,)*] }
+}
+
+// Each live mock `Entry` takes a slot with its own `vkGetInstanceProcAddr`,
+// which hands out a `vkCreateInstance` that reads that slot's `Config`.
+const MAX_ENTRIES: usize = 64;
+static ENTRY_POINTS: [GetInstanceProcAddr; MAX_ENTRIES] = entry_points!(
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61
+    62 63);
+const NO_CONFIG: Option<Arc<Config>> = None;
+static CONFIGS: Mutex<[Option<Arc<Config>>; MAX_ENTRIES]> = Mutex::new([NO_CONFIG; MAX_ENTRIES]);
+
+fn configs() -> MutexGuard<'static, [Option<Arc<Config>>; MAX_ENTRIES]> {
+    CONFIGS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Frees a slot of `CONFIGS` once its `Entry` and all clones are dropped.
+struct Slot(usize);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        configs()[self.0] = None;
+    }
+}
+
+/// Returns an `Entry` backed by the mock implementation.
+///
+/// Instances created through it, on any thread, report the physical devices
+/// described by `config`. Each instance keeps its own copy, so it is not
+/// affected by other mock entries or by dropping this one.
+///
+/// # Panics
+///
+/// Panics if 64 mock entries are already alive.
+pub fn entry(config: Config) -> Entry {
+    let slot = {
+        let mut configs = configs();
+        let slot = configs.iter().position(Option::is_none).expect("too many live mock entries");
+        configs[slot] = Some(Arc::new(config));
+        slot
+    };
+    unsafe { Entry::from_proc_addr_owned(ENTRY_POINTS[slot], Arc::new(Slot(slot))).unwrap() }
+}
+
+/// Handles of every object currently alive on a mock `device`.
+///
+/// # Safety
+///
+/// `device` must be a live device created through a mock `Entry`.
+pub unsafe fn live_objects(device: Device) -> Vec<(ObjectType, u64)> {
+    let state = dev(device).state.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut objects: Vec<_> = state.objects.iter().map(|(&h, o)| (o.kind, h)).collect();
+    objects.sort_by_key(|&(_, h)| h);
+    objects
+}
+
+/// Drains the usage errors detected on a mock `device`, such as destroying
+/// an unknown handle or recording into a command buffer which is not
+/// recording.
+///
+/// # Safety
+///
+/// `device` must be a live device created through a mock `Entry`.
+pub unsafe fn take_errors(device: Device) -> Vec<String> {
+    let mut state = dev(device).state.lock().unwrap_or_else(PoisonError::into_inner);
+    mem::take(&mut state.errors)
+}
+
+/// Bytes currently allocated from each memory heap of a mock `device`.
+///
+/// # Safety
+///
+/// `device` must be a live device created through a mock `Entry`.
+pub unsafe fn heap_usage(device: Device) -> Vec<DeviceSize> {
+    dev(device).state.lock().unwrap_or_else(PoisonError::into_inner).heap_usage.clone()
+}
+
+/// The buffer or image a mock `memory` was allocated for through
+/// `MemoryDedicatedAllocateInfo`, if any.
+///
+/// # Safety
+///
+/// `device` must be a live device created through a mock `Entry`.
+pub unsafe fn dedicated_resource(device: Device, memory: DeviceMemory)
+        -> Option<(ObjectType, u64)> {
+    let state = dev(device).state.lock().unwrap_or_else(PoisonError::into_inner);
+    match state.objects.get(&from_handle(memory)).map(|tracked| &tracked.object) {
+        Some(&Object::Memory { dedicated, .. }) => dedicated,
+        _ => None,
+    }
+}
+
+/// Hides the last `count` physical devices of a mock `instance` until it is
+/// next asked for the number of physical devices or device groups, as if
+/// they were plugged in right after. The second call of an enumeration then
+/// finds more than it was given room for and returns `INCOMPLETE`.
+///
+/// # Safety
+///
+/// `instance` must be a live instance created through a mock `Entry`.
+pub unsafe fn hide_physical_devices(instance: Instance, count: usize) {
+    (*(instance as *const MockInstance)).hidden.store(count, Ordering::SeqCst);
+}
+
+/// Names of the commands recorded into a mock `command_buffer` since it
+/// last began recording.
+///
+/// # Safety
+///
+/// `command_buffer` must be a live command buffer of a mock device.
+pub unsafe fn recorded_commands(command_buffer: CommandBuffer) -> Vec<&'static str> {
+    cmd(command_buffer).commands.lock().unwrap_or_else(PoisonError::into_inner).commands.clone()
+}
+
+// Object bookkeeping
+
+// Physical device and queue handles point into `physical_devices` and
+// `queues`, which are filled in before any of them is handed out and never
+// change afterwards.
+
+struct MockInstance {
+    physical_devices: Vec<MockPhysicalDevice>,
+    /// How many of `physical_devices` are left out, from the end.
+    hidden: AtomicUsize,
+}
+
+impl MockInstance {
+    /// The physical devices which are plugged in. Counting them plugs in the
+    /// hidden ones.
+    fn physical_devices(&self, counting: bool) -> Vec<PhysicalDevice> {
+        let hidden = if counting {
+            self.hidden.swap(0, Ordering::SeqCst)
+        } else {
+            self.hidden.load(Ordering::SeqCst)
+        };
+        let count = self.physical_devices.len().saturating_sub(hidden);
+        self.physical_devices[..count].iter()
+            .map(|p| p as *const MockPhysicalDevice as PhysicalDevice)
+            .collect()
+    }
+}
+
+struct MockPhysicalDevice {
+    config: PhysicalDeviceConfig,
+}
+
+struct MockDevice {
+    physical_device: *const MockPhysicalDevice,
+    queues: Vec<MockQueue>,
+    state: Mutex<DeviceState>,
+}
+
+struct MockQueue {
+    device: *const MockDevice,
+    family: u32,
+    index: u32,
+}
+
+struct MockCommandBuffer {
+    device: *const MockDevice,
+    commands: Mutex<CommandBufferState>,
+}
+
+struct CommandBufferState {
+    recording: bool,
+    commands: Vec<&'static str>,
+}
+
+struct DeviceState {
+    objects: HashMap<u64, Tracked>,
+    heap_usage: Vec<DeviceSize>,
+    allocation_count: u32,
+    errors: Vec<String>,
+}
+
+struct Tracked {
+    kind: ObjectType,
+    object: Object,
+}
+
+enum Object {
+    Plain,
+    /// `data` is allocated by the first `vkMapMemory` and kept until the
+    /// memory is freed, so it holds what was written while mapped.
+    Memory {
+        size: DeviceSize,
+        memory_type: u32,
+        data: Option<Vec<u8>>,
+        mapped: bool,
+        dedicated: Option<(ObjectType, u64)>,
+    },
+    Fence { signaled: bool },
+    Event { set: bool },
+    Buffer { size: DeviceSize },
+    Image { size: DeviceSize },
+    DescriptorSet { pool: u64 },
+    CommandBuffer { pool: u64 },
+}
+
+static NEXT_HANDLE: AtomicUsize = AtomicUsize::new(1);
+
+fn to_handle<T: Handle>(raw: u64) -> T {
+    T::from_raw(raw)
+}
+
+fn from_handle<T: Handle>(handle: T) -> u64 {
+    handle.as_raw()
+}
+
+unsafe fn dev<'a>(device: Device) -> &'a MockDevice {
+    &*(device as *const MockDevice)
+}
+
+unsafe fn phys<'a>(physical_device: PhysicalDevice) -> &'a MockPhysicalDevice {
+    &*(physical_device as *const MockPhysicalDevice)
+}
+
+unsafe fn cmd<'a>(command_buffer: CommandBuffer) -> &'a MockCommandBuffer {
+    &*(command_buffer as *const MockCommandBuffer)
+}
+
+impl MockDevice {
+    fn create(&self, kind: ObjectType, object: Object) -> u64 {
+        let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed) as u64;
+        self.track(handle, kind, object);
+        handle
+    }
+
+    fn track(&self, handle: u64, kind: ObjectType, object: Object) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.objects.insert(handle, Tracked { kind, object });
+    }
+
+    fn destroy(&self, kind: ObjectType, handle: u64) -> Option<Object> {
+        if handle == 0 {
+            return None;
+        }
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        match state.objects.remove(&handle) {
+            Some(ref tracked) if tracked.kind != kind => {
+                state.errors.push(format!("destroying {:x} as {:?}, but it is a {:?}",
+                                          handle, kind, tracked.kind));
+                None
+            }
+            Some(tracked) => Some(tracked.object),
+            None => {
+                state.errors.push(format!("destroying unknown {:?} {:x}", kind, handle));
+                None
+            }
+        }
+    }
+
+    fn error(&self, msg: String) {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).errors.push(msg);
+    }
+
+    fn with<R, F>(&self, kind: ObjectType, handle: u64, f: F) -> Option<R>
+        where F: FnOnce(&mut Object) -> R
+    {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = match state.objects.get_mut(&handle) {
+            Some(tracked) if tracked.kind == kind => Some(f(&mut tracked.object)),
+            _ => None,
+        };
+        if result.is_none() {
+            state.errors.push(format!("using unknown {:?} {:x}", kind, handle));
+        }
+        result
+    }
+
+    unsafe fn physical(&self) -> &MockPhysicalDevice {
+        &*self.physical_device
+    }
+}
+
+/// Reads `count` elements at `ptr`, which may be null when `count` is zero.
+unsafe fn array<'a, T>(ptr: *const T, count: usize) -> &'a [T] {
+    if count == 0 { &[] } else { slice::from_raw_parts(ptr, count) }
+}
+
+unsafe fn write_array<T: Copy>(src: &[T], count: *mut u32, dst: *mut T) -> Result {
+    if dst.is_null() {
+        *count = src.len() as u32;
+        return Result::SUCCESS;
+    }
+    let n = ::std::cmp::min(*count as usize, src.len());
+    for (i, item) in src.iter().take(n).enumerate() {
+        *dst.add(i) = *item;
+    }
+    *count = n as u32;
+    if n < src.len() { Result::INCOMPLETE } else { Result::SUCCESS }
+}
+
+// Extensions
+
+/// Instance extensions the mock implements, with their spec versions.
+const INSTANCE_EXTENSIONS: &[(&str, u32)] = &[];
+
+/// Device extensions the mock implements; both were promoted to Vulkan 1.1,
+/// so their commands are the core ones under a `KHR` alias.
+const DEVICE_EXTENSIONS: &[(&str, u32)] = &[
+    ("VK_KHR_get_memory_requirements2", 1),
+    ("VK_KHR_dedicated_allocation", 3),
+];
+
+fn extension_properties(extensions: &[(&str, u32)]) -> Vec<ExtensionProperties> {
+    extensions.iter().map(|&(name, version)| {
+        let mut properties = ExtensionProperties::default();
+        for (dst, &src) in properties.extensionName.iter_mut().zip(name.as_bytes()) {
+            *dst = src as c_char;
+        }
+        properties.specVersion = version;
+        properties
+    }).collect()
+}
+
+unsafe fn extensions_supported(extensions: &[(&str, u32)], count: u32,
+                               names: *const *const c_char) -> bool {
+    array(names, count as usize).iter().all(|&name| {
+        let name = CStr::from_ptr(name).to_bytes();
+        extensions.iter().any(|&(supported, _)| supported.as_bytes() == name)
+    })
+}
+
+// Global commands
+
+macro_rules! lookup {
+    ($name:expr; $($cmd:ident,)*) => {
+        match $name {
+            $(stringify!($cmd) =>
+                return mem::transmute::<*const (), PFN_vkVoidFunction>($cmd as *const ()),)*
+            _ => { }
+        }
+    };
+    ($name:expr; $($alias:expr => $cmd:expr,)*) => {
+        match $name {
+            $($alias =>
+                return mem::transmute::<*const (), PFN_vkVoidFunction>($cmd as *const ()),)*
+            _ => { }
+        }
+    };
+}
+
+unsafe extern "C" fn vkGetInstanceProcAddr<const SLOT: usize>(instance: Instance,
+                                                              pName: *const c_char)
+                                                              -> PFN_vkVoidFunction {
+    let name = match CStr::from_ptr(pName).to_str() {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
+    lookup!{name;
+        "vkGetInstanceProcAddr" => vkGetInstanceProcAddr::<SLOT>,
+        "vkCreateInstance" => vkCreateInstance::<SLOT>,
+        "vkEnumerateInstanceExtensionProperties" => vkEnumerateInstanceExtensionProperties,
+        "vkEnumerateInstanceLayerProperties" => vkEnumerateInstanceLayerProperties,
+    }
+    if instance.is_null() {
+        return None;
+    }
+    lookup!{name;
+        vkDestroyInstance,
+        vkEnumeratePhysicalDevices,
+        vkEnumeratePhysicalDeviceGroups,
+        vkGetPhysicalDeviceFeatures,
+        vkGetPhysicalDeviceFormatProperties,
+        vkGetPhysicalDeviceImageFormatProperties,
+        vkGetPhysicalDeviceProperties,
+        vkGetPhysicalDeviceQueueFamilyProperties,
+        vkGetPhysicalDeviceMemoryProperties,
+        vkGetPhysicalDeviceSparseImageFormatProperties,
+        vkCreateDevice,
+        vkEnumerateDeviceExtensionProperties,
+        vkEnumerateDeviceLayerProperties,
+    }
+    vkGetDeviceProcAddr(null_mut(), pName)
+}
+
+unsafe extern "C" fn vkGetDeviceProcAddr(_device: Device,
+                                         pName: *const c_char) -> PFN_vkVoidFunction {
+    let name = match CStr::from_ptr(pName).to_str() {
+        Ok(name) => name,
+        Err(_) => return None,
+    };
+    lookup!{name;
+        vkGetDeviceProcAddr,
+        vkDestroyDevice,
+        vkGetDeviceQueue,
+        vkQueueSubmit,
+        vkQueueWaitIdle,
+        vkDeviceWaitIdle,
+        vkAllocateMemory,
+        vkFreeMemory,
+        vkMapMemory,
+        vkUnmapMemory,
+        vkFlushMappedMemoryRanges,
+        vkInvalidateMappedMemoryRanges,
+        vkGetDeviceMemoryCommitment,
+        vkBindBufferMemory,
+        vkBindImageMemory,
+        vkGetBufferMemoryRequirements,
+        vkGetImageMemoryRequirements,
+        vkGetImageSparseMemoryRequirements,
+        vkGetBufferMemoryRequirements2,
+        vkGetImageMemoryRequirements2,
+        vkQueueBindSparse,
+        vkCreateFence,
+        vkDestroyFence,
+        vkResetFences,
+        vkGetFenceStatus,
+        vkWaitForFences,
+        vkCreateSemaphore,
+        vkDestroySemaphore,
+        vkCreateEvent,
+        vkDestroyEvent,
+        vkGetEventStatus,
+        vkSetEvent,
+        vkResetEvent,
+        vkCreateQueryPool,
+        vkDestroyQueryPool,
+        vkGetQueryPoolResults,
+        vkCreateBuffer,
+        vkDestroyBuffer,
+        vkCreateBufferView,
+        vkDestroyBufferView,
+        vkCreateImage,
+        vkDestroyImage,
+        vkGetImageSubresourceLayout,
+        vkCreateImageView,
+        vkDestroyImageView,
+        vkCreateShaderModule,
+        vkDestroyShaderModule,
+        vkCreatePipelineCache,
+        vkDestroyPipelineCache,
+        vkGetPipelineCacheData,
+        vkMergePipelineCaches,
+        vkCreateGraphicsPipelines,
+        vkCreateComputePipelines,
+        vkDestroyPipeline,
+        vkCreatePipelineLayout,
+        vkDestroyPipelineLayout,
+        vkCreateSampler,
+        vkDestroySampler,
+        vkCreateDescriptorSetLayout,
+        vkDestroyDescriptorSetLayout,
+        vkCreateDescriptorPool,
+        vkDestroyDescriptorPool,
+        vkResetDescriptorPool,
+        vkAllocateDescriptorSets,
+        vkFreeDescriptorSets,
+        vkUpdateDescriptorSets,
+        vkCreateFramebuffer,
+        vkDestroyFramebuffer,
+        vkCreateRenderPass,
+        vkDestroyRenderPass,
+        vkGetRenderAreaGranularity,
+        vkCreateCommandPool,
+        vkDestroyCommandPool,
+        vkResetCommandPool,
+        vkAllocateCommandBuffers,
+        vkFreeCommandBuffers,
+        vkBeginCommandBuffer,
+        vkEndCommandBuffer,
+        vkResetCommandBuffer,
+        vkCmdBindPipeline,
+        vkCmdSetViewport,
+        vkCmdSetScissor,
+        vkCmdSetLineWidth,
+        vkCmdSetDepthBias,
+        vkCmdSetBlendConstants,
+        vkCmdSetDepthBounds,
+        vkCmdSetStencilCompareMask,
+        vkCmdSetStencilWriteMask,
+        vkCmdSetStencilReference,
+        vkCmdBindDescriptorSets,
+        vkCmdBindIndexBuffer,
+        vkCmdBindVertexBuffers,
+        vkCmdDraw,
+        vkCmdDrawIndexed,
+        vkCmdDrawIndirect,
+        vkCmdDrawIndexedIndirect,
+        vkCmdDispatch,
+        vkCmdDispatchIndirect,
+        vkCmdCopyBuffer,
+        vkCmdCopyImage,
+        vkCmdBlitImage,
+        vkCmdCopyBufferToImage,
+        vkCmdCopyImageToBuffer,
+        vkCmdUpdateBuffer,
+        vkCmdFillBuffer,
+        vkCmdClearColorImage,
+        vkCmdClearDepthStencilImage,
+        vkCmdClearAttachments,
+        vkCmdResolveImage,
+        vkCmdSetEvent,
+        vkCmdResetEvent,
+        vkCmdWaitEvents,
+        vkCmdPipelineBarrier,
+        vkCmdBeginQuery,
+        vkCmdEndQuery,
+        vkCmdResetQueryPool,
+        vkCmdWriteTimestamp,
+        vkCmdCopyQueryPoolResults,
+        vkCmdPushConstants,
+        vkCmdBeginRenderPass,
+        vkCmdNextSubpass,
+        vkCmdEndRenderPass,
+        vkCmdExecuteCommands,
+    }
+    lookup!{name;
+        "vkGetBufferMemoryRequirements2KHR" => vkGetBufferMemoryRequirements2,
+        "vkGetImageMemoryRequirements2KHR" => vkGetImageMemoryRequirements2,
+    }
+    None
+}
+
+unsafe extern "C" fn vkCreateInstance<const SLOT: usize>(pCreateInfo: *const InstanceCreateInfo,
+                                                         _pAllocator: *const AllocationCallbacks,
+                                                         pInstance: *mut Instance) -> Result {
+    let info = &*pCreateInfo;
+    if info.enabledLayerCount > 0 {
+        return Result::ERROR_LAYER_NOT_PRESENT;
+    }
+    if !extensions_supported(INSTANCE_EXTENSIONS, info.enabledExtensionCount,
+                             info.ppEnabledExtensionNames) {
+        return Result::ERROR_EXTENSION_NOT_PRESENT;
+    }
+    // The slot is only empty if this was called after its `Entry` was dropped.
+    let config = match configs()[SLOT].clone() {
+        Some(config) => config,
+        None => return Result::ERROR_INITIALIZATION_FAILED,
+    };
+    let instance = Box::new(MockInstance {
+        physical_devices: config.physical_devices.iter().map(|config| {
+            MockPhysicalDevice { config: config.clone() }
+        }).collect(),
+        hidden: AtomicUsize::new(0),
+    });
+    *pInstance = Box::into_raw(instance) as Instance;
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkEnumerateInstanceExtensionProperties(pLayerName: *const c_char,
+                                                            pPropertyCount: *mut u32,
+                                                            pProperties: *mut ExtensionProperties)
+                                                            -> Result {
+    if !pLayerName.is_null() {
+        return Result::ERROR_LAYER_NOT_PRESENT;
+    }
+    write_array(&extension_properties(INSTANCE_EXTENSIONS), pPropertyCount, pProperties)
+}
+
+unsafe extern "C" fn vkEnumerateInstanceLayerProperties(pPropertyCount: *mut u32,
+                                                        pProperties: *mut LayerProperties)
+                                                        -> Result {
+    write_array(&[], pPropertyCount, pProperties)
+}
+
+// Instance commands
+
+unsafe extern "C" fn vkDestroyInstance(instance: Instance,
+                                       _pAllocator: *const AllocationCallbacks) {
+    if !instance.is_null() {
+        drop(Box::from_raw(instance as *mut MockInstance));
+    }
+}
+
+unsafe extern "C" fn vkEnumeratePhysicalDevices(instance: Instance,
+                                                pPhysicalDeviceCount: *mut u32,
+                                                pPhysicalDevices: *mut PhysicalDevice) -> Result {
+    let instance = &*(instance as *const MockInstance);
+    let handles = instance.physical_devices(pPhysicalDevices.is_null());
+    write_array(&handles, pPhysicalDeviceCount, pPhysicalDevices)
+}
+
+/// One group per physical device. Only the output members are written, so
+/// `sType` and `pNext` are left as the caller set them.
+unsafe extern "C" fn vkEnumeratePhysicalDeviceGroups(instance: Instance,
+                                                     pPhysicalDeviceGroupCount: *mut u32,
+                                                     pPhysicalDeviceGroupProperties: *mut PhysicalDeviceGroupProperties)
+                                                     -> Result {
+    let instance = &*(instance as *const MockInstance);
+    let handles = instance.physical_devices(pPhysicalDeviceGroupProperties.is_null());
+    if pPhysicalDeviceGroupProperties.is_null() {
+        *pPhysicalDeviceGroupCount = handles.len() as u32;
+        return Result::SUCCESS;
+    }
+    let n = ::std::cmp::min(*pPhysicalDeviceGroupCount as usize, handles.len());
+    let groups = slice::from_raw_parts_mut(pPhysicalDeviceGroupProperties, n);
+    if groups.iter().any(|g| g.sType != StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES) {
+        return Result::ERROR_VALIDATION_FAILED;
+    }
+    for (group, &handle) in groups.iter_mut().zip(&handles) {
+        group.physicalDeviceCount = 1;
+        group.physicalDevices = [null_mut(); MAX_DEVICE_GROUP_SIZE as usize];
+        group.physicalDevices[0] = handle;
+        group.subsetAllocation = FALSE;
+    }
+    *pPhysicalDeviceGroupCount = n as u32;
+    if n < handles.len() { Result::INCOMPLETE } else { Result::SUCCESS }
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceFeatures(physicalDevice: PhysicalDevice,
+                                                 pFeatures: *mut PhysicalDeviceFeatures) {
+    *pFeatures = phys(physicalDevice).config.features;
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceFormatProperties(physicalDevice: PhysicalDevice,
+                                                         format: Format,
+                                                         pFormatProperties: *mut FormatProperties) {
+    *pFormatProperties = phys(physicalDevice).format_properties(format);
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceImageFormatProperties(physicalDevice: PhysicalDevice,
+                                                              format: Format,
+                                                              _type: ImageType,
+                                                              tiling: ImageTiling,
+                                                              _usage: ImageUsageFlags,
+                                                              _flags: ImageCreateFlags,
+                                                              pImageFormatProperties: *mut ImageFormatProperties)
+                                                              -> Result {
+    let physical_device = phys(physicalDevice);
+    let props = physical_device.format_properties(format);
+    let features = if tiling == ImageTiling::LINEAR {
+        props.linearTilingFeatures
+    } else {
+        props.optimalTilingFeatures
+    };
+    if features.0 == 0 {
+        return Result::ERROR_FORMAT_NOT_SUPPORTED;
+    }
+    let limits = &physical_device.config.properties.limits;
+    *pImageFormatProperties = ImageFormatProperties {
+        maxExtent: Extent3D {
+            width: limits.maxImageDimension2D,
+            height: limits.maxImageDimension2D,
+            depth: limits.maxImageDimension3D,
+        },
+        maxMipLevels: 32 - limits.maxImageDimension2D.leading_zeros(),
+        maxArrayLayers: limits.maxImageArrayLayers,
+        sampleCounts: limits.sampledImageColorSampleCounts,
+        maxResourceSize: 1 << 31,
+    };
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceProperties(physicalDevice: PhysicalDevice,
+                                                   pProperties: *mut PhysicalDeviceProperties) {
+    *pProperties = phys(physicalDevice).config.properties;
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice: PhysicalDevice,
+                                                              pQueueFamilyPropertyCount: *mut u32,
+                                                              pQueueFamilyProperties: *mut QueueFamilyProperties) {
+    write_array(&phys(physicalDevice).config.queue_families,
+                pQueueFamilyPropertyCount, pQueueFamilyProperties);
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceMemoryProperties(physicalDevice: PhysicalDevice,
+                                                         pMemoryProperties: *mut PhysicalDeviceMemoryProperties) {
+    *pMemoryProperties = phys(physicalDevice).config.memory_properties;
+}
+
+unsafe extern "C" fn vkGetPhysicalDeviceSparseImageFormatProperties(_physicalDevice: PhysicalDevice,
+                                                                    _format: Format,
+                                                                    _type: ImageType,
+                                                                    _samples: SampleCountFlag,
+                                                                    _usage: ImageUsageFlags,
+                                                                    _tiling: ImageTiling,
+                                                                    pPropertyCount: *mut u32,
+                                                                    pProperties: *mut SparseImageFormatProperties) {
+    write_array(&[], pPropertyCount, pProperties);
+}
+
+unsafe extern "C" fn vkEnumerateDeviceExtensionProperties(_physicalDevice: PhysicalDevice,
+                                                          pLayerName: *const c_char,
+                                                          pPropertyCount: *mut u32,
+                                                          pProperties: *mut ExtensionProperties)
+                                                          -> Result {
+    if !pLayerName.is_null() {
+        return Result::ERROR_LAYER_NOT_PRESENT;
+    }
+    write_array(&extension_properties(DEVICE_EXTENSIONS), pPropertyCount, pProperties)
+}
+
+unsafe extern "C" fn vkEnumerateDeviceLayerProperties(_physicalDevice: PhysicalDevice,
+                                                      pPropertyCount: *mut u32,
+                                                      pProperties: *mut LayerProperties) -> Result {
+    write_array(&[], pPropertyCount, pProperties)
+}
+
+impl MockPhysicalDevice {
+    fn format_properties(&self, format: Format) -> FormatProperties {
+        self.config.format_properties.iter()
+            .find(|&&(f, _)| f == format)
+            .map(|&(_, props)| props)
+            .unwrap_or_default()
+    }
+}
+
+unsafe extern "C" fn vkCreateDevice(physicalDevice: PhysicalDevice,
+                                    pCreateInfo: *const DeviceCreateInfo,
+                                    _pAllocator: *const AllocationCallbacks,
+                                    pDevice: *mut Device) -> Result {
+    let physical_device = phys(physicalDevice);
+    let info = &*pCreateInfo;
+    if info.enabledLayerCount > 0 {
+        return Result::ERROR_LAYER_NOT_PRESENT;
+    }
+    if !extensions_supported(DEVICE_EXTENSIONS, info.enabledExtensionCount,
+                             info.ppEnabledExtensionNames) {
+        return Result::ERROR_EXTENSION_NOT_PRESENT;
+    }
+    if !info.pEnabledFeatures.is_null() {
+        let n = mem::size_of::<PhysicalDeviceFeatures>() / mem::size_of::<Bool32>();
+        let requested = array(info.pEnabledFeatures as *const Bool32, n);
+        let supported = array(&physical_device.config.features as *const _
+                                              as *const Bool32, n);
+        if requested.iter().zip(supported).any(|(&r, &s)| r != FALSE && s == FALSE) {
+            return Result::ERROR_FEATURE_NOT_PRESENT;
+        }
+    }
+
+    let mut device = Box::new(MockDevice {
+        physical_device,
+        queues: Vec::new(),
+        state: Mutex::new(DeviceState {
+            objects: HashMap::new(),
+            heap_usage: vec![0; physical_device.config.memory_properties.memoryHeapCount as usize],
+            allocation_count: 0,
+            errors: Vec::new(),
+        }),
+    });
+    let device_ptr = &*device as *const MockDevice;
+    let queue_infos = array(info.pQueueCreateInfos,
+                                            info.queueCreateInfoCount as usize);
+    for queue_info in queue_infos {
+        let family = match physical_device.config.queue_families.get(queue_info.queueFamilyIndex as usize) {
+            Some(family) => family,
+            None => return Result::ERROR_INITIALIZATION_FAILED,
+        };
+        if queue_info.queueCount > family.queueCount {
+            return Result::ERROR_INITIALIZATION_FAILED;
+        }
+        for index in 0..queue_info.queueCount {
+            device.queues.push(MockQueue {
+                device: device_ptr,
+                family: queue_info.queueFamilyIndex,
+                index,
+            });
+        }
+    }
+    *pDevice = Box::into_raw(device) as Device;
+    Result::SUCCESS
+}
+
+// Device commands
+
+unsafe extern "C" fn vkDestroyDevice(device: Device, _pAllocator: *const AllocationCallbacks) {
+    if device.is_null() {
+        return;
+    }
+    let device = Box::from_raw(device as *mut MockDevice);
+    let state = device.state.into_inner().unwrap_or_else(PoisonError::into_inner);
+    for (handle, tracked) in state.objects {
+        if let Object::CommandBuffer { .. } = tracked.object {
+            drop(Box::from_raw(handle as usize as *mut MockCommandBuffer));
+        }
+    }
+}
+
+unsafe extern "C" fn vkGetDeviceQueue(device: Device, queueFamilyIndex: u32,
+                                      queueIndex: u32, pQueue: *mut Queue) {
+    let device = dev(device);
+    *pQueue = match device.queues.iter().find(|q| q.family == queueFamilyIndex &&
+                                                  q.index == queueIndex) {
+        Some(queue) => queue as *const MockQueue as Queue,
+        None => {
+            device.error(format!("queue {} of family {} was not created",
+                                 queueIndex, queueFamilyIndex));
+            null_mut()
+        }
+    };
+}
+
+unsafe fn signal_fence(device: &MockDevice, fence: Fence) {
+    if !fence.is_null() {
+        device.with(ObjectType::FENCE, from_handle(fence), |fence| {
+            if let Object::Fence { ref mut signaled } = *fence {
+                *signaled = true;
+            }
+        });
+    }
+}
+
+unsafe extern "C" fn vkQueueSubmit(queue: Queue, submitCount: u32,
+                                   pSubmits: *const SubmitInfo, fence: Fence) -> Result {
+    let device = &*(*(queue as *const MockQueue)).device;
+    for submit in array(pSubmits, submitCount as usize) {
+        let command_buffers = array(submit.pCommandBuffers,
+                                    submit.commandBufferCount as usize);
+        for &command_buffer in command_buffers {
+            let commands = cmd(command_buffer).commands.lock();
+            if commands.unwrap_or_else(PoisonError::into_inner).recording {
+                device.error(format!("submitting command buffer {:p} which is still recording",
+                                     command_buffer));
+            }
+        }
+    }
+    signal_fence(device, fence);
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkQueueWaitIdle(_queue: Queue) -> Result {
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkDeviceWaitIdle(_device: Device) -> Result {
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkAllocateMemory(device: Device,
+                                      pAllocateInfo: *const MemoryAllocateInfo,
+                                      _pAllocator: *const AllocationCallbacks,
+                                      pMemory: *mut DeviceMemory) -> Result {
+    let device = dev(device);
+    let info = &*pAllocateInfo;
+    let props = &device.physical().config.properties;
+    let memory_properties = &device.physical().config.memory_properties;
+    if info.memoryTypeIndex >= memory_properties.memoryTypeCount {
+        device.error(format!("memory type {} does not exist", info.memoryTypeIndex));
+        return Result::ERROR_OUT_OF_DEVICE_MEMORY;
+    }
+    let heap = memory_properties.memoryTypes[info.memoryTypeIndex as usize].heapIndex as usize;
+    let dedicated = match info.find_next::<MemoryDedicatedAllocateInfo>() {
+        Some(dedicated) if !dedicated.image.is_null() =>
+            Some((ObjectType::IMAGE, from_handle(dedicated.image))),
+        Some(dedicated) if !dedicated.buffer.is_null() =>
+            Some((ObjectType::BUFFER, from_handle(dedicated.buffer))),
+        _ => None,
+    };
+    if let Some((kind, handle)) = dedicated {
+        device.with(kind, handle, |_| ());
+    }
+    {
+        let mut state = device.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.allocation_count >= props.limits.maxMemoryAllocationCount {
+            return Result::ERROR_TOO_MANY_OBJECTS;
+        }
+        match state.heap_usage[heap].checked_add(info.allocationSize) {
+            Some(usage) if usage <= memory_properties.memoryHeaps[heap].size =>
+                state.heap_usage[heap] = usage,
+            _ => return Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        }
+        state.allocation_count += 1;
+    }
+    *pMemory = to_handle(device.create(ObjectType::DEVICE_MEMORY, Object::Memory {
+        size: info.allocationSize,
+        memory_type: info.memoryTypeIndex,
+        data: None,
+        mapped: false,
+        dedicated,
+    }));
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkFreeMemory(device: Device, memory: DeviceMemory,
+                                  _pAllocator: *const AllocationCallbacks) {
+    let device = dev(device);
+    if let Some(Object::Memory { size, memory_type, .. }) =
+            device.destroy(ObjectType::DEVICE_MEMORY, from_handle(memory)) {
+        let heap = device.physical().config.memory_properties
+                         .memoryTypes[memory_type as usize].heapIndex as usize;
+        let mut state = device.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.heap_usage[heap] -= size;
+        state.allocation_count -= 1;
+    }
+}
+
+unsafe extern "C" fn vkMapMemory(device: Device, memory: DeviceMemory,
+                                 offset: DeviceSize, size: DeviceSize,
+                                 _flags: MemoryMapFlags, ppData: *mut *mut c_void) -> Result {
+    let device = dev(device);
+    let memory_properties = &device.physical().config.memory_properties;
+    let result = device.with(ObjectType::DEVICE_MEMORY, from_handle(memory), |object| {
+        let (memory_size, memory_type, data, mapped) = match *object {
+            Object::Memory { size, memory_type, ref mut data, ref mut mapped, .. } =>
+                (size, memory_type, data, mapped),
+            _ => unreachable!(),
+        };
+        let flags = memory_properties.memoryTypes[memory_type as usize].propertyFlags;
+        if !flags.contains(MemoryPropertyFlag::HOST_VISIBLE) {
+            return Err((Result::ERROR_MEMORY_MAP_FAILED, "is not host visible".to_owned()));
+        }
+        if *mapped {
+            return Err((Result::ERROR_MEMORY_MAP_FAILED, "is already mapped".to_owned()));
+        }
+        let end = if size == WHOLE_SIZE { Some(memory_size) } else { offset.checked_add(size) };
+        match end {
+            Some(end) if offset < memory_size && size != 0 && end <= memory_size => { }
+            _ => return Err((Result::ERROR_MEMORY_MAP_FAILED,
+                             format!("of {} bytes has no range of {} bytes at {}",
+                                     memory_size, size, offset))),
+        }
+        if data.is_none() {
+            let mut bytes = Vec::new();
+            if memory_size > usize::MAX as DeviceSize ||
+               bytes.try_reserve_exact(memory_size as usize).is_err() {
+                return Err((Result::ERROR_OUT_OF_HOST_MEMORY,
+                            format!("of {} bytes cannot be backed", memory_size)));
+            }
+            bytes.resize(memory_size as usize, 0);
+            *data = Some(bytes);
+        }
+        *mapped = true;
+        let bytes = data.as_mut().map_or(null_mut(), |bytes| bytes.as_mut_ptr());
+        Ok(bytes.offset(offset as isize) as *mut c_void)
+    });
+    match result {
+        Some(Ok(ptr)) => {
+            *ppData = ptr;
+            Result::SUCCESS
+        }
+        Some(Err((result, msg))) => {
+            device.error(format!("mapping memory {:?}, which {}", memory, msg));
+            result
+        }
+        None => Result::ERROR_MEMORY_MAP_FAILED,
+    }
+}
+
+unsafe extern "C" fn vkUnmapMemory(device: Device, memory: DeviceMemory) {
+    let device = dev(device);
+    let was_mapped = device.with(ObjectType::DEVICE_MEMORY, from_handle(memory), |memory| {
+        match *memory {
+            Object::Memory { ref mut mapped, .. } => mem::replace(mapped, false),
+            _ => false,
+        }
+    });
+    if was_mapped == Some(false) {
+        device.error(format!("unmapping memory {:?}, which is not mapped", memory));
+    }
+}
+
+unsafe extern "C" fn vkFlushMappedMemoryRanges(_device: Device, _memoryRangeCount: u32,
+                                               _pMemoryRanges: *const MappedMemoryRange) -> Result {
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkInvalidateMappedMemoryRanges(_device: Device, _memoryRangeCount: u32,
+                                                    _pMemoryRanges: *const MappedMemoryRange) -> Result {
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkGetDeviceMemoryCommitment(device: Device, memory: DeviceMemory,
+                                                 pCommittedMemoryInBytes: *mut DeviceSize) {
+    *pCommittedMemoryInBytes = dev(device).with(ObjectType::DEVICE_MEMORY, from_handle(memory), |memory| {
+        match *memory {
+            Object::Memory { size, .. } => size,
+            _ => 0,
+        }
+    }).unwrap_or(0);
+}
+
+unsafe extern "C" fn vkBindBufferMemory(device: Device, buffer: Buffer,
+                                        memory: DeviceMemory, _memoryOffset: DeviceSize) -> Result {
+    let device = dev(device);
+    device.with(ObjectType::BUFFER, from_handle(buffer), |_| ());
+    device.with(ObjectType::DEVICE_MEMORY, from_handle(memory), |_| ());
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkBindImageMemory(device: Device, image: Image,
+                                       memory: DeviceMemory, _memoryOffset: DeviceSize) -> Result {
+    let device = dev(device);
+    device.with(ObjectType::IMAGE, from_handle(image), |_| ());
+    device.with(ObjectType::DEVICE_MEMORY, from_handle(memory), |_| ());
+    Result::SUCCESS
+}
+
+fn align_up(size: DeviceSize, alignment: DeviceSize) -> DeviceSize {
+    size.div_ceil(alignment) * alignment
+}
+
+unsafe fn memory_requirements(device: &MockDevice, size: DeviceSize,
+                              alignment: DeviceSize) -> MemoryRequirements {
+    let type_count = device.physical().config.memory_properties.memoryTypeCount;
+    MemoryRequirements {
+        size: align_up(size, alignment),
+        alignment,
+        memoryTypeBits: if type_count >= 32 { !0 } else { (1 << type_count) - 1 },
+    }
+}
+
+unsafe extern "C" fn vkGetBufferMemoryRequirements(device: Device, buffer: Buffer,
+                                                   pMemoryRequirements: *mut MemoryRequirements) {
+    let device = dev(device);
+    let size = device.with(ObjectType::BUFFER, from_handle(buffer), |buffer| {
+        match *buffer { Object::Buffer { size } => size, _ => 0 }
+    }).unwrap_or(0);
+    *pMemoryRequirements = memory_requirements(device, size, 256);
+}
+
+unsafe extern "C" fn vkGetImageMemoryRequirements(device: Device, image: Image,
+                                                  pMemoryRequirements: *mut MemoryRequirements) {
+    let device = dev(device);
+    let size = device.with(ObjectType::IMAGE, from_handle(image), |image| {
+        match *image { Object::Image { size } => size, _ => 0 }
+    }).unwrap_or(0);
+    *pMemoryRequirements = memory_requirements(device, size, 4096);
+}
+
+/// Fills in `MemoryDedicatedRequirements`, if it is chained onto
+/// `requirements`.
+unsafe fn dedicated_requirements(requirements: *mut MemoryRequirements2, prefers: bool) {
+    let mut next = (*requirements).pNext as *mut BaseOutStructure;
+    while !next.is_null() {
+        if (*next).sType == StructureType::MEMORY_DEDICATED_REQUIREMENTS {
+            let dedicated = &mut *(next as *mut MemoryDedicatedRequirements);
+            dedicated.prefersDedicatedAllocation = prefers.into();
+            dedicated.requiresDedicatedAllocation = FALSE;
+        }
+        next = (*next).pNext;
+    }
+}
+
+unsafe extern "C" fn vkGetBufferMemoryRequirements2(device: Device,
+                                                    pInfo: *const BufferMemoryRequirementsInfo2,
+                                                    pMemoryRequirements: *mut MemoryRequirements2) {
+    vkGetBufferMemoryRequirements(device, (*pInfo).buffer,
+                                  &mut (*pMemoryRequirements).memoryRequirements);
+    dedicated_requirements(pMemoryRequirements, false);
+}
+
+/// Images of 16 MiB or more prefer a dedicated allocation, as large render
+/// targets do on many drivers.
+unsafe extern "C" fn vkGetImageMemoryRequirements2(device: Device,
+                                                   pInfo: *const ImageMemoryRequirementsInfo2,
+                                                   pMemoryRequirements: *mut MemoryRequirements2) {
+    let requirements = &mut (*pMemoryRequirements).memoryRequirements;
+    vkGetImageMemoryRequirements(device, (*pInfo).image, requirements);
+    let prefers = requirements.size >= 16 << 20;
+    dedicated_requirements(pMemoryRequirements, prefers);
+}
+
+unsafe extern "C" fn vkGetImageSparseMemoryRequirements(_device: Device, _image: Image,
+                                                        pSparseMemoryRequirementCount: *mut u32,
+                                                        pSparseMemoryRequirements: *mut SparseImageMemoryRequirements) {
+    write_array(&[], pSparseMemoryRequirementCount, pSparseMemoryRequirements);
+}
+
+unsafe extern "C" fn vkQueueBindSparse(queue: Queue, _bindInfoCount: u32,
+                                       _pBindInfo: *const BindSparseInfo, fence: Fence) -> Result {
+    signal_fence(&*(*(queue as *const MockQueue)).device, fence);
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkCreateFence(device: Device, pCreateInfo: *const FenceCreateInfo,
+                                   _pAllocator: *const AllocationCallbacks,
+                                   pFence: *mut Fence) -> Result {
+    let signaled = (*pCreateInfo).flags.contains(FenceCreateFlag::SIGNALED);
+    *pFence = to_handle(dev(device).create(ObjectType::FENCE, Object::Fence { signaled }));
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkDestroyFence(device: Device, fence: Fence,
+                                    _pAllocator: *const AllocationCallbacks) {
+    dev(device).destroy(ObjectType::FENCE, from_handle(fence));
+}
+
+unsafe fn fence_signaled(device: &MockDevice, fence: Fence) -> bool {
+    device.with(ObjectType::FENCE, from_handle(fence), |fence| {
+        match *fence { Object::Fence { signaled } => signaled, _ => false }
+    }).unwrap_or(false)
+}
+
+unsafe extern "C" fn vkResetFences(device: Device, fenceCount: u32, pFences: *const Fence) -> Result {
+    let device = dev(device);
+    for &fence in array(pFences, fenceCount as usize) {
+        device.with(ObjectType::FENCE, from_handle(fence), |fence| {
+            if let Object::Fence { ref mut signaled } = *fence {
+                *signaled = false;
+            }
+        });
+    }
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkGetFenceStatus(device: Device, fence: Fence) -> Result {
+    if fence_signaled(dev(device), fence) { Result::SUCCESS } else { Result::NOT_READY }
+}
+
+unsafe extern "C" fn vkWaitForFences(device: Device, fenceCount: u32, pFences: *const Fence,
+                                     waitAll: Bool32, _timeout: u64) -> Result {
+    // Nothing is ever in flight, so a fence which is not signaled now never will be.
+    let device = dev(device);
+    let fences = array(pFences, fenceCount as usize);
+    let done = if waitAll != FALSE {
+        fences.iter().all(|&f| fence_signaled(device, f))
+    } else {
+        fences.iter().any(|&f| fence_signaled(device, f))
+    };
+    if done { Result::SUCCESS } else { Result::TIMEOUT }
+}
+
+unsafe extern "C" fn vkCreateEvent(device: Device, _pCreateInfo: *const EventCreateInfo,
+                                   _pAllocator: *const AllocationCallbacks,
+                                   pEvent: *mut Event) -> Result {
+    *pEvent = to_handle(dev(device).create(ObjectType::EVENT, Object::Event { set: false }));
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkDestroyEvent(device: Device, event: Event,
+                                    _pAllocator: *const AllocationCallbacks) {
+    dev(device).destroy(ObjectType::EVENT, from_handle(event));
+}
+
+unsafe fn set_event(device: Device, event: Event, value: bool) -> Result {
+    dev(device).with(ObjectType::EVENT, from_handle(event), |event| {
+        if let Object::Event { ref mut set } = *event {
+            *set = value;
+        }
+    });
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkGetEventStatus(device: Device, event: Event) -> Result {
+    let set = dev(device).with(ObjectType::EVENT, from_handle(event), |event| {
+        match *event { Object::Event { set } => set, _ => false }
+    }).unwrap_or(false);
+    if set { Result::EVENT_SET } else { Result::EVENT_RESET }
+}
+
+unsafe extern "C" fn vkSetEvent(device: Device, event: Event) -> Result {
+    set_event(device, event, true)
+}
+
+unsafe extern "C" fn vkResetEvent(device: Device, event: Event) -> Result {
+    set_event(device, event, false)
+}
+
+unsafe extern "C" fn vkGetQueryPoolResults(device: Device, queryPool: QueryPool,
+                                           _firstQuery: u32, _queryCount: u32,
+                                           dataSize: usize, pData: *mut c_void,
+                                           _stride: DeviceSize, _flags: QueryResultFlags) -> Result {
+    dev(device).with(ObjectType::QUERY_POOL, from_handle(queryPool), |_| ());
+    ::std::ptr::write_bytes(pData as *mut u8, 0, dataSize);
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkCreateBuffer(device: Device, pCreateInfo: *const BufferCreateInfo,
+                                    _pAllocator: *const AllocationCallbacks,
+                                    pBuffer: *mut Buffer) -> Result {
+    let size = (*pCreateInfo).size;
+    *pBuffer = to_handle(dev(device).create(ObjectType::BUFFER, Object::Buffer { size }));
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkDestroyBuffer(device: Device, buffer: Buffer,
+                                     _pAllocator: *const AllocationCallbacks) {
+    dev(device).destroy(ObjectType::BUFFER, from_handle(buffer));
+}
+
+unsafe extern "C" fn vkCreateImage(device: Device, pCreateInfo: *const ImageCreateInfo,
+                                   _pAllocator: *const AllocationCallbacks,
+                                   pImage: *mut Image) -> Result {
+    // Assume four bytes per texel, and that a full mip chain at most doubles the size.
+    let info = &*pCreateInfo;
+    let texels = info.extent.width as DeviceSize * info.extent.height as DeviceSize *
+                 info.extent.depth as DeviceSize * info.arrayLayers as DeviceSize *
+                 info.samples.0 as DeviceSize;
+    let size = texels * 4 * if info.mipLevels > 1 { 2 } else { 1 };
+    *pImage = to_handle(dev(device).create(ObjectType::IMAGE, Object::Image { size }));
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkDestroyImage(device: Device, image: Image,
+                                    _pAllocator: *const AllocationCallbacks) {
+    dev(device).destroy(ObjectType::IMAGE, from_handle(image));
+}
+
+unsafe extern "C" fn vkGetImageSubresourceLayout(device: Device, image: Image,
+                                                 _pSubresource: *const ImageSubresource,
+                                                 pLayout: *mut SubresourceLayout) {
+    let size = dev(device).with(ObjectType::IMAGE, from_handle(image), |image| {
+        match *image { Object::Image { size } => size, _ => 0 }
+    }).unwrap_or(0);
+    *pLayout = SubresourceLayout {
+        offset: 0,
+        size,
+        rowPitch: 0,
+        arrayPitch: 0,
+        depthPitch: 0,
+    };
+}
+
+unsafe extern "C" fn vkGetPipelineCacheData(device: Device, pipelineCache: PipelineCache,
+                                            pDataSize: *mut usize, _pData: *mut c_void) -> Result {
+    dev(device).with(ObjectType::PIPELINE_CACHE, from_handle(pipelineCache), |_| ());
+    *pDataSize = 0;
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkMergePipelineCaches(_device: Device, _dstCache: PipelineCache,
+                                           _srcCacheCount: u32,
+                                           _pSrcCaches: *const PipelineCache) -> Result {
+    Result::SUCCESS
+}
+
+// Nothing is ever cached, so a pipeline which may not be compiled is left
+// null and the result is `PIPELINE_COMPILE_REQUIRED`.
+unsafe fn create_pipelines<I>(device: Device, flags: I, pPipelines: *mut Pipeline) -> Result
+    where I: Iterator<Item = PipelineCreateFlags>
+{
+    let device = dev(device);
+    let mut res = Result::SUCCESS;
+    for (i, flags) in flags.enumerate() {
+        let fail = flags.contains(PipelineCreateFlag::FAIL_ON_PIPELINE_COMPILE_REQUIRED);
+        *pPipelines.add(i) = if fail {
+            res = Result::PIPELINE_COMPILE_REQUIRED;
+            Pipeline::null()
+        } else {
+            to_handle(device.create(ObjectType::PIPELINE, Object::Plain))
+        };
+    }
+    res
+}
+
+unsafe extern "C" fn vkCreateGraphicsPipelines(device: Device, _pipelineCache: PipelineCache,
+                                               createInfoCount: u32,
+                                               pCreateInfos: *const GraphicsPipelineCreateInfo,
+                                               _pAllocator: *const AllocationCallbacks,
+                                               pPipelines: *mut Pipeline) -> Result {
+    let infos = slice::from_raw_parts(pCreateInfos, createInfoCount as usize);
+    create_pipelines(device, infos.iter().map(|info| info.flags), pPipelines)
+}
+
+unsafe extern "C" fn vkCreateComputePipelines(device: Device, _pipelineCache: PipelineCache,
+                                              createInfoCount: u32,
+                                              pCreateInfos: *const ComputePipelineCreateInfo,
+                                              _pAllocator: *const AllocationCallbacks,
+                                              pPipelines: *mut Pipeline) -> Result {
+    let infos = slice::from_raw_parts(pCreateInfos, createInfoCount as usize);
+    create_pipelines(device, infos.iter().map(|info| info.flags), pPipelines)
+}
+
+unsafe extern "C" fn vkDestroyPipeline(device: Device, pipeline: Pipeline,
+                                       _pAllocator: *const AllocationCallbacks) {
+    dev(device).destroy(ObjectType::PIPELINE, from_handle(pipeline));
+}
+
+macro_rules! mock_objects {
+    ($($create:ident, $destroy:ident: $info:ident -> $handle:ident, $kind:ident;)*) => {$(
+        unsafe extern "C" fn $create(device: Device, _pCreateInfo: *const $info,
+                                     _pAllocator: *const AllocationCallbacks,
+                                     pHandle: *mut $handle) -> Result {
+            *pHandle = to_handle(dev(device).create(ObjectType::$kind, Object::Plain));
+            Result::SUCCESS
+        }
+
+        unsafe extern "C" fn $destroy(device: Device, handle: $handle,
+                                      _pAllocator: *const AllocationCallbacks) {
+            dev(device).destroy(ObjectType::$kind, from_handle(handle));
+        }
+    )*}
+}
+
+mock_objects!{
+    vkCreateSemaphore, vkDestroySemaphore: SemaphoreCreateInfo -> Semaphore, SEMAPHORE;
+    vkCreateQueryPool, vkDestroyQueryPool: QueryPoolCreateInfo -> QueryPool, QUERY_POOL;
+    vkCreateBufferView, vkDestroyBufferView: BufferViewCreateInfo -> BufferView, BUFFER_VIEW;
+    vkCreateImageView, vkDestroyImageView: ImageViewCreateInfo -> ImageView, IMAGE_VIEW;
+    vkCreateShaderModule, vkDestroyShaderModule: ShaderModuleCreateInfo -> ShaderModule, SHADER_MODULE;
+    vkCreatePipelineCache, vkDestroyPipelineCache: PipelineCacheCreateInfo -> PipelineCache, PIPELINE_CACHE;
+    vkCreatePipelineLayout, vkDestroyPipelineLayout: PipelineLayoutCreateInfo -> PipelineLayout, PIPELINE_LAYOUT;
+    vkCreateSampler, vkDestroySampler: SamplerCreateInfo -> Sampler, SAMPLER;
+    vkCreateDescriptorSetLayout, vkDestroyDescriptorSetLayout: DescriptorSetLayoutCreateInfo -> DescriptorSetLayout, DESCRIPTOR_SET_LAYOUT;
+    vkCreateFramebuffer, vkDestroyFramebuffer: FramebufferCreateInfo -> Framebuffer, FRAMEBUFFER;
+    vkCreateRenderPass, vkDestroyRenderPass: RenderPassCreateInfo -> RenderPass, RENDER_PASS;
+}
+
+unsafe extern "C" fn vkCreateDescriptorPool(device: Device,
+                                            _pCreateInfo: *const DescriptorPoolCreateInfo,
+                                            _pAllocator: *const AllocationCallbacks,
+                                            pDescriptorPool: *mut DescriptorPool) -> Result {
+    *pDescriptorPool = to_handle(dev(device).create(ObjectType::DESCRIPTOR_POOL, Object::Plain));
+    Result::SUCCESS
+}
+
+unsafe fn free_children<F>(device: &MockDevice, is_child: F) -> Vec<u64>
+    where F: Fn(&Object) -> bool
+{
+    let mut state = device.state.lock().unwrap_or_else(PoisonError::into_inner);
+    let children: Vec<u64> = state.objects.iter()
+        .filter(|&(_, tracked)| is_child(&tracked.object))
+        .map(|(&handle, _)| handle)
+        .collect();
+    for handle in &children {
+        state.objects.remove(handle);
+    }
+    children
+}
+
+unsafe extern "C" fn vkDestroyDescriptorPool(device: Device, descriptorPool: DescriptorPool,
+                                             _pAllocator: *const AllocationCallbacks) {
+    let device = dev(device);
+    let pool = from_handle(descriptorPool);
+    if device.destroy(ObjectType::DESCRIPTOR_POOL, pool).is_some() {
+        free_children(device, |o| match *o { Object::DescriptorSet { pool: p } => p == pool, _ => false });
+    }
+}
+
+unsafe extern "C" fn vkResetDescriptorPool(device: Device, descriptorPool: DescriptorPool,
+                                           _flags: DescriptorPoolResetFlags) -> Result {
+    let pool = from_handle(descriptorPool);
+    free_children(dev(device), |o| match *o { Object::DescriptorSet { pool: p } => p == pool, _ => false });
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkAllocateDescriptorSets(device: Device,
+                                              pAllocateInfo: *const DescriptorSetAllocateInfo,
+                                              pDescriptorSets: *mut DescriptorSet) -> Result {
+    let device = dev(device);
+    let info = &*pAllocateInfo;
+    let pool = from_handle(info.descriptorPool);
+    for i in 0..info.descriptorSetCount as isize {
+        *pDescriptorSets.offset(i) = to_handle(device.create(ObjectType::DESCRIPTOR_SET,
+                                                             Object::DescriptorSet { pool }));
+    }
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkFreeDescriptorSets(device: Device, _descriptorPool: DescriptorPool,
+                                          descriptorSetCount: u32,
+                                          pDescriptorSets: *const DescriptorSet) -> Result {
+    let device = dev(device);
+    for &set in array(pDescriptorSets, descriptorSetCount as usize) {
+        device.destroy(ObjectType::DESCRIPTOR_SET, from_handle(set));
+    }
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkUpdateDescriptorSets(_device: Device, _descriptorWriteCount: u32,
+                                            _pDescriptorWrites: *const WriteDescriptorSet,
+                                            _descriptorCopyCount: u32,
+                                            _pDescriptorCopies: *const CopyDescriptorSet) {
+}
+
+unsafe extern "C" fn vkGetRenderAreaGranularity(_device: Device, _renderPass: RenderPass,
+                                                pGranularity: *mut Extent2D) {
+    *pGranularity = Extent2D { width: 1, height: 1 };
+}
+
+unsafe extern "C" fn vkCreateCommandPool(device: Device,
+                                         _pCreateInfo: *const CommandPoolCreateInfo,
+                                         _pAllocator: *const AllocationCallbacks,
+                                         pCommandPool: *mut CommandPool) -> Result {
+    *pCommandPool = to_handle(dev(device).create(ObjectType::COMMAND_POOL, Object::Plain));
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkDestroyCommandPool(device: Device, commandPool: CommandPool,
+                                          _pAllocator: *const AllocationCallbacks) {
+    let device = dev(device);
+    let pool = from_handle(commandPool);
+    if device.destroy(ObjectType::COMMAND_POOL, pool).is_some() {
+        let freed = free_children(device, |o| match *o {
+            Object::CommandBuffer { pool: p } => p == pool,
+            _ => false,
+        });
+        for handle in freed {
+            drop(Box::from_raw(handle as usize as *mut MockCommandBuffer));
+        }
+    }
+}
+
+unsafe extern "C" fn vkResetCommandPool(device: Device, commandPool: CommandPool,
+                                        _flags: CommandPoolResetFlags) -> Result {
+    let device = dev(device);
+    let pool = from_handle(commandPool);
+    let buffers: Vec<u64> = {
+        let state = device.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.objects.iter()
+            .filter(|&(_, t)| match t.object {
+                Object::CommandBuffer { pool: p } => p == pool,
+                _ => false,
+            })
+            .map(|(&h, _)| h)
+            .collect()
+    };
+    for handle in buffers {
+        reset(&*(handle as usize as *const MockCommandBuffer));
+    }
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkAllocateCommandBuffers(device: Device,
+                                              pAllocateInfo: *const CommandBufferAllocateInfo,
+                                              pCommandBuffers: *mut CommandBuffer) -> Result {
+    let device_ptr = device as *const MockDevice;
+    let device = dev(device);
+    let info = &*pAllocateInfo;
+    let pool = from_handle(info.commandPool);
+    for i in 0..info.commandBufferCount as isize {
+        let command_buffer = Box::into_raw(Box::new(MockCommandBuffer {
+            device: device_ptr,
+            commands: Mutex::new(CommandBufferState { recording: false, commands: Vec::new() }),
+        }));
+        device.track(command_buffer as usize as u64, ObjectType::COMMAND_BUFFER,
+                     Object::CommandBuffer { pool });
+        *pCommandBuffers.offset(i) = command_buffer as CommandBuffer;
+    }
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkFreeCommandBuffers(device: Device, _commandPool: CommandPool,
+                                          commandBufferCount: u32,
+                                          pCommandBuffers: *const CommandBuffer) {
+    let device = dev(device);
+    for &command_buffer in array(pCommandBuffers, commandBufferCount as usize) {
+        if device.destroy(ObjectType::COMMAND_BUFFER, command_buffer as usize as u64).is_some() {
+            drop(Box::from_raw(command_buffer as *mut MockCommandBuffer));
+        }
+    }
+}
+
+unsafe fn reset(command_buffer: &MockCommandBuffer) {
+    let mut state = command_buffer.commands.lock().unwrap_or_else(PoisonError::into_inner);
+    state.recording = false;
+    state.commands.clear();
+}
+
+unsafe extern "C" fn vkBeginCommandBuffer(commandBuffer: CommandBuffer,
+                                          _pBeginInfo: *const CommandBufferBeginInfo) -> Result {
+    let mut state = cmd(commandBuffer).commands.lock().unwrap_or_else(PoisonError::into_inner);
+    state.recording = true;
+    state.commands.clear();
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkEndCommandBuffer(commandBuffer: CommandBuffer) -> Result {
+    let command_buffer = cmd(commandBuffer);
+    let mut state = command_buffer.commands.lock().unwrap_or_else(PoisonError::into_inner);
+    if !state.recording {
+        (*command_buffer.device).error(format!("ending command buffer {:p} which is not recording",
+                                               commandBuffer));
+    }
+    state.recording = false;
+    Result::SUCCESS
+}
+
+unsafe extern "C" fn vkResetCommandBuffer(commandBuffer: CommandBuffer,
+                                          _flags: CommandBufferResetFlags) -> Result {
+    reset(cmd(commandBuffer));
+    Result::SUCCESS
+}
+
+unsafe fn record(commandBuffer: CommandBuffer, name: &'static str) {
+    let command_buffer = cmd(commandBuffer);
+    let mut state = command_buffer.commands.lock().unwrap_or_else(PoisonError::into_inner);
+    if !state.recording {
+        (*command_buffer.device).error(format!("{} on command buffer {:p} which is not recording",
+                                               name, commandBuffer));
+    }
+    state.commands.push(name);
+}
+
+macro_rules! mock_cmds {
+    ($($cmd:ident($($arg:ident: $ty:ty),*);)*) => {$(
+        unsafe extern "C" fn $cmd(commandBuffer: CommandBuffer, $($arg: $ty),*) {
+            record(commandBuffer, stringify!($cmd));
+        }
+    )*}
+}
+
+mock_cmds!{
+    vkCmdBindPipeline(_pipelineBindPoint: PipelineBindPoint, _pipeline: Pipeline);
+    vkCmdSetViewport(_firstViewport: u32, _viewportCount: u32, _pViewports: *const Viewport);
+    vkCmdSetScissor(_firstScissor: u32, _scissorCount: u32, _pScissors: *const Rect2D);
+    vkCmdSetLineWidth(_lineWidth: f32);
+    vkCmdSetDepthBias(_depthBiasConstantFactor: f32, _depthBiasClamp: f32, _depthBiasSlopeFactor: f32);
+    vkCmdSetBlendConstants(_blendConstants: *mut f32);
+    vkCmdSetDepthBounds(_minDepthBounds: f32, _maxDepthBounds: f32);
+    vkCmdSetStencilCompareMask(_faceMask: StencilFaceFlags, _compareMask: u32);
+    vkCmdSetStencilWriteMask(_faceMask: StencilFaceFlags, _writeMask: u32);
+    vkCmdSetStencilReference(_faceMask: StencilFaceFlags, _reference: u32);
+    vkCmdBindDescriptorSets(_pipelineBindPoint: PipelineBindPoint, _layout: PipelineLayout,
+                            _firstSet: u32, _descriptorSetCount: u32,
+                            _pDescriptorSets: *const DescriptorSet, _dynamicOffsetCount: u32,
+                            _pDynamicOffsets: *const u32);
+    vkCmdBindIndexBuffer(_buffer: Buffer, _offset: DeviceSize, _indexType: IndexType);
+    vkCmdBindVertexBuffers(_firstBinding: u32, _bindingCount: u32, _pBuffers: *const Buffer,
+                           _pOffsets: *const DeviceSize);
+    vkCmdDraw(_vertexCount: u32, _instanceCount: u32, _firstVertex: u32, _firstInstance: u32);
+    vkCmdDrawIndexed(_indexCount: u32, _instanceCount: u32, _firstIndex: u32, _vertexOffset: i32,
+                     _firstInstance: u32);
+    vkCmdDrawIndirect(_buffer: Buffer, _offset: DeviceSize, _drawCount: u32, _stride: u32);
+    vkCmdDrawIndexedIndirect(_buffer: Buffer, _offset: DeviceSize, _drawCount: u32, _stride: u32);
+    vkCmdDispatch(_x: u32, _y: u32, _z: u32);
+    vkCmdDispatchIndirect(_buffer: Buffer, _offset: DeviceSize);
+    vkCmdCopyBuffer(_srcBuffer: Buffer, _dstBuffer: Buffer, _regionCount: u32,
+                    _pRegions: *const BufferCopy);
+    vkCmdCopyImage(_srcImage: Image, _srcImageLayout: ImageLayout, _dstImage: Image,
+                   _dstImageLayout: ImageLayout, _regionCount: u32, _pRegions: *const ImageCopy);
+    vkCmdBlitImage(_srcImage: Image, _srcImageLayout: ImageLayout, _dstImage: Image,
+                   _dstImageLayout: ImageLayout, _regionCount: u32, _pRegions: *const ImageBlit,
+                   _filter: Filter);
+    vkCmdCopyBufferToImage(_srcBuffer: Buffer, _dstImage: Image, _dstImageLayout: ImageLayout,
+                           _regionCount: u32, _pRegions: *const BufferImageCopy);
+    vkCmdCopyImageToBuffer(_srcImage: Image, _srcImageLayout: ImageLayout, _dstBuffer: Buffer,
+                           _regionCount: u32, _pRegions: *const BufferImageCopy);
+    vkCmdUpdateBuffer(_dstBuffer: Buffer, _dstOffset: DeviceSize, _dataSize: DeviceSize,
+                      _pData: *const u32);
+    vkCmdFillBuffer(_dstBuffer: Buffer, _dstOffset: DeviceSize, _size: DeviceSize, _data: u32);
+    vkCmdClearColorImage(_image: Image, _imageLayout: ImageLayout, _pColor: *const ClearColorValue,
+                         _rangeCount: u32, _pRanges: *const ImageSubresourceRange);
+    vkCmdClearDepthStencilImage(_image: Image, _imageLayout: ImageLayout,
+                                _pDepthStencil: *const ClearDepthStencilValue, _rangeCount: u32,
+                                _pRanges: *const ImageSubresourceRange);
+    vkCmdClearAttachments(_attachmentCount: u32, _pAttachments: *const ClearAttachment,
+                          _rectCount: u32, _pRects: *const ClearRect);
+    vkCmdResolveImage(_srcImage: Image, _srcImageLayout: ImageLayout, _dstImage: Image,
+                      _dstImageLayout: ImageLayout, _regionCount: u32, _pRegions: *const ImageResolve);
+    vkCmdSetEvent(_event: Event, _stageMask: PipelineStageFlags);
+    vkCmdResetEvent(_event: Event, _stageMask: PipelineStageFlags);
+    vkCmdWaitEvents(_eventCount: u32, _pEvents: *const Event, _srcStageMask: PipelineStageFlags,
+                    _dstStageMask: PipelineStageFlags, _memoryBarrierCount: u32,
+                    _pMemoryBarriers: *const MemoryBarrier, _bufferMemoryBarrierCount: u32,
+                    _pBufferMemoryBarriers: *const BufferMemoryBarrier,
+                    _imageMemoryBarrierCount: u32, _pImageMemoryBarriers: *const ImageMemoryBarrier);
+    vkCmdPipelineBarrier(_srcStageMask: PipelineStageFlags, _dstStageMask: PipelineStageFlags,
+                         _dependencyFlags: DependencyFlags, _memoryBarrierCount: u32,
+                         _pMemoryBarriers: *const MemoryBarrier, _bufferMemoryBarrierCount: u32,
+                         _pBufferMemoryBarriers: *const BufferMemoryBarrier,
+                         _imageMemoryBarrierCount: u32,
+                         _pImageMemoryBarriers: *const ImageMemoryBarrier);
+    vkCmdBeginQuery(_queryPool: QueryPool, _query: u32, _flags: QueryControlFlags);
+    vkCmdEndQuery(_queryPool: QueryPool, _query: u32);
+    vkCmdResetQueryPool(_queryPool: QueryPool, _firstQuery: u32, _queryCount: u32);
+    vkCmdWriteTimestamp(_pipelineStage: PipelineStageFlag, _queryPool: QueryPool, _query: u32);
+    vkCmdCopyQueryPoolResults(_queryPool: QueryPool, _firstQuery: u32, _queryCount: u32,
+                              _dstBuffer: Buffer, _dstOffset: DeviceSize, _stride: DeviceSize,
+                              _flags: QueryResultFlags);
+    vkCmdPushConstants(_layout: PipelineLayout, _stageFlags: ShaderStageFlags, _offset: u32,
+                       _size: u32, _pValues: *const c_void);
+    vkCmdBeginRenderPass(_pRenderPassBegin: *const RenderPassBeginInfo, _contents: SubpassContents);
+    vkCmdNextSubpass(_contents: SubpassContents);
+    vkCmdEndRenderPass();
+    vkCmdExecuteCommands(_commandBufferCount: u32, _pCommandBuffers: *const CommandBuffer);
+}
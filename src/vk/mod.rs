@@ -6,20 +6,46 @@ mod enums;
 mod flags;
 mod types;
 mod structs;
+mod builders;
 mod unions;
+#[cfg(feature = "static_link")]
 mod fns;
 mod pfns;
+mod loader;
+mod dispatch;
+mod names;
+mod strings;
+mod chain;
+mod result;
+mod enumeration;
+mod version;
+mod allocator;
 pub mod ext;
 pub mod khr;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "owned")]
+pub mod owned;
 
 pub use self::consts::*;
 pub use self::enums::*;
 pub use self::flags::*;
 pub use self::types::*;
 pub use self::structs::*;
+pub use self::builders::*;
 pub use self::unions::*;
+#[cfg(feature = "static_link")]
 pub use self::fns::*;
 pub use self::pfns::*;
+pub use self::loader::*;
+pub use self::dispatch::*;
+pub use self::names::*;
+pub use self::strings::*;
+pub use self::chain::*;
+pub use self::result::Error;
+pub use self::enumeration::*;
+pub use self::version::*;
+pub use self::allocator::*;
 
 pub fn make_version(major: u32, minor: u32, patch: u32) -> u32 {
     (major << 22) | (minor << 12) | patch
@@ -0,0 +1,60 @@
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt;
+
+/// The values an extension module adds to one enum, as recorded by
+/// `ext_enums!`.
+#[doc(hidden)]
+pub struct ExtEnumNames {
+    pub ty: fn() -> TypeId,
+    pub names: &'static [(&'static str, i32)],
+}
+
+fn ext_names(ty: TypeId) -> Vec<&'static [(&'static str, i32)]> {
+    let mut tables = Vec::new();
+    ::vk::khr::enum_names(&mut tables);
+    ::vk::ext::enum_names(&mut tables);
+    tables.iter()
+        .flat_map(|table| table.iter())
+        .filter(|e| (e.ty)() == ty)
+        .map(|e| e.names)
+        .collect()
+}
+
+#[doc(hidden)]
+pub fn ext_enum_name(ty: TypeId, value: i32) -> Option<&'static str> {
+    ext_names(ty).into_iter()
+        .flat_map(|names| names.iter())
+        .find(|&&(_, v)| v == value)
+        .map(|&(name, _)| name)
+}
+
+#[doc(hidden)]
+pub fn ext_enum_value(ty: TypeId, name: &str) -> Option<i32> {
+    ext_names(ty).into_iter()
+        .flat_map(|names| names.iter())
+        .find(|&&(n, _)| n == name)
+        .map(|&(_, value)| value)
+}
+
+/// The error from parsing an enum from a string which is none of its names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseEnumError {
+    ty: &'static str,
+    name: String,
+}
+
+impl ParseEnumError {
+    #[doc(hidden)]
+    pub fn new(ty: &'static str, name: &str) -> ParseEnumError {
+        ParseEnumError { ty, name: name.to_owned() }
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a {} value", self.name, self.ty)
+    }
+}
+
+impl Error for ParseEnumError {}
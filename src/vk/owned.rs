@@ -0,0 +1,325 @@
+//! Owned handles which call the matching `vkDestroy*` when dropped.
+//!
+//! An `Instance` or `Device` is shared as an `Arc`. Each object holds on to
+//! its parent, so a device is destroyed only after every object made from it,
+//! and an instance only after its devices. `handle()` is the raw handle for
+//! passing to commands.
+//!
+//! An `Instance` or `Device` keeps the `InstanceFns` or `DeviceFns` loaded
+//! for it, and every object is destroyed through its parent's table, so
+//! these work with any `Entry`, including `vk::mock::entry`. An `Instance`
+//! also keeps a clone of its `Entry`, so the loader library stays open for
+//! as long as anything made through it is alive.
+//!
+//! Command buffers and descriptor sets aren't wrapped: destroying their pool
+//! frees them.
+
+use std::error;
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ptr::{self, null, null_mut};
+use std::sync::Arc;
+
+use vk;
+use vk::{Error, LoadingError};
+
+/// Why `Instance::create` or `Device::create` failed.
+#[derive(Clone, Debug)]
+pub enum CreateError {
+    /// The `vkCreate*` command failed.
+    Vulkan(Error),
+    /// The object was made but its commands could not be loaded, so it was
+    /// destroyed again.
+    Loading(LoadingError),
+}
+
+impl fmt::Display for CreateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CreateError::Vulkan(ref err) => err.fmt(f),
+            CreateError::Loading(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for CreateError {}
+
+impl From<Error> for CreateError {
+    fn from(err: Error) -> CreateError {
+        CreateError::Vulkan(err)
+    }
+}
+
+impl From<LoadingError> for CreateError {
+    fn from(err: LoadingError) -> CreateError {
+        CreateError::Loading(err)
+    }
+}
+
+pub struct Instance {
+    handle: vk::Instance,
+    fns: Arc<vk::InstanceFns>,
+    entry: vk::Entry,
+}
+
+// Handles may be used from any thread. Of the commands on the instance
+// itself only `vkDestroyInstance` needs external synchronization, and `drop`
+// calls it once nothing else refers to the instance.
+unsafe impl Send for Instance {}
+unsafe impl Sync for Instance {}
+
+impl Instance {
+    /// Creates an instance through `entry` and loads its commands.
+    ///
+    /// # Safety
+    ///
+    /// `info` must be valid for `vkCreateInstance`.
+    pub unsafe fn create(entry: &vk::Entry, info: &vk::InstanceCreateInfo)
+        -> ::std::result::Result<Arc<Instance>, CreateError>
+    {
+        let mut handle = null_mut();
+        entry.vkCreateInstance(info, null(), &mut handle).result()?;
+        match vk::InstanceFns::load(entry, handle) {
+            Ok(fns) => Ok(Arc::new(Instance::from_raw(entry, handle, Arc::new(fns)))),
+            Err(err) => {
+                let name = b"vkDestroyInstance\0".as_ptr() as *const _;
+                let destroy: vk::PFN_vkDestroyInstance =
+                    ::std::mem::transmute(entry.vkGetInstanceProcAddr(handle, name));
+                if let Some(destroy) = destroy {
+                    destroy(handle, null());
+                }
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Takes ownership of `handle`.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been created through `entry` and not be destroyed
+    /// by anything else, and `fns` must have been loaded for it.
+    pub unsafe fn from_raw(entry: &vk::Entry, handle: vk::Instance, fns: Arc<vk::InstanceFns>)
+        -> Instance
+    {
+        Instance { handle, fns, entry: entry.clone() }
+    }
+
+    /// Gives up ownership of the handle without destroying it.
+    pub fn into_raw(self) -> vk::Instance {
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            drop(ptr::read(&this.fns));
+            drop(ptr::read(&this.entry));
+        }
+        this.handle
+    }
+
+    #[inline]
+    pub fn handle(&self) -> vk::Instance { self.handle }
+
+    #[inline]
+    pub fn fns(&self) -> &Arc<vk::InstanceFns> { &self.fns }
+
+    /// The `Entry` the instance was created through.
+    #[inline]
+    pub fn entry(&self) -> &vk::Entry { &self.entry }
+}
+
+impl fmt::Debug for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Instance").field("handle", &self.handle).finish()
+    }
+}
+
+impl Drop for Instance {
+    fn drop(&mut self) {
+        unsafe { self.fns.vkDestroyInstance(self.handle, null()) }
+    }
+}
+
+pub struct Device {
+    handle: vk::Device,
+    fns: Arc<vk::DeviceFns>,
+    instance: Arc<Instance>,
+}
+
+// As for `Instance`, with `vkDestroyDevice`.
+unsafe impl Send for Device {}
+unsafe impl Sync for Device {}
+
+impl Device {
+    /// Creates a device on `physical_device` and loads its commands.
+    ///
+    /// # Safety
+    ///
+    /// `physical_device` must be one of `instance`'s, and `info` valid for
+    /// `vkCreateDevice`.
+    pub unsafe fn create(instance: &Arc<Instance>, physical_device: vk::PhysicalDevice,
+                         info: &vk::DeviceCreateInfo)
+        -> ::std::result::Result<Arc<Device>, CreateError>
+    {
+        let mut handle = null_mut();
+        instance.fns().vkCreateDevice(physical_device, info, null(), &mut handle).result()?;
+        match vk::DeviceFns::load(instance.fns(), handle) {
+            Ok(fns) => Ok(Arc::new(Device::from_raw(instance.clone(), handle, Arc::new(fns)))),
+            Err(err) => {
+                let name = b"vkDestroyDevice\0".as_ptr() as *const _;
+                let destroy: vk::PFN_vkDestroyDevice =
+                    ::std::mem::transmute(instance.fns().vkGetDeviceProcAddr(handle, name));
+                if let Some(destroy) = destroy {
+                    destroy(handle, null());
+                }
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Takes ownership of `handle`.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been made from `instance` and not be destroyed by
+    /// anything else, and `fns` must have been loaded for it.
+    pub unsafe fn from_raw(instance: Arc<Instance>, handle: vk::Device, fns: Arc<vk::DeviceFns>)
+        -> Device
+    {
+        Device { handle, fns, instance }
+    }
+
+    /// Gives up ownership of the handle without destroying it.
+    pub fn into_raw(self) -> vk::Device {
+        let this = ManuallyDrop::new(self);
+        drop(unsafe { ptr::read(&this.fns) });
+        drop(unsafe { ptr::read(&this.instance) });
+        this.handle
+    }
+
+    #[inline]
+    pub fn handle(&self) -> vk::Device { self.handle }
+
+    #[inline]
+    pub fn fns(&self) -> &Arc<vk::DeviceFns> { &self.fns }
+
+    #[inline]
+    pub fn instance(&self) -> &Arc<Instance> { &self.instance }
+}
+
+impl fmt::Debug for Device {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Device")
+            .field("handle", &self.handle)
+            .field("instance", &self.instance)
+            .finish()
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        unsafe { self.fns.vkDestroyDevice(self.handle, null()) }
+    }
+}
+
+owned!{DeviceMemory, allocate: vkAllocateMemory(MemoryAllocateInfo), vkFreeMemory}
+owned!{Fence, create: vkCreateFence(FenceCreateInfo), vkDestroyFence}
+owned!{Semaphore, create: vkCreateSemaphore(SemaphoreCreateInfo), vkDestroySemaphore}
+owned!{Event, create: vkCreateEvent(EventCreateInfo), vkDestroyEvent}
+owned!{QueryPool, create: vkCreateQueryPool(QueryPoolCreateInfo), vkDestroyQueryPool}
+owned!{Buffer, create: vkCreateBuffer(BufferCreateInfo), vkDestroyBuffer}
+owned!{BufferView, create: vkCreateBufferView(BufferViewCreateInfo), vkDestroyBufferView}
+owned!{Image, create: vkCreateImage(ImageCreateInfo), vkDestroyImage}
+owned!{ImageView, create: vkCreateImageView(ImageViewCreateInfo), vkDestroyImageView}
+owned!{ShaderModule, create: vkCreateShaderModule(ShaderModuleCreateInfo), vkDestroyShaderModule}
+owned!{PipelineCache, create: vkCreatePipelineCache(PipelineCacheCreateInfo),
+       vkDestroyPipelineCache}
+owned!{PipelineLayout, create: vkCreatePipelineLayout(PipelineLayoutCreateInfo),
+       vkDestroyPipelineLayout}
+owned!{Pipeline, vkDestroyPipeline}
+owned!{Sampler, create: vkCreateSampler(SamplerCreateInfo), vkDestroySampler}
+owned!{DescriptorSetLayout, create: vkCreateDescriptorSetLayout(DescriptorSetLayoutCreateInfo),
+       vkDestroyDescriptorSetLayout}
+owned!{DescriptorPool, create: vkCreateDescriptorPool(DescriptorPoolCreateInfo),
+       vkDestroyDescriptorPool}
+owned!{RenderPass, create: vkCreateRenderPass(RenderPassCreateInfo), vkDestroyRenderPass}
+owned!{Framebuffer, create: vkCreateFramebuffer(FramebufferCreateInfo), vkDestroyFramebuffer}
+owned!{CommandPool, create: vkCreateCommandPool(CommandPoolCreateInfo), vkDestroyCommandPool}
+owned!{SamplerYcbcrConversion: DeviceFnsV1_1,
+       create: vkCreateSamplerYcbcrConversion(SamplerYcbcrConversionCreateInfo),
+       vkDestroySamplerYcbcrConversion}
+owned!{DescriptorUpdateTemplate: DeviceFnsV1_1,
+       create: vkCreateDescriptorUpdateTemplate(DescriptorUpdateTemplateCreateInfo),
+       vkDestroyDescriptorUpdateTemplate}
+owned!{PrivateDataSlot: DeviceFnsV1_3,
+       create: vkCreatePrivateDataSlot(PrivateDataSlotCreateInfo), vkDestroyPrivateDataSlot}
+
+impl RenderPass {
+    /// Makes a render pass with Vulkan 1.2's `vkCreateRenderPass2`.
+    ///
+    /// # Safety
+    ///
+    /// `fns` must have been loaded for `device`, and `info` must be valid
+    /// for `vkCreateRenderPass2`.
+    pub unsafe fn create2(device: &Arc<Device>, fns: &vk::DeviceFnsV1_2,
+                          info: &vk::RenderPassCreateInfo2)
+        -> ::std::result::Result<RenderPass, Error>
+    {
+        let mut handle = vk::RenderPass::null();
+        fns.vkCreateRenderPass2(device.handle(), info, null(), &mut handle).result()?;
+        Ok(RenderPass::from_raw(device.clone(), handle))
+    }
+}
+
+impl Pipeline {
+    /// One pipeline per element of `infos`. If creation fails, or returns
+    /// `PIPELINE_COMPILE_REQUIRED`, the pipelines which were made are
+    /// destroyed again and the result is the error.
+    ///
+    /// # Safety
+    ///
+    /// `infos` must be valid for `vkCreateGraphicsPipelines`.
+    pub unsafe fn create_graphics(device: &Arc<Device>, cache: Option<&PipelineCache>,
+                                  infos: &[vk::GraphicsPipelineCreateInfo])
+        -> ::std::result::Result<Vec<Pipeline>, Error>
+    {
+        let mut handles = vec![vk::Pipeline::null(); infos.len()];
+        let res = device.fns().vkCreateGraphicsPipelines(device.handle(), cache_handle(cache),
+                                                         infos.len() as u32, infos.as_ptr(),
+                                                         null(), handles.as_mut_ptr());
+        Pipeline::adopt(device, handles, res)
+    }
+
+    /// Like `create_graphics`, for compute pipelines.
+    ///
+    /// # Safety
+    ///
+    /// `infos` must be valid for `vkCreateComputePipelines`.
+    pub unsafe fn create_compute(device: &Arc<Device>, cache: Option<&PipelineCache>,
+                                 infos: &[vk::ComputePipelineCreateInfo])
+        -> ::std::result::Result<Vec<Pipeline>, Error>
+    {
+        let mut handles = vec![vk::Pipeline::null(); infos.len()];
+        let res = device.fns().vkCreateComputePipelines(device.handle(), cache_handle(cache),
+                                                        infos.len() as u32, infos.as_ptr(),
+                                                        null(), handles.as_mut_ptr());
+        Pipeline::adopt(device, handles, res)
+    }
+
+    // Anything but `SUCCESS` leaves some elements null, including
+    // `PIPELINE_COMPILE_REQUIRED`, so it is an error and the pipelines that
+    // were made are destroyed again.
+    unsafe fn adopt(device: &Arc<Device>, handles: Vec<vk::Pipeline>, res: vk::Result)
+        -> ::std::result::Result<Vec<Pipeline>, Error>
+    {
+        if res != vk::Result::SUCCESS {
+            for handle in handles.into_iter().filter(|handle| !handle.is_null()) {
+                device.fns().vkDestroyPipeline(device.handle(), handle, null());
+            }
+            return Err(Error(res));
+        }
+        Ok(handles.into_iter().map(|handle| Pipeline::from_raw(device.clone(), handle)).collect())
+    }
+}
+
+fn cache_handle(cache: Option<&PipelineCache>) -> vk::PipelineCache {
+    cache.map_or(vk::PipelineCache::null(), |cache| cache.handle())
+}
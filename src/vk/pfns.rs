@@ -731,3 +731,338 @@ pub type PFN_vkCmdExecuteCommands =
     ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
                                                commandBufferCount: u32,
                                                pCommandBuffers: *const CommandBuffer)>;
+pub type PFN_vkEnumerateInstanceVersion =
+    ::std::option::Option<unsafe extern "C" fn(pApiVersion: *mut u32)
+                              -> Result>;
+pub type PFN_vkEnumeratePhysicalDeviceGroups =
+    ::std::option::Option<unsafe extern "C" fn(instance: Instance,
+                                               pPhysicalDeviceGroupCount: *mut u32,
+                                               pPhysicalDeviceGroupProperties: *mut PhysicalDeviceGroupProperties)
+                              -> Result>;
+pub type PFN_vkGetPhysicalDeviceFeatures2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pFeatures: *mut PhysicalDeviceFeatures2)>;
+pub type PFN_vkGetPhysicalDeviceProperties2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pProperties: *mut PhysicalDeviceProperties2)>;
+pub type PFN_vkGetPhysicalDeviceFormatProperties2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               format: Format,
+                                               pFormatProperties: *mut FormatProperties2)>;
+pub type PFN_vkGetPhysicalDeviceImageFormatProperties2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pImageFormatInfo: *const PhysicalDeviceImageFormatInfo2,
+                                               pImageFormatProperties: *mut ImageFormatProperties2)
+                              -> Result>;
+pub type PFN_vkGetPhysicalDeviceQueueFamilyProperties2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pQueueFamilyPropertyCount: *mut u32,
+                                               pQueueFamilyProperties: *mut QueueFamilyProperties2)>;
+pub type PFN_vkGetPhysicalDeviceMemoryProperties2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pMemoryProperties: *mut PhysicalDeviceMemoryProperties2)>;
+pub type PFN_vkGetPhysicalDeviceSparseImageFormatProperties2 =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pFormatInfo: *const PhysicalDeviceSparseImageFormatInfo2,
+                                               pPropertyCount: *mut u32,
+                                               pProperties: *mut SparseImageFormatProperties2)>;
+pub type PFN_vkGetPhysicalDeviceExternalBufferProperties =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pExternalBufferInfo: *const PhysicalDeviceExternalBufferInfo,
+                                               pExternalBufferProperties: *mut ExternalBufferProperties)>;
+pub type PFN_vkGetPhysicalDeviceExternalFenceProperties =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pExternalFenceInfo: *const PhysicalDeviceExternalFenceInfo,
+                                               pExternalFenceProperties: *mut ExternalFenceProperties)>;
+pub type PFN_vkGetPhysicalDeviceExternalSemaphoreProperties =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pExternalSemaphoreInfo: *const PhysicalDeviceExternalSemaphoreInfo,
+                                               pExternalSemaphoreProperties: *mut ExternalSemaphoreProperties)>;
+pub type PFN_vkBindBufferMemory2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               bindInfoCount: u32,
+                                               pBindInfos: *const BindBufferMemoryInfo)
+                              -> Result>;
+pub type PFN_vkBindImageMemory2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               bindInfoCount: u32,
+                                               pBindInfos: *const BindImageMemoryInfo)
+                              -> Result>;
+pub type PFN_vkGetDeviceGroupPeerMemoryFeatures =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               heapIndex: u32,
+                                               localDeviceIndex: u32,
+                                               remoteDeviceIndex: u32,
+                                               pPeerMemoryFeatures: *mut PeerMemoryFeatureFlags)>;
+pub type PFN_vkCmdSetDeviceMask =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               deviceMask: u32)>;
+pub type PFN_vkCmdDispatchBase =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               baseGroupX: u32,
+                                               baseGroupY: u32,
+                                               baseGroupZ: u32,
+                                               groupCountX: u32,
+                                               groupCountY: u32,
+                                               groupCountZ: u32)>;
+pub type PFN_vkGetImageMemoryRequirements2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const ImageMemoryRequirementsInfo2,
+                                               pMemoryRequirements: *mut MemoryRequirements2)>;
+pub type PFN_vkGetBufferMemoryRequirements2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const BufferMemoryRequirementsInfo2,
+                                               pMemoryRequirements: *mut MemoryRequirements2)>;
+pub type PFN_vkGetImageSparseMemoryRequirements2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const ImageSparseMemoryRequirementsInfo2,
+                                               pSparseMemoryRequirementCount: *mut u32,
+                                               pSparseMemoryRequirements: *mut SparseImageMemoryRequirements2)>;
+pub type PFN_vkTrimCommandPool =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               commandPool: CommandPool,
+                                               flags: CommandPoolTrimFlags)>;
+pub type PFN_vkGetDeviceQueue2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pQueueInfo: *const DeviceQueueInfo2,
+                                               pQueue: *mut Queue)>;
+pub type PFN_vkCreateSamplerYcbcrConversion =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pCreateInfo: *const SamplerYcbcrConversionCreateInfo,
+                                               pAllocator: *const AllocationCallbacks,
+                                               pYcbcrConversion: *mut SamplerYcbcrConversion)
+                              -> Result>;
+pub type PFN_vkDestroySamplerYcbcrConversion =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               ycbcrConversion: SamplerYcbcrConversion,
+                                               pAllocator: *const AllocationCallbacks)>;
+pub type PFN_vkCreateDescriptorUpdateTemplate =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pCreateInfo: *const DescriptorUpdateTemplateCreateInfo,
+                                               pAllocator: *const AllocationCallbacks,
+                                               pDescriptorUpdateTemplate: *mut DescriptorUpdateTemplate)
+                              -> Result>;
+pub type PFN_vkDestroyDescriptorUpdateTemplate =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               descriptorUpdateTemplate: DescriptorUpdateTemplate,
+                                               pAllocator: *const AllocationCallbacks)>;
+pub type PFN_vkUpdateDescriptorSetWithTemplate =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               descriptorSet: DescriptorSet,
+                                               descriptorUpdateTemplate: DescriptorUpdateTemplate,
+                                               pData: *const ::std::os::raw::c_void)>;
+pub type PFN_vkGetDescriptorSetLayoutSupport =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pCreateInfo: *const DescriptorSetLayoutCreateInfo,
+                                               pSupport: *mut DescriptorSetLayoutSupport)>;
+pub type PFN_vkCmdDrawIndirectCount =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               buffer: Buffer,
+                                               offset: DeviceSize,
+                                               countBuffer: Buffer,
+                                               countBufferOffset: DeviceSize,
+                                               maxDrawCount: u32,
+                                               stride: u32)>;
+pub type PFN_vkCmdDrawIndexedIndirectCount =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               buffer: Buffer,
+                                               offset: DeviceSize,
+                                               countBuffer: Buffer,
+                                               countBufferOffset: DeviceSize,
+                                               maxDrawCount: u32,
+                                               stride: u32)>;
+pub type PFN_vkCreateRenderPass2 =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pCreateInfo: *const RenderPassCreateInfo2,
+                                               pAllocator: *const AllocationCallbacks,
+                                               pRenderPass: *mut RenderPass)
+                              -> Result>;
+pub type PFN_vkCmdBeginRenderPass2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pRenderPassBegin: *const RenderPassBeginInfo,
+                                               pSubpassBeginInfo: *const SubpassBeginInfo)>;
+pub type PFN_vkCmdNextSubpass2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pSubpassBeginInfo: *const SubpassBeginInfo,
+                                               pSubpassEndInfo: *const SubpassEndInfo)>;
+pub type PFN_vkCmdEndRenderPass2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pSubpassEndInfo: *const SubpassEndInfo)>;
+pub type PFN_vkResetQueryPool =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               queryPool: QueryPool,
+                                               firstQuery: u32,
+                                               queryCount: u32)>;
+pub type PFN_vkGetSemaphoreCounterValue =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               semaphore: Semaphore,
+                                               pValue: *mut u64)
+                              -> Result>;
+pub type PFN_vkWaitSemaphores =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pWaitInfo: *const SemaphoreWaitInfo,
+                                               timeout: u64)
+                              -> Result>;
+pub type PFN_vkSignalSemaphore =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pSignalInfo: *const SemaphoreSignalInfo)
+                              -> Result>;
+pub type PFN_vkGetBufferDeviceAddress =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const BufferDeviceAddressInfo)
+                              -> DeviceAddress>;
+pub type PFN_vkGetBufferOpaqueCaptureAddress =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const BufferDeviceAddressInfo)
+                              -> u64>;
+pub type PFN_vkGetDeviceMemoryOpaqueCaptureAddress =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const DeviceMemoryOpaqueCaptureAddressInfo)
+                              -> u64>;
+pub type PFN_vkGetPhysicalDeviceToolProperties =
+    ::std::option::Option<unsafe extern "C" fn(physicalDevice: PhysicalDevice,
+                                               pToolCount: *mut u32,
+                                               pToolProperties: *mut PhysicalDeviceToolProperties)
+                              -> Result>;
+pub type PFN_vkCreatePrivateDataSlot =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pCreateInfo: *const PrivateDataSlotCreateInfo,
+                                               pAllocator: *const AllocationCallbacks,
+                                               pPrivateDataSlot: *mut PrivateDataSlot)
+                              -> Result>;
+pub type PFN_vkDestroyPrivateDataSlot =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               privateDataSlot: PrivateDataSlot,
+                                               pAllocator: *const AllocationCallbacks)>;
+pub type PFN_vkSetPrivateData =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               objectType: ObjectType,
+                                               objectHandle: u64,
+                                               privateDataSlot: PrivateDataSlot,
+                                               data: u64)
+                              -> Result>;
+pub type PFN_vkGetPrivateData =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               objectType: ObjectType,
+                                               objectHandle: u64,
+                                               privateDataSlot: PrivateDataSlot,
+                                               pData: *mut u64)>;
+pub type PFN_vkCmdSetEvent2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               event: Event,
+                                               pDependencyInfo: *const DependencyInfo)>;
+pub type PFN_vkCmdResetEvent2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               event: Event,
+                                               stageMask: PipelineStageFlags2)>;
+pub type PFN_vkCmdWaitEvents2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               eventCount: u32,
+                                               pEvents: *const Event,
+                                               pDependencyInfos: *const DependencyInfo)>;
+pub type PFN_vkCmdPipelineBarrier2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pDependencyInfo: *const DependencyInfo)>;
+pub type PFN_vkCmdWriteTimestamp2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               stage: PipelineStageFlags2,
+                                               queryPool: QueryPool,
+                                               query: u32)>;
+pub type PFN_vkQueueSubmit2 =
+    ::std::option::Option<unsafe extern "C" fn(queue: Queue,
+                                               submitCount: u32,
+                                               pSubmits: *const SubmitInfo2,
+                                               fence: Fence)
+                              -> Result>;
+pub type PFN_vkCmdCopyBuffer2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pCopyBufferInfo: *const CopyBufferInfo2)>;
+pub type PFN_vkCmdCopyImage2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pCopyImageInfo: *const CopyImageInfo2)>;
+pub type PFN_vkCmdCopyBufferToImage2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pCopyBufferToImageInfo: *const CopyBufferToImageInfo2)>;
+pub type PFN_vkCmdCopyImageToBuffer2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pCopyImageToBufferInfo: *const CopyImageToBufferInfo2)>;
+pub type PFN_vkCmdBlitImage2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pBlitImageInfo: *const BlitImageInfo2)>;
+pub type PFN_vkCmdResolveImage2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pResolveImageInfo: *const ResolveImageInfo2)>;
+pub type PFN_vkCmdBeginRendering =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               pRenderingInfo: *const RenderingInfo)>;
+pub type PFN_vkCmdEndRendering =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer)>;
+pub type PFN_vkCmdSetCullMode =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               cullMode: CullModeFlags)>;
+pub type PFN_vkCmdSetFrontFace =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               frontFace: FrontFace)>;
+pub type PFN_vkCmdSetPrimitiveTopology =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               primitiveTopology: PrimitiveTopology)>;
+pub type PFN_vkCmdSetViewportWithCount =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               viewportCount: u32,
+                                               pViewports: *const Viewport)>;
+pub type PFN_vkCmdSetScissorWithCount =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               scissorCount: u32,
+                                               pScissors: *const Rect2D)>;
+pub type PFN_vkCmdBindVertexBuffers2 =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               firstBinding: u32,
+                                               bindingCount: u32,
+                                               pBuffers: *const Buffer,
+                                               pOffsets: *const DeviceSize,
+                                               pSizes: *const DeviceSize,
+                                               pStrides: *const DeviceSize)>;
+pub type PFN_vkCmdSetDepthTestEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               depthTestEnable: Bool32)>;
+pub type PFN_vkCmdSetDepthWriteEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               depthWriteEnable: Bool32)>;
+pub type PFN_vkCmdSetDepthCompareOp =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               depthCompareOp: CompareOp)>;
+pub type PFN_vkCmdSetDepthBoundsTestEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               depthBoundsTestEnable: Bool32)>;
+pub type PFN_vkCmdSetStencilTestEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               stencilTestEnable: Bool32)>;
+pub type PFN_vkCmdSetStencilOp =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               faceMask: StencilFaceFlags,
+                                               failOp: StencilOp,
+                                               passOp: StencilOp,
+                                               depthFailOp: StencilOp,
+                                               compareOp: CompareOp)>;
+pub type PFN_vkCmdSetRasterizerDiscardEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               rasterizerDiscardEnable: Bool32)>;
+pub type PFN_vkCmdSetDepthBiasEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               depthBiasEnable: Bool32)>;
+pub type PFN_vkCmdSetPrimitiveRestartEnable =
+    ::std::option::Option<unsafe extern "C" fn(commandBuffer: CommandBuffer,
+                                               primitiveRestartEnable: Bool32)>;
+pub type PFN_vkGetDeviceBufferMemoryRequirements =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const DeviceBufferMemoryRequirements,
+                                               pMemoryRequirements: *mut MemoryRequirements2)>;
+pub type PFN_vkGetDeviceImageMemoryRequirements =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const DeviceImageMemoryRequirements,
+                                               pMemoryRequirements: *mut MemoryRequirements2)>;
+pub type PFN_vkGetDeviceImageSparseMemoryRequirements =
+    ::std::option::Option<unsafe extern "C" fn(device: Device,
+                                               pInfo: *const DeviceImageMemoryRequirements,
+                                               pSparseMemoryRequirementCount: *mut u32,
+                                               pSparseMemoryRequirements: *mut SparseImageMemoryRequirements2)>;
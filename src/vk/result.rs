@@ -0,0 +1,114 @@
+use std::error;
+use std::fmt;
+
+use vk::Result;
+
+impl Result {
+    /// Whether this is a success code. Besides `SUCCESS` these include codes
+    /// such as `INCOMPLETE`, `TIMEOUT` and `SUBOPTIMAL`, which callers may
+    /// still need to look at.
+    #[inline]
+    pub fn is_success(self) -> bool { self.0 >= 0 }
+
+    #[inline]
+    pub fn is_error(self) -> bool { self.0 < 0 }
+
+    /// `Ok` for every success code and `Err` for every error code.
+    #[inline]
+    pub fn result(self) -> ::std::result::Result<(), Error> {
+        self.result_with(())
+    }
+
+    /// `value` for every success code and `Err` for every error code.
+    #[inline]
+    pub fn result_with<T>(self, value: T) -> ::std::result::Result<T, Error> {
+        if self.is_success() { Ok(value) } else { Err(Error(self)) }
+    }
+
+    /// The spec's description of this code.
+    pub fn description(self) -> Option<&'static str> {
+        Some(match self {
+            Result::SUCCESS => "Command successfully completed.",
+            Result::NOT_READY => "A fence or query has not yet completed.",
+            Result::TIMEOUT => "A wait operation has not completed in the specified time.",
+            Result::EVENT_SET => "An event is signaled.",
+            Result::EVENT_RESET => "An event is unsignaled.",
+            Result::INCOMPLETE => "A return array was too small for the result.",
+            Result::SUBOPTIMAL =>
+                "A swapchain no longer matches the surface properties exactly, but can still be \
+                 used to present to the surface successfully.",
+            Result::PIPELINE_COMPILE_REQUIRED =>
+                "A requested pipeline creation would have required compilation, but the \
+                 application requested compilation to not be performed.",
+            Result::ERROR_OUT_OF_HOST_MEMORY => "A host memory allocation has failed.",
+            Result::ERROR_OUT_OF_DEVICE_MEMORY => "A device memory allocation has failed.",
+            Result::ERROR_INITIALIZATION_FAILED =>
+                "Initialization of an object could not be completed for \
+                 implementation-specific reasons.",
+            Result::ERROR_DEVICE_LOST => "The logical or physical device has been lost.",
+            Result::ERROR_MEMORY_MAP_FAILED => "Mapping of a memory object has failed.",
+            Result::ERROR_LAYER_NOT_PRESENT =>
+                "A requested layer is not present or could not be loaded.",
+            Result::ERROR_EXTENSION_NOT_PRESENT => "A requested extension is not supported.",
+            Result::ERROR_FEATURE_NOT_PRESENT => "A requested feature is not supported.",
+            Result::ERROR_INCOMPATIBLE_DRIVER =>
+                "The requested version of Vulkan is not supported by the driver or is otherwise \
+                 incompatible for implementation-specific reasons.",
+            Result::ERROR_TOO_MANY_OBJECTS =>
+                "Too many objects of the type have already been created.",
+            Result::ERROR_FORMAT_NOT_SUPPORTED =>
+                "A requested format is not supported on this device.",
+            Result::ERROR_FRAGMENTED_POOL =>
+                "A pool allocation has failed due to fragmentation of the pool's memory.",
+            Result::ERROR_UNKNOWN =>
+                "An unknown error has occurred; either the application has provided invalid \
+                 input, or an implementation failure has occurred.",
+            Result::ERROR_OUT_OF_POOL_MEMORY => "A pool memory allocation has failed.",
+            Result::ERROR_INVALID_EXTERNAL_HANDLE =>
+                "An external handle is not a valid handle of the specified type.",
+            Result::ERROR_FRAGMENTATION =>
+                "A descriptor pool creation has failed due to fragmentation.",
+            Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS =>
+                "A buffer creation or memory allocation failed because the requested address \
+                 is not available.",
+            Result::ERROR_SURFACE_LOST => "A surface is no longer available.",
+            Result::ERROR_NATIVE_WINDOW_IN_USE =>
+                "The requested window is already in use by Vulkan or another API in a manner \
+                 which prevents it from being used again.",
+            Result::ERROR_OUT_OF_DATE =>
+                "A surface has changed in such a way that it is no longer compatible with the \
+                 swapchain, and further presentation requests using the swapchain will fail.",
+            Result::ERROR_INCOMPATIBLE_DISPLAY =>
+                "The display used by a swapchain does not use the same presentable image \
+                 layout, or is incompatible in a way that prevents sharing an image.",
+            Result::ERROR_VALIDATION_FAILED =>
+                "A command failed because invalid usage was detected by the implementation or \
+                 a validation layer.",
+            _ => return None,
+        })
+    }
+}
+
+/// An error code returned by a command.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Error(pub Result);
+
+impl Error {
+    #[inline]
+    pub fn code(self) -> Result { self.0 }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.description() {
+            Some(description) => write!(f, "{} ({})", description, self.0),
+            None => write!(f, "error {}", self.0),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<Error> for Result {
+    fn from(error: Error) -> Result { error.0 }
+}
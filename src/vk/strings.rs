@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::slice;
+
+use vk::{ExtensionProperties, LayerProperties};
+
+fn bytes(chars: &[c_char]) -> &[u8] {
+    unsafe { slice::from_raw_parts(chars.as_ptr() as *const u8, chars.len()) }
+}
+
+/// The bytes of a fixed-size `char` array up to its NUL terminator, or all
+/// of them if an implementation left it out.
+fn until_nul(chars: &[c_char]) -> &[u8] {
+    let bytes = bytes(chars);
+    &bytes[..bytes.iter().position(|&c| c == 0).unwrap_or(bytes.len())]
+}
+
+/// The string in a fixed-size `char` array such as
+/// `ExtensionProperties::extensionName`, up to its NUL terminator, or `None`
+/// if it has none.
+#[doc(hidden)]
+pub fn c_str(chars: &[c_char]) -> Option<&CStr> {
+    let len = until_nul(chars).len();
+    if len == chars.len() {
+        return None;
+    }
+    Some(unsafe { CStr::from_bytes_with_nul_unchecked(&bytes(chars)[..len + 1]) })
+}
+
+/// The string in a fixed-size `char` array as UTF-8, replacing invalid
+/// sequences with U+FFFD.
+#[doc(hidden)]
+pub fn c_str_lossy<'a>(chars: &'a [c_char]) -> Cow<'a, str> {
+    String::from_utf8_lossy(until_nul(chars))
+}
+
+impl ExtensionProperties {
+    /// Whether this is the extension named `name`, such as
+    /// `khr::swapchain::EXTENSION_NAME`.
+    pub fn is(&self, name: &str) -> bool {
+        until_nul(&self.extensionName) == name.as_bytes()
+    }
+}
+
+impl LayerProperties {
+    /// Whether this is the layer named `name`.
+    pub fn is(&self, name: &str) -> bool {
+        until_nul(&self.layerName) == name.as_bytes()
+    }
+}
@@ -1,5 +1,19 @@
 use vk::*;
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BaseOutStructure {
+    pub sType: StructureType,
+    pub pNext: *mut BaseOutStructure,
+}
+zero_defl!{BaseOutStructure}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BaseInStructure {
+    pub sType: StructureType,
+    pub pNext: *const BaseInStructure,
+}
+zero_defl!{BaseInStructure}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ApplicationInfo {
@@ -11,6 +25,7 @@ pub struct ApplicationInfo {
     pub engineVersion: u32,
     pub apiVersion: u32,
 }
+tagged!{ApplicationInfo, APPLICATION_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct InstanceCreateInfo {
@@ -23,6 +38,7 @@ pub struct InstanceCreateInfo {
     pub enabledExtensionCount: u32,
     pub ppEnabledExtensionNames: *const *const ::std::os::raw::c_char,
 }
+tagged!{InstanceCreateInfo, INSTANCE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct AllocationCallbacks {
@@ -33,6 +49,7 @@ pub struct AllocationCallbacks {
     pub pfnInternalAllocation: PFN_vkInternalAllocationNotification,
     pub pfnInternalFree: PFN_vkInternalFreeNotification,
 }
+zero_defl!{AllocationCallbacks}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PhysicalDeviceFeatures {
@@ -92,6 +109,7 @@ pub struct PhysicalDeviceFeatures {
     pub variableMultisampleRate: Bool32,
     pub inheritedQueries: Bool32,
 }
+zero_defl!{PhysicalDeviceFeatures}
 #[repr(C)]
 #[derive(Copy, Clone, Default)]
 pub struct FormatProperties {
@@ -225,6 +243,7 @@ pub struct PhysicalDeviceLimits {
     pub optimalBufferCopyRowPitchAlignment: DeviceSize,
     pub nonCoherentAtomSize: DeviceSize,
 }
+zero_defl!{PhysicalDeviceLimits}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PhysicalDeviceSparseProperties {
@@ -234,8 +253,9 @@ pub struct PhysicalDeviceSparseProperties {
     pub residencyAlignedMipSize: Bool32,
     pub residencyNonResidentStrict: Bool32,
 }
+zero_defl!{PhysicalDeviceSparseProperties}
 #[repr(C)]
-#[derive(Copy)]
+#[derive(Copy, Clone)]
 pub struct PhysicalDeviceProperties {
     pub apiVersion: u32,
     pub driverVersion: u32,
@@ -247,9 +267,8 @@ pub struct PhysicalDeviceProperties {
     pub limits: PhysicalDeviceLimits,
     pub sparseProperties: PhysicalDeviceSparseProperties,
 }
-impl Clone for PhysicalDeviceProperties {
-    fn clone(&self) -> Self { *self }
-}
+zero_defl!{PhysicalDeviceProperties}
+c_strings!{PhysicalDeviceProperties { deviceName => (device_name, device_name_lossy) }}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct QueueFamilyProperties {
@@ -258,18 +277,21 @@ pub struct QueueFamilyProperties {
     pub timestampValidBits: u32,
     pub minImageTransferGranularity: Extent3D,
 }
+zero_defl!{QueueFamilyProperties}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct MemoryType {
     pub propertyFlags: MemoryPropertyFlags,
     pub heapIndex: u32,
 }
+zero_defl!{MemoryType}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct MemoryHeap {
     pub size: DeviceSize,
     pub flags: MemoryHeapFlags,
 }
+zero_defl!{MemoryHeap}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PhysicalDeviceMemoryProperties {
@@ -278,6 +300,7 @@ pub struct PhysicalDeviceMemoryProperties {
     pub memoryHeapCount: u32,
     pub memoryHeaps: [MemoryHeap; 16usize],
 }
+zero_defl!{PhysicalDeviceMemoryProperties}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DeviceQueueCreateInfo {
@@ -288,6 +311,7 @@ pub struct DeviceQueueCreateInfo {
     pub queueCount: u32,
     pub pQueuePriorities: *const ::std::os::raw::c_float,
 }
+tagged!{DeviceQueueCreateInfo, DEVICE_QUEUE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DeviceCreateInfo {
@@ -302,26 +326,29 @@ pub struct DeviceCreateInfo {
     pub ppEnabledExtensionNames: *const *const ::std::os::raw::c_char,
     pub pEnabledFeatures: *const PhysicalDeviceFeatures,
 }
+extensible!{DeviceCreateInfo, ExtendsDeviceCreateInfo}
+tagged!{DeviceCreateInfo, DEVICE_CREATE_INFO}
 #[repr(C)]
-#[derive(Copy)]
+#[derive(Copy, Clone)]
 pub struct ExtensionProperties {
     pub extensionName: [::std::os::raw::c_char; 256usize],
     pub specVersion: u32,
 }
-impl Clone for ExtensionProperties {
-    fn clone(&self) -> Self { *self }
-}
+zero_defl!{ExtensionProperties}
+c_strings!{ExtensionProperties { extensionName => (extension_name, extension_name_lossy) }}
 #[repr(C)]
-#[derive(Copy)]
+#[derive(Copy, Clone)]
 pub struct LayerProperties {
     pub layerName: [::std::os::raw::c_char; 256usize],
     pub specVersion: u32,
     pub implementationVersion: u32,
     pub description: [::std::os::raw::c_char; 256usize],
 }
-impl Clone for LayerProperties {
-    fn clone(&self) -> Self { *self }
-}
+zero_defl!{LayerProperties}
+c_strings!{LayerProperties {
+    layerName => (layer_name, layer_name_lossy),
+    description => (description, description_lossy)
+}}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SubmitInfo {
@@ -335,6 +362,8 @@ pub struct SubmitInfo {
     pub signalSemaphoreCount: u32,
     pub pSignalSemaphores: *const Semaphore,
 }
+extensible!{SubmitInfo, ExtendsSubmitInfo}
+tagged!{SubmitInfo, SUBMIT_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct MemoryAllocateInfo {
@@ -343,6 +372,8 @@ pub struct MemoryAllocateInfo {
     pub allocationSize: DeviceSize,
     pub memoryTypeIndex: u32,
 }
+extensible!{MemoryAllocateInfo, ExtendsMemoryAllocateInfo}
+tagged!{MemoryAllocateInfo, MEMORY_ALLOCATE_INFO}
 #[repr(C)]
 pub struct MappedMemoryRange {
     pub sType: StructureType,
@@ -351,6 +382,7 @@ pub struct MappedMemoryRange {
     pub offset: DeviceSize,
     pub size: DeviceSize,
 }
+tagged!{MappedMemoryRange, MAPPED_MEMORY_RANGE}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct MemoryRequirements {
@@ -358,6 +390,7 @@ pub struct MemoryRequirements {
     pub alignment: DeviceSize,
     pub memoryTypeBits: u32,
 }
+zero_defl!{MemoryRequirements}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SparseImageFormatProperties {
@@ -365,6 +398,7 @@ pub struct SparseImageFormatProperties {
     pub imageGranularity: Extent3D,
     pub flags: SparseImageFormatFlags,
 }
+zero_defl!{SparseImageFormatProperties}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SparseImageMemoryRequirements {
@@ -374,6 +408,7 @@ pub struct SparseImageMemoryRequirements {
     pub imageMipTailOffset: DeviceSize,
     pub imageMipTailStride: DeviceSize,
 }
+zero_defl!{SparseImageMemoryRequirements}
 #[repr(C)]
 pub struct SparseMemoryBind {
     pub resourceOffset: DeviceSize,
@@ -382,18 +417,21 @@ pub struct SparseMemoryBind {
     pub memoryOffset: DeviceSize,
     pub flags: SparseMemoryBindFlags,
 }
+zero_defl!{SparseMemoryBind}
 #[repr(C)]
 pub struct SparseBufferMemoryBindInfo {
     pub buffer: Buffer,
     pub bindCount: u32,
     pub pBinds: *const SparseMemoryBind,
 }
+zero_defl!{SparseBufferMemoryBindInfo}
 #[repr(C)]
 pub struct SparseImageOpaqueMemoryBindInfo {
     pub image: Image,
     pub bindCount: u32,
     pub pBinds: *const SparseMemoryBind,
 }
+zero_defl!{SparseImageOpaqueMemoryBindInfo}
 #[repr(C)]
 #[derive(Copy, Clone, Default)]
 pub struct ImageSubresource {
@@ -417,12 +455,14 @@ pub struct SparseImageMemoryBind {
     pub memoryOffset: DeviceSize,
     pub flags: SparseMemoryBindFlags,
 }
+zero_defl!{SparseImageMemoryBind}
 #[repr(C)]
 pub struct SparseImageMemoryBindInfo {
     pub image: Image,
     pub bindCount: u32,
     pub pBinds: *const SparseImageMemoryBind,
 }
+zero_defl!{SparseImageMemoryBindInfo}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct BindSparseInfo {
@@ -439,6 +479,8 @@ pub struct BindSparseInfo {
     pub signalSemaphoreCount: u32,
     pub pSignalSemaphores: *const Semaphore,
 }
+extensible!{BindSparseInfo, ExtendsBindSparseInfo}
+tagged!{BindSparseInfo, BIND_SPARSE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct FenceCreateInfo {
@@ -446,6 +488,8 @@ pub struct FenceCreateInfo {
     pub pNext: *const ::std::os::raw::c_void,
     pub flags: FenceCreateFlags,
 }
+extensible!{FenceCreateInfo, ExtendsFenceCreateInfo}
+tagged!{FenceCreateInfo, FENCE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SemaphoreCreateInfo {
@@ -453,6 +497,8 @@ pub struct SemaphoreCreateInfo {
     pub pNext: *const ::std::os::raw::c_void,
     pub flags: SemaphoreCreateFlags,
 }
+extensible!{SemaphoreCreateInfo, ExtendsSemaphoreCreateInfo}
+tagged!{SemaphoreCreateInfo, SEMAPHORE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct EventCreateInfo {
@@ -460,6 +506,7 @@ pub struct EventCreateInfo {
     pub pNext: *const ::std::os::raw::c_void,
     pub flags: EventCreateFlags,
 }
+tagged!{EventCreateInfo, EVENT_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct QueryPoolCreateInfo {
@@ -470,6 +517,7 @@ pub struct QueryPoolCreateInfo {
     pub queryCount: u32,
     pub pipelineStatistics: QueryPipelineStatisticFlags,
 }
+tagged!{QueryPoolCreateInfo, QUERY_POOL_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct BufferCreateInfo {
@@ -482,6 +530,8 @@ pub struct BufferCreateInfo {
     pub queueFamilyIndexCount: u32,
     pub pQueueFamilyIndices: *const u32,
 }
+extensible!{BufferCreateInfo, ExtendsBufferCreateInfo}
+tagged!{BufferCreateInfo, BUFFER_CREATE_INFO}
 #[repr(C)]
 pub struct BufferViewCreateInfo {
     pub sType: StructureType,
@@ -492,6 +542,7 @@ pub struct BufferViewCreateInfo {
     pub offset: DeviceSize,
     pub range: DeviceSize,
 }
+tagged!{BufferViewCreateInfo, BUFFER_VIEW_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ImageCreateInfo {
@@ -511,6 +562,8 @@ pub struct ImageCreateInfo {
     pub pQueueFamilyIndices: *const u32,
     pub initialLayout: ImageLayout,
 }
+extensible!{ImageCreateInfo, ExtendsImageCreateInfo}
+tagged!{ImageCreateInfo, IMAGE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SubresourceLayout {
@@ -520,6 +573,7 @@ pub struct SubresourceLayout {
     pub arrayPitch: DeviceSize,
     pub depthPitch: DeviceSize,
 }
+zero_defl!{SubresourceLayout}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ComponentMapping {
@@ -558,6 +612,8 @@ pub struct ImageViewCreateInfo {
     pub components: ComponentMapping,
     pub subresourceRange: ImageSubresourceRange,
 }
+extensible!{ImageViewCreateInfo, ExtendsImageViewCreateInfo}
+tagged!{ImageViewCreateInfo, IMAGE_VIEW_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ShaderModuleCreateInfo {
@@ -567,6 +623,7 @@ pub struct ShaderModuleCreateInfo {
     pub codeSize: usize,
     pub pCode: *const u32,
 }
+tagged!{ShaderModuleCreateInfo, SHADER_MODULE_CREATE_INFO; ExtendsPipelineShaderStageCreateInfo}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineCacheCreateInfo {
@@ -576,6 +633,7 @@ pub struct PipelineCacheCreateInfo {
     pub initialDataSize: usize,
     pub pInitialData: *const ::std::os::raw::c_void,
 }
+tagged!{PipelineCacheCreateInfo, PIPELINE_CACHE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SpecializationMapEntry {
@@ -583,6 +641,7 @@ pub struct SpecializationMapEntry {
     pub offset: u32,
     pub size: usize,
 }
+zero_defl!{SpecializationMapEntry}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SpecializationInfo {
@@ -591,6 +650,7 @@ pub struct SpecializationInfo {
     pub dataSize: usize,
     pub pData: *const ::std::os::raw::c_void,
 }
+zero_defl!{SpecializationInfo}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineShaderStageCreateInfo {
@@ -602,6 +662,8 @@ pub struct PipelineShaderStageCreateInfo {
     pub pName: *const ::std::os::raw::c_char,
     pub pSpecializationInfo: *const SpecializationInfo,
 }
+extensible!{PipelineShaderStageCreateInfo, ExtendsPipelineShaderStageCreateInfo}
+tagged!{PipelineShaderStageCreateInfo, PIPELINE_SHADER_STAGE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct VertexInputBindingDescription {
@@ -609,6 +671,7 @@ pub struct VertexInputBindingDescription {
     pub stride: u32,
     pub inputRate: VertexInputRate,
 }
+zero_defl!{VertexInputBindingDescription}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct VertexInputAttributeDescription {
@@ -617,6 +680,7 @@ pub struct VertexInputAttributeDescription {
     pub format: Format,
     pub offset: u32,
 }
+zero_defl!{VertexInputAttributeDescription}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineVertexInputStateCreateInfo {
@@ -628,6 +692,7 @@ pub struct PipelineVertexInputStateCreateInfo {
     pub vertexAttributeDescriptionCount: u32,
     pub pVertexAttributeDescriptions: *const VertexInputAttributeDescription,
 }
+tagged!{PipelineVertexInputStateCreateInfo, PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineInputAssemblyStateCreateInfo {
@@ -637,6 +702,7 @@ pub struct PipelineInputAssemblyStateCreateInfo {
     pub topology: PrimitiveTopology,
     pub primitiveRestartEnable: Bool32,
 }
+tagged!{PipelineInputAssemblyStateCreateInfo, PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineTessellationStateCreateInfo {
@@ -645,6 +711,7 @@ pub struct PipelineTessellationStateCreateInfo {
     pub flags: PipelineTessellationStateCreateFlags,
     pub patchControlPoints: u32,
 }
+tagged!{PipelineTessellationStateCreateInfo, PIPELINE_TESSELLATION_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct Viewport {
@@ -655,6 +722,7 @@ pub struct Viewport {
     pub minDepth: ::std::os::raw::c_float,
     pub maxDepth: ::std::os::raw::c_float,
 }
+zero_defl!{Viewport}
 #[repr(C)]
 #[derive(Copy, Clone, Default)]
 pub struct Offset2D {
@@ -684,6 +752,7 @@ pub struct PipelineViewportStateCreateInfo {
     pub scissorCount: u32,
     pub pScissors: *const Rect2D,
 }
+tagged!{PipelineViewportStateCreateInfo, PIPELINE_VIEWPORT_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineRasterizationStateCreateInfo {
@@ -701,6 +770,7 @@ pub struct PipelineRasterizationStateCreateInfo {
     pub depthBiasSlopeFactor: ::std::os::raw::c_float,
     pub lineWidth: ::std::os::raw::c_float,
 }
+tagged!{PipelineRasterizationStateCreateInfo, PIPELINE_RASTERIZATION_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineMultisampleStateCreateInfo {
@@ -714,6 +784,7 @@ pub struct PipelineMultisampleStateCreateInfo {
     pub alphaToCoverageEnable: Bool32,
     pub alphaToOneEnable: Bool32,
 }
+tagged!{PipelineMultisampleStateCreateInfo, PIPELINE_MULTISAMPLE_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone, Default)]
 pub struct StencilOpState {
@@ -741,6 +812,7 @@ pub struct PipelineDepthStencilStateCreateInfo {
     pub minDepthBounds: ::std::os::raw::c_float,
     pub maxDepthBounds: ::std::os::raw::c_float,
 }
+tagged!{PipelineDepthStencilStateCreateInfo, PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineColorBlendAttachmentState {
@@ -753,6 +825,7 @@ pub struct PipelineColorBlendAttachmentState {
     pub alphaBlendOp: BlendOp,
     pub colorWriteMask: ColorComponentFlags,
 }
+zero_defl!{PipelineColorBlendAttachmentState}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineColorBlendStateCreateInfo {
@@ -765,6 +838,7 @@ pub struct PipelineColorBlendStateCreateInfo {
     pub pAttachments: *const PipelineColorBlendAttachmentState,
     pub blendConstants: [::std::os::raw::c_float; 4usize],
 }
+tagged!{PipelineColorBlendStateCreateInfo, PIPELINE_COLOR_BLEND_STATE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineDynamicStateCreateInfo {
@@ -774,6 +848,7 @@ pub struct PipelineDynamicStateCreateInfo {
     pub dynamicStateCount: u32,
     pub pDynamicStates: *const DynamicState,
 }
+tagged!{PipelineDynamicStateCreateInfo, PIPELINE_DYNAMIC_STATE_CREATE_INFO}
 #[repr(C)]
 pub struct GraphicsPipelineCreateInfo {
     pub sType: StructureType,
@@ -796,6 +871,8 @@ pub struct GraphicsPipelineCreateInfo {
     pub basePipelineHandle: Pipeline,
     pub basePipelineIndex: i32,
 }
+extensible!{GraphicsPipelineCreateInfo, ExtendsGraphicsPipelineCreateInfo}
+tagged!{GraphicsPipelineCreateInfo, GRAPHICS_PIPELINE_CREATE_INFO}
 #[repr(C)]
 pub struct ComputePipelineCreateInfo {
     pub sType: StructureType,
@@ -806,6 +883,8 @@ pub struct ComputePipelineCreateInfo {
     pub basePipelineHandle: Pipeline,
     pub basePipelineIndex: i32,
 }
+extensible!{ComputePipelineCreateInfo, ExtendsComputePipelineCreateInfo}
+tagged!{ComputePipelineCreateInfo, COMPUTE_PIPELINE_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PushConstantRange {
@@ -813,6 +892,7 @@ pub struct PushConstantRange {
     pub offset: u32,
     pub size: u32,
 }
+zero_defl!{PushConstantRange}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PipelineLayoutCreateInfo {
@@ -824,6 +904,7 @@ pub struct PipelineLayoutCreateInfo {
     pub pushConstantRangeCount: u32,
     pub pPushConstantRanges: *const PushConstantRange,
 }
+tagged!{PipelineLayoutCreateInfo, PIPELINE_LAYOUT_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SamplerCreateInfo {
@@ -846,6 +927,8 @@ pub struct SamplerCreateInfo {
     pub borderColor: BorderColor,
     pub unnormalizedCoordinates: Bool32,
 }
+extensible!{SamplerCreateInfo, ExtendsSamplerCreateInfo}
+tagged!{SamplerCreateInfo, SAMPLER_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DescriptorSetLayoutBinding {
@@ -855,6 +938,7 @@ pub struct DescriptorSetLayoutBinding {
     pub stageFlags: ShaderStageFlags,
     pub pImmutableSamplers: *const Sampler,
 }
+zero_defl!{DescriptorSetLayoutBinding}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DescriptorSetLayoutCreateInfo {
@@ -864,12 +948,15 @@ pub struct DescriptorSetLayoutCreateInfo {
     pub bindingCount: u32,
     pub pBindings: *const DescriptorSetLayoutBinding,
 }
+extensible!{DescriptorSetLayoutCreateInfo, ExtendsDescriptorSetLayoutCreateInfo}
+tagged!{DescriptorSetLayoutCreateInfo, DESCRIPTOR_SET_LAYOUT_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DescriptorPoolSize {
     pub typ: DescriptorType,
     pub descriptorCount: u32,
 }
+zero_defl!{DescriptorPoolSize}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DescriptorPoolCreateInfo {
@@ -880,6 +967,8 @@ pub struct DescriptorPoolCreateInfo {
     pub poolSizeCount: u32,
     pub pPoolSizes: *const DescriptorPoolSize,
 }
+extensible!{DescriptorPoolCreateInfo, ExtendsDescriptorPoolCreateInfo}
+tagged!{DescriptorPoolCreateInfo, DESCRIPTOR_POOL_CREATE_INFO}
 #[repr(C)]
 pub struct DescriptorSetAllocateInfo {
     pub sType: StructureType,
@@ -888,18 +977,21 @@ pub struct DescriptorSetAllocateInfo {
     pub descriptorSetCount: u32,
     pub pSetLayouts: *const DescriptorSetLayout,
 }
+tagged!{DescriptorSetAllocateInfo, DESCRIPTOR_SET_ALLOCATE_INFO}
 #[repr(C)]
 pub struct DescriptorImageInfo {
     pub sampler: Sampler,
     pub imageView: ImageView,
     pub imageLayout: ImageLayout,
 }
+zero_defl!{DescriptorImageInfo}
 #[repr(C)]
 pub struct DescriptorBufferInfo {
     pub buffer: Buffer,
     pub offset: DeviceSize,
     pub range: DeviceSize,
 }
+zero_defl!{DescriptorBufferInfo}
 #[repr(C)]
 pub struct WriteDescriptorSet {
     pub sType: StructureType,
@@ -913,6 +1005,8 @@ pub struct WriteDescriptorSet {
     pub pBufferInfo: *const DescriptorBufferInfo,
     pub pTexelBufferView: *const BufferView,
 }
+extensible!{WriteDescriptorSet, ExtendsWriteDescriptorSet}
+tagged!{WriteDescriptorSet, WRITE_DESCRIPTOR_SET}
 #[repr(C)]
 pub struct CopyDescriptorSet {
     pub sType: StructureType,
@@ -925,6 +1019,7 @@ pub struct CopyDescriptorSet {
     pub dstArrayElement: u32,
     pub descriptorCount: u32,
 }
+tagged!{CopyDescriptorSet, COPY_DESCRIPTOR_SET}
 #[repr(C)]
 pub struct FramebufferCreateInfo {
     pub sType: StructureType,
@@ -937,6 +1032,8 @@ pub struct FramebufferCreateInfo {
     pub height: u32,
     pub layers: u32,
 }
+extensible!{FramebufferCreateInfo, ExtendsFramebufferCreateInfo}
+tagged!{FramebufferCreateInfo, FRAMEBUFFER_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct AttachmentDescription {
@@ -950,12 +1047,14 @@ pub struct AttachmentDescription {
     pub initialLayout: ImageLayout,
     pub finalLayout: ImageLayout,
 }
+zero_defl!{AttachmentDescription}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct AttachmentReference {
     pub attachment: u32,
     pub layout: ImageLayout,
 }
+zero_defl!{AttachmentReference}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SubpassDescription {
@@ -970,6 +1069,7 @@ pub struct SubpassDescription {
     pub preserveAttachmentCount: u32,
     pub pPreserveAttachments: *const u32,
 }
+zero_defl!{SubpassDescription}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct SubpassDependency {
@@ -981,6 +1081,7 @@ pub struct SubpassDependency {
     pub dstAccessMask: AccessFlags,
     pub dependencyFlags: DependencyFlags,
 }
+zero_defl!{SubpassDependency}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct RenderPassCreateInfo {
@@ -994,6 +1095,8 @@ pub struct RenderPassCreateInfo {
     pub dependencyCount: u32,
     pub pDependencies: *const SubpassDependency,
 }
+extensible!{RenderPassCreateInfo, ExtendsRenderPassCreateInfo}
+tagged!{RenderPassCreateInfo, RENDER_PASS_CREATE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct CommandPoolCreateInfo {
@@ -1002,6 +1105,7 @@ pub struct CommandPoolCreateInfo {
     pub flags: CommandPoolCreateFlags,
     pub queueFamilyIndex: u32,
 }
+tagged!{CommandPoolCreateInfo, COMMAND_POOL_CREATE_INFO}
 #[repr(C)]
 pub struct CommandBufferAllocateInfo {
     pub sType: StructureType,
@@ -1010,6 +1114,7 @@ pub struct CommandBufferAllocateInfo {
     pub level: CommandBufferLevel,
     pub commandBufferCount: u32,
 }
+tagged!{CommandBufferAllocateInfo, COMMAND_BUFFER_ALLOCATE_INFO}
 #[repr(C)]
 pub struct CommandBufferInheritanceInfo {
     pub sType: StructureType,
@@ -1021,6 +1126,8 @@ pub struct CommandBufferInheritanceInfo {
     pub queryFlags: QueryControlFlags,
     pub pipelineStatistics: QueryPipelineStatisticFlags,
 }
+extensible!{CommandBufferInheritanceInfo, ExtendsCommandBufferInheritanceInfo}
+tagged!{CommandBufferInheritanceInfo, COMMAND_BUFFER_INHERITANCE_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct CommandBufferBeginInfo {
@@ -1029,6 +1136,8 @@ pub struct CommandBufferBeginInfo {
     pub flags: CommandBufferUsageFlags,
     pub pInheritanceInfo: *const CommandBufferInheritanceInfo,
 }
+extensible!{CommandBufferBeginInfo, ExtendsCommandBufferBeginInfo}
+tagged!{CommandBufferBeginInfo, COMMAND_BUFFER_BEGIN_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct BufferCopy {
@@ -1036,6 +1145,7 @@ pub struct BufferCopy {
     pub dstOffset: DeviceSize,
     pub size: DeviceSize,
 }
+zero_defl!{BufferCopy}
 #[repr(C)]
 #[derive(Copy, Clone, Default)]
 pub struct ImageSubresourceLayers {
@@ -1071,12 +1181,14 @@ pub struct BufferImageCopy {
     pub imageOffset: Offset3D,
     pub imageExtent: Extent3D,
 }
+zero_defl!{BufferImageCopy}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ClearDepthStencilValue {
     pub depth: ::std::os::raw::c_float,
     pub stencil: u32,
 }
+zero_defl!{ClearDepthStencilValue}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ClearAttachment {
@@ -1084,6 +1196,7 @@ pub struct ClearAttachment {
     pub colorAttachment: u32,
     pub clearValue: ClearValue,
 }
+zero_defl!{ClearAttachment}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct ClearRect {
@@ -1091,6 +1204,7 @@ pub struct ClearRect {
     pub baseArrayLayer: u32,
     pub layerCount: u32,
 }
+zero_defl!{ClearRect}
 #[repr(C)]
 #[derive(Copy, Clone, Default)]
 pub struct ImageResolve {
@@ -1108,6 +1222,7 @@ pub struct MemoryBarrier {
     pub srcAccessMask: AccessFlags,
     pub dstAccessMask: AccessFlags,
 }
+tagged!{MemoryBarrier, MEMORY_BARRIER}
 #[repr(C)]
 pub struct BufferMemoryBarrier {
     pub sType: StructureType,
@@ -1120,6 +1235,7 @@ pub struct BufferMemoryBarrier {
     pub offset: DeviceSize,
     pub size: DeviceSize,
 }
+tagged!{BufferMemoryBarrier, BUFFER_MEMORY_BARRIER}
 #[repr(C)]
 pub struct ImageMemoryBarrier {
     pub sType: StructureType,
@@ -1133,6 +1249,7 @@ pub struct ImageMemoryBarrier {
     pub image: Image,
     pub subresourceRange: ImageSubresourceRange,
 }
+tagged!{ImageMemoryBarrier, IMAGE_MEMORY_BARRIER}
 #[repr(C)]
 pub struct RenderPassBeginInfo {
     pub sType: StructureType,
@@ -1143,6 +1260,8 @@ pub struct RenderPassBeginInfo {
     pub clearValueCount: u32,
     pub pClearValues: *const ClearValue,
 }
+extensible!{RenderPassBeginInfo, ExtendsRenderPassBeginInfo}
+tagged!{RenderPassBeginInfo, RENDER_PASS_BEGIN_INFO}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DispatchIndirectCommand {
@@ -1150,6 +1269,7 @@ pub struct DispatchIndirectCommand {
     pub y: u32,
     pub z: u32,
 }
+zero_defl!{DispatchIndirectCommand}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DrawIndexedIndirectCommand {
@@ -1159,6 +1279,7 @@ pub struct DrawIndexedIndirectCommand {
     pub vertexOffset: i32,
     pub firstInstance: u32,
 }
+zero_defl!{DrawIndexedIndirectCommand}
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DrawIndirectCommand {
@@ -1167,3 +1288,2027 @@ pub struct DrawIndirectCommand {
     pub firstVertex: u32,
     pub firstInstance: u32,
 }
+zero_defl!{DrawIndirectCommand}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSubgroupProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub subgroupSize: u32,
+    pub supportedStages: ShaderStageFlags,
+    pub supportedOperations: SubgroupFeatureFlags,
+    pub quadOperationsInAllStages: Bool32,
+}
+tagged!{PhysicalDeviceSubgroupProperties, PHYSICAL_DEVICE_SUBGROUP_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BindBufferMemoryInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub buffer: Buffer,
+    pub memory: DeviceMemory,
+    pub memoryOffset: DeviceSize,
+}
+extensible!{BindBufferMemoryInfo, ExtendsBindBufferMemoryInfo}
+tagged!{BindBufferMemoryInfo, BIND_BUFFER_MEMORY_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BindImageMemoryInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub image: Image,
+    pub memory: DeviceMemory,
+    pub memoryOffset: DeviceSize,
+}
+extensible!{BindImageMemoryInfo, ExtendsBindImageMemoryInfo}
+tagged!{BindImageMemoryInfo, BIND_IMAGE_MEMORY_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDevice16BitStorageFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub storageBuffer16BitAccess: Bool32,
+    pub uniformAndStorageBuffer16BitAccess: Bool32,
+    pub storagePushConstant16: Bool32,
+    pub storageInputOutput16: Bool32,
+}
+tagged!{PhysicalDevice16BitStorageFeatures, PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MemoryDedicatedRequirements {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub prefersDedicatedAllocation: Bool32,
+    pub requiresDedicatedAllocation: Bool32,
+}
+tagged!{MemoryDedicatedRequirements, MEMORY_DEDICATED_REQUIREMENTS; ExtendsMemoryRequirements2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MemoryDedicatedAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub image: Image,
+    pub buffer: Buffer,
+}
+tagged!{MemoryDedicatedAllocateInfo, MEMORY_DEDICATED_ALLOCATE_INFO; ExtendsMemoryAllocateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MemoryAllocateFlagsInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: MemoryAllocateFlags,
+    pub deviceMask: u32,
+}
+tagged!{MemoryAllocateFlagsInfo, MEMORY_ALLOCATE_FLAGS_INFO; ExtendsMemoryAllocateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceGroupRenderPassBeginInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub deviceMask: u32,
+    pub deviceRenderAreaCount: u32,
+    pub pDeviceRenderAreas: *const Rect2D,
+}
+tagged!{DeviceGroupRenderPassBeginInfo, DEVICE_GROUP_RENDER_PASS_BEGIN_INFO;
+    ExtendsRenderPassBeginInfo, ExtendsRenderingInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceGroupCommandBufferBeginInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub deviceMask: u32,
+}
+tagged!{DeviceGroupCommandBufferBeginInfo, DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO;
+    ExtendsCommandBufferBeginInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceGroupSubmitInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub waitSemaphoreCount: u32,
+    pub pWaitSemaphoreDeviceIndices: *const u32,
+    pub commandBufferCount: u32,
+    pub pCommandBufferDeviceMasks: *const u32,
+    pub signalSemaphoreCount: u32,
+    pub pSignalSemaphoreDeviceIndices: *const u32,
+}
+tagged!{DeviceGroupSubmitInfo, DEVICE_GROUP_SUBMIT_INFO; ExtendsSubmitInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceGroupBindSparseInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub resourceDeviceIndex: u32,
+    pub memoryDeviceIndex: u32,
+}
+tagged!{DeviceGroupBindSparseInfo, DEVICE_GROUP_BIND_SPARSE_INFO; ExtendsBindSparseInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BindBufferMemoryDeviceGroupInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub deviceIndexCount: u32,
+    pub pDeviceIndices: *const u32,
+}
+tagged!{BindBufferMemoryDeviceGroupInfo, BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO;
+    ExtendsBindBufferMemoryInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BindImageMemoryDeviceGroupInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub deviceIndexCount: u32,
+    pub pDeviceIndices: *const u32,
+    pub splitInstanceBindRegionCount: u32,
+    pub pSplitInstanceBindRegions: *const Rect2D,
+}
+tagged!{BindImageMemoryDeviceGroupInfo, BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO;
+    ExtendsBindImageMemoryInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceGroupProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub physicalDeviceCount: u32,
+    pub physicalDevices: [PhysicalDevice; 32usize],
+    pub subsetAllocation: Bool32,
+}
+tagged!{PhysicalDeviceGroupProperties, PHYSICAL_DEVICE_GROUP_PROPERTIES}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceGroupDeviceCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub physicalDeviceCount: u32,
+    pub pPhysicalDevices: *const PhysicalDevice,
+}
+tagged!{DeviceGroupDeviceCreateInfo, DEVICE_GROUP_DEVICE_CREATE_INFO; ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BufferMemoryRequirementsInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub buffer: Buffer,
+}
+tagged!{BufferMemoryRequirementsInfo2, BUFFER_MEMORY_REQUIREMENTS_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageMemoryRequirementsInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub image: Image,
+}
+extensible!{ImageMemoryRequirementsInfo2, ExtendsImageMemoryRequirementsInfo2}
+tagged!{ImageMemoryRequirementsInfo2, IMAGE_MEMORY_REQUIREMENTS_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageSparseMemoryRequirementsInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub image: Image,
+}
+tagged!{ImageSparseMemoryRequirementsInfo2, IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MemoryRequirements2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub memoryRequirements: MemoryRequirements,
+}
+extensible!{MemoryRequirements2, ExtendsMemoryRequirements2}
+tagged!{MemoryRequirements2, MEMORY_REQUIREMENTS_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SparseImageMemoryRequirements2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub memoryRequirements: SparseImageMemoryRequirements,
+}
+tagged!{SparseImageMemoryRequirements2, SPARSE_IMAGE_MEMORY_REQUIREMENTS_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceFeatures2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub features: PhysicalDeviceFeatures,
+}
+extensible!{PhysicalDeviceFeatures2, ExtendsPhysicalDeviceFeatures2}
+tagged!{PhysicalDeviceFeatures2, PHYSICAL_DEVICE_FEATURES_2; ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceProperties2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub properties: PhysicalDeviceProperties,
+}
+extensible!{PhysicalDeviceProperties2, ExtendsPhysicalDeviceProperties2}
+tagged!{PhysicalDeviceProperties2, PHYSICAL_DEVICE_PROPERTIES_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FormatProperties2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub formatProperties: FormatProperties,
+}
+extensible!{FormatProperties2, ExtendsFormatProperties2}
+tagged!{FormatProperties2, FORMAT_PROPERTIES_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageFormatProperties2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub imageFormatProperties: ImageFormatProperties,
+}
+extensible!{ImageFormatProperties2, ExtendsImageFormatProperties2}
+tagged!{ImageFormatProperties2, IMAGE_FORMAT_PROPERTIES_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceImageFormatInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub format: Format,
+    pub _type: ImageType,
+    pub tiling: ImageTiling,
+    pub usage: ImageUsageFlags,
+    pub flags: ImageCreateFlags,
+}
+extensible!{PhysicalDeviceImageFormatInfo2, ExtendsPhysicalDeviceImageFormatInfo2}
+tagged!{PhysicalDeviceImageFormatInfo2, PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct QueueFamilyProperties2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub queueFamilyProperties: QueueFamilyProperties,
+}
+tagged!{QueueFamilyProperties2, QUEUE_FAMILY_PROPERTIES_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMemoryProperties2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub memoryProperties: PhysicalDeviceMemoryProperties,
+}
+tagged!{PhysicalDeviceMemoryProperties2, PHYSICAL_DEVICE_MEMORY_PROPERTIES_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SparseImageFormatProperties2 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub properties: SparseImageFormatProperties,
+}
+tagged!{SparseImageFormatProperties2, SPARSE_IMAGE_FORMAT_PROPERTIES_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSparseImageFormatInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub format: Format,
+    pub _type: ImageType,
+    pub samples: SampleCountFlag,
+    pub usage: ImageUsageFlags,
+    pub tiling: ImageTiling,
+}
+tagged!{PhysicalDeviceSparseImageFormatInfo2, PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDevicePointClippingProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub pointClippingBehavior: PointClippingBehavior,
+}
+tagged!{PhysicalDevicePointClippingProperties, PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct InputAttachmentAspectReference {
+    pub subpass: u32,
+    pub inputAttachmentIndex: u32,
+    pub aspectMask: ImageAspectFlags,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderPassInputAttachmentAspectCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub aspectReferenceCount: u32,
+    pub pAspectReferences: *const InputAttachmentAspectReference,
+}
+tagged!{RenderPassInputAttachmentAspectCreateInfo, RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO;
+    ExtendsRenderPassCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageViewUsageCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub usage: ImageUsageFlags,
+}
+tagged!{ImageViewUsageCreateInfo, IMAGE_VIEW_USAGE_CREATE_INFO; ExtendsImageViewCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PipelineTessellationDomainOriginStateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub domainOrigin: TessellationDomainOrigin,
+}
+tagged!{PipelineTessellationDomainOriginStateCreateInfo, PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderPassMultiviewCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub subpassCount: u32,
+    pub pViewMasks: *const u32,
+    pub dependencyCount: u32,
+    pub pViewOffsets: *const i32,
+    pub correlationMaskCount: u32,
+    pub pCorrelationMasks: *const u32,
+}
+tagged!{RenderPassMultiviewCreateInfo, RENDER_PASS_MULTIVIEW_CREATE_INFO;
+    ExtendsRenderPassCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMultiviewFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub multiview: Bool32,
+    pub multiviewGeometryShader: Bool32,
+    pub multiviewTessellationShader: Bool32,
+}
+tagged!{PhysicalDeviceMultiviewFeatures, PHYSICAL_DEVICE_MULTIVIEW_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMultiviewProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxMultiviewViewCount: u32,
+    pub maxMultiviewInstanceIndex: u32,
+}
+tagged!{PhysicalDeviceMultiviewProperties, PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVariablePointersFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub variablePointersStorageBuffer: Bool32,
+    pub variablePointers: Bool32,
+}
+tagged!{PhysicalDeviceVariablePointersFeatures, PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceProtectedMemoryFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub protectedMemory: Bool32,
+}
+tagged!{PhysicalDeviceProtectedMemoryFeatures, PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceProtectedMemoryProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub protectedNoFault: Bool32,
+}
+tagged!{PhysicalDeviceProtectedMemoryProperties, PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceQueueInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: DeviceQueueCreateFlags,
+    pub queueFamilyIndex: u32,
+    pub queueIndex: u32,
+}
+tagged!{DeviceQueueInfo2, DEVICE_QUEUE_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ProtectedSubmitInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub protectedSubmit: Bool32,
+}
+tagged!{ProtectedSubmitInfo, PROTECTED_SUBMIT_INFO; ExtendsSubmitInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SamplerYcbcrConversionCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub format: Format,
+    pub ycbcrModel: SamplerYcbcrModelConversion,
+    pub ycbcrRange: SamplerYcbcrRange,
+    pub components: ComponentMapping,
+    pub xChromaOffset: ChromaLocation,
+    pub yChromaOffset: ChromaLocation,
+    pub chromaFilter: Filter,
+    pub forceExplicitReconstruction: Bool32,
+}
+tagged!{SamplerYcbcrConversionCreateInfo, SAMPLER_YCBCR_CONVERSION_CREATE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SamplerYcbcrConversionInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub conversion: SamplerYcbcrConversion,
+}
+tagged!{SamplerYcbcrConversionInfo, SAMPLER_YCBCR_CONVERSION_INFO; ExtendsSamplerCreateInfo,
+    ExtendsImageViewCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BindImagePlaneMemoryInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub planeAspect: ImageAspectFlag,
+}
+tagged!{BindImagePlaneMemoryInfo, BIND_IMAGE_PLANE_MEMORY_INFO; ExtendsBindImageMemoryInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImagePlaneMemoryRequirementsInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub planeAspect: ImageAspectFlag,
+}
+tagged!{ImagePlaneMemoryRequirementsInfo, IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO;
+    ExtendsImageMemoryRequirementsInfo2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSamplerYcbcrConversionFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub samplerYcbcrConversion: Bool32,
+}
+tagged!{PhysicalDeviceSamplerYcbcrConversionFeatures, PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SamplerYcbcrConversionImageFormatProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub combinedImageSamplerDescriptorCount: u32,
+}
+tagged!{SamplerYcbcrConversionImageFormatProperties, SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES;
+    ExtendsImageFormatProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorUpdateTemplateEntry {
+    pub dstBinding: u32,
+    pub dstArrayElement: u32,
+    pub descriptorCount: u32,
+    pub descriptorType: DescriptorType,
+    pub offset: usize,
+    pub stride: usize,
+}
+zero_defl!{DescriptorUpdateTemplateEntry}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorUpdateTemplateCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: DescriptorUpdateTemplateCreateFlags,
+    pub descriptorUpdateEntryCount: u32,
+    pub pDescriptorUpdateEntries: *const DescriptorUpdateTemplateEntry,
+    pub templateType: DescriptorUpdateTemplateType,
+    pub descriptorSetLayout: DescriptorSetLayout,
+    pub pipelineBindPoint: PipelineBindPoint,
+    pub pipelineLayout: PipelineLayout,
+    pub set: u32,
+}
+tagged!{DescriptorUpdateTemplateCreateInfo, DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct ExternalMemoryProperties {
+    pub externalMemoryFeatures: ExternalMemoryFeatureFlags,
+    pub exportFromImportedHandleTypes: ExternalMemoryHandleTypeFlags,
+    pub compatibleHandleTypes: ExternalMemoryHandleTypeFlags,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceExternalImageFormatInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleType: ExternalMemoryHandleTypeFlag,
+}
+tagged!{PhysicalDeviceExternalImageFormatInfo, PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO;
+    ExtendsPhysicalDeviceImageFormatInfo2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExternalImageFormatProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub externalMemoryProperties: ExternalMemoryProperties,
+}
+tagged!{ExternalImageFormatProperties, EXTERNAL_IMAGE_FORMAT_PROPERTIES;
+    ExtendsImageFormatProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceExternalBufferInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: BufferCreateFlags,
+    pub usage: BufferUsageFlags,
+    pub handleType: ExternalMemoryHandleTypeFlag,
+}
+tagged!{PhysicalDeviceExternalBufferInfo, PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExternalBufferProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub externalMemoryProperties: ExternalMemoryProperties,
+}
+tagged!{ExternalBufferProperties, EXTERNAL_BUFFER_PROPERTIES}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceIDProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub deviceUUID: [u8; 16usize],
+    pub driverUUID: [u8; 16usize],
+    pub deviceLUID: [u8; 8usize],
+    pub deviceNodeMask: u32,
+    pub deviceLUIDValid: Bool32,
+}
+tagged!{PhysicalDeviceIDProperties, PHYSICAL_DEVICE_ID_PROPERTIES; ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExternalMemoryImageCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleTypes: ExternalMemoryHandleTypeFlags,
+}
+tagged!{ExternalMemoryImageCreateInfo, EXTERNAL_MEMORY_IMAGE_CREATE_INFO; ExtendsImageCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExternalMemoryBufferCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleTypes: ExternalMemoryHandleTypeFlags,
+}
+tagged!{ExternalMemoryBufferCreateInfo, EXTERNAL_MEMORY_BUFFER_CREATE_INFO; ExtendsBufferCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExportMemoryAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleTypes: ExternalMemoryHandleTypeFlags,
+}
+tagged!{ExportMemoryAllocateInfo, EXPORT_MEMORY_ALLOCATE_INFO; ExtendsMemoryAllocateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceExternalFenceInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleType: ExternalFenceHandleTypeFlag,
+}
+tagged!{PhysicalDeviceExternalFenceInfo, PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExternalFenceProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub exportFromImportedHandleTypes: ExternalFenceHandleTypeFlags,
+    pub compatibleHandleTypes: ExternalFenceHandleTypeFlags,
+    pub externalFenceFeatures: ExternalFenceFeatureFlags,
+}
+tagged!{ExternalFenceProperties, EXTERNAL_FENCE_PROPERTIES}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExportFenceCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleTypes: ExternalFenceHandleTypeFlags,
+}
+tagged!{ExportFenceCreateInfo, EXPORT_FENCE_CREATE_INFO; ExtendsFenceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExportSemaphoreCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleTypes: ExternalSemaphoreHandleTypeFlags,
+}
+tagged!{ExportSemaphoreCreateInfo, EXPORT_SEMAPHORE_CREATE_INFO; ExtendsSemaphoreCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceExternalSemaphoreInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub handleType: ExternalSemaphoreHandleTypeFlag,
+}
+extensible!{PhysicalDeviceExternalSemaphoreInfo, ExtendsPhysicalDeviceExternalSemaphoreInfo}
+tagged!{PhysicalDeviceExternalSemaphoreInfo, PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ExternalSemaphoreProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub exportFromImportedHandleTypes: ExternalSemaphoreHandleTypeFlags,
+    pub compatibleHandleTypes: ExternalSemaphoreHandleTypeFlags,
+    pub externalSemaphoreFeatures: ExternalSemaphoreFeatureFlags,
+}
+tagged!{ExternalSemaphoreProperties, EXTERNAL_SEMAPHORE_PROPERTIES}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMaintenance3Properties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxPerSetDescriptors: u32,
+    pub maxMemoryAllocationSize: DeviceSize,
+}
+tagged!{PhysicalDeviceMaintenance3Properties, PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetLayoutSupport {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub supported: Bool32,
+}
+tagged!{DescriptorSetLayoutSupport, DESCRIPTOR_SET_LAYOUT_SUPPORT}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderDrawParametersFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderDrawParameters: Bool32,
+}
+tagged!{PhysicalDeviceShaderDrawParametersFeatures, PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct ConformanceVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub subminor: u8,
+    pub patch: u8,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkan11Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub storageBuffer16BitAccess: Bool32,
+    pub uniformAndStorageBuffer16BitAccess: Bool32,
+    pub storagePushConstant16: Bool32,
+    pub storageInputOutput16: Bool32,
+    pub multiview: Bool32,
+    pub multiviewGeometryShader: Bool32,
+    pub multiviewTessellationShader: Bool32,
+    pub variablePointersStorageBuffer: Bool32,
+    pub variablePointers: Bool32,
+    pub protectedMemory: Bool32,
+    pub samplerYcbcrConversion: Bool32,
+    pub shaderDrawParameters: Bool32,
+}
+tagged!{PhysicalDeviceVulkan11Features, PHYSICAL_DEVICE_VULKAN_1_1_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkan11Properties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub deviceUUID: [u8; 16usize],
+    pub driverUUID: [u8; 16usize],
+    pub deviceLUID: [u8; 8usize],
+    pub deviceNodeMask: u32,
+    pub deviceLUIDValid: Bool32,
+    pub subgroupSize: u32,
+    pub subgroupSupportedStages: ShaderStageFlags,
+    pub subgroupSupportedOperations: SubgroupFeatureFlags,
+    pub subgroupQuadOperationsInAllStages: Bool32,
+    pub pointClippingBehavior: PointClippingBehavior,
+    pub maxMultiviewViewCount: u32,
+    pub maxMultiviewInstanceIndex: u32,
+    pub protectedNoFault: Bool32,
+    pub maxPerSetDescriptors: u32,
+    pub maxMemoryAllocationSize: DeviceSize,
+}
+tagged!{PhysicalDeviceVulkan11Properties, PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkan12Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub samplerMirrorClampToEdge: Bool32,
+    pub drawIndirectCount: Bool32,
+    pub storageBuffer8BitAccess: Bool32,
+    pub uniformAndStorageBuffer8BitAccess: Bool32,
+    pub storagePushConstant8: Bool32,
+    pub shaderBufferInt64Atomics: Bool32,
+    pub shaderSharedInt64Atomics: Bool32,
+    pub shaderFloat16: Bool32,
+    pub shaderInt8: Bool32,
+    pub descriptorIndexing: Bool32,
+    pub shaderInputAttachmentArrayDynamicIndexing: Bool32,
+    pub shaderUniformTexelBufferArrayDynamicIndexing: Bool32,
+    pub shaderStorageTexelBufferArrayDynamicIndexing: Bool32,
+    pub shaderUniformBufferArrayNonUniformIndexing: Bool32,
+    pub shaderSampledImageArrayNonUniformIndexing: Bool32,
+    pub shaderStorageBufferArrayNonUniformIndexing: Bool32,
+    pub shaderStorageImageArrayNonUniformIndexing: Bool32,
+    pub shaderInputAttachmentArrayNonUniformIndexing: Bool32,
+    pub shaderUniformTexelBufferArrayNonUniformIndexing: Bool32,
+    pub shaderStorageTexelBufferArrayNonUniformIndexing: Bool32,
+    pub descriptorBindingUniformBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingSampledImageUpdateAfterBind: Bool32,
+    pub descriptorBindingStorageImageUpdateAfterBind: Bool32,
+    pub descriptorBindingStorageBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingUniformTexelBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingStorageTexelBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingUpdateUnusedWhilePending: Bool32,
+    pub descriptorBindingPartiallyBound: Bool32,
+    pub descriptorBindingVariableDescriptorCount: Bool32,
+    pub runtimeDescriptorArray: Bool32,
+    pub samplerFilterMinmax: Bool32,
+    pub scalarBlockLayout: Bool32,
+    pub imagelessFramebuffer: Bool32,
+    pub uniformBufferStandardLayout: Bool32,
+    pub shaderSubgroupExtendedTypes: Bool32,
+    pub separateDepthStencilLayouts: Bool32,
+    pub hostQueryReset: Bool32,
+    pub timelineSemaphore: Bool32,
+    pub bufferDeviceAddress: Bool32,
+    pub bufferDeviceAddressCaptureReplay: Bool32,
+    pub bufferDeviceAddressMultiDevice: Bool32,
+    pub vulkanMemoryModel: Bool32,
+    pub vulkanMemoryModelDeviceScope: Bool32,
+    pub vulkanMemoryModelAvailabilityVisibilityChains: Bool32,
+    pub shaderOutputViewportIndex: Bool32,
+    pub shaderOutputLayer: Bool32,
+    pub subgroupBroadcastDynamicId: Bool32,
+}
+tagged!{PhysicalDeviceVulkan12Features, PHYSICAL_DEVICE_VULKAN_1_2_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkan12Properties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub driverID: DriverId,
+    pub driverName: [::std::os::raw::c_char; 256usize],
+    pub driverInfo: [::std::os::raw::c_char; 256usize],
+    pub conformanceVersion: ConformanceVersion,
+    pub denormBehaviorIndependence: ShaderFloatControlsIndependence,
+    pub roundingModeIndependence: ShaderFloatControlsIndependence,
+    pub shaderSignedZeroInfNanPreserveFloat16: Bool32,
+    pub shaderSignedZeroInfNanPreserveFloat32: Bool32,
+    pub shaderSignedZeroInfNanPreserveFloat64: Bool32,
+    pub shaderDenormPreserveFloat16: Bool32,
+    pub shaderDenormPreserveFloat32: Bool32,
+    pub shaderDenormPreserveFloat64: Bool32,
+    pub shaderDenormFlushToZeroFloat16: Bool32,
+    pub shaderDenormFlushToZeroFloat32: Bool32,
+    pub shaderDenormFlushToZeroFloat64: Bool32,
+    pub shaderRoundingModeRTEFloat16: Bool32,
+    pub shaderRoundingModeRTEFloat32: Bool32,
+    pub shaderRoundingModeRTEFloat64: Bool32,
+    pub shaderRoundingModeRTZFloat16: Bool32,
+    pub shaderRoundingModeRTZFloat32: Bool32,
+    pub shaderRoundingModeRTZFloat64: Bool32,
+    pub maxUpdateAfterBindDescriptorsInAllPools: u32,
+    pub shaderUniformBufferArrayNonUniformIndexingNative: Bool32,
+    pub shaderSampledImageArrayNonUniformIndexingNative: Bool32,
+    pub shaderStorageBufferArrayNonUniformIndexingNative: Bool32,
+    pub shaderStorageImageArrayNonUniformIndexingNative: Bool32,
+    pub shaderInputAttachmentArrayNonUniformIndexingNative: Bool32,
+    pub robustBufferAccessUpdateAfterBind: Bool32,
+    pub quadDivergentImplicitLod: Bool32,
+    pub maxPerStageDescriptorUpdateAfterBindSamplers: u32,
+    pub maxPerStageDescriptorUpdateAfterBindUniformBuffers: u32,
+    pub maxPerStageDescriptorUpdateAfterBindStorageBuffers: u32,
+    pub maxPerStageDescriptorUpdateAfterBindSampledImages: u32,
+    pub maxPerStageDescriptorUpdateAfterBindStorageImages: u32,
+    pub maxPerStageDescriptorUpdateAfterBindInputAttachments: u32,
+    pub maxPerStageUpdateAfterBindResources: u32,
+    pub maxDescriptorSetUpdateAfterBindSamplers: u32,
+    pub maxDescriptorSetUpdateAfterBindUniformBuffers: u32,
+    pub maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: u32,
+    pub maxDescriptorSetUpdateAfterBindStorageBuffers: u32,
+    pub maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: u32,
+    pub maxDescriptorSetUpdateAfterBindSampledImages: u32,
+    pub maxDescriptorSetUpdateAfterBindStorageImages: u32,
+    pub maxDescriptorSetUpdateAfterBindInputAttachments: u32,
+    pub supportedDepthResolveModes: ResolveModeFlags,
+    pub supportedStencilResolveModes: ResolveModeFlags,
+    pub independentResolveNone: Bool32,
+    pub independentResolve: Bool32,
+    pub filterMinmaxSingleComponentFormats: Bool32,
+    pub filterMinmaxImageComponentMapping: Bool32,
+    pub maxTimelineSemaphoreValueDifference: u64,
+    pub framebufferIntegerColorSampleCounts: SampleCountFlags,
+}
+c_strings!{PhysicalDeviceVulkan12Properties {
+    driverName => (driver_name, driver_name_lossy),
+    driverInfo => (driver_info, driver_info_lossy)
+}}
+tagged!{PhysicalDeviceVulkan12Properties, PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageFormatListCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub viewFormatCount: u32,
+    pub pViewFormats: *const Format,
+}
+tagged!{ImageFormatListCreateInfo, IMAGE_FORMAT_LIST_CREATE_INFO; ExtendsImageCreateInfo,
+    ExtendsPhysicalDeviceImageFormatInfo2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AttachmentDescription2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: AttachmentDescriptionFlags,
+    pub format: Format,
+    pub samples: SampleCountFlag,
+    pub loadOp: AttachmentLoadOp,
+    pub storeOp: AttachmentStoreOp,
+    pub stencilLoadOp: AttachmentLoadOp,
+    pub stencilStoreOp: AttachmentStoreOp,
+    pub initialLayout: ImageLayout,
+    pub finalLayout: ImageLayout,
+}
+extensible!{AttachmentDescription2, ExtendsAttachmentDescription2}
+tagged!{AttachmentDescription2, ATTACHMENT_DESCRIPTION_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AttachmentReference2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub attachment: u32,
+    pub layout: ImageLayout,
+    pub aspectMask: ImageAspectFlags,
+}
+extensible!{AttachmentReference2, ExtendsAttachmentReference2}
+tagged!{AttachmentReference2, ATTACHMENT_REFERENCE_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SubpassDescription2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: SubpassDescriptionFlags,
+    pub pipelineBindPoint: PipelineBindPoint,
+    pub viewMask: u32,
+    pub inputAttachmentCount: u32,
+    pub pInputAttachments: *const AttachmentReference2,
+    pub colorAttachmentCount: u32,
+    pub pColorAttachments: *const AttachmentReference2,
+    pub pResolveAttachments: *const AttachmentReference2,
+    pub pDepthStencilAttachment: *const AttachmentReference2,
+    pub preserveAttachmentCount: u32,
+    pub pPreserveAttachments: *const u32,
+}
+extensible!{SubpassDescription2, ExtendsSubpassDescription2}
+tagged!{SubpassDescription2, SUBPASS_DESCRIPTION_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SubpassDependency2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcSubpass: u32,
+    pub dstSubpass: u32,
+    pub srcStageMask: PipelineStageFlags,
+    pub dstStageMask: PipelineStageFlags,
+    pub srcAccessMask: AccessFlags,
+    pub dstAccessMask: AccessFlags,
+    pub dependencyFlags: DependencyFlags,
+    pub viewOffset: i32,
+}
+extensible!{SubpassDependency2, ExtendsSubpassDependency2}
+tagged!{SubpassDependency2, SUBPASS_DEPENDENCY_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderPassCreateInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: RenderPassCreateFlags,
+    pub attachmentCount: u32,
+    pub pAttachments: *const AttachmentDescription2,
+    pub subpassCount: u32,
+    pub pSubpasses: *const SubpassDescription2,
+    pub dependencyCount: u32,
+    pub pDependencies: *const SubpassDependency2,
+    pub correlatedViewMaskCount: u32,
+    pub pCorrelatedViewMasks: *const u32,
+}
+tagged!{RenderPassCreateInfo2, RENDER_PASS_CREATE_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SubpassBeginInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub contents: SubpassContents,
+}
+tagged!{SubpassBeginInfo, SUBPASS_BEGIN_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SubpassEndInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+}
+tagged!{SubpassEndInfo, SUBPASS_END_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDevice8BitStorageFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub storageBuffer8BitAccess: Bool32,
+    pub uniformAndStorageBuffer8BitAccess: Bool32,
+    pub storagePushConstant8: Bool32,
+}
+tagged!{PhysicalDevice8BitStorageFeatures, PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceDriverProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub driverID: DriverId,
+    pub driverName: [::std::os::raw::c_char; 256usize],
+    pub driverInfo: [::std::os::raw::c_char; 256usize],
+    pub conformanceVersion: ConformanceVersion,
+}
+c_strings!{PhysicalDeviceDriverProperties {
+    driverName => (driver_name, driver_name_lossy),
+    driverInfo => (driver_info, driver_info_lossy)
+}}
+tagged!{PhysicalDeviceDriverProperties, PHYSICAL_DEVICE_DRIVER_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderAtomicInt64Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderBufferInt64Atomics: Bool32,
+    pub shaderSharedInt64Atomics: Bool32,
+}
+tagged!{PhysicalDeviceShaderAtomicInt64Features, PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderFloat16Int8Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderFloat16: Bool32,
+    pub shaderInt8: Bool32,
+}
+tagged!{PhysicalDeviceShaderFloat16Int8Features, PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceFloatControlsProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub denormBehaviorIndependence: ShaderFloatControlsIndependence,
+    pub roundingModeIndependence: ShaderFloatControlsIndependence,
+    pub shaderSignedZeroInfNanPreserveFloat16: Bool32,
+    pub shaderSignedZeroInfNanPreserveFloat32: Bool32,
+    pub shaderSignedZeroInfNanPreserveFloat64: Bool32,
+    pub shaderDenormPreserveFloat16: Bool32,
+    pub shaderDenormPreserveFloat32: Bool32,
+    pub shaderDenormPreserveFloat64: Bool32,
+    pub shaderDenormFlushToZeroFloat16: Bool32,
+    pub shaderDenormFlushToZeroFloat32: Bool32,
+    pub shaderDenormFlushToZeroFloat64: Bool32,
+    pub shaderRoundingModeRTEFloat16: Bool32,
+    pub shaderRoundingModeRTEFloat32: Bool32,
+    pub shaderRoundingModeRTEFloat64: Bool32,
+    pub shaderRoundingModeRTZFloat16: Bool32,
+    pub shaderRoundingModeRTZFloat32: Bool32,
+    pub shaderRoundingModeRTZFloat64: Bool32,
+}
+tagged!{PhysicalDeviceFloatControlsProperties, PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetLayoutBindingFlagsCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub bindingCount: u32,
+    pub pBindingFlags: *const DescriptorBindingFlags,
+}
+tagged!{DescriptorSetLayoutBindingFlagsCreateInfo, DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO;
+    ExtendsDescriptorSetLayoutCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceDescriptorIndexingFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderInputAttachmentArrayDynamicIndexing: Bool32,
+    pub shaderUniformTexelBufferArrayDynamicIndexing: Bool32,
+    pub shaderStorageTexelBufferArrayDynamicIndexing: Bool32,
+    pub shaderUniformBufferArrayNonUniformIndexing: Bool32,
+    pub shaderSampledImageArrayNonUniformIndexing: Bool32,
+    pub shaderStorageBufferArrayNonUniformIndexing: Bool32,
+    pub shaderStorageImageArrayNonUniformIndexing: Bool32,
+    pub shaderInputAttachmentArrayNonUniformIndexing: Bool32,
+    pub shaderUniformTexelBufferArrayNonUniformIndexing: Bool32,
+    pub shaderStorageTexelBufferArrayNonUniformIndexing: Bool32,
+    pub descriptorBindingUniformBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingSampledImageUpdateAfterBind: Bool32,
+    pub descriptorBindingStorageImageUpdateAfterBind: Bool32,
+    pub descriptorBindingStorageBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingUniformTexelBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingStorageTexelBufferUpdateAfterBind: Bool32,
+    pub descriptorBindingUpdateUnusedWhilePending: Bool32,
+    pub descriptorBindingPartiallyBound: Bool32,
+    pub descriptorBindingVariableDescriptorCount: Bool32,
+    pub runtimeDescriptorArray: Bool32,
+}
+tagged!{PhysicalDeviceDescriptorIndexingFeatures, PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceDescriptorIndexingProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxUpdateAfterBindDescriptorsInAllPools: u32,
+    pub shaderUniformBufferArrayNonUniformIndexingNative: Bool32,
+    pub shaderSampledImageArrayNonUniformIndexingNative: Bool32,
+    pub shaderStorageBufferArrayNonUniformIndexingNative: Bool32,
+    pub shaderStorageImageArrayNonUniformIndexingNative: Bool32,
+    pub shaderInputAttachmentArrayNonUniformIndexingNative: Bool32,
+    pub robustBufferAccessUpdateAfterBind: Bool32,
+    pub quadDivergentImplicitLod: Bool32,
+    pub maxPerStageDescriptorUpdateAfterBindSamplers: u32,
+    pub maxPerStageDescriptorUpdateAfterBindUniformBuffers: u32,
+    pub maxPerStageDescriptorUpdateAfterBindStorageBuffers: u32,
+    pub maxPerStageDescriptorUpdateAfterBindSampledImages: u32,
+    pub maxPerStageDescriptorUpdateAfterBindStorageImages: u32,
+    pub maxPerStageDescriptorUpdateAfterBindInputAttachments: u32,
+    pub maxPerStageUpdateAfterBindResources: u32,
+    pub maxDescriptorSetUpdateAfterBindSamplers: u32,
+    pub maxDescriptorSetUpdateAfterBindUniformBuffers: u32,
+    pub maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: u32,
+    pub maxDescriptorSetUpdateAfterBindStorageBuffers: u32,
+    pub maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: u32,
+    pub maxDescriptorSetUpdateAfterBindSampledImages: u32,
+    pub maxDescriptorSetUpdateAfterBindStorageImages: u32,
+    pub maxDescriptorSetUpdateAfterBindInputAttachments: u32,
+}
+tagged!{PhysicalDeviceDescriptorIndexingProperties, PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetVariableDescriptorCountAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub descriptorSetCount: u32,
+    pub pDescriptorCounts: *const u32,
+}
+tagged!{DescriptorSetVariableDescriptorCountAllocateInfo, DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorSetVariableDescriptorCountLayoutSupport {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxVariableDescriptorCount: u32,
+}
+tagged!{DescriptorSetVariableDescriptorCountLayoutSupport, DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SubpassDescriptionDepthStencilResolve {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub depthResolveMode: ResolveModeFlag,
+    pub stencilResolveMode: ResolveModeFlag,
+    pub pDepthStencilResolveAttachment: *const AttachmentReference2,
+}
+tagged!{SubpassDescriptionDepthStencilResolve, SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE;
+    ExtendsSubpassDescription2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceDepthStencilResolveProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub supportedDepthResolveModes: ResolveModeFlags,
+    pub supportedStencilResolveModes: ResolveModeFlags,
+    pub independentResolveNone: Bool32,
+    pub independentResolve: Bool32,
+}
+tagged!{PhysicalDeviceDepthStencilResolveProperties, PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceScalarBlockLayoutFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub scalarBlockLayout: Bool32,
+}
+tagged!{PhysicalDeviceScalarBlockLayoutFeatures, PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageStencilUsageCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub stencilUsage: ImageUsageFlags,
+}
+tagged!{ImageStencilUsageCreateInfo, IMAGE_STENCIL_USAGE_CREATE_INFO; ExtendsImageCreateInfo,
+    ExtendsPhysicalDeviceImageFormatInfo2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SamplerReductionModeCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub reductionMode: SamplerReductionMode,
+}
+tagged!{SamplerReductionModeCreateInfo, SAMPLER_REDUCTION_MODE_CREATE_INFO;
+    ExtendsSamplerCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSamplerFilterMinmaxProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub filterMinmaxSingleComponentFormats: Bool32,
+    pub filterMinmaxImageComponentMapping: Bool32,
+}
+tagged!{PhysicalDeviceSamplerFilterMinmaxProperties, PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkanMemoryModelFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub vulkanMemoryModel: Bool32,
+    pub vulkanMemoryModelDeviceScope: Bool32,
+    pub vulkanMemoryModelAvailabilityVisibilityChains: Bool32,
+}
+tagged!{PhysicalDeviceVulkanMemoryModelFeatures, PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceImagelessFramebufferFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub imagelessFramebuffer: Bool32,
+}
+tagged!{PhysicalDeviceImagelessFramebufferFeatures, PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FramebufferAttachmentImageInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: ImageCreateFlags,
+    pub usage: ImageUsageFlags,
+    pub width: u32,
+    pub height: u32,
+    pub layerCount: u32,
+    pub viewFormatCount: u32,
+    pub pViewFormats: *const Format,
+}
+tagged!{FramebufferAttachmentImageInfo, FRAMEBUFFER_ATTACHMENT_IMAGE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FramebufferAttachmentsCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub attachmentImageInfoCount: u32,
+    pub pAttachmentImageInfos: *const FramebufferAttachmentImageInfo,
+}
+tagged!{FramebufferAttachmentsCreateInfo, FRAMEBUFFER_ATTACHMENTS_CREATE_INFO;
+    ExtendsFramebufferCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderPassAttachmentBeginInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub attachmentCount: u32,
+    pub pAttachments: *const ImageView,
+}
+tagged!{RenderPassAttachmentBeginInfo, RENDER_PASS_ATTACHMENT_BEGIN_INFO;
+    ExtendsRenderPassBeginInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceUniformBufferStandardLayoutFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub uniformBufferStandardLayout: Bool32,
+}
+tagged!{PhysicalDeviceUniformBufferStandardLayoutFeatures, PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES;
+    ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderSubgroupExtendedTypesFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderSubgroupExtendedTypes: Bool32,
+}
+tagged!{PhysicalDeviceShaderSubgroupExtendedTypesFeatures, PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES;
+    ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSeparateDepthStencilLayoutsFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub separateDepthStencilLayouts: Bool32,
+}
+tagged!{PhysicalDeviceSeparateDepthStencilLayoutsFeatures, PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES;
+    ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AttachmentReferenceStencilLayout {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub stencilLayout: ImageLayout,
+}
+tagged!{AttachmentReferenceStencilLayout, ATTACHMENT_REFERENCE_STENCIL_LAYOUT;
+    ExtendsAttachmentReference2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AttachmentDescriptionStencilLayout {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub stencilInitialLayout: ImageLayout,
+    pub stencilFinalLayout: ImageLayout,
+}
+tagged!{AttachmentDescriptionStencilLayout, ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT;
+    ExtendsAttachmentDescription2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceHostQueryResetFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub hostQueryReset: Bool32,
+}
+tagged!{PhysicalDeviceHostQueryResetFeatures, PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceTimelineSemaphoreFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub timelineSemaphore: Bool32,
+}
+tagged!{PhysicalDeviceTimelineSemaphoreFeatures, PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceTimelineSemaphoreProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxTimelineSemaphoreValueDifference: u64,
+}
+tagged!{PhysicalDeviceTimelineSemaphoreProperties, PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SemaphoreTypeCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub semaphoreType: SemaphoreType,
+    pub initialValue: u64,
+}
+tagged!{SemaphoreTypeCreateInfo, SEMAPHORE_TYPE_CREATE_INFO; ExtendsSemaphoreCreateInfo,
+    ExtendsPhysicalDeviceExternalSemaphoreInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TimelineSemaphoreSubmitInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub waitSemaphoreValueCount: u32,
+    pub pWaitSemaphoreValues: *const u64,
+    pub signalSemaphoreValueCount: u32,
+    pub pSignalSemaphoreValues: *const u64,
+}
+tagged!{TimelineSemaphoreSubmitInfo, TIMELINE_SEMAPHORE_SUBMIT_INFO; ExtendsSubmitInfo,
+    ExtendsBindSparseInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SemaphoreWaitInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: SemaphoreWaitFlags,
+    pub semaphoreCount: u32,
+    pub pSemaphores: *const Semaphore,
+    pub pValues: *const u64,
+}
+tagged!{SemaphoreWaitInfo, SEMAPHORE_WAIT_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SemaphoreSignalInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub semaphore: Semaphore,
+    pub value: u64,
+}
+tagged!{SemaphoreSignalInfo, SEMAPHORE_SIGNAL_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceBufferDeviceAddressFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub bufferDeviceAddress: Bool32,
+    pub bufferDeviceAddressCaptureReplay: Bool32,
+    pub bufferDeviceAddressMultiDevice: Bool32,
+}
+tagged!{PhysicalDeviceBufferDeviceAddressFeatures, PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BufferDeviceAddressInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub buffer: Buffer,
+}
+tagged!{BufferDeviceAddressInfo, BUFFER_DEVICE_ADDRESS_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BufferOpaqueCaptureAddressCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub opaqueCaptureAddress: u64,
+}
+tagged!{BufferOpaqueCaptureAddressCreateInfo, BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO;
+    ExtendsBufferCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MemoryOpaqueCaptureAddressAllocateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub opaqueCaptureAddress: u64,
+}
+tagged!{MemoryOpaqueCaptureAddressAllocateInfo, MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO;
+    ExtendsMemoryAllocateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceMemoryOpaqueCaptureAddressInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub memory: DeviceMemory,
+}
+tagged!{DeviceMemoryOpaqueCaptureAddressInfo, DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkan13Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub robustImageAccess: Bool32,
+    pub inlineUniformBlock: Bool32,
+    pub descriptorBindingInlineUniformBlockUpdateAfterBind: Bool32,
+    pub pipelineCreationCacheControl: Bool32,
+    pub privateData: Bool32,
+    pub shaderDemoteToHelperInvocation: Bool32,
+    pub shaderTerminateInvocation: Bool32,
+    pub subgroupSizeControl: Bool32,
+    pub computeFullSubgroups: Bool32,
+    pub synchronization2: Bool32,
+    pub textureCompressionASTC_HDR: Bool32,
+    pub shaderZeroInitializeWorkgroupMemory: Bool32,
+    pub dynamicRendering: Bool32,
+    pub shaderIntegerDotProduct: Bool32,
+    pub maintenance4: Bool32,
+}
+tagged!{PhysicalDeviceVulkan13Features, PHYSICAL_DEVICE_VULKAN_1_3_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceVulkan13Properties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub minSubgroupSize: u32,
+    pub maxSubgroupSize: u32,
+    pub maxComputeWorkgroupSubgroups: u32,
+    pub requiredSubgroupSizeStages: ShaderStageFlags,
+    pub maxInlineUniformBlockSize: u32,
+    pub maxPerStageDescriptorInlineUniformBlocks: u32,
+    pub maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: u32,
+    pub maxDescriptorSetInlineUniformBlocks: u32,
+    pub maxDescriptorSetUpdateAfterBindInlineUniformBlocks: u32,
+    pub maxInlineUniformTotalSize: u32,
+    pub integerDotProduct8BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct8BitSignedAccelerated: Bool32,
+    pub integerDotProduct8BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct4x8BitPackedUnsignedAccelerated: Bool32,
+    pub integerDotProduct4x8BitPackedSignedAccelerated: Bool32,
+    pub integerDotProduct4x8BitPackedMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct16BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct16BitSignedAccelerated: Bool32,
+    pub integerDotProduct16BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct32BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct32BitSignedAccelerated: Bool32,
+    pub integerDotProduct32BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct64BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct64BitSignedAccelerated: Bool32,
+    pub integerDotProduct64BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating8BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating16BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating32BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating64BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: Bool32,
+    pub storageTexelBufferOffsetAlignmentBytes: DeviceSize,
+    pub storageTexelBufferOffsetSingleTexelAlignment: Bool32,
+    pub uniformTexelBufferOffsetAlignmentBytes: DeviceSize,
+    pub uniformTexelBufferOffsetSingleTexelAlignment: Bool32,
+    pub maxBufferSize: DeviceSize,
+}
+tagged!{PhysicalDeviceVulkan13Properties, PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct PipelineCreationFeedback {
+    pub flags: PipelineCreationFeedbackFlags,
+    pub duration: u64,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PipelineCreationFeedbackCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pPipelineCreationFeedback: *mut PipelineCreationFeedback,
+    pub pipelineStageCreationFeedbackCount: u32,
+    pub pPipelineStageCreationFeedbacks: *mut PipelineCreationFeedback,
+}
+tagged!{PipelineCreationFeedbackCreateInfo, PIPELINE_CREATION_FEEDBACK_CREATE_INFO;
+    ExtendsGraphicsPipelineCreateInfo, ExtendsComputePipelineCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderTerminateInvocationFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderTerminateInvocation: Bool32,
+}
+tagged!{PhysicalDeviceShaderTerminateInvocationFeatures, PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceToolProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub name: [::std::os::raw::c_char; 256usize],
+    pub version: [::std::os::raw::c_char; 256usize],
+    pub purposes: ToolPurposeFlags,
+    pub description: [::std::os::raw::c_char; 256usize],
+    pub layer: [::std::os::raw::c_char; 256usize],
+}
+c_strings!{PhysicalDeviceToolProperties {
+    name => (name, name_lossy),
+    version => (version, version_lossy),
+    description => (description, description_lossy),
+    layer => (layer, layer_lossy)
+}}
+tagged!{PhysicalDeviceToolProperties, PHYSICAL_DEVICE_TOOL_PROPERTIES}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderDemoteToHelperInvocationFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderDemoteToHelperInvocation: Bool32,
+}
+tagged!{PhysicalDeviceShaderDemoteToHelperInvocationFeatures, PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES;
+    ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDevicePrivateDataFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub privateData: Bool32,
+}
+tagged!{PhysicalDevicePrivateDataFeatures, PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DevicePrivateDataCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub privateDataSlotRequestCount: u32,
+}
+tagged!{DevicePrivateDataCreateInfo, DEVICE_PRIVATE_DATA_CREATE_INFO; ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PrivateDataSlotCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: PrivateDataSlotCreateFlags,
+}
+tagged!{PrivateDataSlotCreateInfo, PRIVATE_DATA_SLOT_CREATE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDevicePipelineCreationCacheControlFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub pipelineCreationCacheControl: Bool32,
+}
+tagged!{PhysicalDevicePipelineCreationCacheControlFeatures, PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES;
+    ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MemoryBarrier2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcStageMask: PipelineStageFlags2,
+    pub srcAccessMask: AccessFlags2,
+    pub dstStageMask: PipelineStageFlags2,
+    pub dstAccessMask: AccessFlags2,
+}
+tagged!{MemoryBarrier2, MEMORY_BARRIER_2; ExtendsSubpassDependency2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BufferMemoryBarrier2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcStageMask: PipelineStageFlags2,
+    pub srcAccessMask: AccessFlags2,
+    pub dstStageMask: PipelineStageFlags2,
+    pub dstAccessMask: AccessFlags2,
+    pub srcQueueFamilyIndex: u32,
+    pub dstQueueFamilyIndex: u32,
+    pub buffer: Buffer,
+    pub offset: DeviceSize,
+    pub size: DeviceSize,
+}
+tagged!{BufferMemoryBarrier2, BUFFER_MEMORY_BARRIER_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageMemoryBarrier2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcStageMask: PipelineStageFlags2,
+    pub srcAccessMask: AccessFlags2,
+    pub dstStageMask: PipelineStageFlags2,
+    pub dstAccessMask: AccessFlags2,
+    pub oldLayout: ImageLayout,
+    pub newLayout: ImageLayout,
+    pub srcQueueFamilyIndex: u32,
+    pub dstQueueFamilyIndex: u32,
+    pub image: Image,
+    pub subresourceRange: ImageSubresourceRange,
+}
+tagged!{ImageMemoryBarrier2, IMAGE_MEMORY_BARRIER_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DependencyInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub dependencyFlags: DependencyFlags,
+    pub memoryBarrierCount: u32,
+    pub pMemoryBarriers: *const MemoryBarrier2,
+    pub bufferMemoryBarrierCount: u32,
+    pub pBufferMemoryBarriers: *const BufferMemoryBarrier2,
+    pub imageMemoryBarrierCount: u32,
+    pub pImageMemoryBarriers: *const ImageMemoryBarrier2,
+}
+tagged!{DependencyInfo, DEPENDENCY_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SemaphoreSubmitInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub semaphore: Semaphore,
+    pub value: u64,
+    pub stageMask: PipelineStageFlags2,
+    pub deviceIndex: u32,
+}
+tagged!{SemaphoreSubmitInfo, SEMAPHORE_SUBMIT_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CommandBufferSubmitInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub commandBuffer: CommandBuffer,
+    pub deviceMask: u32,
+}
+tagged!{CommandBufferSubmitInfo, COMMAND_BUFFER_SUBMIT_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct SubmitInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: SubmitFlags,
+    pub waitSemaphoreInfoCount: u32,
+    pub pWaitSemaphoreInfos: *const SemaphoreSubmitInfo,
+    pub commandBufferInfoCount: u32,
+    pub pCommandBufferInfos: *const CommandBufferSubmitInfo,
+    pub signalSemaphoreInfoCount: u32,
+    pub pSignalSemaphoreInfos: *const SemaphoreSubmitInfo,
+}
+tagged!{SubmitInfo2, SUBMIT_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSynchronization2Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub synchronization2: Bool32,
+}
+tagged!{PhysicalDeviceSynchronization2Features, PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderZeroInitializeWorkgroupMemory: Bool32,
+}
+tagged!{PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES;
+    ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceImageRobustnessFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub robustImageAccess: Bool32,
+}
+tagged!{PhysicalDeviceImageRobustnessFeatures, PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BufferCopy2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcOffset: DeviceSize,
+    pub dstOffset: DeviceSize,
+    pub size: DeviceSize,
+}
+tagged!{BufferCopy2, BUFFER_COPY_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CopyBufferInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcBuffer: Buffer,
+    pub dstBuffer: Buffer,
+    pub regionCount: u32,
+    pub pRegions: *const BufferCopy2,
+}
+tagged!{CopyBufferInfo2, COPY_BUFFER_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageCopy2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcSubresource: ImageSubresourceLayers,
+    pub srcOffset: Offset3D,
+    pub dstSubresource: ImageSubresourceLayers,
+    pub dstOffset: Offset3D,
+    pub extent: Extent3D,
+}
+tagged!{ImageCopy2, IMAGE_COPY_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CopyImageInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcImage: Image,
+    pub srcImageLayout: ImageLayout,
+    pub dstImage: Image,
+    pub dstImageLayout: ImageLayout,
+    pub regionCount: u32,
+    pub pRegions: *const ImageCopy2,
+}
+tagged!{CopyImageInfo2, COPY_IMAGE_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BufferImageCopy2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub bufferOffset: DeviceSize,
+    pub bufferRowLength: u32,
+    pub bufferImageHeight: u32,
+    pub imageSubresource: ImageSubresourceLayers,
+    pub imageOffset: Offset3D,
+    pub imageExtent: Extent3D,
+}
+tagged!{BufferImageCopy2, BUFFER_IMAGE_COPY_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CopyBufferToImageInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcBuffer: Buffer,
+    pub dstImage: Image,
+    pub dstImageLayout: ImageLayout,
+    pub regionCount: u32,
+    pub pRegions: *const BufferImageCopy2,
+}
+tagged!{CopyBufferToImageInfo2, COPY_BUFFER_TO_IMAGE_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CopyImageToBufferInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcImage: Image,
+    pub srcImageLayout: ImageLayout,
+    pub dstBuffer: Buffer,
+    pub regionCount: u32,
+    pub pRegions: *const BufferImageCopy2,
+}
+tagged!{CopyImageToBufferInfo2, COPY_IMAGE_TO_BUFFER_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageBlit2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcSubresource: ImageSubresourceLayers,
+    pub srcOffsets: [Offset3D; 2usize],
+    pub dstSubresource: ImageSubresourceLayers,
+    pub dstOffsets: [Offset3D; 2usize],
+}
+tagged!{ImageBlit2, IMAGE_BLIT_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BlitImageInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcImage: Image,
+    pub srcImageLayout: ImageLayout,
+    pub dstImage: Image,
+    pub dstImageLayout: ImageLayout,
+    pub regionCount: u32,
+    pub pRegions: *const ImageBlit2,
+    pub filter: Filter,
+}
+tagged!{BlitImageInfo2, BLIT_IMAGE_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ImageResolve2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcSubresource: ImageSubresourceLayers,
+    pub srcOffset: Offset3D,
+    pub dstSubresource: ImageSubresourceLayers,
+    pub dstOffset: Offset3D,
+    pub extent: Extent3D,
+}
+tagged!{ImageResolve2, IMAGE_RESOLVE_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ResolveImageInfo2 {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub srcImage: Image,
+    pub srcImageLayout: ImageLayout,
+    pub dstImage: Image,
+    pub dstImageLayout: ImageLayout,
+    pub regionCount: u32,
+    pub pRegions: *const ImageResolve2,
+}
+tagged!{ResolveImageInfo2, RESOLVE_IMAGE_INFO_2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSubgroupSizeControlFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub subgroupSizeControl: Bool32,
+    pub computeFullSubgroups: Bool32,
+}
+tagged!{PhysicalDeviceSubgroupSizeControlFeatures, PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceSubgroupSizeControlProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub minSubgroupSize: u32,
+    pub maxSubgroupSize: u32,
+    pub maxComputeWorkgroupSubgroups: u32,
+    pub requiredSubgroupSizeStages: ShaderStageFlags,
+}
+tagged!{PhysicalDeviceSubgroupSizeControlProperties, PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PipelineShaderStageRequiredSubgroupSizeCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub requiredSubgroupSize: u32,
+}
+tagged!{PipelineShaderStageRequiredSubgroupSizeCreateInfo, PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceInlineUniformBlockFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub inlineUniformBlock: Bool32,
+    pub descriptorBindingInlineUniformBlockUpdateAfterBind: Bool32,
+}
+tagged!{PhysicalDeviceInlineUniformBlockFeatures, PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceInlineUniformBlockProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxInlineUniformBlockSize: u32,
+    pub maxPerStageDescriptorInlineUniformBlocks: u32,
+    pub maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: u32,
+    pub maxDescriptorSetInlineUniformBlocks: u32,
+    pub maxDescriptorSetUpdateAfterBindInlineUniformBlocks: u32,
+}
+tagged!{PhysicalDeviceInlineUniformBlockProperties, PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct WriteDescriptorSetInlineUniformBlock {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub dataSize: u32,
+    pub pData: *const ::std::os::raw::c_void,
+}
+tagged!{WriteDescriptorSetInlineUniformBlock, WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK;
+    ExtendsWriteDescriptorSet}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DescriptorPoolInlineUniformBlockCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub maxInlineUniformBlockBindings: u32,
+}
+tagged!{DescriptorPoolInlineUniformBlockCreateInfo, DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO;
+    ExtendsDescriptorPoolCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceTextureCompressionASTCHDRFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub textureCompressionASTC_HDR: Bool32,
+}
+tagged!{PhysicalDeviceTextureCompressionASTCHDRFeatures, PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderingAttachmentInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub imageView: ImageView,
+    pub imageLayout: ImageLayout,
+    pub resolveMode: ResolveModeFlag,
+    pub resolveImageView: ImageView,
+    pub resolveImageLayout: ImageLayout,
+    pub loadOp: AttachmentLoadOp,
+    pub storeOp: AttachmentStoreOp,
+    pub clearValue: ClearValue,
+}
+tagged!{RenderingAttachmentInfo, RENDERING_ATTACHMENT_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderingInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: RenderingFlags,
+    pub renderArea: Rect2D,
+    pub layerCount: u32,
+    pub viewMask: u32,
+    pub colorAttachmentCount: u32,
+    pub pColorAttachments: *const RenderingAttachmentInfo,
+    pub pDepthAttachment: *const RenderingAttachmentInfo,
+    pub pStencilAttachment: *const RenderingAttachmentInfo,
+}
+extensible!{RenderingInfo, ExtendsRenderingInfo}
+tagged!{RenderingInfo, RENDERING_INFO}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PipelineRenderingCreateInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub viewMask: u32,
+    pub colorAttachmentCount: u32,
+    pub pColorAttachmentFormats: *const Format,
+    pub depthAttachmentFormat: Format,
+    pub stencilAttachmentFormat: Format,
+}
+tagged!{PipelineRenderingCreateInfo, PIPELINE_RENDERING_CREATE_INFO;
+    ExtendsGraphicsPipelineCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceDynamicRenderingFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub dynamicRendering: Bool32,
+}
+tagged!{PhysicalDeviceDynamicRenderingFeatures, PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct CommandBufferInheritanceRenderingInfo {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub flags: RenderingFlags,
+    pub viewMask: u32,
+    pub colorAttachmentCount: u32,
+    pub pColorAttachmentFormats: *const Format,
+    pub depthAttachmentFormat: Format,
+    pub stencilAttachmentFormat: Format,
+    pub rasterizationSamples: SampleCountFlag,
+}
+tagged!{CommandBufferInheritanceRenderingInfo, COMMAND_BUFFER_INHERITANCE_RENDERING_INFO;
+    ExtendsCommandBufferInheritanceInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderIntegerDotProductFeatures {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub shaderIntegerDotProduct: Bool32,
+}
+tagged!{PhysicalDeviceShaderIntegerDotProductFeatures, PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceShaderIntegerDotProductProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub integerDotProduct8BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct8BitSignedAccelerated: Bool32,
+    pub integerDotProduct8BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct4x8BitPackedUnsignedAccelerated: Bool32,
+    pub integerDotProduct4x8BitPackedSignedAccelerated: Bool32,
+    pub integerDotProduct4x8BitPackedMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct16BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct16BitSignedAccelerated: Bool32,
+    pub integerDotProduct16BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct32BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct32BitSignedAccelerated: Bool32,
+    pub integerDotProduct32BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProduct64BitUnsignedAccelerated: Bool32,
+    pub integerDotProduct64BitSignedAccelerated: Bool32,
+    pub integerDotProduct64BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating8BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating16BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating32BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating64BitSignedAccelerated: Bool32,
+    pub integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: Bool32,
+}
+tagged!{PhysicalDeviceShaderIntegerDotProductProperties, PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceTexelBufferAlignmentProperties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub storageTexelBufferOffsetAlignmentBytes: DeviceSize,
+    pub storageTexelBufferOffsetSingleTexelAlignment: Bool32,
+    pub uniformTexelBufferOffsetAlignmentBytes: DeviceSize,
+    pub uniformTexelBufferOffsetSingleTexelAlignment: Bool32,
+}
+tagged!{PhysicalDeviceTexelBufferAlignmentProperties, PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct FormatProperties3 {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub linearTilingFeatures: FormatFeatureFlags2,
+    pub optimalTilingFeatures: FormatFeatureFlags2,
+    pub bufferFeatures: FormatFeatureFlags2,
+}
+tagged!{FormatProperties3, FORMAT_PROPERTIES_3; ExtendsFormatProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMaintenance4Features {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maintenance4: Bool32,
+}
+tagged!{PhysicalDeviceMaintenance4Features, PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES;
+    ExtendsPhysicalDeviceFeatures2, ExtendsDeviceCreateInfo}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct PhysicalDeviceMaintenance4Properties {
+    pub sType: StructureType,
+    pub pNext: *mut ::std::os::raw::c_void,
+    pub maxBufferSize: DeviceSize,
+}
+tagged!{PhysicalDeviceMaintenance4Properties, PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES;
+    ExtendsPhysicalDeviceProperties2}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceBufferMemoryRequirements {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pCreateInfo: *const BufferCreateInfo,
+}
+tagged!{DeviceBufferMemoryRequirements, DEVICE_BUFFER_MEMORY_REQUIREMENTS}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DeviceImageMemoryRequirements {
+    pub sType: StructureType,
+    pub pNext: *const ::std::os::raw::c_void,
+    pub pCreateInfo: *const ImageCreateInfo,
+    pub planeAspect: ImageAspectFlag,
+}
+tagged!{DeviceImageMemoryRequirements, DEVICE_IMAGE_MEMORY_REQUIREMENTS}
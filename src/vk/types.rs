@@ -1,40 +1,83 @@
 pub type DeviceSize = u64;
+pub type DeviceAddress = u64;
 pub type SampleMask = u32;
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-#[repr(u32)]
-pub enum Bool32 {
-    False = 0,
-    True = 1,
+/// A Vulkan boolean. Implementations may write any non-zero value for true,
+/// so it is a `u32` rather than an enum; compare with `==` or convert to
+/// `bool` rather than matching on the raw value.
+#[repr(transparent)]
+#[derive(Copy, Clone, Default)]
+pub struct Bool32(pub u32);
+
+pub const FALSE: Bool32 = Bool32(0);
+pub const TRUE: Bool32 = Bool32(1);
+
+impl From<bool> for Bool32 {
+    #[inline]
+    fn from(value: bool) -> Bool32 { if value { TRUE } else { FALSE } }
+}
+
+impl From<Bool32> for bool {
+    #[inline]
+    fn from(value: Bool32) -> bool { value.0 != 0 }
+}
+
+impl PartialEq for Bool32 {
+    #[inline]
+    fn eq(&self, other: &Bool32) -> bool { bool::from(*self) == bool::from(*other) }
+}
+
+impl Eq for Bool32 { }
+
+impl ::std::hash::Hash for Bool32 {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) { bool::from(*self).hash(state) }
+}
+
+impl ::std::fmt::Debug for Bool32 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::std::fmt::Debug::fmt(&bool::from(*self), f)
+    }
+}
+
+// Handles
+
+/// Operations shared by every handle type.
+pub trait Handle: Copy {
+    /// The object type of this handle, as used to name or report objects.
+    const OBJECT_TYPE: ::vk::ObjectType;
+
+    /// The handle as the `uint64_t` used by object names and debug reports.
+    fn as_raw(self) -> u64;
+
+    /// The inverse of `as_raw`.
+    fn from_raw(raw: u64) -> Self;
 }
 
-pub const FALSE: Bool32 = Bool32::False;
-pub const TRUE: Bool32 = Bool32::True;
-
-// Opaque structs
-
-opaque!{_Instance, Instance}
-opaque!{_PhysicalDevice, PhysicalDevice}
-opaque!{_Device, Device}
-opaque!{_Queue, Queue}
-opaque!{_Semaphore, Semaphore}
-opaque!{_CommandBuffer, CommandBuffer}
-opaque!{_Fence, Fence}
-opaque!{_DeviceMemory, DeviceMemory}
-opaque!{_Buffer, Buffer}
-opaque!{_Image, Image}
-opaque!{_Event, Event}
-opaque!{_QueryPool, QueryPool}
-opaque!{_BufferView, BufferView}
-opaque!{_ImageView, ImageView}
-opaque!{_ShaderModule, ShaderModule}
-opaque!{_PipelineCache, PipelineCache}
-opaque!{_PipelineLayout, PipelineLayout}
-opaque!{_RenderPass, RenderPass}
-opaque!{_Pipeline, Pipeline}
-opaque!{_DescriptorSetLayout, DescriptorSetLayout}
-opaque!{_Sampler, Sampler}
-opaque!{_DescriptorPool, DescriptorPool}
-opaque!{_DescriptorSet, DescriptorSet}
-opaque!{_Framebuffer, Framebuffer}
-opaque!{_CommandPool, CommandPool}
+opaque!{_Instance, Instance, INSTANCE}
+opaque!{_PhysicalDevice, PhysicalDevice, PHYSICAL_DEVICE}
+opaque!{_Device, Device, DEVICE}
+opaque!{_Queue, Queue, QUEUE}
+handle!{Semaphore, SEMAPHORE}
+opaque!{_CommandBuffer, CommandBuffer, COMMAND_BUFFER}
+handle!{Fence, FENCE}
+handle!{DeviceMemory, DEVICE_MEMORY}
+handle!{Buffer, BUFFER}
+handle!{Image, IMAGE}
+handle!{Event, EVENT}
+handle!{QueryPool, QUERY_POOL}
+handle!{BufferView, BUFFER_VIEW}
+handle!{ImageView, IMAGE_VIEW}
+handle!{ShaderModule, SHADER_MODULE}
+handle!{PipelineCache, PIPELINE_CACHE}
+handle!{PipelineLayout, PIPELINE_LAYOUT}
+handle!{RenderPass, RENDER_PASS}
+handle!{Pipeline, PIPELINE}
+handle!{DescriptorSetLayout, DESCRIPTOR_SET_LAYOUT}
+handle!{Sampler, SAMPLER}
+handle!{DescriptorPool, DESCRIPTOR_POOL}
+handle!{DescriptorSet, DESCRIPTOR_SET}
+handle!{Framebuffer, FRAMEBUFFER}
+handle!{CommandPool, COMMAND_POOL}
+handle!{SamplerYcbcrConversion, SAMPLER_YCBCR_CONVERSION}
+handle!{DescriptorUpdateTemplate, DESCRIPTOR_UPDATE_TEMPLATE}
+handle!{PrivateDataSlot, PRIVATE_DATA_SLOT}
@@ -2,36 +2,68 @@ use vk::*;
 
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct ClearColorValue {
-    _bindgen_data_: [u32; 4usize],
+pub union ClearColorValue {
+    pub float32: [f32; 4usize],
+    pub int32: [i32; 4usize],
+    pub uint32: [u32; 4usize],
 }
+union_bits!{ClearColorValue, [u32; 4usize]}
 impl ClearColorValue {
-    pub unsafe fn float32(&mut self)
-     -> *mut [::std::os::raw::c_float; 4usize] {
-        let raw: *mut u8 = ::std::mem::transmute(&self._bindgen_data_);
-        ::std::mem::transmute(raw.offset(0))
+    #[inline]
+    pub fn float32(float32: [f32; 4usize]) -> Self {
+        let mut value = Self::default();
+        value.float32 = float32;
+        value
     }
-    pub unsafe fn int32(&mut self) -> *mut [i32; 4usize] {
-        let raw: *mut u8 = ::std::mem::transmute(&self._bindgen_data_);
-        ::std::mem::transmute(raw.offset(0))
+    #[inline]
+    pub fn int32(int32: [i32; 4usize]) -> Self {
+        let mut value = Self::default();
+        value.int32 = int32;
+        value
     }
-    pub unsafe fn uint32(&mut self) -> *mut [u32; 4usize] {
-        let raw: *mut u8 = ::std::mem::transmute(&self._bindgen_data_);
-        ::std::mem::transmute(raw.offset(0))
+    #[inline]
+    pub fn uint32(uint32: [u32; 4usize]) -> Self {
+        let mut value = Self::default();
+        value.uint32 = uint32;
+        value
     }
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
-pub struct ClearValue {
-    _bindgen_data_: [u32; 4usize],
+pub union ClearValue {
+    pub color: ClearColorValue,
+    pub depthStencil: ClearDepthStencilValue,
 }
+union_bits!{ClearValue, [u32; 4usize]}
 impl ClearValue {
-    pub unsafe fn color(&mut self) -> *mut ClearColorValue {
-        let raw: *mut u8 = ::std::mem::transmute(&self._bindgen_data_);
-        ::std::mem::transmute(raw.offset(0))
+    #[inline]
+    pub fn color(color: ClearColorValue) -> Self {
+        let mut value = Self::default();
+        value.color = color;
+        value
     }
-    pub unsafe fn depthStencil(&mut self) -> *mut ClearDepthStencilValue {
-        let raw: *mut u8 = ::std::mem::transmute(&self._bindgen_data_);
-        ::std::mem::transmute(raw.offset(0))
+    #[inline]
+    pub fn color_f32(float32: [f32; 4usize]) -> Self {
+        let mut value = Self::default();
+        value.color = ClearColorValue::float32(float32);
+        value
+    }
+    #[inline]
+    pub fn color_i32(int32: [i32; 4usize]) -> Self {
+        let mut value = Self::default();
+        value.color = ClearColorValue::int32(int32);
+        value
+    }
+    #[inline]
+    pub fn color_u32(uint32: [u32; 4usize]) -> Self {
+        let mut value = Self::default();
+        value.color = ClearColorValue::uint32(uint32);
+        value
+    }
+    #[inline]
+    pub fn depth_stencil(depth: f32, stencil: u32) -> Self {
+        let mut value = Self::default();
+        value.depthStencil = ClearDepthStencilValue { depth, stencil };
+        value
     }
 }
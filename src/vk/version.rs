@@ -0,0 +1,177 @@
+// Packed API and driver version numbers
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use vk::{ApplicationInfo, PhysicalDeviceProperties};
+
+/// PCI vendor ID of NVIDIA devices.
+pub const VENDOR_ID_NVIDIA: u32 = 0x10DE;
+/// PCI vendor ID of AMD devices.
+pub const VENDOR_ID_AMD: u32 = 0x1002;
+/// PCI vendor ID of Intel devices.
+pub const VENDOR_ID_INTEL: u32 = 0x8086;
+/// Khronos vendor ID of Mesa's software devices, such as lavapipe.
+pub const VENDOR_ID_MESA: u32 = 0x10005;
+
+/// A version number as packed by `VK_MAKE_API_VERSION`: 3 bits of variant,
+/// then 7 of major, 10 of minor and 12 of patch. Orders like the packed
+/// value.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub variant: u32,
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Version {
+        Version::with_variant(0, major, minor, patch)
+    }
+
+    pub fn with_variant(variant: u32, major: u32, minor: u32, patch: u32) -> Version {
+        Version { variant, major, minor, patch }
+    }
+
+    pub fn from_raw(raw: u32) -> Version {
+        Version {
+            variant: raw >> 29,
+            major: (raw >> 22) & 0x7f,
+            minor: (raw >> 12) & 0x3ff,
+            patch: raw & 0xfff,
+        }
+    }
+
+    /// The packed value. Fields too large for their bits are truncated.
+    pub fn to_raw(self) -> u32 {
+        (self.variant & 0x7) << 29 | (self.major & 0x7f) << 22 | (self.minor & 0x3ff) << 12
+            | self.patch & 0xfff
+    }
+}
+
+impl From<u32> for Version {
+    fn from(raw: u32) -> Version {
+        Version::from_raw(raw)
+    }
+}
+
+impl From<Version> for u32 {
+    fn from(version: Version) -> u32 {
+        version.to_raw()
+    }
+}
+
+/// `1.0.4`, or `1:1.0.4` for variant 1. The spec has no text form for the
+/// variant; the `variant:` prefix is this crate's, after the `epoch:` of
+/// Debian package versions, and is left out when the variant is 0.
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.variant != 0 {
+            write!(f, "{}:", self.variant)?;
+        }
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Parses the `Display` form. The patch may be left out, as in `1.2`.
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> ::std::result::Result<Version, ParseVersionError> {
+        let err = || ParseVersionError { text: s.to_owned() };
+        let field = |text: &str, max: u32| match text.parse() {
+            Ok(n) if n <= max && !text.starts_with('+') => Ok(n),
+            _ => Err(err()),
+        };
+        let (variant, rest) = match s.find(':') {
+            Some(i) => (field(&s[..i], 0x7)?, &s[i + 1..]),
+            None => (0, s),
+        };
+        let parts: Vec<&str> = rest.split('.').collect();
+        let patch = match parts.len() {
+            2 => 0,
+            3 => field(parts[2], 0xfff)?,
+            _ => return Err(err()),
+        };
+        Ok(Version::with_variant(variant, field(parts[0], 0x7f)?, field(parts[1], 0x3ff)?, patch))
+    }
+}
+
+/// The error from parsing a `Version` out of a string of another form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseVersionError {
+    text: String,
+}
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a Vulkan version", self.text)
+    }
+}
+
+impl Error for ParseVersionError {}
+
+/// `PhysicalDeviceProperties::driverVersion`, decoded by the vendor's own
+/// scheme.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DriverVersion {
+    /// Packed like an API version, as by AMD, Mesa and most other vendors.
+    Standard(Version),
+    /// NVIDIA's 10, 8, 8 and 6 bits, such as `535.98.0.0`.
+    Nvidia { major: u32, minor: u32, secondary: u32, tertiary: u32 },
+    /// Intel's Windows drivers' 18 and 14 bits, such as `101.2115`. Intel's
+    /// drivers elsewhere use the standard packing.
+    IntelWindows { major: u32, minor: u32 },
+}
+
+impl DriverVersion {
+    /// Decodes `raw` by the scheme of the vendor with `vendor_id`, falling
+    /// back to the standard packing for unknown vendors. `windows` is whether
+    /// the driver runs on Windows, which changes Intel's scheme.
+    pub fn decode(vendor_id: u32, raw: u32, windows: bool) -> DriverVersion {
+        match vendor_id {
+            VENDOR_ID_NVIDIA => DriverVersion::Nvidia {
+                major: raw >> 22,
+                minor: (raw >> 14) & 0xff,
+                secondary: (raw >> 6) & 0xff,
+                tertiary: raw & 0x3f,
+            },
+            VENDOR_ID_INTEL if windows => DriverVersion::IntelWindows {
+                major: raw >> 14,
+                minor: raw & 0x3fff,
+            },
+            _ => DriverVersion::Standard(Version::from_raw(raw)),
+        }
+    }
+}
+
+impl fmt::Display for DriverVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DriverVersion::Standard(version) => version.fmt(f),
+            DriverVersion::Nvidia { major, minor, secondary, tertiary } =>
+                write!(f, "{}.{}.{}.{}", major, minor, secondary, tertiary),
+            DriverVersion::IntelWindows { major, minor } => write!(f, "{}.{}", major, minor),
+        }
+    }
+}
+
+impl PhysicalDeviceProperties {
+    pub fn api_version(&self) -> Version {
+        Version::from_raw(self.apiVersion)
+    }
+
+    /// `driverVersion` decoded for a driver on the platform this was built
+    /// for. Use `DriverVersion::decode` for properties recorded elsewhere.
+    pub fn driver_version(&self) -> DriverVersion {
+        DriverVersion::decode(self.vendorID, self.driverVersion, cfg!(windows))
+    }
+}
+
+impl ApplicationInfo {
+    pub fn api_version(&self) -> Version {
+        Version::from_raw(self.apiVersion)
+    }
+}
@@ -0,0 +1,228 @@
+//! `vk::Allocator` against the mock implementation.
+#![cfg(feature = "mock")]
+
+extern crate vulkan_bind;
+
+use std::ptr::null;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::mock;
+
+mod common;
+
+use common::Setup;
+
+/// A device of the mock's first physical device.
+struct DeviceSetup {
+    setup: Setup,
+    fns: vk::DeviceFns,
+    device: vk::Device,
+    properties: vk::PhysicalDeviceProperties,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+}
+
+impl DeviceSetup {
+    fn new() -> DeviceSetup {
+        let setup = Setup::new();
+        let (device, fns) = setup.device();
+        let properties = setup.properties();
+        let memory_properties = setup.memory_properties();
+        DeviceSetup { setup, fns, device, properties, memory_properties }
+    }
+
+    fn allocator(&self) -> vk::Allocator {
+        vk::Allocator::new(&self.fns, self.device, &self.properties, &self.memory_properties)
+    }
+
+    fn fns_v1_1(&self) -> vk::DeviceFnsV1_1 {
+        unsafe {
+            vk::DeviceFnsV1_1::load_with(|name| {
+                self.setup.instance_fns.vkGetDeviceProcAddr(self.device, name.as_ptr())
+            })
+        }
+    }
+
+    fn buffer(&self, size: vk::DeviceSize) -> vk::Buffer {
+        let info = vk::BufferCreateInfo { size, ..Default::default() };
+        let mut buffer = vk::Buffer::null();
+        unsafe {
+            self.fns.vkCreateBuffer(self.device, &info, null(), &mut buffer).result().unwrap();
+        }
+        buffer
+    }
+
+    fn image(&self, width: u32, height: u32) -> vk::Image {
+        let info = vk::ImageCreateInfo {
+            imageType: vk::ImageType::E_2D,
+            format: vk::Format::R8G8B8A8_UNORM,
+            extent: vk::Extent3D { width, height, depth: 1 },
+            mipLevels: 1,
+            arrayLayers: 1,
+            samples: vk::SampleCountFlag::E_1.into(),
+            ..Default::default()
+        };
+        let mut image = vk::Image::null();
+        unsafe {
+            self.fns.vkCreateImage(self.device, &info, null(), &mut image).result().unwrap();
+        }
+        image
+    }
+}
+
+fn requirements(size: vk::DeviceSize) -> vk::MemoryRequirements {
+    vk::MemoryRequirements { size, alignment: 256, memoryTypeBits: !0 }
+}
+
+fn linear() -> vk::AllocationRequest {
+    vk::AllocationRequest { linear: true, ..Default::default() }
+}
+
+#[test]
+fn first_fit() {
+    let setup = DeviceSetup::new();
+    let mut allocator = setup.allocator();
+    unsafe {
+        let a = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let b = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let c = allocator.allocate(&requirements(256), &linear()).unwrap();
+        assert_eq!((a.offset(), b.offset(), c.offset()), (0, 256, 512));
+        assert_eq!(a.memory(), c.memory());
+        allocator.free(b);
+        let d = allocator.allocate(&requirements(256), &linear()).unwrap();
+        assert_eq!(d.offset(), 256);
+        let e = allocator.allocate(&requirements(512), &linear()).unwrap();
+        assert_eq!(e.offset(), 768);
+        assert_eq!(allocator.heap_stats()[0].allocation_count, 4);
+        assert_eq!(allocator.heap_stats()[0].used, 1280);
+    }
+}
+
+#[test]
+fn granularity_separates_linear_and_optimal() {
+    let setup = DeviceSetup::new();
+    assert_eq!(setup.properties.limits.bufferImageGranularity, 1024);
+    let mut allocator = setup.allocator();
+    let optimal = vk::AllocationRequest::default();
+    unsafe {
+        let buffer = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let image = allocator.allocate(&requirements(256), &optimal).unwrap();
+        assert_eq!((buffer.offset(), image.offset()), (0, 1024));
+        // Another buffer fits between them, on the first buffer's page.
+        let buffer2 = allocator.allocate(&requirements(256), &linear()).unwrap();
+        assert_eq!(buffer2.offset(), 256);
+        // An image can't share that page, nor a buffer the image's.
+        let image2 = allocator.allocate(&requirements(256), &optimal).unwrap();
+        assert_eq!(image2.offset(), 1280);
+        let buffer3 = allocator.allocate(&requirements(256), &linear()).unwrap();
+        assert_eq!(buffer3.offset(), 512);
+        let buffer4 = allocator.allocate(&requirements(1024), &linear()).unwrap();
+        assert_eq!(buffer4.offset(), 2048);
+    }
+}
+
+#[test]
+fn free_coalesces_neighbours() {
+    let setup = DeviceSetup::new();
+    let mut allocator = setup.allocator();
+    unsafe {
+        let a = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let b = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let c = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let memory = c.memory();
+        allocator.free(a);
+        allocator.free(b);
+        let ab = allocator.allocate(&requirements(512), &linear()).unwrap();
+        assert_eq!((ab.memory(), ab.offset()), (memory, 0));
+        allocator.free(ab);
+        allocator.free(c);
+        // The empty block is given back.
+        assert_eq!(allocator.heap_stats()[0].block_count, 0);
+        assert!(mock::live_objects(setup.device).is_empty());
+    }
+}
+
+#[test]
+fn map() {
+    let setup = DeviceSetup::new();
+    let mut allocator = setup.allocator();
+    let request = vk::AllocationRequest {
+        required: vk::MemoryPropertyFlag::HOST_VISIBLE.into(),
+        linear: true,
+        ..Default::default()
+    };
+    unsafe {
+        let a = allocator.allocate(&requirements(256), &request).unwrap();
+        let b = allocator.allocate(&requirements(256), &request).unwrap();
+        let pa = allocator.map(&a).unwrap() as *mut u8;
+        let pb = allocator.map(&b).unwrap() as *mut u8;
+        assert_eq!(pb as usize - pa as usize, 256);
+        *pa = 7;
+        assert_eq!(*(allocator.map(&a).unwrap() as *mut u8), 7);
+        assert!(mock::take_errors(setup.device).is_empty());
+    }
+}
+
+#[test]
+fn small_block_sizes_are_raised() {
+    let setup = DeviceSetup::new();
+    let mut allocator = setup.allocator().with_block_size(0);
+    unsafe {
+        let a = allocator.allocate(&requirements(256), &linear()).unwrap();
+        let b = allocator.allocate(&requirements(256), &linear()).unwrap();
+        assert_eq!(a.memory(), b.memory());
+        assert_eq!(allocator.heap_stats()[0].reserved, 64 << 10);
+    }
+}
+
+#[test]
+fn max_memory_allocation_count() {
+    let mut setup = DeviceSetup::new();
+    setup.properties.limits.maxMemoryAllocationCount = 2;
+    let mut allocator = setup.allocator();
+    let request = vk::AllocationRequest { dedicated: true, ..linear() };
+    unsafe {
+        allocator.allocate(&requirements(256), &request).unwrap();
+        allocator.allocate(&requirements(256), &request).unwrap();
+        let err = allocator.allocate(&requirements(256), &request).unwrap_err();
+        assert_eq!(err.0, vk::Result::ERROR_TOO_MANY_OBJECTS);
+    }
+}
+
+#[test]
+fn dedicated_allocations_name_their_resource() {
+    let setup = DeviceSetup::new();
+    let fns_v1_1 = setup.fns_v1_1();
+    let mut allocator = setup.allocator().with_dedicated_allocation(&fns_v1_1);
+    unsafe {
+        // The mock prefers dedicated memory for images of 16 MiB, which is
+        // half a block of this heap.
+        let image = setup.image(2048, 2048);
+        let allocation = allocator.allocate_image(image, &Default::default()).unwrap();
+        assert_eq!(mock::dedicated_resource(setup.device, allocation.memory()),
+                   Some((vk::ObjectType::IMAGE, image.0)));
+
+        let buffer = setup.buffer(256);
+        let request = vk::AllocationRequest { dedicated: true, ..Default::default() };
+        let allocation = allocator.allocate_buffer(buffer, &request).unwrap();
+        assert_eq!(mock::dedicated_resource(setup.device, allocation.memory()),
+                   Some((vk::ObjectType::BUFFER, buffer.0)));
+
+        let small = setup.buffer(256);
+        let allocation = allocator.allocate_buffer(small, &Default::default()).unwrap();
+        assert_eq!(mock::dedicated_resource(setup.device, allocation.memory()), None);
+        assert_eq!(allocator.heap_stats()[0].dedicated_count, 2);
+        assert!(mock::take_errors(setup.device).is_empty());
+    }
+}
+
+#[test]
+fn without_dedicated_allocation_images_share_blocks() {
+    let setup = DeviceSetup::new();
+    let mut allocator = setup.allocator();
+    unsafe {
+        let image = setup.image(2048, 2048);
+        let allocation = allocator.allocate_image(image, &Default::default()).unwrap();
+        assert_eq!(mock::dedicated_resource(setup.device, allocation.memory()), None);
+        assert_eq!(allocator.heap_stats()[0].dedicated_count, 0);
+    }
+}
@@ -0,0 +1,36 @@
+//! `vk::Bool32`, which treats any non-zero value as true.
+extern crate vulkan_bind;
+
+use std::collections::HashSet;
+use std::mem;
+
+use vulkan_bind::vk;
+
+#[test]
+fn non_zero_is_true() {
+    assert_eq!(vk::Bool32(5), vk::TRUE);
+    assert_ne!(vk::Bool32(5), vk::FALSE);
+    assert!(bool::from(vk::Bool32(5)));
+    assert!(!bool::from(vk::Bool32(0)));
+    assert_eq!(vk::Bool32::from(true).0, 1);
+    assert_eq!(vk::Bool32::from(false), vk::Bool32::default());
+}
+
+#[test]
+fn hash_agrees_with_eq() {
+    let set: HashSet<_> = [vk::Bool32(1), vk::Bool32(5), vk::Bool32(0)].iter().cloned().collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&vk::Bool32(0xffff_ffff)));
+}
+
+#[test]
+fn debug() {
+    assert_eq!(format!("{:?}", vk::Bool32(5)), "true");
+    assert_eq!(format!("{:?}", vk::FALSE), "false");
+}
+
+#[test]
+fn layout() {
+    assert_eq!(mem::size_of::<vk::Bool32>(), mem::size_of::<u32>());
+    assert_eq!(mem::align_of::<vk::Bool32>(), mem::align_of::<u32>());
+}
@@ -0,0 +1,89 @@
+//! The `*Info` builders: slices fill in their counts, and references and
+//! pushed structs become pointers.
+extern crate vulkan_bind;
+
+use std::ffi::CStr;
+use std::ptr;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::TaggedStructure;
+
+#[test]
+fn slices_set_pointer_and_count() {
+    let priorities = [1.0, 0.5];
+    let info = vk::DeviceQueueCreateInfo::builder()
+        .queue_family_index(2)
+        .queue_priorities(&priorities)
+        .build();
+    assert_eq!(info.sType, vk::StructureType::DEVICE_QUEUE_CREATE_INFO);
+    assert_eq!(info.queueFamilyIndex, 2);
+    assert_eq!(info.queueCount, 2);
+    assert_eq!(info.pQueuePriorities, priorities.as_ptr());
+}
+
+#[test]
+fn byte_sized_counts() {
+    let code = [0x0723_0203, 0, 0];
+    let info = vk::ShaderModuleCreateInfo::builder().code(&code);
+    assert_eq!(info.codeSize, 12);
+    assert_eq!(info.pCode, code.as_ptr());
+}
+
+#[test]
+fn uncounted_slices_are_null_when_empty() {
+    let info = vk::PipelineMultisampleStateCreateInfo::builder().sample_mask(&[]);
+    assert!(info.pSampleMask.is_null());
+    let mask = [!0];
+    let info = vk::PipelineMultisampleStateCreateInfo::builder().sample_mask(&mask);
+    assert_eq!(info.pSampleMask, mask.as_ptr());
+}
+
+#[test]
+fn references_and_strings() {
+    let name = CStr::from_bytes_with_nul(b"app\0").unwrap();
+    let app = vk::ApplicationInfo::builder()
+        .application_name(name)
+        .api_version(vk::API_VERSION_1_3);
+    let info = vk::InstanceCreateInfo::builder().application_info(&app);
+    assert_eq!(info.pApplicationInfo, &*app as *const _);
+    assert_eq!(app.pApplicationName, name.as_ptr());
+    assert_eq!(app.apiVersion, vk::API_VERSION_1_3);
+}
+
+#[test]
+fn info2_structs() {
+    let regions = [vk::BufferCopy2 { size: 16, ..Default::default() }; 3];
+    let copy = vk::CopyBufferInfo2::builder()
+        .src_buffer(vk::Buffer(1))
+        .dst_buffer(vk::Buffer(2))
+        .regions(&regions);
+    assert_eq!(copy.sType, vk::StructureType::COPY_BUFFER_INFO_2);
+    assert_eq!((copy.srcBuffer, copy.dstBuffer), (vk::Buffer(1), vk::Buffer(2)));
+    assert_eq!(copy.regionCount, 3);
+    assert_eq!(copy.pRegions, regions.as_ptr());
+
+    let command_buffers = [vk::CommandBufferSubmitInfo::default(); 2];
+    let submit = vk::SubmitInfo2::builder().command_buffer_infos(&command_buffers);
+    assert_eq!(submit.commandBufferInfoCount, 2);
+    assert_eq!(submit.waitSemaphoreInfoCount, 0);
+    assert!(submit.pWaitSemaphoreInfos.is_null());
+
+    let queue = vk::DeviceQueueInfo2::builder().queue_family_index(1).queue_index(3);
+    assert_eq!((queue.queueFamilyIndex, queue.queueIndex), (1, 3));
+}
+
+#[test]
+fn push_next_chains_the_struct() {
+    let mut external = vk::PhysicalDeviceExternalImageFormatInfo::default();
+    let ptr = &external as *const vk::PhysicalDeviceExternalImageFormatInfo;
+    let info = vk::PhysicalDeviceImageFormatInfo2::builder()
+        .format(vk::Format::R8G8B8A8_UNORM)
+        .push_next(&mut external);
+    assert_eq!(info.pNext, ptr as *const _);
+    let found = unsafe { info.find_next::<vk::PhysicalDeviceExternalImageFormatInfo>() };
+    assert_eq!(found.map(|s| s as *const _), Some(ptr));
+    assert!(unsafe { (*ptr).pNext.is_null() });
+
+    let info = vk::PhysicalDeviceImageFormatInfo2::builder();
+    assert_eq!(info.pNext, ptr::null());
+}
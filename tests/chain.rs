@@ -0,0 +1,58 @@
+//! Building and walking `pNext` chains.
+extern crate vulkan_bind;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::TaggedStructure;
+
+fn chain<T: TaggedStructure>(base: &T) -> Vec<vk::StructureType> {
+    unsafe { base.iter_next().map(|s| s.structure_type()).collect() }
+}
+
+#[test]
+fn push_next_links_after_base() {
+    let mut group = vk::DeviceGroupDeviceCreateInfo::default();
+    let mut features = vk::PhysicalDeviceFeatures2::default();
+    let mut info = vk::DeviceCreateInfo::default();
+    unsafe {
+        info.push_next(&mut group).push_next(&mut features);
+    }
+    assert_eq!(chain(&info), [vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+                              vk::StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO]);
+}
+
+#[test]
+fn push_next_keeps_the_pushed_chain() {
+    let mut group = vk::DeviceGroupDeviceCreateInfo::default();
+    let mut features = vk::PhysicalDeviceFeatures2::default();
+    let mut info = vk::DeviceCreateInfo::default();
+    features.pNext = &mut group as *mut _ as *mut _;
+    unsafe {
+        info.push_next(&mut features);
+    }
+    assert_eq!(chain(&info), [vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+                              vk::StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO]);
+    let found = unsafe { info.find_next::<vk::DeviceGroupDeviceCreateInfo>() };
+    assert_eq!(found.map(|s| s as *const _), Some(&group as *const _));
+}
+
+#[test]
+#[should_panic(expected = "already in the pNext chain")]
+fn push_next_twice_panics() {
+    let mut features = vk::PhysicalDeviceFeatures2::default();
+    let mut info = vk::DeviceCreateInfo::default();
+    unsafe {
+        info.push_next(&mut features);
+        info.push_next(&mut features);
+    }
+}
+
+#[test]
+fn builder_push_next() {
+    let mut group = vk::DeviceGroupDeviceCreateInfo::default();
+    let mut features = vk::PhysicalDeviceFeatures2::default();
+    let info = vk::DeviceCreateInfo::builder()
+        .push_next(&mut group)
+        .push_next(&mut features);
+    assert_eq!(chain(&*info), [vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+                               vk::StructureType::DEVICE_GROUP_DEVICE_CREATE_INFO]);
+}
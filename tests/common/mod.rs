@@ -0,0 +1,72 @@
+//! Fixtures shared by the tests that run against the mock implementation.
+#![allow(dead_code)]
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr::{null, null_mut};
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::mock;
+
+/// An instance of the mock and its first physical device.
+pub struct Setup {
+    pub instance: vk::Instance,
+    pub instance_fns: vk::InstanceFns,
+    pub physical_device: vk::PhysicalDevice,
+}
+
+impl Setup {
+    pub fn new() -> Setup {
+        Setup::from_entry(&mock::entry(Default::default()))
+    }
+
+    pub fn from_entry(entry: &vk::Entry) -> Setup {
+        unsafe {
+            let mut instance = null_mut();
+            entry.vkCreateInstance(&Default::default(), null(), &mut instance).result().unwrap();
+            let instance_fns = vk::InstanceFns::load(entry, instance).unwrap();
+            let physical_device = instance_fns.enumerate_physical_devices(instance).unwrap()[0];
+            Setup { instance, instance_fns, physical_device }
+        }
+    }
+
+    pub fn properties(&self) -> vk::PhysicalDeviceProperties {
+        let mut properties = Default::default();
+        unsafe {
+            self.instance_fns.vkGetPhysicalDeviceProperties(self.physical_device,
+                                                            &mut properties);
+        }
+        properties
+    }
+
+    pub fn memory_properties(&self) -> vk::PhysicalDeviceMemoryProperties {
+        let mut memory_properties = Default::default();
+        unsafe {
+            self.instance_fns.vkGetPhysicalDeviceMemoryProperties(self.physical_device,
+                                                                  &mut memory_properties);
+        }
+        memory_properties
+    }
+
+    pub fn create_device(&self, extensions: &[&CStr]) -> Result<vk::Device, vk::Result> {
+        let names: Vec<*const c_char> = extensions.iter().map(|name| name.as_ptr()).collect();
+        let info = vk::DeviceCreateInfo {
+            enabledExtensionCount: names.len() as u32,
+            ppEnabledExtensionNames: names.as_ptr(),
+            ..Default::default()
+        };
+        let mut device = null_mut();
+        unsafe {
+            match self.instance_fns.vkCreateDevice(self.physical_device, &info, null(),
+                                                   &mut device) {
+                vk::Result::SUCCESS => Ok(device),
+                err => Err(err),
+            }
+        }
+    }
+
+    pub fn device(&self) -> (vk::Device, vk::DeviceFns) {
+        let device = self.create_device(&[]).unwrap();
+        (device, unsafe { vk::DeviceFns::load(&self.instance_fns, device).unwrap() })
+    }
+}
@@ -0,0 +1,60 @@
+//! `vk::enumerate` and `vk::enumerate_tagged` against the mock implementation.
+#![cfg(feature = "mock")]
+
+extern crate vulkan_bind;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::mock;
+
+mod common;
+
+use common::Setup;
+
+fn two_devices() -> mock::Config {
+    mock::Config { physical_devices: vec![Default::default(), Default::default()] }
+}
+
+#[test]
+fn starts_over_on_incomplete() {
+    let setup = Setup::from_entry(&mock::entry(two_devices()));
+    let mut calls = 0;
+    let devices = unsafe {
+        mock::hide_physical_devices(setup.instance, 1);
+        vk::enumerate(|count, data| {
+            calls += 1;
+            setup.instance_fns.vkEnumeratePhysicalDevices(setup.instance, count, data)
+        }).unwrap()
+    };
+    assert_eq!(devices.len(), 2);
+    assert_eq!(devices[0], setup.physical_device);
+    // Counted one, found two, then counted and read both.
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn tagged_elements_are_initialized() {
+    let entry = mock::entry(two_devices());
+    let setup = Setup::from_entry(&entry);
+    let mut calls = 0;
+    let groups = unsafe {
+        let fns = vk::InstanceFnsV1_1::load_with(|name| {
+            entry.vkGetInstanceProcAddr(setup.instance, name.as_ptr())
+        });
+        mock::hide_physical_devices(setup.instance, 1);
+        // The mock fails with ERROR_VALIDATION_FAILED on any other sType.
+        vk::enumerate_tagged(|count, data| {
+            calls += 1;
+            fns.vkEnumeratePhysicalDeviceGroups(setup.instance, count, data)
+        }).unwrap()
+    };
+    assert_eq!(calls, 4);
+    assert_eq!(groups.len(), 2);
+    for group in &groups {
+        assert_eq!(group.sType, vk::StructureType::PHYSICAL_DEVICE_GROUP_PROPERTIES);
+        assert!(group.pNext.is_null());
+        assert_eq!(group.physicalDeviceCount, 1);
+    }
+    assert_eq!(groups[0].physicalDevices[0], setup.physical_device);
+    assert!(groups[1].physicalDevices[1..].iter().all(|device| device.is_null()));
+    assert_ne!(groups[0].physicalDevices[0], groups[1].physicalDevices[0]);
+}
@@ -0,0 +1,57 @@
+//! The bitflags API of `make_flag!` types.
+extern crate vulkan_bind;
+
+use vulkan_bind::vk::{CullModeFlag, CullModeFlags, PipelineStageFlag2, PipelineStageFlags2,
+                      QueueFlag, QueueFlags};
+
+#[test]
+fn iter_lowest_first() {
+    let flags = QueueFlag::TRANSFER | QueueFlag::GRAPHICS | QueueFlag::PROTECTED;
+    let bits: Vec<QueueFlag> = flags.iter().collect();
+    assert_eq!(bits, [QueueFlag::GRAPHICS, QueueFlag::TRANSFER, QueueFlag::PROTECTED]);
+    assert_eq!(QueueFlags::empty().iter().count(), 0);
+
+    let high = PipelineStageFlags2::from(PipelineStageFlag2::TOP_OF_PIPE) |
+               PipelineStageFlags2(1 << 40);
+    assert_eq!(high.iter().map(|bit| bit.0).collect::<Vec<_>>(), [1, 1 << 40]);
+}
+
+#[test]
+fn debug_names() {
+    assert_eq!(format!("{:?}", QueueFlag::GRAPHICS | QueueFlag::COMPUTE), "GRAPHICS | COMPUTE");
+    assert_eq!(format!("{:?}", QueueFlag::COMPUTE), "COMPUTE");
+    assert_eq!(format!("{:?}", QueueFlags::empty()), "(empty)");
+    assert_eq!(format!("{:?}", QueueFlag::GRAPHICS | QueueFlags(0x100)), "GRAPHICS | 0x100");
+}
+
+#[test]
+fn debug_skips_aliases() {
+    assert_eq!(format!("{:?}", CullModeFlags::empty()), "NONE");
+    assert_eq!(format!("{:?}", CullModeFlag::FRONT_AND_BACK), "FRONT | BACK");
+    assert_eq!(format!("{:?}", CullModeFlags::all()), "FRONT | BACK");
+}
+
+#[test]
+fn all_and_complement() {
+    assert_eq!(QueueFlags::all().0, 0x1f);
+    assert_eq!(CullModeFlags::all().0, 0x3);
+    assert_eq!((!QueueFlag::GRAPHICS).0, !0x1);
+    assert_eq!(QueueFlags::from(QueueFlag::GRAPHICS).complement(),
+               QueueFlag::COMPUTE | QueueFlag::TRANSFER | QueueFlag::SPARSE_BINDING |
+               QueueFlag::PROTECTED);
+    assert_eq!(QueueFlags(0x100).complement(), QueueFlags::all());
+}
+
+#[test]
+fn set_operations() {
+    let mut flags = QueueFlags::empty();
+    flags.insert(QueueFlag::GRAPHICS | QueueFlag::COMPUTE);
+    assert!(flags.contains(QueueFlag::GRAPHICS));
+    assert!(!flags.contains(QueueFlag::GRAPHICS | QueueFlag::TRANSFER));
+    assert!(flags.intersects(QueueFlag::GRAPHICS | QueueFlag::TRANSFER));
+    flags.remove(QueueFlag::GRAPHICS);
+    flags.toggle(QueueFlag::TRANSFER);
+    assert_eq!(flags, QueueFlag::COMPUTE | QueueFlag::TRANSFER);
+    flags -= QueueFlag::COMPUTE;
+    assert_eq!(flags, QueueFlag::TRANSFER.into());
+}
@@ -0,0 +1,40 @@
+//! Dispatchable and non-dispatchable handle types.
+extern crate vulkan_bind;
+
+use std::collections::HashSet;
+use std::mem;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::Handle;
+
+#[test]
+fn sizes() {
+    assert_eq!(mem::size_of::<vk::Buffer>(), 8);
+    assert_eq!(mem::size_of::<vk::Semaphore>(), 8);
+    assert_eq!(mem::size_of::<Option<vk::Fence>>(), 16);
+    assert_eq!(mem::size_of::<vk::Device>(), mem::size_of::<usize>());
+    assert_eq!(mem::size_of::<vk::CommandBuffer>(), mem::size_of::<usize>());
+}
+
+#[test]
+fn null() {
+    assert!(vk::Image::null().is_null());
+    assert_eq!(vk::Image::default(), vk::Image::null());
+    assert!(!vk::Image(1).is_null());
+}
+
+#[test]
+fn raw_round_trip() {
+    let raw = 0xdead_beef_0000_0001;
+    assert_eq!(vk::Pipeline::from_raw(raw).as_raw(), raw);
+    assert_eq!(vk::Pipeline::OBJECT_TYPE, vk::ObjectType::PIPELINE);
+    assert_eq!(vk::Device::OBJECT_TYPE, vk::ObjectType::DEVICE);
+}
+
+#[test]
+fn traits() {
+    assert_eq!(format!("{:?}", vk::Buffer(0x10)), "Buffer(0x10)");
+    assert!(vk::Buffer(1) < vk::Buffer(2));
+    let set: HashSet<_> = [vk::Sampler(1), vk::Sampler(2), vk::Sampler(1)].iter().cloned().collect();
+    assert_eq!(set.len(), 2);
+}
@@ -0,0 +1,2213 @@
+/* The layout of every struct in the bindings, as the C compiler sees it.
+ *
+ * Generated by vulkan-bind-generator from vk.xml. Compiled with -S, each
+ * STRUCT and FIELD leaves an `@struct <test> <Rust name> <size> <align>` or
+ * `@field <Rust name> <offset>` line in the assembly, which
+ * `vulkan-bind-generator --layout` turns into tests/layout.rs. See
+ * generator/layout.sh.
+ */
+
+#include <stddef.h>
+#include <vulkan/vulkan_core.h>
+
+#define STRUCT(test, rust, c) \
+    __asm__ volatile ("\n.ascii \"@struct " #test " " #rust " %c0 %c1\"" \
+                      : : "i" (sizeof(c)), "i" (_Alignof(c)))
+#define FIELD(c, field, rust) \
+    __asm__ volatile ("\n.ascii \"@field " #rust " %c0\"" : : "i" (offsetof(c, field)))
+
+void layout(void)
+{
+    STRUCT(structs, vk::BaseOutStructure, VkBaseOutStructure);
+    FIELD(VkBaseOutStructure, sType, sType);
+    FIELD(VkBaseOutStructure, pNext, pNext);
+    STRUCT(structs, vk::BaseInStructure, VkBaseInStructure);
+    FIELD(VkBaseInStructure, sType, sType);
+    FIELD(VkBaseInStructure, pNext, pNext);
+    STRUCT(structs, vk::ApplicationInfo, VkApplicationInfo);
+    FIELD(VkApplicationInfo, sType, sType);
+    FIELD(VkApplicationInfo, pNext, pNext);
+    FIELD(VkApplicationInfo, pApplicationName, pApplicationName);
+    FIELD(VkApplicationInfo, applicationVersion, applicationVersion);
+    FIELD(VkApplicationInfo, pEngineName, pEngineName);
+    FIELD(VkApplicationInfo, engineVersion, engineVersion);
+    FIELD(VkApplicationInfo, apiVersion, apiVersion);
+    STRUCT(structs, vk::InstanceCreateInfo, VkInstanceCreateInfo);
+    FIELD(VkInstanceCreateInfo, sType, sType);
+    FIELD(VkInstanceCreateInfo, pNext, pNext);
+    FIELD(VkInstanceCreateInfo, flags, flags);
+    FIELD(VkInstanceCreateInfo, pApplicationInfo, pApplicationInfo);
+    FIELD(VkInstanceCreateInfo, enabledLayerCount, enabledLayerCount);
+    FIELD(VkInstanceCreateInfo, ppEnabledLayerNames, ppEnabledLayerNames);
+    FIELD(VkInstanceCreateInfo, enabledExtensionCount, enabledExtensionCount);
+    FIELD(VkInstanceCreateInfo, ppEnabledExtensionNames, ppEnabledExtensionNames);
+    STRUCT(structs, vk::AllocationCallbacks, VkAllocationCallbacks);
+    FIELD(VkAllocationCallbacks, pUserData, pUserData);
+    FIELD(VkAllocationCallbacks, pfnAllocation, pfnAllocation);
+    FIELD(VkAllocationCallbacks, pfnReallocation, pfnReallocation);
+    FIELD(VkAllocationCallbacks, pfnFree, pfnFree);
+    FIELD(VkAllocationCallbacks, pfnInternalAllocation, pfnInternalAllocation);
+    FIELD(VkAllocationCallbacks, pfnInternalFree, pfnInternalFree);
+    STRUCT(structs, vk::PhysicalDeviceFeatures, VkPhysicalDeviceFeatures);
+    FIELD(VkPhysicalDeviceFeatures, robustBufferAccess, robustBufferAccess);
+    FIELD(VkPhysicalDeviceFeatures, fullDrawIndexUint32, fullDrawIndexUint32);
+    FIELD(VkPhysicalDeviceFeatures, imageCubeArray, imageCubeArray);
+    FIELD(VkPhysicalDeviceFeatures, independentBlend, independentBlend);
+    FIELD(VkPhysicalDeviceFeatures, geometryShader, geometryShader);
+    FIELD(VkPhysicalDeviceFeatures, tessellationShader, tessellationShader);
+    FIELD(VkPhysicalDeviceFeatures, sampleRateShading, sampleRateShading);
+    FIELD(VkPhysicalDeviceFeatures, dualSrcBlend, dualSrcBlend);
+    FIELD(VkPhysicalDeviceFeatures, logicOp, logicOp);
+    FIELD(VkPhysicalDeviceFeatures, multiDrawIndirect, multiDrawIndirect);
+    FIELD(VkPhysicalDeviceFeatures, drawIndirectFirstInstance, drawIndirectFirstInstance);
+    FIELD(VkPhysicalDeviceFeatures, depthClamp, depthClamp);
+    FIELD(VkPhysicalDeviceFeatures, depthBiasClamp, depthBiasClamp);
+    FIELD(VkPhysicalDeviceFeatures, fillModeNonSolid, fillModeNonSolid);
+    FIELD(VkPhysicalDeviceFeatures, depthBounds, depthBounds);
+    FIELD(VkPhysicalDeviceFeatures, wideLines, wideLines);
+    FIELD(VkPhysicalDeviceFeatures, largePoints, largePoints);
+    FIELD(VkPhysicalDeviceFeatures, alphaToOne, alphaToOne);
+    FIELD(VkPhysicalDeviceFeatures, multiViewport, multiViewport);
+    FIELD(VkPhysicalDeviceFeatures, samplerAnisotropy, samplerAnisotropy);
+    FIELD(VkPhysicalDeviceFeatures, textureCompressionETC2, textureCompressionETC2);
+    FIELD(VkPhysicalDeviceFeatures, textureCompressionASTC_LDR, textureCompressionASTC_LDR);
+    FIELD(VkPhysicalDeviceFeatures, textureCompressionBC, textureCompressionBC);
+    FIELD(VkPhysicalDeviceFeatures, occlusionQueryPrecise, occlusionQueryPrecise);
+    FIELD(VkPhysicalDeviceFeatures, pipelineStatisticsQuery, pipelineStatisticsQuery);
+    FIELD(VkPhysicalDeviceFeatures, vertexPipelineStoresAndAtomics, vertexPipelineStoresAndAtomics);
+    FIELD(VkPhysicalDeviceFeatures, fragmentStoresAndAtomics, fragmentStoresAndAtomics);
+    FIELD(VkPhysicalDeviceFeatures, shaderTessellationAndGeometryPointSize, shaderTessellationAndGeometryPointSize);
+    FIELD(VkPhysicalDeviceFeatures, shaderImageGatherExtended, shaderImageGatherExtended);
+    FIELD(VkPhysicalDeviceFeatures, shaderStorageImageExtendedFormats, shaderStorageImageExtendedFormats);
+    FIELD(VkPhysicalDeviceFeatures, shaderStorageImageMultisample, shaderStorageImageMultisample);
+    FIELD(VkPhysicalDeviceFeatures, shaderStorageImageReadWithoutFormat, shaderStorageImageReadWithoutFormat);
+    FIELD(VkPhysicalDeviceFeatures, shaderStorageImageWriteWithoutFormat, shaderStorageImageWriteWithoutFormat);
+    FIELD(VkPhysicalDeviceFeatures, shaderUniformBufferArrayDynamicIndexing, shaderUniformBufferArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceFeatures, shaderSampledImageArrayDynamicIndexing, shaderSampledImageArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceFeatures, shaderStorageBufferArrayDynamicIndexing, shaderStorageBufferArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceFeatures, shaderStorageImageArrayDynamicIndexing, shaderStorageImageArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceFeatures, shaderClipDistance, shaderClipDistance);
+    FIELD(VkPhysicalDeviceFeatures, shaderCullDistance, shaderCullDistance);
+    FIELD(VkPhysicalDeviceFeatures, shaderFloat64, shaderFloat64);
+    FIELD(VkPhysicalDeviceFeatures, shaderInt64, shaderInt64);
+    FIELD(VkPhysicalDeviceFeatures, shaderInt16, shaderInt16);
+    FIELD(VkPhysicalDeviceFeatures, shaderResourceResidency, shaderResourceResidency);
+    FIELD(VkPhysicalDeviceFeatures, shaderResourceMinLod, shaderResourceMinLod);
+    FIELD(VkPhysicalDeviceFeatures, sparseBinding, sparseBinding);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidencyBuffer, sparseResidencyBuffer);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidencyImage2D, sparseResidencyImage2D);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidencyImage3D, sparseResidencyImage3D);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidency2Samples, sparseResidency2Samples);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidency4Samples, sparseResidency4Samples);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidency8Samples, sparseResidency8Samples);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidency16Samples, sparseResidency16Samples);
+    FIELD(VkPhysicalDeviceFeatures, sparseResidencyAliased, sparseResidencyAliased);
+    FIELD(VkPhysicalDeviceFeatures, variableMultisampleRate, variableMultisampleRate);
+    FIELD(VkPhysicalDeviceFeatures, inheritedQueries, inheritedQueries);
+    STRUCT(structs, vk::FormatProperties, VkFormatProperties);
+    FIELD(VkFormatProperties, linearTilingFeatures, linearTilingFeatures);
+    FIELD(VkFormatProperties, optimalTilingFeatures, optimalTilingFeatures);
+    FIELD(VkFormatProperties, bufferFeatures, bufferFeatures);
+    STRUCT(structs, vk::Extent3D, VkExtent3D);
+    FIELD(VkExtent3D, width, width);
+    FIELD(VkExtent3D, height, height);
+    FIELD(VkExtent3D, depth, depth);
+    STRUCT(structs, vk::ImageFormatProperties, VkImageFormatProperties);
+    FIELD(VkImageFormatProperties, maxExtent, maxExtent);
+    FIELD(VkImageFormatProperties, maxMipLevels, maxMipLevels);
+    FIELD(VkImageFormatProperties, maxArrayLayers, maxArrayLayers);
+    FIELD(VkImageFormatProperties, sampleCounts, sampleCounts);
+    FIELD(VkImageFormatProperties, maxResourceSize, maxResourceSize);
+    STRUCT(structs, vk::PhysicalDeviceLimits, VkPhysicalDeviceLimits);
+    FIELD(VkPhysicalDeviceLimits, maxImageDimension1D, maxImageDimension1D);
+    FIELD(VkPhysicalDeviceLimits, maxImageDimension2D, maxImageDimension2D);
+    FIELD(VkPhysicalDeviceLimits, maxImageDimension3D, maxImageDimension3D);
+    FIELD(VkPhysicalDeviceLimits, maxImageDimensionCube, maxImageDimensionCube);
+    FIELD(VkPhysicalDeviceLimits, maxImageArrayLayers, maxImageArrayLayers);
+    FIELD(VkPhysicalDeviceLimits, maxTexelBufferElements, maxTexelBufferElements);
+    FIELD(VkPhysicalDeviceLimits, maxUniformBufferRange, maxUniformBufferRange);
+    FIELD(VkPhysicalDeviceLimits, maxStorageBufferRange, maxStorageBufferRange);
+    FIELD(VkPhysicalDeviceLimits, maxPushConstantsSize, maxPushConstantsSize);
+    FIELD(VkPhysicalDeviceLimits, maxMemoryAllocationCount, maxMemoryAllocationCount);
+    FIELD(VkPhysicalDeviceLimits, maxSamplerAllocationCount, maxSamplerAllocationCount);
+    FIELD(VkPhysicalDeviceLimits, bufferImageGranularity, bufferImageGranularity);
+    FIELD(VkPhysicalDeviceLimits, sparseAddressSpaceSize, sparseAddressSpaceSize);
+    FIELD(VkPhysicalDeviceLimits, maxBoundDescriptorSets, maxBoundDescriptorSets);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageDescriptorSamplers, maxPerStageDescriptorSamplers);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageDescriptorUniformBuffers, maxPerStageDescriptorUniformBuffers);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageDescriptorStorageBuffers, maxPerStageDescriptorStorageBuffers);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageDescriptorSampledImages, maxPerStageDescriptorSampledImages);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageDescriptorStorageImages, maxPerStageDescriptorStorageImages);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageDescriptorInputAttachments, maxPerStageDescriptorInputAttachments);
+    FIELD(VkPhysicalDeviceLimits, maxPerStageResources, maxPerStageResources);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetSamplers, maxDescriptorSetSamplers);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetUniformBuffers, maxDescriptorSetUniformBuffers);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetUniformBuffersDynamic, maxDescriptorSetUniformBuffersDynamic);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetStorageBuffers, maxDescriptorSetStorageBuffers);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetStorageBuffersDynamic, maxDescriptorSetStorageBuffersDynamic);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetSampledImages, maxDescriptorSetSampledImages);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetStorageImages, maxDescriptorSetStorageImages);
+    FIELD(VkPhysicalDeviceLimits, maxDescriptorSetInputAttachments, maxDescriptorSetInputAttachments);
+    FIELD(VkPhysicalDeviceLimits, maxVertexInputAttributes, maxVertexInputAttributes);
+    FIELD(VkPhysicalDeviceLimits, maxVertexInputBindings, maxVertexInputBindings);
+    FIELD(VkPhysicalDeviceLimits, maxVertexInputAttributeOffset, maxVertexInputAttributeOffset);
+    FIELD(VkPhysicalDeviceLimits, maxVertexInputBindingStride, maxVertexInputBindingStride);
+    FIELD(VkPhysicalDeviceLimits, maxVertexOutputComponents, maxVertexOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationGenerationLevel, maxTessellationGenerationLevel);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationPatchSize, maxTessellationPatchSize);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationControlPerVertexInputComponents, maxTessellationControlPerVertexInputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationControlPerVertexOutputComponents, maxTessellationControlPerVertexOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationControlPerPatchOutputComponents, maxTessellationControlPerPatchOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationControlTotalOutputComponents, maxTessellationControlTotalOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationEvaluationInputComponents, maxTessellationEvaluationInputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxTessellationEvaluationOutputComponents, maxTessellationEvaluationOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxGeometryShaderInvocations, maxGeometryShaderInvocations);
+    FIELD(VkPhysicalDeviceLimits, maxGeometryInputComponents, maxGeometryInputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxGeometryOutputComponents, maxGeometryOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxGeometryOutputVertices, maxGeometryOutputVertices);
+    FIELD(VkPhysicalDeviceLimits, maxGeometryTotalOutputComponents, maxGeometryTotalOutputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxFragmentInputComponents, maxFragmentInputComponents);
+    FIELD(VkPhysicalDeviceLimits, maxFragmentOutputAttachments, maxFragmentOutputAttachments);
+    FIELD(VkPhysicalDeviceLimits, maxFragmentDualSrcAttachments, maxFragmentDualSrcAttachments);
+    FIELD(VkPhysicalDeviceLimits, maxFragmentCombinedOutputResources, maxFragmentCombinedOutputResources);
+    FIELD(VkPhysicalDeviceLimits, maxComputeSharedMemorySize, maxComputeSharedMemorySize);
+    FIELD(VkPhysicalDeviceLimits, maxComputeWorkGroupCount, maxComputeWorkGroupCount);
+    FIELD(VkPhysicalDeviceLimits, maxComputeWorkGroupInvocations, maxComputeWorkGroupInvocations);
+    FIELD(VkPhysicalDeviceLimits, maxComputeWorkGroupSize, maxComputeWorkGroupSize);
+    FIELD(VkPhysicalDeviceLimits, subPixelPrecisionBits, subPixelPrecisionBits);
+    FIELD(VkPhysicalDeviceLimits, subTexelPrecisionBits, subTexelPrecisionBits);
+    FIELD(VkPhysicalDeviceLimits, mipmapPrecisionBits, mipmapPrecisionBits);
+    FIELD(VkPhysicalDeviceLimits, maxDrawIndexedIndexValue, maxDrawIndexedIndexValue);
+    FIELD(VkPhysicalDeviceLimits, maxDrawIndirectCount, maxDrawIndirectCount);
+    FIELD(VkPhysicalDeviceLimits, maxSamplerLodBias, maxSamplerLodBias);
+    FIELD(VkPhysicalDeviceLimits, maxSamplerAnisotropy, maxSamplerAnisotropy);
+    FIELD(VkPhysicalDeviceLimits, maxViewports, maxViewports);
+    FIELD(VkPhysicalDeviceLimits, maxViewportDimensions, maxViewportDimensions);
+    FIELD(VkPhysicalDeviceLimits, viewportBoundsRange, viewportBoundsRange);
+    FIELD(VkPhysicalDeviceLimits, viewportSubPixelBits, viewportSubPixelBits);
+    FIELD(VkPhysicalDeviceLimits, minMemoryMapAlignment, minMemoryMapAlignment);
+    FIELD(VkPhysicalDeviceLimits, minTexelBufferOffsetAlignment, minTexelBufferOffsetAlignment);
+    FIELD(VkPhysicalDeviceLimits, minUniformBufferOffsetAlignment, minUniformBufferOffsetAlignment);
+    FIELD(VkPhysicalDeviceLimits, minStorageBufferOffsetAlignment, minStorageBufferOffsetAlignment);
+    FIELD(VkPhysicalDeviceLimits, minTexelOffset, minTexelOffset);
+    FIELD(VkPhysicalDeviceLimits, maxTexelOffset, maxTexelOffset);
+    FIELD(VkPhysicalDeviceLimits, minTexelGatherOffset, minTexelGatherOffset);
+    FIELD(VkPhysicalDeviceLimits, maxTexelGatherOffset, maxTexelGatherOffset);
+    FIELD(VkPhysicalDeviceLimits, minInterpolationOffset, minInterpolationOffset);
+    FIELD(VkPhysicalDeviceLimits, maxInterpolationOffset, maxInterpolationOffset);
+    FIELD(VkPhysicalDeviceLimits, subPixelInterpolationOffsetBits, subPixelInterpolationOffsetBits);
+    FIELD(VkPhysicalDeviceLimits, maxFramebufferWidth, maxFramebufferWidth);
+    FIELD(VkPhysicalDeviceLimits, maxFramebufferHeight, maxFramebufferHeight);
+    FIELD(VkPhysicalDeviceLimits, maxFramebufferLayers, maxFramebufferLayers);
+    FIELD(VkPhysicalDeviceLimits, framebufferColorSampleCounts, framebufferColorSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, framebufferDepthSampleCounts, framebufferDepthSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, framebufferStencilSampleCounts, framebufferStencilSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, framebufferNoAttachmentsSampleCounts, framebufferNoAttachmentsSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, maxColorAttachments, maxColorAttachments);
+    FIELD(VkPhysicalDeviceLimits, sampledImageColorSampleCounts, sampledImageColorSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, sampledImageIntegerSampleCounts, sampledImageIntegerSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, sampledImageDepthSampleCounts, sampledImageDepthSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, sampledImageStencilSampleCounts, sampledImageStencilSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, storageImageSampleCounts, storageImageSampleCounts);
+    FIELD(VkPhysicalDeviceLimits, maxSampleMaskWords, maxSampleMaskWords);
+    FIELD(VkPhysicalDeviceLimits, timestampComputeAndGraphics, timestampComputeAndGraphics);
+    FIELD(VkPhysicalDeviceLimits, timestampPeriod, timestampPeriod);
+    FIELD(VkPhysicalDeviceLimits, maxClipDistances, maxClipDistances);
+    FIELD(VkPhysicalDeviceLimits, maxCullDistances, maxCullDistances);
+    FIELD(VkPhysicalDeviceLimits, maxCombinedClipAndCullDistances, maxCombinedClipAndCullDistances);
+    FIELD(VkPhysicalDeviceLimits, discreteQueuePriorities, discreteQueuePriorities);
+    FIELD(VkPhysicalDeviceLimits, pointSizeRange, pointSizeRange);
+    FIELD(VkPhysicalDeviceLimits, lineWidthRange, lineWidthRange);
+    FIELD(VkPhysicalDeviceLimits, pointSizeGranularity, pointSizeGranularity);
+    FIELD(VkPhysicalDeviceLimits, lineWidthGranularity, lineWidthGranularity);
+    FIELD(VkPhysicalDeviceLimits, strictLines, strictLines);
+    FIELD(VkPhysicalDeviceLimits, standardSampleLocations, standardSampleLocations);
+    FIELD(VkPhysicalDeviceLimits, optimalBufferCopyOffsetAlignment, optimalBufferCopyOffsetAlignment);
+    FIELD(VkPhysicalDeviceLimits, optimalBufferCopyRowPitchAlignment, optimalBufferCopyRowPitchAlignment);
+    FIELD(VkPhysicalDeviceLimits, nonCoherentAtomSize, nonCoherentAtomSize);
+    STRUCT(structs, vk::PhysicalDeviceSparseProperties, VkPhysicalDeviceSparseProperties);
+    FIELD(VkPhysicalDeviceSparseProperties, residencyStandard2DBlockShape, residencyStandard2DBlockShape);
+    FIELD(VkPhysicalDeviceSparseProperties, residencyStandard2DMultisampleBlockShape, residencyStandard2DMultisampleBlockShape);
+    FIELD(VkPhysicalDeviceSparseProperties, residencyStandard3DBlockShape, residencyStandard3DBlockShape);
+    FIELD(VkPhysicalDeviceSparseProperties, residencyAlignedMipSize, residencyAlignedMipSize);
+    FIELD(VkPhysicalDeviceSparseProperties, residencyNonResidentStrict, residencyNonResidentStrict);
+    STRUCT(structs, vk::PhysicalDeviceProperties, VkPhysicalDeviceProperties);
+    FIELD(VkPhysicalDeviceProperties, apiVersion, apiVersion);
+    FIELD(VkPhysicalDeviceProperties, driverVersion, driverVersion);
+    FIELD(VkPhysicalDeviceProperties, vendorID, vendorID);
+    FIELD(VkPhysicalDeviceProperties, deviceID, deviceID);
+    FIELD(VkPhysicalDeviceProperties, deviceType, deviceType);
+    FIELD(VkPhysicalDeviceProperties, deviceName, deviceName);
+    FIELD(VkPhysicalDeviceProperties, pipelineCacheUUID, pipelineCacheUUID);
+    FIELD(VkPhysicalDeviceProperties, limits, limits);
+    FIELD(VkPhysicalDeviceProperties, sparseProperties, sparseProperties);
+    STRUCT(structs, vk::QueueFamilyProperties, VkQueueFamilyProperties);
+    FIELD(VkQueueFamilyProperties, queueFlags, queueFlags);
+    FIELD(VkQueueFamilyProperties, queueCount, queueCount);
+    FIELD(VkQueueFamilyProperties, timestampValidBits, timestampValidBits);
+    FIELD(VkQueueFamilyProperties, minImageTransferGranularity, minImageTransferGranularity);
+    STRUCT(structs, vk::MemoryType, VkMemoryType);
+    FIELD(VkMemoryType, propertyFlags, propertyFlags);
+    FIELD(VkMemoryType, heapIndex, heapIndex);
+    STRUCT(structs, vk::MemoryHeap, VkMemoryHeap);
+    FIELD(VkMemoryHeap, size, size);
+    FIELD(VkMemoryHeap, flags, flags);
+    STRUCT(structs, vk::PhysicalDeviceMemoryProperties, VkPhysicalDeviceMemoryProperties);
+    FIELD(VkPhysicalDeviceMemoryProperties, memoryTypeCount, memoryTypeCount);
+    FIELD(VkPhysicalDeviceMemoryProperties, memoryTypes, memoryTypes);
+    FIELD(VkPhysicalDeviceMemoryProperties, memoryHeapCount, memoryHeapCount);
+    FIELD(VkPhysicalDeviceMemoryProperties, memoryHeaps, memoryHeaps);
+    STRUCT(structs, vk::DeviceQueueCreateInfo, VkDeviceQueueCreateInfo);
+    FIELD(VkDeviceQueueCreateInfo, sType, sType);
+    FIELD(VkDeviceQueueCreateInfo, pNext, pNext);
+    FIELD(VkDeviceQueueCreateInfo, flags, flags);
+    FIELD(VkDeviceQueueCreateInfo, queueFamilyIndex, queueFamilyIndex);
+    FIELD(VkDeviceQueueCreateInfo, queueCount, queueCount);
+    FIELD(VkDeviceQueueCreateInfo, pQueuePriorities, pQueuePriorities);
+    STRUCT(structs, vk::DeviceCreateInfo, VkDeviceCreateInfo);
+    FIELD(VkDeviceCreateInfo, sType, sType);
+    FIELD(VkDeviceCreateInfo, pNext, pNext);
+    FIELD(VkDeviceCreateInfo, flags, flags);
+    FIELD(VkDeviceCreateInfo, queueCreateInfoCount, queueCreateInfoCount);
+    FIELD(VkDeviceCreateInfo, pQueueCreateInfos, pQueueCreateInfos);
+    FIELD(VkDeviceCreateInfo, enabledLayerCount, enabledLayerCount);
+    FIELD(VkDeviceCreateInfo, ppEnabledLayerNames, ppEnabledLayerNames);
+    FIELD(VkDeviceCreateInfo, enabledExtensionCount, enabledExtensionCount);
+    FIELD(VkDeviceCreateInfo, ppEnabledExtensionNames, ppEnabledExtensionNames);
+    FIELD(VkDeviceCreateInfo, pEnabledFeatures, pEnabledFeatures);
+    STRUCT(structs, vk::ExtensionProperties, VkExtensionProperties);
+    FIELD(VkExtensionProperties, extensionName, extensionName);
+    FIELD(VkExtensionProperties, specVersion, specVersion);
+    STRUCT(structs, vk::LayerProperties, VkLayerProperties);
+    FIELD(VkLayerProperties, layerName, layerName);
+    FIELD(VkLayerProperties, specVersion, specVersion);
+    FIELD(VkLayerProperties, implementationVersion, implementationVersion);
+    FIELD(VkLayerProperties, description, description);
+    STRUCT(structs, vk::SubmitInfo, VkSubmitInfo);
+    FIELD(VkSubmitInfo, sType, sType);
+    FIELD(VkSubmitInfo, pNext, pNext);
+    FIELD(VkSubmitInfo, waitSemaphoreCount, waitSemaphoreCount);
+    FIELD(VkSubmitInfo, pWaitSemaphores, pWaitSemaphores);
+    FIELD(VkSubmitInfo, pWaitDstStageMask, pWaitDstStageMask);
+    FIELD(VkSubmitInfo, commandBufferCount, commandBufferCount);
+    FIELD(VkSubmitInfo, pCommandBuffers, pCommandBuffers);
+    FIELD(VkSubmitInfo, signalSemaphoreCount, signalSemaphoreCount);
+    FIELD(VkSubmitInfo, pSignalSemaphores, pSignalSemaphores);
+    STRUCT(structs, vk::MemoryAllocateInfo, VkMemoryAllocateInfo);
+    FIELD(VkMemoryAllocateInfo, sType, sType);
+    FIELD(VkMemoryAllocateInfo, pNext, pNext);
+    FIELD(VkMemoryAllocateInfo, allocationSize, allocationSize);
+    FIELD(VkMemoryAllocateInfo, memoryTypeIndex, memoryTypeIndex);
+    STRUCT(structs, vk::MappedMemoryRange, VkMappedMemoryRange);
+    FIELD(VkMappedMemoryRange, sType, sType);
+    FIELD(VkMappedMemoryRange, pNext, pNext);
+    FIELD(VkMappedMemoryRange, memory, memory);
+    FIELD(VkMappedMemoryRange, offset, offset);
+    FIELD(VkMappedMemoryRange, size, size);
+    STRUCT(structs, vk::MemoryRequirements, VkMemoryRequirements);
+    FIELD(VkMemoryRequirements, size, size);
+    FIELD(VkMemoryRequirements, alignment, alignment);
+    FIELD(VkMemoryRequirements, memoryTypeBits, memoryTypeBits);
+    STRUCT(structs, vk::SparseImageFormatProperties, VkSparseImageFormatProperties);
+    FIELD(VkSparseImageFormatProperties, aspectMask, aspectMask);
+    FIELD(VkSparseImageFormatProperties, imageGranularity, imageGranularity);
+    FIELD(VkSparseImageFormatProperties, flags, flags);
+    STRUCT(structs, vk::SparseImageMemoryRequirements, VkSparseImageMemoryRequirements);
+    FIELD(VkSparseImageMemoryRequirements, formatProperties, formatProperties);
+    FIELD(VkSparseImageMemoryRequirements, imageMipTailFirstLod, imageMipTailFirstLod);
+    FIELD(VkSparseImageMemoryRequirements, imageMipTailSize, imageMipTailSize);
+    FIELD(VkSparseImageMemoryRequirements, imageMipTailOffset, imageMipTailOffset);
+    FIELD(VkSparseImageMemoryRequirements, imageMipTailStride, imageMipTailStride);
+    STRUCT(structs, vk::SparseMemoryBind, VkSparseMemoryBind);
+    FIELD(VkSparseMemoryBind, resourceOffset, resourceOffset);
+    FIELD(VkSparseMemoryBind, size, size);
+    FIELD(VkSparseMemoryBind, memory, memory);
+    FIELD(VkSparseMemoryBind, memoryOffset, memoryOffset);
+    FIELD(VkSparseMemoryBind, flags, flags);
+    STRUCT(structs, vk::SparseBufferMemoryBindInfo, VkSparseBufferMemoryBindInfo);
+    FIELD(VkSparseBufferMemoryBindInfo, buffer, buffer);
+    FIELD(VkSparseBufferMemoryBindInfo, bindCount, bindCount);
+    FIELD(VkSparseBufferMemoryBindInfo, pBinds, pBinds);
+    STRUCT(structs, vk::SparseImageOpaqueMemoryBindInfo, VkSparseImageOpaqueMemoryBindInfo);
+    FIELD(VkSparseImageOpaqueMemoryBindInfo, image, image);
+    FIELD(VkSparseImageOpaqueMemoryBindInfo, bindCount, bindCount);
+    FIELD(VkSparseImageOpaqueMemoryBindInfo, pBinds, pBinds);
+    STRUCT(structs, vk::ImageSubresource, VkImageSubresource);
+    FIELD(VkImageSubresource, aspectMask, aspectMask);
+    FIELD(VkImageSubresource, mipLevel, mipLevel);
+    FIELD(VkImageSubresource, arrayLayer, arrayLayer);
+    STRUCT(structs, vk::Offset3D, VkOffset3D);
+    FIELD(VkOffset3D, x, x);
+    FIELD(VkOffset3D, y, y);
+    FIELD(VkOffset3D, z, z);
+    STRUCT(structs, vk::SparseImageMemoryBind, VkSparseImageMemoryBind);
+    FIELD(VkSparseImageMemoryBind, subresource, subresource);
+    FIELD(VkSparseImageMemoryBind, offset, offset);
+    FIELD(VkSparseImageMemoryBind, extent, extent);
+    FIELD(VkSparseImageMemoryBind, memory, memory);
+    FIELD(VkSparseImageMemoryBind, memoryOffset, memoryOffset);
+    FIELD(VkSparseImageMemoryBind, flags, flags);
+    STRUCT(structs, vk::SparseImageMemoryBindInfo, VkSparseImageMemoryBindInfo);
+    FIELD(VkSparseImageMemoryBindInfo, image, image);
+    FIELD(VkSparseImageMemoryBindInfo, bindCount, bindCount);
+    FIELD(VkSparseImageMemoryBindInfo, pBinds, pBinds);
+    STRUCT(structs, vk::BindSparseInfo, VkBindSparseInfo);
+    FIELD(VkBindSparseInfo, sType, sType);
+    FIELD(VkBindSparseInfo, pNext, pNext);
+    FIELD(VkBindSparseInfo, waitSemaphoreCount, waitSemaphoreCount);
+    FIELD(VkBindSparseInfo, pWaitSemaphores, pWaitSemaphores);
+    FIELD(VkBindSparseInfo, bufferBindCount, bufferBindCount);
+    FIELD(VkBindSparseInfo, pBufferBinds, pBufferBinds);
+    FIELD(VkBindSparseInfo, imageOpaqueBindCount, imageOpaqueBindCount);
+    FIELD(VkBindSparseInfo, pImageOpaqueBinds, pImageOpaqueBinds);
+    FIELD(VkBindSparseInfo, imageBindCount, imageBindCount);
+    FIELD(VkBindSparseInfo, pImageBinds, pImageBinds);
+    FIELD(VkBindSparseInfo, signalSemaphoreCount, signalSemaphoreCount);
+    FIELD(VkBindSparseInfo, pSignalSemaphores, pSignalSemaphores);
+    STRUCT(structs, vk::FenceCreateInfo, VkFenceCreateInfo);
+    FIELD(VkFenceCreateInfo, sType, sType);
+    FIELD(VkFenceCreateInfo, pNext, pNext);
+    FIELD(VkFenceCreateInfo, flags, flags);
+    STRUCT(structs, vk::SemaphoreCreateInfo, VkSemaphoreCreateInfo);
+    FIELD(VkSemaphoreCreateInfo, sType, sType);
+    FIELD(VkSemaphoreCreateInfo, pNext, pNext);
+    FIELD(VkSemaphoreCreateInfo, flags, flags);
+    STRUCT(structs, vk::EventCreateInfo, VkEventCreateInfo);
+    FIELD(VkEventCreateInfo, sType, sType);
+    FIELD(VkEventCreateInfo, pNext, pNext);
+    FIELD(VkEventCreateInfo, flags, flags);
+    STRUCT(structs, vk::QueryPoolCreateInfo, VkQueryPoolCreateInfo);
+    FIELD(VkQueryPoolCreateInfo, sType, sType);
+    FIELD(VkQueryPoolCreateInfo, pNext, pNext);
+    FIELD(VkQueryPoolCreateInfo, flags, flags);
+    FIELD(VkQueryPoolCreateInfo, queryType, queryType);
+    FIELD(VkQueryPoolCreateInfo, queryCount, queryCount);
+    FIELD(VkQueryPoolCreateInfo, pipelineStatistics, pipelineStatistics);
+    STRUCT(structs, vk::BufferCreateInfo, VkBufferCreateInfo);
+    FIELD(VkBufferCreateInfo, sType, sType);
+    FIELD(VkBufferCreateInfo, pNext, pNext);
+    FIELD(VkBufferCreateInfo, flags, flags);
+    FIELD(VkBufferCreateInfo, size, size);
+    FIELD(VkBufferCreateInfo, usage, usage);
+    FIELD(VkBufferCreateInfo, sharingMode, sharingMode);
+    FIELD(VkBufferCreateInfo, queueFamilyIndexCount, queueFamilyIndexCount);
+    FIELD(VkBufferCreateInfo, pQueueFamilyIndices, pQueueFamilyIndices);
+    STRUCT(structs, vk::BufferViewCreateInfo, VkBufferViewCreateInfo);
+    FIELD(VkBufferViewCreateInfo, sType, sType);
+    FIELD(VkBufferViewCreateInfo, pNext, pNext);
+    FIELD(VkBufferViewCreateInfo, flags, flags);
+    FIELD(VkBufferViewCreateInfo, buffer, buffer);
+    FIELD(VkBufferViewCreateInfo, format, format);
+    FIELD(VkBufferViewCreateInfo, offset, offset);
+    FIELD(VkBufferViewCreateInfo, range, range);
+    STRUCT(structs, vk::ImageCreateInfo, VkImageCreateInfo);
+    FIELD(VkImageCreateInfo, sType, sType);
+    FIELD(VkImageCreateInfo, pNext, pNext);
+    FIELD(VkImageCreateInfo, flags, flags);
+    FIELD(VkImageCreateInfo, imageType, imageType);
+    FIELD(VkImageCreateInfo, format, format);
+    FIELD(VkImageCreateInfo, extent, extent);
+    FIELD(VkImageCreateInfo, mipLevels, mipLevels);
+    FIELD(VkImageCreateInfo, arrayLayers, arrayLayers);
+    FIELD(VkImageCreateInfo, samples, samples);
+    FIELD(VkImageCreateInfo, tiling, tiling);
+    FIELD(VkImageCreateInfo, usage, usage);
+    FIELD(VkImageCreateInfo, sharingMode, sharingMode);
+    FIELD(VkImageCreateInfo, queueFamilyIndexCount, queueFamilyIndexCount);
+    FIELD(VkImageCreateInfo, pQueueFamilyIndices, pQueueFamilyIndices);
+    FIELD(VkImageCreateInfo, initialLayout, initialLayout);
+    STRUCT(structs, vk::SubresourceLayout, VkSubresourceLayout);
+    FIELD(VkSubresourceLayout, offset, offset);
+    FIELD(VkSubresourceLayout, size, size);
+    FIELD(VkSubresourceLayout, rowPitch, rowPitch);
+    FIELD(VkSubresourceLayout, arrayPitch, arrayPitch);
+    FIELD(VkSubresourceLayout, depthPitch, depthPitch);
+    STRUCT(structs, vk::ComponentMapping, VkComponentMapping);
+    FIELD(VkComponentMapping, r, r);
+    FIELD(VkComponentMapping, g, g);
+    FIELD(VkComponentMapping, b, b);
+    FIELD(VkComponentMapping, a, a);
+    STRUCT(structs, vk::ImageSubresourceRange, VkImageSubresourceRange);
+    FIELD(VkImageSubresourceRange, aspectMask, aspectMask);
+    FIELD(VkImageSubresourceRange, baseMipLevel, baseMipLevel);
+    FIELD(VkImageSubresourceRange, levelCount, levelCount);
+    FIELD(VkImageSubresourceRange, baseArrayLayer, baseArrayLayer);
+    FIELD(VkImageSubresourceRange, layerCount, layerCount);
+    STRUCT(structs, vk::ImageViewCreateInfo, VkImageViewCreateInfo);
+    FIELD(VkImageViewCreateInfo, sType, sType);
+    FIELD(VkImageViewCreateInfo, pNext, pNext);
+    FIELD(VkImageViewCreateInfo, flags, flags);
+    FIELD(VkImageViewCreateInfo, image, image);
+    FIELD(VkImageViewCreateInfo, viewType, viewType);
+    FIELD(VkImageViewCreateInfo, format, format);
+    FIELD(VkImageViewCreateInfo, components, components);
+    FIELD(VkImageViewCreateInfo, subresourceRange, subresourceRange);
+    STRUCT(structs, vk::ShaderModuleCreateInfo, VkShaderModuleCreateInfo);
+    FIELD(VkShaderModuleCreateInfo, sType, sType);
+    FIELD(VkShaderModuleCreateInfo, pNext, pNext);
+    FIELD(VkShaderModuleCreateInfo, flags, flags);
+    FIELD(VkShaderModuleCreateInfo, codeSize, codeSize);
+    FIELD(VkShaderModuleCreateInfo, pCode, pCode);
+    STRUCT(structs, vk::PipelineCacheCreateInfo, VkPipelineCacheCreateInfo);
+    FIELD(VkPipelineCacheCreateInfo, sType, sType);
+    FIELD(VkPipelineCacheCreateInfo, pNext, pNext);
+    FIELD(VkPipelineCacheCreateInfo, flags, flags);
+    FIELD(VkPipelineCacheCreateInfo, initialDataSize, initialDataSize);
+    FIELD(VkPipelineCacheCreateInfo, pInitialData, pInitialData);
+    STRUCT(structs, vk::SpecializationMapEntry, VkSpecializationMapEntry);
+    FIELD(VkSpecializationMapEntry, constantID, constantID);
+    FIELD(VkSpecializationMapEntry, offset, offset);
+    FIELD(VkSpecializationMapEntry, size, size);
+    STRUCT(structs, vk::SpecializationInfo, VkSpecializationInfo);
+    FIELD(VkSpecializationInfo, mapEntryCount, mapEntryCount);
+    FIELD(VkSpecializationInfo, pMapEntries, pMapEntries);
+    FIELD(VkSpecializationInfo, dataSize, dataSize);
+    FIELD(VkSpecializationInfo, pData, pData);
+    STRUCT(structs, vk::PipelineShaderStageCreateInfo, VkPipelineShaderStageCreateInfo);
+    FIELD(VkPipelineShaderStageCreateInfo, sType, sType);
+    FIELD(VkPipelineShaderStageCreateInfo, pNext, pNext);
+    FIELD(VkPipelineShaderStageCreateInfo, flags, flags);
+    FIELD(VkPipelineShaderStageCreateInfo, stage, stage);
+    FIELD(VkPipelineShaderStageCreateInfo, module, module);
+    FIELD(VkPipelineShaderStageCreateInfo, pName, pName);
+    FIELD(VkPipelineShaderStageCreateInfo, pSpecializationInfo, pSpecializationInfo);
+    STRUCT(structs, vk::VertexInputBindingDescription, VkVertexInputBindingDescription);
+    FIELD(VkVertexInputBindingDescription, binding, binding);
+    FIELD(VkVertexInputBindingDescription, stride, stride);
+    FIELD(VkVertexInputBindingDescription, inputRate, inputRate);
+    STRUCT(structs, vk::VertexInputAttributeDescription, VkVertexInputAttributeDescription);
+    FIELD(VkVertexInputAttributeDescription, location, location);
+    FIELD(VkVertexInputAttributeDescription, binding, binding);
+    FIELD(VkVertexInputAttributeDescription, format, format);
+    FIELD(VkVertexInputAttributeDescription, offset, offset);
+    STRUCT(structs, vk::PipelineVertexInputStateCreateInfo, VkPipelineVertexInputStateCreateInfo);
+    FIELD(VkPipelineVertexInputStateCreateInfo, sType, sType);
+    FIELD(VkPipelineVertexInputStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineVertexInputStateCreateInfo, flags, flags);
+    FIELD(VkPipelineVertexInputStateCreateInfo, vertexBindingDescriptionCount, vertexBindingDescriptionCount);
+    FIELD(VkPipelineVertexInputStateCreateInfo, pVertexBindingDescriptions, pVertexBindingDescriptions);
+    FIELD(VkPipelineVertexInputStateCreateInfo, vertexAttributeDescriptionCount, vertexAttributeDescriptionCount);
+    FIELD(VkPipelineVertexInputStateCreateInfo, pVertexAttributeDescriptions, pVertexAttributeDescriptions);
+    STRUCT(structs, vk::PipelineInputAssemblyStateCreateInfo, VkPipelineInputAssemblyStateCreateInfo);
+    FIELD(VkPipelineInputAssemblyStateCreateInfo, sType, sType);
+    FIELD(VkPipelineInputAssemblyStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineInputAssemblyStateCreateInfo, flags, flags);
+    FIELD(VkPipelineInputAssemblyStateCreateInfo, topology, topology);
+    FIELD(VkPipelineInputAssemblyStateCreateInfo, primitiveRestartEnable, primitiveRestartEnable);
+    STRUCT(structs, vk::PipelineTessellationStateCreateInfo, VkPipelineTessellationStateCreateInfo);
+    FIELD(VkPipelineTessellationStateCreateInfo, sType, sType);
+    FIELD(VkPipelineTessellationStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineTessellationStateCreateInfo, flags, flags);
+    FIELD(VkPipelineTessellationStateCreateInfo, patchControlPoints, patchControlPoints);
+    STRUCT(structs, vk::Viewport, VkViewport);
+    FIELD(VkViewport, x, x);
+    FIELD(VkViewport, y, y);
+    FIELD(VkViewport, width, width);
+    FIELD(VkViewport, height, height);
+    FIELD(VkViewport, minDepth, minDepth);
+    FIELD(VkViewport, maxDepth, maxDepth);
+    STRUCT(structs, vk::Offset2D, VkOffset2D);
+    FIELD(VkOffset2D, x, x);
+    FIELD(VkOffset2D, y, y);
+    STRUCT(structs, vk::Extent2D, VkExtent2D);
+    FIELD(VkExtent2D, width, width);
+    FIELD(VkExtent2D, height, height);
+    STRUCT(structs, vk::Rect2D, VkRect2D);
+    FIELD(VkRect2D, offset, offset);
+    FIELD(VkRect2D, extent, extent);
+    STRUCT(structs, vk::PipelineViewportStateCreateInfo, VkPipelineViewportStateCreateInfo);
+    FIELD(VkPipelineViewportStateCreateInfo, sType, sType);
+    FIELD(VkPipelineViewportStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineViewportStateCreateInfo, flags, flags);
+    FIELD(VkPipelineViewportStateCreateInfo, viewportCount, viewportCount);
+    FIELD(VkPipelineViewportStateCreateInfo, pViewports, pViewports);
+    FIELD(VkPipelineViewportStateCreateInfo, scissorCount, scissorCount);
+    FIELD(VkPipelineViewportStateCreateInfo, pScissors, pScissors);
+    STRUCT(structs, vk::PipelineRasterizationStateCreateInfo, VkPipelineRasterizationStateCreateInfo);
+    FIELD(VkPipelineRasterizationStateCreateInfo, sType, sType);
+    FIELD(VkPipelineRasterizationStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineRasterizationStateCreateInfo, flags, flags);
+    FIELD(VkPipelineRasterizationStateCreateInfo, depthClampEnable, depthClampEnable);
+    FIELD(VkPipelineRasterizationStateCreateInfo, rasterizerDiscardEnable, rasterizerDiscardEnable);
+    FIELD(VkPipelineRasterizationStateCreateInfo, polygonMode, polygonMode);
+    FIELD(VkPipelineRasterizationStateCreateInfo, cullMode, cullMode);
+    FIELD(VkPipelineRasterizationStateCreateInfo, frontFace, frontFace);
+    FIELD(VkPipelineRasterizationStateCreateInfo, depthBiasEnable, depthBiasEnable);
+    FIELD(VkPipelineRasterizationStateCreateInfo, depthBiasConstantFactor, depthBiasConstantFactor);
+    FIELD(VkPipelineRasterizationStateCreateInfo, depthBiasClamp, depthBiasClamp);
+    FIELD(VkPipelineRasterizationStateCreateInfo, depthBiasSlopeFactor, depthBiasSlopeFactor);
+    FIELD(VkPipelineRasterizationStateCreateInfo, lineWidth, lineWidth);
+    STRUCT(structs, vk::PipelineMultisampleStateCreateInfo, VkPipelineMultisampleStateCreateInfo);
+    FIELD(VkPipelineMultisampleStateCreateInfo, sType, sType);
+    FIELD(VkPipelineMultisampleStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineMultisampleStateCreateInfo, flags, flags);
+    FIELD(VkPipelineMultisampleStateCreateInfo, rasterizationSamples, rasterizationSamples);
+    FIELD(VkPipelineMultisampleStateCreateInfo, sampleShadingEnable, sampleShadingEnable);
+    FIELD(VkPipelineMultisampleStateCreateInfo, minSampleShading, minSampleShading);
+    FIELD(VkPipelineMultisampleStateCreateInfo, pSampleMask, pSampleMask);
+    FIELD(VkPipelineMultisampleStateCreateInfo, alphaToCoverageEnable, alphaToCoverageEnable);
+    FIELD(VkPipelineMultisampleStateCreateInfo, alphaToOneEnable, alphaToOneEnable);
+    STRUCT(structs, vk::StencilOpState, VkStencilOpState);
+    FIELD(VkStencilOpState, failOp, failOp);
+    FIELD(VkStencilOpState, passOp, passOp);
+    FIELD(VkStencilOpState, depthFailOp, depthFailOp);
+    FIELD(VkStencilOpState, compareOp, compareOp);
+    FIELD(VkStencilOpState, compareMask, compareMask);
+    FIELD(VkStencilOpState, writeMask, writeMask);
+    FIELD(VkStencilOpState, reference, reference);
+    STRUCT(structs, vk::PipelineDepthStencilStateCreateInfo, VkPipelineDepthStencilStateCreateInfo);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, sType, sType);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, flags, flags);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, depthTestEnable, depthTestEnable);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, depthWriteEnable, depthWriteEnable);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, depthCompareOp, depthCompareOp);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, depthBoundsTestEnable, depthBoundsTestEnable);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, stencilTestEnable, stencilTestEnable);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, front, front);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, back, back);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, minDepthBounds, minDepthBounds);
+    FIELD(VkPipelineDepthStencilStateCreateInfo, maxDepthBounds, maxDepthBounds);
+    STRUCT(structs, vk::PipelineColorBlendAttachmentState, VkPipelineColorBlendAttachmentState);
+    FIELD(VkPipelineColorBlendAttachmentState, blendEnable, blendEnable);
+    FIELD(VkPipelineColorBlendAttachmentState, srcColorBlendFactor, srcColorBlendFactor);
+    FIELD(VkPipelineColorBlendAttachmentState, dstColorBlendFactor, dstColorBlendFactor);
+    FIELD(VkPipelineColorBlendAttachmentState, colorBlendOp, colorBlendOp);
+    FIELD(VkPipelineColorBlendAttachmentState, srcAlphaBlendFactor, srcAlphaBlendFactor);
+    FIELD(VkPipelineColorBlendAttachmentState, dstAlphaBlendFactor, dstAlphaBlendFactor);
+    FIELD(VkPipelineColorBlendAttachmentState, alphaBlendOp, alphaBlendOp);
+    FIELD(VkPipelineColorBlendAttachmentState, colorWriteMask, colorWriteMask);
+    STRUCT(structs, vk::PipelineColorBlendStateCreateInfo, VkPipelineColorBlendStateCreateInfo);
+    FIELD(VkPipelineColorBlendStateCreateInfo, sType, sType);
+    FIELD(VkPipelineColorBlendStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineColorBlendStateCreateInfo, flags, flags);
+    FIELD(VkPipelineColorBlendStateCreateInfo, logicOpEnable, logicOpEnable);
+    FIELD(VkPipelineColorBlendStateCreateInfo, logicOp, logicOp);
+    FIELD(VkPipelineColorBlendStateCreateInfo, attachmentCount, attachmentCount);
+    FIELD(VkPipelineColorBlendStateCreateInfo, pAttachments, pAttachments);
+    FIELD(VkPipelineColorBlendStateCreateInfo, blendConstants, blendConstants);
+    STRUCT(structs, vk::PipelineDynamicStateCreateInfo, VkPipelineDynamicStateCreateInfo);
+    FIELD(VkPipelineDynamicStateCreateInfo, sType, sType);
+    FIELD(VkPipelineDynamicStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineDynamicStateCreateInfo, flags, flags);
+    FIELD(VkPipelineDynamicStateCreateInfo, dynamicStateCount, dynamicStateCount);
+    FIELD(VkPipelineDynamicStateCreateInfo, pDynamicStates, pDynamicStates);
+    STRUCT(structs, vk::GraphicsPipelineCreateInfo, VkGraphicsPipelineCreateInfo);
+    FIELD(VkGraphicsPipelineCreateInfo, sType, sType);
+    FIELD(VkGraphicsPipelineCreateInfo, pNext, pNext);
+    FIELD(VkGraphicsPipelineCreateInfo, flags, flags);
+    FIELD(VkGraphicsPipelineCreateInfo, stageCount, stageCount);
+    FIELD(VkGraphicsPipelineCreateInfo, pStages, pStages);
+    FIELD(VkGraphicsPipelineCreateInfo, pVertexInputState, pVertexInputState);
+    FIELD(VkGraphicsPipelineCreateInfo, pInputAssemblyState, pInputAssemblyState);
+    FIELD(VkGraphicsPipelineCreateInfo, pTessellationState, pTessellationState);
+    FIELD(VkGraphicsPipelineCreateInfo, pViewportState, pViewportState);
+    FIELD(VkGraphicsPipelineCreateInfo, pRasterizationState, pRasterizationState);
+    FIELD(VkGraphicsPipelineCreateInfo, pMultisampleState, pMultisampleState);
+    FIELD(VkGraphicsPipelineCreateInfo, pDepthStencilState, pDepthStencilState);
+    FIELD(VkGraphicsPipelineCreateInfo, pColorBlendState, pColorBlendState);
+    FIELD(VkGraphicsPipelineCreateInfo, pDynamicState, pDynamicState);
+    FIELD(VkGraphicsPipelineCreateInfo, layout, layout);
+    FIELD(VkGraphicsPipelineCreateInfo, renderPass, renderPass);
+    FIELD(VkGraphicsPipelineCreateInfo, subpass, subpass);
+    FIELD(VkGraphicsPipelineCreateInfo, basePipelineHandle, basePipelineHandle);
+    FIELD(VkGraphicsPipelineCreateInfo, basePipelineIndex, basePipelineIndex);
+    STRUCT(structs, vk::ComputePipelineCreateInfo, VkComputePipelineCreateInfo);
+    FIELD(VkComputePipelineCreateInfo, sType, sType);
+    FIELD(VkComputePipelineCreateInfo, pNext, pNext);
+    FIELD(VkComputePipelineCreateInfo, flags, flags);
+    FIELD(VkComputePipelineCreateInfo, stage, stage);
+    FIELD(VkComputePipelineCreateInfo, layout, layout);
+    FIELD(VkComputePipelineCreateInfo, basePipelineHandle, basePipelineHandle);
+    FIELD(VkComputePipelineCreateInfo, basePipelineIndex, basePipelineIndex);
+    STRUCT(structs, vk::PushConstantRange, VkPushConstantRange);
+    FIELD(VkPushConstantRange, stageFlags, stageFlags);
+    FIELD(VkPushConstantRange, offset, offset);
+    FIELD(VkPushConstantRange, size, size);
+    STRUCT(structs, vk::PipelineLayoutCreateInfo, VkPipelineLayoutCreateInfo);
+    FIELD(VkPipelineLayoutCreateInfo, sType, sType);
+    FIELD(VkPipelineLayoutCreateInfo, pNext, pNext);
+    FIELD(VkPipelineLayoutCreateInfo, flags, flags);
+    FIELD(VkPipelineLayoutCreateInfo, setLayoutCount, setLayoutCount);
+    FIELD(VkPipelineLayoutCreateInfo, pSetLayouts, pSetLayouts);
+    FIELD(VkPipelineLayoutCreateInfo, pushConstantRangeCount, pushConstantRangeCount);
+    FIELD(VkPipelineLayoutCreateInfo, pPushConstantRanges, pPushConstantRanges);
+    STRUCT(structs, vk::SamplerCreateInfo, VkSamplerCreateInfo);
+    FIELD(VkSamplerCreateInfo, sType, sType);
+    FIELD(VkSamplerCreateInfo, pNext, pNext);
+    FIELD(VkSamplerCreateInfo, flags, flags);
+    FIELD(VkSamplerCreateInfo, magFilter, magFilter);
+    FIELD(VkSamplerCreateInfo, minFilter, minFilter);
+    FIELD(VkSamplerCreateInfo, mipmapMode, mipmapMode);
+    FIELD(VkSamplerCreateInfo, addressModeU, addressModeU);
+    FIELD(VkSamplerCreateInfo, addressModeV, addressModeV);
+    FIELD(VkSamplerCreateInfo, addressModeW, addressModeW);
+    FIELD(VkSamplerCreateInfo, mipLodBias, mipLodBias);
+    FIELD(VkSamplerCreateInfo, anisotropyEnable, anisotropyEnable);
+    FIELD(VkSamplerCreateInfo, maxAnisotropy, maxAnisotropy);
+    FIELD(VkSamplerCreateInfo, compareEnable, compareEnable);
+    FIELD(VkSamplerCreateInfo, compareOp, compareOp);
+    FIELD(VkSamplerCreateInfo, minLod, minLod);
+    FIELD(VkSamplerCreateInfo, maxLod, maxLod);
+    FIELD(VkSamplerCreateInfo, borderColor, borderColor);
+    FIELD(VkSamplerCreateInfo, unnormalizedCoordinates, unnormalizedCoordinates);
+    STRUCT(structs, vk::DescriptorSetLayoutBinding, VkDescriptorSetLayoutBinding);
+    FIELD(VkDescriptorSetLayoutBinding, binding, binding);
+    FIELD(VkDescriptorSetLayoutBinding, descriptorType, descriptorType);
+    FIELD(VkDescriptorSetLayoutBinding, descriptorCount, descriptorCount);
+    FIELD(VkDescriptorSetLayoutBinding, stageFlags, stageFlags);
+    FIELD(VkDescriptorSetLayoutBinding, pImmutableSamplers, pImmutableSamplers);
+    STRUCT(structs, vk::DescriptorSetLayoutCreateInfo, VkDescriptorSetLayoutCreateInfo);
+    FIELD(VkDescriptorSetLayoutCreateInfo, sType, sType);
+    FIELD(VkDescriptorSetLayoutCreateInfo, pNext, pNext);
+    FIELD(VkDescriptorSetLayoutCreateInfo, flags, flags);
+    FIELD(VkDescriptorSetLayoutCreateInfo, bindingCount, bindingCount);
+    FIELD(VkDescriptorSetLayoutCreateInfo, pBindings, pBindings);
+    STRUCT(structs, vk::DescriptorPoolSize, VkDescriptorPoolSize);
+    FIELD(VkDescriptorPoolSize, type, typ);
+    FIELD(VkDescriptorPoolSize, descriptorCount, descriptorCount);
+    STRUCT(structs, vk::DescriptorPoolCreateInfo, VkDescriptorPoolCreateInfo);
+    FIELD(VkDescriptorPoolCreateInfo, sType, sType);
+    FIELD(VkDescriptorPoolCreateInfo, pNext, pNext);
+    FIELD(VkDescriptorPoolCreateInfo, flags, flags);
+    FIELD(VkDescriptorPoolCreateInfo, maxSets, maxSets);
+    FIELD(VkDescriptorPoolCreateInfo, poolSizeCount, poolSizeCount);
+    FIELD(VkDescriptorPoolCreateInfo, pPoolSizes, pPoolSizes);
+    STRUCT(structs, vk::DescriptorSetAllocateInfo, VkDescriptorSetAllocateInfo);
+    FIELD(VkDescriptorSetAllocateInfo, sType, sType);
+    FIELD(VkDescriptorSetAllocateInfo, pNext, pNext);
+    FIELD(VkDescriptorSetAllocateInfo, descriptorPool, descriptorPool);
+    FIELD(VkDescriptorSetAllocateInfo, descriptorSetCount, descriptorSetCount);
+    FIELD(VkDescriptorSetAllocateInfo, pSetLayouts, pSetLayouts);
+    STRUCT(structs, vk::DescriptorImageInfo, VkDescriptorImageInfo);
+    FIELD(VkDescriptorImageInfo, sampler, sampler);
+    FIELD(VkDescriptorImageInfo, imageView, imageView);
+    FIELD(VkDescriptorImageInfo, imageLayout, imageLayout);
+    STRUCT(structs, vk::DescriptorBufferInfo, VkDescriptorBufferInfo);
+    FIELD(VkDescriptorBufferInfo, buffer, buffer);
+    FIELD(VkDescriptorBufferInfo, offset, offset);
+    FIELD(VkDescriptorBufferInfo, range, range);
+    STRUCT(structs, vk::WriteDescriptorSet, VkWriteDescriptorSet);
+    FIELD(VkWriteDescriptorSet, sType, sType);
+    FIELD(VkWriteDescriptorSet, pNext, pNext);
+    FIELD(VkWriteDescriptorSet, dstSet, dstSet);
+    FIELD(VkWriteDescriptorSet, dstBinding, dstBinding);
+    FIELD(VkWriteDescriptorSet, dstArrayElement, dstArrayElement);
+    FIELD(VkWriteDescriptorSet, descriptorCount, descriptorCount);
+    FIELD(VkWriteDescriptorSet, descriptorType, descriptorType);
+    FIELD(VkWriteDescriptorSet, pImageInfo, pImageInfo);
+    FIELD(VkWriteDescriptorSet, pBufferInfo, pBufferInfo);
+    FIELD(VkWriteDescriptorSet, pTexelBufferView, pTexelBufferView);
+    STRUCT(structs, vk::CopyDescriptorSet, VkCopyDescriptorSet);
+    FIELD(VkCopyDescriptorSet, sType, sType);
+    FIELD(VkCopyDescriptorSet, pNext, pNext);
+    FIELD(VkCopyDescriptorSet, srcSet, srcSet);
+    FIELD(VkCopyDescriptorSet, srcBinding, srcBinding);
+    FIELD(VkCopyDescriptorSet, srcArrayElement, srcArrayElement);
+    FIELD(VkCopyDescriptorSet, dstSet, dstSet);
+    FIELD(VkCopyDescriptorSet, dstBinding, dstBinding);
+    FIELD(VkCopyDescriptorSet, dstArrayElement, dstArrayElement);
+    FIELD(VkCopyDescriptorSet, descriptorCount, descriptorCount);
+    STRUCT(structs, vk::FramebufferCreateInfo, VkFramebufferCreateInfo);
+    FIELD(VkFramebufferCreateInfo, sType, sType);
+    FIELD(VkFramebufferCreateInfo, pNext, pNext);
+    FIELD(VkFramebufferCreateInfo, flags, flags);
+    FIELD(VkFramebufferCreateInfo, renderPass, renderPass);
+    FIELD(VkFramebufferCreateInfo, attachmentCount, attachmentCount);
+    FIELD(VkFramebufferCreateInfo, pAttachments, pAttachments);
+    FIELD(VkFramebufferCreateInfo, width, width);
+    FIELD(VkFramebufferCreateInfo, height, height);
+    FIELD(VkFramebufferCreateInfo, layers, layers);
+    STRUCT(structs, vk::AttachmentDescription, VkAttachmentDescription);
+    FIELD(VkAttachmentDescription, flags, flags);
+    FIELD(VkAttachmentDescription, format, format);
+    FIELD(VkAttachmentDescription, samples, samples);
+    FIELD(VkAttachmentDescription, loadOp, loadOp);
+    FIELD(VkAttachmentDescription, storeOp, storeOp);
+    FIELD(VkAttachmentDescription, stencilLoadOp, stencilLoadOp);
+    FIELD(VkAttachmentDescription, stencilStoreOp, stencilStoreOp);
+    FIELD(VkAttachmentDescription, initialLayout, initialLayout);
+    FIELD(VkAttachmentDescription, finalLayout, finalLayout);
+    STRUCT(structs, vk::AttachmentReference, VkAttachmentReference);
+    FIELD(VkAttachmentReference, attachment, attachment);
+    FIELD(VkAttachmentReference, layout, layout);
+    STRUCT(structs, vk::SubpassDescription, VkSubpassDescription);
+    FIELD(VkSubpassDescription, flags, flags);
+    FIELD(VkSubpassDescription, pipelineBindPoint, pipelineBindPoint);
+    FIELD(VkSubpassDescription, inputAttachmentCount, inputAttachmentCount);
+    FIELD(VkSubpassDescription, pInputAttachments, pInputAttachments);
+    FIELD(VkSubpassDescription, colorAttachmentCount, colorAttachmentCount);
+    FIELD(VkSubpassDescription, pColorAttachments, pColorAttachments);
+    FIELD(VkSubpassDescription, pResolveAttachments, pResolveAttachments);
+    FIELD(VkSubpassDescription, pDepthStencilAttachment, pDepthStencilAttachment);
+    FIELD(VkSubpassDescription, preserveAttachmentCount, preserveAttachmentCount);
+    FIELD(VkSubpassDescription, pPreserveAttachments, pPreserveAttachments);
+    STRUCT(structs, vk::SubpassDependency, VkSubpassDependency);
+    FIELD(VkSubpassDependency, srcSubpass, srcSubpass);
+    FIELD(VkSubpassDependency, dstSubpass, dstSubpass);
+    FIELD(VkSubpassDependency, srcStageMask, srcStageMask);
+    FIELD(VkSubpassDependency, dstStageMask, dstStageMask);
+    FIELD(VkSubpassDependency, srcAccessMask, srcAccessMask);
+    FIELD(VkSubpassDependency, dstAccessMask, dstAccessMask);
+    FIELD(VkSubpassDependency, dependencyFlags, dependencyFlags);
+    STRUCT(structs, vk::RenderPassCreateInfo, VkRenderPassCreateInfo);
+    FIELD(VkRenderPassCreateInfo, sType, sType);
+    FIELD(VkRenderPassCreateInfo, pNext, pNext);
+    FIELD(VkRenderPassCreateInfo, flags, flags);
+    FIELD(VkRenderPassCreateInfo, attachmentCount, attachmentCount);
+    FIELD(VkRenderPassCreateInfo, pAttachments, pAttachments);
+    FIELD(VkRenderPassCreateInfo, subpassCount, subpassCount);
+    FIELD(VkRenderPassCreateInfo, pSubpasses, pSubpasses);
+    FIELD(VkRenderPassCreateInfo, dependencyCount, dependencyCount);
+    FIELD(VkRenderPassCreateInfo, pDependencies, pDependencies);
+    STRUCT(structs, vk::CommandPoolCreateInfo, VkCommandPoolCreateInfo);
+    FIELD(VkCommandPoolCreateInfo, sType, sType);
+    FIELD(VkCommandPoolCreateInfo, pNext, pNext);
+    FIELD(VkCommandPoolCreateInfo, flags, flags);
+    FIELD(VkCommandPoolCreateInfo, queueFamilyIndex, queueFamilyIndex);
+    STRUCT(structs, vk::CommandBufferAllocateInfo, VkCommandBufferAllocateInfo);
+    FIELD(VkCommandBufferAllocateInfo, sType, sType);
+    FIELD(VkCommandBufferAllocateInfo, pNext, pNext);
+    FIELD(VkCommandBufferAllocateInfo, commandPool, commandPool);
+    FIELD(VkCommandBufferAllocateInfo, level, level);
+    FIELD(VkCommandBufferAllocateInfo, commandBufferCount, commandBufferCount);
+    STRUCT(structs, vk::CommandBufferInheritanceInfo, VkCommandBufferInheritanceInfo);
+    FIELD(VkCommandBufferInheritanceInfo, sType, sType);
+    FIELD(VkCommandBufferInheritanceInfo, pNext, pNext);
+    FIELD(VkCommandBufferInheritanceInfo, renderPass, renderPass);
+    FIELD(VkCommandBufferInheritanceInfo, subpass, subpass);
+    FIELD(VkCommandBufferInheritanceInfo, framebuffer, framebuffer);
+    FIELD(VkCommandBufferInheritanceInfo, occlusionQueryEnable, occlusionQueryEnable);
+    FIELD(VkCommandBufferInheritanceInfo, queryFlags, queryFlags);
+    FIELD(VkCommandBufferInheritanceInfo, pipelineStatistics, pipelineStatistics);
+    STRUCT(structs, vk::CommandBufferBeginInfo, VkCommandBufferBeginInfo);
+    FIELD(VkCommandBufferBeginInfo, sType, sType);
+    FIELD(VkCommandBufferBeginInfo, pNext, pNext);
+    FIELD(VkCommandBufferBeginInfo, flags, flags);
+    FIELD(VkCommandBufferBeginInfo, pInheritanceInfo, pInheritanceInfo);
+    STRUCT(structs, vk::BufferCopy, VkBufferCopy);
+    FIELD(VkBufferCopy, srcOffset, srcOffset);
+    FIELD(VkBufferCopy, dstOffset, dstOffset);
+    FIELD(VkBufferCopy, size, size);
+    STRUCT(structs, vk::ImageSubresourceLayers, VkImageSubresourceLayers);
+    FIELD(VkImageSubresourceLayers, aspectMask, aspectMask);
+    FIELD(VkImageSubresourceLayers, mipLevel, mipLevel);
+    FIELD(VkImageSubresourceLayers, baseArrayLayer, baseArrayLayer);
+    FIELD(VkImageSubresourceLayers, layerCount, layerCount);
+    STRUCT(structs, vk::ImageCopy, VkImageCopy);
+    FIELD(VkImageCopy, srcSubresource, srcSubresource);
+    FIELD(VkImageCopy, srcOffset, srcOffset);
+    FIELD(VkImageCopy, dstSubresource, dstSubresource);
+    FIELD(VkImageCopy, dstOffset, dstOffset);
+    FIELD(VkImageCopy, extent, extent);
+    STRUCT(structs, vk::ImageBlit, VkImageBlit);
+    FIELD(VkImageBlit, srcSubresource, srcSubresource);
+    FIELD(VkImageBlit, srcOffsets, srcOffsets);
+    FIELD(VkImageBlit, dstSubresource, dstSubresource);
+    FIELD(VkImageBlit, dstOffsets, dstOffsets);
+    STRUCT(structs, vk::BufferImageCopy, VkBufferImageCopy);
+    FIELD(VkBufferImageCopy, bufferOffset, bufferOffset);
+    FIELD(VkBufferImageCopy, bufferRowLength, bufferRowLength);
+    FIELD(VkBufferImageCopy, bufferImageHeight, bufferImageHeight);
+    FIELD(VkBufferImageCopy, imageSubresource, imageSubresource);
+    FIELD(VkBufferImageCopy, imageOffset, imageOffset);
+    FIELD(VkBufferImageCopy, imageExtent, imageExtent);
+    STRUCT(structs, vk::ClearDepthStencilValue, VkClearDepthStencilValue);
+    FIELD(VkClearDepthStencilValue, depth, depth);
+    FIELD(VkClearDepthStencilValue, stencil, stencil);
+    STRUCT(structs, vk::ClearAttachment, VkClearAttachment);
+    FIELD(VkClearAttachment, aspectMask, aspectMask);
+    FIELD(VkClearAttachment, colorAttachment, colorAttachment);
+    FIELD(VkClearAttachment, clearValue, clearValue);
+    STRUCT(structs, vk::ClearRect, VkClearRect);
+    FIELD(VkClearRect, rect, rect);
+    FIELD(VkClearRect, baseArrayLayer, baseArrayLayer);
+    FIELD(VkClearRect, layerCount, layerCount);
+    STRUCT(structs, vk::ImageResolve, VkImageResolve);
+    FIELD(VkImageResolve, srcSubresource, srcSubresource);
+    FIELD(VkImageResolve, srcOffset, srcOffset);
+    FIELD(VkImageResolve, dstSubresource, dstSubresource);
+    FIELD(VkImageResolve, dstOffset, dstOffset);
+    FIELD(VkImageResolve, extent, extent);
+    STRUCT(structs, vk::MemoryBarrier, VkMemoryBarrier);
+    FIELD(VkMemoryBarrier, sType, sType);
+    FIELD(VkMemoryBarrier, pNext, pNext);
+    FIELD(VkMemoryBarrier, srcAccessMask, srcAccessMask);
+    FIELD(VkMemoryBarrier, dstAccessMask, dstAccessMask);
+    STRUCT(structs, vk::BufferMemoryBarrier, VkBufferMemoryBarrier);
+    FIELD(VkBufferMemoryBarrier, sType, sType);
+    FIELD(VkBufferMemoryBarrier, pNext, pNext);
+    FIELD(VkBufferMemoryBarrier, srcAccessMask, srcAccessMask);
+    FIELD(VkBufferMemoryBarrier, dstAccessMask, dstAccessMask);
+    FIELD(VkBufferMemoryBarrier, srcQueueFamilyIndex, srcQueueFamilyIndex);
+    FIELD(VkBufferMemoryBarrier, dstQueueFamilyIndex, dstQueueFamilyIndex);
+    FIELD(VkBufferMemoryBarrier, buffer, buffer);
+    FIELD(VkBufferMemoryBarrier, offset, offset);
+    FIELD(VkBufferMemoryBarrier, size, size);
+    STRUCT(structs, vk::ImageMemoryBarrier, VkImageMemoryBarrier);
+    FIELD(VkImageMemoryBarrier, sType, sType);
+    FIELD(VkImageMemoryBarrier, pNext, pNext);
+    FIELD(VkImageMemoryBarrier, srcAccessMask, srcAccessMask);
+    FIELD(VkImageMemoryBarrier, dstAccessMask, dstAccessMask);
+    FIELD(VkImageMemoryBarrier, oldLayout, oldLayout);
+    FIELD(VkImageMemoryBarrier, newLayout, newLayout);
+    FIELD(VkImageMemoryBarrier, srcQueueFamilyIndex, srcQueueFamilyIndex);
+    FIELD(VkImageMemoryBarrier, dstQueueFamilyIndex, dstQueueFamilyIndex);
+    FIELD(VkImageMemoryBarrier, image, image);
+    FIELD(VkImageMemoryBarrier, subresourceRange, subresourceRange);
+    STRUCT(structs, vk::RenderPassBeginInfo, VkRenderPassBeginInfo);
+    FIELD(VkRenderPassBeginInfo, sType, sType);
+    FIELD(VkRenderPassBeginInfo, pNext, pNext);
+    FIELD(VkRenderPassBeginInfo, renderPass, renderPass);
+    FIELD(VkRenderPassBeginInfo, framebuffer, framebuffer);
+    FIELD(VkRenderPassBeginInfo, renderArea, renderArea);
+    FIELD(VkRenderPassBeginInfo, clearValueCount, clearValueCount);
+    FIELD(VkRenderPassBeginInfo, pClearValues, pClearValues);
+    STRUCT(structs, vk::DispatchIndirectCommand, VkDispatchIndirectCommand);
+    FIELD(VkDispatchIndirectCommand, x, x);
+    FIELD(VkDispatchIndirectCommand, y, y);
+    FIELD(VkDispatchIndirectCommand, z, z);
+    STRUCT(structs, vk::DrawIndexedIndirectCommand, VkDrawIndexedIndirectCommand);
+    FIELD(VkDrawIndexedIndirectCommand, indexCount, indexCount);
+    FIELD(VkDrawIndexedIndirectCommand, instanceCount, instanceCount);
+    FIELD(VkDrawIndexedIndirectCommand, firstIndex, firstIndex);
+    FIELD(VkDrawIndexedIndirectCommand, vertexOffset, vertexOffset);
+    FIELD(VkDrawIndexedIndirectCommand, firstInstance, firstInstance);
+    STRUCT(structs, vk::DrawIndirectCommand, VkDrawIndirectCommand);
+    FIELD(VkDrawIndirectCommand, vertexCount, vertexCount);
+    FIELD(VkDrawIndirectCommand, instanceCount, instanceCount);
+    FIELD(VkDrawIndirectCommand, firstVertex, firstVertex);
+    FIELD(VkDrawIndirectCommand, firstInstance, firstInstance);
+    STRUCT(structs, vk::PhysicalDeviceSubgroupProperties, VkPhysicalDeviceSubgroupProperties);
+    FIELD(VkPhysicalDeviceSubgroupProperties, sType, sType);
+    FIELD(VkPhysicalDeviceSubgroupProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceSubgroupProperties, subgroupSize, subgroupSize);
+    FIELD(VkPhysicalDeviceSubgroupProperties, supportedStages, supportedStages);
+    FIELD(VkPhysicalDeviceSubgroupProperties, supportedOperations, supportedOperations);
+    FIELD(VkPhysicalDeviceSubgroupProperties, quadOperationsInAllStages, quadOperationsInAllStages);
+    STRUCT(structs, vk::BindBufferMemoryInfo, VkBindBufferMemoryInfo);
+    FIELD(VkBindBufferMemoryInfo, sType, sType);
+    FIELD(VkBindBufferMemoryInfo, pNext, pNext);
+    FIELD(VkBindBufferMemoryInfo, buffer, buffer);
+    FIELD(VkBindBufferMemoryInfo, memory, memory);
+    FIELD(VkBindBufferMemoryInfo, memoryOffset, memoryOffset);
+    STRUCT(structs, vk::BindImageMemoryInfo, VkBindImageMemoryInfo);
+    FIELD(VkBindImageMemoryInfo, sType, sType);
+    FIELD(VkBindImageMemoryInfo, pNext, pNext);
+    FIELD(VkBindImageMemoryInfo, image, image);
+    FIELD(VkBindImageMemoryInfo, memory, memory);
+    FIELD(VkBindImageMemoryInfo, memoryOffset, memoryOffset);
+    STRUCT(structs, vk::PhysicalDevice16BitStorageFeatures, VkPhysicalDevice16BitStorageFeatures);
+    FIELD(VkPhysicalDevice16BitStorageFeatures, sType, sType);
+    FIELD(VkPhysicalDevice16BitStorageFeatures, pNext, pNext);
+    FIELD(VkPhysicalDevice16BitStorageFeatures, storageBuffer16BitAccess, storageBuffer16BitAccess);
+    FIELD(VkPhysicalDevice16BitStorageFeatures, uniformAndStorageBuffer16BitAccess, uniformAndStorageBuffer16BitAccess);
+    FIELD(VkPhysicalDevice16BitStorageFeatures, storagePushConstant16, storagePushConstant16);
+    FIELD(VkPhysicalDevice16BitStorageFeatures, storageInputOutput16, storageInputOutput16);
+    STRUCT(structs, vk::MemoryDedicatedRequirements, VkMemoryDedicatedRequirements);
+    FIELD(VkMemoryDedicatedRequirements, sType, sType);
+    FIELD(VkMemoryDedicatedRequirements, pNext, pNext);
+    FIELD(VkMemoryDedicatedRequirements, prefersDedicatedAllocation, prefersDedicatedAllocation);
+    FIELD(VkMemoryDedicatedRequirements, requiresDedicatedAllocation, requiresDedicatedAllocation);
+    STRUCT(structs, vk::MemoryDedicatedAllocateInfo, VkMemoryDedicatedAllocateInfo);
+    FIELD(VkMemoryDedicatedAllocateInfo, sType, sType);
+    FIELD(VkMemoryDedicatedAllocateInfo, pNext, pNext);
+    FIELD(VkMemoryDedicatedAllocateInfo, image, image);
+    FIELD(VkMemoryDedicatedAllocateInfo, buffer, buffer);
+    STRUCT(structs, vk::MemoryAllocateFlagsInfo, VkMemoryAllocateFlagsInfo);
+    FIELD(VkMemoryAllocateFlagsInfo, sType, sType);
+    FIELD(VkMemoryAllocateFlagsInfo, pNext, pNext);
+    FIELD(VkMemoryAllocateFlagsInfo, flags, flags);
+    FIELD(VkMemoryAllocateFlagsInfo, deviceMask, deviceMask);
+    STRUCT(structs, vk::DeviceGroupRenderPassBeginInfo, VkDeviceGroupRenderPassBeginInfo);
+    FIELD(VkDeviceGroupRenderPassBeginInfo, sType, sType);
+    FIELD(VkDeviceGroupRenderPassBeginInfo, pNext, pNext);
+    FIELD(VkDeviceGroupRenderPassBeginInfo, deviceMask, deviceMask);
+    FIELD(VkDeviceGroupRenderPassBeginInfo, deviceRenderAreaCount, deviceRenderAreaCount);
+    FIELD(VkDeviceGroupRenderPassBeginInfo, pDeviceRenderAreas, pDeviceRenderAreas);
+    STRUCT(structs, vk::DeviceGroupCommandBufferBeginInfo, VkDeviceGroupCommandBufferBeginInfo);
+    FIELD(VkDeviceGroupCommandBufferBeginInfo, sType, sType);
+    FIELD(VkDeviceGroupCommandBufferBeginInfo, pNext, pNext);
+    FIELD(VkDeviceGroupCommandBufferBeginInfo, deviceMask, deviceMask);
+    STRUCT(structs, vk::DeviceGroupSubmitInfo, VkDeviceGroupSubmitInfo);
+    FIELD(VkDeviceGroupSubmitInfo, sType, sType);
+    FIELD(VkDeviceGroupSubmitInfo, pNext, pNext);
+    FIELD(VkDeviceGroupSubmitInfo, waitSemaphoreCount, waitSemaphoreCount);
+    FIELD(VkDeviceGroupSubmitInfo, pWaitSemaphoreDeviceIndices, pWaitSemaphoreDeviceIndices);
+    FIELD(VkDeviceGroupSubmitInfo, commandBufferCount, commandBufferCount);
+    FIELD(VkDeviceGroupSubmitInfo, pCommandBufferDeviceMasks, pCommandBufferDeviceMasks);
+    FIELD(VkDeviceGroupSubmitInfo, signalSemaphoreCount, signalSemaphoreCount);
+    FIELD(VkDeviceGroupSubmitInfo, pSignalSemaphoreDeviceIndices, pSignalSemaphoreDeviceIndices);
+    STRUCT(structs, vk::DeviceGroupBindSparseInfo, VkDeviceGroupBindSparseInfo);
+    FIELD(VkDeviceGroupBindSparseInfo, sType, sType);
+    FIELD(VkDeviceGroupBindSparseInfo, pNext, pNext);
+    FIELD(VkDeviceGroupBindSparseInfo, resourceDeviceIndex, resourceDeviceIndex);
+    FIELD(VkDeviceGroupBindSparseInfo, memoryDeviceIndex, memoryDeviceIndex);
+    STRUCT(structs, vk::BindBufferMemoryDeviceGroupInfo, VkBindBufferMemoryDeviceGroupInfo);
+    FIELD(VkBindBufferMemoryDeviceGroupInfo, sType, sType);
+    FIELD(VkBindBufferMemoryDeviceGroupInfo, pNext, pNext);
+    FIELD(VkBindBufferMemoryDeviceGroupInfo, deviceIndexCount, deviceIndexCount);
+    FIELD(VkBindBufferMemoryDeviceGroupInfo, pDeviceIndices, pDeviceIndices);
+    STRUCT(structs, vk::BindImageMemoryDeviceGroupInfo, VkBindImageMemoryDeviceGroupInfo);
+    FIELD(VkBindImageMemoryDeviceGroupInfo, sType, sType);
+    FIELD(VkBindImageMemoryDeviceGroupInfo, pNext, pNext);
+    FIELD(VkBindImageMemoryDeviceGroupInfo, deviceIndexCount, deviceIndexCount);
+    FIELD(VkBindImageMemoryDeviceGroupInfo, pDeviceIndices, pDeviceIndices);
+    FIELD(VkBindImageMemoryDeviceGroupInfo, splitInstanceBindRegionCount, splitInstanceBindRegionCount);
+    FIELD(VkBindImageMemoryDeviceGroupInfo, pSplitInstanceBindRegions, pSplitInstanceBindRegions);
+    STRUCT(structs, vk::PhysicalDeviceGroupProperties, VkPhysicalDeviceGroupProperties);
+    FIELD(VkPhysicalDeviceGroupProperties, sType, sType);
+    FIELD(VkPhysicalDeviceGroupProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceGroupProperties, physicalDeviceCount, physicalDeviceCount);
+    FIELD(VkPhysicalDeviceGroupProperties, physicalDevices, physicalDevices);
+    FIELD(VkPhysicalDeviceGroupProperties, subsetAllocation, subsetAllocation);
+    STRUCT(structs, vk::DeviceGroupDeviceCreateInfo, VkDeviceGroupDeviceCreateInfo);
+    FIELD(VkDeviceGroupDeviceCreateInfo, sType, sType);
+    FIELD(VkDeviceGroupDeviceCreateInfo, pNext, pNext);
+    FIELD(VkDeviceGroupDeviceCreateInfo, physicalDeviceCount, physicalDeviceCount);
+    FIELD(VkDeviceGroupDeviceCreateInfo, pPhysicalDevices, pPhysicalDevices);
+    STRUCT(structs, vk::BufferMemoryRequirementsInfo2, VkBufferMemoryRequirementsInfo2);
+    FIELD(VkBufferMemoryRequirementsInfo2, sType, sType);
+    FIELD(VkBufferMemoryRequirementsInfo2, pNext, pNext);
+    FIELD(VkBufferMemoryRequirementsInfo2, buffer, buffer);
+    STRUCT(structs, vk::ImageMemoryRequirementsInfo2, VkImageMemoryRequirementsInfo2);
+    FIELD(VkImageMemoryRequirementsInfo2, sType, sType);
+    FIELD(VkImageMemoryRequirementsInfo2, pNext, pNext);
+    FIELD(VkImageMemoryRequirementsInfo2, image, image);
+    STRUCT(structs, vk::ImageSparseMemoryRequirementsInfo2, VkImageSparseMemoryRequirementsInfo2);
+    FIELD(VkImageSparseMemoryRequirementsInfo2, sType, sType);
+    FIELD(VkImageSparseMemoryRequirementsInfo2, pNext, pNext);
+    FIELD(VkImageSparseMemoryRequirementsInfo2, image, image);
+    STRUCT(structs, vk::MemoryRequirements2, VkMemoryRequirements2);
+    FIELD(VkMemoryRequirements2, sType, sType);
+    FIELD(VkMemoryRequirements2, pNext, pNext);
+    FIELD(VkMemoryRequirements2, memoryRequirements, memoryRequirements);
+    STRUCT(structs, vk::SparseImageMemoryRequirements2, VkSparseImageMemoryRequirements2);
+    FIELD(VkSparseImageMemoryRequirements2, sType, sType);
+    FIELD(VkSparseImageMemoryRequirements2, pNext, pNext);
+    FIELD(VkSparseImageMemoryRequirements2, memoryRequirements, memoryRequirements);
+    STRUCT(structs, vk::PhysicalDeviceFeatures2, VkPhysicalDeviceFeatures2);
+    FIELD(VkPhysicalDeviceFeatures2, sType, sType);
+    FIELD(VkPhysicalDeviceFeatures2, pNext, pNext);
+    FIELD(VkPhysicalDeviceFeatures2, features, features);
+    STRUCT(structs, vk::PhysicalDeviceProperties2, VkPhysicalDeviceProperties2);
+    FIELD(VkPhysicalDeviceProperties2, sType, sType);
+    FIELD(VkPhysicalDeviceProperties2, pNext, pNext);
+    FIELD(VkPhysicalDeviceProperties2, properties, properties);
+    STRUCT(structs, vk::FormatProperties2, VkFormatProperties2);
+    FIELD(VkFormatProperties2, sType, sType);
+    FIELD(VkFormatProperties2, pNext, pNext);
+    FIELD(VkFormatProperties2, formatProperties, formatProperties);
+    STRUCT(structs, vk::ImageFormatProperties2, VkImageFormatProperties2);
+    FIELD(VkImageFormatProperties2, sType, sType);
+    FIELD(VkImageFormatProperties2, pNext, pNext);
+    FIELD(VkImageFormatProperties2, imageFormatProperties, imageFormatProperties);
+    STRUCT(structs, vk::PhysicalDeviceImageFormatInfo2, VkPhysicalDeviceImageFormatInfo2);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, sType, sType);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, pNext, pNext);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, format, format);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, type, _type);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, tiling, tiling);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, usage, usage);
+    FIELD(VkPhysicalDeviceImageFormatInfo2, flags, flags);
+    STRUCT(structs, vk::QueueFamilyProperties2, VkQueueFamilyProperties2);
+    FIELD(VkQueueFamilyProperties2, sType, sType);
+    FIELD(VkQueueFamilyProperties2, pNext, pNext);
+    FIELD(VkQueueFamilyProperties2, queueFamilyProperties, queueFamilyProperties);
+    STRUCT(structs, vk::PhysicalDeviceMemoryProperties2, VkPhysicalDeviceMemoryProperties2);
+    FIELD(VkPhysicalDeviceMemoryProperties2, sType, sType);
+    FIELD(VkPhysicalDeviceMemoryProperties2, pNext, pNext);
+    FIELD(VkPhysicalDeviceMemoryProperties2, memoryProperties, memoryProperties);
+    STRUCT(structs, vk::SparseImageFormatProperties2, VkSparseImageFormatProperties2);
+    FIELD(VkSparseImageFormatProperties2, sType, sType);
+    FIELD(VkSparseImageFormatProperties2, pNext, pNext);
+    FIELD(VkSparseImageFormatProperties2, properties, properties);
+    STRUCT(structs, vk::PhysicalDeviceSparseImageFormatInfo2, VkPhysicalDeviceSparseImageFormatInfo2);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, sType, sType);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, pNext, pNext);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, format, format);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, type, _type);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, samples, samples);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, usage, usage);
+    FIELD(VkPhysicalDeviceSparseImageFormatInfo2, tiling, tiling);
+    STRUCT(structs, vk::PhysicalDevicePointClippingProperties, VkPhysicalDevicePointClippingProperties);
+    FIELD(VkPhysicalDevicePointClippingProperties, sType, sType);
+    FIELD(VkPhysicalDevicePointClippingProperties, pNext, pNext);
+    FIELD(VkPhysicalDevicePointClippingProperties, pointClippingBehavior, pointClippingBehavior);
+    STRUCT(structs, vk::InputAttachmentAspectReference, VkInputAttachmentAspectReference);
+    FIELD(VkInputAttachmentAspectReference, subpass, subpass);
+    FIELD(VkInputAttachmentAspectReference, inputAttachmentIndex, inputAttachmentIndex);
+    FIELD(VkInputAttachmentAspectReference, aspectMask, aspectMask);
+    STRUCT(structs, vk::RenderPassInputAttachmentAspectCreateInfo, VkRenderPassInputAttachmentAspectCreateInfo);
+    FIELD(VkRenderPassInputAttachmentAspectCreateInfo, sType, sType);
+    FIELD(VkRenderPassInputAttachmentAspectCreateInfo, pNext, pNext);
+    FIELD(VkRenderPassInputAttachmentAspectCreateInfo, aspectReferenceCount, aspectReferenceCount);
+    FIELD(VkRenderPassInputAttachmentAspectCreateInfo, pAspectReferences, pAspectReferences);
+    STRUCT(structs, vk::ImageViewUsageCreateInfo, VkImageViewUsageCreateInfo);
+    FIELD(VkImageViewUsageCreateInfo, sType, sType);
+    FIELD(VkImageViewUsageCreateInfo, pNext, pNext);
+    FIELD(VkImageViewUsageCreateInfo, usage, usage);
+    STRUCT(structs, vk::PipelineTessellationDomainOriginStateCreateInfo, VkPipelineTessellationDomainOriginStateCreateInfo);
+    FIELD(VkPipelineTessellationDomainOriginStateCreateInfo, sType, sType);
+    FIELD(VkPipelineTessellationDomainOriginStateCreateInfo, pNext, pNext);
+    FIELD(VkPipelineTessellationDomainOriginStateCreateInfo, domainOrigin, domainOrigin);
+    STRUCT(structs, vk::RenderPassMultiviewCreateInfo, VkRenderPassMultiviewCreateInfo);
+    FIELD(VkRenderPassMultiviewCreateInfo, sType, sType);
+    FIELD(VkRenderPassMultiviewCreateInfo, pNext, pNext);
+    FIELD(VkRenderPassMultiviewCreateInfo, subpassCount, subpassCount);
+    FIELD(VkRenderPassMultiviewCreateInfo, pViewMasks, pViewMasks);
+    FIELD(VkRenderPassMultiviewCreateInfo, dependencyCount, dependencyCount);
+    FIELD(VkRenderPassMultiviewCreateInfo, pViewOffsets, pViewOffsets);
+    FIELD(VkRenderPassMultiviewCreateInfo, correlationMaskCount, correlationMaskCount);
+    FIELD(VkRenderPassMultiviewCreateInfo, pCorrelationMasks, pCorrelationMasks);
+    STRUCT(structs, vk::PhysicalDeviceMultiviewFeatures, VkPhysicalDeviceMultiviewFeatures);
+    FIELD(VkPhysicalDeviceMultiviewFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceMultiviewFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceMultiviewFeatures, multiview, multiview);
+    FIELD(VkPhysicalDeviceMultiviewFeatures, multiviewGeometryShader, multiviewGeometryShader);
+    FIELD(VkPhysicalDeviceMultiviewFeatures, multiviewTessellationShader, multiviewTessellationShader);
+    STRUCT(structs, vk::PhysicalDeviceMultiviewProperties, VkPhysicalDeviceMultiviewProperties);
+    FIELD(VkPhysicalDeviceMultiviewProperties, sType, sType);
+    FIELD(VkPhysicalDeviceMultiviewProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceMultiviewProperties, maxMultiviewViewCount, maxMultiviewViewCount);
+    FIELD(VkPhysicalDeviceMultiviewProperties, maxMultiviewInstanceIndex, maxMultiviewInstanceIndex);
+    STRUCT(structs, vk::PhysicalDeviceVariablePointersFeatures, VkPhysicalDeviceVariablePointersFeatures);
+    FIELD(VkPhysicalDeviceVariablePointersFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceVariablePointersFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceVariablePointersFeatures, variablePointersStorageBuffer, variablePointersStorageBuffer);
+    FIELD(VkPhysicalDeviceVariablePointersFeatures, variablePointers, variablePointers);
+    STRUCT(structs, vk::PhysicalDeviceProtectedMemoryFeatures, VkPhysicalDeviceProtectedMemoryFeatures);
+    FIELD(VkPhysicalDeviceProtectedMemoryFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceProtectedMemoryFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceProtectedMemoryFeatures, protectedMemory, protectedMemory);
+    STRUCT(structs, vk::PhysicalDeviceProtectedMemoryProperties, VkPhysicalDeviceProtectedMemoryProperties);
+    FIELD(VkPhysicalDeviceProtectedMemoryProperties, sType, sType);
+    FIELD(VkPhysicalDeviceProtectedMemoryProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceProtectedMemoryProperties, protectedNoFault, protectedNoFault);
+    STRUCT(structs, vk::DeviceQueueInfo2, VkDeviceQueueInfo2);
+    FIELD(VkDeviceQueueInfo2, sType, sType);
+    FIELD(VkDeviceQueueInfo2, pNext, pNext);
+    FIELD(VkDeviceQueueInfo2, flags, flags);
+    FIELD(VkDeviceQueueInfo2, queueFamilyIndex, queueFamilyIndex);
+    FIELD(VkDeviceQueueInfo2, queueIndex, queueIndex);
+    STRUCT(structs, vk::ProtectedSubmitInfo, VkProtectedSubmitInfo);
+    FIELD(VkProtectedSubmitInfo, sType, sType);
+    FIELD(VkProtectedSubmitInfo, pNext, pNext);
+    FIELD(VkProtectedSubmitInfo, protectedSubmit, protectedSubmit);
+    STRUCT(structs, vk::SamplerYcbcrConversionCreateInfo, VkSamplerYcbcrConversionCreateInfo);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, sType, sType);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, pNext, pNext);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, format, format);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, ycbcrModel, ycbcrModel);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, ycbcrRange, ycbcrRange);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, components, components);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, xChromaOffset, xChromaOffset);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, yChromaOffset, yChromaOffset);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, chromaFilter, chromaFilter);
+    FIELD(VkSamplerYcbcrConversionCreateInfo, forceExplicitReconstruction, forceExplicitReconstruction);
+    STRUCT(structs, vk::SamplerYcbcrConversionInfo, VkSamplerYcbcrConversionInfo);
+    FIELD(VkSamplerYcbcrConversionInfo, sType, sType);
+    FIELD(VkSamplerYcbcrConversionInfo, pNext, pNext);
+    FIELD(VkSamplerYcbcrConversionInfo, conversion, conversion);
+    STRUCT(structs, vk::BindImagePlaneMemoryInfo, VkBindImagePlaneMemoryInfo);
+    FIELD(VkBindImagePlaneMemoryInfo, sType, sType);
+    FIELD(VkBindImagePlaneMemoryInfo, pNext, pNext);
+    FIELD(VkBindImagePlaneMemoryInfo, planeAspect, planeAspect);
+    STRUCT(structs, vk::ImagePlaneMemoryRequirementsInfo, VkImagePlaneMemoryRequirementsInfo);
+    FIELD(VkImagePlaneMemoryRequirementsInfo, sType, sType);
+    FIELD(VkImagePlaneMemoryRequirementsInfo, pNext, pNext);
+    FIELD(VkImagePlaneMemoryRequirementsInfo, planeAspect, planeAspect);
+    STRUCT(structs, vk::PhysicalDeviceSamplerYcbcrConversionFeatures, VkPhysicalDeviceSamplerYcbcrConversionFeatures);
+    FIELD(VkPhysicalDeviceSamplerYcbcrConversionFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceSamplerYcbcrConversionFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceSamplerYcbcrConversionFeatures, samplerYcbcrConversion, samplerYcbcrConversion);
+    STRUCT(structs, vk::SamplerYcbcrConversionImageFormatProperties, VkSamplerYcbcrConversionImageFormatProperties);
+    FIELD(VkSamplerYcbcrConversionImageFormatProperties, sType, sType);
+    FIELD(VkSamplerYcbcrConversionImageFormatProperties, pNext, pNext);
+    FIELD(VkSamplerYcbcrConversionImageFormatProperties, combinedImageSamplerDescriptorCount, combinedImageSamplerDescriptorCount);
+    STRUCT(structs, vk::DescriptorUpdateTemplateEntry, VkDescriptorUpdateTemplateEntry);
+    FIELD(VkDescriptorUpdateTemplateEntry, dstBinding, dstBinding);
+    FIELD(VkDescriptorUpdateTemplateEntry, dstArrayElement, dstArrayElement);
+    FIELD(VkDescriptorUpdateTemplateEntry, descriptorCount, descriptorCount);
+    FIELD(VkDescriptorUpdateTemplateEntry, descriptorType, descriptorType);
+    FIELD(VkDescriptorUpdateTemplateEntry, offset, offset);
+    FIELD(VkDescriptorUpdateTemplateEntry, stride, stride);
+    STRUCT(structs, vk::DescriptorUpdateTemplateCreateInfo, VkDescriptorUpdateTemplateCreateInfo);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, sType, sType);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, pNext, pNext);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, flags, flags);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, descriptorUpdateEntryCount, descriptorUpdateEntryCount);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, pDescriptorUpdateEntries, pDescriptorUpdateEntries);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, templateType, templateType);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, descriptorSetLayout, descriptorSetLayout);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, pipelineBindPoint, pipelineBindPoint);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, pipelineLayout, pipelineLayout);
+    FIELD(VkDescriptorUpdateTemplateCreateInfo, set, set);
+    STRUCT(structs, vk::ExternalMemoryProperties, VkExternalMemoryProperties);
+    FIELD(VkExternalMemoryProperties, externalMemoryFeatures, externalMemoryFeatures);
+    FIELD(VkExternalMemoryProperties, exportFromImportedHandleTypes, exportFromImportedHandleTypes);
+    FIELD(VkExternalMemoryProperties, compatibleHandleTypes, compatibleHandleTypes);
+    STRUCT(structs, vk::PhysicalDeviceExternalImageFormatInfo, VkPhysicalDeviceExternalImageFormatInfo);
+    FIELD(VkPhysicalDeviceExternalImageFormatInfo, sType, sType);
+    FIELD(VkPhysicalDeviceExternalImageFormatInfo, pNext, pNext);
+    FIELD(VkPhysicalDeviceExternalImageFormatInfo, handleType, handleType);
+    STRUCT(structs, vk::ExternalImageFormatProperties, VkExternalImageFormatProperties);
+    FIELD(VkExternalImageFormatProperties, sType, sType);
+    FIELD(VkExternalImageFormatProperties, pNext, pNext);
+    FIELD(VkExternalImageFormatProperties, externalMemoryProperties, externalMemoryProperties);
+    STRUCT(structs, vk::PhysicalDeviceExternalBufferInfo, VkPhysicalDeviceExternalBufferInfo);
+    FIELD(VkPhysicalDeviceExternalBufferInfo, sType, sType);
+    FIELD(VkPhysicalDeviceExternalBufferInfo, pNext, pNext);
+    FIELD(VkPhysicalDeviceExternalBufferInfo, flags, flags);
+    FIELD(VkPhysicalDeviceExternalBufferInfo, usage, usage);
+    FIELD(VkPhysicalDeviceExternalBufferInfo, handleType, handleType);
+    STRUCT(structs, vk::ExternalBufferProperties, VkExternalBufferProperties);
+    FIELD(VkExternalBufferProperties, sType, sType);
+    FIELD(VkExternalBufferProperties, pNext, pNext);
+    FIELD(VkExternalBufferProperties, externalMemoryProperties, externalMemoryProperties);
+    STRUCT(structs, vk::PhysicalDeviceIDProperties, VkPhysicalDeviceIDProperties);
+    FIELD(VkPhysicalDeviceIDProperties, sType, sType);
+    FIELD(VkPhysicalDeviceIDProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceIDProperties, deviceUUID, deviceUUID);
+    FIELD(VkPhysicalDeviceIDProperties, driverUUID, driverUUID);
+    FIELD(VkPhysicalDeviceIDProperties, deviceLUID, deviceLUID);
+    FIELD(VkPhysicalDeviceIDProperties, deviceNodeMask, deviceNodeMask);
+    FIELD(VkPhysicalDeviceIDProperties, deviceLUIDValid, deviceLUIDValid);
+    STRUCT(structs, vk::ExternalMemoryImageCreateInfo, VkExternalMemoryImageCreateInfo);
+    FIELD(VkExternalMemoryImageCreateInfo, sType, sType);
+    FIELD(VkExternalMemoryImageCreateInfo, pNext, pNext);
+    FIELD(VkExternalMemoryImageCreateInfo, handleTypes, handleTypes);
+    STRUCT(structs, vk::ExternalMemoryBufferCreateInfo, VkExternalMemoryBufferCreateInfo);
+    FIELD(VkExternalMemoryBufferCreateInfo, sType, sType);
+    FIELD(VkExternalMemoryBufferCreateInfo, pNext, pNext);
+    FIELD(VkExternalMemoryBufferCreateInfo, handleTypes, handleTypes);
+    STRUCT(structs, vk::ExportMemoryAllocateInfo, VkExportMemoryAllocateInfo);
+    FIELD(VkExportMemoryAllocateInfo, sType, sType);
+    FIELD(VkExportMemoryAllocateInfo, pNext, pNext);
+    FIELD(VkExportMemoryAllocateInfo, handleTypes, handleTypes);
+    STRUCT(structs, vk::PhysicalDeviceExternalFenceInfo, VkPhysicalDeviceExternalFenceInfo);
+    FIELD(VkPhysicalDeviceExternalFenceInfo, sType, sType);
+    FIELD(VkPhysicalDeviceExternalFenceInfo, pNext, pNext);
+    FIELD(VkPhysicalDeviceExternalFenceInfo, handleType, handleType);
+    STRUCT(structs, vk::ExternalFenceProperties, VkExternalFenceProperties);
+    FIELD(VkExternalFenceProperties, sType, sType);
+    FIELD(VkExternalFenceProperties, pNext, pNext);
+    FIELD(VkExternalFenceProperties, exportFromImportedHandleTypes, exportFromImportedHandleTypes);
+    FIELD(VkExternalFenceProperties, compatibleHandleTypes, compatibleHandleTypes);
+    FIELD(VkExternalFenceProperties, externalFenceFeatures, externalFenceFeatures);
+    STRUCT(structs, vk::ExportFenceCreateInfo, VkExportFenceCreateInfo);
+    FIELD(VkExportFenceCreateInfo, sType, sType);
+    FIELD(VkExportFenceCreateInfo, pNext, pNext);
+    FIELD(VkExportFenceCreateInfo, handleTypes, handleTypes);
+    STRUCT(structs, vk::ExportSemaphoreCreateInfo, VkExportSemaphoreCreateInfo);
+    FIELD(VkExportSemaphoreCreateInfo, sType, sType);
+    FIELD(VkExportSemaphoreCreateInfo, pNext, pNext);
+    FIELD(VkExportSemaphoreCreateInfo, handleTypes, handleTypes);
+    STRUCT(structs, vk::PhysicalDeviceExternalSemaphoreInfo, VkPhysicalDeviceExternalSemaphoreInfo);
+    FIELD(VkPhysicalDeviceExternalSemaphoreInfo, sType, sType);
+    FIELD(VkPhysicalDeviceExternalSemaphoreInfo, pNext, pNext);
+    FIELD(VkPhysicalDeviceExternalSemaphoreInfo, handleType, handleType);
+    STRUCT(structs, vk::ExternalSemaphoreProperties, VkExternalSemaphoreProperties);
+    FIELD(VkExternalSemaphoreProperties, sType, sType);
+    FIELD(VkExternalSemaphoreProperties, pNext, pNext);
+    FIELD(VkExternalSemaphoreProperties, exportFromImportedHandleTypes, exportFromImportedHandleTypes);
+    FIELD(VkExternalSemaphoreProperties, compatibleHandleTypes, compatibleHandleTypes);
+    FIELD(VkExternalSemaphoreProperties, externalSemaphoreFeatures, externalSemaphoreFeatures);
+    STRUCT(structs, vk::PhysicalDeviceMaintenance3Properties, VkPhysicalDeviceMaintenance3Properties);
+    FIELD(VkPhysicalDeviceMaintenance3Properties, sType, sType);
+    FIELD(VkPhysicalDeviceMaintenance3Properties, pNext, pNext);
+    FIELD(VkPhysicalDeviceMaintenance3Properties, maxPerSetDescriptors, maxPerSetDescriptors);
+    FIELD(VkPhysicalDeviceMaintenance3Properties, maxMemoryAllocationSize, maxMemoryAllocationSize);
+    STRUCT(structs, vk::DescriptorSetLayoutSupport, VkDescriptorSetLayoutSupport);
+    FIELD(VkDescriptorSetLayoutSupport, sType, sType);
+    FIELD(VkDescriptorSetLayoutSupport, pNext, pNext);
+    FIELD(VkDescriptorSetLayoutSupport, supported, supported);
+    STRUCT(structs, vk::PhysicalDeviceShaderDrawParametersFeatures, VkPhysicalDeviceShaderDrawParametersFeatures);
+    FIELD(VkPhysicalDeviceShaderDrawParametersFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceShaderDrawParametersFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderDrawParametersFeatures, shaderDrawParameters, shaderDrawParameters);
+    STRUCT(structs, vk::ConformanceVersion, VkConformanceVersion);
+    FIELD(VkConformanceVersion, major, major);
+    FIELD(VkConformanceVersion, minor, minor);
+    FIELD(VkConformanceVersion, subminor, subminor);
+    FIELD(VkConformanceVersion, patch, patch);
+    STRUCT(structs, vk::PhysicalDeviceVulkan11Features, VkPhysicalDeviceVulkan11Features);
+    FIELD(VkPhysicalDeviceVulkan11Features, sType, sType);
+    FIELD(VkPhysicalDeviceVulkan11Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkan11Features, storageBuffer16BitAccess, storageBuffer16BitAccess);
+    FIELD(VkPhysicalDeviceVulkan11Features, uniformAndStorageBuffer16BitAccess, uniformAndStorageBuffer16BitAccess);
+    FIELD(VkPhysicalDeviceVulkan11Features, storagePushConstant16, storagePushConstant16);
+    FIELD(VkPhysicalDeviceVulkan11Features, storageInputOutput16, storageInputOutput16);
+    FIELD(VkPhysicalDeviceVulkan11Features, multiview, multiview);
+    FIELD(VkPhysicalDeviceVulkan11Features, multiviewGeometryShader, multiviewGeometryShader);
+    FIELD(VkPhysicalDeviceVulkan11Features, multiviewTessellationShader, multiviewTessellationShader);
+    FIELD(VkPhysicalDeviceVulkan11Features, variablePointersStorageBuffer, variablePointersStorageBuffer);
+    FIELD(VkPhysicalDeviceVulkan11Features, variablePointers, variablePointers);
+    FIELD(VkPhysicalDeviceVulkan11Features, protectedMemory, protectedMemory);
+    FIELD(VkPhysicalDeviceVulkan11Features, samplerYcbcrConversion, samplerYcbcrConversion);
+    FIELD(VkPhysicalDeviceVulkan11Features, shaderDrawParameters, shaderDrawParameters);
+    STRUCT(structs, vk::PhysicalDeviceVulkan11Properties, VkPhysicalDeviceVulkan11Properties);
+    FIELD(VkPhysicalDeviceVulkan11Properties, sType, sType);
+    FIELD(VkPhysicalDeviceVulkan11Properties, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkan11Properties, deviceUUID, deviceUUID);
+    FIELD(VkPhysicalDeviceVulkan11Properties, driverUUID, driverUUID);
+    FIELD(VkPhysicalDeviceVulkan11Properties, deviceLUID, deviceLUID);
+    FIELD(VkPhysicalDeviceVulkan11Properties, deviceNodeMask, deviceNodeMask);
+    FIELD(VkPhysicalDeviceVulkan11Properties, deviceLUIDValid, deviceLUIDValid);
+    FIELD(VkPhysicalDeviceVulkan11Properties, subgroupSize, subgroupSize);
+    FIELD(VkPhysicalDeviceVulkan11Properties, subgroupSupportedStages, subgroupSupportedStages);
+    FIELD(VkPhysicalDeviceVulkan11Properties, subgroupSupportedOperations, subgroupSupportedOperations);
+    FIELD(VkPhysicalDeviceVulkan11Properties, subgroupQuadOperationsInAllStages, subgroupQuadOperationsInAllStages);
+    FIELD(VkPhysicalDeviceVulkan11Properties, pointClippingBehavior, pointClippingBehavior);
+    FIELD(VkPhysicalDeviceVulkan11Properties, maxMultiviewViewCount, maxMultiviewViewCount);
+    FIELD(VkPhysicalDeviceVulkan11Properties, maxMultiviewInstanceIndex, maxMultiviewInstanceIndex);
+    FIELD(VkPhysicalDeviceVulkan11Properties, protectedNoFault, protectedNoFault);
+    FIELD(VkPhysicalDeviceVulkan11Properties, maxPerSetDescriptors, maxPerSetDescriptors);
+    FIELD(VkPhysicalDeviceVulkan11Properties, maxMemoryAllocationSize, maxMemoryAllocationSize);
+    STRUCT(structs, vk::PhysicalDeviceVulkan12Features, VkPhysicalDeviceVulkan12Features);
+    FIELD(VkPhysicalDeviceVulkan12Features, sType, sType);
+    FIELD(VkPhysicalDeviceVulkan12Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkan12Features, samplerMirrorClampToEdge, samplerMirrorClampToEdge);
+    FIELD(VkPhysicalDeviceVulkan12Features, drawIndirectCount, drawIndirectCount);
+    FIELD(VkPhysicalDeviceVulkan12Features, storageBuffer8BitAccess, storageBuffer8BitAccess);
+    FIELD(VkPhysicalDeviceVulkan12Features, uniformAndStorageBuffer8BitAccess, uniformAndStorageBuffer8BitAccess);
+    FIELD(VkPhysicalDeviceVulkan12Features, storagePushConstant8, storagePushConstant8);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderBufferInt64Atomics, shaderBufferInt64Atomics);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderSharedInt64Atomics, shaderSharedInt64Atomics);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderFloat16, shaderFloat16);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderInt8, shaderInt8);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorIndexing, descriptorIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderInputAttachmentArrayDynamicIndexing, shaderInputAttachmentArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderUniformTexelBufferArrayDynamicIndexing, shaderUniformTexelBufferArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderStorageTexelBufferArrayDynamicIndexing, shaderStorageTexelBufferArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderUniformBufferArrayNonUniformIndexing, shaderUniformBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderSampledImageArrayNonUniformIndexing, shaderSampledImageArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderStorageBufferArrayNonUniformIndexing, shaderStorageBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderStorageImageArrayNonUniformIndexing, shaderStorageImageArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderInputAttachmentArrayNonUniformIndexing, shaderInputAttachmentArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderUniformTexelBufferArrayNonUniformIndexing, shaderUniformTexelBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderStorageTexelBufferArrayNonUniformIndexing, shaderStorageTexelBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingUniformBufferUpdateAfterBind, descriptorBindingUniformBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingSampledImageUpdateAfterBind, descriptorBindingSampledImageUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingStorageImageUpdateAfterBind, descriptorBindingStorageImageUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingStorageBufferUpdateAfterBind, descriptorBindingStorageBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingUniformTexelBufferUpdateAfterBind, descriptorBindingUniformTexelBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingStorageTexelBufferUpdateAfterBind, descriptorBindingStorageTexelBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingUpdateUnusedWhilePending, descriptorBindingUpdateUnusedWhilePending);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingPartiallyBound, descriptorBindingPartiallyBound);
+    FIELD(VkPhysicalDeviceVulkan12Features, descriptorBindingVariableDescriptorCount, descriptorBindingVariableDescriptorCount);
+    FIELD(VkPhysicalDeviceVulkan12Features, runtimeDescriptorArray, runtimeDescriptorArray);
+    FIELD(VkPhysicalDeviceVulkan12Features, samplerFilterMinmax, samplerFilterMinmax);
+    FIELD(VkPhysicalDeviceVulkan12Features, scalarBlockLayout, scalarBlockLayout);
+    FIELD(VkPhysicalDeviceVulkan12Features, imagelessFramebuffer, imagelessFramebuffer);
+    FIELD(VkPhysicalDeviceVulkan12Features, uniformBufferStandardLayout, uniformBufferStandardLayout);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderSubgroupExtendedTypes, shaderSubgroupExtendedTypes);
+    FIELD(VkPhysicalDeviceVulkan12Features, separateDepthStencilLayouts, separateDepthStencilLayouts);
+    FIELD(VkPhysicalDeviceVulkan12Features, hostQueryReset, hostQueryReset);
+    FIELD(VkPhysicalDeviceVulkan12Features, timelineSemaphore, timelineSemaphore);
+    FIELD(VkPhysicalDeviceVulkan12Features, bufferDeviceAddress, bufferDeviceAddress);
+    FIELD(VkPhysicalDeviceVulkan12Features, bufferDeviceAddressCaptureReplay, bufferDeviceAddressCaptureReplay);
+    FIELD(VkPhysicalDeviceVulkan12Features, bufferDeviceAddressMultiDevice, bufferDeviceAddressMultiDevice);
+    FIELD(VkPhysicalDeviceVulkan12Features, vulkanMemoryModel, vulkanMemoryModel);
+    FIELD(VkPhysicalDeviceVulkan12Features, vulkanMemoryModelDeviceScope, vulkanMemoryModelDeviceScope);
+    FIELD(VkPhysicalDeviceVulkan12Features, vulkanMemoryModelAvailabilityVisibilityChains, vulkanMemoryModelAvailabilityVisibilityChains);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderOutputViewportIndex, shaderOutputViewportIndex);
+    FIELD(VkPhysicalDeviceVulkan12Features, shaderOutputLayer, shaderOutputLayer);
+    FIELD(VkPhysicalDeviceVulkan12Features, subgroupBroadcastDynamicId, subgroupBroadcastDynamicId);
+    STRUCT(structs, vk::PhysicalDeviceVulkan12Properties, VkPhysicalDeviceVulkan12Properties);
+    FIELD(VkPhysicalDeviceVulkan12Properties, sType, sType);
+    FIELD(VkPhysicalDeviceVulkan12Properties, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkan12Properties, driverID, driverID);
+    FIELD(VkPhysicalDeviceVulkan12Properties, driverName, driverName);
+    FIELD(VkPhysicalDeviceVulkan12Properties, driverInfo, driverInfo);
+    FIELD(VkPhysicalDeviceVulkan12Properties, conformanceVersion, conformanceVersion);
+    FIELD(VkPhysicalDeviceVulkan12Properties, denormBehaviorIndependence, denormBehaviorIndependence);
+    FIELD(VkPhysicalDeviceVulkan12Properties, roundingModeIndependence, roundingModeIndependence);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderSignedZeroInfNanPreserveFloat16, shaderSignedZeroInfNanPreserveFloat16);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderSignedZeroInfNanPreserveFloat32, shaderSignedZeroInfNanPreserveFloat32);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderSignedZeroInfNanPreserveFloat64, shaderSignedZeroInfNanPreserveFloat64);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderDenormPreserveFloat16, shaderDenormPreserveFloat16);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderDenormPreserveFloat32, shaderDenormPreserveFloat32);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderDenormPreserveFloat64, shaderDenormPreserveFloat64);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderDenormFlushToZeroFloat16, shaderDenormFlushToZeroFloat16);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderDenormFlushToZeroFloat32, shaderDenormFlushToZeroFloat32);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderDenormFlushToZeroFloat64, shaderDenormFlushToZeroFloat64);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderRoundingModeRTEFloat16, shaderRoundingModeRTEFloat16);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderRoundingModeRTEFloat32, shaderRoundingModeRTEFloat32);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderRoundingModeRTEFloat64, shaderRoundingModeRTEFloat64);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderRoundingModeRTZFloat16, shaderRoundingModeRTZFloat16);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderRoundingModeRTZFloat32, shaderRoundingModeRTZFloat32);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderRoundingModeRTZFloat64, shaderRoundingModeRTZFloat64);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxUpdateAfterBindDescriptorsInAllPools, maxUpdateAfterBindDescriptorsInAllPools);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderUniformBufferArrayNonUniformIndexingNative, shaderUniformBufferArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderSampledImageArrayNonUniformIndexingNative, shaderSampledImageArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderStorageBufferArrayNonUniformIndexingNative, shaderStorageBufferArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderStorageImageArrayNonUniformIndexingNative, shaderStorageImageArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceVulkan12Properties, shaderInputAttachmentArrayNonUniformIndexingNative, shaderInputAttachmentArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceVulkan12Properties, robustBufferAccessUpdateAfterBind, robustBufferAccessUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan12Properties, quadDivergentImplicitLod, quadDivergentImplicitLod);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageDescriptorUpdateAfterBindSamplers, maxPerStageDescriptorUpdateAfterBindSamplers);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageDescriptorUpdateAfterBindUniformBuffers, maxPerStageDescriptorUpdateAfterBindUniformBuffers);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageDescriptorUpdateAfterBindStorageBuffers, maxPerStageDescriptorUpdateAfterBindStorageBuffers);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageDescriptorUpdateAfterBindSampledImages, maxPerStageDescriptorUpdateAfterBindSampledImages);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageDescriptorUpdateAfterBindStorageImages, maxPerStageDescriptorUpdateAfterBindStorageImages);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageDescriptorUpdateAfterBindInputAttachments, maxPerStageDescriptorUpdateAfterBindInputAttachments);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxPerStageUpdateAfterBindResources, maxPerStageUpdateAfterBindResources);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindSamplers, maxDescriptorSetUpdateAfterBindSamplers);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindUniformBuffers, maxDescriptorSetUpdateAfterBindUniformBuffers);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindUniformBuffersDynamic, maxDescriptorSetUpdateAfterBindUniformBuffersDynamic);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindStorageBuffers, maxDescriptorSetUpdateAfterBindStorageBuffers);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindStorageBuffersDynamic, maxDescriptorSetUpdateAfterBindStorageBuffersDynamic);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindSampledImages, maxDescriptorSetUpdateAfterBindSampledImages);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindStorageImages, maxDescriptorSetUpdateAfterBindStorageImages);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxDescriptorSetUpdateAfterBindInputAttachments, maxDescriptorSetUpdateAfterBindInputAttachments);
+    FIELD(VkPhysicalDeviceVulkan12Properties, supportedDepthResolveModes, supportedDepthResolveModes);
+    FIELD(VkPhysicalDeviceVulkan12Properties, supportedStencilResolveModes, supportedStencilResolveModes);
+    FIELD(VkPhysicalDeviceVulkan12Properties, independentResolveNone, independentResolveNone);
+    FIELD(VkPhysicalDeviceVulkan12Properties, independentResolve, independentResolve);
+    FIELD(VkPhysicalDeviceVulkan12Properties, filterMinmaxSingleComponentFormats, filterMinmaxSingleComponentFormats);
+    FIELD(VkPhysicalDeviceVulkan12Properties, filterMinmaxImageComponentMapping, filterMinmaxImageComponentMapping);
+    FIELD(VkPhysicalDeviceVulkan12Properties, maxTimelineSemaphoreValueDifference, maxTimelineSemaphoreValueDifference);
+    FIELD(VkPhysicalDeviceVulkan12Properties, framebufferIntegerColorSampleCounts, framebufferIntegerColorSampleCounts);
+    STRUCT(structs, vk::ImageFormatListCreateInfo, VkImageFormatListCreateInfo);
+    FIELD(VkImageFormatListCreateInfo, sType, sType);
+    FIELD(VkImageFormatListCreateInfo, pNext, pNext);
+    FIELD(VkImageFormatListCreateInfo, viewFormatCount, viewFormatCount);
+    FIELD(VkImageFormatListCreateInfo, pViewFormats, pViewFormats);
+    STRUCT(structs, vk::AttachmentDescription2, VkAttachmentDescription2);
+    FIELD(VkAttachmentDescription2, sType, sType);
+    FIELD(VkAttachmentDescription2, pNext, pNext);
+    FIELD(VkAttachmentDescription2, flags, flags);
+    FIELD(VkAttachmentDescription2, format, format);
+    FIELD(VkAttachmentDescription2, samples, samples);
+    FIELD(VkAttachmentDescription2, loadOp, loadOp);
+    FIELD(VkAttachmentDescription2, storeOp, storeOp);
+    FIELD(VkAttachmentDescription2, stencilLoadOp, stencilLoadOp);
+    FIELD(VkAttachmentDescription2, stencilStoreOp, stencilStoreOp);
+    FIELD(VkAttachmentDescription2, initialLayout, initialLayout);
+    FIELD(VkAttachmentDescription2, finalLayout, finalLayout);
+    STRUCT(structs, vk::AttachmentReference2, VkAttachmentReference2);
+    FIELD(VkAttachmentReference2, sType, sType);
+    FIELD(VkAttachmentReference2, pNext, pNext);
+    FIELD(VkAttachmentReference2, attachment, attachment);
+    FIELD(VkAttachmentReference2, layout, layout);
+    FIELD(VkAttachmentReference2, aspectMask, aspectMask);
+    STRUCT(structs, vk::SubpassDescription2, VkSubpassDescription2);
+    FIELD(VkSubpassDescription2, sType, sType);
+    FIELD(VkSubpassDescription2, pNext, pNext);
+    FIELD(VkSubpassDescription2, flags, flags);
+    FIELD(VkSubpassDescription2, pipelineBindPoint, pipelineBindPoint);
+    FIELD(VkSubpassDescription2, viewMask, viewMask);
+    FIELD(VkSubpassDescription2, inputAttachmentCount, inputAttachmentCount);
+    FIELD(VkSubpassDescription2, pInputAttachments, pInputAttachments);
+    FIELD(VkSubpassDescription2, colorAttachmentCount, colorAttachmentCount);
+    FIELD(VkSubpassDescription2, pColorAttachments, pColorAttachments);
+    FIELD(VkSubpassDescription2, pResolveAttachments, pResolveAttachments);
+    FIELD(VkSubpassDescription2, pDepthStencilAttachment, pDepthStencilAttachment);
+    FIELD(VkSubpassDescription2, preserveAttachmentCount, preserveAttachmentCount);
+    FIELD(VkSubpassDescription2, pPreserveAttachments, pPreserveAttachments);
+    STRUCT(structs, vk::SubpassDependency2, VkSubpassDependency2);
+    FIELD(VkSubpassDependency2, sType, sType);
+    FIELD(VkSubpassDependency2, pNext, pNext);
+    FIELD(VkSubpassDependency2, srcSubpass, srcSubpass);
+    FIELD(VkSubpassDependency2, dstSubpass, dstSubpass);
+    FIELD(VkSubpassDependency2, srcStageMask, srcStageMask);
+    FIELD(VkSubpassDependency2, dstStageMask, dstStageMask);
+    FIELD(VkSubpassDependency2, srcAccessMask, srcAccessMask);
+    FIELD(VkSubpassDependency2, dstAccessMask, dstAccessMask);
+    FIELD(VkSubpassDependency2, dependencyFlags, dependencyFlags);
+    FIELD(VkSubpassDependency2, viewOffset, viewOffset);
+    STRUCT(structs, vk::RenderPassCreateInfo2, VkRenderPassCreateInfo2);
+    FIELD(VkRenderPassCreateInfo2, sType, sType);
+    FIELD(VkRenderPassCreateInfo2, pNext, pNext);
+    FIELD(VkRenderPassCreateInfo2, flags, flags);
+    FIELD(VkRenderPassCreateInfo2, attachmentCount, attachmentCount);
+    FIELD(VkRenderPassCreateInfo2, pAttachments, pAttachments);
+    FIELD(VkRenderPassCreateInfo2, subpassCount, subpassCount);
+    FIELD(VkRenderPassCreateInfo2, pSubpasses, pSubpasses);
+    FIELD(VkRenderPassCreateInfo2, dependencyCount, dependencyCount);
+    FIELD(VkRenderPassCreateInfo2, pDependencies, pDependencies);
+    FIELD(VkRenderPassCreateInfo2, correlatedViewMaskCount, correlatedViewMaskCount);
+    FIELD(VkRenderPassCreateInfo2, pCorrelatedViewMasks, pCorrelatedViewMasks);
+    STRUCT(structs, vk::SubpassBeginInfo, VkSubpassBeginInfo);
+    FIELD(VkSubpassBeginInfo, sType, sType);
+    FIELD(VkSubpassBeginInfo, pNext, pNext);
+    FIELD(VkSubpassBeginInfo, contents, contents);
+    STRUCT(structs, vk::SubpassEndInfo, VkSubpassEndInfo);
+    FIELD(VkSubpassEndInfo, sType, sType);
+    FIELD(VkSubpassEndInfo, pNext, pNext);
+    STRUCT(structs, vk::PhysicalDevice8BitStorageFeatures, VkPhysicalDevice8BitStorageFeatures);
+    FIELD(VkPhysicalDevice8BitStorageFeatures, sType, sType);
+    FIELD(VkPhysicalDevice8BitStorageFeatures, pNext, pNext);
+    FIELD(VkPhysicalDevice8BitStorageFeatures, storageBuffer8BitAccess, storageBuffer8BitAccess);
+    FIELD(VkPhysicalDevice8BitStorageFeatures, uniformAndStorageBuffer8BitAccess, uniformAndStorageBuffer8BitAccess);
+    FIELD(VkPhysicalDevice8BitStorageFeatures, storagePushConstant8, storagePushConstant8);
+    STRUCT(structs, vk::PhysicalDeviceDriverProperties, VkPhysicalDeviceDriverProperties);
+    FIELD(VkPhysicalDeviceDriverProperties, sType, sType);
+    FIELD(VkPhysicalDeviceDriverProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceDriverProperties, driverID, driverID);
+    FIELD(VkPhysicalDeviceDriverProperties, driverName, driverName);
+    FIELD(VkPhysicalDeviceDriverProperties, driverInfo, driverInfo);
+    FIELD(VkPhysicalDeviceDriverProperties, conformanceVersion, conformanceVersion);
+    STRUCT(structs, vk::PhysicalDeviceShaderAtomicInt64Features, VkPhysicalDeviceShaderAtomicInt64Features);
+    FIELD(VkPhysicalDeviceShaderAtomicInt64Features, sType, sType);
+    FIELD(VkPhysicalDeviceShaderAtomicInt64Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderAtomicInt64Features, shaderBufferInt64Atomics, shaderBufferInt64Atomics);
+    FIELD(VkPhysicalDeviceShaderAtomicInt64Features, shaderSharedInt64Atomics, shaderSharedInt64Atomics);
+    STRUCT(structs, vk::PhysicalDeviceShaderFloat16Int8Features, VkPhysicalDeviceShaderFloat16Int8Features);
+    FIELD(VkPhysicalDeviceShaderFloat16Int8Features, sType, sType);
+    FIELD(VkPhysicalDeviceShaderFloat16Int8Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderFloat16Int8Features, shaderFloat16, shaderFloat16);
+    FIELD(VkPhysicalDeviceShaderFloat16Int8Features, shaderInt8, shaderInt8);
+    STRUCT(structs, vk::PhysicalDeviceFloatControlsProperties, VkPhysicalDeviceFloatControlsProperties);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, sType, sType);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, denormBehaviorIndependence, denormBehaviorIndependence);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, roundingModeIndependence, roundingModeIndependence);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderSignedZeroInfNanPreserveFloat16, shaderSignedZeroInfNanPreserveFloat16);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderSignedZeroInfNanPreserveFloat32, shaderSignedZeroInfNanPreserveFloat32);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderSignedZeroInfNanPreserveFloat64, shaderSignedZeroInfNanPreserveFloat64);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderDenormPreserveFloat16, shaderDenormPreserveFloat16);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderDenormPreserveFloat32, shaderDenormPreserveFloat32);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderDenormPreserveFloat64, shaderDenormPreserveFloat64);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderDenormFlushToZeroFloat16, shaderDenormFlushToZeroFloat16);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderDenormFlushToZeroFloat32, shaderDenormFlushToZeroFloat32);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderDenormFlushToZeroFloat64, shaderDenormFlushToZeroFloat64);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderRoundingModeRTEFloat16, shaderRoundingModeRTEFloat16);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderRoundingModeRTEFloat32, shaderRoundingModeRTEFloat32);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderRoundingModeRTEFloat64, shaderRoundingModeRTEFloat64);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderRoundingModeRTZFloat16, shaderRoundingModeRTZFloat16);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderRoundingModeRTZFloat32, shaderRoundingModeRTZFloat32);
+    FIELD(VkPhysicalDeviceFloatControlsProperties, shaderRoundingModeRTZFloat64, shaderRoundingModeRTZFloat64);
+    STRUCT(structs, vk::DescriptorSetLayoutBindingFlagsCreateInfo, VkDescriptorSetLayoutBindingFlagsCreateInfo);
+    FIELD(VkDescriptorSetLayoutBindingFlagsCreateInfo, sType, sType);
+    FIELD(VkDescriptorSetLayoutBindingFlagsCreateInfo, pNext, pNext);
+    FIELD(VkDescriptorSetLayoutBindingFlagsCreateInfo, bindingCount, bindingCount);
+    FIELD(VkDescriptorSetLayoutBindingFlagsCreateInfo, pBindingFlags, pBindingFlags);
+    STRUCT(structs, vk::PhysicalDeviceDescriptorIndexingFeatures, VkPhysicalDeviceDescriptorIndexingFeatures);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderInputAttachmentArrayDynamicIndexing, shaderInputAttachmentArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderUniformTexelBufferArrayDynamicIndexing, shaderUniformTexelBufferArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderStorageTexelBufferArrayDynamicIndexing, shaderStorageTexelBufferArrayDynamicIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderUniformBufferArrayNonUniformIndexing, shaderUniformBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderSampledImageArrayNonUniformIndexing, shaderSampledImageArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderStorageBufferArrayNonUniformIndexing, shaderStorageBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderStorageImageArrayNonUniformIndexing, shaderStorageImageArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderInputAttachmentArrayNonUniformIndexing, shaderInputAttachmentArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderUniformTexelBufferArrayNonUniformIndexing, shaderUniformTexelBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, shaderStorageTexelBufferArrayNonUniformIndexing, shaderStorageTexelBufferArrayNonUniformIndexing);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingUniformBufferUpdateAfterBind, descriptorBindingUniformBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingSampledImageUpdateAfterBind, descriptorBindingSampledImageUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingStorageImageUpdateAfterBind, descriptorBindingStorageImageUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingStorageBufferUpdateAfterBind, descriptorBindingStorageBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingUniformTexelBufferUpdateAfterBind, descriptorBindingUniformTexelBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingStorageTexelBufferUpdateAfterBind, descriptorBindingStorageTexelBufferUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingUpdateUnusedWhilePending, descriptorBindingUpdateUnusedWhilePending);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingPartiallyBound, descriptorBindingPartiallyBound);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, descriptorBindingVariableDescriptorCount, descriptorBindingVariableDescriptorCount);
+    FIELD(VkPhysicalDeviceDescriptorIndexingFeatures, runtimeDescriptorArray, runtimeDescriptorArray);
+    STRUCT(structs, vk::PhysicalDeviceDescriptorIndexingProperties, VkPhysicalDeviceDescriptorIndexingProperties);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, sType, sType);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxUpdateAfterBindDescriptorsInAllPools, maxUpdateAfterBindDescriptorsInAllPools);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, shaderUniformBufferArrayNonUniformIndexingNative, shaderUniformBufferArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, shaderSampledImageArrayNonUniformIndexingNative, shaderSampledImageArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, shaderStorageBufferArrayNonUniformIndexingNative, shaderStorageBufferArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, shaderStorageImageArrayNonUniformIndexingNative, shaderStorageImageArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, shaderInputAttachmentArrayNonUniformIndexingNative, shaderInputAttachmentArrayNonUniformIndexingNative);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, robustBufferAccessUpdateAfterBind, robustBufferAccessUpdateAfterBind);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, quadDivergentImplicitLod, quadDivergentImplicitLod);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageDescriptorUpdateAfterBindSamplers, maxPerStageDescriptorUpdateAfterBindSamplers);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageDescriptorUpdateAfterBindUniformBuffers, maxPerStageDescriptorUpdateAfterBindUniformBuffers);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageDescriptorUpdateAfterBindStorageBuffers, maxPerStageDescriptorUpdateAfterBindStorageBuffers);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageDescriptorUpdateAfterBindSampledImages, maxPerStageDescriptorUpdateAfterBindSampledImages);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageDescriptorUpdateAfterBindStorageImages, maxPerStageDescriptorUpdateAfterBindStorageImages);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageDescriptorUpdateAfterBindInputAttachments, maxPerStageDescriptorUpdateAfterBindInputAttachments);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxPerStageUpdateAfterBindResources, maxPerStageUpdateAfterBindResources);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindSamplers, maxDescriptorSetUpdateAfterBindSamplers);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindUniformBuffers, maxDescriptorSetUpdateAfterBindUniformBuffers);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindUniformBuffersDynamic, maxDescriptorSetUpdateAfterBindUniformBuffersDynamic);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindStorageBuffers, maxDescriptorSetUpdateAfterBindStorageBuffers);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindStorageBuffersDynamic, maxDescriptorSetUpdateAfterBindStorageBuffersDynamic);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindSampledImages, maxDescriptorSetUpdateAfterBindSampledImages);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindStorageImages, maxDescriptorSetUpdateAfterBindStorageImages);
+    FIELD(VkPhysicalDeviceDescriptorIndexingProperties, maxDescriptorSetUpdateAfterBindInputAttachments, maxDescriptorSetUpdateAfterBindInputAttachments);
+    STRUCT(structs, vk::DescriptorSetVariableDescriptorCountAllocateInfo, VkDescriptorSetVariableDescriptorCountAllocateInfo);
+    FIELD(VkDescriptorSetVariableDescriptorCountAllocateInfo, sType, sType);
+    FIELD(VkDescriptorSetVariableDescriptorCountAllocateInfo, pNext, pNext);
+    FIELD(VkDescriptorSetVariableDescriptorCountAllocateInfo, descriptorSetCount, descriptorSetCount);
+    FIELD(VkDescriptorSetVariableDescriptorCountAllocateInfo, pDescriptorCounts, pDescriptorCounts);
+    STRUCT(structs, vk::DescriptorSetVariableDescriptorCountLayoutSupport, VkDescriptorSetVariableDescriptorCountLayoutSupport);
+    FIELD(VkDescriptorSetVariableDescriptorCountLayoutSupport, sType, sType);
+    FIELD(VkDescriptorSetVariableDescriptorCountLayoutSupport, pNext, pNext);
+    FIELD(VkDescriptorSetVariableDescriptorCountLayoutSupport, maxVariableDescriptorCount, maxVariableDescriptorCount);
+    STRUCT(structs, vk::SubpassDescriptionDepthStencilResolve, VkSubpassDescriptionDepthStencilResolve);
+    FIELD(VkSubpassDescriptionDepthStencilResolve, sType, sType);
+    FIELD(VkSubpassDescriptionDepthStencilResolve, pNext, pNext);
+    FIELD(VkSubpassDescriptionDepthStencilResolve, depthResolveMode, depthResolveMode);
+    FIELD(VkSubpassDescriptionDepthStencilResolve, stencilResolveMode, stencilResolveMode);
+    FIELD(VkSubpassDescriptionDepthStencilResolve, pDepthStencilResolveAttachment, pDepthStencilResolveAttachment);
+    STRUCT(structs, vk::PhysicalDeviceDepthStencilResolveProperties, VkPhysicalDeviceDepthStencilResolveProperties);
+    FIELD(VkPhysicalDeviceDepthStencilResolveProperties, sType, sType);
+    FIELD(VkPhysicalDeviceDepthStencilResolveProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceDepthStencilResolveProperties, supportedDepthResolveModes, supportedDepthResolveModes);
+    FIELD(VkPhysicalDeviceDepthStencilResolveProperties, supportedStencilResolveModes, supportedStencilResolveModes);
+    FIELD(VkPhysicalDeviceDepthStencilResolveProperties, independentResolveNone, independentResolveNone);
+    FIELD(VkPhysicalDeviceDepthStencilResolveProperties, independentResolve, independentResolve);
+    STRUCT(structs, vk::PhysicalDeviceScalarBlockLayoutFeatures, VkPhysicalDeviceScalarBlockLayoutFeatures);
+    FIELD(VkPhysicalDeviceScalarBlockLayoutFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceScalarBlockLayoutFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceScalarBlockLayoutFeatures, scalarBlockLayout, scalarBlockLayout);
+    STRUCT(structs, vk::ImageStencilUsageCreateInfo, VkImageStencilUsageCreateInfo);
+    FIELD(VkImageStencilUsageCreateInfo, sType, sType);
+    FIELD(VkImageStencilUsageCreateInfo, pNext, pNext);
+    FIELD(VkImageStencilUsageCreateInfo, stencilUsage, stencilUsage);
+    STRUCT(structs, vk::SamplerReductionModeCreateInfo, VkSamplerReductionModeCreateInfo);
+    FIELD(VkSamplerReductionModeCreateInfo, sType, sType);
+    FIELD(VkSamplerReductionModeCreateInfo, pNext, pNext);
+    FIELD(VkSamplerReductionModeCreateInfo, reductionMode, reductionMode);
+    STRUCT(structs, vk::PhysicalDeviceSamplerFilterMinmaxProperties, VkPhysicalDeviceSamplerFilterMinmaxProperties);
+    FIELD(VkPhysicalDeviceSamplerFilterMinmaxProperties, sType, sType);
+    FIELD(VkPhysicalDeviceSamplerFilterMinmaxProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceSamplerFilterMinmaxProperties, filterMinmaxSingleComponentFormats, filterMinmaxSingleComponentFormats);
+    FIELD(VkPhysicalDeviceSamplerFilterMinmaxProperties, filterMinmaxImageComponentMapping, filterMinmaxImageComponentMapping);
+    STRUCT(structs, vk::PhysicalDeviceVulkanMemoryModelFeatures, VkPhysicalDeviceVulkanMemoryModelFeatures);
+    FIELD(VkPhysicalDeviceVulkanMemoryModelFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceVulkanMemoryModelFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkanMemoryModelFeatures, vulkanMemoryModel, vulkanMemoryModel);
+    FIELD(VkPhysicalDeviceVulkanMemoryModelFeatures, vulkanMemoryModelDeviceScope, vulkanMemoryModelDeviceScope);
+    FIELD(VkPhysicalDeviceVulkanMemoryModelFeatures, vulkanMemoryModelAvailabilityVisibilityChains, vulkanMemoryModelAvailabilityVisibilityChains);
+    STRUCT(structs, vk::PhysicalDeviceImagelessFramebufferFeatures, VkPhysicalDeviceImagelessFramebufferFeatures);
+    FIELD(VkPhysicalDeviceImagelessFramebufferFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceImagelessFramebufferFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceImagelessFramebufferFeatures, imagelessFramebuffer, imagelessFramebuffer);
+    STRUCT(structs, vk::FramebufferAttachmentImageInfo, VkFramebufferAttachmentImageInfo);
+    FIELD(VkFramebufferAttachmentImageInfo, sType, sType);
+    FIELD(VkFramebufferAttachmentImageInfo, pNext, pNext);
+    FIELD(VkFramebufferAttachmentImageInfo, flags, flags);
+    FIELD(VkFramebufferAttachmentImageInfo, usage, usage);
+    FIELD(VkFramebufferAttachmentImageInfo, width, width);
+    FIELD(VkFramebufferAttachmentImageInfo, height, height);
+    FIELD(VkFramebufferAttachmentImageInfo, layerCount, layerCount);
+    FIELD(VkFramebufferAttachmentImageInfo, viewFormatCount, viewFormatCount);
+    FIELD(VkFramebufferAttachmentImageInfo, pViewFormats, pViewFormats);
+    STRUCT(structs, vk::FramebufferAttachmentsCreateInfo, VkFramebufferAttachmentsCreateInfo);
+    FIELD(VkFramebufferAttachmentsCreateInfo, sType, sType);
+    FIELD(VkFramebufferAttachmentsCreateInfo, pNext, pNext);
+    FIELD(VkFramebufferAttachmentsCreateInfo, attachmentImageInfoCount, attachmentImageInfoCount);
+    FIELD(VkFramebufferAttachmentsCreateInfo, pAttachmentImageInfos, pAttachmentImageInfos);
+    STRUCT(structs, vk::RenderPassAttachmentBeginInfo, VkRenderPassAttachmentBeginInfo);
+    FIELD(VkRenderPassAttachmentBeginInfo, sType, sType);
+    FIELD(VkRenderPassAttachmentBeginInfo, pNext, pNext);
+    FIELD(VkRenderPassAttachmentBeginInfo, attachmentCount, attachmentCount);
+    FIELD(VkRenderPassAttachmentBeginInfo, pAttachments, pAttachments);
+    STRUCT(structs, vk::PhysicalDeviceUniformBufferStandardLayoutFeatures, VkPhysicalDeviceUniformBufferStandardLayoutFeatures);
+    FIELD(VkPhysicalDeviceUniformBufferStandardLayoutFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceUniformBufferStandardLayoutFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceUniformBufferStandardLayoutFeatures, uniformBufferStandardLayout, uniformBufferStandardLayout);
+    STRUCT(structs, vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures, VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures);
+    FIELD(VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderSubgroupExtendedTypesFeatures, shaderSubgroupExtendedTypes, shaderSubgroupExtendedTypes);
+    STRUCT(structs, vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures, VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures);
+    FIELD(VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceSeparateDepthStencilLayoutsFeatures, separateDepthStencilLayouts, separateDepthStencilLayouts);
+    STRUCT(structs, vk::AttachmentReferenceStencilLayout, VkAttachmentReferenceStencilLayout);
+    FIELD(VkAttachmentReferenceStencilLayout, sType, sType);
+    FIELD(VkAttachmentReferenceStencilLayout, pNext, pNext);
+    FIELD(VkAttachmentReferenceStencilLayout, stencilLayout, stencilLayout);
+    STRUCT(structs, vk::AttachmentDescriptionStencilLayout, VkAttachmentDescriptionStencilLayout);
+    FIELD(VkAttachmentDescriptionStencilLayout, sType, sType);
+    FIELD(VkAttachmentDescriptionStencilLayout, pNext, pNext);
+    FIELD(VkAttachmentDescriptionStencilLayout, stencilInitialLayout, stencilInitialLayout);
+    FIELD(VkAttachmentDescriptionStencilLayout, stencilFinalLayout, stencilFinalLayout);
+    STRUCT(structs, vk::PhysicalDeviceHostQueryResetFeatures, VkPhysicalDeviceHostQueryResetFeatures);
+    FIELD(VkPhysicalDeviceHostQueryResetFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceHostQueryResetFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceHostQueryResetFeatures, hostQueryReset, hostQueryReset);
+    STRUCT(structs, vk::PhysicalDeviceTimelineSemaphoreFeatures, VkPhysicalDeviceTimelineSemaphoreFeatures);
+    FIELD(VkPhysicalDeviceTimelineSemaphoreFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceTimelineSemaphoreFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceTimelineSemaphoreFeatures, timelineSemaphore, timelineSemaphore);
+    STRUCT(structs, vk::PhysicalDeviceTimelineSemaphoreProperties, VkPhysicalDeviceTimelineSemaphoreProperties);
+    FIELD(VkPhysicalDeviceTimelineSemaphoreProperties, sType, sType);
+    FIELD(VkPhysicalDeviceTimelineSemaphoreProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceTimelineSemaphoreProperties, maxTimelineSemaphoreValueDifference, maxTimelineSemaphoreValueDifference);
+    STRUCT(structs, vk::SemaphoreTypeCreateInfo, VkSemaphoreTypeCreateInfo);
+    FIELD(VkSemaphoreTypeCreateInfo, sType, sType);
+    FIELD(VkSemaphoreTypeCreateInfo, pNext, pNext);
+    FIELD(VkSemaphoreTypeCreateInfo, semaphoreType, semaphoreType);
+    FIELD(VkSemaphoreTypeCreateInfo, initialValue, initialValue);
+    STRUCT(structs, vk::TimelineSemaphoreSubmitInfo, VkTimelineSemaphoreSubmitInfo);
+    FIELD(VkTimelineSemaphoreSubmitInfo, sType, sType);
+    FIELD(VkTimelineSemaphoreSubmitInfo, pNext, pNext);
+    FIELD(VkTimelineSemaphoreSubmitInfo, waitSemaphoreValueCount, waitSemaphoreValueCount);
+    FIELD(VkTimelineSemaphoreSubmitInfo, pWaitSemaphoreValues, pWaitSemaphoreValues);
+    FIELD(VkTimelineSemaphoreSubmitInfo, signalSemaphoreValueCount, signalSemaphoreValueCount);
+    FIELD(VkTimelineSemaphoreSubmitInfo, pSignalSemaphoreValues, pSignalSemaphoreValues);
+    STRUCT(structs, vk::SemaphoreWaitInfo, VkSemaphoreWaitInfo);
+    FIELD(VkSemaphoreWaitInfo, sType, sType);
+    FIELD(VkSemaphoreWaitInfo, pNext, pNext);
+    FIELD(VkSemaphoreWaitInfo, flags, flags);
+    FIELD(VkSemaphoreWaitInfo, semaphoreCount, semaphoreCount);
+    FIELD(VkSemaphoreWaitInfo, pSemaphores, pSemaphores);
+    FIELD(VkSemaphoreWaitInfo, pValues, pValues);
+    STRUCT(structs, vk::SemaphoreSignalInfo, VkSemaphoreSignalInfo);
+    FIELD(VkSemaphoreSignalInfo, sType, sType);
+    FIELD(VkSemaphoreSignalInfo, pNext, pNext);
+    FIELD(VkSemaphoreSignalInfo, semaphore, semaphore);
+    FIELD(VkSemaphoreSignalInfo, value, value);
+    STRUCT(structs, vk::PhysicalDeviceBufferDeviceAddressFeatures, VkPhysicalDeviceBufferDeviceAddressFeatures);
+    FIELD(VkPhysicalDeviceBufferDeviceAddressFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceBufferDeviceAddressFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceBufferDeviceAddressFeatures, bufferDeviceAddress, bufferDeviceAddress);
+    FIELD(VkPhysicalDeviceBufferDeviceAddressFeatures, bufferDeviceAddressCaptureReplay, bufferDeviceAddressCaptureReplay);
+    FIELD(VkPhysicalDeviceBufferDeviceAddressFeatures, bufferDeviceAddressMultiDevice, bufferDeviceAddressMultiDevice);
+    STRUCT(structs, vk::BufferDeviceAddressInfo, VkBufferDeviceAddressInfo);
+    FIELD(VkBufferDeviceAddressInfo, sType, sType);
+    FIELD(VkBufferDeviceAddressInfo, pNext, pNext);
+    FIELD(VkBufferDeviceAddressInfo, buffer, buffer);
+    STRUCT(structs, vk::BufferOpaqueCaptureAddressCreateInfo, VkBufferOpaqueCaptureAddressCreateInfo);
+    FIELD(VkBufferOpaqueCaptureAddressCreateInfo, sType, sType);
+    FIELD(VkBufferOpaqueCaptureAddressCreateInfo, pNext, pNext);
+    FIELD(VkBufferOpaqueCaptureAddressCreateInfo, opaqueCaptureAddress, opaqueCaptureAddress);
+    STRUCT(structs, vk::MemoryOpaqueCaptureAddressAllocateInfo, VkMemoryOpaqueCaptureAddressAllocateInfo);
+    FIELD(VkMemoryOpaqueCaptureAddressAllocateInfo, sType, sType);
+    FIELD(VkMemoryOpaqueCaptureAddressAllocateInfo, pNext, pNext);
+    FIELD(VkMemoryOpaqueCaptureAddressAllocateInfo, opaqueCaptureAddress, opaqueCaptureAddress);
+    STRUCT(structs, vk::DeviceMemoryOpaqueCaptureAddressInfo, VkDeviceMemoryOpaqueCaptureAddressInfo);
+    FIELD(VkDeviceMemoryOpaqueCaptureAddressInfo, sType, sType);
+    FIELD(VkDeviceMemoryOpaqueCaptureAddressInfo, pNext, pNext);
+    FIELD(VkDeviceMemoryOpaqueCaptureAddressInfo, memory, memory);
+    STRUCT(structs, vk::PhysicalDeviceVulkan13Features, VkPhysicalDeviceVulkan13Features);
+    FIELD(VkPhysicalDeviceVulkan13Features, sType, sType);
+    FIELD(VkPhysicalDeviceVulkan13Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkan13Features, robustImageAccess, robustImageAccess);
+    FIELD(VkPhysicalDeviceVulkan13Features, inlineUniformBlock, inlineUniformBlock);
+    FIELD(VkPhysicalDeviceVulkan13Features, descriptorBindingInlineUniformBlockUpdateAfterBind, descriptorBindingInlineUniformBlockUpdateAfterBind);
+    FIELD(VkPhysicalDeviceVulkan13Features, pipelineCreationCacheControl, pipelineCreationCacheControl);
+    FIELD(VkPhysicalDeviceVulkan13Features, privateData, privateData);
+    FIELD(VkPhysicalDeviceVulkan13Features, shaderDemoteToHelperInvocation, shaderDemoteToHelperInvocation);
+    FIELD(VkPhysicalDeviceVulkan13Features, shaderTerminateInvocation, shaderTerminateInvocation);
+    FIELD(VkPhysicalDeviceVulkan13Features, subgroupSizeControl, subgroupSizeControl);
+    FIELD(VkPhysicalDeviceVulkan13Features, computeFullSubgroups, computeFullSubgroups);
+    FIELD(VkPhysicalDeviceVulkan13Features, synchronization2, synchronization2);
+    FIELD(VkPhysicalDeviceVulkan13Features, textureCompressionASTC_HDR, textureCompressionASTC_HDR);
+    FIELD(VkPhysicalDeviceVulkan13Features, shaderZeroInitializeWorkgroupMemory, shaderZeroInitializeWorkgroupMemory);
+    FIELD(VkPhysicalDeviceVulkan13Features, dynamicRendering, dynamicRendering);
+    FIELD(VkPhysicalDeviceVulkan13Features, shaderIntegerDotProduct, shaderIntegerDotProduct);
+    FIELD(VkPhysicalDeviceVulkan13Features, maintenance4, maintenance4);
+    STRUCT(structs, vk::PhysicalDeviceVulkan13Properties, VkPhysicalDeviceVulkan13Properties);
+    FIELD(VkPhysicalDeviceVulkan13Properties, sType, sType);
+    FIELD(VkPhysicalDeviceVulkan13Properties, pNext, pNext);
+    FIELD(VkPhysicalDeviceVulkan13Properties, minSubgroupSize, minSubgroupSize);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxSubgroupSize, maxSubgroupSize);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxComputeWorkgroupSubgroups, maxComputeWorkgroupSubgroups);
+    FIELD(VkPhysicalDeviceVulkan13Properties, requiredSubgroupSizeStages, requiredSubgroupSizeStages);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxInlineUniformBlockSize, maxInlineUniformBlockSize);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxPerStageDescriptorInlineUniformBlocks, maxPerStageDescriptorInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks, maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxDescriptorSetInlineUniformBlocks, maxDescriptorSetInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxDescriptorSetUpdateAfterBindInlineUniformBlocks, maxDescriptorSetUpdateAfterBindInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxInlineUniformTotalSize, maxInlineUniformTotalSize);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct8BitUnsignedAccelerated, integerDotProduct8BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct8BitSignedAccelerated, integerDotProduct8BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct8BitMixedSignednessAccelerated, integerDotProduct8BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct4x8BitPackedUnsignedAccelerated, integerDotProduct4x8BitPackedUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct4x8BitPackedSignedAccelerated, integerDotProduct4x8BitPackedSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct4x8BitPackedMixedSignednessAccelerated, integerDotProduct4x8BitPackedMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct16BitUnsignedAccelerated, integerDotProduct16BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct16BitSignedAccelerated, integerDotProduct16BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct16BitMixedSignednessAccelerated, integerDotProduct16BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct32BitUnsignedAccelerated, integerDotProduct32BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct32BitSignedAccelerated, integerDotProduct32BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct32BitMixedSignednessAccelerated, integerDotProduct32BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct64BitUnsignedAccelerated, integerDotProduct64BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct64BitSignedAccelerated, integerDotProduct64BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProduct64BitMixedSignednessAccelerated, integerDotProduct64BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating8BitUnsignedAccelerated, integerDotProductAccumulatingSaturating8BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating8BitSignedAccelerated, integerDotProductAccumulatingSaturating8BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated, integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated, integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated, integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating16BitUnsignedAccelerated, integerDotProductAccumulatingSaturating16BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating16BitSignedAccelerated, integerDotProductAccumulatingSaturating16BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating32BitUnsignedAccelerated, integerDotProductAccumulatingSaturating32BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating32BitSignedAccelerated, integerDotProductAccumulatingSaturating32BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating64BitUnsignedAccelerated, integerDotProductAccumulatingSaturating64BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating64BitSignedAccelerated, integerDotProductAccumulatingSaturating64BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceVulkan13Properties, storageTexelBufferOffsetAlignmentBytes, storageTexelBufferOffsetAlignmentBytes);
+    FIELD(VkPhysicalDeviceVulkan13Properties, storageTexelBufferOffsetSingleTexelAlignment, storageTexelBufferOffsetSingleTexelAlignment);
+    FIELD(VkPhysicalDeviceVulkan13Properties, uniformTexelBufferOffsetAlignmentBytes, uniformTexelBufferOffsetAlignmentBytes);
+    FIELD(VkPhysicalDeviceVulkan13Properties, uniformTexelBufferOffsetSingleTexelAlignment, uniformTexelBufferOffsetSingleTexelAlignment);
+    FIELD(VkPhysicalDeviceVulkan13Properties, maxBufferSize, maxBufferSize);
+    STRUCT(structs, vk::PipelineCreationFeedback, VkPipelineCreationFeedback);
+    FIELD(VkPipelineCreationFeedback, flags, flags);
+    FIELD(VkPipelineCreationFeedback, duration, duration);
+    STRUCT(structs, vk::PipelineCreationFeedbackCreateInfo, VkPipelineCreationFeedbackCreateInfo);
+    FIELD(VkPipelineCreationFeedbackCreateInfo, sType, sType);
+    FIELD(VkPipelineCreationFeedbackCreateInfo, pNext, pNext);
+    FIELD(VkPipelineCreationFeedbackCreateInfo, pPipelineCreationFeedback, pPipelineCreationFeedback);
+    FIELD(VkPipelineCreationFeedbackCreateInfo, pipelineStageCreationFeedbackCount, pipelineStageCreationFeedbackCount);
+    FIELD(VkPipelineCreationFeedbackCreateInfo, pPipelineStageCreationFeedbacks, pPipelineStageCreationFeedbacks);
+    STRUCT(structs, vk::PhysicalDeviceShaderTerminateInvocationFeatures, VkPhysicalDeviceShaderTerminateInvocationFeatures);
+    FIELD(VkPhysicalDeviceShaderTerminateInvocationFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceShaderTerminateInvocationFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderTerminateInvocationFeatures, shaderTerminateInvocation, shaderTerminateInvocation);
+    STRUCT(structs, vk::PhysicalDeviceToolProperties, VkPhysicalDeviceToolProperties);
+    FIELD(VkPhysicalDeviceToolProperties, sType, sType);
+    FIELD(VkPhysicalDeviceToolProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceToolProperties, name, name);
+    FIELD(VkPhysicalDeviceToolProperties, version, version);
+    FIELD(VkPhysicalDeviceToolProperties, purposes, purposes);
+    FIELD(VkPhysicalDeviceToolProperties, description, description);
+    FIELD(VkPhysicalDeviceToolProperties, layer, layer);
+    STRUCT(structs, vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures, VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures);
+    FIELD(VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderDemoteToHelperInvocationFeatures, shaderDemoteToHelperInvocation, shaderDemoteToHelperInvocation);
+    STRUCT(structs, vk::PhysicalDevicePrivateDataFeatures, VkPhysicalDevicePrivateDataFeatures);
+    FIELD(VkPhysicalDevicePrivateDataFeatures, sType, sType);
+    FIELD(VkPhysicalDevicePrivateDataFeatures, pNext, pNext);
+    FIELD(VkPhysicalDevicePrivateDataFeatures, privateData, privateData);
+    STRUCT(structs, vk::DevicePrivateDataCreateInfo, VkDevicePrivateDataCreateInfo);
+    FIELD(VkDevicePrivateDataCreateInfo, sType, sType);
+    FIELD(VkDevicePrivateDataCreateInfo, pNext, pNext);
+    FIELD(VkDevicePrivateDataCreateInfo, privateDataSlotRequestCount, privateDataSlotRequestCount);
+    STRUCT(structs, vk::PrivateDataSlotCreateInfo, VkPrivateDataSlotCreateInfo);
+    FIELD(VkPrivateDataSlotCreateInfo, sType, sType);
+    FIELD(VkPrivateDataSlotCreateInfo, pNext, pNext);
+    FIELD(VkPrivateDataSlotCreateInfo, flags, flags);
+    STRUCT(structs, vk::PhysicalDevicePipelineCreationCacheControlFeatures, VkPhysicalDevicePipelineCreationCacheControlFeatures);
+    FIELD(VkPhysicalDevicePipelineCreationCacheControlFeatures, sType, sType);
+    FIELD(VkPhysicalDevicePipelineCreationCacheControlFeatures, pNext, pNext);
+    FIELD(VkPhysicalDevicePipelineCreationCacheControlFeatures, pipelineCreationCacheControl, pipelineCreationCacheControl);
+    STRUCT(structs, vk::MemoryBarrier2, VkMemoryBarrier2);
+    FIELD(VkMemoryBarrier2, sType, sType);
+    FIELD(VkMemoryBarrier2, pNext, pNext);
+    FIELD(VkMemoryBarrier2, srcStageMask, srcStageMask);
+    FIELD(VkMemoryBarrier2, srcAccessMask, srcAccessMask);
+    FIELD(VkMemoryBarrier2, dstStageMask, dstStageMask);
+    FIELD(VkMemoryBarrier2, dstAccessMask, dstAccessMask);
+    STRUCT(structs, vk::BufferMemoryBarrier2, VkBufferMemoryBarrier2);
+    FIELD(VkBufferMemoryBarrier2, sType, sType);
+    FIELD(VkBufferMemoryBarrier2, pNext, pNext);
+    FIELD(VkBufferMemoryBarrier2, srcStageMask, srcStageMask);
+    FIELD(VkBufferMemoryBarrier2, srcAccessMask, srcAccessMask);
+    FIELD(VkBufferMemoryBarrier2, dstStageMask, dstStageMask);
+    FIELD(VkBufferMemoryBarrier2, dstAccessMask, dstAccessMask);
+    FIELD(VkBufferMemoryBarrier2, srcQueueFamilyIndex, srcQueueFamilyIndex);
+    FIELD(VkBufferMemoryBarrier2, dstQueueFamilyIndex, dstQueueFamilyIndex);
+    FIELD(VkBufferMemoryBarrier2, buffer, buffer);
+    FIELD(VkBufferMemoryBarrier2, offset, offset);
+    FIELD(VkBufferMemoryBarrier2, size, size);
+    STRUCT(structs, vk::ImageMemoryBarrier2, VkImageMemoryBarrier2);
+    FIELD(VkImageMemoryBarrier2, sType, sType);
+    FIELD(VkImageMemoryBarrier2, pNext, pNext);
+    FIELD(VkImageMemoryBarrier2, srcStageMask, srcStageMask);
+    FIELD(VkImageMemoryBarrier2, srcAccessMask, srcAccessMask);
+    FIELD(VkImageMemoryBarrier2, dstStageMask, dstStageMask);
+    FIELD(VkImageMemoryBarrier2, dstAccessMask, dstAccessMask);
+    FIELD(VkImageMemoryBarrier2, oldLayout, oldLayout);
+    FIELD(VkImageMemoryBarrier2, newLayout, newLayout);
+    FIELD(VkImageMemoryBarrier2, srcQueueFamilyIndex, srcQueueFamilyIndex);
+    FIELD(VkImageMemoryBarrier2, dstQueueFamilyIndex, dstQueueFamilyIndex);
+    FIELD(VkImageMemoryBarrier2, image, image);
+    FIELD(VkImageMemoryBarrier2, subresourceRange, subresourceRange);
+    STRUCT(structs, vk::DependencyInfo, VkDependencyInfo);
+    FIELD(VkDependencyInfo, sType, sType);
+    FIELD(VkDependencyInfo, pNext, pNext);
+    FIELD(VkDependencyInfo, dependencyFlags, dependencyFlags);
+    FIELD(VkDependencyInfo, memoryBarrierCount, memoryBarrierCount);
+    FIELD(VkDependencyInfo, pMemoryBarriers, pMemoryBarriers);
+    FIELD(VkDependencyInfo, bufferMemoryBarrierCount, bufferMemoryBarrierCount);
+    FIELD(VkDependencyInfo, pBufferMemoryBarriers, pBufferMemoryBarriers);
+    FIELD(VkDependencyInfo, imageMemoryBarrierCount, imageMemoryBarrierCount);
+    FIELD(VkDependencyInfo, pImageMemoryBarriers, pImageMemoryBarriers);
+    STRUCT(structs, vk::SemaphoreSubmitInfo, VkSemaphoreSubmitInfo);
+    FIELD(VkSemaphoreSubmitInfo, sType, sType);
+    FIELD(VkSemaphoreSubmitInfo, pNext, pNext);
+    FIELD(VkSemaphoreSubmitInfo, semaphore, semaphore);
+    FIELD(VkSemaphoreSubmitInfo, value, value);
+    FIELD(VkSemaphoreSubmitInfo, stageMask, stageMask);
+    FIELD(VkSemaphoreSubmitInfo, deviceIndex, deviceIndex);
+    STRUCT(structs, vk::CommandBufferSubmitInfo, VkCommandBufferSubmitInfo);
+    FIELD(VkCommandBufferSubmitInfo, sType, sType);
+    FIELD(VkCommandBufferSubmitInfo, pNext, pNext);
+    FIELD(VkCommandBufferSubmitInfo, commandBuffer, commandBuffer);
+    FIELD(VkCommandBufferSubmitInfo, deviceMask, deviceMask);
+    STRUCT(structs, vk::SubmitInfo2, VkSubmitInfo2);
+    FIELD(VkSubmitInfo2, sType, sType);
+    FIELD(VkSubmitInfo2, pNext, pNext);
+    FIELD(VkSubmitInfo2, flags, flags);
+    FIELD(VkSubmitInfo2, waitSemaphoreInfoCount, waitSemaphoreInfoCount);
+    FIELD(VkSubmitInfo2, pWaitSemaphoreInfos, pWaitSemaphoreInfos);
+    FIELD(VkSubmitInfo2, commandBufferInfoCount, commandBufferInfoCount);
+    FIELD(VkSubmitInfo2, pCommandBufferInfos, pCommandBufferInfos);
+    FIELD(VkSubmitInfo2, signalSemaphoreInfoCount, signalSemaphoreInfoCount);
+    FIELD(VkSubmitInfo2, pSignalSemaphoreInfos, pSignalSemaphoreInfos);
+    STRUCT(structs, vk::PhysicalDeviceSynchronization2Features, VkPhysicalDeviceSynchronization2Features);
+    FIELD(VkPhysicalDeviceSynchronization2Features, sType, sType);
+    FIELD(VkPhysicalDeviceSynchronization2Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceSynchronization2Features, synchronization2, synchronization2);
+    STRUCT(structs, vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures);
+    FIELD(VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, shaderZeroInitializeWorkgroupMemory, shaderZeroInitializeWorkgroupMemory);
+    STRUCT(structs, vk::PhysicalDeviceImageRobustnessFeatures, VkPhysicalDeviceImageRobustnessFeatures);
+    FIELD(VkPhysicalDeviceImageRobustnessFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceImageRobustnessFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceImageRobustnessFeatures, robustImageAccess, robustImageAccess);
+    STRUCT(structs, vk::BufferCopy2, VkBufferCopy2);
+    FIELD(VkBufferCopy2, sType, sType);
+    FIELD(VkBufferCopy2, pNext, pNext);
+    FIELD(VkBufferCopy2, srcOffset, srcOffset);
+    FIELD(VkBufferCopy2, dstOffset, dstOffset);
+    FIELD(VkBufferCopy2, size, size);
+    STRUCT(structs, vk::CopyBufferInfo2, VkCopyBufferInfo2);
+    FIELD(VkCopyBufferInfo2, sType, sType);
+    FIELD(VkCopyBufferInfo2, pNext, pNext);
+    FIELD(VkCopyBufferInfo2, srcBuffer, srcBuffer);
+    FIELD(VkCopyBufferInfo2, dstBuffer, dstBuffer);
+    FIELD(VkCopyBufferInfo2, regionCount, regionCount);
+    FIELD(VkCopyBufferInfo2, pRegions, pRegions);
+    STRUCT(structs, vk::ImageCopy2, VkImageCopy2);
+    FIELD(VkImageCopy2, sType, sType);
+    FIELD(VkImageCopy2, pNext, pNext);
+    FIELD(VkImageCopy2, srcSubresource, srcSubresource);
+    FIELD(VkImageCopy2, srcOffset, srcOffset);
+    FIELD(VkImageCopy2, dstSubresource, dstSubresource);
+    FIELD(VkImageCopy2, dstOffset, dstOffset);
+    FIELD(VkImageCopy2, extent, extent);
+    STRUCT(structs, vk::CopyImageInfo2, VkCopyImageInfo2);
+    FIELD(VkCopyImageInfo2, sType, sType);
+    FIELD(VkCopyImageInfo2, pNext, pNext);
+    FIELD(VkCopyImageInfo2, srcImage, srcImage);
+    FIELD(VkCopyImageInfo2, srcImageLayout, srcImageLayout);
+    FIELD(VkCopyImageInfo2, dstImage, dstImage);
+    FIELD(VkCopyImageInfo2, dstImageLayout, dstImageLayout);
+    FIELD(VkCopyImageInfo2, regionCount, regionCount);
+    FIELD(VkCopyImageInfo2, pRegions, pRegions);
+    STRUCT(structs, vk::BufferImageCopy2, VkBufferImageCopy2);
+    FIELD(VkBufferImageCopy2, sType, sType);
+    FIELD(VkBufferImageCopy2, pNext, pNext);
+    FIELD(VkBufferImageCopy2, bufferOffset, bufferOffset);
+    FIELD(VkBufferImageCopy2, bufferRowLength, bufferRowLength);
+    FIELD(VkBufferImageCopy2, bufferImageHeight, bufferImageHeight);
+    FIELD(VkBufferImageCopy2, imageSubresource, imageSubresource);
+    FIELD(VkBufferImageCopy2, imageOffset, imageOffset);
+    FIELD(VkBufferImageCopy2, imageExtent, imageExtent);
+    STRUCT(structs, vk::CopyBufferToImageInfo2, VkCopyBufferToImageInfo2);
+    FIELD(VkCopyBufferToImageInfo2, sType, sType);
+    FIELD(VkCopyBufferToImageInfo2, pNext, pNext);
+    FIELD(VkCopyBufferToImageInfo2, srcBuffer, srcBuffer);
+    FIELD(VkCopyBufferToImageInfo2, dstImage, dstImage);
+    FIELD(VkCopyBufferToImageInfo2, dstImageLayout, dstImageLayout);
+    FIELD(VkCopyBufferToImageInfo2, regionCount, regionCount);
+    FIELD(VkCopyBufferToImageInfo2, pRegions, pRegions);
+    STRUCT(structs, vk::CopyImageToBufferInfo2, VkCopyImageToBufferInfo2);
+    FIELD(VkCopyImageToBufferInfo2, sType, sType);
+    FIELD(VkCopyImageToBufferInfo2, pNext, pNext);
+    FIELD(VkCopyImageToBufferInfo2, srcImage, srcImage);
+    FIELD(VkCopyImageToBufferInfo2, srcImageLayout, srcImageLayout);
+    FIELD(VkCopyImageToBufferInfo2, dstBuffer, dstBuffer);
+    FIELD(VkCopyImageToBufferInfo2, regionCount, regionCount);
+    FIELD(VkCopyImageToBufferInfo2, pRegions, pRegions);
+    STRUCT(structs, vk::ImageBlit2, VkImageBlit2);
+    FIELD(VkImageBlit2, sType, sType);
+    FIELD(VkImageBlit2, pNext, pNext);
+    FIELD(VkImageBlit2, srcSubresource, srcSubresource);
+    FIELD(VkImageBlit2, srcOffsets, srcOffsets);
+    FIELD(VkImageBlit2, dstSubresource, dstSubresource);
+    FIELD(VkImageBlit2, dstOffsets, dstOffsets);
+    STRUCT(structs, vk::BlitImageInfo2, VkBlitImageInfo2);
+    FIELD(VkBlitImageInfo2, sType, sType);
+    FIELD(VkBlitImageInfo2, pNext, pNext);
+    FIELD(VkBlitImageInfo2, srcImage, srcImage);
+    FIELD(VkBlitImageInfo2, srcImageLayout, srcImageLayout);
+    FIELD(VkBlitImageInfo2, dstImage, dstImage);
+    FIELD(VkBlitImageInfo2, dstImageLayout, dstImageLayout);
+    FIELD(VkBlitImageInfo2, regionCount, regionCount);
+    FIELD(VkBlitImageInfo2, pRegions, pRegions);
+    FIELD(VkBlitImageInfo2, filter, filter);
+    STRUCT(structs, vk::ImageResolve2, VkImageResolve2);
+    FIELD(VkImageResolve2, sType, sType);
+    FIELD(VkImageResolve2, pNext, pNext);
+    FIELD(VkImageResolve2, srcSubresource, srcSubresource);
+    FIELD(VkImageResolve2, srcOffset, srcOffset);
+    FIELD(VkImageResolve2, dstSubresource, dstSubresource);
+    FIELD(VkImageResolve2, dstOffset, dstOffset);
+    FIELD(VkImageResolve2, extent, extent);
+    STRUCT(structs, vk::ResolveImageInfo2, VkResolveImageInfo2);
+    FIELD(VkResolveImageInfo2, sType, sType);
+    FIELD(VkResolveImageInfo2, pNext, pNext);
+    FIELD(VkResolveImageInfo2, srcImage, srcImage);
+    FIELD(VkResolveImageInfo2, srcImageLayout, srcImageLayout);
+    FIELD(VkResolveImageInfo2, dstImage, dstImage);
+    FIELD(VkResolveImageInfo2, dstImageLayout, dstImageLayout);
+    FIELD(VkResolveImageInfo2, regionCount, regionCount);
+    FIELD(VkResolveImageInfo2, pRegions, pRegions);
+    STRUCT(structs, vk::PhysicalDeviceSubgroupSizeControlFeatures, VkPhysicalDeviceSubgroupSizeControlFeatures);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlFeatures, subgroupSizeControl, subgroupSizeControl);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlFeatures, computeFullSubgroups, computeFullSubgroups);
+    STRUCT(structs, vk::PhysicalDeviceSubgroupSizeControlProperties, VkPhysicalDeviceSubgroupSizeControlProperties);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlProperties, sType, sType);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlProperties, minSubgroupSize, minSubgroupSize);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlProperties, maxSubgroupSize, maxSubgroupSize);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlProperties, maxComputeWorkgroupSubgroups, maxComputeWorkgroupSubgroups);
+    FIELD(VkPhysicalDeviceSubgroupSizeControlProperties, requiredSubgroupSizeStages, requiredSubgroupSizeStages);
+    STRUCT(structs, vk::PipelineShaderStageRequiredSubgroupSizeCreateInfo, VkPipelineShaderStageRequiredSubgroupSizeCreateInfo);
+    FIELD(VkPipelineShaderStageRequiredSubgroupSizeCreateInfo, sType, sType);
+    FIELD(VkPipelineShaderStageRequiredSubgroupSizeCreateInfo, pNext, pNext);
+    FIELD(VkPipelineShaderStageRequiredSubgroupSizeCreateInfo, requiredSubgroupSize, requiredSubgroupSize);
+    STRUCT(structs, vk::PhysicalDeviceInlineUniformBlockFeatures, VkPhysicalDeviceInlineUniformBlockFeatures);
+    FIELD(VkPhysicalDeviceInlineUniformBlockFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceInlineUniformBlockFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceInlineUniformBlockFeatures, inlineUniformBlock, inlineUniformBlock);
+    FIELD(VkPhysicalDeviceInlineUniformBlockFeatures, descriptorBindingInlineUniformBlockUpdateAfterBind, descriptorBindingInlineUniformBlockUpdateAfterBind);
+    STRUCT(structs, vk::PhysicalDeviceInlineUniformBlockProperties, VkPhysicalDeviceInlineUniformBlockProperties);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, sType, sType);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, maxInlineUniformBlockSize, maxInlineUniformBlockSize);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, maxPerStageDescriptorInlineUniformBlocks, maxPerStageDescriptorInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks, maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, maxDescriptorSetInlineUniformBlocks, maxDescriptorSetInlineUniformBlocks);
+    FIELD(VkPhysicalDeviceInlineUniformBlockProperties, maxDescriptorSetUpdateAfterBindInlineUniformBlocks, maxDescriptorSetUpdateAfterBindInlineUniformBlocks);
+    STRUCT(structs, vk::WriteDescriptorSetInlineUniformBlock, VkWriteDescriptorSetInlineUniformBlock);
+    FIELD(VkWriteDescriptorSetInlineUniformBlock, sType, sType);
+    FIELD(VkWriteDescriptorSetInlineUniformBlock, pNext, pNext);
+    FIELD(VkWriteDescriptorSetInlineUniformBlock, dataSize, dataSize);
+    FIELD(VkWriteDescriptorSetInlineUniformBlock, pData, pData);
+    STRUCT(structs, vk::DescriptorPoolInlineUniformBlockCreateInfo, VkDescriptorPoolInlineUniformBlockCreateInfo);
+    FIELD(VkDescriptorPoolInlineUniformBlockCreateInfo, sType, sType);
+    FIELD(VkDescriptorPoolInlineUniformBlockCreateInfo, pNext, pNext);
+    FIELD(VkDescriptorPoolInlineUniformBlockCreateInfo, maxInlineUniformBlockBindings, maxInlineUniformBlockBindings);
+    STRUCT(structs, vk::PhysicalDeviceTextureCompressionASTCHDRFeatures, VkPhysicalDeviceTextureCompressionASTCHDRFeatures);
+    FIELD(VkPhysicalDeviceTextureCompressionASTCHDRFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceTextureCompressionASTCHDRFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceTextureCompressionASTCHDRFeatures, textureCompressionASTC_HDR, textureCompressionASTC_HDR);
+    STRUCT(structs, vk::RenderingAttachmentInfo, VkRenderingAttachmentInfo);
+    FIELD(VkRenderingAttachmentInfo, sType, sType);
+    FIELD(VkRenderingAttachmentInfo, pNext, pNext);
+    FIELD(VkRenderingAttachmentInfo, imageView, imageView);
+    FIELD(VkRenderingAttachmentInfo, imageLayout, imageLayout);
+    FIELD(VkRenderingAttachmentInfo, resolveMode, resolveMode);
+    FIELD(VkRenderingAttachmentInfo, resolveImageView, resolveImageView);
+    FIELD(VkRenderingAttachmentInfo, resolveImageLayout, resolveImageLayout);
+    FIELD(VkRenderingAttachmentInfo, loadOp, loadOp);
+    FIELD(VkRenderingAttachmentInfo, storeOp, storeOp);
+    FIELD(VkRenderingAttachmentInfo, clearValue, clearValue);
+    STRUCT(structs, vk::RenderingInfo, VkRenderingInfo);
+    FIELD(VkRenderingInfo, sType, sType);
+    FIELD(VkRenderingInfo, pNext, pNext);
+    FIELD(VkRenderingInfo, flags, flags);
+    FIELD(VkRenderingInfo, renderArea, renderArea);
+    FIELD(VkRenderingInfo, layerCount, layerCount);
+    FIELD(VkRenderingInfo, viewMask, viewMask);
+    FIELD(VkRenderingInfo, colorAttachmentCount, colorAttachmentCount);
+    FIELD(VkRenderingInfo, pColorAttachments, pColorAttachments);
+    FIELD(VkRenderingInfo, pDepthAttachment, pDepthAttachment);
+    FIELD(VkRenderingInfo, pStencilAttachment, pStencilAttachment);
+    STRUCT(structs, vk::PipelineRenderingCreateInfo, VkPipelineRenderingCreateInfo);
+    FIELD(VkPipelineRenderingCreateInfo, sType, sType);
+    FIELD(VkPipelineRenderingCreateInfo, pNext, pNext);
+    FIELD(VkPipelineRenderingCreateInfo, viewMask, viewMask);
+    FIELD(VkPipelineRenderingCreateInfo, colorAttachmentCount, colorAttachmentCount);
+    FIELD(VkPipelineRenderingCreateInfo, pColorAttachmentFormats, pColorAttachmentFormats);
+    FIELD(VkPipelineRenderingCreateInfo, depthAttachmentFormat, depthAttachmentFormat);
+    FIELD(VkPipelineRenderingCreateInfo, stencilAttachmentFormat, stencilAttachmentFormat);
+    STRUCT(structs, vk::PhysicalDeviceDynamicRenderingFeatures, VkPhysicalDeviceDynamicRenderingFeatures);
+    FIELD(VkPhysicalDeviceDynamicRenderingFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceDynamicRenderingFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceDynamicRenderingFeatures, dynamicRendering, dynamicRendering);
+    STRUCT(structs, vk::CommandBufferInheritanceRenderingInfo, VkCommandBufferInheritanceRenderingInfo);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, sType, sType);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, pNext, pNext);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, flags, flags);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, viewMask, viewMask);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, colorAttachmentCount, colorAttachmentCount);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, pColorAttachmentFormats, pColorAttachmentFormats);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, depthAttachmentFormat, depthAttachmentFormat);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, stencilAttachmentFormat, stencilAttachmentFormat);
+    FIELD(VkCommandBufferInheritanceRenderingInfo, rasterizationSamples, rasterizationSamples);
+    STRUCT(structs, vk::PhysicalDeviceShaderIntegerDotProductFeatures, VkPhysicalDeviceShaderIntegerDotProductFeatures);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductFeatures, sType, sType);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductFeatures, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductFeatures, shaderIntegerDotProduct, shaderIntegerDotProduct);
+    STRUCT(structs, vk::PhysicalDeviceShaderIntegerDotProductProperties, VkPhysicalDeviceShaderIntegerDotProductProperties);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, sType, sType);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct8BitUnsignedAccelerated, integerDotProduct8BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct8BitSignedAccelerated, integerDotProduct8BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct8BitMixedSignednessAccelerated, integerDotProduct8BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct4x8BitPackedUnsignedAccelerated, integerDotProduct4x8BitPackedUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct4x8BitPackedSignedAccelerated, integerDotProduct4x8BitPackedSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct4x8BitPackedMixedSignednessAccelerated, integerDotProduct4x8BitPackedMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct16BitUnsignedAccelerated, integerDotProduct16BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct16BitSignedAccelerated, integerDotProduct16BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct16BitMixedSignednessAccelerated, integerDotProduct16BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct32BitUnsignedAccelerated, integerDotProduct32BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct32BitSignedAccelerated, integerDotProduct32BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct32BitMixedSignednessAccelerated, integerDotProduct32BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct64BitUnsignedAccelerated, integerDotProduct64BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct64BitSignedAccelerated, integerDotProduct64BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProduct64BitMixedSignednessAccelerated, integerDotProduct64BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating8BitUnsignedAccelerated, integerDotProductAccumulatingSaturating8BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating8BitSignedAccelerated, integerDotProductAccumulatingSaturating8BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated, integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated, integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated, integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating16BitUnsignedAccelerated, integerDotProductAccumulatingSaturating16BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating16BitSignedAccelerated, integerDotProductAccumulatingSaturating16BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating32BitUnsignedAccelerated, integerDotProductAccumulatingSaturating32BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating32BitSignedAccelerated, integerDotProductAccumulatingSaturating32BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating64BitUnsignedAccelerated, integerDotProductAccumulatingSaturating64BitUnsignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating64BitSignedAccelerated, integerDotProductAccumulatingSaturating64BitSignedAccelerated);
+    FIELD(VkPhysicalDeviceShaderIntegerDotProductProperties, integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated, integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated);
+    STRUCT(structs, vk::PhysicalDeviceTexelBufferAlignmentProperties, VkPhysicalDeviceTexelBufferAlignmentProperties);
+    FIELD(VkPhysicalDeviceTexelBufferAlignmentProperties, sType, sType);
+    FIELD(VkPhysicalDeviceTexelBufferAlignmentProperties, pNext, pNext);
+    FIELD(VkPhysicalDeviceTexelBufferAlignmentProperties, storageTexelBufferOffsetAlignmentBytes, storageTexelBufferOffsetAlignmentBytes);
+    FIELD(VkPhysicalDeviceTexelBufferAlignmentProperties, storageTexelBufferOffsetSingleTexelAlignment, storageTexelBufferOffsetSingleTexelAlignment);
+    FIELD(VkPhysicalDeviceTexelBufferAlignmentProperties, uniformTexelBufferOffsetAlignmentBytes, uniformTexelBufferOffsetAlignmentBytes);
+    FIELD(VkPhysicalDeviceTexelBufferAlignmentProperties, uniformTexelBufferOffsetSingleTexelAlignment, uniformTexelBufferOffsetSingleTexelAlignment);
+    STRUCT(structs, vk::FormatProperties3, VkFormatProperties3);
+    FIELD(VkFormatProperties3, sType, sType);
+    FIELD(VkFormatProperties3, pNext, pNext);
+    FIELD(VkFormatProperties3, linearTilingFeatures, linearTilingFeatures);
+    FIELD(VkFormatProperties3, optimalTilingFeatures, optimalTilingFeatures);
+    FIELD(VkFormatProperties3, bufferFeatures, bufferFeatures);
+    STRUCT(structs, vk::PhysicalDeviceMaintenance4Features, VkPhysicalDeviceMaintenance4Features);
+    FIELD(VkPhysicalDeviceMaintenance4Features, sType, sType);
+    FIELD(VkPhysicalDeviceMaintenance4Features, pNext, pNext);
+    FIELD(VkPhysicalDeviceMaintenance4Features, maintenance4, maintenance4);
+    STRUCT(structs, vk::PhysicalDeviceMaintenance4Properties, VkPhysicalDeviceMaintenance4Properties);
+    FIELD(VkPhysicalDeviceMaintenance4Properties, sType, sType);
+    FIELD(VkPhysicalDeviceMaintenance4Properties, pNext, pNext);
+    FIELD(VkPhysicalDeviceMaintenance4Properties, maxBufferSize, maxBufferSize);
+    STRUCT(structs, vk::DeviceBufferMemoryRequirements, VkDeviceBufferMemoryRequirements);
+    FIELD(VkDeviceBufferMemoryRequirements, sType, sType);
+    FIELD(VkDeviceBufferMemoryRequirements, pNext, pNext);
+    FIELD(VkDeviceBufferMemoryRequirements, pCreateInfo, pCreateInfo);
+    STRUCT(structs, vk::DeviceImageMemoryRequirements, VkDeviceImageMemoryRequirements);
+    FIELD(VkDeviceImageMemoryRequirements, sType, sType);
+    FIELD(VkDeviceImageMemoryRequirements, pNext, pNext);
+    FIELD(VkDeviceImageMemoryRequirements, pCreateInfo, pCreateInfo);
+    FIELD(VkDeviceImageMemoryRequirements, planeAspect, planeAspect);
+    STRUCT(unions, vk::ClearColorValue, VkClearColorValue);
+    FIELD(VkClearColorValue, float32, float32);
+    FIELD(VkClearColorValue, int32, int32);
+    FIELD(VkClearColorValue, uint32, uint32);
+    STRUCT(unions, vk::ClearValue, VkClearValue);
+    FIELD(VkClearValue, color, color);
+    FIELD(VkClearValue, depthStencil, depthStencil);
+    STRUCT(khr_display, vk::khr::display::Properties, VkDisplayPropertiesKHR);
+    FIELD(VkDisplayPropertiesKHR, display, display);
+    FIELD(VkDisplayPropertiesKHR, displayName, displayName);
+    FIELD(VkDisplayPropertiesKHR, physicalDimensions, physicalDimensions);
+    FIELD(VkDisplayPropertiesKHR, physicalResolution, physicalResolution);
+    FIELD(VkDisplayPropertiesKHR, supportedTransforms, supportedTransforms);
+    FIELD(VkDisplayPropertiesKHR, planeReorderPossible, planeReorderPossible);
+    FIELD(VkDisplayPropertiesKHR, persistentContent, persistentContent);
+    STRUCT(khr_display, vk::khr::display::ModeParameters, VkDisplayModeParametersKHR);
+    FIELD(VkDisplayModeParametersKHR, visibleRegion, visibleRegion);
+    FIELD(VkDisplayModeParametersKHR, refreshRate, refreshRate);
+    STRUCT(khr_display, vk::khr::display::ModeProperties, VkDisplayModePropertiesKHR);
+    FIELD(VkDisplayModePropertiesKHR, displayMode, displayMode);
+    FIELD(VkDisplayModePropertiesKHR, parameters, parameters);
+    STRUCT(khr_display, vk::khr::display::ModeCreateInfo, VkDisplayModeCreateInfoKHR);
+    FIELD(VkDisplayModeCreateInfoKHR, sType, sType);
+    FIELD(VkDisplayModeCreateInfoKHR, pNext, pNext);
+    FIELD(VkDisplayModeCreateInfoKHR, flags, flags);
+    FIELD(VkDisplayModeCreateInfoKHR, parameters, parameters);
+    STRUCT(khr_display, vk::khr::display::PlaneCapabilities, VkDisplayPlaneCapabilitiesKHR);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, supportedAlpha, supportedAlpha);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, minSrcPosition, minSrcPosition);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, maxSrcPosition, maxSrcPosition);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, minSrcExtent, minSrcExtent);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, maxSrcExtent, maxSrcExtent);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, minDstPosition, minDstPosition);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, maxDstPosition, maxDstPosition);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, minDstExtent, minDstExtent);
+    FIELD(VkDisplayPlaneCapabilitiesKHR, maxDstExtent, maxDstExtent);
+    STRUCT(khr_display, vk::khr::display::PlaneProperties, VkDisplayPlanePropertiesKHR);
+    FIELD(VkDisplayPlanePropertiesKHR, currentDisplay, currentDisplay);
+    FIELD(VkDisplayPlanePropertiesKHR, currentStackIndex, currentStackIndex);
+    STRUCT(khr_display, vk::khr::display::SurfaceCreateInfo, VkDisplaySurfaceCreateInfoKHR);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, sType, sType);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, pNext, pNext);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, flags, flags);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, displayMode, displayMode);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, planeIndex, planeIndex);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, planeStackIndex, planeStackIndex);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, transform, transform);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, globalAlpha, globalAlpha);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, alphaMode, alphaMode);
+    FIELD(VkDisplaySurfaceCreateInfoKHR, imageExtent, imageExtent);
+    STRUCT(khr_display_swapchain, vk::khr::display_swapchain::DisplayPresentInfo, VkDisplayPresentInfoKHR);
+    FIELD(VkDisplayPresentInfoKHR, sType, sType);
+    FIELD(VkDisplayPresentInfoKHR, pNext, pNext);
+    FIELD(VkDisplayPresentInfoKHR, srcRect, srcRect);
+    FIELD(VkDisplayPresentInfoKHR, dstRect, dstRect);
+    FIELD(VkDisplayPresentInfoKHR, persistent, persistent);
+    STRUCT(khr_surface, vk::khr::surface::Capabilities, VkSurfaceCapabilitiesKHR);
+    FIELD(VkSurfaceCapabilitiesKHR, minImageCount, minImageCount);
+    FIELD(VkSurfaceCapabilitiesKHR, maxImageCount, maxImageCount);
+    FIELD(VkSurfaceCapabilitiesKHR, currentExtent, currentExtent);
+    FIELD(VkSurfaceCapabilitiesKHR, minImageExtent, minImageExtent);
+    FIELD(VkSurfaceCapabilitiesKHR, maxImageExtent, maxImageExtent);
+    FIELD(VkSurfaceCapabilitiesKHR, maxImageArrayLayers, maxImageArrayLayers);
+    FIELD(VkSurfaceCapabilitiesKHR, supportedTransforms, supportedTransforms);
+    FIELD(VkSurfaceCapabilitiesKHR, currentTransform, currentTransform);
+    FIELD(VkSurfaceCapabilitiesKHR, supportedCompositeAlpha, supportedCompositeAlpha);
+    FIELD(VkSurfaceCapabilitiesKHR, supportedUsageFlags, supportedUsageFlags);
+    STRUCT(khr_surface, vk::khr::surface::Format, VkSurfaceFormatKHR);
+    FIELD(VkSurfaceFormatKHR, format, format);
+    FIELD(VkSurfaceFormatKHR, colorSpace, colorSpace);
+    STRUCT(khr_swapchain, vk::khr::swapchain::CreateInfo, VkSwapchainCreateInfoKHR);
+    FIELD(VkSwapchainCreateInfoKHR, sType, sType);
+    FIELD(VkSwapchainCreateInfoKHR, pNext, pNext);
+    FIELD(VkSwapchainCreateInfoKHR, flags, flags);
+    FIELD(VkSwapchainCreateInfoKHR, surface, surface);
+    FIELD(VkSwapchainCreateInfoKHR, minImageCount, minImageCount);
+    FIELD(VkSwapchainCreateInfoKHR, imageFormat, imageFormat);
+    FIELD(VkSwapchainCreateInfoKHR, imageColorSpace, imageColorSpace);
+    FIELD(VkSwapchainCreateInfoKHR, imageExtent, imageExtent);
+    FIELD(VkSwapchainCreateInfoKHR, imageArrayLayers, imageArrayLayers);
+    FIELD(VkSwapchainCreateInfoKHR, imageUsage, imageUsage);
+    FIELD(VkSwapchainCreateInfoKHR, imageSharingMode, imageSharingMode);
+    FIELD(VkSwapchainCreateInfoKHR, queueFamilyIndexCount, queueFamilyIndexCount);
+    FIELD(VkSwapchainCreateInfoKHR, pQueueFamilyIndices, pQueueFamilyIndices);
+    FIELD(VkSwapchainCreateInfoKHR, preTransform, preTransform);
+    FIELD(VkSwapchainCreateInfoKHR, compositeAlpha, compositeAlpha);
+    FIELD(VkSwapchainCreateInfoKHR, presentMode, presentMode);
+    FIELD(VkSwapchainCreateInfoKHR, clipped, clipped);
+    FIELD(VkSwapchainCreateInfoKHR, oldSwapchain, oldSwapchain);
+    STRUCT(khr_swapchain, vk::khr::swapchain::PresentInfo, VkPresentInfoKHR);
+    FIELD(VkPresentInfoKHR, sType, sType);
+    FIELD(VkPresentInfoKHR, pNext, pNext);
+    FIELD(VkPresentInfoKHR, waitSemaphoreCount, waitSemaphoreCount);
+    FIELD(VkPresentInfoKHR, pWaitSemaphores, pWaitSemaphores);
+    FIELD(VkPresentInfoKHR, swapchainCount, swapchainCount);
+    FIELD(VkPresentInfoKHR, pSwapchains, pSwapchains);
+    FIELD(VkPresentInfoKHR, pImageIndices, pImageIndices);
+    FIELD(VkPresentInfoKHR, pResults, pResults);
+    STRUCT(ext_debug_report, vk::ext::debug_report::CreateInfo, VkDebugReportCallbackCreateInfoEXT);
+    FIELD(VkDebugReportCallbackCreateInfoEXT, sType, sType);
+    FIELD(VkDebugReportCallbackCreateInfoEXT, pNext, pNext);
+    FIELD(VkDebugReportCallbackCreateInfoEXT, flags, flags);
+    FIELD(VkDebugReportCallbackCreateInfoEXT, pfnCallback, pfnCallback);
+    FIELD(VkDebugReportCallbackCreateInfoEXT, pUserData, pUserData);
+}
@@ -0,0 +1,2437 @@
+//! Size, alignment and field offsets of every `#[repr(C)]` struct, checked
+//! against what the C compiler gives the declarations in `vulkan_core.h` on
+//! 64-bit targets, on 32-bit x86 outside Windows (where 64-bit fields are
+//! 4-byte aligned) and on other 32-bit targets.
+//!
+//! Generated by `generator/layout.sh`, which compiles `tests/layout.c`
+//! against the Vulkan-Headers release the bindings are generated from.
+
+extern crate vulkan_bind;
+
+use std::mem::{align_of, offset_of, size_of};
+
+use vulkan_bind::vk;
+
+macro_rules! check_layout {
+    ($ty:ty, $size:expr, $align:expr $(, $field:ident: $offset:expr)*) => {
+        assert_eq!(size_of::<$ty>(), $size, concat!("size of ", stringify!($ty)));
+        assert_eq!(align_of::<$ty>(), $align, concat!("alignment of ", stringify!($ty)));
+        $(assert_eq!(offset_of!($ty, $field), $offset,
+                     concat!("offset of ", stringify!($ty), "::", stringify!($field)));)*
+    }
+}
+
+#[test]
+fn structs() {
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BaseOutStructure, 16, 8, sType: 0, pNext: 8);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::BaseOutStructure, 8, 4, sType: 0, pNext: 4);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BaseInStructure, 16, 8, sType: 0, pNext: 8);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::BaseInStructure, 8, 4, sType: 0, pNext: 4);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ApplicationInfo, 48, 8, sType: 0, pNext: 8, pApplicationName: 16,
+        applicationVersion: 24, pEngineName: 32, engineVersion: 40, apiVersion: 44);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ApplicationInfo, 28, 4, sType: 0, pNext: 4, pApplicationName: 8,
+        applicationVersion: 12, pEngineName: 16, engineVersion: 20, apiVersion: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::InstanceCreateInfo, 64, 8, sType: 0, pNext: 8, flags: 16,
+        pApplicationInfo: 24, enabledLayerCount: 32, ppEnabledLayerNames: 40,
+        enabledExtensionCount: 48, ppEnabledExtensionNames: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::InstanceCreateInfo, 32, 4, sType: 0, pNext: 4, flags: 8,
+        pApplicationInfo: 12, enabledLayerCount: 16, ppEnabledLayerNames: 20,
+        enabledExtensionCount: 24, ppEnabledExtensionNames: 28);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::AllocationCallbacks, 48, 8, pUserData: 0, pfnAllocation: 8,
+        pfnReallocation: 16, pfnFree: 24, pfnInternalAllocation: 32, pfnInternalFree: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::AllocationCallbacks, 24, 4, pUserData: 0, pfnAllocation: 4,
+        pfnReallocation: 8, pfnFree: 12, pfnInternalAllocation: 16, pfnInternalFree: 20);
+    check_layout!(vk::PhysicalDeviceFeatures, 220, 4, robustBufferAccess: 0,
+        fullDrawIndexUint32: 4, imageCubeArray: 8, independentBlend: 12, geometryShader: 16,
+        tessellationShader: 20, sampleRateShading: 24, dualSrcBlend: 28, logicOp: 32,
+        multiDrawIndirect: 36, drawIndirectFirstInstance: 40, depthClamp: 44, depthBiasClamp: 48,
+        fillModeNonSolid: 52, depthBounds: 56, wideLines: 60, largePoints: 64, alphaToOne: 68,
+        multiViewport: 72, samplerAnisotropy: 76, textureCompressionETC2: 80,
+        textureCompressionASTC_LDR: 84, textureCompressionBC: 88, occlusionQueryPrecise: 92,
+        pipelineStatisticsQuery: 96, vertexPipelineStoresAndAtomics: 100,
+        fragmentStoresAndAtomics: 104, shaderTessellationAndGeometryPointSize: 108,
+        shaderImageGatherExtended: 112, shaderStorageImageExtendedFormats: 116,
+        shaderStorageImageMultisample: 120, shaderStorageImageReadWithoutFormat: 124,
+        shaderStorageImageWriteWithoutFormat: 128, shaderUniformBufferArrayDynamicIndexing: 132,
+        shaderSampledImageArrayDynamicIndexing: 136, shaderStorageBufferArrayDynamicIndexing: 140,
+        shaderStorageImageArrayDynamicIndexing: 144, shaderClipDistance: 148,
+        shaderCullDistance: 152, shaderFloat64: 156, shaderInt64: 160, shaderInt16: 164,
+        shaderResourceResidency: 168, shaderResourceMinLod: 172, sparseBinding: 176,
+        sparseResidencyBuffer: 180, sparseResidencyImage2D: 184, sparseResidencyImage3D: 188,
+        sparseResidency2Samples: 192, sparseResidency4Samples: 196, sparseResidency8Samples: 200,
+        sparseResidency16Samples: 204, sparseResidencyAliased: 208, variableMultisampleRate: 212,
+        inheritedQueries: 216);
+    check_layout!(vk::FormatProperties, 12, 4, linearTilingFeatures: 0, optimalTilingFeatures: 4,
+        bufferFeatures: 8);
+    check_layout!(vk::Extent3D, 12, 4, width: 0, height: 4, depth: 8);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::ImageFormatProperties, 32, 8, maxExtent: 0, maxMipLevels: 12,
+        maxArrayLayers: 16, sampleCounts: 20, maxResourceSize: 24);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageFormatProperties, 32, 4, maxExtent: 0, maxMipLevels: 12,
+        maxArrayLayers: 16, sampleCounts: 20, maxResourceSize: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceLimits, 504, 8, maxImageDimension1D: 0, maxImageDimension2D: 4,
+        maxImageDimension3D: 8, maxImageDimensionCube: 12, maxImageArrayLayers: 16,
+        maxTexelBufferElements: 20, maxUniformBufferRange: 24, maxStorageBufferRange: 28,
+        maxPushConstantsSize: 32, maxMemoryAllocationCount: 36, maxSamplerAllocationCount: 40,
+        bufferImageGranularity: 48, sparseAddressSpaceSize: 56, maxBoundDescriptorSets: 64,
+        maxPerStageDescriptorSamplers: 68, maxPerStageDescriptorUniformBuffers: 72,
+        maxPerStageDescriptorStorageBuffers: 76, maxPerStageDescriptorSampledImages: 80,
+        maxPerStageDescriptorStorageImages: 84, maxPerStageDescriptorInputAttachments: 88,
+        maxPerStageResources: 92, maxDescriptorSetSamplers: 96,
+        maxDescriptorSetUniformBuffers: 100, maxDescriptorSetUniformBuffersDynamic: 104,
+        maxDescriptorSetStorageBuffers: 108, maxDescriptorSetStorageBuffersDynamic: 112,
+        maxDescriptorSetSampledImages: 116, maxDescriptorSetStorageImages: 120,
+        maxDescriptorSetInputAttachments: 124, maxVertexInputAttributes: 128,
+        maxVertexInputBindings: 132, maxVertexInputAttributeOffset: 136,
+        maxVertexInputBindingStride: 140, maxVertexOutputComponents: 144,
+        maxTessellationGenerationLevel: 148, maxTessellationPatchSize: 152,
+        maxTessellationControlPerVertexInputComponents: 156,
+        maxTessellationControlPerVertexOutputComponents: 160,
+        maxTessellationControlPerPatchOutputComponents: 164,
+        maxTessellationControlTotalOutputComponents: 168,
+        maxTessellationEvaluationInputComponents: 172,
+        maxTessellationEvaluationOutputComponents: 176, maxGeometryShaderInvocations: 180,
+        maxGeometryInputComponents: 184, maxGeometryOutputComponents: 188,
+        maxGeometryOutputVertices: 192, maxGeometryTotalOutputComponents: 196,
+        maxFragmentInputComponents: 200, maxFragmentOutputAttachments: 204,
+        maxFragmentDualSrcAttachments: 208, maxFragmentCombinedOutputResources: 212,
+        maxComputeSharedMemorySize: 216, maxComputeWorkGroupCount: 220,
+        maxComputeWorkGroupInvocations: 232, maxComputeWorkGroupSize: 236,
+        subPixelPrecisionBits: 248, subTexelPrecisionBits: 252, mipmapPrecisionBits: 256,
+        maxDrawIndexedIndexValue: 260, maxDrawIndirectCount: 264, maxSamplerLodBias: 268,
+        maxSamplerAnisotropy: 272, maxViewports: 276, maxViewportDimensions: 280,
+        viewportBoundsRange: 288, viewportSubPixelBits: 296, minMemoryMapAlignment: 304,
+        minTexelBufferOffsetAlignment: 312, minUniformBufferOffsetAlignment: 320,
+        minStorageBufferOffsetAlignment: 328, minTexelOffset: 336, maxTexelOffset: 340,
+        minTexelGatherOffset: 344, maxTexelGatherOffset: 348, minInterpolationOffset: 352,
+        maxInterpolationOffset: 356, subPixelInterpolationOffsetBits: 360,
+        maxFramebufferWidth: 364, maxFramebufferHeight: 368, maxFramebufferLayers: 372,
+        framebufferColorSampleCounts: 376, framebufferDepthSampleCounts: 380,
+        framebufferStencilSampleCounts: 384, framebufferNoAttachmentsSampleCounts: 388,
+        maxColorAttachments: 392, sampledImageColorSampleCounts: 396,
+        sampledImageIntegerSampleCounts: 400, sampledImageDepthSampleCounts: 404,
+        sampledImageStencilSampleCounts: 408, storageImageSampleCounts: 412,
+        maxSampleMaskWords: 416, timestampComputeAndGraphics: 420, timestampPeriod: 424,
+        maxClipDistances: 428, maxCullDistances: 432, maxCombinedClipAndCullDistances: 436,
+        discreteQueuePriorities: 440, pointSizeRange: 444, lineWidthRange: 452,
+        pointSizeGranularity: 460, lineWidthGranularity: 464, strictLines: 468,
+        standardSampleLocations: 472, optimalBufferCopyOffsetAlignment: 480,
+        optimalBufferCopyRowPitchAlignment: 488, nonCoherentAtomSize: 496);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceLimits, 488, 4, maxImageDimension1D: 0, maxImageDimension2D: 4,
+        maxImageDimension3D: 8, maxImageDimensionCube: 12, maxImageArrayLayers: 16,
+        maxTexelBufferElements: 20, maxUniformBufferRange: 24, maxStorageBufferRange: 28,
+        maxPushConstantsSize: 32, maxMemoryAllocationCount: 36, maxSamplerAllocationCount: 40,
+        bufferImageGranularity: 44, sparseAddressSpaceSize: 52, maxBoundDescriptorSets: 60,
+        maxPerStageDescriptorSamplers: 64, maxPerStageDescriptorUniformBuffers: 68,
+        maxPerStageDescriptorStorageBuffers: 72, maxPerStageDescriptorSampledImages: 76,
+        maxPerStageDescriptorStorageImages: 80, maxPerStageDescriptorInputAttachments: 84,
+        maxPerStageResources: 88, maxDescriptorSetSamplers: 92, maxDescriptorSetUniformBuffers: 96,
+        maxDescriptorSetUniformBuffersDynamic: 100, maxDescriptorSetStorageBuffers: 104,
+        maxDescriptorSetStorageBuffersDynamic: 108, maxDescriptorSetSampledImages: 112,
+        maxDescriptorSetStorageImages: 116, maxDescriptorSetInputAttachments: 120,
+        maxVertexInputAttributes: 124, maxVertexInputBindings: 128,
+        maxVertexInputAttributeOffset: 132, maxVertexInputBindingStride: 136,
+        maxVertexOutputComponents: 140, maxTessellationGenerationLevel: 144,
+        maxTessellationPatchSize: 148, maxTessellationControlPerVertexInputComponents: 152,
+        maxTessellationControlPerVertexOutputComponents: 156,
+        maxTessellationControlPerPatchOutputComponents: 160,
+        maxTessellationControlTotalOutputComponents: 164,
+        maxTessellationEvaluationInputComponents: 168,
+        maxTessellationEvaluationOutputComponents: 172, maxGeometryShaderInvocations: 176,
+        maxGeometryInputComponents: 180, maxGeometryOutputComponents: 184,
+        maxGeometryOutputVertices: 188, maxGeometryTotalOutputComponents: 192,
+        maxFragmentInputComponents: 196, maxFragmentOutputAttachments: 200,
+        maxFragmentDualSrcAttachments: 204, maxFragmentCombinedOutputResources: 208,
+        maxComputeSharedMemorySize: 212, maxComputeWorkGroupCount: 216,
+        maxComputeWorkGroupInvocations: 228, maxComputeWorkGroupSize: 232,
+        subPixelPrecisionBits: 244, subTexelPrecisionBits: 248, mipmapPrecisionBits: 252,
+        maxDrawIndexedIndexValue: 256, maxDrawIndirectCount: 260, maxSamplerLodBias: 264,
+        maxSamplerAnisotropy: 268, maxViewports: 272, maxViewportDimensions: 276,
+        viewportBoundsRange: 284, viewportSubPixelBits: 292, minMemoryMapAlignment: 296,
+        minTexelBufferOffsetAlignment: 300, minUniformBufferOffsetAlignment: 308,
+        minStorageBufferOffsetAlignment: 316, minTexelOffset: 324, maxTexelOffset: 328,
+        minTexelGatherOffset: 332, maxTexelGatherOffset: 336, minInterpolationOffset: 340,
+        maxInterpolationOffset: 344, subPixelInterpolationOffsetBits: 348,
+        maxFramebufferWidth: 352, maxFramebufferHeight: 356, maxFramebufferLayers: 360,
+        framebufferColorSampleCounts: 364, framebufferDepthSampleCounts: 368,
+        framebufferStencilSampleCounts: 372, framebufferNoAttachmentsSampleCounts: 376,
+        maxColorAttachments: 380, sampledImageColorSampleCounts: 384,
+        sampledImageIntegerSampleCounts: 388, sampledImageDepthSampleCounts: 392,
+        sampledImageStencilSampleCounts: 396, storageImageSampleCounts: 400,
+        maxSampleMaskWords: 404, timestampComputeAndGraphics: 408, timestampPeriod: 412,
+        maxClipDistances: 416, maxCullDistances: 420, maxCombinedClipAndCullDistances: 424,
+        discreteQueuePriorities: 428, pointSizeRange: 432, lineWidthRange: 440,
+        pointSizeGranularity: 448, lineWidthGranularity: 452, strictLines: 456,
+        standardSampleLocations: 460, optimalBufferCopyOffsetAlignment: 464,
+        optimalBufferCopyRowPitchAlignment: 472, nonCoherentAtomSize: 480);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceLimits, 496, 8, maxImageDimension1D: 0, maxImageDimension2D: 4,
+        maxImageDimension3D: 8, maxImageDimensionCube: 12, maxImageArrayLayers: 16,
+        maxTexelBufferElements: 20, maxUniformBufferRange: 24, maxStorageBufferRange: 28,
+        maxPushConstantsSize: 32, maxMemoryAllocationCount: 36, maxSamplerAllocationCount: 40,
+        bufferImageGranularity: 48, sparseAddressSpaceSize: 56, maxBoundDescriptorSets: 64,
+        maxPerStageDescriptorSamplers: 68, maxPerStageDescriptorUniformBuffers: 72,
+        maxPerStageDescriptorStorageBuffers: 76, maxPerStageDescriptorSampledImages: 80,
+        maxPerStageDescriptorStorageImages: 84, maxPerStageDescriptorInputAttachments: 88,
+        maxPerStageResources: 92, maxDescriptorSetSamplers: 96,
+        maxDescriptorSetUniformBuffers: 100, maxDescriptorSetUniformBuffersDynamic: 104,
+        maxDescriptorSetStorageBuffers: 108, maxDescriptorSetStorageBuffersDynamic: 112,
+        maxDescriptorSetSampledImages: 116, maxDescriptorSetStorageImages: 120,
+        maxDescriptorSetInputAttachments: 124, maxVertexInputAttributes: 128,
+        maxVertexInputBindings: 132, maxVertexInputAttributeOffset: 136,
+        maxVertexInputBindingStride: 140, maxVertexOutputComponents: 144,
+        maxTessellationGenerationLevel: 148, maxTessellationPatchSize: 152,
+        maxTessellationControlPerVertexInputComponents: 156,
+        maxTessellationControlPerVertexOutputComponents: 160,
+        maxTessellationControlPerPatchOutputComponents: 164,
+        maxTessellationControlTotalOutputComponents: 168,
+        maxTessellationEvaluationInputComponents: 172,
+        maxTessellationEvaluationOutputComponents: 176, maxGeometryShaderInvocations: 180,
+        maxGeometryInputComponents: 184, maxGeometryOutputComponents: 188,
+        maxGeometryOutputVertices: 192, maxGeometryTotalOutputComponents: 196,
+        maxFragmentInputComponents: 200, maxFragmentOutputAttachments: 204,
+        maxFragmentDualSrcAttachments: 208, maxFragmentCombinedOutputResources: 212,
+        maxComputeSharedMemorySize: 216, maxComputeWorkGroupCount: 220,
+        maxComputeWorkGroupInvocations: 232, maxComputeWorkGroupSize: 236,
+        subPixelPrecisionBits: 248, subTexelPrecisionBits: 252, mipmapPrecisionBits: 256,
+        maxDrawIndexedIndexValue: 260, maxDrawIndirectCount: 264, maxSamplerLodBias: 268,
+        maxSamplerAnisotropy: 272, maxViewports: 276, maxViewportDimensions: 280,
+        viewportBoundsRange: 288, viewportSubPixelBits: 296, minMemoryMapAlignment: 300,
+        minTexelBufferOffsetAlignment: 304, minUniformBufferOffsetAlignment: 312,
+        minStorageBufferOffsetAlignment: 320, minTexelOffset: 328, maxTexelOffset: 332,
+        minTexelGatherOffset: 336, maxTexelGatherOffset: 340, minInterpolationOffset: 344,
+        maxInterpolationOffset: 348, subPixelInterpolationOffsetBits: 352,
+        maxFramebufferWidth: 356, maxFramebufferHeight: 360, maxFramebufferLayers: 364,
+        framebufferColorSampleCounts: 368, framebufferDepthSampleCounts: 372,
+        framebufferStencilSampleCounts: 376, framebufferNoAttachmentsSampleCounts: 380,
+        maxColorAttachments: 384, sampledImageColorSampleCounts: 388,
+        sampledImageIntegerSampleCounts: 392, sampledImageDepthSampleCounts: 396,
+        sampledImageStencilSampleCounts: 400, storageImageSampleCounts: 404,
+        maxSampleMaskWords: 408, timestampComputeAndGraphics: 412, timestampPeriod: 416,
+        maxClipDistances: 420, maxCullDistances: 424, maxCombinedClipAndCullDistances: 428,
+        discreteQueuePriorities: 432, pointSizeRange: 436, lineWidthRange: 444,
+        pointSizeGranularity: 452, lineWidthGranularity: 456, strictLines: 460,
+        standardSampleLocations: 464, optimalBufferCopyOffsetAlignment: 472,
+        optimalBufferCopyRowPitchAlignment: 480, nonCoherentAtomSize: 488);
+    check_layout!(vk::PhysicalDeviceSparseProperties, 20, 4, residencyStandard2DBlockShape: 0,
+        residencyStandard2DMultisampleBlockShape: 4, residencyStandard3DBlockShape: 8,
+        residencyAlignedMipSize: 12, residencyNonResidentStrict: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceProperties, 824, 8, apiVersion: 0, driverVersion: 4,
+        vendorID: 8, deviceID: 12, deviceType: 16, deviceName: 20, pipelineCacheUUID: 276,
+        limits: 296, sparseProperties: 800);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceProperties, 800, 4, apiVersion: 0, driverVersion: 4,
+        vendorID: 8, deviceID: 12, deviceType: 16, deviceName: 20, pipelineCacheUUID: 276,
+        limits: 292, sparseProperties: 780);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceProperties, 816, 8, apiVersion: 0, driverVersion: 4,
+        vendorID: 8, deviceID: 12, deviceType: 16, deviceName: 20, pipelineCacheUUID: 276,
+        limits: 296, sparseProperties: 792);
+    check_layout!(vk::QueueFamilyProperties, 24, 4, queueFlags: 0, queueCount: 4,
+        timestampValidBits: 8, minImageTransferGranularity: 12);
+    check_layout!(vk::MemoryType, 8, 4, propertyFlags: 0, heapIndex: 4);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::MemoryHeap, 16, 8, size: 0, flags: 8);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryHeap, 12, 4, size: 0, flags: 8);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::PhysicalDeviceMemoryProperties, 520, 8, memoryTypeCount: 0, memoryTypes: 4,
+        memoryHeapCount: 260, memoryHeaps: 264);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceMemoryProperties, 456, 4, memoryTypeCount: 0, memoryTypes: 4,
+        memoryHeapCount: 260, memoryHeaps: 264);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceQueueCreateInfo, 40, 8, sType: 0, pNext: 8, flags: 16,
+        queueFamilyIndex: 20, queueCount: 24, pQueuePriorities: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceQueueCreateInfo, 24, 4, sType: 0, pNext: 4, flags: 8,
+        queueFamilyIndex: 12, queueCount: 16, pQueuePriorities: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceCreateInfo, 72, 8, sType: 0, pNext: 8, flags: 16,
+        queueCreateInfoCount: 20, pQueueCreateInfos: 24, enabledLayerCount: 32,
+        ppEnabledLayerNames: 40, enabledExtensionCount: 48, ppEnabledExtensionNames: 56,
+        pEnabledFeatures: 64);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceCreateInfo, 40, 4, sType: 0, pNext: 4, flags: 8,
+        queueCreateInfoCount: 12, pQueueCreateInfos: 16, enabledLayerCount: 20,
+        ppEnabledLayerNames: 24, enabledExtensionCount: 28, ppEnabledExtensionNames: 32,
+        pEnabledFeatures: 36);
+    check_layout!(vk::ExtensionProperties, 260, 4, extensionName: 0, specVersion: 256);
+    check_layout!(vk::LayerProperties, 520, 4, layerName: 0, specVersion: 256,
+        implementationVersion: 260, description: 264);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubmitInfo, 72, 8, sType: 0, pNext: 8, waitSemaphoreCount: 16,
+        pWaitSemaphores: 24, pWaitDstStageMask: 32, commandBufferCount: 40, pCommandBuffers: 48,
+        signalSemaphoreCount: 56, pSignalSemaphores: 64);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubmitInfo, 36, 4, sType: 0, pNext: 4, waitSemaphoreCount: 8,
+        pWaitSemaphores: 12, pWaitDstStageMask: 16, commandBufferCount: 20, pCommandBuffers: 24,
+        signalSemaphoreCount: 28, pSignalSemaphores: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryAllocateInfo, 32, 8, sType: 0, pNext: 8, allocationSize: 16,
+        memoryTypeIndex: 24);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryAllocateInfo, 20, 4, sType: 0, pNext: 4, allocationSize: 8,
+        memoryTypeIndex: 16);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::MemoryAllocateInfo, 24, 8, sType: 0, pNext: 4, allocationSize: 8,
+        memoryTypeIndex: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MappedMemoryRange, 40, 8, sType: 0, pNext: 8, memory: 16, offset: 24,
+        size: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MappedMemoryRange, 32, 4, sType: 0, pNext: 4, memory: 8, offset: 16,
+        size: 24);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::MappedMemoryRange, 32, 8, sType: 0, pNext: 4, memory: 8, offset: 16,
+        size: 24);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::MemoryRequirements, 24, 8, size: 0, alignment: 8, memoryTypeBits: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryRequirements, 20, 4, size: 0, alignment: 8, memoryTypeBits: 16);
+    check_layout!(vk::SparseImageFormatProperties, 20, 4, aspectMask: 0, imageGranularity: 4,
+        flags: 16);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::SparseImageMemoryRequirements, 48, 8, formatProperties: 0,
+        imageMipTailFirstLod: 20, imageMipTailSize: 24, imageMipTailOffset: 32,
+        imageMipTailStride: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseImageMemoryRequirements, 48, 4, formatProperties: 0,
+        imageMipTailFirstLod: 20, imageMipTailSize: 24, imageMipTailOffset: 32,
+        imageMipTailStride: 40);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::SparseMemoryBind, 40, 8, resourceOffset: 0, size: 8, memory: 16,
+        memoryOffset: 24, flags: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseMemoryBind, 36, 4, resourceOffset: 0, size: 8, memory: 16,
+        memoryOffset: 24, flags: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SparseBufferMemoryBindInfo, 24, 8, buffer: 0, bindCount: 8, pBinds: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseBufferMemoryBindInfo, 16, 4, buffer: 0, bindCount: 8, pBinds: 12);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SparseBufferMemoryBindInfo, 16, 8, buffer: 0, bindCount: 8, pBinds: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SparseImageOpaqueMemoryBindInfo, 24, 8, image: 0, bindCount: 8, pBinds: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseImageOpaqueMemoryBindInfo, 16, 4, image: 0, bindCount: 8, pBinds: 12);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SparseImageOpaqueMemoryBindInfo, 16, 8, image: 0, bindCount: 8, pBinds: 12);
+    check_layout!(vk::ImageSubresource, 12, 4, aspectMask: 0, mipLevel: 4, arrayLayer: 8);
+    check_layout!(vk::Offset3D, 12, 4, x: 0, y: 4, z: 8);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::SparseImageMemoryBind, 64, 8, subresource: 0, offset: 12, extent: 24,
+        memory: 40, memoryOffset: 48, flags: 56);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseImageMemoryBind, 56, 4, subresource: 0, offset: 12, extent: 24,
+        memory: 36, memoryOffset: 44, flags: 52);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SparseImageMemoryBindInfo, 24, 8, image: 0, bindCount: 8, pBinds: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseImageMemoryBindInfo, 16, 4, image: 0, bindCount: 8, pBinds: 12);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SparseImageMemoryBindInfo, 16, 8, image: 0, bindCount: 8, pBinds: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BindSparseInfo, 96, 8, sType: 0, pNext: 8, waitSemaphoreCount: 16,
+        pWaitSemaphores: 24, bufferBindCount: 32, pBufferBinds: 40, imageOpaqueBindCount: 48,
+        pImageOpaqueBinds: 56, imageBindCount: 64, pImageBinds: 72, signalSemaphoreCount: 80,
+        pSignalSemaphores: 88);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::BindSparseInfo, 48, 4, sType: 0, pNext: 4, waitSemaphoreCount: 8,
+        pWaitSemaphores: 12, bufferBindCount: 16, pBufferBinds: 20, imageOpaqueBindCount: 24,
+        pImageOpaqueBinds: 28, imageBindCount: 32, pImageBinds: 36, signalSemaphoreCount: 40,
+        pSignalSemaphores: 44);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::FenceCreateInfo, 24, 8, sType: 0, pNext: 8, flags: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::FenceCreateInfo, 12, 4, sType: 0, pNext: 4, flags: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SemaphoreCreateInfo, 24, 8, sType: 0, pNext: 8, flags: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SemaphoreCreateInfo, 12, 4, sType: 0, pNext: 4, flags: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::EventCreateInfo, 24, 8, sType: 0, pNext: 8, flags: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::EventCreateInfo, 12, 4, sType: 0, pNext: 4, flags: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::QueryPoolCreateInfo, 32, 8, sType: 0, pNext: 8, flags: 16, queryType: 20,
+        queryCount: 24, pipelineStatistics: 28);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::QueryPoolCreateInfo, 24, 4, sType: 0, pNext: 4, flags: 8, queryType: 12,
+        queryCount: 16, pipelineStatistics: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferCreateInfo, 56, 8, sType: 0, pNext: 8, flags: 16, size: 24, usage: 32,
+        sharingMode: 36, queueFamilyIndexCount: 40, pQueueFamilyIndices: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferCreateInfo, 36, 4, sType: 0, pNext: 4, flags: 8, size: 12, usage: 20,
+        sharingMode: 24, queueFamilyIndexCount: 28, pQueueFamilyIndices: 32);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferCreateInfo, 40, 8, sType: 0, pNext: 4, flags: 8, size: 16, usage: 24,
+        sharingMode: 28, queueFamilyIndexCount: 32, pQueueFamilyIndices: 36);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferViewCreateInfo, 56, 8, sType: 0, pNext: 8, flags: 16, buffer: 24,
+        format: 32, offset: 40, range: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferViewCreateInfo, 40, 4, sType: 0, pNext: 4, flags: 8, buffer: 12,
+        format: 20, offset: 24, range: 32);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferViewCreateInfo, 48, 8, sType: 0, pNext: 4, flags: 8, buffer: 16,
+        format: 24, offset: 32, range: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageCreateInfo, 88, 8, sType: 0, pNext: 8, flags: 16, imageType: 20,
+        format: 24, extent: 28, mipLevels: 40, arrayLayers: 44, samples: 48, tiling: 52, usage: 56,
+        sharingMode: 60, queueFamilyIndexCount: 64, pQueueFamilyIndices: 72, initialLayout: 80);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageCreateInfo, 68, 4, sType: 0, pNext: 4, flags: 8, imageType: 12,
+        format: 16, extent: 20, mipLevels: 32, arrayLayers: 36, samples: 40, tiling: 44, usage: 48,
+        sharingMode: 52, queueFamilyIndexCount: 56, pQueueFamilyIndices: 60, initialLayout: 64);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::SubresourceLayout, 40, 8, offset: 0, size: 8, rowPitch: 16, arrayPitch: 24,
+        depthPitch: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SubresourceLayout, 40, 4, offset: 0, size: 8, rowPitch: 16, arrayPitch: 24,
+        depthPitch: 32);
+    check_layout!(vk::ComponentMapping, 16, 4, r: 0, g: 4, b: 8, a: 12);
+    check_layout!(vk::ImageSubresourceRange, 20, 4, aspectMask: 0, baseMipLevel: 4, levelCount: 8,
+        baseArrayLayer: 12, layerCount: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageViewCreateInfo, 80, 8, sType: 0, pNext: 8, flags: 16, image: 24,
+        viewType: 32, format: 36, components: 40, subresourceRange: 56);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageViewCreateInfo, 64, 4, sType: 0, pNext: 4, flags: 8, image: 12,
+        viewType: 20, format: 24, components: 28, subresourceRange: 44);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ImageViewCreateInfo, 72, 8, sType: 0, pNext: 4, flags: 8, image: 16,
+        viewType: 24, format: 28, components: 32, subresourceRange: 48);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ShaderModuleCreateInfo, 40, 8, sType: 0, pNext: 8, flags: 16, codeSize: 24,
+        pCode: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ShaderModuleCreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8, codeSize: 12,
+        pCode: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineCacheCreateInfo, 40, 8, sType: 0, pNext: 8, flags: 16,
+        initialDataSize: 24, pInitialData: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineCacheCreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        initialDataSize: 12, pInitialData: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SpecializationMapEntry, 16, 8, constantID: 0, offset: 4, size: 8);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SpecializationMapEntry, 12, 4, constantID: 0, offset: 4, size: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SpecializationInfo, 32, 8, mapEntryCount: 0, pMapEntries: 8, dataSize: 16,
+        pData: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SpecializationInfo, 16, 4, mapEntryCount: 0, pMapEntries: 4, dataSize: 8,
+        pData: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineShaderStageCreateInfo, 48, 8, sType: 0, pNext: 8, flags: 16,
+        stage: 20, module: 24, pName: 32, pSpecializationInfo: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PipelineShaderStageCreateInfo, 32, 4, sType: 0, pNext: 4, flags: 8,
+        stage: 12, module: 16, pName: 24, pSpecializationInfo: 28);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PipelineShaderStageCreateInfo, 32, 8, sType: 0, pNext: 4, flags: 8,
+        stage: 12, module: 16, pName: 24, pSpecializationInfo: 28);
+    check_layout!(vk::VertexInputBindingDescription, 12, 4, binding: 0, stride: 4, inputRate: 8);
+    check_layout!(vk::VertexInputAttributeDescription, 16, 4, location: 0, binding: 4, format: 8,
+        offset: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineVertexInputStateCreateInfo, 48, 8, sType: 0, pNext: 8, flags: 16,
+        vertexBindingDescriptionCount: 20, pVertexBindingDescriptions: 24,
+        vertexAttributeDescriptionCount: 32, pVertexAttributeDescriptions: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineVertexInputStateCreateInfo, 28, 4, sType: 0, pNext: 4, flags: 8,
+        vertexBindingDescriptionCount: 12, pVertexBindingDescriptions: 16,
+        vertexAttributeDescriptionCount: 20, pVertexAttributeDescriptions: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineInputAssemblyStateCreateInfo, 32, 8, sType: 0, pNext: 8, flags: 16,
+        topology: 20, primitiveRestartEnable: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineInputAssemblyStateCreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        topology: 12, primitiveRestartEnable: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineTessellationStateCreateInfo, 24, 8, sType: 0, pNext: 8, flags: 16,
+        patchControlPoints: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineTessellationStateCreateInfo, 16, 4, sType: 0, pNext: 4, flags: 8,
+        patchControlPoints: 12);
+    check_layout!(vk::Viewport, 24, 4, x: 0, y: 4, width: 8, height: 12, minDepth: 16,
+        maxDepth: 20);
+    check_layout!(vk::Offset2D, 8, 4, x: 0, y: 4);
+    check_layout!(vk::Extent2D, 8, 4, width: 0, height: 4);
+    check_layout!(vk::Rect2D, 16, 4, offset: 0, extent: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineViewportStateCreateInfo, 48, 8, sType: 0, pNext: 8, flags: 16,
+        viewportCount: 20, pViewports: 24, scissorCount: 32, pScissors: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineViewportStateCreateInfo, 28, 4, sType: 0, pNext: 4, flags: 8,
+        viewportCount: 12, pViewports: 16, scissorCount: 20, pScissors: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineRasterizationStateCreateInfo, 64, 8, sType: 0, pNext: 8, flags: 16,
+        depthClampEnable: 20, rasterizerDiscardEnable: 24, polygonMode: 28, cullMode: 32,
+        frontFace: 36, depthBiasEnable: 40, depthBiasConstantFactor: 44, depthBiasClamp: 48,
+        depthBiasSlopeFactor: 52, lineWidth: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineRasterizationStateCreateInfo, 52, 4, sType: 0, pNext: 4, flags: 8,
+        depthClampEnable: 12, rasterizerDiscardEnable: 16, polygonMode: 20, cullMode: 24,
+        frontFace: 28, depthBiasEnable: 32, depthBiasConstantFactor: 36, depthBiasClamp: 40,
+        depthBiasSlopeFactor: 44, lineWidth: 48);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineMultisampleStateCreateInfo, 48, 8, sType: 0, pNext: 8, flags: 16,
+        rasterizationSamples: 20, sampleShadingEnable: 24, minSampleShading: 28, pSampleMask: 32,
+        alphaToCoverageEnable: 40, alphaToOneEnable: 44);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineMultisampleStateCreateInfo, 36, 4, sType: 0, pNext: 4, flags: 8,
+        rasterizationSamples: 12, sampleShadingEnable: 16, minSampleShading: 20, pSampleMask: 24,
+        alphaToCoverageEnable: 28, alphaToOneEnable: 32);
+    check_layout!(vk::StencilOpState, 28, 4, failOp: 0, passOp: 4, depthFailOp: 8, compareOp: 12,
+        compareMask: 16, writeMask: 20, reference: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineDepthStencilStateCreateInfo, 104, 8, sType: 0, pNext: 8, flags: 16,
+        depthTestEnable: 20, depthWriteEnable: 24, depthCompareOp: 28, depthBoundsTestEnable: 32,
+        stencilTestEnable: 36, front: 40, back: 68, minDepthBounds: 96, maxDepthBounds: 100);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineDepthStencilStateCreateInfo, 96, 4, sType: 0, pNext: 4, flags: 8,
+        depthTestEnable: 12, depthWriteEnable: 16, depthCompareOp: 20, depthBoundsTestEnable: 24,
+        stencilTestEnable: 28, front: 32, back: 60, minDepthBounds: 88, maxDepthBounds: 92);
+    check_layout!(vk::PipelineColorBlendAttachmentState, 32, 4, blendEnable: 0,
+        srcColorBlendFactor: 4, dstColorBlendFactor: 8, colorBlendOp: 12, srcAlphaBlendFactor: 16,
+        dstAlphaBlendFactor: 20, alphaBlendOp: 24, colorWriteMask: 28);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineColorBlendStateCreateInfo, 56, 8, sType: 0, pNext: 8, flags: 16,
+        logicOpEnable: 20, logicOp: 24, attachmentCount: 28, pAttachments: 32, blendConstants: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineColorBlendStateCreateInfo, 44, 4, sType: 0, pNext: 4, flags: 8,
+        logicOpEnable: 12, logicOp: 16, attachmentCount: 20, pAttachments: 24, blendConstants: 28);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineDynamicStateCreateInfo, 32, 8, sType: 0, pNext: 8, flags: 16,
+        dynamicStateCount: 20, pDynamicStates: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineDynamicStateCreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        dynamicStateCount: 12, pDynamicStates: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::GraphicsPipelineCreateInfo, 144, 8, sType: 0, pNext: 8, flags: 16,
+        stageCount: 20, pStages: 24, pVertexInputState: 32, pInputAssemblyState: 40,
+        pTessellationState: 48, pViewportState: 56, pRasterizationState: 64, pMultisampleState: 72,
+        pDepthStencilState: 80, pColorBlendState: 88, pDynamicState: 96, layout: 104,
+        renderPass: 112, subpass: 120, basePipelineHandle: 128, basePipelineIndex: 136);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::GraphicsPipelineCreateInfo, 88, 4, sType: 0, pNext: 4, flags: 8,
+        stageCount: 12, pStages: 16, pVertexInputState: 20, pInputAssemblyState: 24,
+        pTessellationState: 28, pViewportState: 32, pRasterizationState: 36, pMultisampleState: 40,
+        pDepthStencilState: 44, pColorBlendState: 48, pDynamicState: 52, layout: 56,
+        renderPass: 64, subpass: 72, basePipelineHandle: 76, basePipelineIndex: 84);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::GraphicsPipelineCreateInfo, 96, 8, sType: 0, pNext: 4, flags: 8,
+        stageCount: 12, pStages: 16, pVertexInputState: 20, pInputAssemblyState: 24,
+        pTessellationState: 28, pViewportState: 32, pRasterizationState: 36, pMultisampleState: 40,
+        pDepthStencilState: 44, pColorBlendState: 48, pDynamicState: 52, layout: 56,
+        renderPass: 64, subpass: 72, basePipelineHandle: 80, basePipelineIndex: 88);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ComputePipelineCreateInfo, 96, 8, sType: 0, pNext: 8, flags: 16, stage: 24,
+        layout: 72, basePipelineHandle: 80, basePipelineIndex: 88);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ComputePipelineCreateInfo, 64, 4, sType: 0, pNext: 4, flags: 8, stage: 12,
+        layout: 44, basePipelineHandle: 52, basePipelineIndex: 60);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ComputePipelineCreateInfo, 72, 8, sType: 0, pNext: 4, flags: 8, stage: 16,
+        layout: 48, basePipelineHandle: 56, basePipelineIndex: 64);
+    check_layout!(vk::PushConstantRange, 12, 4, stageFlags: 0, offset: 4, size: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineLayoutCreateInfo, 48, 8, sType: 0, pNext: 8, flags: 16,
+        setLayoutCount: 20, pSetLayouts: 24, pushConstantRangeCount: 32, pPushConstantRanges: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineLayoutCreateInfo, 28, 4, sType: 0, pNext: 4, flags: 8,
+        setLayoutCount: 12, pSetLayouts: 16, pushConstantRangeCount: 20, pPushConstantRanges: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SamplerCreateInfo, 80, 8, sType: 0, pNext: 8, flags: 16, magFilter: 20,
+        minFilter: 24, mipmapMode: 28, addressModeU: 32, addressModeV: 36, addressModeW: 40,
+        mipLodBias: 44, anisotropyEnable: 48, maxAnisotropy: 52, compareEnable: 56, compareOp: 60,
+        minLod: 64, maxLod: 68, borderColor: 72, unnormalizedCoordinates: 76);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SamplerCreateInfo, 72, 4, sType: 0, pNext: 4, flags: 8, magFilter: 12,
+        minFilter: 16, mipmapMode: 20, addressModeU: 24, addressModeV: 28, addressModeW: 32,
+        mipLodBias: 36, anisotropyEnable: 40, maxAnisotropy: 44, compareEnable: 48, compareOp: 52,
+        minLod: 56, maxLod: 60, borderColor: 64, unnormalizedCoordinates: 68);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetLayoutBinding, 24, 8, binding: 0, descriptorType: 4,
+        descriptorCount: 8, stageFlags: 12, pImmutableSamplers: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorSetLayoutBinding, 20, 4, binding: 0, descriptorType: 4,
+        descriptorCount: 8, stageFlags: 12, pImmutableSamplers: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetLayoutCreateInfo, 32, 8, sType: 0, pNext: 8, flags: 16,
+        bindingCount: 20, pBindings: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorSetLayoutCreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        bindingCount: 12, pBindings: 16);
+    check_layout!(vk::DescriptorPoolSize, 8, 4, typ: 0, descriptorCount: 4);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorPoolCreateInfo, 40, 8, sType: 0, pNext: 8, flags: 16, maxSets: 20,
+        poolSizeCount: 24, pPoolSizes: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorPoolCreateInfo, 24, 4, sType: 0, pNext: 4, flags: 8, maxSets: 12,
+        poolSizeCount: 16, pPoolSizes: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetAllocateInfo, 40, 8, sType: 0, pNext: 8, descriptorPool: 16,
+        descriptorSetCount: 24, pSetLayouts: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::DescriptorSetAllocateInfo, 24, 4, sType: 0, pNext: 4, descriptorPool: 8,
+        descriptorSetCount: 16, pSetLayouts: 20);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::DescriptorSetAllocateInfo, 24, 8, sType: 0, pNext: 4, descriptorPool: 8,
+        descriptorSetCount: 16, pSetLayouts: 20);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::DescriptorImageInfo, 24, 8, sampler: 0, imageView: 8, imageLayout: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::DescriptorImageInfo, 20, 4, sampler: 0, imageView: 8, imageLayout: 16);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::DescriptorBufferInfo, 24, 8, buffer: 0, offset: 8, range: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::DescriptorBufferInfo, 24, 4, buffer: 0, offset: 8, range: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::WriteDescriptorSet, 64, 8, sType: 0, pNext: 8, dstSet: 16, dstBinding: 24,
+        dstArrayElement: 28, descriptorCount: 32, descriptorType: 36, pImageInfo: 40,
+        pBufferInfo: 48, pTexelBufferView: 56);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::WriteDescriptorSet, 44, 4, sType: 0, pNext: 4, dstSet: 8, dstBinding: 16,
+        dstArrayElement: 20, descriptorCount: 24, descriptorType: 28, pImageInfo: 32,
+        pBufferInfo: 36, pTexelBufferView: 40);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::WriteDescriptorSet, 48, 8, sType: 0, pNext: 4, dstSet: 8, dstBinding: 16,
+        dstArrayElement: 20, descriptorCount: 24, descriptorType: 28, pImageInfo: 32,
+        pBufferInfo: 36, pTexelBufferView: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CopyDescriptorSet, 56, 8, sType: 0, pNext: 8, srcSet: 16, srcBinding: 24,
+        srcArrayElement: 28, dstSet: 32, dstBinding: 40, dstArrayElement: 44, descriptorCount: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CopyDescriptorSet, 44, 4, sType: 0, pNext: 4, srcSet: 8, srcBinding: 16,
+        srcArrayElement: 20, dstSet: 24, dstBinding: 32, dstArrayElement: 36, descriptorCount: 40);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CopyDescriptorSet, 48, 8, sType: 0, pNext: 4, srcSet: 8, srcBinding: 16,
+        srcArrayElement: 20, dstSet: 24, dstBinding: 32, dstArrayElement: 36, descriptorCount: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::FramebufferCreateInfo, 64, 8, sType: 0, pNext: 8, flags: 16, renderPass: 24,
+        attachmentCount: 32, pAttachments: 40, width: 48, height: 52, layers: 56);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::FramebufferCreateInfo, 40, 4, sType: 0, pNext: 4, flags: 8, renderPass: 12,
+        attachmentCount: 20, pAttachments: 24, width: 28, height: 32, layers: 36);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::FramebufferCreateInfo, 48, 8, sType: 0, pNext: 4, flags: 8, renderPass: 16,
+        attachmentCount: 24, pAttachments: 28, width: 32, height: 36, layers: 40);
+    check_layout!(vk::AttachmentDescription, 36, 4, flags: 0, format: 4, samples: 8, loadOp: 12,
+        storeOp: 16, stencilLoadOp: 20, stencilStoreOp: 24, initialLayout: 28, finalLayout: 32);
+    check_layout!(vk::AttachmentReference, 8, 4, attachment: 0, layout: 4);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubpassDescription, 72, 8, flags: 0, pipelineBindPoint: 4,
+        inputAttachmentCount: 8, pInputAttachments: 16, colorAttachmentCount: 24,
+        pColorAttachments: 32, pResolveAttachments: 40, pDepthStencilAttachment: 48,
+        preserveAttachmentCount: 56, pPreserveAttachments: 64);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubpassDescription, 40, 4, flags: 0, pipelineBindPoint: 4,
+        inputAttachmentCount: 8, pInputAttachments: 12, colorAttachmentCount: 16,
+        pColorAttachments: 20, pResolveAttachments: 24, pDepthStencilAttachment: 28,
+        preserveAttachmentCount: 32, pPreserveAttachments: 36);
+    check_layout!(vk::SubpassDependency, 28, 4, srcSubpass: 0, dstSubpass: 4, srcStageMask: 8,
+        dstStageMask: 12, srcAccessMask: 16, dstAccessMask: 20, dependencyFlags: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderPassCreateInfo, 64, 8, sType: 0, pNext: 8, flags: 16,
+        attachmentCount: 20, pAttachments: 24, subpassCount: 32, pSubpasses: 40,
+        dependencyCount: 48, pDependencies: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::RenderPassCreateInfo, 36, 4, sType: 0, pNext: 4, flags: 8,
+        attachmentCount: 12, pAttachments: 16, subpassCount: 20, pSubpasses: 24,
+        dependencyCount: 28, pDependencies: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CommandPoolCreateInfo, 24, 8, sType: 0, pNext: 8, flags: 16,
+        queueFamilyIndex: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::CommandPoolCreateInfo, 16, 4, sType: 0, pNext: 4, flags: 8,
+        queueFamilyIndex: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CommandBufferAllocateInfo, 32, 8, sType: 0, pNext: 8, commandPool: 16,
+        level: 24, commandBufferCount: 28);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CommandBufferAllocateInfo, 24, 4, sType: 0, pNext: 4, commandPool: 8,
+        level: 16, commandBufferCount: 20);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CommandBufferAllocateInfo, 24, 8, sType: 0, pNext: 4, commandPool: 8,
+        level: 16, commandBufferCount: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CommandBufferInheritanceInfo, 56, 8, sType: 0, pNext: 8, renderPass: 16,
+        subpass: 24, framebuffer: 32, occlusionQueryEnable: 40, queryFlags: 44,
+        pipelineStatistics: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CommandBufferInheritanceInfo, 40, 4, sType: 0, pNext: 4, renderPass: 8,
+        subpass: 16, framebuffer: 20, occlusionQueryEnable: 28, queryFlags: 32,
+        pipelineStatistics: 36);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CommandBufferInheritanceInfo, 48, 8, sType: 0, pNext: 4, renderPass: 8,
+        subpass: 16, framebuffer: 24, occlusionQueryEnable: 32, queryFlags: 36,
+        pipelineStatistics: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CommandBufferBeginInfo, 32, 8, sType: 0, pNext: 8, flags: 16,
+        pInheritanceInfo: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::CommandBufferBeginInfo, 16, 4, sType: 0, pNext: 4, flags: 8,
+        pInheritanceInfo: 12);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::BufferCopy, 24, 8, srcOffset: 0, dstOffset: 8, size: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferCopy, 24, 4, srcOffset: 0, dstOffset: 8, size: 16);
+    check_layout!(vk::ImageSubresourceLayers, 16, 4, aspectMask: 0, mipLevel: 4, baseArrayLayer: 8,
+        layerCount: 12);
+    check_layout!(vk::ImageCopy, 68, 4, srcSubresource: 0, srcOffset: 16, dstSubresource: 28,
+        dstOffset: 44, extent: 56);
+    check_layout!(vk::ImageBlit, 80, 4, srcSubresource: 0, srcOffsets: 16, dstSubresource: 40,
+        dstOffsets: 56);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::BufferImageCopy, 56, 8, bufferOffset: 0, bufferRowLength: 8,
+        bufferImageHeight: 12, imageSubresource: 16, imageOffset: 32, imageExtent: 44);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferImageCopy, 56, 4, bufferOffset: 0, bufferRowLength: 8,
+        bufferImageHeight: 12, imageSubresource: 16, imageOffset: 32, imageExtent: 44);
+    check_layout!(vk::ClearDepthStencilValue, 8, 4, depth: 0, stencil: 4);
+    check_layout!(vk::ClearAttachment, 24, 4, aspectMask: 0, colorAttachment: 4, clearValue: 8);
+    check_layout!(vk::ClearRect, 24, 4, rect: 0, baseArrayLayer: 16, layerCount: 20);
+    check_layout!(vk::ImageResolve, 68, 4, srcSubresource: 0, srcOffset: 16, dstSubresource: 28,
+        dstOffset: 44, extent: 56);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryBarrier, 24, 8, sType: 0, pNext: 8, srcAccessMask: 16,
+        dstAccessMask: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::MemoryBarrier, 16, 4, sType: 0, pNext: 4, srcAccessMask: 8,
+        dstAccessMask: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferMemoryBarrier, 56, 8, sType: 0, pNext: 8, srcAccessMask: 16,
+        dstAccessMask: 20, srcQueueFamilyIndex: 24, dstQueueFamilyIndex: 28, buffer: 32,
+        offset: 40, size: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferMemoryBarrier, 48, 4, sType: 0, pNext: 4, srcAccessMask: 8,
+        dstAccessMask: 12, srcQueueFamilyIndex: 16, dstQueueFamilyIndex: 20, buffer: 24,
+        offset: 32, size: 40);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferMemoryBarrier, 48, 8, sType: 0, pNext: 4, srcAccessMask: 8,
+        dstAccessMask: 12, srcQueueFamilyIndex: 16, dstQueueFamilyIndex: 20, buffer: 24,
+        offset: 32, size: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageMemoryBarrier, 72, 8, sType: 0, pNext: 8, srcAccessMask: 16,
+        dstAccessMask: 20, oldLayout: 24, newLayout: 28, srcQueueFamilyIndex: 32,
+        dstQueueFamilyIndex: 36, image: 40, subresourceRange: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageMemoryBarrier, 60, 4, sType: 0, pNext: 4, srcAccessMask: 8,
+        dstAccessMask: 12, oldLayout: 16, newLayout: 20, srcQueueFamilyIndex: 24,
+        dstQueueFamilyIndex: 28, image: 32, subresourceRange: 40);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ImageMemoryBarrier, 64, 8, sType: 0, pNext: 4, srcAccessMask: 8,
+        dstAccessMask: 12, oldLayout: 16, newLayout: 20, srcQueueFamilyIndex: 24,
+        dstQueueFamilyIndex: 28, image: 32, subresourceRange: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderPassBeginInfo, 64, 8, sType: 0, pNext: 8, renderPass: 16,
+        framebuffer: 24, renderArea: 32, clearValueCount: 48, pClearValues: 56);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::RenderPassBeginInfo, 48, 4, sType: 0, pNext: 4, renderPass: 8,
+        framebuffer: 16, renderArea: 24, clearValueCount: 40, pClearValues: 44);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::RenderPassBeginInfo, 48, 8, sType: 0, pNext: 4, renderPass: 8,
+        framebuffer: 16, renderArea: 24, clearValueCount: 40, pClearValues: 44);
+    check_layout!(vk::DispatchIndirectCommand, 12, 4, x: 0, y: 4, z: 8);
+    check_layout!(vk::DrawIndexedIndirectCommand, 20, 4, indexCount: 0, instanceCount: 4,
+        firstIndex: 8, vertexOffset: 12, firstInstance: 16);
+    check_layout!(vk::DrawIndirectCommand, 16, 4, vertexCount: 0, instanceCount: 4, firstVertex: 8,
+        firstInstance: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSubgroupProperties, 32, 8, sType: 0, pNext: 8,
+        subgroupSize: 16, supportedStages: 20, supportedOperations: 24,
+        quadOperationsInAllStages: 28);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSubgroupProperties, 24, 4, sType: 0, pNext: 4, subgroupSize: 8,
+        supportedStages: 12, supportedOperations: 16, quadOperationsInAllStages: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BindBufferMemoryInfo, 40, 8, sType: 0, pNext: 8, buffer: 16, memory: 24,
+        memoryOffset: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BindBufferMemoryInfo, 32, 4, sType: 0, pNext: 4, buffer: 8, memory: 16,
+        memoryOffset: 24);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BindBufferMemoryInfo, 32, 8, sType: 0, pNext: 4, buffer: 8, memory: 16,
+        memoryOffset: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BindImageMemoryInfo, 40, 8, sType: 0, pNext: 8, image: 16, memory: 24,
+        memoryOffset: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BindImageMemoryInfo, 32, 4, sType: 0, pNext: 4, image: 8, memory: 16,
+        memoryOffset: 24);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BindImageMemoryInfo, 32, 8, sType: 0, pNext: 4, image: 8, memory: 16,
+        memoryOffset: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDevice16BitStorageFeatures, 32, 8, sType: 0, pNext: 8,
+        storageBuffer16BitAccess: 16, uniformAndStorageBuffer16BitAccess: 20,
+        storagePushConstant16: 24, storageInputOutput16: 28);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDevice16BitStorageFeatures, 24, 4, sType: 0, pNext: 4,
+        storageBuffer16BitAccess: 8, uniformAndStorageBuffer16BitAccess: 12,
+        storagePushConstant16: 16, storageInputOutput16: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryDedicatedRequirements, 24, 8, sType: 0, pNext: 8,
+        prefersDedicatedAllocation: 16, requiresDedicatedAllocation: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::MemoryDedicatedRequirements, 16, 4, sType: 0, pNext: 4,
+        prefersDedicatedAllocation: 8, requiresDedicatedAllocation: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryDedicatedAllocateInfo, 32, 8, sType: 0, pNext: 8, image: 16,
+        buffer: 24);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryDedicatedAllocateInfo, 24, 4, sType: 0, pNext: 4, image: 8, buffer: 16);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::MemoryDedicatedAllocateInfo, 24, 8, sType: 0, pNext: 4, image: 8, buffer: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryAllocateFlagsInfo, 24, 8, sType: 0, pNext: 8, flags: 16,
+        deviceMask: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::MemoryAllocateFlagsInfo, 16, 4, sType: 0, pNext: 4, flags: 8, deviceMask: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceGroupRenderPassBeginInfo, 32, 8, sType: 0, pNext: 8, deviceMask: 16,
+        deviceRenderAreaCount: 20, pDeviceRenderAreas: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceGroupRenderPassBeginInfo, 20, 4, sType: 0, pNext: 4, deviceMask: 8,
+        deviceRenderAreaCount: 12, pDeviceRenderAreas: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceGroupCommandBufferBeginInfo, 24, 8, sType: 0, pNext: 8, deviceMask: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceGroupCommandBufferBeginInfo, 12, 4, sType: 0, pNext: 4, deviceMask: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceGroupSubmitInfo, 64, 8, sType: 0, pNext: 8, waitSemaphoreCount: 16,
+        pWaitSemaphoreDeviceIndices: 24, commandBufferCount: 32, pCommandBufferDeviceMasks: 40,
+        signalSemaphoreCount: 48, pSignalSemaphoreDeviceIndices: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceGroupSubmitInfo, 32, 4, sType: 0, pNext: 4, waitSemaphoreCount: 8,
+        pWaitSemaphoreDeviceIndices: 12, commandBufferCount: 16, pCommandBufferDeviceMasks: 20,
+        signalSemaphoreCount: 24, pSignalSemaphoreDeviceIndices: 28);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceGroupBindSparseInfo, 24, 8, sType: 0, pNext: 8,
+        resourceDeviceIndex: 16, memoryDeviceIndex: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceGroupBindSparseInfo, 16, 4, sType: 0, pNext: 4, resourceDeviceIndex: 8,
+        memoryDeviceIndex: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BindBufferMemoryDeviceGroupInfo, 32, 8, sType: 0, pNext: 8,
+        deviceIndexCount: 16, pDeviceIndices: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::BindBufferMemoryDeviceGroupInfo, 16, 4, sType: 0, pNext: 4,
+        deviceIndexCount: 8, pDeviceIndices: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BindImageMemoryDeviceGroupInfo, 48, 8, sType: 0, pNext: 8,
+        deviceIndexCount: 16, pDeviceIndices: 24, splitInstanceBindRegionCount: 32,
+        pSplitInstanceBindRegions: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::BindImageMemoryDeviceGroupInfo, 24, 4, sType: 0, pNext: 4,
+        deviceIndexCount: 8, pDeviceIndices: 12, splitInstanceBindRegionCount: 16,
+        pSplitInstanceBindRegions: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceGroupProperties, 288, 8, sType: 0, pNext: 8,
+        physicalDeviceCount: 16, physicalDevices: 24, subsetAllocation: 280);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceGroupProperties, 144, 4, sType: 0, pNext: 4,
+        physicalDeviceCount: 8, physicalDevices: 12, subsetAllocation: 140);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceGroupDeviceCreateInfo, 32, 8, sType: 0, pNext: 8,
+        physicalDeviceCount: 16, pPhysicalDevices: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceGroupDeviceCreateInfo, 16, 4, sType: 0, pNext: 4,
+        physicalDeviceCount: 8, pPhysicalDevices: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferMemoryRequirementsInfo2, 24, 8, sType: 0, pNext: 8, buffer: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferMemoryRequirementsInfo2, 16, 4, sType: 0, pNext: 4, buffer: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferMemoryRequirementsInfo2, 16, 8, sType: 0, pNext: 4, buffer: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageMemoryRequirementsInfo2, 24, 8, sType: 0, pNext: 8, image: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageMemoryRequirementsInfo2, 16, 4, sType: 0, pNext: 4, image: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ImageMemoryRequirementsInfo2, 16, 8, sType: 0, pNext: 4, image: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageSparseMemoryRequirementsInfo2, 24, 8, sType: 0, pNext: 8, image: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageSparseMemoryRequirementsInfo2, 16, 4, sType: 0, pNext: 4, image: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ImageSparseMemoryRequirementsInfo2, 16, 8, sType: 0, pNext: 4, image: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryRequirements2, 40, 8, sType: 0, pNext: 8, memoryRequirements: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryRequirements2, 28, 4, sType: 0, pNext: 4, memoryRequirements: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::MemoryRequirements2, 32, 8, sType: 0, pNext: 4, memoryRequirements: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SparseImageMemoryRequirements2, 64, 8, sType: 0, pNext: 8,
+        memoryRequirements: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SparseImageMemoryRequirements2, 56, 4, sType: 0, pNext: 4,
+        memoryRequirements: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SparseImageMemoryRequirements2, 56, 8, sType: 0, pNext: 4,
+        memoryRequirements: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceFeatures2, 240, 8, sType: 0, pNext: 8, features: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceFeatures2, 228, 4, sType: 0, pNext: 4, features: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceProperties2, 840, 8, sType: 0, pNext: 8, properties: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceProperties2, 808, 4, sType: 0, pNext: 4, properties: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceProperties2, 824, 8, sType: 0, pNext: 4, properties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::FormatProperties2, 32, 8, sType: 0, pNext: 8, formatProperties: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::FormatProperties2, 20, 4, sType: 0, pNext: 4, formatProperties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageFormatProperties2, 48, 8, sType: 0, pNext: 8, imageFormatProperties: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageFormatProperties2, 40, 4, sType: 0, pNext: 4, imageFormatProperties: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ImageFormatProperties2, 40, 8, sType: 0, pNext: 4, imageFormatProperties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceImageFormatInfo2, 40, 8, sType: 0, pNext: 8, format: 16,
+        _type: 20, tiling: 24, usage: 28, flags: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceImageFormatInfo2, 28, 4, sType: 0, pNext: 4, format: 8,
+        _type: 12, tiling: 16, usage: 20, flags: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::QueueFamilyProperties2, 40, 8, sType: 0, pNext: 8, queueFamilyProperties: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::QueueFamilyProperties2, 32, 4, sType: 0, pNext: 4, queueFamilyProperties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceMemoryProperties2, 536, 8, sType: 0, pNext: 8,
+        memoryProperties: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceMemoryProperties2, 464, 4, sType: 0, pNext: 4,
+        memoryProperties: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceMemoryProperties2, 528, 8, sType: 0, pNext: 4,
+        memoryProperties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SparseImageFormatProperties2, 40, 8, sType: 0, pNext: 8, properties: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SparseImageFormatProperties2, 28, 4, sType: 0, pNext: 4, properties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSparseImageFormatInfo2, 40, 8, sType: 0, pNext: 8, format: 16,
+        _type: 20, samples: 24, usage: 28, tiling: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSparseImageFormatInfo2, 28, 4, sType: 0, pNext: 4, format: 8,
+        _type: 12, samples: 16, usage: 20, tiling: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDevicePointClippingProperties, 24, 8, sType: 0, pNext: 8,
+        pointClippingBehavior: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDevicePointClippingProperties, 12, 4, sType: 0, pNext: 4,
+        pointClippingBehavior: 8);
+    check_layout!(vk::InputAttachmentAspectReference, 12, 4, subpass: 0, inputAttachmentIndex: 4,
+        aspectMask: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderPassInputAttachmentAspectCreateInfo, 32, 8, sType: 0, pNext: 8,
+        aspectReferenceCount: 16, pAspectReferences: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::RenderPassInputAttachmentAspectCreateInfo, 16, 4, sType: 0, pNext: 4,
+        aspectReferenceCount: 8, pAspectReferences: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageViewUsageCreateInfo, 24, 8, sType: 0, pNext: 8, usage: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageViewUsageCreateInfo, 12, 4, sType: 0, pNext: 4, usage: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineTessellationDomainOriginStateCreateInfo, 24, 8, sType: 0, pNext: 8,
+        domainOrigin: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineTessellationDomainOriginStateCreateInfo, 12, 4, sType: 0, pNext: 4,
+        domainOrigin: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderPassMultiviewCreateInfo, 64, 8, sType: 0, pNext: 8, subpassCount: 16,
+        pViewMasks: 24, dependencyCount: 32, pViewOffsets: 40, correlationMaskCount: 48,
+        pCorrelationMasks: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::RenderPassMultiviewCreateInfo, 32, 4, sType: 0, pNext: 4, subpassCount: 8,
+        pViewMasks: 12, dependencyCount: 16, pViewOffsets: 20, correlationMaskCount: 24,
+        pCorrelationMasks: 28);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceMultiviewFeatures, 32, 8, sType: 0, pNext: 8, multiview: 16,
+        multiviewGeometryShader: 20, multiviewTessellationShader: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceMultiviewFeatures, 20, 4, sType: 0, pNext: 4, multiview: 8,
+        multiviewGeometryShader: 12, multiviewTessellationShader: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceMultiviewProperties, 24, 8, sType: 0, pNext: 8,
+        maxMultiviewViewCount: 16, maxMultiviewInstanceIndex: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceMultiviewProperties, 16, 4, sType: 0, pNext: 4,
+        maxMultiviewViewCount: 8, maxMultiviewInstanceIndex: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVariablePointersFeatures, 24, 8, sType: 0, pNext: 8,
+        variablePointersStorageBuffer: 16, variablePointers: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceVariablePointersFeatures, 16, 4, sType: 0, pNext: 4,
+        variablePointersStorageBuffer: 8, variablePointers: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceProtectedMemoryFeatures, 24, 8, sType: 0, pNext: 8,
+        protectedMemory: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceProtectedMemoryFeatures, 12, 4, sType: 0, pNext: 4,
+        protectedMemory: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceProtectedMemoryProperties, 24, 8, sType: 0, pNext: 8,
+        protectedNoFault: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceProtectedMemoryProperties, 12, 4, sType: 0, pNext: 4,
+        protectedNoFault: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceQueueInfo2, 32, 8, sType: 0, pNext: 8, flags: 16, queueFamilyIndex: 20,
+        queueIndex: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceQueueInfo2, 20, 4, sType: 0, pNext: 4, flags: 8, queueFamilyIndex: 12,
+        queueIndex: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ProtectedSubmitInfo, 24, 8, sType: 0, pNext: 8, protectedSubmit: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ProtectedSubmitInfo, 12, 4, sType: 0, pNext: 4, protectedSubmit: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SamplerYcbcrConversionCreateInfo, 64, 8, sType: 0, pNext: 8, format: 16,
+        ycbcrModel: 20, ycbcrRange: 24, components: 28, xChromaOffset: 44, yChromaOffset: 48,
+        chromaFilter: 52, forceExplicitReconstruction: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SamplerYcbcrConversionCreateInfo, 52, 4, sType: 0, pNext: 4, format: 8,
+        ycbcrModel: 12, ycbcrRange: 16, components: 20, xChromaOffset: 36, yChromaOffset: 40,
+        chromaFilter: 44, forceExplicitReconstruction: 48);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SamplerYcbcrConversionInfo, 24, 8, sType: 0, pNext: 8, conversion: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SamplerYcbcrConversionInfo, 16, 4, sType: 0, pNext: 4, conversion: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SamplerYcbcrConversionInfo, 16, 8, sType: 0, pNext: 4, conversion: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BindImagePlaneMemoryInfo, 24, 8, sType: 0, pNext: 8, planeAspect: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::BindImagePlaneMemoryInfo, 12, 4, sType: 0, pNext: 4, planeAspect: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImagePlaneMemoryRequirementsInfo, 24, 8, sType: 0, pNext: 8, planeAspect: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImagePlaneMemoryRequirementsInfo, 12, 4, sType: 0, pNext: 4, planeAspect: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSamplerYcbcrConversionFeatures, 24, 8, sType: 0, pNext: 8,
+        samplerYcbcrConversion: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSamplerYcbcrConversionFeatures, 12, 4, sType: 0, pNext: 4,
+        samplerYcbcrConversion: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SamplerYcbcrConversionImageFormatProperties, 24, 8, sType: 0, pNext: 8,
+        combinedImageSamplerDescriptorCount: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SamplerYcbcrConversionImageFormatProperties, 12, 4, sType: 0, pNext: 4,
+        combinedImageSamplerDescriptorCount: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorUpdateTemplateEntry, 32, 8, dstBinding: 0, dstArrayElement: 4,
+        descriptorCount: 8, descriptorType: 12, offset: 16, stride: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorUpdateTemplateEntry, 24, 4, dstBinding: 0, dstArrayElement: 4,
+        descriptorCount: 8, descriptorType: 12, offset: 16, stride: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorUpdateTemplateCreateInfo, 72, 8, sType: 0, pNext: 8, flags: 16,
+        descriptorUpdateEntryCount: 20, pDescriptorUpdateEntries: 24, templateType: 32,
+        descriptorSetLayout: 40, pipelineBindPoint: 48, pipelineLayout: 56, set: 64);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::DescriptorUpdateTemplateCreateInfo, 48, 4, sType: 0, pNext: 4, flags: 8,
+        descriptorUpdateEntryCount: 12, pDescriptorUpdateEntries: 16, templateType: 20,
+        descriptorSetLayout: 24, pipelineBindPoint: 32, pipelineLayout: 36, set: 44);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::DescriptorUpdateTemplateCreateInfo, 56, 8, sType: 0, pNext: 4, flags: 8,
+        descriptorUpdateEntryCount: 12, pDescriptorUpdateEntries: 16, templateType: 20,
+        descriptorSetLayout: 24, pipelineBindPoint: 32, pipelineLayout: 40, set: 48);
+    check_layout!(vk::ExternalMemoryProperties, 12, 4, externalMemoryFeatures: 0,
+        exportFromImportedHandleTypes: 4, compatibleHandleTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceExternalImageFormatInfo, 24, 8, sType: 0, pNext: 8,
+        handleType: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceExternalImageFormatInfo, 12, 4, sType: 0, pNext: 4,
+        handleType: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExternalImageFormatProperties, 32, 8, sType: 0, pNext: 8,
+        externalMemoryProperties: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExternalImageFormatProperties, 20, 4, sType: 0, pNext: 4,
+        externalMemoryProperties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceExternalBufferInfo, 32, 8, sType: 0, pNext: 8, flags: 16,
+        usage: 20, handleType: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceExternalBufferInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        usage: 12, handleType: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExternalBufferProperties, 32, 8, sType: 0, pNext: 8,
+        externalMemoryProperties: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExternalBufferProperties, 20, 4, sType: 0, pNext: 4,
+        externalMemoryProperties: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceIDProperties, 64, 8, sType: 0, pNext: 8, deviceUUID: 16,
+        driverUUID: 32, deviceLUID: 48, deviceNodeMask: 56, deviceLUIDValid: 60);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceIDProperties, 56, 4, sType: 0, pNext: 4, deviceUUID: 8,
+        driverUUID: 24, deviceLUID: 40, deviceNodeMask: 48, deviceLUIDValid: 52);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExternalMemoryImageCreateInfo, 24, 8, sType: 0, pNext: 8, handleTypes: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExternalMemoryImageCreateInfo, 12, 4, sType: 0, pNext: 4, handleTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExternalMemoryBufferCreateInfo, 24, 8, sType: 0, pNext: 8, handleTypes: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExternalMemoryBufferCreateInfo, 12, 4, sType: 0, pNext: 4, handleTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExportMemoryAllocateInfo, 24, 8, sType: 0, pNext: 8, handleTypes: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExportMemoryAllocateInfo, 12, 4, sType: 0, pNext: 4, handleTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceExternalFenceInfo, 24, 8, sType: 0, pNext: 8, handleType: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceExternalFenceInfo, 12, 4, sType: 0, pNext: 4, handleType: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExternalFenceProperties, 32, 8, sType: 0, pNext: 8,
+        exportFromImportedHandleTypes: 16, compatibleHandleTypes: 20, externalFenceFeatures: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExternalFenceProperties, 20, 4, sType: 0, pNext: 4,
+        exportFromImportedHandleTypes: 8, compatibleHandleTypes: 12, externalFenceFeatures: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExportFenceCreateInfo, 24, 8, sType: 0, pNext: 8, handleTypes: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExportFenceCreateInfo, 12, 4, sType: 0, pNext: 4, handleTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExportSemaphoreCreateInfo, 24, 8, sType: 0, pNext: 8, handleTypes: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExportSemaphoreCreateInfo, 12, 4, sType: 0, pNext: 4, handleTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceExternalSemaphoreInfo, 24, 8, sType: 0, pNext: 8,
+        handleType: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceExternalSemaphoreInfo, 12, 4, sType: 0, pNext: 4,
+        handleType: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ExternalSemaphoreProperties, 32, 8, sType: 0, pNext: 8,
+        exportFromImportedHandleTypes: 16, compatibleHandleTypes: 20,
+        externalSemaphoreFeatures: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ExternalSemaphoreProperties, 20, 4, sType: 0, pNext: 4,
+        exportFromImportedHandleTypes: 8, compatibleHandleTypes: 12, externalSemaphoreFeatures: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceMaintenance3Properties, 32, 8, sType: 0, pNext: 8,
+        maxPerSetDescriptors: 16, maxMemoryAllocationSize: 24);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceMaintenance3Properties, 20, 4, sType: 0, pNext: 4,
+        maxPerSetDescriptors: 8, maxMemoryAllocationSize: 12);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceMaintenance3Properties, 24, 8, sType: 0, pNext: 4,
+        maxPerSetDescriptors: 8, maxMemoryAllocationSize: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetLayoutSupport, 24, 8, sType: 0, pNext: 8, supported: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorSetLayoutSupport, 12, 4, sType: 0, pNext: 4, supported: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderDrawParametersFeatures, 24, 8, sType: 0, pNext: 8,
+        shaderDrawParameters: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderDrawParametersFeatures, 12, 4, sType: 0, pNext: 4,
+        shaderDrawParameters: 8);
+    check_layout!(vk::ConformanceVersion, 4, 1, major: 0, minor: 1, subminor: 2, patch: 3);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkan11Features, 64, 8, sType: 0, pNext: 8,
+        storageBuffer16BitAccess: 16, uniformAndStorageBuffer16BitAccess: 20,
+        storagePushConstant16: 24, storageInputOutput16: 28, multiview: 32,
+        multiviewGeometryShader: 36, multiviewTessellationShader: 40,
+        variablePointersStorageBuffer: 44, variablePointers: 48, protectedMemory: 52,
+        samplerYcbcrConversion: 56, shaderDrawParameters: 60);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceVulkan11Features, 56, 4, sType: 0, pNext: 4,
+        storageBuffer16BitAccess: 8, uniformAndStorageBuffer16BitAccess: 12,
+        storagePushConstant16: 16, storageInputOutput16: 20, multiview: 24,
+        multiviewGeometryShader: 28, multiviewTessellationShader: 32,
+        variablePointersStorageBuffer: 36, variablePointers: 40, protectedMemory: 44,
+        samplerYcbcrConversion: 48, shaderDrawParameters: 52);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkan11Properties, 112, 8, sType: 0, pNext: 8, deviceUUID: 16,
+        driverUUID: 32, deviceLUID: 48, deviceNodeMask: 56, deviceLUIDValid: 60, subgroupSize: 64,
+        subgroupSupportedStages: 68, subgroupSupportedOperations: 72,
+        subgroupQuadOperationsInAllStages: 76, pointClippingBehavior: 80,
+        maxMultiviewViewCount: 84, maxMultiviewInstanceIndex: 88, protectedNoFault: 92,
+        maxPerSetDescriptors: 96, maxMemoryAllocationSize: 104);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceVulkan11Properties, 100, 4, sType: 0, pNext: 4, deviceUUID: 8,
+        driverUUID: 24, deviceLUID: 40, deviceNodeMask: 48, deviceLUIDValid: 52, subgroupSize: 56,
+        subgroupSupportedStages: 60, subgroupSupportedOperations: 64,
+        subgroupQuadOperationsInAllStages: 68, pointClippingBehavior: 72,
+        maxMultiviewViewCount: 76, maxMultiviewInstanceIndex: 80, protectedNoFault: 84,
+        maxPerSetDescriptors: 88, maxMemoryAllocationSize: 92);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceVulkan11Properties, 104, 8, sType: 0, pNext: 4, deviceUUID: 8,
+        driverUUID: 24, deviceLUID: 40, deviceNodeMask: 48, deviceLUIDValid: 52, subgroupSize: 56,
+        subgroupSupportedStages: 60, subgroupSupportedOperations: 64,
+        subgroupQuadOperationsInAllStages: 68, pointClippingBehavior: 72,
+        maxMultiviewViewCount: 76, maxMultiviewInstanceIndex: 80, protectedNoFault: 84,
+        maxPerSetDescriptors: 88, maxMemoryAllocationSize: 96);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkan12Features, 208, 8, sType: 0, pNext: 8,
+        samplerMirrorClampToEdge: 16, drawIndirectCount: 20, storageBuffer8BitAccess: 24,
+        uniformAndStorageBuffer8BitAccess: 28, storagePushConstant8: 32,
+        shaderBufferInt64Atomics: 36, shaderSharedInt64Atomics: 40, shaderFloat16: 44,
+        shaderInt8: 48, descriptorIndexing: 52, shaderInputAttachmentArrayDynamicIndexing: 56,
+        shaderUniformTexelBufferArrayDynamicIndexing: 60,
+        shaderStorageTexelBufferArrayDynamicIndexing: 64,
+        shaderUniformBufferArrayNonUniformIndexing: 68,
+        shaderSampledImageArrayNonUniformIndexing: 72,
+        shaderStorageBufferArrayNonUniformIndexing: 76,
+        shaderStorageImageArrayNonUniformIndexing: 80,
+        shaderInputAttachmentArrayNonUniformIndexing: 84,
+        shaderUniformTexelBufferArrayNonUniformIndexing: 88,
+        shaderStorageTexelBufferArrayNonUniformIndexing: 92,
+        descriptorBindingUniformBufferUpdateAfterBind: 96,
+        descriptorBindingSampledImageUpdateAfterBind: 100,
+        descriptorBindingStorageImageUpdateAfterBind: 104,
+        descriptorBindingStorageBufferUpdateAfterBind: 108,
+        descriptorBindingUniformTexelBufferUpdateAfterBind: 112,
+        descriptorBindingStorageTexelBufferUpdateAfterBind: 116,
+        descriptorBindingUpdateUnusedWhilePending: 120, descriptorBindingPartiallyBound: 124,
+        descriptorBindingVariableDescriptorCount: 128, runtimeDescriptorArray: 132,
+        samplerFilterMinmax: 136, scalarBlockLayout: 140, imagelessFramebuffer: 144,
+        uniformBufferStandardLayout: 148, shaderSubgroupExtendedTypes: 152,
+        separateDepthStencilLayouts: 156, hostQueryReset: 160, timelineSemaphore: 164,
+        bufferDeviceAddress: 168, bufferDeviceAddressCaptureReplay: 172,
+        bufferDeviceAddressMultiDevice: 176, vulkanMemoryModel: 180,
+        vulkanMemoryModelDeviceScope: 184, vulkanMemoryModelAvailabilityVisibilityChains: 188,
+        shaderOutputViewportIndex: 192, shaderOutputLayer: 196, subgroupBroadcastDynamicId: 200);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceVulkan12Features, 196, 4, sType: 0, pNext: 4,
+        samplerMirrorClampToEdge: 8, drawIndirectCount: 12, storageBuffer8BitAccess: 16,
+        uniformAndStorageBuffer8BitAccess: 20, storagePushConstant8: 24,
+        shaderBufferInt64Atomics: 28, shaderSharedInt64Atomics: 32, shaderFloat16: 36,
+        shaderInt8: 40, descriptorIndexing: 44, shaderInputAttachmentArrayDynamicIndexing: 48,
+        shaderUniformTexelBufferArrayDynamicIndexing: 52,
+        shaderStorageTexelBufferArrayDynamicIndexing: 56,
+        shaderUniformBufferArrayNonUniformIndexing: 60,
+        shaderSampledImageArrayNonUniformIndexing: 64,
+        shaderStorageBufferArrayNonUniformIndexing: 68,
+        shaderStorageImageArrayNonUniformIndexing: 72,
+        shaderInputAttachmentArrayNonUniformIndexing: 76,
+        shaderUniformTexelBufferArrayNonUniformIndexing: 80,
+        shaderStorageTexelBufferArrayNonUniformIndexing: 84,
+        descriptorBindingUniformBufferUpdateAfterBind: 88,
+        descriptorBindingSampledImageUpdateAfterBind: 92,
+        descriptorBindingStorageImageUpdateAfterBind: 96,
+        descriptorBindingStorageBufferUpdateAfterBind: 100,
+        descriptorBindingUniformTexelBufferUpdateAfterBind: 104,
+        descriptorBindingStorageTexelBufferUpdateAfterBind: 108,
+        descriptorBindingUpdateUnusedWhilePending: 112, descriptorBindingPartiallyBound: 116,
+        descriptorBindingVariableDescriptorCount: 120, runtimeDescriptorArray: 124,
+        samplerFilterMinmax: 128, scalarBlockLayout: 132, imagelessFramebuffer: 136,
+        uniformBufferStandardLayout: 140, shaderSubgroupExtendedTypes: 144,
+        separateDepthStencilLayouts: 148, hostQueryReset: 152, timelineSemaphore: 156,
+        bufferDeviceAddress: 160, bufferDeviceAddressCaptureReplay: 164,
+        bufferDeviceAddressMultiDevice: 168, vulkanMemoryModel: 172,
+        vulkanMemoryModelDeviceScope: 176, vulkanMemoryModelAvailabilityVisibilityChains: 180,
+        shaderOutputViewportIndex: 184, shaderOutputLayer: 188, subgroupBroadcastDynamicId: 192);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkan12Properties, 736, 8, sType: 0, pNext: 8, driverID: 16,
+        driverName: 20, driverInfo: 276, conformanceVersion: 532, denormBehaviorIndependence: 536,
+        roundingModeIndependence: 540, shaderSignedZeroInfNanPreserveFloat16: 544,
+        shaderSignedZeroInfNanPreserveFloat32: 548, shaderSignedZeroInfNanPreserveFloat64: 552,
+        shaderDenormPreserveFloat16: 556, shaderDenormPreserveFloat32: 560,
+        shaderDenormPreserveFloat64: 564, shaderDenormFlushToZeroFloat16: 568,
+        shaderDenormFlushToZeroFloat32: 572, shaderDenormFlushToZeroFloat64: 576,
+        shaderRoundingModeRTEFloat16: 580, shaderRoundingModeRTEFloat32: 584,
+        shaderRoundingModeRTEFloat64: 588, shaderRoundingModeRTZFloat16: 592,
+        shaderRoundingModeRTZFloat32: 596, shaderRoundingModeRTZFloat64: 600,
+        maxUpdateAfterBindDescriptorsInAllPools: 604,
+        shaderUniformBufferArrayNonUniformIndexingNative: 608,
+        shaderSampledImageArrayNonUniformIndexingNative: 612,
+        shaderStorageBufferArrayNonUniformIndexingNative: 616,
+        shaderStorageImageArrayNonUniformIndexingNative: 620,
+        shaderInputAttachmentArrayNonUniformIndexingNative: 624,
+        robustBufferAccessUpdateAfterBind: 628, quadDivergentImplicitLod: 632,
+        maxPerStageDescriptorUpdateAfterBindSamplers: 636,
+        maxPerStageDescriptorUpdateAfterBindUniformBuffers: 640,
+        maxPerStageDescriptorUpdateAfterBindStorageBuffers: 644,
+        maxPerStageDescriptorUpdateAfterBindSampledImages: 648,
+        maxPerStageDescriptorUpdateAfterBindStorageImages: 652,
+        maxPerStageDescriptorUpdateAfterBindInputAttachments: 656,
+        maxPerStageUpdateAfterBindResources: 660, maxDescriptorSetUpdateAfterBindSamplers: 664,
+        maxDescriptorSetUpdateAfterBindUniformBuffers: 668,
+        maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: 672,
+        maxDescriptorSetUpdateAfterBindStorageBuffers: 676,
+        maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: 680,
+        maxDescriptorSetUpdateAfterBindSampledImages: 684,
+        maxDescriptorSetUpdateAfterBindStorageImages: 688,
+        maxDescriptorSetUpdateAfterBindInputAttachments: 692, supportedDepthResolveModes: 696,
+        supportedStencilResolveModes: 700, independentResolveNone: 704, independentResolve: 708,
+        filterMinmaxSingleComponentFormats: 712, filterMinmaxImageComponentMapping: 716,
+        maxTimelineSemaphoreValueDifference: 720, framebufferIntegerColorSampleCounts: 728);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceVulkan12Properties, 724, 4, sType: 0, pNext: 4, driverID: 8,
+        driverName: 12, driverInfo: 268, conformanceVersion: 524, denormBehaviorIndependence: 528,
+        roundingModeIndependence: 532, shaderSignedZeroInfNanPreserveFloat16: 536,
+        shaderSignedZeroInfNanPreserveFloat32: 540, shaderSignedZeroInfNanPreserveFloat64: 544,
+        shaderDenormPreserveFloat16: 548, shaderDenormPreserveFloat32: 552,
+        shaderDenormPreserveFloat64: 556, shaderDenormFlushToZeroFloat16: 560,
+        shaderDenormFlushToZeroFloat32: 564, shaderDenormFlushToZeroFloat64: 568,
+        shaderRoundingModeRTEFloat16: 572, shaderRoundingModeRTEFloat32: 576,
+        shaderRoundingModeRTEFloat64: 580, shaderRoundingModeRTZFloat16: 584,
+        shaderRoundingModeRTZFloat32: 588, shaderRoundingModeRTZFloat64: 592,
+        maxUpdateAfterBindDescriptorsInAllPools: 596,
+        shaderUniformBufferArrayNonUniformIndexingNative: 600,
+        shaderSampledImageArrayNonUniformIndexingNative: 604,
+        shaderStorageBufferArrayNonUniformIndexingNative: 608,
+        shaderStorageImageArrayNonUniformIndexingNative: 612,
+        shaderInputAttachmentArrayNonUniformIndexingNative: 616,
+        robustBufferAccessUpdateAfterBind: 620, quadDivergentImplicitLod: 624,
+        maxPerStageDescriptorUpdateAfterBindSamplers: 628,
+        maxPerStageDescriptorUpdateAfterBindUniformBuffers: 632,
+        maxPerStageDescriptorUpdateAfterBindStorageBuffers: 636,
+        maxPerStageDescriptorUpdateAfterBindSampledImages: 640,
+        maxPerStageDescriptorUpdateAfterBindStorageImages: 644,
+        maxPerStageDescriptorUpdateAfterBindInputAttachments: 648,
+        maxPerStageUpdateAfterBindResources: 652, maxDescriptorSetUpdateAfterBindSamplers: 656,
+        maxDescriptorSetUpdateAfterBindUniformBuffers: 660,
+        maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: 664,
+        maxDescriptorSetUpdateAfterBindStorageBuffers: 668,
+        maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: 672,
+        maxDescriptorSetUpdateAfterBindSampledImages: 676,
+        maxDescriptorSetUpdateAfterBindStorageImages: 680,
+        maxDescriptorSetUpdateAfterBindInputAttachments: 684, supportedDepthResolveModes: 688,
+        supportedStencilResolveModes: 692, independentResolveNone: 696, independentResolve: 700,
+        filterMinmaxSingleComponentFormats: 704, filterMinmaxImageComponentMapping: 708,
+        maxTimelineSemaphoreValueDifference: 712, framebufferIntegerColorSampleCounts: 720);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceVulkan12Properties, 728, 8, sType: 0, pNext: 4, driverID: 8,
+        driverName: 12, driverInfo: 268, conformanceVersion: 524, denormBehaviorIndependence: 528,
+        roundingModeIndependence: 532, shaderSignedZeroInfNanPreserveFloat16: 536,
+        shaderSignedZeroInfNanPreserveFloat32: 540, shaderSignedZeroInfNanPreserveFloat64: 544,
+        shaderDenormPreserveFloat16: 548, shaderDenormPreserveFloat32: 552,
+        shaderDenormPreserveFloat64: 556, shaderDenormFlushToZeroFloat16: 560,
+        shaderDenormFlushToZeroFloat32: 564, shaderDenormFlushToZeroFloat64: 568,
+        shaderRoundingModeRTEFloat16: 572, shaderRoundingModeRTEFloat32: 576,
+        shaderRoundingModeRTEFloat64: 580, shaderRoundingModeRTZFloat16: 584,
+        shaderRoundingModeRTZFloat32: 588, shaderRoundingModeRTZFloat64: 592,
+        maxUpdateAfterBindDescriptorsInAllPools: 596,
+        shaderUniformBufferArrayNonUniformIndexingNative: 600,
+        shaderSampledImageArrayNonUniformIndexingNative: 604,
+        shaderStorageBufferArrayNonUniformIndexingNative: 608,
+        shaderStorageImageArrayNonUniformIndexingNative: 612,
+        shaderInputAttachmentArrayNonUniformIndexingNative: 616,
+        robustBufferAccessUpdateAfterBind: 620, quadDivergentImplicitLod: 624,
+        maxPerStageDescriptorUpdateAfterBindSamplers: 628,
+        maxPerStageDescriptorUpdateAfterBindUniformBuffers: 632,
+        maxPerStageDescriptorUpdateAfterBindStorageBuffers: 636,
+        maxPerStageDescriptorUpdateAfterBindSampledImages: 640,
+        maxPerStageDescriptorUpdateAfterBindStorageImages: 644,
+        maxPerStageDescriptorUpdateAfterBindInputAttachments: 648,
+        maxPerStageUpdateAfterBindResources: 652, maxDescriptorSetUpdateAfterBindSamplers: 656,
+        maxDescriptorSetUpdateAfterBindUniformBuffers: 660,
+        maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: 664,
+        maxDescriptorSetUpdateAfterBindStorageBuffers: 668,
+        maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: 672,
+        maxDescriptorSetUpdateAfterBindSampledImages: 676,
+        maxDescriptorSetUpdateAfterBindStorageImages: 680,
+        maxDescriptorSetUpdateAfterBindInputAttachments: 684, supportedDepthResolveModes: 688,
+        supportedStencilResolveModes: 692, independentResolveNone: 696, independentResolve: 700,
+        filterMinmaxSingleComponentFormats: 704, filterMinmaxImageComponentMapping: 708,
+        maxTimelineSemaphoreValueDifference: 712, framebufferIntegerColorSampleCounts: 720);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageFormatListCreateInfo, 32, 8, sType: 0, pNext: 8, viewFormatCount: 16,
+        pViewFormats: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageFormatListCreateInfo, 16, 4, sType: 0, pNext: 4, viewFormatCount: 8,
+        pViewFormats: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::AttachmentDescription2, 56, 8, sType: 0, pNext: 8, flags: 16, format: 20,
+        samples: 24, loadOp: 28, storeOp: 32, stencilLoadOp: 36, stencilStoreOp: 40,
+        initialLayout: 44, finalLayout: 48);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::AttachmentDescription2, 44, 4, sType: 0, pNext: 4, flags: 8, format: 12,
+        samples: 16, loadOp: 20, storeOp: 24, stencilLoadOp: 28, stencilStoreOp: 32,
+        initialLayout: 36, finalLayout: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::AttachmentReference2, 32, 8, sType: 0, pNext: 8, attachment: 16, layout: 20,
+        aspectMask: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::AttachmentReference2, 20, 4, sType: 0, pNext: 4, attachment: 8, layout: 12,
+        aspectMask: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubpassDescription2, 88, 8, sType: 0, pNext: 8, flags: 16,
+        pipelineBindPoint: 20, viewMask: 24, inputAttachmentCount: 28, pInputAttachments: 32,
+        colorAttachmentCount: 40, pColorAttachments: 48, pResolveAttachments: 56,
+        pDepthStencilAttachment: 64, preserveAttachmentCount: 72, pPreserveAttachments: 80);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubpassDescription2, 52, 4, sType: 0, pNext: 4, flags: 8,
+        pipelineBindPoint: 12, viewMask: 16, inputAttachmentCount: 20, pInputAttachments: 24,
+        colorAttachmentCount: 28, pColorAttachments: 32, pResolveAttachments: 36,
+        pDepthStencilAttachment: 40, preserveAttachmentCount: 44, pPreserveAttachments: 48);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubpassDependency2, 48, 8, sType: 0, pNext: 8, srcSubpass: 16,
+        dstSubpass: 20, srcStageMask: 24, dstStageMask: 28, srcAccessMask: 32, dstAccessMask: 36,
+        dependencyFlags: 40, viewOffset: 44);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubpassDependency2, 40, 4, sType: 0, pNext: 4, srcSubpass: 8, dstSubpass: 12,
+        srcStageMask: 16, dstStageMask: 20, srcAccessMask: 24, dstAccessMask: 28,
+        dependencyFlags: 32, viewOffset: 36);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderPassCreateInfo2, 80, 8, sType: 0, pNext: 8, flags: 16,
+        attachmentCount: 20, pAttachments: 24, subpassCount: 32, pSubpasses: 40,
+        dependencyCount: 48, pDependencies: 56, correlatedViewMaskCount: 64,
+        pCorrelatedViewMasks: 72);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::RenderPassCreateInfo2, 44, 4, sType: 0, pNext: 4, flags: 8,
+        attachmentCount: 12, pAttachments: 16, subpassCount: 20, pSubpasses: 24,
+        dependencyCount: 28, pDependencies: 32, correlatedViewMaskCount: 36,
+        pCorrelatedViewMasks: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubpassBeginInfo, 24, 8, sType: 0, pNext: 8, contents: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubpassBeginInfo, 12, 4, sType: 0, pNext: 4, contents: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubpassEndInfo, 16, 8, sType: 0, pNext: 8);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubpassEndInfo, 8, 4, sType: 0, pNext: 4);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDevice8BitStorageFeatures, 32, 8, sType: 0, pNext: 8,
+        storageBuffer8BitAccess: 16, uniformAndStorageBuffer8BitAccess: 20,
+        storagePushConstant8: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDevice8BitStorageFeatures, 20, 4, sType: 0, pNext: 4,
+        storageBuffer8BitAccess: 8, uniformAndStorageBuffer8BitAccess: 12,
+        storagePushConstant8: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceDriverProperties, 536, 8, sType: 0, pNext: 8, driverID: 16,
+        driverName: 20, driverInfo: 276, conformanceVersion: 532);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceDriverProperties, 528, 4, sType: 0, pNext: 4, driverID: 8,
+        driverName: 12, driverInfo: 268, conformanceVersion: 524);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderAtomicInt64Features, 24, 8, sType: 0, pNext: 8,
+        shaderBufferInt64Atomics: 16, shaderSharedInt64Atomics: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderAtomicInt64Features, 16, 4, sType: 0, pNext: 4,
+        shaderBufferInt64Atomics: 8, shaderSharedInt64Atomics: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderFloat16Int8Features, 24, 8, sType: 0, pNext: 8,
+        shaderFloat16: 16, shaderInt8: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderFloat16Int8Features, 16, 4, sType: 0, pNext: 4,
+        shaderFloat16: 8, shaderInt8: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceFloatControlsProperties, 88, 8, sType: 0, pNext: 8,
+        denormBehaviorIndependence: 16, roundingModeIndependence: 20,
+        shaderSignedZeroInfNanPreserveFloat16: 24, shaderSignedZeroInfNanPreserveFloat32: 28,
+        shaderSignedZeroInfNanPreserveFloat64: 32, shaderDenormPreserveFloat16: 36,
+        shaderDenormPreserveFloat32: 40, shaderDenormPreserveFloat64: 44,
+        shaderDenormFlushToZeroFloat16: 48, shaderDenormFlushToZeroFloat32: 52,
+        shaderDenormFlushToZeroFloat64: 56, shaderRoundingModeRTEFloat16: 60,
+        shaderRoundingModeRTEFloat32: 64, shaderRoundingModeRTEFloat64: 68,
+        shaderRoundingModeRTZFloat16: 72, shaderRoundingModeRTZFloat32: 76,
+        shaderRoundingModeRTZFloat64: 80);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceFloatControlsProperties, 76, 4, sType: 0, pNext: 4,
+        denormBehaviorIndependence: 8, roundingModeIndependence: 12,
+        shaderSignedZeroInfNanPreserveFloat16: 16, shaderSignedZeroInfNanPreserveFloat32: 20,
+        shaderSignedZeroInfNanPreserveFloat64: 24, shaderDenormPreserveFloat16: 28,
+        shaderDenormPreserveFloat32: 32, shaderDenormPreserveFloat64: 36,
+        shaderDenormFlushToZeroFloat16: 40, shaderDenormFlushToZeroFloat32: 44,
+        shaderDenormFlushToZeroFloat64: 48, shaderRoundingModeRTEFloat16: 52,
+        shaderRoundingModeRTEFloat32: 56, shaderRoundingModeRTEFloat64: 60,
+        shaderRoundingModeRTZFloat16: 64, shaderRoundingModeRTZFloat32: 68,
+        shaderRoundingModeRTZFloat64: 72);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetLayoutBindingFlagsCreateInfo, 32, 8, sType: 0, pNext: 8,
+        bindingCount: 16, pBindingFlags: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorSetLayoutBindingFlagsCreateInfo, 16, 4, sType: 0, pNext: 4,
+        bindingCount: 8, pBindingFlags: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceDescriptorIndexingFeatures, 96, 8, sType: 0, pNext: 8,
+        shaderInputAttachmentArrayDynamicIndexing: 16,
+        shaderUniformTexelBufferArrayDynamicIndexing: 20,
+        shaderStorageTexelBufferArrayDynamicIndexing: 24,
+        shaderUniformBufferArrayNonUniformIndexing: 28,
+        shaderSampledImageArrayNonUniformIndexing: 32,
+        shaderStorageBufferArrayNonUniformIndexing: 36,
+        shaderStorageImageArrayNonUniformIndexing: 40,
+        shaderInputAttachmentArrayNonUniformIndexing: 44,
+        shaderUniformTexelBufferArrayNonUniformIndexing: 48,
+        shaderStorageTexelBufferArrayNonUniformIndexing: 52,
+        descriptorBindingUniformBufferUpdateAfterBind: 56,
+        descriptorBindingSampledImageUpdateAfterBind: 60,
+        descriptorBindingStorageImageUpdateAfterBind: 64,
+        descriptorBindingStorageBufferUpdateAfterBind: 68,
+        descriptorBindingUniformTexelBufferUpdateAfterBind: 72,
+        descriptorBindingStorageTexelBufferUpdateAfterBind: 76,
+        descriptorBindingUpdateUnusedWhilePending: 80, descriptorBindingPartiallyBound: 84,
+        descriptorBindingVariableDescriptorCount: 88, runtimeDescriptorArray: 92);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceDescriptorIndexingFeatures, 88, 4, sType: 0, pNext: 4,
+        shaderInputAttachmentArrayDynamicIndexing: 8,
+        shaderUniformTexelBufferArrayDynamicIndexing: 12,
+        shaderStorageTexelBufferArrayDynamicIndexing: 16,
+        shaderUniformBufferArrayNonUniformIndexing: 20,
+        shaderSampledImageArrayNonUniformIndexing: 24,
+        shaderStorageBufferArrayNonUniformIndexing: 28,
+        shaderStorageImageArrayNonUniformIndexing: 32,
+        shaderInputAttachmentArrayNonUniformIndexing: 36,
+        shaderUniformTexelBufferArrayNonUniformIndexing: 40,
+        shaderStorageTexelBufferArrayNonUniformIndexing: 44,
+        descriptorBindingUniformBufferUpdateAfterBind: 48,
+        descriptorBindingSampledImageUpdateAfterBind: 52,
+        descriptorBindingStorageImageUpdateAfterBind: 56,
+        descriptorBindingStorageBufferUpdateAfterBind: 60,
+        descriptorBindingUniformTexelBufferUpdateAfterBind: 64,
+        descriptorBindingStorageTexelBufferUpdateAfterBind: 68,
+        descriptorBindingUpdateUnusedWhilePending: 72, descriptorBindingPartiallyBound: 76,
+        descriptorBindingVariableDescriptorCount: 80, runtimeDescriptorArray: 84);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceDescriptorIndexingProperties, 112, 8, sType: 0, pNext: 8,
+        maxUpdateAfterBindDescriptorsInAllPools: 16,
+        shaderUniformBufferArrayNonUniformIndexingNative: 20,
+        shaderSampledImageArrayNonUniformIndexingNative: 24,
+        shaderStorageBufferArrayNonUniformIndexingNative: 28,
+        shaderStorageImageArrayNonUniformIndexingNative: 32,
+        shaderInputAttachmentArrayNonUniformIndexingNative: 36,
+        robustBufferAccessUpdateAfterBind: 40, quadDivergentImplicitLod: 44,
+        maxPerStageDescriptorUpdateAfterBindSamplers: 48,
+        maxPerStageDescriptorUpdateAfterBindUniformBuffers: 52,
+        maxPerStageDescriptorUpdateAfterBindStorageBuffers: 56,
+        maxPerStageDescriptorUpdateAfterBindSampledImages: 60,
+        maxPerStageDescriptorUpdateAfterBindStorageImages: 64,
+        maxPerStageDescriptorUpdateAfterBindInputAttachments: 68,
+        maxPerStageUpdateAfterBindResources: 72, maxDescriptorSetUpdateAfterBindSamplers: 76,
+        maxDescriptorSetUpdateAfterBindUniformBuffers: 80,
+        maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: 84,
+        maxDescriptorSetUpdateAfterBindStorageBuffers: 88,
+        maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: 92,
+        maxDescriptorSetUpdateAfterBindSampledImages: 96,
+        maxDescriptorSetUpdateAfterBindStorageImages: 100,
+        maxDescriptorSetUpdateAfterBindInputAttachments: 104);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceDescriptorIndexingProperties, 100, 4, sType: 0, pNext: 4,
+        maxUpdateAfterBindDescriptorsInAllPools: 8,
+        shaderUniformBufferArrayNonUniformIndexingNative: 12,
+        shaderSampledImageArrayNonUniformIndexingNative: 16,
+        shaderStorageBufferArrayNonUniformIndexingNative: 20,
+        shaderStorageImageArrayNonUniformIndexingNative: 24,
+        shaderInputAttachmentArrayNonUniformIndexingNative: 28,
+        robustBufferAccessUpdateAfterBind: 32, quadDivergentImplicitLod: 36,
+        maxPerStageDescriptorUpdateAfterBindSamplers: 40,
+        maxPerStageDescriptorUpdateAfterBindUniformBuffers: 44,
+        maxPerStageDescriptorUpdateAfterBindStorageBuffers: 48,
+        maxPerStageDescriptorUpdateAfterBindSampledImages: 52,
+        maxPerStageDescriptorUpdateAfterBindStorageImages: 56,
+        maxPerStageDescriptorUpdateAfterBindInputAttachments: 60,
+        maxPerStageUpdateAfterBindResources: 64, maxDescriptorSetUpdateAfterBindSamplers: 68,
+        maxDescriptorSetUpdateAfterBindUniformBuffers: 72,
+        maxDescriptorSetUpdateAfterBindUniformBuffersDynamic: 76,
+        maxDescriptorSetUpdateAfterBindStorageBuffers: 80,
+        maxDescriptorSetUpdateAfterBindStorageBuffersDynamic: 84,
+        maxDescriptorSetUpdateAfterBindSampledImages: 88,
+        maxDescriptorSetUpdateAfterBindStorageImages: 92,
+        maxDescriptorSetUpdateAfterBindInputAttachments: 96);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetVariableDescriptorCountAllocateInfo, 32, 8, sType: 0, pNext: 8,
+        descriptorSetCount: 16, pDescriptorCounts: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorSetVariableDescriptorCountAllocateInfo, 16, 4, sType: 0, pNext: 4,
+        descriptorSetCount: 8, pDescriptorCounts: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorSetVariableDescriptorCountLayoutSupport, 24, 8, sType: 0, pNext: 8,
+        maxVariableDescriptorCount: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorSetVariableDescriptorCountLayoutSupport, 12, 4, sType: 0, pNext: 4,
+        maxVariableDescriptorCount: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubpassDescriptionDepthStencilResolve, 32, 8, sType: 0, pNext: 8,
+        depthResolveMode: 16, stencilResolveMode: 20, pDepthStencilResolveAttachment: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubpassDescriptionDepthStencilResolve, 20, 4, sType: 0, pNext: 4,
+        depthResolveMode: 8, stencilResolveMode: 12, pDepthStencilResolveAttachment: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceDepthStencilResolveProperties, 32, 8, sType: 0, pNext: 8,
+        supportedDepthResolveModes: 16, supportedStencilResolveModes: 20,
+        independentResolveNone: 24, independentResolve: 28);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceDepthStencilResolveProperties, 24, 4, sType: 0, pNext: 4,
+        supportedDepthResolveModes: 8, supportedStencilResolveModes: 12,
+        independentResolveNone: 16, independentResolve: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceScalarBlockLayoutFeatures, 24, 8, sType: 0, pNext: 8,
+        scalarBlockLayout: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceScalarBlockLayoutFeatures, 12, 4, sType: 0, pNext: 4,
+        scalarBlockLayout: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageStencilUsageCreateInfo, 24, 8, sType: 0, pNext: 8, stencilUsage: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageStencilUsageCreateInfo, 12, 4, sType: 0, pNext: 4, stencilUsage: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SamplerReductionModeCreateInfo, 24, 8, sType: 0, pNext: 8, reductionMode: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SamplerReductionModeCreateInfo, 12, 4, sType: 0, pNext: 4, reductionMode: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSamplerFilterMinmaxProperties, 24, 8, sType: 0, pNext: 8,
+        filterMinmaxSingleComponentFormats: 16, filterMinmaxImageComponentMapping: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSamplerFilterMinmaxProperties, 16, 4, sType: 0, pNext: 4,
+        filterMinmaxSingleComponentFormats: 8, filterMinmaxImageComponentMapping: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkanMemoryModelFeatures, 32, 8, sType: 0, pNext: 8,
+        vulkanMemoryModel: 16, vulkanMemoryModelDeviceScope: 20,
+        vulkanMemoryModelAvailabilityVisibilityChains: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceVulkanMemoryModelFeatures, 20, 4, sType: 0, pNext: 4,
+        vulkanMemoryModel: 8, vulkanMemoryModelDeviceScope: 12,
+        vulkanMemoryModelAvailabilityVisibilityChains: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceImagelessFramebufferFeatures, 24, 8, sType: 0, pNext: 8,
+        imagelessFramebuffer: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceImagelessFramebufferFeatures, 12, 4, sType: 0, pNext: 4,
+        imagelessFramebuffer: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::FramebufferAttachmentImageInfo, 48, 8, sType: 0, pNext: 8, flags: 16,
+        usage: 20, width: 24, height: 28, layerCount: 32, viewFormatCount: 36, pViewFormats: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::FramebufferAttachmentImageInfo, 36, 4, sType: 0, pNext: 4, flags: 8,
+        usage: 12, width: 16, height: 20, layerCount: 24, viewFormatCount: 28, pViewFormats: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::FramebufferAttachmentsCreateInfo, 32, 8, sType: 0, pNext: 8,
+        attachmentImageInfoCount: 16, pAttachmentImageInfos: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::FramebufferAttachmentsCreateInfo, 16, 4, sType: 0, pNext: 4,
+        attachmentImageInfoCount: 8, pAttachmentImageInfos: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderPassAttachmentBeginInfo, 32, 8, sType: 0, pNext: 8,
+        attachmentCount: 16, pAttachments: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::RenderPassAttachmentBeginInfo, 16, 4, sType: 0, pNext: 4, attachmentCount: 8,
+        pAttachments: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceUniformBufferStandardLayoutFeatures, 24, 8, sType: 0, pNext: 8,
+        uniformBufferStandardLayout: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceUniformBufferStandardLayoutFeatures, 12, 4, sType: 0, pNext: 4,
+        uniformBufferStandardLayout: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures, 24, 8, sType: 0, pNext: 8,
+        shaderSubgroupExtendedTypes: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderSubgroupExtendedTypesFeatures, 12, 4, sType: 0, pNext: 4,
+        shaderSubgroupExtendedTypes: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures, 24, 8, sType: 0, pNext: 8,
+        separateDepthStencilLayouts: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSeparateDepthStencilLayoutsFeatures, 12, 4, sType: 0, pNext: 4,
+        separateDepthStencilLayouts: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::AttachmentReferenceStencilLayout, 24, 8, sType: 0, pNext: 8,
+        stencilLayout: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::AttachmentReferenceStencilLayout, 12, 4, sType: 0, pNext: 4,
+        stencilLayout: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::AttachmentDescriptionStencilLayout, 24, 8, sType: 0, pNext: 8,
+        stencilInitialLayout: 16, stencilFinalLayout: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::AttachmentDescriptionStencilLayout, 16, 4, sType: 0, pNext: 4,
+        stencilInitialLayout: 8, stencilFinalLayout: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceHostQueryResetFeatures, 24, 8, sType: 0, pNext: 8,
+        hostQueryReset: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceHostQueryResetFeatures, 12, 4, sType: 0, pNext: 4,
+        hostQueryReset: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceTimelineSemaphoreFeatures, 24, 8, sType: 0, pNext: 8,
+        timelineSemaphore: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceTimelineSemaphoreFeatures, 12, 4, sType: 0, pNext: 4,
+        timelineSemaphore: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceTimelineSemaphoreProperties, 24, 8, sType: 0, pNext: 8,
+        maxTimelineSemaphoreValueDifference: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceTimelineSemaphoreProperties, 16, 4, sType: 0, pNext: 4,
+        maxTimelineSemaphoreValueDifference: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceTimelineSemaphoreProperties, 16, 8, sType: 0, pNext: 4,
+        maxTimelineSemaphoreValueDifference: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SemaphoreTypeCreateInfo, 32, 8, sType: 0, pNext: 8, semaphoreType: 16,
+        initialValue: 24);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SemaphoreTypeCreateInfo, 20, 4, sType: 0, pNext: 4, semaphoreType: 8,
+        initialValue: 12);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SemaphoreTypeCreateInfo, 24, 8, sType: 0, pNext: 4, semaphoreType: 8,
+        initialValue: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::TimelineSemaphoreSubmitInfo, 48, 8, sType: 0, pNext: 8,
+        waitSemaphoreValueCount: 16, pWaitSemaphoreValues: 24, signalSemaphoreValueCount: 32,
+        pSignalSemaphoreValues: 40);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::TimelineSemaphoreSubmitInfo, 24, 4, sType: 0, pNext: 4,
+        waitSemaphoreValueCount: 8, pWaitSemaphoreValues: 12, signalSemaphoreValueCount: 16,
+        pSignalSemaphoreValues: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SemaphoreWaitInfo, 40, 8, sType: 0, pNext: 8, flags: 16, semaphoreCount: 20,
+        pSemaphores: 24, pValues: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SemaphoreWaitInfo, 24, 4, sType: 0, pNext: 4, flags: 8, semaphoreCount: 12,
+        pSemaphores: 16, pValues: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SemaphoreSignalInfo, 32, 8, sType: 0, pNext: 8, semaphore: 16, value: 24);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SemaphoreSignalInfo, 24, 4, sType: 0, pNext: 4, semaphore: 8, value: 16);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SemaphoreSignalInfo, 24, 8, sType: 0, pNext: 4, semaphore: 8, value: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceBufferDeviceAddressFeatures, 32, 8, sType: 0, pNext: 8,
+        bufferDeviceAddress: 16, bufferDeviceAddressCaptureReplay: 20,
+        bufferDeviceAddressMultiDevice: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceBufferDeviceAddressFeatures, 20, 4, sType: 0, pNext: 4,
+        bufferDeviceAddress: 8, bufferDeviceAddressCaptureReplay: 12,
+        bufferDeviceAddressMultiDevice: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferDeviceAddressInfo, 24, 8, sType: 0, pNext: 8, buffer: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferDeviceAddressInfo, 16, 4, sType: 0, pNext: 4, buffer: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferDeviceAddressInfo, 16, 8, sType: 0, pNext: 4, buffer: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferOpaqueCaptureAddressCreateInfo, 24, 8, sType: 0, pNext: 8,
+        opaqueCaptureAddress: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferOpaqueCaptureAddressCreateInfo, 16, 4, sType: 0, pNext: 4,
+        opaqueCaptureAddress: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferOpaqueCaptureAddressCreateInfo, 16, 8, sType: 0, pNext: 4,
+        opaqueCaptureAddress: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryOpaqueCaptureAddressAllocateInfo, 24, 8, sType: 0, pNext: 8,
+        opaqueCaptureAddress: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryOpaqueCaptureAddressAllocateInfo, 16, 4, sType: 0, pNext: 4,
+        opaqueCaptureAddress: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::MemoryOpaqueCaptureAddressAllocateInfo, 16, 8, sType: 0, pNext: 4,
+        opaqueCaptureAddress: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceMemoryOpaqueCaptureAddressInfo, 24, 8, sType: 0, pNext: 8, memory: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::DeviceMemoryOpaqueCaptureAddressInfo, 16, 4, sType: 0, pNext: 4, memory: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::DeviceMemoryOpaqueCaptureAddressInfo, 16, 8, sType: 0, pNext: 4, memory: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkan13Features, 80, 8, sType: 0, pNext: 8,
+        robustImageAccess: 16, inlineUniformBlock: 20,
+        descriptorBindingInlineUniformBlockUpdateAfterBind: 24, pipelineCreationCacheControl: 28,
+        privateData: 32, shaderDemoteToHelperInvocation: 36, shaderTerminateInvocation: 40,
+        subgroupSizeControl: 44, computeFullSubgroups: 48, synchronization2: 52,
+        textureCompressionASTC_HDR: 56, shaderZeroInitializeWorkgroupMemory: 60,
+        dynamicRendering: 64, shaderIntegerDotProduct: 68, maintenance4: 72);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceVulkan13Features, 68, 4, sType: 0, pNext: 4,
+        robustImageAccess: 8, inlineUniformBlock: 12,
+        descriptorBindingInlineUniformBlockUpdateAfterBind: 16, pipelineCreationCacheControl: 20,
+        privateData: 24, shaderDemoteToHelperInvocation: 28, shaderTerminateInvocation: 32,
+        subgroupSizeControl: 36, computeFullSubgroups: 40, synchronization2: 44,
+        textureCompressionASTC_HDR: 48, shaderZeroInitializeWorkgroupMemory: 52,
+        dynamicRendering: 56, shaderIntegerDotProduct: 60, maintenance4: 64);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceVulkan13Properties, 216, 8, sType: 0, pNext: 8,
+        minSubgroupSize: 16, maxSubgroupSize: 20, maxComputeWorkgroupSubgroups: 24,
+        requiredSubgroupSizeStages: 28, maxInlineUniformBlockSize: 32,
+        maxPerStageDescriptorInlineUniformBlocks: 36,
+        maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: 40,
+        maxDescriptorSetInlineUniformBlocks: 44,
+        maxDescriptorSetUpdateAfterBindInlineUniformBlocks: 48, maxInlineUniformTotalSize: 52,
+        integerDotProduct8BitUnsignedAccelerated: 56, integerDotProduct8BitSignedAccelerated: 60,
+        integerDotProduct8BitMixedSignednessAccelerated: 64,
+        integerDotProduct4x8BitPackedUnsignedAccelerated: 68,
+        integerDotProduct4x8BitPackedSignedAccelerated: 72,
+        integerDotProduct4x8BitPackedMixedSignednessAccelerated: 76,
+        integerDotProduct16BitUnsignedAccelerated: 80, integerDotProduct16BitSignedAccelerated: 84,
+        integerDotProduct16BitMixedSignednessAccelerated: 88,
+        integerDotProduct32BitUnsignedAccelerated: 92, integerDotProduct32BitSignedAccelerated: 96,
+        integerDotProduct32BitMixedSignednessAccelerated: 100,
+        integerDotProduct64BitUnsignedAccelerated: 104,
+        integerDotProduct64BitSignedAccelerated: 108,
+        integerDotProduct64BitMixedSignednessAccelerated: 112,
+        integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: 116,
+        integerDotProductAccumulatingSaturating8BitSignedAccelerated: 120,
+        integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: 124,
+        integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: 128,
+        integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: 132,
+        integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: 136,
+        integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: 140,
+        integerDotProductAccumulatingSaturating16BitSignedAccelerated: 144,
+        integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: 148,
+        integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: 152,
+        integerDotProductAccumulatingSaturating32BitSignedAccelerated: 156,
+        integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: 160,
+        integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: 164,
+        integerDotProductAccumulatingSaturating64BitSignedAccelerated: 168,
+        integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: 172,
+        storageTexelBufferOffsetAlignmentBytes: 176,
+        storageTexelBufferOffsetSingleTexelAlignment: 184,
+        uniformTexelBufferOffsetAlignmentBytes: 192,
+        uniformTexelBufferOffsetSingleTexelAlignment: 200, maxBufferSize: 208);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceVulkan13Properties, 200, 4, sType: 0, pNext: 4,
+        minSubgroupSize: 8, maxSubgroupSize: 12, maxComputeWorkgroupSubgroups: 16,
+        requiredSubgroupSizeStages: 20, maxInlineUniformBlockSize: 24,
+        maxPerStageDescriptorInlineUniformBlocks: 28,
+        maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: 32,
+        maxDescriptorSetInlineUniformBlocks: 36,
+        maxDescriptorSetUpdateAfterBindInlineUniformBlocks: 40, maxInlineUniformTotalSize: 44,
+        integerDotProduct8BitUnsignedAccelerated: 48, integerDotProduct8BitSignedAccelerated: 52,
+        integerDotProduct8BitMixedSignednessAccelerated: 56,
+        integerDotProduct4x8BitPackedUnsignedAccelerated: 60,
+        integerDotProduct4x8BitPackedSignedAccelerated: 64,
+        integerDotProduct4x8BitPackedMixedSignednessAccelerated: 68,
+        integerDotProduct16BitUnsignedAccelerated: 72, integerDotProduct16BitSignedAccelerated: 76,
+        integerDotProduct16BitMixedSignednessAccelerated: 80,
+        integerDotProduct32BitUnsignedAccelerated: 84, integerDotProduct32BitSignedAccelerated: 88,
+        integerDotProduct32BitMixedSignednessAccelerated: 92,
+        integerDotProduct64BitUnsignedAccelerated: 96,
+        integerDotProduct64BitSignedAccelerated: 100,
+        integerDotProduct64BitMixedSignednessAccelerated: 104,
+        integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: 108,
+        integerDotProductAccumulatingSaturating8BitSignedAccelerated: 112,
+        integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: 116,
+        integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: 120,
+        integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: 124,
+        integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: 128,
+        integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: 132,
+        integerDotProductAccumulatingSaturating16BitSignedAccelerated: 136,
+        integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: 140,
+        integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: 144,
+        integerDotProductAccumulatingSaturating32BitSignedAccelerated: 148,
+        integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: 152,
+        integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: 156,
+        integerDotProductAccumulatingSaturating64BitSignedAccelerated: 160,
+        integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: 164,
+        storageTexelBufferOffsetAlignmentBytes: 168,
+        storageTexelBufferOffsetSingleTexelAlignment: 176,
+        uniformTexelBufferOffsetAlignmentBytes: 180,
+        uniformTexelBufferOffsetSingleTexelAlignment: 188, maxBufferSize: 192);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceVulkan13Properties, 208, 8, sType: 0, pNext: 4,
+        minSubgroupSize: 8, maxSubgroupSize: 12, maxComputeWorkgroupSubgroups: 16,
+        requiredSubgroupSizeStages: 20, maxInlineUniformBlockSize: 24,
+        maxPerStageDescriptorInlineUniformBlocks: 28,
+        maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: 32,
+        maxDescriptorSetInlineUniformBlocks: 36,
+        maxDescriptorSetUpdateAfterBindInlineUniformBlocks: 40, maxInlineUniformTotalSize: 44,
+        integerDotProduct8BitUnsignedAccelerated: 48, integerDotProduct8BitSignedAccelerated: 52,
+        integerDotProduct8BitMixedSignednessAccelerated: 56,
+        integerDotProduct4x8BitPackedUnsignedAccelerated: 60,
+        integerDotProduct4x8BitPackedSignedAccelerated: 64,
+        integerDotProduct4x8BitPackedMixedSignednessAccelerated: 68,
+        integerDotProduct16BitUnsignedAccelerated: 72, integerDotProduct16BitSignedAccelerated: 76,
+        integerDotProduct16BitMixedSignednessAccelerated: 80,
+        integerDotProduct32BitUnsignedAccelerated: 84, integerDotProduct32BitSignedAccelerated: 88,
+        integerDotProduct32BitMixedSignednessAccelerated: 92,
+        integerDotProduct64BitUnsignedAccelerated: 96,
+        integerDotProduct64BitSignedAccelerated: 100,
+        integerDotProduct64BitMixedSignednessAccelerated: 104,
+        integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: 108,
+        integerDotProductAccumulatingSaturating8BitSignedAccelerated: 112,
+        integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: 116,
+        integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: 120,
+        integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: 124,
+        integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: 128,
+        integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: 132,
+        integerDotProductAccumulatingSaturating16BitSignedAccelerated: 136,
+        integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: 140,
+        integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: 144,
+        integerDotProductAccumulatingSaturating32BitSignedAccelerated: 148,
+        integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: 152,
+        integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: 156,
+        integerDotProductAccumulatingSaturating64BitSignedAccelerated: 160,
+        integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: 164,
+        storageTexelBufferOffsetAlignmentBytes: 168,
+        storageTexelBufferOffsetSingleTexelAlignment: 176,
+        uniformTexelBufferOffsetAlignmentBytes: 184,
+        uniformTexelBufferOffsetSingleTexelAlignment: 192, maxBufferSize: 200);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::PipelineCreationFeedback, 16, 8, flags: 0, duration: 8);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PipelineCreationFeedback, 12, 4, flags: 0, duration: 4);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineCreationFeedbackCreateInfo, 40, 8, sType: 0, pNext: 8,
+        pPipelineCreationFeedback: 16, pipelineStageCreationFeedbackCount: 24,
+        pPipelineStageCreationFeedbacks: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineCreationFeedbackCreateInfo, 20, 4, sType: 0, pNext: 4,
+        pPipelineCreationFeedback: 8, pipelineStageCreationFeedbackCount: 12,
+        pPipelineStageCreationFeedbacks: 16);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderTerminateInvocationFeatures, 24, 8, sType: 0, pNext: 8,
+        shaderTerminateInvocation: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderTerminateInvocationFeatures, 12, 4, sType: 0, pNext: 4,
+        shaderTerminateInvocation: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceToolProperties, 1048, 8, sType: 0, pNext: 8, name: 16,
+        version: 272, purposes: 528, description: 532, layer: 788);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceToolProperties, 1036, 4, sType: 0, pNext: 4, name: 8,
+        version: 264, purposes: 520, description: 524, layer: 780);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures, 24, 8, sType: 0,
+        pNext: 8, shaderDemoteToHelperInvocation: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderDemoteToHelperInvocationFeatures, 12, 4, sType: 0,
+        pNext: 4, shaderDemoteToHelperInvocation: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDevicePrivateDataFeatures, 24, 8, sType: 0, pNext: 8,
+        privateData: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDevicePrivateDataFeatures, 12, 4, sType: 0, pNext: 4, privateData: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DevicePrivateDataCreateInfo, 24, 8, sType: 0, pNext: 8,
+        privateDataSlotRequestCount: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DevicePrivateDataCreateInfo, 12, 4, sType: 0, pNext: 4,
+        privateDataSlotRequestCount: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PrivateDataSlotCreateInfo, 24, 8, sType: 0, pNext: 8, flags: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PrivateDataSlotCreateInfo, 12, 4, sType: 0, pNext: 4, flags: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDevicePipelineCreationCacheControlFeatures, 24, 8, sType: 0,
+        pNext: 8, pipelineCreationCacheControl: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDevicePipelineCreationCacheControlFeatures, 12, 4, sType: 0,
+        pNext: 4, pipelineCreationCacheControl: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::MemoryBarrier2, 48, 8, sType: 0, pNext: 8, srcStageMask: 16,
+        srcAccessMask: 24, dstStageMask: 32, dstAccessMask: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::MemoryBarrier2, 40, 4, sType: 0, pNext: 4, srcStageMask: 8,
+        srcAccessMask: 16, dstStageMask: 24, dstAccessMask: 32);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::MemoryBarrier2, 40, 8, sType: 0, pNext: 4, srcStageMask: 8,
+        srcAccessMask: 16, dstStageMask: 24, dstAccessMask: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferMemoryBarrier2, 80, 8, sType: 0, pNext: 8, srcStageMask: 16,
+        srcAccessMask: 24, dstStageMask: 32, dstAccessMask: 40, srcQueueFamilyIndex: 48,
+        dstQueueFamilyIndex: 52, buffer: 56, offset: 64, size: 72);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferMemoryBarrier2, 72, 4, sType: 0, pNext: 4, srcStageMask: 8,
+        srcAccessMask: 16, dstStageMask: 24, dstAccessMask: 32, srcQueueFamilyIndex: 40,
+        dstQueueFamilyIndex: 44, buffer: 48, offset: 56, size: 64);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferMemoryBarrier2, 72, 8, sType: 0, pNext: 4, srcStageMask: 8,
+        srcAccessMask: 16, dstStageMask: 24, dstAccessMask: 32, srcQueueFamilyIndex: 40,
+        dstQueueFamilyIndex: 44, buffer: 48, offset: 56, size: 64);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageMemoryBarrier2, 96, 8, sType: 0, pNext: 8, srcStageMask: 16,
+        srcAccessMask: 24, dstStageMask: 32, dstAccessMask: 40, oldLayout: 48, newLayout: 52,
+        srcQueueFamilyIndex: 56, dstQueueFamilyIndex: 60, image: 64, subresourceRange: 72);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ImageMemoryBarrier2, 84, 4, sType: 0, pNext: 4, srcStageMask: 8,
+        srcAccessMask: 16, dstStageMask: 24, dstAccessMask: 32, oldLayout: 40, newLayout: 44,
+        srcQueueFamilyIndex: 48, dstQueueFamilyIndex: 52, image: 56, subresourceRange: 64);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ImageMemoryBarrier2, 88, 8, sType: 0, pNext: 4, srcStageMask: 8,
+        srcAccessMask: 16, dstStageMask: 24, dstAccessMask: 32, oldLayout: 40, newLayout: 44,
+        srcQueueFamilyIndex: 48, dstQueueFamilyIndex: 52, image: 56, subresourceRange: 64);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DependencyInfo, 64, 8, sType: 0, pNext: 8, dependencyFlags: 16,
+        memoryBarrierCount: 20, pMemoryBarriers: 24, bufferMemoryBarrierCount: 32,
+        pBufferMemoryBarriers: 40, imageMemoryBarrierCount: 48, pImageMemoryBarriers: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DependencyInfo, 36, 4, sType: 0, pNext: 4, dependencyFlags: 8,
+        memoryBarrierCount: 12, pMemoryBarriers: 16, bufferMemoryBarrierCount: 20,
+        pBufferMemoryBarriers: 24, imageMemoryBarrierCount: 28, pImageMemoryBarriers: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SemaphoreSubmitInfo, 48, 8, sType: 0, pNext: 8, semaphore: 16, value: 24,
+        stageMask: 32, deviceIndex: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::SemaphoreSubmitInfo, 36, 4, sType: 0, pNext: 4, semaphore: 8, value: 16,
+        stageMask: 24, deviceIndex: 32);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::SemaphoreSubmitInfo, 40, 8, sType: 0, pNext: 4, semaphore: 8, value: 16,
+        stageMask: 24, deviceIndex: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CommandBufferSubmitInfo, 32, 8, sType: 0, pNext: 8, commandBuffer: 16,
+        deviceMask: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::CommandBufferSubmitInfo, 16, 4, sType: 0, pNext: 4, commandBuffer: 8,
+        deviceMask: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::SubmitInfo2, 64, 8, sType: 0, pNext: 8, flags: 16,
+        waitSemaphoreInfoCount: 20, pWaitSemaphoreInfos: 24, commandBufferInfoCount: 32,
+        pCommandBufferInfos: 40, signalSemaphoreInfoCount: 48, pSignalSemaphoreInfos: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::SubmitInfo2, 36, 4, sType: 0, pNext: 4, flags: 8, waitSemaphoreInfoCount: 12,
+        pWaitSemaphoreInfos: 16, commandBufferInfoCount: 20, pCommandBufferInfos: 24,
+        signalSemaphoreInfoCount: 28, pSignalSemaphoreInfos: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSynchronization2Features, 24, 8, sType: 0, pNext: 8,
+        synchronization2: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSynchronization2Features, 12, 4, sType: 0, pNext: 4,
+        synchronization2: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, 24, 8, sType: 0,
+        pNext: 8, shaderZeroInitializeWorkgroupMemory: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures, 12, 4, sType: 0,
+        pNext: 4, shaderZeroInitializeWorkgroupMemory: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceImageRobustnessFeatures, 24, 8, sType: 0, pNext: 8,
+        robustImageAccess: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceImageRobustnessFeatures, 12, 4, sType: 0, pNext: 4,
+        robustImageAccess: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferCopy2, 40, 8, sType: 0, pNext: 8, srcOffset: 16, dstOffset: 24,
+        size: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferCopy2, 32, 4, sType: 0, pNext: 4, srcOffset: 8, dstOffset: 16,
+        size: 24);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferCopy2, 32, 8, sType: 0, pNext: 4, srcOffset: 8, dstOffset: 16,
+        size: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CopyBufferInfo2, 48, 8, sType: 0, pNext: 8, srcBuffer: 16, dstBuffer: 24,
+        regionCount: 32, pRegions: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CopyBufferInfo2, 32, 4, sType: 0, pNext: 4, srcBuffer: 8, dstBuffer: 16,
+        regionCount: 24, pRegions: 28);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CopyBufferInfo2, 32, 8, sType: 0, pNext: 4, srcBuffer: 8, dstBuffer: 16,
+        regionCount: 24, pRegions: 28);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageCopy2, 88, 8, sType: 0, pNext: 8, srcSubresource: 16, srcOffset: 32,
+        dstSubresource: 44, dstOffset: 60, extent: 72);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageCopy2, 76, 4, sType: 0, pNext: 4, srcSubresource: 8, srcOffset: 24,
+        dstSubresource: 36, dstOffset: 52, extent: 64);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CopyImageInfo2, 56, 8, sType: 0, pNext: 8, srcImage: 16, srcImageLayout: 24,
+        dstImage: 32, dstImageLayout: 40, regionCount: 44, pRegions: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CopyImageInfo2, 40, 4, sType: 0, pNext: 4, srcImage: 8, srcImageLayout: 16,
+        dstImage: 20, dstImageLayout: 28, regionCount: 32, pRegions: 36);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CopyImageInfo2, 48, 8, sType: 0, pNext: 4, srcImage: 8, srcImageLayout: 16,
+        dstImage: 24, dstImageLayout: 32, regionCount: 36, pRegions: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BufferImageCopy2, 72, 8, sType: 0, pNext: 8, bufferOffset: 16,
+        bufferRowLength: 24, bufferImageHeight: 28, imageSubresource: 32, imageOffset: 48,
+        imageExtent: 60);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BufferImageCopy2, 64, 4, sType: 0, pNext: 4, bufferOffset: 8,
+        bufferRowLength: 16, bufferImageHeight: 20, imageSubresource: 24, imageOffset: 40,
+        imageExtent: 52);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BufferImageCopy2, 64, 8, sType: 0, pNext: 4, bufferOffset: 8,
+        bufferRowLength: 16, bufferImageHeight: 20, imageSubresource: 24, imageOffset: 40,
+        imageExtent: 52);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CopyBufferToImageInfo2, 48, 8, sType: 0, pNext: 8, srcBuffer: 16,
+        dstImage: 24, dstImageLayout: 32, regionCount: 36, pRegions: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CopyBufferToImageInfo2, 36, 4, sType: 0, pNext: 4, srcBuffer: 8,
+        dstImage: 16, dstImageLayout: 24, regionCount: 28, pRegions: 32);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CopyBufferToImageInfo2, 40, 8, sType: 0, pNext: 4, srcBuffer: 8,
+        dstImage: 16, dstImageLayout: 24, regionCount: 28, pRegions: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CopyImageToBufferInfo2, 56, 8, sType: 0, pNext: 8, srcImage: 16,
+        srcImageLayout: 24, dstBuffer: 32, regionCount: 40, pRegions: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::CopyImageToBufferInfo2, 36, 4, sType: 0, pNext: 4, srcImage: 8,
+        srcImageLayout: 16, dstBuffer: 20, regionCount: 28, pRegions: 32);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::CopyImageToBufferInfo2, 40, 8, sType: 0, pNext: 4, srcImage: 8,
+        srcImageLayout: 16, dstBuffer: 24, regionCount: 32, pRegions: 36);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageBlit2, 96, 8, sType: 0, pNext: 8, srcSubresource: 16, srcOffsets: 32,
+        dstSubresource: 56, dstOffsets: 72);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageBlit2, 88, 4, sType: 0, pNext: 4, srcSubresource: 8, srcOffsets: 24,
+        dstSubresource: 48, dstOffsets: 64);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::BlitImageInfo2, 64, 8, sType: 0, pNext: 8, srcImage: 16, srcImageLayout: 24,
+        dstImage: 32, dstImageLayout: 40, regionCount: 44, pRegions: 48, filter: 56);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::BlitImageInfo2, 44, 4, sType: 0, pNext: 4, srcImage: 8, srcImageLayout: 16,
+        dstImage: 20, dstImageLayout: 28, regionCount: 32, pRegions: 36, filter: 40);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::BlitImageInfo2, 48, 8, sType: 0, pNext: 4, srcImage: 8, srcImageLayout: 16,
+        dstImage: 24, dstImageLayout: 32, regionCount: 36, pRegions: 40, filter: 44);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ImageResolve2, 88, 8, sType: 0, pNext: 8, srcSubresource: 16, srcOffset: 32,
+        dstSubresource: 44, dstOffset: 60, extent: 72);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ImageResolve2, 76, 4, sType: 0, pNext: 4, srcSubresource: 8, srcOffset: 24,
+        dstSubresource: 36, dstOffset: 52, extent: 64);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ResolveImageInfo2, 56, 8, sType: 0, pNext: 8, srcImage: 16,
+        srcImageLayout: 24, dstImage: 32, dstImageLayout: 40, regionCount: 44, pRegions: 48);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::ResolveImageInfo2, 40, 4, sType: 0, pNext: 4, srcImage: 8,
+        srcImageLayout: 16, dstImage: 20, dstImageLayout: 28, regionCount: 32, pRegions: 36);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::ResolveImageInfo2, 48, 8, sType: 0, pNext: 4, srcImage: 8,
+        srcImageLayout: 16, dstImage: 24, dstImageLayout: 32, regionCount: 36, pRegions: 40);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSubgroupSizeControlFeatures, 24, 8, sType: 0, pNext: 8,
+        subgroupSizeControl: 16, computeFullSubgroups: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSubgroupSizeControlFeatures, 16, 4, sType: 0, pNext: 4,
+        subgroupSizeControl: 8, computeFullSubgroups: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceSubgroupSizeControlProperties, 32, 8, sType: 0, pNext: 8,
+        minSubgroupSize: 16, maxSubgroupSize: 20, maxComputeWorkgroupSubgroups: 24,
+        requiredSubgroupSizeStages: 28);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceSubgroupSizeControlProperties, 24, 4, sType: 0, pNext: 4,
+        minSubgroupSize: 8, maxSubgroupSize: 12, maxComputeWorkgroupSubgroups: 16,
+        requiredSubgroupSizeStages: 20);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineShaderStageRequiredSubgroupSizeCreateInfo, 24, 8, sType: 0, pNext: 8,
+        requiredSubgroupSize: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineShaderStageRequiredSubgroupSizeCreateInfo, 12, 4, sType: 0, pNext: 4,
+        requiredSubgroupSize: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceInlineUniformBlockFeatures, 24, 8, sType: 0, pNext: 8,
+        inlineUniformBlock: 16, descriptorBindingInlineUniformBlockUpdateAfterBind: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceInlineUniformBlockFeatures, 16, 4, sType: 0, pNext: 4,
+        inlineUniformBlock: 8, descriptorBindingInlineUniformBlockUpdateAfterBind: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceInlineUniformBlockProperties, 40, 8, sType: 0, pNext: 8,
+        maxInlineUniformBlockSize: 16, maxPerStageDescriptorInlineUniformBlocks: 20,
+        maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: 24,
+        maxDescriptorSetInlineUniformBlocks: 28,
+        maxDescriptorSetUpdateAfterBindInlineUniformBlocks: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceInlineUniformBlockProperties, 28, 4, sType: 0, pNext: 4,
+        maxInlineUniformBlockSize: 8, maxPerStageDescriptorInlineUniformBlocks: 12,
+        maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks: 16,
+        maxDescriptorSetInlineUniformBlocks: 20,
+        maxDescriptorSetUpdateAfterBindInlineUniformBlocks: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::WriteDescriptorSetInlineUniformBlock, 32, 8, sType: 0, pNext: 8,
+        dataSize: 16, pData: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::WriteDescriptorSetInlineUniformBlock, 16, 4, sType: 0, pNext: 4, dataSize: 8,
+        pData: 12);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DescriptorPoolInlineUniformBlockCreateInfo, 24, 8, sType: 0, pNext: 8,
+        maxInlineUniformBlockBindings: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DescriptorPoolInlineUniformBlockCreateInfo, 12, 4, sType: 0, pNext: 4,
+        maxInlineUniformBlockBindings: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceTextureCompressionASTCHDRFeatures, 24, 8, sType: 0, pNext: 8,
+        textureCompressionASTC_HDR: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceTextureCompressionASTCHDRFeatures, 12, 4, sType: 0, pNext: 4,
+        textureCompressionASTC_HDR: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderingAttachmentInfo, 72, 8, sType: 0, pNext: 8, imageView: 16,
+        imageLayout: 24, resolveMode: 28, resolveImageView: 32, resolveImageLayout: 40, loadOp: 44,
+        storeOp: 48, clearValue: 52);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::RenderingAttachmentInfo, 60, 4, sType: 0, pNext: 4, imageView: 8,
+        imageLayout: 16, resolveMode: 20, resolveImageView: 24, resolveImageLayout: 32, loadOp: 36,
+        storeOp: 40, clearValue: 44);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::RenderingAttachmentInfo, 64, 8, sType: 0, pNext: 4, imageView: 8,
+        imageLayout: 16, resolveMode: 20, resolveImageView: 24, resolveImageLayout: 32, loadOp: 36,
+        storeOp: 40, clearValue: 44);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::RenderingInfo, 72, 8, sType: 0, pNext: 8, flags: 16, renderArea: 20,
+        layerCount: 36, viewMask: 40, colorAttachmentCount: 44, pColorAttachments: 48,
+        pDepthAttachment: 56, pStencilAttachment: 64);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::RenderingInfo, 52, 4, sType: 0, pNext: 4, flags: 8, renderArea: 12,
+        layerCount: 28, viewMask: 32, colorAttachmentCount: 36, pColorAttachments: 40,
+        pDepthAttachment: 44, pStencilAttachment: 48);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PipelineRenderingCreateInfo, 40, 8, sType: 0, pNext: 8, viewMask: 16,
+        colorAttachmentCount: 20, pColorAttachmentFormats: 24, depthAttachmentFormat: 32,
+        stencilAttachmentFormat: 36);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PipelineRenderingCreateInfo, 28, 4, sType: 0, pNext: 4, viewMask: 8,
+        colorAttachmentCount: 12, pColorAttachmentFormats: 16, depthAttachmentFormat: 20,
+        stencilAttachmentFormat: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceDynamicRenderingFeatures, 24, 8, sType: 0, pNext: 8,
+        dynamicRendering: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceDynamicRenderingFeatures, 12, 4, sType: 0, pNext: 4,
+        dynamicRendering: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::CommandBufferInheritanceRenderingInfo, 56, 8, sType: 0, pNext: 8, flags: 16,
+        viewMask: 20, colorAttachmentCount: 24, pColorAttachmentFormats: 32,
+        depthAttachmentFormat: 40, stencilAttachmentFormat: 44, rasterizationSamples: 48);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::CommandBufferInheritanceRenderingInfo, 36, 4, sType: 0, pNext: 4, flags: 8,
+        viewMask: 12, colorAttachmentCount: 16, pColorAttachmentFormats: 20,
+        depthAttachmentFormat: 24, stencilAttachmentFormat: 28, rasterizationSamples: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderIntegerDotProductFeatures, 24, 8, sType: 0, pNext: 8,
+        shaderIntegerDotProduct: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderIntegerDotProductFeatures, 12, 4, sType: 0, pNext: 4,
+        shaderIntegerDotProduct: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceShaderIntegerDotProductProperties, 136, 8, sType: 0, pNext: 8,
+        integerDotProduct8BitUnsignedAccelerated: 16, integerDotProduct8BitSignedAccelerated: 20,
+        integerDotProduct8BitMixedSignednessAccelerated: 24,
+        integerDotProduct4x8BitPackedUnsignedAccelerated: 28,
+        integerDotProduct4x8BitPackedSignedAccelerated: 32,
+        integerDotProduct4x8BitPackedMixedSignednessAccelerated: 36,
+        integerDotProduct16BitUnsignedAccelerated: 40, integerDotProduct16BitSignedAccelerated: 44,
+        integerDotProduct16BitMixedSignednessAccelerated: 48,
+        integerDotProduct32BitUnsignedAccelerated: 52, integerDotProduct32BitSignedAccelerated: 56,
+        integerDotProduct32BitMixedSignednessAccelerated: 60,
+        integerDotProduct64BitUnsignedAccelerated: 64, integerDotProduct64BitSignedAccelerated: 68,
+        integerDotProduct64BitMixedSignednessAccelerated: 72,
+        integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: 76,
+        integerDotProductAccumulatingSaturating8BitSignedAccelerated: 80,
+        integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: 84,
+        integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: 88,
+        integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: 92,
+        integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: 96,
+        integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: 100,
+        integerDotProductAccumulatingSaturating16BitSignedAccelerated: 104,
+        integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: 108,
+        integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: 112,
+        integerDotProductAccumulatingSaturating32BitSignedAccelerated: 116,
+        integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: 120,
+        integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: 124,
+        integerDotProductAccumulatingSaturating64BitSignedAccelerated: 128,
+        integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: 132);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceShaderIntegerDotProductProperties, 128, 4, sType: 0, pNext: 4,
+        integerDotProduct8BitUnsignedAccelerated: 8, integerDotProduct8BitSignedAccelerated: 12,
+        integerDotProduct8BitMixedSignednessAccelerated: 16,
+        integerDotProduct4x8BitPackedUnsignedAccelerated: 20,
+        integerDotProduct4x8BitPackedSignedAccelerated: 24,
+        integerDotProduct4x8BitPackedMixedSignednessAccelerated: 28,
+        integerDotProduct16BitUnsignedAccelerated: 32, integerDotProduct16BitSignedAccelerated: 36,
+        integerDotProduct16BitMixedSignednessAccelerated: 40,
+        integerDotProduct32BitUnsignedAccelerated: 44, integerDotProduct32BitSignedAccelerated: 48,
+        integerDotProduct32BitMixedSignednessAccelerated: 52,
+        integerDotProduct64BitUnsignedAccelerated: 56, integerDotProduct64BitSignedAccelerated: 60,
+        integerDotProduct64BitMixedSignednessAccelerated: 64,
+        integerDotProductAccumulatingSaturating8BitUnsignedAccelerated: 68,
+        integerDotProductAccumulatingSaturating8BitSignedAccelerated: 72,
+        integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated: 76,
+        integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated: 80,
+        integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated: 84,
+        integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated: 88,
+        integerDotProductAccumulatingSaturating16BitUnsignedAccelerated: 92,
+        integerDotProductAccumulatingSaturating16BitSignedAccelerated: 96,
+        integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated: 100,
+        integerDotProductAccumulatingSaturating32BitUnsignedAccelerated: 104,
+        integerDotProductAccumulatingSaturating32BitSignedAccelerated: 108,
+        integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated: 112,
+        integerDotProductAccumulatingSaturating64BitUnsignedAccelerated: 116,
+        integerDotProductAccumulatingSaturating64BitSignedAccelerated: 120,
+        integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated: 124);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceTexelBufferAlignmentProperties, 48, 8, sType: 0, pNext: 8,
+        storageTexelBufferOffsetAlignmentBytes: 16,
+        storageTexelBufferOffsetSingleTexelAlignment: 24,
+        uniformTexelBufferOffsetAlignmentBytes: 32,
+        uniformTexelBufferOffsetSingleTexelAlignment: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceTexelBufferAlignmentProperties, 32, 4, sType: 0, pNext: 4,
+        storageTexelBufferOffsetAlignmentBytes: 8,
+        storageTexelBufferOffsetSingleTexelAlignment: 16,
+        uniformTexelBufferOffsetAlignmentBytes: 20,
+        uniformTexelBufferOffsetSingleTexelAlignment: 28);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceTexelBufferAlignmentProperties, 40, 8, sType: 0, pNext: 4,
+        storageTexelBufferOffsetAlignmentBytes: 8,
+        storageTexelBufferOffsetSingleTexelAlignment: 16,
+        uniformTexelBufferOffsetAlignmentBytes: 24,
+        uniformTexelBufferOffsetSingleTexelAlignment: 32);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::FormatProperties3, 40, 8, sType: 0, pNext: 8, linearTilingFeatures: 16,
+        optimalTilingFeatures: 24, bufferFeatures: 32);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::FormatProperties3, 32, 4, sType: 0, pNext: 4, linearTilingFeatures: 8,
+        optimalTilingFeatures: 16, bufferFeatures: 24);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::FormatProperties3, 32, 8, sType: 0, pNext: 4, linearTilingFeatures: 8,
+        optimalTilingFeatures: 16, bufferFeatures: 24);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceMaintenance4Features, 24, 8, sType: 0, pNext: 8,
+        maintenance4: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::PhysicalDeviceMaintenance4Features, 12, 4, sType: 0, pNext: 4,
+        maintenance4: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::PhysicalDeviceMaintenance4Properties, 24, 8, sType: 0, pNext: 8,
+        maxBufferSize: 16);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::PhysicalDeviceMaintenance4Properties, 16, 4, sType: 0, pNext: 4,
+        maxBufferSize: 8);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::PhysicalDeviceMaintenance4Properties, 16, 8, sType: 0, pNext: 4,
+        maxBufferSize: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceBufferMemoryRequirements, 24, 8, sType: 0, pNext: 8, pCreateInfo: 16);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceBufferMemoryRequirements, 12, 4, sType: 0, pNext: 4, pCreateInfo: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::DeviceImageMemoryRequirements, 32, 8, sType: 0, pNext: 8, pCreateInfo: 16,
+        planeAspect: 24);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::DeviceImageMemoryRequirements, 16, 4, sType: 0, pNext: 4, pCreateInfo: 8,
+        planeAspect: 12);
+}
+
+#[test]
+fn unions() {
+    check_layout!(vk::ClearColorValue, 16, 4, float32: 0, int32: 0, uint32: 0);
+    check_layout!(vk::ClearValue, 16, 4, color: 0, depthStencil: 0);
+}
+
+#[test]
+fn khr_display() {
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::display::Properties, 48, 8, display: 0, displayName: 8,
+        physicalDimensions: 16, physicalResolution: 24, supportedTransforms: 32,
+        planeReorderPossible: 36, persistentContent: 40);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::khr::display::Properties, 40, 4, display: 0, displayName: 8,
+        physicalDimensions: 12, physicalResolution: 20, supportedTransforms: 28,
+        planeReorderPossible: 32, persistentContent: 36);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::khr::display::Properties, 40, 8, display: 0, displayName: 8,
+        physicalDimensions: 12, physicalResolution: 20, supportedTransforms: 28,
+        planeReorderPossible: 32, persistentContent: 36);
+    check_layout!(vk::khr::display::ModeParameters, 12, 4, visibleRegion: 0, refreshRate: 8);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::khr::display::ModeProperties, 24, 8, displayMode: 0, parameters: 8);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::khr::display::ModeProperties, 20, 4, displayMode: 0, parameters: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::display::ModeCreateInfo, 32, 8, sType: 0, pNext: 8, flags: 16,
+        parameters: 20);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::khr::display::ModeCreateInfo, 24, 4, sType: 0, pNext: 4, flags: 8,
+        parameters: 12);
+    check_layout!(vk::khr::display::PlaneCapabilities, 68, 4, supportedAlpha: 0, minSrcPosition: 4,
+        maxSrcPosition: 12, minSrcExtent: 20, maxSrcExtent: 28, minDstPosition: 36,
+        maxDstPosition: 44, minDstExtent: 52, maxDstExtent: 60);
+    #[cfg(not(all(target_arch = "x86", not(windows))))]
+    check_layout!(vk::khr::display::PlaneProperties, 16, 8, currentDisplay: 0,
+        currentStackIndex: 8);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::khr::display::PlaneProperties, 12, 4, currentDisplay: 0,
+        currentStackIndex: 8);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::display::SurfaceCreateInfo, 64, 8, sType: 0, pNext: 8, flags: 16,
+        displayMode: 24, planeIndex: 32, planeStackIndex: 36, transform: 40, globalAlpha: 44,
+        alphaMode: 48, imageExtent: 52);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::khr::display::SurfaceCreateInfo, 48, 4, sType: 0, pNext: 4, flags: 8,
+        displayMode: 12, planeIndex: 20, planeStackIndex: 24, transform: 28, globalAlpha: 32,
+        alphaMode: 36, imageExtent: 40);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::khr::display::SurfaceCreateInfo, 56, 8, sType: 0, pNext: 4, flags: 8,
+        displayMode: 16, planeIndex: 24, planeStackIndex: 28, transform: 32, globalAlpha: 36,
+        alphaMode: 40, imageExtent: 44);
+}
+
+#[test]
+fn khr_display_swapchain() {
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::display_swapchain::DisplayPresentInfo, 56, 8, sType: 0, pNext: 8,
+        srcRect: 16, dstRect: 32, persistent: 48);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::khr::display_swapchain::DisplayPresentInfo, 44, 4, sType: 0, pNext: 4,
+        srcRect: 8, dstRect: 24, persistent: 40);
+}
+
+#[test]
+fn khr_surface() {
+    check_layout!(vk::khr::surface::Capabilities, 52, 4, minImageCount: 0, maxImageCount: 4,
+        currentExtent: 8, minImageExtent: 16, maxImageExtent: 24, maxImageArrayLayers: 32,
+        supportedTransforms: 36, currentTransform: 40, supportedCompositeAlpha: 44,
+        supportedUsageFlags: 48);
+    check_layout!(vk::khr::surface::Format, 8, 4, format: 0, colorSpace: 4);
+}
+
+#[test]
+fn khr_swapchain() {
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::swapchain::CreateInfo, 104, 8, sType: 0, pNext: 8, flags: 16,
+        surface: 24, minImageCount: 32, imageFormat: 36, imageColorSpace: 40, imageExtent: 44,
+        imageArrayLayers: 52, imageUsage: 56, imageSharingMode: 60, queueFamilyIndexCount: 64,
+        pQueueFamilyIndices: 72, preTransform: 80, compositeAlpha: 84, presentMode: 88,
+        clipped: 92, oldSwapchain: 96);
+    #[cfg(all(target_arch = "x86", not(windows)))]
+    check_layout!(vk::khr::swapchain::CreateInfo, 84, 4, sType: 0, pNext: 4, flags: 8, surface: 12,
+        minImageCount: 20, imageFormat: 24, imageColorSpace: 28, imageExtent: 32,
+        imageArrayLayers: 40, imageUsage: 44, imageSharingMode: 48, queueFamilyIndexCount: 52,
+        pQueueFamilyIndices: 56, preTransform: 60, compositeAlpha: 64, presentMode: 68,
+        clipped: 72, oldSwapchain: 76);
+    #[cfg(all(target_pointer_width = "32", any(not(target_arch = "x86"), windows)))]
+    check_layout!(vk::khr::swapchain::CreateInfo, 88, 8, sType: 0, pNext: 4, flags: 8, surface: 16,
+        minImageCount: 24, imageFormat: 28, imageColorSpace: 32, imageExtent: 36,
+        imageArrayLayers: 44, imageUsage: 48, imageSharingMode: 52, queueFamilyIndexCount: 56,
+        pQueueFamilyIndices: 60, preTransform: 64, compositeAlpha: 68, presentMode: 72,
+        clipped: 76, oldSwapchain: 80);
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::swapchain::PresentInfo, 64, 8, sType: 0, pNext: 8,
+        waitSemaphoreCount: 16, pWaitSemaphores: 24, swapchainCount: 32, pSwapchains: 40,
+        pImageIndices: 48, pResults: 56);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::khr::swapchain::PresentInfo, 32, 4, sType: 0, pNext: 4,
+        waitSemaphoreCount: 8, pWaitSemaphores: 12, swapchainCount: 16, pSwapchains: 20,
+        pImageIndices: 24, pResults: 28);
+}
+
+#[test]
+fn ext_debug_report() {
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::ext::debug_report::CreateInfo, 40, 8, sType: 0, pNext: 8, flags: 16,
+        pfnCallback: 24, pUserData: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::ext::debug_report::CreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        pfnCallback: 12, pUserData: 16);
+}
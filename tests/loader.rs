@@ -0,0 +1,32 @@
+//! Loading entry points and the errors when they are missing.
+extern crate vulkan_bind;
+
+use std::ptr::{null, null_mut};
+
+use vulkan_bind::vk;
+
+#[test]
+fn missing_library() {
+    match vk::Entry::load_from("/nonexistent/libvulkan.so.1") {
+        Err(vk::LoadingError::Library(_)) => { }
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(_) => panic!("loaded a nonexistent library"),
+    }
+}
+
+#[test]
+fn missing_entry_points() {
+    let fns = unsafe { vk::EntryFns::load_with(|_| None) };
+    assert_eq!(fns.missing(), ["vkCreateInstance", "vkEnumerateInstanceExtensionProperties",
+                               "vkEnumerateInstanceLayerProperties"]);
+}
+
+#[test]
+#[should_panic(expected = "vkCreateInstance was not loaded")]
+fn calling_a_missing_entry_point_panics() {
+    let fns = unsafe { vk::EntryFns::load_with(|_| None) };
+    let mut instance = null_mut();
+    unsafe {
+        fns.vkCreateInstance(&Default::default(), null(), &mut instance);
+    }
+}
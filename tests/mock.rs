@@ -0,0 +1,186 @@
+//! Behavior of the mock implementation itself.
+#![cfg(feature = "mock")]
+
+extern crate vulkan_bind;
+
+use std::ffi::CStr;
+use std::os::raw::c_void;
+use std::ptr::{null, null_mut};
+use std::slice;
+use std::thread;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::mock;
+
+mod common;
+
+use common::Setup;
+
+fn cstr(bytes: &[u8]) -> &CStr {
+    CStr::from_bytes_with_nul(bytes).unwrap()
+}
+
+unsafe fn allocate(fns: &vk::DeviceFns, device: vk::Device, size: vk::DeviceSize,
+                   memory_type: u32) -> ::std::result::Result<vk::DeviceMemory, vk::Result> {
+    let info = vk::MemoryAllocateInfo {
+        allocationSize: size,
+        memoryTypeIndex: memory_type,
+        ..Default::default()
+    };
+    let mut memory = vk::DeviceMemory::null();
+    match fns.vkAllocateMemory(device, &info, null(), &mut memory) {
+        vk::Result::SUCCESS => Ok(memory),
+        err => Err(err),
+    }
+}
+
+unsafe fn map(fns: &vk::DeviceFns, device: vk::Device, memory: vk::DeviceMemory,
+              offset: vk::DeviceSize, size: vk::DeviceSize)
+              -> ::std::result::Result<*mut u8, vk::Result> {
+    let mut data: *mut c_void = null_mut();
+    match fns.vkMapMemory(device, memory, offset, size, Default::default(), &mut data) {
+        vk::Result::SUCCESS => Ok(data as *mut u8),
+        err => Err(err),
+    }
+}
+
+#[test]
+fn mapped_contents_survive_unmap() {
+    let setup = Setup::new();
+    let (device, fns) = setup.device();
+    unsafe {
+        let memory = allocate(&fns, device, 4096, 1).unwrap();
+        let data = map(&fns, device, memory, 0, vk::WHOLE_SIZE).unwrap();
+        slice::from_raw_parts_mut(data, 4096)[1000..1004].copy_from_slice(b"mock");
+        fns.vkUnmapMemory(device, memory);
+
+        let data = map(&fns, device, memory, 1000, 4).unwrap();
+        assert_eq!(slice::from_raw_parts(data, 4), b"mock");
+        fns.vkUnmapMemory(device, memory);
+        fns.vkFreeMemory(device, memory, null());
+        assert!(mock::take_errors(device).is_empty());
+    }
+}
+
+#[test]
+fn map_checks_the_range() {
+    let setup = Setup::new();
+    let (device, fns) = setup.device();
+    unsafe {
+        let memory = allocate(&fns, device, 4096, 1).unwrap();
+        let failed = vk::Result::ERROR_MEMORY_MAP_FAILED;
+        assert_eq!(map(&fns, device, memory, 4096, vk::WHOLE_SIZE), Err(failed));
+        assert_eq!(map(&fns, device, memory, 4000, 100), Err(failed));
+        assert_eq!(map(&fns, device, memory, 2, !0 - 1), Err(failed));
+        assert_eq!(map(&fns, device, memory, 0, 0), Err(failed));
+        assert_eq!(mock::take_errors(device).len(), 4);
+
+        map(&fns, device, memory, 4000, 96).unwrap();
+        assert_eq!(map(&fns, device, memory, 0, 16), Err(failed));
+        assert_eq!(mock::take_errors(device).len(), 1);
+        fns.vkUnmapMemory(device, memory);
+        fns.vkUnmapMemory(device, memory);
+        assert_eq!(mock::take_errors(device).len(), 1);
+
+        let device_local = allocate(&fns, device, 4096, 0).unwrap();
+        assert_eq!(map(&fns, device, device_local, 0, vk::WHOLE_SIZE), Err(failed));
+        assert_eq!(mock::take_errors(device).len(), 1);
+    }
+}
+
+#[test]
+fn heap_usage_does_not_overflow() {
+    let setup = Setup::new();
+    let (device, fns) = setup.device();
+    unsafe {
+        let memory = allocate(&fns, device, 4096, 0).unwrap();
+        assert_eq!(allocate(&fns, device, !0 - 100, 0),
+                   Err(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY));
+        assert_eq!(mock::heap_usage(device)[0], 4096);
+        fns.vkFreeMemory(device, memory, null());
+        assert_eq!(mock::heap_usage(device)[0], 0);
+    }
+}
+
+#[test]
+fn implemented_device_extensions() {
+    let setup = Setup::new();
+    unsafe {
+        let mut count = 0;
+        setup.instance_fns.vkEnumerateDeviceExtensionProperties(
+            setup.physical_device, null(), &mut count, null_mut()).result().unwrap();
+        let mut properties = vec![vk::ExtensionProperties::default(); count as usize];
+        setup.instance_fns.vkEnumerateDeviceExtensionProperties(
+            setup.physical_device, null(), &mut count, properties.as_mut_ptr())
+            .result().unwrap();
+        let names: Vec<_> = properties.iter()
+            .map(|p| CStr::from_ptr(p.extensionName.as_ptr()).to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["VK_KHR_get_memory_requirements2", "VK_KHR_dedicated_allocation"]);
+
+        let device = setup.create_device(&[cstr(b"VK_KHR_get_memory_requirements2\0"),
+                                           cstr(b"VK_KHR_dedicated_allocation\0")])
+            .unwrap();
+        let name = cstr(b"vkGetBufferMemoryRequirements2KHR\0");
+        assert!(setup.instance_fns.vkGetDeviceProcAddr(device, name.as_ptr()).is_some());
+
+        assert_eq!(setup.create_device(&[cstr(b"VK_KHR_dedicated_allocation\0"),
+                                         cstr(b"VK_KHR_swapchain\0")]),
+                   Err(vk::Result::ERROR_EXTENSION_NOT_PRESENT));
+    }
+}
+
+#[test]
+fn unknown_instance_extensions() {
+    let entry = mock::entry(Default::default());
+    let names = [cstr(b"VK_KHR_surface\0").as_ptr()];
+    let info = vk::InstanceCreateInfo {
+        enabledExtensionCount: 1,
+        ppEnabledExtensionNames: names.as_ptr(),
+        ..Default::default()
+    };
+    let mut instance = null_mut();
+    unsafe {
+        assert_eq!(entry.vkCreateInstance(&info, null(), &mut instance),
+                   vk::Result::ERROR_EXTENSION_NOT_PRESENT);
+    }
+}
+
+#[test]
+fn misuse_is_recorded() {
+    let setup = Setup::new();
+    let (device, fns) = setup.device();
+    unsafe {
+        let mut fence = vk::Fence::null();
+        fns.vkCreateFence(device, &Default::default(), null(), &mut fence).result().unwrap();
+        assert_eq!(mock::live_objects(device).len(), 1);
+        fns.vkDestroyFence(device, fence, null());
+        fns.vkDestroyFence(device, fence, null());
+        assert!(mock::live_objects(device).is_empty());
+        assert_eq!(mock::take_errors(device).len(), 1);
+        assert!(mock::take_errors(device).is_empty());
+    }
+}
+
+fn named(name: &str) -> mock::Config {
+    let mut device = mock::PhysicalDeviceConfig::default();
+    device.set_name(name);
+    mock::Config { physical_devices: vec![device] }
+}
+
+/// Name of the first physical device of a new instance made through `entry`.
+fn device_name(entry: &vk::Entry) -> String {
+    Setup::from_entry(entry).properties().device_name_lossy().into_owned()
+}
+
+#[test]
+fn each_entry_has_its_own_config() {
+    let first = mock::entry(named("first"));
+    let second = mock::entry(named("second"));
+    assert_eq!(device_name(&first), "first");
+    assert_eq!(device_name(&second), "second");
+    drop(second);
+    assert_eq!(device_name(&first.clone()), "first");
+    let other_thread = thread::spawn(move || device_name(&first));
+    assert_eq!(other_thread.join().unwrap(), "first");
+}
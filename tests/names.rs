@@ -0,0 +1,38 @@
+//! Names of enum values, from `make_enum!` and `ext_enums!`.
+extern crate vulkan_bind;
+
+use vulkan_bind::vk;
+
+#[test]
+fn core_names() {
+    assert_eq!(format!("{:?}", vk::Format(37)), "R8G8B8A8_UNORM");
+    assert_eq!(vk::Format::R8G8B8A8_UNORM.to_string(), "R8G8B8A8_UNORM");
+    assert_eq!(vk::Result::ERROR_DEVICE_LOST.name(), Some("ERROR_DEVICE_LOST"));
+    assert_eq!("R8G8B8A8_UNORM".parse(), Ok(vk::Format::R8G8B8A8_UNORM));
+}
+
+#[test]
+fn extension_names() {
+    assert_eq!(format!("{:?}", vk::Result::ERROR_OUT_OF_DATE), "ERROR_OUT_OF_DATE");
+    assert_eq!(vk::Result::SUBOPTIMAL.to_string(), "SUBOPTIMAL");
+    assert_eq!("ERROR_SURFACE_LOST".parse(), Ok(vk::Result::ERROR_SURFACE_LOST));
+}
+
+#[test]
+fn unknown_values() {
+    assert_eq!(vk::Result(12345).name(), None);
+    assert_eq!(format!("{:?}", vk::Result(12345)), "Result(12345)");
+    assert_eq!(vk::Result(12345).to_string(), "12345");
+    assert_eq!("12345".parse(), Ok(vk::Result(12345)));
+    assert_eq!("-4".parse(), Ok(vk::Result::ERROR_DEVICE_LOST));
+}
+
+#[test]
+fn parse_failures() {
+    let err = "R8G8B8A8_unorm".parse::<vk::Format>().unwrap_err();
+    assert_eq!(err.to_string(), "`R8G8B8A8_unorm` is not a Format value");
+    assert!("".parse::<vk::Format>().is_err());
+    assert!("VK_FORMAT_R8G8B8A8_UNORM".parse::<vk::Format>().is_err());
+    // Extension values only name the enum they extend.
+    assert!("ERROR_OUT_OF_DATE".parse::<vk::Format>().is_err());
+}
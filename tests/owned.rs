@@ -0,0 +1,146 @@
+//! Drop order of the owned handles, checked against the mock implementation.
+#![cfg(all(feature = "mock", feature = "owned"))]
+
+extern crate vulkan_bind;
+
+use std::sync::Arc;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::mock;
+use vulkan_bind::vk::owned;
+
+fn device() -> Arc<owned::Device> {
+    let entry = mock::entry(Default::default());
+    unsafe {
+        let instance = owned::Instance::create(&entry, &Default::default()).unwrap();
+        let physical_device = instance.fns().enumerate_physical_devices(instance.handle())
+            .unwrap()[0];
+        let priorities = [1.0];
+        let queue_info = vk::DeviceQueueCreateInfo {
+            queueFamilyIndex: 0,
+            queueCount: 1,
+            pQueuePriorities: priorities.as_ptr(),
+            ..Default::default()
+        };
+        let info = vk::DeviceCreateInfo {
+            queueCreateInfoCount: 1,
+            pQueueCreateInfos: &queue_info,
+            ..Default::default()
+        };
+        owned::Device::create(&instance, physical_device, &info).unwrap()
+    }
+}
+
+#[test]
+fn children_are_destroyed_on_drop() {
+    let device = device();
+    let raw = device.handle();
+    unsafe {
+        let fence = owned::Fence::create(&device, &Default::default()).unwrap();
+        let buffer = owned::Buffer::create(&device, &vk::BufferCreateInfo {
+            size: 256,
+            ..Default::default()
+        }).unwrap();
+        assert_eq!(mock::live_objects(raw).len(), 2);
+        drop(fence);
+        assert_eq!(mock::live_objects(raw), [(vk::ObjectType::BUFFER, buffer.handle().0)]);
+        drop(buffer);
+        assert!(mock::live_objects(raw).is_empty());
+        assert!(mock::take_errors(raw).is_empty());
+    }
+}
+
+#[test]
+fn device_outlives_its_children() {
+    let device = device();
+    let raw = device.handle();
+    unsafe {
+        let semaphore = owned::Semaphore::create(&device, &Default::default()).unwrap();
+        // The semaphore still holds the device, and the device its instance.
+        drop(device);
+        assert_eq!(Arc::strong_count(semaphore.device()), 1);
+        assert_eq!(Arc::strong_count(semaphore.device().instance()), 1);
+        assert_eq!(mock::live_objects(raw).len(), 1);
+        drop(semaphore);
+    }
+}
+
+#[test]
+fn into_raw_keeps_the_object() {
+    let device = device();
+    let raw = device.handle();
+    unsafe {
+        let event = owned::Event::create(&device, &Default::default()).unwrap();
+        let handle = event.into_raw();
+        assert_eq!(Arc::strong_count(&device), 1);
+        assert_eq!(mock::live_objects(raw), [(vk::ObjectType::EVENT, handle.0)]);
+        device.fns().vkDestroyEvent(raw, handle, std::ptr::null());
+        assert!(mock::take_errors(raw).is_empty());
+    }
+}
+
+#[test]
+fn each_pipeline_holds_the_device() {
+    let device = device();
+    let raw = device.handle();
+    unsafe {
+        let infos: Vec<vk::ComputePipelineCreateInfo> =
+            (0..3).map(|_| Default::default()).collect();
+        let pipelines = owned::Pipeline::create_compute(&device, None, &infos).unwrap();
+        assert_eq!(pipelines.len(), 3);
+        assert_eq!(Arc::strong_count(&device), 4);
+        drop(pipelines);
+        assert!(mock::live_objects(raw).is_empty());
+        assert!(mock::take_errors(raw).is_empty());
+    }
+}
+
+#[test]
+fn create_reports_vulkan_errors() {
+    let entry = mock::entry(Default::default());
+    let layer = b"VK_LAYER_missing\0".as_ptr() as *const _;
+    let info = vk::InstanceCreateInfo {
+        enabledLayerCount: 1,
+        ppEnabledLayerNames: &layer,
+        ..Default::default()
+    };
+    match unsafe { owned::Instance::create(&entry, &info) } {
+        Err(owned::CreateError::Vulkan(err)) =>
+            assert_eq!(err.0, vk::Result::ERROR_LAYER_NOT_PRESENT),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn objects_keep_the_entry() {
+    let entry = mock::entry(Default::default());
+    let instance = unsafe { owned::Instance::create(&entry, &Default::default()).unwrap() };
+    drop(entry);
+    unsafe {
+        let physical_devices = instance.fns().enumerate_physical_devices(instance.handle());
+        assert_eq!(physical_devices.unwrap().len(), 1);
+        assert!(instance.entry().enumerate_instance_extension_properties(None).is_ok());
+    }
+    drop(instance);
+}
+
+#[test]
+fn compile_required_is_an_error() {
+    let device = device();
+    let raw = device.handle();
+    unsafe {
+        let infos = [
+            Default::default(),
+            vk::ComputePipelineCreateInfo {
+                flags: vk::PipelineCreateFlag::FAIL_ON_PIPELINE_COMPILE_REQUIRED.into(),
+                ..Default::default()
+            },
+        ];
+        match owned::Pipeline::create_compute(&device, None, &infos) {
+            Err(err) => assert_eq!(err.0, vk::Result::PIPELINE_COMPILE_REQUIRED),
+            Ok(pipelines) => panic!("{:?}", pipelines),
+        }
+        assert!(mock::live_objects(raw).is_empty());
+        assert!(mock::take_errors(raw).is_empty());
+    }
+}
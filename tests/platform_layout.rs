@@ -0,0 +1,29 @@
+//! Layout of the structs in the hand-written platform modules. Their C
+//! declarations need the window system's headers, so `tests/layout.c`
+//! doesn't measure them and these are written out by hand.
+#![cfg(all(target_os = "linux", feature = "xcb_surface"))]
+
+extern crate vulkan_bind;
+
+use std::mem::{align_of, offset_of, size_of};
+
+use vulkan_bind::vk;
+
+macro_rules! check_layout {
+    ($ty:ty, $size:expr, $align:expr $(, $field:ident: $offset:expr)*) => {
+        assert_eq!(size_of::<$ty>(), $size, concat!("size of ", stringify!($ty)));
+        assert_eq!(align_of::<$ty>(), $align, concat!("alignment of ", stringify!($ty)));
+        $(assert_eq!(offset_of!($ty, $field), $offset,
+                     concat!("offset of ", stringify!($ty), "::", stringify!($field)));)*
+    }
+}
+
+#[test]
+fn khr_xcb_surface() {
+    #[cfg(target_pointer_width = "64")]
+    check_layout!(vk::khr::xcb_surface::CreateInfo, 40, 8, sType: 0, pNext: 8, flags: 16,
+        connection: 24, window: 32);
+    #[cfg(target_pointer_width = "32")]
+    check_layout!(vk::khr::xcb_surface::CreateInfo, 20, 4, sType: 0, pNext: 4, flags: 8,
+        connection: 12, window: 16);
+}
@@ -0,0 +1,38 @@
+//! Success and error codes of `vk::Result`, and `vk::Error`.
+extern crate vulkan_bind;
+
+use std::error::Error;
+
+use vulkan_bind::vk;
+
+#[test]
+fn success_codes_are_ok() {
+    for &code in &[vk::Result::SUCCESS, vk::Result::TIMEOUT, vk::Result::INCOMPLETE,
+                   vk::Result::SUBOPTIMAL] {
+        assert!(code.is_success() && !code.is_error(), "{:?}", code);
+        assert_eq!(code.result(), Ok(()));
+    }
+    assert_eq!(vk::Result::INCOMPLETE.result_with(3), Ok(3));
+}
+
+#[test]
+fn error_codes_are_err() {
+    for &code in &[vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE,
+                   vk::Result(-12345)] {
+        assert!(code.is_error() && !code.is_success(), "{:?}", code);
+        assert_eq!(code.result(), Err(vk::Error(code)));
+        assert_eq!(code.result_with(3).unwrap_err().code(), code);
+    }
+    assert_eq!(vk::Result::from(vk::Error(vk::Result::ERROR_DEVICE_LOST)),
+               vk::Result::ERROR_DEVICE_LOST);
+}
+
+#[test]
+fn display() {
+    let error = vk::Error(vk::Result::ERROR_DEVICE_LOST);
+    assert_eq!(error.to_string(),
+               "The logical or physical device has been lost. (ERROR_DEVICE_LOST)");
+    assert_eq!(vk::Error(vk::Result(-12345)).to_string(), "error -12345");
+    let boxed: Box<dyn Error> = Box::new(error);
+    assert!(boxed.source().is_none());
+}
@@ -0,0 +1,38 @@
+//! Accessors for fixed-size string fields.
+extern crate vulkan_bind;
+
+use std::os::raw::c_char;
+
+use vulkan_bind::vk;
+
+fn extension(name: &[u8]) -> vk::ExtensionProperties {
+    let mut properties = vk::ExtensionProperties::default();
+    for (dst, &src) in properties.extensionName.iter_mut().zip(name) {
+        *dst = src as c_char;
+    }
+    properties
+}
+
+#[test]
+fn up_to_nul() {
+    let properties = extension(b"VK_KHR_swapchain\0garbage");
+    assert_eq!(properties.extension_name().unwrap().to_bytes(), b"VK_KHR_swapchain");
+    assert_eq!(properties.extension_name_lossy(), "VK_KHR_swapchain");
+    assert!(properties.is("VK_KHR_swapchain"));
+    assert!(!properties.is("VK_KHR_swap"));
+}
+
+#[test]
+fn without_nul() {
+    let properties = extension(&[b'x'; 256]);
+    assert!(properties.extension_name().is_none());
+    assert_eq!(properties.extension_name_lossy().len(), 256);
+    assert!(properties.is(&"x".repeat(256)));
+}
+
+#[test]
+fn invalid_utf8() {
+    let properties = extension(b"name\xff\0");
+    assert_eq!(properties.extension_name().unwrap().to_bytes(), b"name\xff");
+    assert_eq!(properties.extension_name_lossy(), "name\u{fffd}");
+}
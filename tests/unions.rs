@@ -0,0 +1,37 @@
+//! Constructors and `Debug` of the unions in `vk`.
+
+extern crate vulkan_bind;
+
+use vulkan_bind::vk;
+
+#[test]
+fn depth_stencil_debug() {
+    let value = vk::ClearValue::depth_stencil(1.0, 0x7f);
+    assert_eq!(format!("{:?}", value), "ClearValue { bits: [0x3f800000, 0x7f, 0x0, 0x0] }");
+    assert_eq!(unsafe { value.depthStencil.stencil }, 0x7f);
+}
+
+#[test]
+fn constructors_zero_the_rest() {
+    let value = vk::ClearValue::depth_stencil(1.0, 2);
+    let words: [u32; 4] = unsafe { std::mem::transmute(value) };
+    assert_eq!(words, [0x3f800000, 2, 0, 0]);
+}
+
+#[test]
+fn color_debug() {
+    let value = vk::ClearValue::color_u32([1, 2, 3, 4]);
+    assert_eq!(unsafe { value.color.uint32 }, [1, 2, 3, 4]);
+    assert_eq!(format!("{:?}", value), "ClearValue { bits: [0x1, 0x2, 0x3, 0x4] }");
+    let value = vk::ClearValue::color_f32([0.1, 0.2, 0.3, 1.0]);
+    assert_eq!(format!("{:?}", value),
+               "ClearValue { bits: [0x3dcccccd, 0x3e4ccccd, 0x3e99999a, 0x3f800000] }");
+    let color = vk::ClearColorValue::float32([0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(format!("{:?}", color), "ClearColorValue { bits: [0x0, 0x0, 0x0, 0x3f800000] }");
+}
+
+#[test]
+fn default_is_zero() {
+    let value = vk::ClearValue::default();
+    assert_eq!(unsafe { value.color.uint32 }, [0; 4]);
+}
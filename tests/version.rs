@@ -0,0 +1,54 @@
+//! Packing, printing and parsing version numbers.
+extern crate vulkan_bind;
+
+use vulkan_bind::vk;
+use vulkan_bind::vk::{DriverVersion, Version};
+
+#[test]
+fn raw_round_trip() {
+    let version = Version::with_variant(1, 1, 3, 250);
+    assert_eq!(version.to_raw(), 1 << 29 | 1 << 22 | 3 << 12 | 250);
+    assert_eq!(Version::from_raw(version.to_raw()), version);
+    assert_eq!(vk::make_version(1, 3, 250), Version::new(1, 3, 250).to_raw());
+}
+
+#[test]
+fn make_version_does_not_mask() {
+    assert_eq!(vk::make_version(0, 0, 0x1000), 1 << 12);
+    assert_eq!(vk::make_version(0x80, 0, 0), 1 << 29);
+}
+
+#[test]
+fn display_round_trip() {
+    for &(version, text) in &[(Version::new(1, 0, 4), "1.0.4"),
+                              (Version::new(127, 1023, 4095), "127.1023.4095"),
+                              (Version::with_variant(7, 1, 2, 3), "7:1.2.3")] {
+        assert_eq!(version.to_string(), text);
+        assert_eq!(text.parse(), Ok(version));
+    }
+    assert_eq!("1.2".parse(), Ok(Version::new(1, 2, 0)));
+}
+
+#[test]
+fn parse_errors() {
+    for text in &["", "1", "1.2.3.4", "128.0.0", "1.1024.0", "1.0.4096", "8:1.0.0",
+                  "+1.0.0", "1.-1.0", "a.b.c", "1:2:1.0.0"] {
+        assert!(text.parse::<Version>().is_err(), "{:?} parsed", text);
+    }
+}
+
+#[test]
+fn driver_versions() {
+    let nvidia = 535 << 22 | 98 << 14 | 1 << 6 | 2;
+    assert_eq!(DriverVersion::decode(vk::VENDOR_ID_NVIDIA, nvidia, false).to_string(),
+               "535.98.1.2");
+
+    let intel = 101 << 14 | 2115;
+    assert_eq!(DriverVersion::decode(vk::VENDOR_ID_INTEL, intel, true).to_string(),
+               "101.2115");
+    assert_eq!(DriverVersion::decode(vk::VENDOR_ID_INTEL, intel, false),
+               DriverVersion::Standard(Version::from_raw(intel)));
+
+    let mesa = vk::make_version(23, 1, 4);
+    assert_eq!(DriverVersion::decode(vk::VENDOR_ID_MESA, mesa, true).to_string(), "23.1.4");
+}